@@ -1,53 +1,183 @@
 use std::collections::HashMap;
+use std::sync::atomic::{AtomicU64, Ordering};
 use std::sync::Arc;
 use std::time::{Duration, Instant};
-use tokio::sync::RwLock;
+use tokio::sync::{broadcast, Mutex, RwLock};
 use serde_json::Value;
 
 #[derive(Clone, Debug)]
 pub struct CacheEntry {
     pub data: Value,
     pub expires_at: Instant,
+    /// Hard removal deadline. Equal to `expires_at` unless a stale-while-revalidate TTL was
+    /// requested, in which case the entry is still servable (but `soft_expired`) until then.
+    pub stale_until: Instant,
+    pub last_accessed: Instant,
+    pub size_bytes: usize,
 }
 
 impl CacheEntry {
     pub fn new(data: Value, ttl: Duration) -> Self {
+        Self::with_stale_ttl(data, ttl, Duration::ZERO)
+    }
+
+    pub fn with_stale_ttl(data: Value, ttl: Duration, stale_ttl: Duration) -> Self {
+        let size_bytes = serde_json::to_vec(&data).map(|v| v.len()).unwrap_or(0);
+        let now = Instant::now();
+        let expires_at = now + ttl;
         Self {
             data,
-            expires_at: Instant::now() + ttl,
+            expires_at,
+            stale_until: expires_at + stale_ttl,
+            last_accessed: now,
+            size_bytes,
         }
     }
 
+    /// True once the entry is past its stale window entirely and must be treated as a miss.
     pub fn is_expired(&self) -> bool {
-        Instant::now() > self.expires_at
+        Instant::now() > self.stale_until
+    }
+
+    /// True when the entry is past `expires_at` but still within `stale_until`: servable, but
+    /// a caller should trigger a background refresh.
+    pub fn soft_expired(&self) -> bool {
+        let now = Instant::now();
+        now > self.expires_at && now <= self.stale_until
     }
 }
 
+/// Default cache budget: 64 MiB of serialized JSON, no entry-count cap.
+const DEFAULT_MAX_BYTES: usize = 64 * 1024 * 1024;
+
 #[derive(Clone)]
 pub struct MemoryCache {
     storage: Arc<RwLock<HashMap<String, CacheEntry>>>,
+    max_bytes: usize,
+    max_entries: Option<usize>,
+    hits: Arc<AtomicU64>,
+    misses: Arc<AtomicU64>,
+    /// Cache keys with a computation currently in flight via [`Self::get_or_compute`], so
+    /// concurrent callers for the same key share one recompute instead of each running it.
+    in_flight: Arc<Mutex<HashMap<String, broadcast::Sender<Value>>>>,
 }
 
 impl MemoryCache {
     pub fn new() -> Self {
+        Self::with_limits(DEFAULT_MAX_BYTES, None)
+    }
+
+    pub fn with_limits(max_bytes: usize, max_entries: Option<usize>) -> Self {
         Self {
             storage: Arc::new(RwLock::new(HashMap::new())),
+            max_bytes,
+            max_entries,
+            hits: Arc::new(AtomicU64::new(0)),
+            misses: Arc::new(AtomicU64::new(0)),
+            in_flight: Arc::new(Mutex::new(HashMap::new())),
         }
     }
 
     pub async fn get(&self, key: &str) -> Option<Value> {
-        let storage = self.storage.read().await;
-        if let Some(entry) = storage.get(key) {
+        let mut storage = self.storage.write().await;
+        if let Some(entry) = storage.get_mut(key) {
             if !entry.is_expired() {
+                entry.last_accessed = Instant::now();
+                self.hits.fetch_add(1, Ordering::Relaxed);
                 return Some(entry.data.clone());
             }
         }
+        self.misses.fetch_add(1, Ordering::Relaxed);
         None
     }
 
     pub async fn set(&self, key: String, data: Value, ttl: Duration) {
         let mut storage = self.storage.write().await;
         storage.insert(key, CacheEntry::new(data, ttl));
+        Self::evict_lru_over_budget(&mut storage, self.max_bytes, self.max_entries);
+    }
+
+    /// Like `set`, but the entry remains servable (marked `soft_expired`) for `stale_ttl`
+    /// past `ttl` before it's treated as a full miss.
+    pub async fn set_with_stale(&self, key: String, data: Value, ttl: Duration, stale_ttl: Duration) {
+        let mut storage = self.storage.write().await;
+        storage.insert(key, CacheEntry::with_stale_ttl(data, ttl, stale_ttl));
+        Self::evict_lru_over_budget(&mut storage, self.max_bytes, self.max_entries);
+    }
+
+    /// Like `get`, but also reports whether the entry is past `expires_at` (soft-expired) so
+    /// a stale-while-revalidate caller knows to kick off a background refresh.
+    pub async fn get_with_staleness(&self, key: &str) -> Option<(Value, bool)> {
+        let mut storage = self.storage.write().await;
+        if let Some(entry) = storage.get_mut(key) {
+            if !entry.is_expired() {
+                entry.last_accessed = Instant::now();
+                self.hits.fetch_add(1, Ordering::Relaxed);
+                return Some((entry.data.clone(), entry.soft_expired()));
+            }
+        }
+        self.misses.fetch_add(1, Ordering::Relaxed);
+        None
+    }
+
+    /// Returns the cached value for `key` if live; otherwise runs `compute` once and caches
+    /// the result for `ttl`. Concurrent callers for the same key while a computation is
+    /// already in flight await that single computation instead of each re-running `compute`
+    /// (stampede protection) -- the same pattern
+    /// [`crate::optimized_client::OptimizedApiClient::single_flight`] uses for upstream HTTP
+    /// fetches, applied here to arbitrary in-process recomputation (e.g. handlers that fan out
+    /// to several sources and re-derive metrics on every call).
+    pub async fn get_or_compute<F, Fut>(&self, key: &str, ttl: Duration, compute: F) -> Value
+    where
+        F: FnOnce() -> Fut,
+        Fut: std::future::Future<Output = Value>,
+    {
+        if let Some(cached) = self.get(key).await {
+            return cached;
+        }
+
+        let receiver = {
+            let mut in_flight = self.in_flight.lock().await;
+            if let Some(sender) = in_flight.get(key) {
+                Some(sender.subscribe())
+            } else {
+                let (sender, _) = broadcast::channel(1);
+                in_flight.insert(key.to_string(), sender);
+                None
+            }
+        };
+
+        if let Some(mut receiver) = receiver {
+            if let Ok(value) = receiver.recv().await {
+                return value;
+            }
+            // The in-flight computation's sender was dropped without sending (it panicked) --
+            // fall through and compute it ourselves.
+        }
+
+        let result = compute().await;
+        self.set(key.to_string(), result.clone(), ttl).await;
+        if let Some(sender) = self.in_flight.lock().await.remove(key) {
+            let _ = sender.send(result.clone());
+        }
+        result
+    }
+
+    /// Evicts least-recently-used entries until the cache fits within `max_bytes` and, if
+    /// set, `max_entries`.
+    fn evict_lru_over_budget(storage: &mut HashMap<String, CacheEntry>, max_bytes: usize, max_entries: Option<usize>) {
+        loop {
+            let total_bytes: usize = storage.values().map(|e| e.size_bytes).sum();
+            let over_entries = max_entries.map(|m| storage.len() > m).unwrap_or(false);
+            if total_bytes <= max_bytes && !over_entries {
+                break;
+            }
+            let lru_key = match storage.iter().min_by_key(|(_, e)| e.last_accessed) {
+                Some((k, _)) => k.clone(),
+                None => break,
+            };
+            storage.remove(&lru_key);
+        }
     }
 
     pub async fn cleanup_expired(&self) {
@@ -64,6 +194,32 @@ impl MemoryCache {
         let storage = self.storage.read().await;
         storage.len()
     }
+
+    pub async fn bytes_used(&self) -> usize {
+        let storage = self.storage.read().await;
+        storage.values().map(|e| e.size_bytes).sum()
+    }
+
+    /// Total `get`/`get_with_staleness` calls that returned a live entry, since process start.
+    pub fn hits(&self) -> u64 {
+        self.hits.load(Ordering::Relaxed)
+    }
+
+    /// Total `get`/`get_with_staleness` calls that found no live entry, since process start.
+    pub fn misses(&self) -> u64 {
+        self.misses.load(Ordering::Relaxed)
+    }
+
+    /// `hits / (hits + misses)`, or `0.0` before any lookups have been made.
+    pub fn hit_rate(&self) -> f64 {
+        let hits = self.hits() as f64;
+        let misses = self.misses() as f64;
+        if hits + misses == 0.0 {
+            0.0
+        } else {
+            hits / (hits + misses)
+        }
+    }
 }
 
 impl Default for MemoryCache {
@@ -117,4 +273,105 @@ mod tests {
         let key = cache_key("yahoo_prices", &[("symbol", "AAPL"), ("range", "1d")]);
         assert_eq!(key, "yahoo_prices:symbol=AAPL:range=1d");
     }
+
+    #[tokio::test]
+    async fn test_cache_evicts_lru_over_byte_budget() {
+        let cache = MemoryCache::with_limits(30, None);
+        let value = serde_json::json!({"message": "hello"});
+
+        cache.set("a".to_string(), value.clone(), Duration::from_secs(60)).await;
+        cache.set("b".to_string(), value.clone(), Duration::from_secs(60)).await;
+
+        assert_eq!(cache.size().await, 1);
+        assert_eq!(cache.get("a").await, None);
+        assert_eq!(cache.get("b").await, Some(value));
+    }
+
+    #[tokio::test]
+    async fn test_cache_evicts_lru_over_entry_cap() {
+        let cache = MemoryCache::with_limits(DEFAULT_MAX_BYTES, Some(1));
+        let value = serde_json::json!({"message": "hello"});
+
+        cache.set("a".to_string(), value.clone(), Duration::from_secs(60)).await;
+        cache.get("a").await;
+        cache.set("b".to_string(), value.clone(), Duration::from_secs(60)).await;
+
+        assert_eq!(cache.size().await, 1);
+        assert_eq!(cache.get("b").await, Some(value));
+    }
+
+    #[tokio::test]
+    async fn test_stale_while_revalidate_window() {
+        let cache = MemoryCache::new();
+        let key = "swr_key";
+        let value = serde_json::json!({"message": "still good"});
+
+        cache.set_with_stale(key.to_string(), value.clone(), Duration::from_millis(10), Duration::from_millis(200)).await;
+        sleep(Duration::from_millis(20)).await;
+
+        let (cached, stale) = cache.get_with_staleness(key).await.expect("entry should still be servable within stale window");
+        assert_eq!(cached, value);
+        assert!(stale);
+    }
+
+    #[tokio::test]
+    async fn test_bytes_used_tracks_entries() {
+        let cache = MemoryCache::new();
+        assert_eq!(cache.bytes_used().await, 0);
+        cache.set("k".to_string(), serde_json::json!({"a": 1}), Duration::from_secs(60)).await;
+        assert!(cache.bytes_used().await > 0);
+    }
+
+    #[tokio::test]
+    async fn test_get_or_compute_coalesces_concurrent_misses() {
+        let cache = Arc::new(MemoryCache::new());
+        let calls = Arc::new(AtomicU64::new(0));
+
+        let mut handles = Vec::new();
+        for _ in 0..5 {
+            let cache = cache.clone();
+            let calls = calls.clone();
+            handles.push(tokio::spawn(async move {
+                cache.get_or_compute("shared_key", Duration::from_secs(60), || async move {
+                    calls.fetch_add(1, Ordering::Relaxed);
+                    sleep(Duration::from_millis(20)).await;
+                    serde_json::json!({"computed": true})
+                }).await
+            }));
+        }
+
+        for handle in handles {
+            assert_eq!(handle.await.unwrap(), serde_json::json!({"computed": true}));
+        }
+        assert_eq!(calls.load(Ordering::Relaxed), 1);
+    }
+
+    #[tokio::test]
+    async fn test_get_or_compute_reuses_cached_value() {
+        let cache = MemoryCache::new();
+        let calls = Arc::new(AtomicU64::new(0));
+
+        for _ in 0..3 {
+            let calls = calls.clone();
+            let value = cache.get_or_compute("cached_key", Duration::from_secs(60), || async move {
+                calls.fetch_add(1, Ordering::Relaxed);
+                serde_json::json!({"computed": true})
+            }).await;
+            assert_eq!(value, serde_json::json!({"computed": true}));
+        }
+        assert_eq!(calls.load(Ordering::Relaxed), 1);
+    }
+
+    #[tokio::test]
+    async fn test_hit_miss_counters() {
+        let cache = MemoryCache::new();
+        cache.get("missing").await;
+        cache.set("k".to_string(), serde_json::json!({"a": 1}), Duration::from_secs(60)).await;
+        cache.get("k").await;
+        cache.get("k").await;
+
+        assert_eq!(cache.misses(), 1);
+        assert_eq!(cache.hits(), 2);
+        assert!((cache.hit_rate() - (2.0 / 3.0)).abs() < f64::EPSILON);
+    }
 }
\ No newline at end of file
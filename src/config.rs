@@ -1,5 +1,7 @@
 use std::env;
 use serde::{Deserialize, Serialize};
+use crate::sources::jito_data::JitoConfig;
+use crate::middleware::redis_rate_limit::DeferredRateLimitConfig;
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Config {
@@ -9,6 +11,30 @@ pub struct Config {
     pub logging: LoggingConfig,
     pub rate_limiting: RateLimitConfig,
     pub retry: RetryConfig,
+    pub metrics: MetricsConfig,
+    pub jito: JitoConfig,
+    #[serde(skip)]
+    pub data_rate_limiting: DeferredRateLimitConfig,
+    /// Network-wide micro-lamports-per-compute-unit fallback used when no recent
+    /// prioritization fee data exists for the accounts an estimate was requested for.
+    pub priority_fee_default_micro_lamports: u64,
+    /// Postgres connection settings for the screener-history store; `None` disables
+    /// persistence entirely (`HISTORY_DATABASE_URL`/`HISTORY_PG_*` unset).
+    pub history_db: Option<crate::services::history::HistoryDbConfig>,
+    /// Postgres connection settings for the OHLC candle store; `None` disables persistence
+    /// entirely (`CANDLES_DATABASE_URL`/`CANDLES_PG_*` unset).
+    pub candles_db: Option<crate::services::candles::CandleDbConfig>,
+    /// Postgres connection settings for the market store (ticker mentions, OHLCV bars,
+    /// computed metrics); `None` disables persistence entirely
+    /// (`MARKET_STORE_DATABASE_URL`/`MARKET_STORE_PG_*` unset).
+    pub market_store_db: Option<crate::services::market_store::MarketStoreDbConfig>,
+    /// Postgres connection settings for the pump.fun position manager's persisted exit rules;
+    /// `None` disables persistence entirely (`POSITIONS_DATABASE_URL`/`POSITIONS_PG_*` unset).
+    pub positions_db: Option<crate::services::position_manager::PositionManagerDbConfig>,
+    pub health_monitor: HealthMonitorConfig,
+    pub alerting: AlertingConfig,
+    pub coingecko: crate::sources::coingecko_data::CoinGeckoConfig,
+    pub trading_auth: TradingAuthConfig,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -43,6 +69,11 @@ pub struct RateLimitConfig {
     pub requests_per_minute: u32,
     pub burst_size: u32,
     pub enabled: bool,
+    /// Bearer tokens (`RATE_LIMIT_CLIENT_KEYS`, comma-separated) trusted as a per-client
+    /// rate-limit identity. A bearer token not in this list is never used as the bucket key,
+    /// since trusting an unverified token would let a caller get a fresh bucket on every
+    /// request just by sending a new random one.
+    pub client_keys: Vec<String>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -51,6 +82,108 @@ pub struct RetryConfig {
     pub base_delay_ms: u64,
     pub max_delay_ms: u64,
     pub enabled: bool,
+    pub backoff_mode: BackoffMode,
+}
+
+/// How [`crate::utils::retry_with_backoff`] spaces out retries. `Exponential` is the
+/// long-standing deterministic `base * 2^attempt` schedule; the jittered modes spread
+/// concurrent retries out in time so many clients failing at once (e.g. a CoinGecko/Kraken
+/// blip) don't all retry in lockstep and re-trigger the same rate limit.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum BackoffMode {
+    Exponential,
+    /// `delay = random_between(0, min(max_delay_ms, base_delay_ms * 2^attempt))`.
+    FullJitter,
+    /// AWS's decorrelated jitter: `delay = random_between(base_delay_ms, prev_delay * 3)`,
+    /// capped at `max_delay_ms`, with `prev_delay` carried forward attempt to attempt.
+    DecorrelatedJitter,
+}
+
+impl RetryConfig {
+    pub fn from_env() -> Self {
+        RetryConfig {
+            max_retries: env::var("RETRY_MAX_RETRIES")
+                .unwrap_or_else(|_| "3".to_string())
+                .parse()
+                .unwrap_or(3),
+            base_delay_ms: env::var("RETRY_BASE_DELAY_MS")
+                .unwrap_or_else(|_| "1000".to_string())
+                .parse()
+                .unwrap_or(1000),
+            max_delay_ms: env::var("RETRY_MAX_DELAY_MS")
+                .unwrap_or_else(|_| "10000".to_string())
+                .parse()
+                .unwrap_or(10000),
+            enabled: env::var("RETRY_ENABLED")
+                .unwrap_or_else(|_| "true".to_string())
+                .parse()
+                .unwrap_or(true),
+            backoff_mode: env::var("RETRY_BACKOFF_MODE")
+                .ok()
+                .and_then(|s| s.parse().ok())
+                .unwrap_or(BackoffMode::Exponential),
+        }
+    }
+}
+
+impl std::str::FromStr for BackoffMode {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_ascii_lowercase().as_str() {
+            "exponential" => Ok(BackoffMode::Exponential),
+            "full_jitter" | "full-jitter" => Ok(BackoffMode::FullJitter),
+            "decorrelated_jitter" | "decorrelated-jitter" => Ok(BackoffMode::DecorrelatedJitter),
+            other => Err(format!("unknown backoff mode: {other}")),
+        }
+    }
+}
+
+/// Controls the Prometheus text-exposition scrape endpoint; `enabled` mirrors
+/// [`RateLimitConfig::enabled`]'s env-var opt-out pattern so operators can pull metrics
+/// collection without touching anything else.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MetricsConfig {
+    pub enabled: bool,
+}
+
+/// Tunables for the background dependency prober that backs
+/// [`crate::services::health::HealthRegistry`]. `response_time_threshold_ms` is compared
+/// against each service's EWMA, not the raw sample, so one slow probe doesn't flip status.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct HealthMonitorConfig {
+    pub probe_interval_ms: u64,
+    pub ewma_alpha: f64,
+    pub response_time_threshold_ms: f64,
+    pub max_consecutive_failures: u32,
+}
+
+/// One HTTP endpoint [`crate::services::alerting::AlertDispatcher`] notifies on a service
+/// health transition, with whatever extra headers it needs (bearer tokens, signing secrets).
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct WebhookTarget {
+    pub url: String,
+    pub headers: Vec<(String, String)>,
+}
+
+/// Controls outbound alerting on dependency health transitions. `rtt_warning_threshold_ms`
+/// is a single network-wide default rather than truly per-service, since there's currently no
+/// env-var-friendly way to express a per-service map here (see
+/// [`DeferredRateLimitConfig::per_route_requests_per_minute`] for the same limitation).
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AlertingConfig {
+    pub webhooks: Vec<WebhookTarget>,
+    pub rtt_warning_threshold_ms: f64,
+}
+
+/// Gates routes that place real orders against the operator's own Alpaca account
+/// (`POST /options/orders`). `api_key` is `None` when `TRADING_API_KEY` isn't set, in which case
+/// [`crate::middleware::require_trading_api_key`] rejects every request rather than leaving the
+/// route open by default.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TradingAuthConfig {
+    pub api_key: Option<String>,
 }
 
 impl Config {
@@ -114,25 +247,112 @@ impl Config {
                 .unwrap_or_else(|_| "true".to_string())
                 .parse()
                 .unwrap_or(true),
+            client_keys: env::var("RATE_LIMIT_CLIENT_KEYS")
+                .ok()
+                .map(|keys| {
+                    keys.split(',')
+                        .map(|k| k.trim().to_string())
+                        .filter(|k| !k.is_empty())
+                        .collect()
+                })
+                .unwrap_or_default(),
         };
 
-        let retry = RetryConfig {
-            max_retries: env::var("RETRY_MAX_RETRIES")
+        let retry = RetryConfig::from_env();
+
+        let metrics = MetricsConfig {
+            enabled: env::var("METRICS_ENABLED")
+                .unwrap_or_else(|_| "true".to_string())
+                .parse()
+                .unwrap_or(true),
+        };
+
+        let jito = JitoConfig {
+            block_engine_url: env::var("JITO_BLOCK_ENGINE_URL")
+                .unwrap_or_else(|_| "https://mainnet.block-engine.jito.wtf".to_string()),
+            rpc_url: env::var("JITO_RPC_URL")
+                .unwrap_or_else(|_| "https://api.mainnet-beta.solana.com".to_string()),
+            auth_token: env::var("JITO_AUTH_TOKEN").ok(),
+            tip_amount: env::var("JITO_TIP_AMOUNT_LAMPORTS")
+                .ok()
+                .and_then(|v| v.parse().ok())
+                .or(Some(1000)),
+            max_retries: env::var("JITO_MAX_RETRIES")
                 .unwrap_or_else(|_| "3".to_string())
                 .parse()
                 .unwrap_or(3),
-            base_delay_ms: env::var("RETRY_BASE_DELAY_MS")
-                .unwrap_or_else(|_| "1000".to_string())
+            timeout_ms: env::var("JITO_TIMEOUT_MS")
+                .unwrap_or_else(|_| "30000".to_string())
                 .parse()
-                .unwrap_or(1000),
-            max_delay_ms: env::var("RETRY_MAX_DELAY_MS")
-                .unwrap_or_else(|_| "10000".to_string())
-                .parse()
-                .unwrap_or(10000),
-            enabled: env::var("RETRY_ENABLED")
-                .unwrap_or_else(|_| "true".to_string())
-                .parse()
-                .unwrap_or(true),
+                .unwrap_or(30000),
+            mock: env::var("MOCK_JITO")
+                .map(|v| v == "1" || v.eq_ignore_ascii_case("true"))
+                .unwrap_or(false),
+        };
+
+        let data_rate_limiting = DeferredRateLimitConfig {
+            redis_url: env::var("REDIS_URL").ok(),
+            default_requests_per_minute: env::var("DATA_RATE_LIMIT_PER_MINUTE")
+                .ok()
+                .and_then(|v| v.parse().ok())
+                .unwrap_or(60),
+            per_route_requests_per_minute: std::collections::HashMap::new(),
+            local_fast_path_threshold: env::var("DATA_RATE_LIMIT_LOCAL_THRESHOLD")
+                .ok()
+                .and_then(|v| v.parse().ok())
+                .unwrap_or(10),
+        };
+
+        let priority_fee_default_micro_lamports = env::var("PRIORITY_FEE_DEFAULT_MICRO_LAMPORTS")
+            .ok()
+            .and_then(|v| v.parse().ok())
+            .unwrap_or(1_000);
+
+        let history_db = crate::services::history::HistoryDbConfig::from_env();
+        let candles_db = crate::services::candles::CandleDbConfig::from_env();
+        let market_store_db = crate::services::market_store::MarketStoreDbConfig::from_env();
+        let positions_db = crate::services::position_manager::PositionManagerDbConfig::from_env();
+
+        let health_monitor = HealthMonitorConfig {
+            probe_interval_ms: env::var("HEALTH_PROBE_INTERVAL_MS")
+                .ok()
+                .and_then(|v| v.parse().ok())
+                .unwrap_or(30_000),
+            ewma_alpha: env::var("HEALTH_EWMA_ALPHA")
+                .ok()
+                .and_then(|v| v.parse().ok())
+                .unwrap_or(0.3),
+            response_time_threshold_ms: env::var("HEALTH_RESPONSE_TIME_THRESHOLD_MS")
+                .ok()
+                .and_then(|v| v.parse().ok())
+                .unwrap_or(2_000.0),
+            max_consecutive_failures: env::var("HEALTH_MAX_CONSECUTIVE_FAILURES")
+                .ok()
+                .and_then(|v| v.parse().ok())
+                .unwrap_or(3),
+        };
+
+        let alerting = AlertingConfig {
+            webhooks: env::var("ALERT_WEBHOOK_URLS")
+                .ok()
+                .map(|urls| {
+                    urls.split(',')
+                        .map(str::trim)
+                        .filter(|url| !url.is_empty())
+                        .map(|url| WebhookTarget { url: url.to_string(), headers: Vec::new() })
+                        .collect()
+                })
+                .unwrap_or_default(),
+            rtt_warning_threshold_ms: env::var("ALERT_RTT_WARNING_THRESHOLD_MS")
+                .ok()
+                .and_then(|v| v.parse().ok())
+                .unwrap_or(1_500.0),
+        };
+
+        let coingecko = crate::sources::coingecko_data::CoinGeckoConfig::from_env();
+
+        let trading_auth = TradingAuthConfig {
+            api_key: env::var("TRADING_API_KEY").ok().filter(|k| !k.is_empty()),
         };
 
         Ok(Config {
@@ -142,6 +362,18 @@ impl Config {
             logging,
             rate_limiting,
             retry,
+            metrics,
+            jito,
+            data_rate_limiting,
+            priority_fee_default_micro_lamports,
+            history_db,
+            candles_db,
+            market_store_db,
+            positions_db,
+            health_monitor,
+            alerting,
+            coingecko,
+            trading_auth,
         })
     }
 
@@ -171,13 +403,35 @@ impl Default for Config {
                 requests_per_minute: 60,
                 burst_size: 10,
                 enabled: true,
+                client_keys: Vec::new(),
             },
             retry: RetryConfig {
                 max_retries: 3,
                 base_delay_ms: 1000,
                 max_delay_ms: 10000,
                 enabled: true,
+                backoff_mode: BackoffMode::Exponential,
+            },
+            metrics: MetricsConfig { enabled: true },
+            jito: JitoConfig::default(),
+            data_rate_limiting: DeferredRateLimitConfig::default(),
+            priority_fee_default_micro_lamports: 1_000,
+            history_db: None,
+            candles_db: None,
+            market_store_db: None,
+            positions_db: None,
+            health_monitor: HealthMonitorConfig {
+                probe_interval_ms: 30_000,
+                ewma_alpha: 0.3,
+                response_time_threshold_ms: 2_000.0,
+                max_consecutive_failures: 3,
+            },
+            alerting: AlertingConfig {
+                webhooks: Vec::new(),
+                rtt_warning_threshold_ms: 1_500.0,
             },
+            coingecko: crate::sources::coingecko_data::CoinGeckoConfig::default(),
+            trading_auth: TradingAuthConfig { api_key: None },
         }
     }
 }
@@ -25,6 +25,13 @@ pub enum ApiError {
     Configuration(String),
     #[error("invalid input: {0}")]
     InvalidInput(String),
+    #[error("schema mismatch: {0}")]
+    SchemaMismatch(String),
+    /// A request referenced a bonding curve that's already migrated to Raydium (e.g. a
+    /// position-manager exit rule registered against a completed curve), so pump.fun's virtual
+    /// reserves no longer price it.
+    #[error("bonding curve already migrated: {0}")]
+    CurveMigrated(String),
 }
 
 impl IntoResponse for ApiError {
@@ -80,6 +87,16 @@ impl IntoResponse for ApiError {
                 Json(ErrorResponse { error: msg }),
             )
                 .into_response(),
+            ApiError::SchemaMismatch(msg) => (
+                StatusCode::BAD_GATEWAY,
+                Json(ErrorResponse { error: msg }),
+            )
+                .into_response(),
+            ApiError::CurveMigrated(msg) => (
+                StatusCode::CONFLICT,
+                Json(ErrorResponse { error: msg }),
+            )
+                .into_response(),
         }
     }
 }
@@ -1,8 +1,110 @@
-use chrono::Utc;
-use reqwest::Client;
-use serde_json::Value;
+use chrono::{Datelike, NaiveDate, Utc};
+use futures::stream::{self, StreamExt};
+use yahoo_finance_api::YahooConnector;
+use crate::helpers::options::{black_scholes_greeks, solve_implied_vol};
+use crate::services::yahoo::latest_close;
+use crate::sources::alpaca_data::ALPACA_OPTIONS_CLIENT;
 use crate::types::{OptionContract, OptionPrices, HighOpenInterestResult};
 
+/// Bound on concurrent per-ticker fallback requests in [`fetch_contracts_multi`], so a batch
+/// with many tickers missing from the combined response doesn't fire them all at once against
+/// [`ALPACA_OPTIONS_CLIENT`]'s rate limit.
+const FALLBACK_CONCURRENCY: usize = 4;
+
+/// Default days-to-expiration threshold at which the short-term/leap expiry cycle rolls to the
+/// next one, so the "front" pick doesn't ride down to 0 DTE before handing off.
+const DEFAULT_ROLL_WHEN_WITHIN_DAYS: i64 = 5;
+
+/// Padding in days either side of a computed standard expiration when building the
+/// `expiration_date_gte/lte` window sent to Alpaca -- wide enough to tolerate the contracts feed
+/// listing the date a day or two off calendar, tight enough to stay anchored to the one
+/// expiration traders actually use instead of a 60-day bucket.
+const EXPIRATION_WINDOW_PAD_DAYS: i64 = 3;
+
+/// Third Friday of `year`/`month`, the standard monthly (and January LEAP) options expiration.
+fn third_friday(year: i32, month: u32) -> NaiveDate {
+    let first_of_month = NaiveDate::from_ymd_opt(year, month, 1).expect("valid calendar month");
+    let days_to_first_friday =
+        (4 + 7 - first_of_month.weekday().num_days_from_monday() as i64) % 7;
+    first_of_month + chrono::Duration::days(days_to_first_friday + 14)
+}
+
+/// Next standard monthly expiration on or after `today`, rolling forward a month at a time once
+/// the current candidate's third Friday is within `roll_when_within_days` of `today` (or has
+/// already passed), so callers get a stable front-month pick rather than one that changes every
+/// single day as a sliding window drifts.
+fn next_monthly_expiration(today: NaiveDate, roll_when_within_days: i64) -> NaiveDate {
+    let (mut year, mut month) = (today.year(), today.month());
+    loop {
+        let expiry = third_friday(year, month);
+        if (expiry - today).num_days() >= roll_when_within_days {
+            return expiry;
+        }
+        (year, month) = if month == 12 { (year + 1, 1) } else { (year, month + 1) };
+    }
+}
+
+/// Next January LEAP expiration roughly a year out from `today`, rolling a year forward at a
+/// time once it's within `roll_when_within_days`, mirroring [`next_monthly_expiration`]'s
+/// rollover but on an annual cadence.
+fn next_leap_expiration(today: NaiveDate, roll_when_within_days: i64) -> NaiveDate {
+    let mut year = today.year() + 1;
+    loop {
+        let expiry = third_friday(year, 1);
+        if (expiry - today).num_days() >= roll_when_within_days {
+            return expiry;
+        }
+        year += 1;
+    }
+}
+
+/// `expiration_date_gte/lte` bounds tightly bracketing `expiry` (see
+/// [`EXPIRATION_WINDOW_PAD_DAYS`]), as opposed to the old crude `+1..+60`/`+365..+730` day-offset
+/// buckets that straddled arbitrary dates.
+fn expiration_window(expiry: NaiveDate) -> (String, String) {
+    let start = expiry - chrono::Duration::days(EXPIRATION_WINDOW_PAD_DAYS);
+    let end = expiry + chrono::Duration::days(EXPIRATION_WINDOW_PAD_DAYS);
+    (start.format("%Y-%m-%d").to_string(), end.format("%Y-%m-%d").to_string())
+}
+
+/// Solves implied vol and the full Greeks for `contract` from its mid premium (bid/ask
+/// average, falling back to last price) against a freshly-fetched spot price, mirroring the
+/// same pricing pipeline `routes/options.rs` runs for the recommendations feed. Leaves the
+/// contract untouched if the spot fetch, expiration parsing, or the IV solve fails -- a
+/// contract without Greeks is still useful, just unpriced.
+async fn annotate_with_greeks(contract: &mut OptionContract, yahoo: &YahooConnector, rf_annual: f64) {
+    let bid = contract.bid_price.unwrap_or(0.0);
+    let ask = contract.ask_price.unwrap_or(0.0);
+    let premium = if bid > 0.0 && ask > 0.0 {
+        (bid + ask) / 2.0
+    } else {
+        contract.last_price.unwrap_or(0.0)
+    };
+    if premium <= 0.0 {
+        return;
+    }
+
+    let Ok(expiration) = NaiveDate::parse_from_str(&contract.expiration_date, "%Y-%m-%d") else {
+        return;
+    };
+    let dte_days = (expiration - Utc::now().date_naive()).num_days();
+    if dte_days <= 0 {
+        return;
+    }
+    let t_years = dte_days as f64 / 365.0;
+
+    let Ok(spot) = latest_close(yahoo, &contract.underlying_symbol).await else {
+        return;
+    };
+    let is_call = contract.r#type.eq_ignore_ascii_case("call");
+
+    let Some(iv) = solve_implied_vol(spot, contract.strike_price, rf_annual, t_years, premium, is_call) else {
+        return;
+    };
+    contract.implied_volatility = Some(iv.abs());
+    contract.greeks = black_scholes_greeks(spot, contract.strike_price, rf_annual, iv.abs(), t_years, is_call);
+}
+
 /// Helper function to fetch current option prices for a specific contract
 async fn get_option_prices(contract: &OptionContract) -> Result<OptionPrices, String> {
     let normalized_symbol = if contract.symbol.contains("FB") {
@@ -20,25 +122,12 @@ async fn get_option_prices(contract: &OptionContract) -> Result<OptionPrices, St
         .map_err(|_| "ALPACA_API_SECRET_KEY/APCA_API_SECRET_KEY missing".to_string())?;
 
     let url = format!("https://api.alpaca.markets/v2/options/contracts/{}", normalized_symbol);
-    
-    let client = Client::new();
-    let response = client.get(&url)
-        .header("Apca-Api-Key-Id", key)
-        .header("Apca-Api-Secret-Key", secret)
-        .send()
+
+    let data = ALPACA_OPTIONS_CLIENT
+        .get_json(&url, &[("Apca-Api-Key-Id", &key), ("Apca-Api-Secret-Key", &secret)])
         .await
         .map_err(|e| format!("Error fetching option prices: {}", e))?;
 
-    if !response.status().is_success() {
-        if response.status().as_u16() == 429 {
-            return Err("Rate limit hit for option prices".to_string());
-        }
-        return Err(format!("Error fetching option prices: {} {}", response.status(), response.status().canonical_reason().unwrap_or("")));
-    }
-
-    let data: Value = response.json().await
-        .map_err(|e| format!("Error parsing option prices JSON: {}", e))?;
-
     // Check if we have valid price data
     let close_price = data.get("close_price")
         .and_then(|v| v.as_f64())
@@ -55,93 +144,187 @@ async fn get_option_prices(contract: &OptionContract) -> Result<OptionPrices, St
         .and_then(|v| v.as_str())
         .map(|s| s.to_string());
 
+    // Pull the real NBBO bid/ask, last trade price, IV, and Greeks from the snapshot
+    // endpoint, falling back to the close price (and no Greeks) for whichever fields it
+    // doesn't return rather than faking them as equal to the close.
+    let snapshot = fetch_option_snapshot(&normalized_symbol).await.ok();
+
     Ok(OptionPrices {
-        ask_price: close_price,
-        bid_price: close_price,
-        last_price: close_price,
-        implied_volatility: 0.0, // We don't have IV in the response
+        ask_price: snapshot.as_ref().and_then(|s| s.ask_price).unwrap_or(close_price),
+        bid_price: snapshot.as_ref().and_then(|s| s.bid_price).unwrap_or(close_price),
+        last_price: snapshot.as_ref().and_then(|s| s.last_price).unwrap_or(close_price),
+        implied_volatility: snapshot.as_ref().and_then(|s| s.implied_volatility).unwrap_or(0.0),
         open_interest,
         open_interest_date,
         close_price_date,
+        greeks: snapshot.and_then(|s| s.greeks),
     })
 }
 
-/// Fetch contracts for a specific expiration range
-async fn fetch_contracts(
+/// Real bid/ask/last/IV/Greeks parsed out of Alpaca's options snapshot quote, as opposed to the
+/// close-price placeholder [`get_option_prices`] used to report for all three.
+struct OptionSnapshot {
+    bid_price: Option<f64>,
+    ask_price: Option<f64>,
+    last_price: Option<f64>,
+    implied_volatility: Option<f64>,
+    greeks: Option<crate::helpers::options::Greeks>,
+}
+
+/// Fetches the latest NBBO quote, last trade, implied volatility, and Greeks for `symbol` from
+/// Alpaca's options snapshot endpoint (`/v1beta1/options/snapshots/{symbol}`), which is what
+/// actually carries live bid/ask and Greeks -- unlike `/v2/options/contracts/{symbol}`, which
+/// only has the prior session's close price.
+async fn fetch_option_snapshot(symbol: &str) -> Result<OptionSnapshot, String> {
+    let key = std::env::var("ALPACA_API_KEY_ID")
+        .or_else(|_| std::env::var("APCA_API_KEY_ID"))
+        .map_err(|_| "ALPACA_API_KEY_ID/APCA_API_KEY_ID missing".to_string())?;
+
+    let secret = std::env::var("ALPACA_API_SECRET_KEY")
+        .or_else(|_| std::env::var("APCA_API_SECRET_KEY"))
+        .map_err(|_| "ALPACA_API_SECRET_KEY/APCA_API_SECRET_KEY missing".to_string())?;
+
+    let url = format!("https://data.alpaca.markets/v1beta1/options/snapshots/{}", symbol);
+
+    let data = ALPACA_OPTIONS_CLIENT
+        .get_json(&url, &[("Apca-Api-Key-Id", &key), ("Apca-Api-Secret-Key", &secret)])
+        .await
+        .map_err(|e| format!("Error fetching option snapshot for {}: {}", symbol, e))?;
+
+    let snapshot = data.get("snapshots")
+        .and_then(|v| v.get(symbol))
+        .ok_or_else(|| format!("No snapshot found for {}", symbol))?;
+
+    let bid_price = snapshot.get("latestQuote").and_then(|q| q.get("bp")).and_then(|v| v.as_f64());
+    let ask_price = snapshot.get("latestQuote").and_then(|q| q.get("ap")).and_then(|v| v.as_f64());
+    let last_price = snapshot.get("latestTrade").and_then(|t| t.get("p")).and_then(|v| v.as_f64());
+    let implied_volatility = snapshot.get("impliedVolatility").and_then(|v| v.as_f64());
+
+    let greeks = snapshot.get("greeks").and_then(|g| {
+        Some(crate::helpers::options::Greeks {
+            delta: g.get("delta")?.as_f64()?,
+            gamma: g.get("gamma")?.as_f64()?,
+            theta: g.get("theta")?.as_f64()?,
+            vega: g.get("vega")?.as_f64()?,
+            rho: g.get("rho")?.as_f64()?,
+        })
+    });
+
+    Ok(OptionSnapshot {
+        bid_price,
+        ask_price,
+        last_price,
+        implied_volatility,
+        greeks,
+    })
+}
+
+/// Default cap on pages followed via `next_page_token` when no explicit `max_pages` is given,
+/// bounding worst-case latency for tickers with unusually deep chains.
+const DEFAULT_MAX_PAGES: u32 = 10;
+
+/// Fetches every page of `option_contracts` for `ticker`/`option_type` within the given
+/// expiration window, following Alpaca's `next_page_token` until it's absent or `max_pages`
+/// (default: [`DEFAULT_MAX_PAGES`]) is reached, so a deep chain isn't silently truncated to the
+/// first 100 contracts.
+async fn fetch_all_contract_pages(
     ticker: &str,
     option_type: &str,
     expiration_start: &str,
     expiration_end: &str,
-) -> Result<Option<OptionContract>, String> {
+    max_pages: Option<u32>,
+) -> Result<Vec<OptionContract>, String> {
     let normalized_ticker = if ticker == "FB" { "META" } else { ticker };
 
     let key = std::env::var("ALPACA_API_KEY_ID")
         .or_else(|_| std::env::var("APCA_API_KEY_ID"))
         .map_err(|_| "ALPACA_API_KEY_ID/APCA_API_KEY_ID missing".to_string())?;
-    
+
     let secret = std::env::var("ALPACA_API_SECRET_KEY")
         .or_else(|_| std::env::var("APCA_API_SECRET_KEY"))
         .map_err(|_| "ALPACA_API_SECRET_KEY/APCA_API_SECRET_KEY missing".to_string())?;
 
-    let url = format!(
-        "https://api.alpaca.markets/v2/options/contracts?underlying_symbol={}&status=active&expiration_date_gte={}&expiration_date_lte={}&type={}&limit=100",
-        normalized_ticker, expiration_start, expiration_end, option_type
-    );
+    let max_pages = max_pages.unwrap_or(DEFAULT_MAX_PAGES).max(1);
 
-    let client = Client::new();
-    let response = client.get(&url)
-        .header("Apca-Api-Key-Id", key)
-        .header("Apca-Api-Secret-Key", secret)
-        .send()
-        .await
-        .map_err(|e| format!("Network error fetching contracts for {}: {}", ticker, e))?;
-
-    if !response.status().is_success() {
-        if response.status().as_u16() == 422 {
-            let error_message = if normalized_ticker == "META" {
-                format!("Invalid ticker symbol: {} (Note: Meta's ticker changed from FB to META in June 2022)", ticker)
-            } else {
-                format!("Invalid ticker symbol: {}", ticker)
-            };
-            return Err(error_message);
-        }
-        if response.status().as_u16() == 429 {
-            return Err(format!("Rate limit hit for {}", ticker));
-        }
-        return Err(format!("Error fetching contracts for {}: {} {}", ticker, response.status(), response.status().canonical_reason().unwrap_or("")));
-    }
+    let mut parsed_contracts: Vec<OptionContract> = Vec::new();
+    let mut page_token: Option<String> = None;
 
-    let data: Value = response.json().await
-        .map_err(|e| format!("Error parsing contracts JSON: {}", e))?;
+    for _ in 0..max_pages {
+        let mut url = format!(
+            "https://api.alpaca.markets/v2/options/contracts?underlying_symbol={}&status=active&expiration_date_gte={}&expiration_date_lte={}&type={}&limit=100",
+            normalized_ticker, expiration_start, expiration_end, option_type
+        );
+        if let Some(token) = &page_token {
+            url.push_str(&format!("&page_token={}", token));
+        }
 
-    let contracts = data.get("option_contracts")
-        .and_then(|v| v.as_array())
-        .ok_or(format!("No {} contracts found for {}", option_type, ticker))?;
+        let data = ALPACA_OPTIONS_CLIENT
+            .get_json(&url, &[("Apca-Api-Key-Id", &key), ("Apca-Api-Secret-Key", &secret)])
+            .await
+            .map_err(|e| {
+                if normalized_ticker == "META" && e.contains("422") {
+                    format!("Invalid ticker symbol: {} (Note: Meta's ticker changed from FB to META in June 2022)", ticker)
+                } else {
+                    format!("Error fetching contracts for {}: {}", ticker, e)
+                }
+            })?;
 
-    if contracts.is_empty() {
-        return Ok(None);
-    }
+        if let Some(contracts) = data.get("option_contracts").and_then(|v| v.as_array()) {
+            for contract_value in contracts {
+                if let Ok(contract) = serde_json::from_value::<OptionContract>(contract_value.clone()) {
+                    parsed_contracts.push(contract);
+                }
+            }
+        }
 
-    // Parse contracts and sort by open interest
-    let mut parsed_contracts: Vec<OptionContract> = Vec::new();
-    for contract_value in contracts {
-        if let Ok(contract) = serde_json::from_value::<OptionContract>(contract_value.clone()) {
-            parsed_contracts.push(contract);
+        page_token = data.get("next_page_token").and_then(|v| v.as_str()).map(|s| s.to_string());
+        if page_token.is_none() {
+            break;
         }
     }
 
-    if parsed_contracts.is_empty() {
-        return Ok(None);
+    Ok(parsed_contracts)
+}
+
+/// Fetch contracts for a specific expiration range, paginating through the full chain via
+/// [`fetch_all_contract_pages`] before picking the single highest-open-interest contract.
+/// `max_pages` bounds how many `next_page_token` pages are followed (default:
+/// [`DEFAULT_MAX_PAGES`]), trading completeness for latency on very deep chains.
+async fn fetch_contracts_with_max_pages(
+    ticker: &str,
+    option_type: &str,
+    expiration_start: &str,
+    expiration_end: &str,
+    yahoo: &YahooConnector,
+    rf_annual: f64,
+    max_pages: Option<u32>,
+) -> Result<Option<OptionContract>, String> {
+    let parsed_contracts =
+        fetch_all_contract_pages(ticker, option_type, expiration_start, expiration_end, max_pages).await?;
+    Ok(pick_and_price_best(parsed_contracts, yahoo, rf_annual).await)
+}
+
+/// Picks the highest-open-interest contract out of `contracts`, fetches its current
+/// bid/ask/last, and annotates it with implied vol and Greeks -- the shared tail end of both
+/// [`fetch_contracts_with_max_pages`] (single ticker) and [`fetch_contracts_multi`] (batch).
+async fn pick_and_price_best(
+    mut contracts: Vec<OptionContract>,
+    yahoo: &YahooConnector,
+    rf_annual: f64,
+) -> Option<OptionContract> {
+    if contracts.is_empty() {
+        return None;
     }
 
-    // Sort by open interest (descending) and get the highest one
-    parsed_contracts.sort_by(|a, b| {
+    // Sort by open interest (descending) and get the highest one across the whole chain.
+    contracts.sort_by(|a, b| {
         let a_oi = a.open_interest.unwrap_or(0);
         let b_oi = b.open_interest.unwrap_or(0);
         b_oi.cmp(&a_oi)
     });
 
-    let mut best_contract = parsed_contracts[0].clone();
+    let mut best_contract = contracts[0].clone();
+    let mut has_real_greeks = false;
 
     // Fetch current prices for the contract
     match get_option_prices(&best_contract).await {
@@ -153,31 +336,184 @@ async fn fetch_contracts(
             best_contract.implied_volatility = Some(prices.implied_volatility);
             best_contract.close_price = Some(prices.last_price);
             best_contract.close_price_date = prices.close_price_date;
+            if prices.greeks.is_some() {
+                best_contract.greeks = prices.greeks;
+                has_real_greeks = true;
+            }
         }
         Err(e) => {
             eprintln!("Failed to get price data for contract {}: {}", best_contract.symbol, e);
         }
     }
 
-    Ok(Some(best_contract))
+    // Only fall back to the analytic Black-Scholes solve when the snapshot didn't give us real
+    // Greeks -- prefer the market's own quote over a model of it.
+    if !has_real_greeks {
+        annotate_with_greeks(&mut best_contract, yahoo, rf_annual).await;
+    }
+
+    Some(best_contract)
+}
+
+/// Fetches contracts for several underlyings in one request via Alpaca's comma-joined
+/// `underlying_symbols` parameter, grouping the combined `option_contracts` array back by each
+/// contract's own `underlying_symbol` field (so FB-normalized-to-META results still map back to
+/// the caller's original ticker). Paginates the same way as [`fetch_all_contract_pages`]. Tickers
+/// missing entirely from the combined response (e.g. an invalid symbol silently dropped by
+/// Alpaca) simply have no entry in the returned map -- callers fall back to a per-ticker request
+/// for those.
+async fn fetch_all_contract_pages_multi(
+    tickers: &[String],
+    option_type: &str,
+    expiration_start: &str,
+    expiration_end: &str,
+    max_pages: Option<u32>,
+) -> Result<std::collections::HashMap<String, Vec<OptionContract>>, String> {
+    if tickers.is_empty() {
+        return Ok(std::collections::HashMap::new());
+    }
+
+    let key = std::env::var("ALPACA_API_KEY_ID")
+        .or_else(|_| std::env::var("APCA_API_KEY_ID"))
+        .map_err(|_| "ALPACA_API_KEY_ID/APCA_API_KEY_ID missing".to_string())?;
+
+    let secret = std::env::var("ALPACA_API_SECRET_KEY")
+        .or_else(|_| std::env::var("APCA_API_SECRET_KEY"))
+        .map_err(|_| "ALPACA_API_SECRET_KEY/APCA_API_SECRET_KEY missing".to_string())?;
+
+    // Normalized (META-mapped) symbol -> original ticker, so results group back under the
+    // symbol the caller asked for.
+    let normalized_to_original: std::collections::HashMap<String, String> = tickers
+        .iter()
+        .map(|t| (if t == "FB" { "META".to_string() } else { t.clone() }, t.clone()))
+        .collect();
+    let symbols_param = normalized_to_original.keys().cloned().collect::<Vec<_>>().join(",");
+
+    let max_pages = max_pages.unwrap_or(DEFAULT_MAX_PAGES).max(1);
+
+    let mut by_ticker: std::collections::HashMap<String, Vec<OptionContract>> = std::collections::HashMap::new();
+    let mut page_token: Option<String> = None;
+
+    for _ in 0..max_pages {
+        let mut url = format!(
+            "https://api.alpaca.markets/v2/options/contracts?underlying_symbols={}&status=active&expiration_date_gte={}&expiration_date_lte={}&type={}&limit=100",
+            symbols_param, expiration_start, expiration_end, option_type
+        );
+        if let Some(token) = &page_token {
+            url.push_str(&format!("&page_token={}", token));
+        }
+
+        let data = ALPACA_OPTIONS_CLIENT
+            .get_json(&url, &[("Apca-Api-Key-Id", &key), ("Apca-Api-Secret-Key", &secret)])
+            .await
+            .map_err(|e| format!("Error fetching batched contracts: {}", e))?;
+
+        if let Some(contracts) = data.get("option_contracts").and_then(|v| v.as_array()) {
+            for contract_value in contracts {
+                if let Ok(contract) = serde_json::from_value::<OptionContract>(contract_value.clone()) {
+                    let original = normalized_to_original
+                        .get(&contract.underlying_symbol)
+                        .cloned()
+                        .unwrap_or_else(|| contract.underlying_symbol.clone());
+                    by_ticker.entry(original).or_default().push(contract);
+                }
+            }
+        }
+
+        page_token = data.get("next_page_token").and_then(|v| v.as_str()).map(|s| s.to_string());
+        if page_token.is_none() {
+            break;
+        }
+    }
+
+    Ok(by_ticker)
+}
+
+/// Fetches the single highest-open-interest contract per ticker for a shared expiration window
+/// in one (paginated) batched request via [`fetch_all_contract_pages_multi`], falling back to a
+/// per-ticker [`fetch_contracts_with_max_pages`] call only for tickers absent from the combined
+/// response.
+async fn fetch_contracts_multi(
+    tickers: &[String],
+    option_type: &str,
+    expiration_start: &str,
+    expiration_end: &str,
+    yahoo: &YahooConnector,
+    rf_annual: f64,
+    max_pages: Option<u32>,
+) -> std::collections::HashMap<String, Option<OptionContract>> {
+    let mut by_ticker = match fetch_all_contract_pages_multi(tickers, option_type, expiration_start, expiration_end, max_pages).await {
+        Ok(map) => map,
+        Err(e) => {
+            eprintln!("Error fetching batched contracts for {:?}: {}", tickers, e);
+            std::collections::HashMap::new()
+        }
+    };
+
+    let mut results = std::collections::HashMap::new();
+    let mut missing: Vec<&String> = Vec::new();
+    for ticker in tickers {
+        if let Some(contracts) = by_ticker.remove(ticker) {
+            results.insert(ticker.clone(), pick_and_price_best(contracts, yahoo, rf_annual).await);
+        } else {
+            missing.push(ticker);
+        }
+    }
+
+    // Tickers absent from the combined response fall back to individual requests, bounded to
+    // FALLBACK_CONCURRENCY in flight at once so a batch with many misses doesn't blow past
+    // ALPACA_OPTIONS_CLIENT's rate limit all at once.
+    let fallbacks: Vec<(String, Option<OptionContract>)> = stream::iter(missing)
+        .map(|ticker| async move {
+            let fallback = fetch_contracts_with_max_pages(ticker, option_type, expiration_start, expiration_end, yahoo, rf_annual, max_pages)
+                .await
+                .unwrap_or_else(|e| {
+                    eprintln!("Error fetching fallback contracts for {}: {}", ticker, e);
+                    None
+                });
+            (ticker.clone(), fallback)
+        })
+        .buffer_unordered(FALLBACK_CONCURRENCY)
+        .collect()
+        .await;
+
+    for (ticker, fallback) in fallbacks {
+        results.insert(ticker, fallback);
+    }
+    results
 }
 
-/// Fetch high open-interest contracts for a given ticker
+/// Fetch high open-interest contracts for a given ticker, annotated with implied vol and
+/// Greeks solved against `yahoo`'s spot price at risk-free rate `rf_annual` (default 0.03 to
+/// match `routes/options.rs`'s recommendations feed). `max_pages` bounds how many
+/// `next_page_token` pages [`fetch_contracts_with_max_pages`] follows per leg (default: [`DEFAULT_MAX_PAGES`]),
+/// so the short-term/leap picks are the true highest-OI contract across the whole expiration
+/// window rather than just its first 100 results. The short-term leg targets the next standard
+/// monthly expiration (third Friday) and the leap leg the next January LEAP roughly a year out
+/// (see [`next_monthly_expiration`]/[`next_leap_expiration`]), each rolling forward to the next
+/// cycle once within `roll_when_within_days` of today (default: [`DEFAULT_ROLL_WHEN_WITHIN_DAYS`]),
+/// rather than a sliding day-offset bucket that can miss the expirations traders actually use.
 pub async fn get_high_open_interest_contracts(
     ticker: &str,
     option_type: Option<&str>,
+    yahoo: &YahooConnector,
+    rf_annual: f64,
+    max_pages: Option<u32>,
+    roll_when_within_days: Option<u32>,
 ) -> HighOpenInterestResult {
     let option_type = option_type.unwrap_or("call");
-    
-    // Calculate date ranges
-    let now = Utc::now();
-    let short_term_start = (now + chrono::Duration::days(1)).format("%Y-%m-%d").to_string();
-    let short_term_end = (now + chrono::Duration::days(60)).format("%Y-%m-%d").to_string();
-    let leap_start = (now + chrono::Duration::days(365)).format("%Y-%m-%d").to_string();
-    let leap_end = (now + chrono::Duration::days(730)).format("%Y-%m-%d").to_string();
+    let roll_when_within_days = roll_when_within_days
+        .map(i64::from)
+        .unwrap_or(DEFAULT_ROLL_WHEN_WITHIN_DAYS);
+
+    let today = Utc::now().date_naive();
+    let (short_term_start, short_term_end) =
+        expiration_window(next_monthly_expiration(today, roll_when_within_days));
+    let (leap_start, leap_end) = expiration_window(next_leap_expiration(today, roll_when_within_days));
 
     // Fetch short-term contracts
-    let short_term_result = fetch_contracts(ticker, option_type, &short_term_start, &short_term_end).await;
+    let short_term_result =
+        fetch_contracts_with_max_pages(ticker, option_type, &short_term_start, &short_term_end, yahoo, rf_annual, max_pages).await;
     let short_term = match short_term_result {
         Ok(contract) => contract,
         Err(e) => {
@@ -187,7 +523,8 @@ pub async fn get_high_open_interest_contracts(
     };
 
     // Fetch leap contracts
-    let leap_result = fetch_contracts(ticker, option_type, &leap_start, &leap_end).await;
+    let leap_result =
+        fetch_contracts_with_max_pages(ticker, option_type, &leap_start, &leap_end, yahoo, rf_annual, max_pages).await;
     let leap = match leap_result {
         Ok(contract) => contract,
         Err(e) => {
@@ -203,20 +540,42 @@ pub async fn get_high_open_interest_contracts(
     }
 }
 
-/// Fetch high open-interest contracts for multiple tickers
+/// Fetch high open-interest contracts for multiple tickers in two batched requests (one per
+/// expiration leg) via [`fetch_contracts_multi`]'s `underlying_symbols` grouping, instead of
+/// looping one ticker at a time behind a fixed delay. See [`get_high_open_interest_contracts`]
+/// for how `roll_when_within_days` picks the short-term/leap expiration windows.
 pub async fn get_high_open_interest_contracts_batch(
     tickers: &[String],
     option_type: Option<&str>,
+    yahoo: &YahooConnector,
+    rf_annual: f64,
+    max_pages: Option<u32>,
+    roll_when_within_days: Option<u32>,
 ) -> Vec<(String, HighOpenInterestResult)> {
-    let mut results = Vec::new();
-    
-    for ticker in tickers {
-        let result = get_high_open_interest_contracts(ticker, option_type).await;
-        results.push((ticker.clone(), result));
-        
-        // Add a small delay to avoid rate limiting
-        tokio::time::sleep(tokio::time::Duration::from_millis(100)).await;
-    }
-    
-    results
+    let option_type = option_type.unwrap_or("call");
+    let roll_when_within_days = roll_when_within_days
+        .map(i64::from)
+        .unwrap_or(DEFAULT_ROLL_WHEN_WITHIN_DAYS);
+
+    let today = Utc::now().date_naive();
+    let (short_term_start, short_term_end) =
+        expiration_window(next_monthly_expiration(today, roll_when_within_days));
+    let (leap_start, leap_end) = expiration_window(next_leap_expiration(today, roll_when_within_days));
+
+    let mut short_term_by_ticker =
+        fetch_contracts_multi(tickers, option_type, &short_term_start, &short_term_end, yahoo, rf_annual, max_pages).await;
+    let mut leap_by_ticker =
+        fetch_contracts_multi(tickers, option_type, &leap_start, &leap_end, yahoo, rf_annual, max_pages).await;
+
+    tickers
+        .iter()
+        .map(|ticker| {
+            let result = HighOpenInterestResult {
+                short_term: short_term_by_ticker.remove(ticker).flatten(),
+                leap: leap_by_ticker.remove(ticker).flatten(),
+                error: None,
+            };
+            (ticker.clone(), result)
+        })
+        .collect()
 }
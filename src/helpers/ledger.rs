@@ -0,0 +1,73 @@
+use crate::sources::alpaca_data::AlpacaActivity;
+
+/// Standard OCC option contract size: one contract controls 100 shares of the underlying.
+const CONTRACT_MULTIPLIER: f64 = 100.0;
+
+/// Root/underlying symbol from either an OCC option symbol (e.g. `AAPL240119C00150000` ->
+/// `AAPL`) or a bare equity symbol (returned unchanged, since it has no expiration/strike
+/// suffix to strip). OCC symbols are the root ticker immediately followed by a 6-digit
+/// `YYMMDD` expiration, so the root ends at the first digit.
+fn underlying_symbol(symbol: &str) -> &str {
+    match symbol.find(|c: char| c.is_ascii_digit()) {
+        Some(idx) if idx > 0 => &symbol[..idx],
+        _ => symbol,
+    }
+}
+
+/// True if `symbol` looks like an OCC option symbol (root + 6-digit date + C/P + 8-digit
+/// strike) rather than a bare equity ticker, so the ×100 contract multiplier only applies to
+/// option fills.
+fn is_option_symbol(symbol: &str) -> bool {
+    let Some(idx) = symbol.find(|c: char| c.is_ascii_digit()) else { return false };
+    let rest = &symbol[idx..];
+    rest.len() == 15 && rest.chars().nth(6).is_some_and(|c| c == 'C' || c == 'P')
+}
+
+/// Render one [`AlpacaActivity`] fill as a double-entry Ledger-CLI transaction: a posting
+/// against `account_template` (with `{underlying}` substituted for the fill's root symbol)
+/// sized in contract units, balanced against `cash_account` for the signed premium. `side`
+/// "buy"/"buy_to_open"/"buy_to_close" debits the position account and credits cash;
+/// "sell"/"sell_to_open"/"sell_to_close" does the reverse. `commission` is a flat per-fill fee
+/// added to the cash leg (Alpaca's FILL activities don't report one separately).
+fn render_transaction(activity: &AlpacaActivity, account_template: &str, cash_account: &str, commission: f64) -> Option<String> {
+    let qty: f64 = activity.qty.parse().ok()?;
+    let price: f64 = activity.price.parse().ok()?;
+    let underlying = underlying_symbol(&activity.symbol);
+    let multiplier = if is_option_symbol(&activity.symbol) { CONTRACT_MULTIPLIER } else { 1.0 };
+    let premium = qty * price * multiplier;
+
+    let is_buy = activity.side.starts_with("buy");
+    let position_qty = if is_buy { qty } else { -qty };
+    let cash_delta = if is_buy { -(premium + commission) } else { premium - commission };
+
+    let date = activity.transaction_time.split('T').next().unwrap_or(&activity.transaction_time);
+    let position_account = account_template.replace("{underlying}", underlying);
+
+    Some(format!(
+        "{date} {symbol}\n    {position_account}  {position_qty:+.0} {symbol} @ {price:.2} USD\n    {cash_account}  {cash_delta:+.2} USD\n",
+        date = date,
+        symbol = activity.symbol,
+        position_account = position_account,
+        position_qty = position_qty,
+        price = price,
+        cash_account = cash_account,
+        cash_delta = cash_delta,
+    ))
+}
+
+/// Build a Ledger-CLI plaintext export from a list of fills, one double-entry transaction per
+/// fill in the order given (callers should sort/filter by date beforehand -- this just
+/// formats). Fills whose `qty`/`price` don't parse as numbers are skipped rather than failing
+/// the whole export.
+pub fn build_option_ledger(
+    activities: &[AlpacaActivity],
+    account_template: &str,
+    cash_account: &str,
+    commission: f64,
+) -> String {
+    activities
+        .iter()
+        .filter_map(|activity| render_transaction(activity, account_template, cash_account, commission))
+        .collect::<Vec<_>>()
+        .join("\n")
+}
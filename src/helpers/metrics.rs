@@ -1,5 +1,7 @@
 use serde::Serialize;
 
+use crate::services::yahoo::Candle;
+
 #[derive(Debug, Clone, Serialize)]
 pub struct MetricsResult {
     pub n_periods: usize,
@@ -13,6 +15,12 @@ pub struct MetricsResult {
     pub calmar: f64,
     pub kelly_fraction: f64,
     pub composite_score: f64,
+    /// Average True Range over the candle series. `0.0` when computed from
+    /// [`compute_metrics_from_returns`] (close-only prices can't express intraday range).
+    pub atr: f64,
+    /// Mean volume over the candle series. `0.0` when computed from
+    /// [`compute_metrics_from_returns`] (close-only prices carry no volume).
+    pub avg_volume: f64,
 }
 
 #[derive(Debug, Clone)]
@@ -20,6 +28,16 @@ pub struct CompositeWeights {
     pub sharpe: f64,
     pub sortino: f64,
     pub calmar: f64,
+    /// Weight for CAGR's direct contribution to the composite (on top of its indirect effect via
+    /// `calmar`). Defaults to 0.0 so existing callers that only set sharpe/sortino/calmar see an
+    /// unchanged composite.
+    pub cagr: f64,
+    /// Penalty weight for volatility: subtracted from the composite, so higher volatility lowers
+    /// the score. Defaults to 0.0 (no penalty, matching prior behavior).
+    pub volatility: f64,
+    /// Penalty weight for max drawdown: subtracted from the composite. Defaults to 0.0 (no
+    /// penalty, matching prior behavior).
+    pub max_drawdown: f64,
 }
 
 impl Default for CompositeWeights {
@@ -28,6 +46,9 @@ impl Default for CompositeWeights {
             sharpe: 0.4,
             sortino: 0.4,
             calmar: 0.2,
+            cagr: 0.0,
+            volatility: 0.0,
+            max_drawdown: 0.0,
         }
     }
 }
@@ -162,7 +183,9 @@ pub fn compute_metrics_from_returns(
     kelly = kelly.clamp(0.0, 1.0);
 
     let w = weights.unwrap_or_default();
-    let mut composite = w.sharpe * sharpe + w.sortino * sortino + w.calmar * calmar;
+    let mut composite = w.sharpe * sharpe + w.sortino * sortino + w.calmar * calmar + w.cagr * cagr
+        - w.volatility * vol
+        - w.max_drawdown * max_dd;
     if !composite.is_finite() {
         composite = 0.0;
     }
@@ -179,7 +202,72 @@ pub fn compute_metrics_from_returns(
         calmar,
         kelly_fraction: kelly,
         composite_score: composite,
+        atr: 0.0,
+        avg_volume: 0.0,
+    }
+}
+
+/// True range per candle (Wilder's definition): the widest of the current bar's own high-low
+/// range and its gap from the prior close, so a gap-open day still registers real volatility
+/// even though that bar's own high-low range alone would understate it. The first candle has no
+/// prior close to gap from, so it's skipped rather than falling back to its own high-low range,
+/// which would silently understate that one bar relative to the rest of the series.
+fn true_range(candles: &[Candle]) -> Vec<f64> {
+    candles
+        .windows(2)
+        .map(|w| {
+            let (prev, cur) = (&w[0], &w[1]);
+            (cur.high - cur.low)
+                .max((cur.high - prev.close).abs())
+                .max((cur.low - prev.close).abs())
+        })
+        .collect()
+}
+
+/// Average True Range over the full candle series.
+pub fn average_true_range(candles: &[Candle]) -> f64 {
+    mean(&true_range(candles))
+}
+
+/// Mean volume over the full candle series.
+pub fn average_volume(candles: &[Candle]) -> f64 {
+    let volumes: Vec<f64> = candles.iter().map(|c| c.volume).collect();
+    mean(&volumes)
+}
+
+/// Candle-aware entry point: identical to [`compute_metrics_from_returns`] for every
+/// return-based field (derived from the same close series), but also populates `atr` and
+/// `avg_volume` so callers with full OHLCV data (not just closes) get true-range and
+/// volume-based statistics alongside the existing risk/return metrics.
+pub fn compute_metrics_from_candles(
+    candles: &[Candle],
+    rf_annual: f64,
+    target_return_annual: f64,
+    periods_per_year: usize,
+    weights: Option<CompositeWeights>,
+) -> MetricsResult {
+    let closes: Vec<f64> = candles.iter().map(|c| c.close).collect();
+    let returns = compute_returns_from_prices(&closes);
+    let mut result = compute_metrics_from_returns(&returns, rf_annual, target_return_annual, periods_per_year, weights);
+    result.atr = average_true_range(candles);
+    result.avg_volume = average_volume(candles);
+    result
+}
+
+/// Percentile rank (0-100) of each value in `values` relative to the whole set -- the fraction of
+/// values at or below it. Lets callers see relative standing across a requested symbol set instead
+/// of only the absolute metric value.
+pub fn percentile_ranks(values: &[f64]) -> Vec<f64> {
+    let n = values.len();
+    if n <= 1 {
+        return vec![100.0; n];
     }
+    values.iter()
+        .map(|&v| {
+            let count_le = values.iter().filter(|&&other| other <= v).count();
+            (count_le as f64 / n as f64) * 100.0
+        })
+        .collect()
 }
 
 
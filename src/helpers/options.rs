@@ -8,4 +8,201 @@ pub fn black_scholes_delta(spot: f64, strike: f64, r: f64, sigma: f64, t_years:
     if is_call { Some(nd1) } else { Some(nd1 - 1.0) }
 }
 
+/// Full Black-Scholes Greeks for a European option: delta, gamma, theta, vega, rho.
+/// `vega` and `rho` are per 1.00 (100 percentage points) of vol/rate, not per 1%.
+#[derive(Debug, Clone, Copy, serde::Serialize, serde::Deserialize, utoipa::ToSchema)]
+pub struct Greeks {
+    pub delta: f64,
+    pub gamma: f64,
+    pub theta: f64,
+    pub vega: f64,
+    pub rho: f64,
+}
+
+/// Computes the full set of analytic Black-Scholes Greeks for a European option with
+/// spot `spot`, strike `strike`, risk-free rate `r`, volatility `sigma`, and time to
+/// expiry `t_years`. Returns `None` for non-positive spot/strike/sigma/time, the same
+/// guard `black_scholes_delta` uses to avoid NaNs from `ln`/`sqrt`/division by zero.
+pub fn black_scholes_greeks(spot: f64, strike: f64, r: f64, sigma: f64, t_years: f64, is_call: bool) -> Option<Greeks> {
+    if spot <= 0.0 || strike <= 0.0 || sigma <= 0.0 || t_years <= 0.0 {
+        return None;
+    }
+
+    let sqrt_t = t_years.sqrt();
+    let d1 = ((spot / strike).ln() + (r + 0.5 * sigma * sigma) * t_years) / (sigma * sqrt_t);
+    let d2 = d1 - sigma * sqrt_t;
+
+    let norm = statrs::distribution::Normal::new(0.0, 1.0).ok()?;
+    let nd1 = statrs::distribution::ContinuousCDF::cdf(&norm, d1);
+    let nd2 = statrs::distribution::ContinuousCDF::cdf(&norm, d2);
+    let n_neg_d2 = statrs::distribution::ContinuousCDF::cdf(&norm, -d2);
+    let pdf_d1 = statrs::distribution::Continuous::pdf(&norm, d1);
+
+    let delta = if is_call { nd1 } else { nd1 - 1.0 };
+    let gamma = pdf_d1 / (spot * sigma * sqrt_t);
+    let vega = spot * pdf_d1 * sqrt_t;
+    let discounted_strike = strike * (-r * t_years).exp();
+
+    let theta = if is_call {
+        -(spot * pdf_d1 * sigma) / (2.0 * sqrt_t) - r * discounted_strike * nd2
+    } else {
+        -(spot * pdf_d1 * sigma) / (2.0 * sqrt_t) + r * discounted_strike * n_neg_d2
+    };
+    let rho = if is_call {
+        t_years * discounted_strike * nd2
+    } else {
+        -t_years * discounted_strike * n_neg_d2
+    };
+
+    Some(Greeks { delta, gamma, theta, vega, rho })
+}
+
+/// Analytic Black-Scholes theoretical price for a European option with spot `spot`, strike
+/// `strike`, risk-free rate `r`, volatility `sigma`, and time to expiry `t_years`, via
+/// put-call parity for puts. Returns `None` for non-positive spot/strike/sigma/time.
+pub fn black_scholes_price(spot: f64, strike: f64, r: f64, sigma: f64, t_years: f64, is_call: bool) -> Option<f64> {
+    if spot <= 0.0 || strike <= 0.0 || sigma <= 0.0 || t_years <= 0.0 {
+        return None;
+    }
+    let sqrt_t = t_years.sqrt();
+    let d1 = ((spot / strike).ln() + (r + 0.5 * sigma * sigma) * t_years) / (sigma * sqrt_t);
+    let d2 = d1 - sigma * sqrt_t;
+    let norm = statrs::distribution::Normal::new(0.0, 1.0).ok()?;
+    let nd1 = statrs::distribution::ContinuousCDF::cdf(&norm, d1);
+    let nd2 = statrs::distribution::ContinuousCDF::cdf(&norm, d2);
+    let discounted_strike = strike * (-r * t_years).exp();
+    Some(if is_call {
+        spot * nd1 - discounted_strike * nd2
+    } else {
+        discounted_strike * (1.0 - nd2) - spot * (1.0 - nd1)
+    })
+}
+
+fn black_scholes_vega(spot: f64, strike: f64, r: f64, sigma: f64, t_years: f64) -> Option<f64> {
+    if spot <= 0.0 || strike <= 0.0 || sigma <= 0.0 || t_years <= 0.0 {
+        return None;
+    }
+    let sqrt_t = t_years.sqrt();
+    let d1 = ((spot / strike).ln() + (r + 0.5 * sigma * sigma) * t_years) / (sigma * sqrt_t);
+    let norm = statrs::distribution::Normal::new(0.0, 1.0).ok()?;
+    let pdf_d1 = statrs::distribution::Continuous::pdf(&norm, d1);
+    Some(spot * pdf_d1 * sqrt_t)
+}
+
+/// Back-solves implied volatility from an observed mid premium via Newton-Raphson on the
+/// Black-Scholes price, seeding from the Brenner-Subrahmanyam approximation and falling
+/// back to bisection if vega underflows near deep ITM/OTM. Stops once the price error is
+/// below 1e-6 or after 50 Newton iterations (then 50 more bisection steps). Returns `None`
+/// if spot/strike/time/premium are non-positive or no bracketing root is found.
+pub fn solve_implied_vol(spot: f64, strike: f64, r: f64, t_years: f64, market_mid: f64, is_call: bool) -> Option<f64> {
+    if spot <= 0.0 || strike <= 0.0 || t_years <= 0.0 || market_mid <= 0.0 {
+        return None;
+    }
+
+    let seed = (2.0 * std::f64::consts::PI / t_years).sqrt() * (market_mid / spot);
+    let mut sigma = if seed.is_finite() && seed > 0.0 { seed } else { 0.3 };
+    sigma = sigma.clamp(1e-4, 5.0);
+
+    for _ in 0..50 {
+        let price = black_scholes_price(spot, strike, r, sigma, t_years, is_call)?;
+        let diff = price - market_mid;
+        if diff.abs() < 1e-6 {
+            return Some(sigma);
+        }
+        let vega = match black_scholes_vega(spot, strike, r, sigma, t_years) {
+            Some(v) if v.abs() > 1e-8 => v,
+            _ => break,
+        };
+        let next = sigma - diff / vega;
+        if !next.is_finite() {
+            break;
+        }
+        sigma = next.clamp(1e-4, 5.0);
+    }
+
+    let f = |s: f64| -> f64 { black_scholes_price(spot, strike, r, s, t_years, is_call).unwrap_or(f64::NAN) - market_mid };
+    let mut lo = 1e-4_f64;
+    let mut hi = 5.0_f64;
+    let mut f_lo = f(lo);
+    let f_hi = f(hi);
+    if !f_lo.is_finite() || !f_hi.is_finite() || f_lo.signum() == f_hi.signum() {
+        return None;
+    }
+    for _ in 0..50 {
+        let mid = 0.5 * (lo + hi);
+        let f_mid = f(mid);
+        if !f_mid.is_finite() || f_mid.abs() < 1e-6 {
+            return Some(mid);
+        }
+        if f_mid.signum() == f_lo.signum() {
+            lo = mid;
+            f_lo = f_mid;
+        } else {
+            hi = mid;
+        }
+    }
+    Some(0.5 * (lo + hi))
+}
+
+/// American-style option price and delta from a Cox-Ross-Rubinstein binomial tree.
+#[derive(Debug, Clone, Copy, serde::Serialize)]
+pub struct BinomialResult {
+    pub price: f64,
+    pub delta: f64,
+}
+
+/// Prices an American option with an `steps`-step CRR binomial tree (spot `spot`, strike
+/// `strike`, risk-free rate `r`, volatility `sigma`, time to expiry `t_years`), allowing
+/// early exercise at every node. Delta is derived from the two step-1 nodes. Returns `None`
+/// for non-positive spot/strike/sigma/time or zero steps, the same guard `black_scholes_delta`
+/// uses to avoid NaNs.
+pub fn crr_price_and_delta(
+    spot: f64,
+    strike: f64,
+    r: f64,
+    sigma: f64,
+    t_years: f64,
+    is_call: bool,
+    steps: usize,
+) -> Option<BinomialResult> {
+    if spot <= 0.0 || strike <= 0.0 || sigma <= 0.0 || t_years <= 0.0 || steps == 0 {
+        return None;
+    }
+
+    let n = steps;
+    let dt = t_years / n as f64;
+    let u = (sigma * dt.sqrt()).exp();
+    let d = 1.0 / u;
+    let disc = (-r * dt).exp();
+    let p = ((r * dt).exp() - d) / (u - d);
+    let multiplier = if is_call { 1.0 } else { -1.0 };
+
+    let mut values: Vec<f64> = (0..=n)
+        .map(|j| {
+            let price_at_node = spot * u.powi((n - j) as i32) * d.powi(j as i32);
+            (multiplier * (price_at_node - strike)).max(0.0)
+        })
+        .collect();
+
+    let mut step1: Option<(f64, f64)> = None;
+    for level in (0..n).rev() {
+        if level == 0 {
+            step1 = Some((values[0], values[1]));
+        }
+        let mut next = Vec::with_capacity(level + 1);
+        for j in 0..=level {
+            let continuation = disc * (p * values[j] + (1.0 - p) * values[j + 1]);
+            let price_at_node = spot * u.powi((level - j) as i32) * d.powi(j as i32);
+            let intrinsic = (multiplier * (price_at_node - strike)).max(0.0);
+            next.push(continuation.max(intrinsic));
+        }
+        values = next;
+    }
+
+    let price = values[0];
+    let (v_up, v_down) = step1.unwrap_or((price, price));
+    let delta = (v_up - v_down) / (spot * u - spot * d);
+    Some(BinomialResult { price, delta })
+}
+
 
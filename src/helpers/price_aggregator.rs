@@ -0,0 +1,369 @@
+use std::time::Duration;
+
+use async_trait::async_trait;
+use futures::future::join_all;
+use serde::Serialize;
+use serde_json::Value;
+use utoipa::ToSchema;
+
+use crate::errors::ApiError;
+use crate::sources::coingecko_data::CoinGeckoClient;
+
+/// A single exchange/aggregator adapter polled for a spot price. Mirrors the
+/// [`crate::helpers::trending_cryptos::TrendingSource`] pattern: each upstream implements this
+/// behind a common interface so the aggregator doesn't hard-code which providers exist, and
+/// callers can inject a custom source set (or a mock) for tests.
+#[async_trait]
+pub trait Exchange: Send + Sync {
+    fn name(&self) -> &'static str;
+
+    async fn fetch_price(&self, base: &str, quote: &str) -> Result<f64, ApiError>;
+}
+
+/// Binance's spot ticker (`/api/v3/ticker/price`). Binance has no raw-USD market, so a `quote`
+/// of `"USD"` is treated as `"USDT"`.
+pub struct BinanceExchange {
+    client: reqwest::Client,
+}
+
+impl BinanceExchange {
+    pub fn new() -> Self {
+        Self {
+            client: reqwest::Client::builder().timeout(Duration::from_secs(5)).build().unwrap(),
+        }
+    }
+}
+
+impl Default for BinanceExchange {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[async_trait]
+impl Exchange for BinanceExchange {
+    fn name(&self) -> &'static str {
+        "binance"
+    }
+
+    async fn fetch_price(&self, base: &str, quote: &str) -> Result<f64, ApiError> {
+        let quote = if quote.eq_ignore_ascii_case("usd") { "USDT" } else { quote };
+        let symbol = format!("{}{}", base.to_uppercase(), quote.to_uppercase());
+
+        let response = self
+            .client
+            .get("https://api.binance.com/api/v3/ticker/price")
+            .query(&[("symbol", symbol.as_str())])
+            .send()
+            .await
+            .map_err(|e| ApiError::Upstream(format!("binance: {e}")))?;
+
+        if !response.status().is_success() {
+            return Err(ApiError::Upstream(format!("binance returned status {}", response.status())));
+        }
+
+        let body: Value = response.json().await.map_err(|e| ApiError::Upstream(format!("binance: {e}")))?;
+        body.get("price")
+            .and_then(|p| p.as_str())
+            .and_then(|p| p.parse::<f64>().ok())
+            .ok_or_else(|| ApiError::Upstream("binance: missing or invalid price field".to_string()))
+    }
+}
+
+/// Coinbase's spot price (`/v2/prices/{base}-{quote}/spot`).
+pub struct CoinbaseExchange {
+    client: reqwest::Client,
+}
+
+impl CoinbaseExchange {
+    pub fn new() -> Self {
+        Self {
+            client: reqwest::Client::builder().timeout(Duration::from_secs(5)).build().unwrap(),
+        }
+    }
+}
+
+impl Default for CoinbaseExchange {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[async_trait]
+impl Exchange for CoinbaseExchange {
+    fn name(&self) -> &'static str {
+        "coinbase"
+    }
+
+    async fn fetch_price(&self, base: &str, quote: &str) -> Result<f64, ApiError> {
+        let url = format!("https://api.coinbase.com/v2/prices/{}-{}/spot", base.to_uppercase(), quote.to_uppercase());
+
+        let response = self.client.get(&url).send().await.map_err(|e| ApiError::Upstream(format!("coinbase: {e}")))?;
+
+        if !response.status().is_success() {
+            return Err(ApiError::Upstream(format!("coinbase returned status {}", response.status())));
+        }
+
+        let body: Value = response.json().await.map_err(|e| ApiError::Upstream(format!("coinbase: {e}")))?;
+        body.get("data")
+            .and_then(|d| d.get("amount"))
+            .and_then(|a| a.as_str())
+            .and_then(|a| a.parse::<f64>().ok())
+            .ok_or_else(|| ApiError::Upstream("coinbase: missing or invalid amount field".to_string()))
+    }
+}
+
+/// Gemini's public ticker (`/v1/pubticker/{symbol}`).
+pub struct GeminiExchange {
+    client: reqwest::Client,
+}
+
+impl GeminiExchange {
+    pub fn new() -> Self {
+        Self {
+            client: reqwest::Client::builder().timeout(Duration::from_secs(5)).build().unwrap(),
+        }
+    }
+}
+
+impl Default for GeminiExchange {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[async_trait]
+impl Exchange for GeminiExchange {
+    fn name(&self) -> &'static str {
+        "gemini"
+    }
+
+    async fn fetch_price(&self, base: &str, quote: &str) -> Result<f64, ApiError> {
+        let symbol = format!("{}{}", base.to_lowercase(), quote.to_lowercase());
+        let url = format!("https://api.gemini.com/v1/pubticker/{symbol}");
+
+        let response = self.client.get(&url).send().await.map_err(|e| ApiError::Upstream(format!("gemini: {e}")))?;
+
+        if !response.status().is_success() {
+            return Err(ApiError::Upstream(format!("gemini returned status {}", response.status())));
+        }
+
+        let body: Value = response.json().await.map_err(|e| ApiError::Upstream(format!("gemini: {e}")))?;
+        body.get("last")
+            .and_then(|p| p.as_str())
+            .and_then(|p| p.parse::<f64>().ok())
+            .ok_or_else(|| ApiError::Upstream("gemini: missing or invalid last field".to_string()))
+    }
+}
+
+/// KuCoin's level-1 order book ticker (`/api/1/market/orderbook/level1`), read for its `price`
+/// field. KuCoin has no raw-USD market, so a `quote` of `"USD"` is treated as `"USDT"`.
+pub struct KuCoinExchange {
+    client: reqwest::Client,
+}
+
+impl KuCoinExchange {
+    pub fn new() -> Self {
+        Self {
+            client: reqwest::Client::builder().timeout(Duration::from_secs(5)).build().unwrap(),
+        }
+    }
+}
+
+impl Default for KuCoinExchange {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[async_trait]
+impl Exchange for KuCoinExchange {
+    fn name(&self) -> &'static str {
+        "kucoin"
+    }
+
+    async fn fetch_price(&self, base: &str, quote: &str) -> Result<f64, ApiError> {
+        let quote = if quote.eq_ignore_ascii_case("usd") { "USDT" } else { quote };
+        let symbol = format!("{}-{}", base.to_uppercase(), quote.to_uppercase());
+
+        let response = self
+            .client
+            .get("https://api.kucoin.com/api/1/market/orderbook/level1")
+            .query(&[("symbol", symbol.as_str())])
+            .send()
+            .await
+            .map_err(|e| ApiError::Upstream(format!("kucoin: {e}")))?;
+
+        if !response.status().is_success() {
+            return Err(ApiError::Upstream(format!("kucoin returned status {}", response.status())));
+        }
+
+        let body: Value = response.json().await.map_err(|e| ApiError::Upstream(format!("kucoin: {e}")))?;
+        body.get("data")
+            .and_then(|d| d.get("price"))
+            .and_then(|p| p.as_str())
+            .and_then(|p| p.parse::<f64>().ok())
+            .ok_or_else(|| ApiError::Upstream("kucoin: missing or invalid price field".to_string()))
+    }
+}
+
+/// Symbol -> CoinGecko coin ID for the handful of assets this aggregator supports; extend as
+/// needed the same way [`crate::sources::helius_data::default_pyth_feed_map`] does for Pyth
+/// feeds.
+fn coingecko_id_for_symbol(symbol: &str) -> Option<&'static str> {
+    match symbol.to_uppercase().as_str() {
+        "BTC" => Some("bitcoin"),
+        "ETH" => Some("ethereum"),
+        "SOL" => Some("solana"),
+        "USDC" => Some("usd-coin"),
+        "USDT" => Some("tether"),
+        _ => None,
+    }
+}
+
+/// CoinGecko's simple-price endpoint, via [`CoinGeckoClient::get_simple_price`].
+pub struct CoinGeckoExchange {
+    client: CoinGeckoClient,
+}
+
+impl CoinGeckoExchange {
+    pub fn new() -> Self {
+        Self { client: CoinGeckoClient::new() }
+    }
+}
+
+impl Default for CoinGeckoExchange {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[async_trait]
+impl Exchange for CoinGeckoExchange {
+    fn name(&self) -> &'static str {
+        "coingecko"
+    }
+
+    async fn fetch_price(&self, base: &str, quote: &str) -> Result<f64, ApiError> {
+        let id = coingecko_id_for_symbol(base)
+            .ok_or_else(|| ApiError::Upstream(format!("coingecko: no known coin id for symbol '{base}'")))?;
+        let vs_currency = quote.to_lowercase();
+
+        let body = self
+            .client
+            .get_simple_price(&[id.to_string()], &[vs_currency.clone()], false)
+            .await
+            .map_err(|e| ApiError::Upstream(format!("coingecko: {e}")))?;
+
+        body.get(id)
+            .and_then(|coin| coin.get(&vs_currency))
+            .and_then(|p| p.as_f64())
+            .ok_or_else(|| ApiError::Upstream("coingecko: missing or invalid price field".to_string()))
+    }
+}
+
+/// One exchange's contribution to a [`PriceConsensus`].
+#[derive(Debug, Clone, Serialize, ToSchema)]
+pub struct PriceSourceQuote {
+    pub source: &'static str,
+    pub price: f64,
+}
+
+/// Median-consensus spot price across several exchanges, from [`get_price_consensus`].
+#[derive(Debug, Clone, Serialize, ToSchema)]
+pub struct PriceConsensus {
+    pub base: String,
+    pub quote: String,
+    pub median_price: f64,
+    /// `true` if fewer sources responded than the requested minimum and `median_price` is the
+    /// trusted source's value rather than an actual median.
+    pub used_trusted_fallback: bool,
+    pub sources: Vec<PriceSourceQuote>,
+}
+
+fn default_exchanges() -> Vec<Box<dyn Exchange>> {
+    vec![
+        Box::new(BinanceExchange::new()),
+        Box::new(CoinbaseExchange::new()),
+        Box::new(GeminiExchange::new()),
+        Box::new(KuCoinExchange::new()),
+        Box::new(CoinGeckoExchange::new()),
+    ]
+}
+
+/// Runs every exchange concurrently and discards failures and non-positive/non-finite prices,
+/// mirroring [`crate::helpers::trending_cryptos::fetch_all_trending`]'s
+/// degrade-gracefully-per-source approach.
+async fn fetch_all_prices(exchanges: &[Box<dyn Exchange>], base: &str, quote: &str) -> Vec<PriceSourceQuote> {
+    let futures = exchanges.iter().map(|exchange| async move {
+        match exchange.fetch_price(base, quote).await {
+            Ok(price) if price.is_finite() && price > 0.0 => Some(PriceSourceQuote { source: exchange.name(), price }),
+            Ok(price) => {
+                tracing::error!("price source {} returned an unusable price {} for {}/{}", exchange.name(), price, base, quote);
+                None
+            }
+            Err(e) => {
+                tracing::error!("price source {} failed for {}/{}: {}", exchange.name(), base, quote, e);
+                None
+            }
+        }
+    });
+
+    join_all(futures).await.into_iter().flatten().collect()
+}
+
+fn median(prices: &[f64]) -> f64 {
+    let mut sorted = prices.to_vec();
+    sorted.sort_by(|a, b| a.partial_cmp(b).unwrap_or(std::cmp::Ordering::Equal));
+    let mid = sorted.len() / 2;
+    if sorted.len() % 2 == 0 {
+        (sorted[mid - 1] + sorted[mid]) / 2.0
+    } else {
+        sorted[mid]
+    }
+}
+
+/// Median-consensus spot price for `base`/`quote` across [`default_exchanges`]: queries all of
+/// them concurrently and discards errored or unusable results. If fewer than `min_sources`
+/// responded, falls back to `trusted_source`'s value (matched by [`Exchange::name`]) rather
+/// than failing outright; this still fails if `trusted_source` is unset or didn't respond.
+pub async fn get_price_consensus(
+    base: &str,
+    quote: &str,
+    min_sources: usize,
+    trusted_source: Option<&str>,
+) -> Result<PriceConsensus, ApiError> {
+    let exchanges = default_exchanges();
+    let sources = fetch_all_prices(&exchanges, base, quote).await;
+
+    if sources.is_empty() {
+        return Err(ApiError::Upstream(format!("no price source responded for {base}/{quote}")));
+    }
+
+    let (median_price, used_trusted_fallback) = if sources.len() < min_sources {
+        let trusted = trusted_source.ok_or_else(|| {
+            ApiError::Upstream(format!(
+                "only {} of {min_sources} required sources responded for {base}/{quote} and no trusted source was configured",
+                sources.len()
+            ))
+        })?;
+        let trusted_quote = sources.iter().find(|s| s.source == trusted).ok_or_else(|| {
+            ApiError::Upstream(format!(
+                "only {} of {min_sources} required sources responded for {base}/{quote} and trusted source '{trusted}' was not among them",
+                sources.len()
+            ))
+        })?;
+        (trusted_quote.price, true)
+    } else {
+        let prices: Vec<f64> = sources.iter().map(|s| s.price).collect();
+        (median(&prices), false)
+    };
+
+    Ok(PriceConsensus {
+        base: base.to_string(),
+        quote: quote.to_string(),
+        median_price,
+        used_trusted_fallback,
+        sources,
+    })
+}
@@ -0,0 +1,240 @@
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::Arc;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+use tokio::sync::Mutex;
+
+type FetchFuture = Pin<Box<dyn Future<Output = Result<serde_json::Value, String>> + Send>>;
+type FetchFn = Arc<dyn Fn() -> FetchFuture + Send + Sync>;
+
+/// Outcome of one named fetcher within a [`ResilientFetch::run`], kept even on failure so a
+/// caller can show partial results instead of losing the whole batch to one bad source.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FetchOutcome {
+    pub name: String,
+    pub data: Option<serde_json::Value>,
+    pub error: Option<String>,
+}
+
+// Shared cache keyed by the caller-supplied cache key, generalized from `helpers::news`'s
+// single-purpose `NewsCache`.
+struct CacheEntry {
+    outcomes: HashMap<String, FetchOutcome>,
+    fetched_at: u64,
+    // Set while a background refresh for this key is in flight, so a burst of stale reads
+    // triggers at most one refresh instead of one per caller.
+    refreshing: bool,
+}
+
+lazy_static::lazy_static! {
+    static ref FETCH_CACHE: Arc<Mutex<HashMap<String, CacheEntry>>> = Arc::new(Mutex::new(HashMap::new()));
+}
+
+fn now_secs() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap()
+        .as_secs()
+}
+
+/// Generic parallel fetch-with-timeout-retry-and-cache, extracted from the ad hoc
+/// caching/retry/per-service-timeout logic `helpers::news` used to hard-code for
+/// Finviz/Reddit/Alpaca. Register any number of named async fetchers with [`Self::fetcher`];
+/// [`Self::run`] executes them all in parallel, isolating a slow or failing source into its own
+/// [`FetchOutcome`] rather than letting it sink the whole batch, and caches the combined result
+/// under the configured cache key.
+pub struct ResilientFetch {
+    fetchers: Vec<(String, FetchFn)>,
+    per_fetcher_timeout: Duration,
+    max_retries: u32,
+    cache_key: Option<String>,
+    cache_ttl: Duration,
+    hard_expire_after: Duration,
+}
+
+impl Default for ResilientFetch {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl ResilientFetch {
+    pub fn new() -> Self {
+        Self {
+            fetchers: Vec::new(),
+            per_fetcher_timeout: Duration::from_secs(10),
+            max_retries: 1,
+            cache_key: None,
+            cache_ttl: Duration::from_secs(300),
+            hard_expire_after: Duration::from_secs(300 * 4),
+        }
+    }
+
+    /// Register a named fetcher. `f` is called fresh on every attempt (including retries), so it
+    /// must be callable more than once -- unlike a bare future, which can only be polled to
+    /// completion once.
+    pub fn fetcher<F, Fut>(mut self, name: impl Into<String>, f: F) -> Self
+    where
+        F: Fn() -> Fut + Send + Sync + 'static,
+        Fut: Future<Output = Result<serde_json::Value, String>> + Send + 'static,
+    {
+        self.fetchers
+            .push((name.into(), Arc::new(move || Box::pin(f()) as FetchFuture)));
+        self
+    }
+
+    /// Per-fetcher timeout (default: 10s). Applies individually, so one slow source doesn't
+    /// delay the others.
+    pub fn timeout(mut self, per_fetcher_timeout: Duration) -> Self {
+        self.per_fetcher_timeout = per_fetcher_timeout;
+        self
+    }
+
+    /// Max attempts per fetcher, including the first (default: 1, i.e. no retry). Failed
+    /// attempts back off exponentially, matching `helpers::news::get_news_with_retry`'s
+    /// `100ms * 2^attempt` schedule.
+    pub fn max_retries(mut self, max_retries: u32) -> Self {
+        self.max_retries = max_retries.max(1);
+        self
+    }
+
+    /// Cache the combined result under `key` for `ttl`, matching `helpers::news`'s 5-minute
+    /// default TTL in spirit (callers pick their own). Past `ttl` the entry is stale but still
+    /// served -- see [`Self::run`] -- until it passes `hard_expire_after` (default: `ttl * 4`,
+    /// override with [`Self::stale_while_revalidate`]).
+    pub fn cache(mut self, key: impl Into<String>, ttl: Duration) -> Self {
+        self.cache_key = Some(key.into());
+        self.cache_ttl = ttl;
+        self.hard_expire_after = ttl * 4;
+        self
+    }
+
+    /// Override how long a stale cache entry keeps being served (with a background refresh
+    /// spawned in the background) before `run` falls back to blocking on a fresh fetch. Only
+    /// takes effect when [`Self::cache`] is also configured.
+    pub fn stale_while_revalidate(mut self, hard_expire_after: Duration) -> Self {
+        self.hard_expire_after = hard_expire_after;
+        self
+    }
+
+    async fn fetch_one(
+        name: &str,
+        f: &FetchFn,
+        per_fetcher_timeout: Duration,
+        max_retries: u32,
+    ) -> FetchOutcome {
+        let mut attempt = 0u32;
+        loop {
+            // Each attempt is recorded individually in `crate::metrics`, keyed by source name
+            // and outcome (ok/timeout/error), so a flaky source's timeout rate is visible even
+            // when a retry ultimately succeeds.
+            let result = crate::metrics::observe(name, per_fetcher_timeout, f()).await;
+
+            match result {
+                Ok(data) => {
+                    return FetchOutcome {
+                        name: name.to_string(),
+                        data: Some(data),
+                        error: None,
+                    };
+                }
+                Err(e) => {
+                    attempt += 1;
+                    if attempt >= max_retries {
+                        return FetchOutcome {
+                            name: name.to_string(),
+                            data: None,
+                            error: Some(e),
+                        };
+                    }
+                    let delay = Duration::from_millis(100 * 2_u64.pow(attempt - 1));
+                    tokio::time::sleep(delay).await;
+                }
+            }
+        }
+    }
+
+    /// Run every registered fetcher in parallel, returning one [`FetchOutcome`] per fetcher
+    /// keyed by name. When a cache key is configured: a fresh entry (younger than `cache_ttl`)
+    /// is returned straight away; a stale-but-not-hard-expired entry is still returned
+    /// immediately, with a refresh spawned in the background (at most one per key at a time) so
+    /// the next caller gets a fresh result without anyone blocking on it; only a missing or
+    /// hard-expired entry blocks the caller on a live fetch.
+    pub async fn run(&self) -> HashMap<String, FetchOutcome> {
+        if let Some(key) = &self.cache_key {
+            let mut cache = FETCH_CACHE.lock().await;
+            if let Some(entry) = cache.get_mut(key) {
+                let age = now_secs().saturating_sub(entry.fetched_at);
+                if age < self.cache_ttl.as_secs() {
+                    return entry.outcomes.clone();
+                }
+                if age < self.hard_expire_after.as_secs() {
+                    let stale = entry.outcomes.clone();
+                    if !entry.refreshing {
+                        entry.refreshing = true;
+                        self.spawn_refresh(key.clone());
+                    }
+                    return stale;
+                }
+                // Past the hard expiry: fall through and block on a live fetch below.
+            }
+        }
+
+        let outcomes = self.fetch_all().await;
+
+        if let Some(key) = &self.cache_key {
+            let mut cache = FETCH_CACHE.lock().await;
+            cache.insert(
+                key.clone(),
+                CacheEntry {
+                    outcomes: outcomes.clone(),
+                    fetched_at: now_secs(),
+                    refreshing: false,
+                },
+            );
+        }
+
+        outcomes
+    }
+
+    async fn fetch_all(&self) -> HashMap<String, FetchOutcome> {
+        let results =
+            futures::future::join_all(self.fetchers.iter().map(|(name, f)| {
+                Self::fetch_one(name, f, self.per_fetcher_timeout, self.max_retries)
+            }))
+            .await;
+
+        results.into_iter().map(|o| (o.name.clone(), o)).collect()
+    }
+
+    /// Single-flight background refresh for a stale cache entry: fetches fresh outcomes and
+    /// writes them back under `key`, clearing `refreshing` so a later stale read can trigger
+    /// another refresh once this one's result has aged out again.
+    fn spawn_refresh(&self, key: String) {
+        let fetchers = self.fetchers.clone();
+        let per_fetcher_timeout = self.per_fetcher_timeout;
+        let max_retries = self.max_retries;
+        tokio::spawn(async move {
+            let results = futures::future::join_all(
+                fetchers
+                    .iter()
+                    .map(|(name, f)| Self::fetch_one(name, f, per_fetcher_timeout, max_retries)),
+            )
+            .await;
+            let outcomes: HashMap<String, FetchOutcome> =
+                results.into_iter().map(|o| (o.name.clone(), o)).collect();
+
+            let mut cache = FETCH_CACHE.lock().await;
+            cache.insert(
+                key,
+                CacheEntry {
+                    outcomes,
+                    fetched_at: now_secs(),
+                    refreshing: false,
+                },
+            );
+        });
+    }
+}
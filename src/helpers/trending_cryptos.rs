@@ -1,74 +1,164 @@
-use crate::sources::kraken_data::get_trending_crypto_pairs;
+use std::collections::HashMap;
+
+use async_trait::async_trait;
+use futures::future::join_all;
+
+use crate::errors::ApiError;
 use crate::sources::coingecko_data::get_trending_cryptos;
+use crate::sources::kraken_data::get_trending_crypto_pairs;
+use crate::utils::normalize_ticker_symbol;
 
-/// Get trending cryptocurrencies from Kraken by volume
-pub async fn get_trending_cryptos_kraken(limit: usize) -> Vec<String> {
-    match get_trending_crypto_pairs(limit).await {
-        Ok(items) => items.into_iter().map(|item| item.symbol).collect(),
-        Err(e) => {
-            tracing::error!("Failed to get trending cryptos from Kraken: {}", e);
-            vec![]
-        }
+/// Reciprocal Rank Fusion constant: the standard RRF default, controlling how quickly a
+/// source's contribution decays with rank position.
+const RRF_K: f64 = 60.0;
+
+/// A source of trending-crypto symbols, ranked best-first. Mirrors the
+/// [`crate::services::rates::LatestRate`] pattern: each upstream feed (exchange volume,
+/// aggregator trending score, scrape, ...) implements this behind a common interface so the
+/// aggregator doesn't hard-code which providers exist, and callers can inject a custom source
+/// set (or a mock) for tests.
+#[async_trait]
+pub trait TrendingSource: Send + Sync {
+    fn name(&self) -> &'static str;
+
+    async fn fetch(&self, limit: usize) -> Result<Vec<String>, ApiError>;
+}
+
+/// Trending cryptocurrencies from Kraken by volume.
+pub struct KrakenTrending;
+
+#[async_trait]
+impl TrendingSource for KrakenTrending {
+    fn name(&self) -> &'static str {
+        "kraken"
+    }
+
+    async fn fetch(&self, limit: usize) -> Result<Vec<String>, ApiError> {
+        let data_source = crate::sources::kraken_data::KrakenDataSource::new_async()
+            .await
+            .map_err(|e| ApiError::Upstream(format!("kraken trending: {e}")))?;
+        get_trending_crypto_pairs(&data_source, limit)
+            .await
+            .map(|items| items.into_iter().map(|item| item.symbol).collect())
+            .map_err(|e| ApiError::Upstream(format!("kraken trending: {e}")))
+    }
+}
+
+/// Trending cryptocurrencies from CoinGecko's trending endpoint.
+pub struct CoinGeckoTrending;
+
+#[async_trait]
+impl TrendingSource for CoinGeckoTrending {
+    fn name(&self) -> &'static str {
+        "coingecko"
+    }
+
+    async fn fetch(&self, limit: usize) -> Result<Vec<String>, ApiError> {
+        let mut result = get_trending_cryptos()
+            .await
+            .map_err(|e| ApiError::Upstream(format!("coingecko trending: {e}")))?;
+        result.truncate(limit);
+        Ok(result)
+    }
+}
+
+/// Trending cryptocurrencies from Finviz. Not yet implemented upstream.
+pub struct FinvizTrending;
+
+#[async_trait]
+impl TrendingSource for FinvizTrending {
+    fn name(&self) -> &'static str {
+        "finviz"
+    }
+
+    async fn fetch(&self, _limit: usize) -> Result<Vec<String>, ApiError> {
+        // TODO: Implement Finviz crypto trending
+        Ok(vec![])
     }
 }
 
-/// Get trending cryptos from finviz
-pub async fn get_trending_cryptos_finviz() -> Vec<String> {
-    // TODO: Implement Finviz crypto trending
-    vec![]
+/// Trending cryptocurrencies from Yahoo. Not yet implemented upstream.
+pub struct YahooTrending;
+
+#[async_trait]
+impl TrendingSource for YahooTrending {
+    fn name(&self) -> &'static str {
+        "yahoo"
+    }
+
+    async fn fetch(&self, _limit: usize) -> Result<Vec<String>, ApiError> {
+        // TODO: Implement Yahoo crypto trending
+        Ok(vec![])
+    }
 }
 
-/// Get trending cryptos from yahoo
-pub async fn get_trending_cryptos_yahoo() -> Vec<String> {
-    // TODO: Implement Yahoo crypto trending
-    vec![]
+fn default_sources() -> Vec<Box<dyn TrendingSource>> {
+    vec![
+        Box::new(KrakenTrending),
+        Box::new(FinvizTrending),
+        Box::new(YahooTrending),
+        Box::new(CoinGeckoTrending),
+    ]
 }
 
-/// Get trending cryptos from CoinGecko
-pub async fn get_trending_cryptos_coingecko(limit: usize) -> Vec<String> {
-    match get_trending_cryptos().await {
-        Ok(cryptos) => {
-            let mut result = cryptos;
-            result.truncate(limit);
-            result
+/// Runs every source concurrently, logs and skips any that error instead of silently swallowing
+/// them, and keeps each source's own ranked result list (rather than flattening into a set) so
+/// downstream aggregation can weigh rank, not just membership.
+pub async fn fetch_all_trending(
+    sources: &[Box<dyn TrendingSource>],
+    limit: usize,
+) -> Vec<(&'static str, Vec<String>)> {
+    let futures = sources.iter().map(|source| async move {
+        match source.fetch(limit).await {
+            Ok(symbols) => Some((source.name(), symbols)),
+            Err(e) => {
+                tracing::error!("trending source {} failed: {}", source.name(), e);
+                None
+            }
         }
-        Err(e) => {
-            tracing::error!("Failed to get trending cryptos from CoinGecko: {}", e);
-            vec![]
+    });
+
+    join_all(futures).await.into_iter().flatten().collect()
+}
+
+/// CoinGecko's trending endpoint is itself a curated "what's trending" signal, whereas Kraken's
+/// is raw volume ranking -- weigh it higher so a coin CoinGecko calls out doesn't get buried by
+/// volume-driven noise from the exchange feeds.
+fn default_source_weights() -> HashMap<&'static str, f64> {
+    HashMap::from([("coingecko", 1.5)])
+}
+
+/// Fuses each source's own ranked list into one consensus ranking via Reciprocal Rank Fusion: a
+/// symbol at zero-based rank `r` in a source contributes `weight / (RRF_K + r)`, contributions
+/// are summed across every source that returned the symbol, and the result is sorted by
+/// descending total score. Symbols are deduplicated by [`normalize_ticker_symbol`] so "BTC" from
+/// Kraken and "btc" from CoinGecko fuse into a single entry. `weights` defaults missing sources
+/// to a multiplier of `1.0`.
+pub fn fuse_trending_rankings(
+    per_source: &[(&'static str, Vec<String>)],
+    weights: &HashMap<&'static str, f64>,
+    limit: usize,
+) -> Vec<String> {
+    let mut scores: HashMap<String, f64> = HashMap::new();
+
+    for (source, symbols) in per_source {
+        let weight = weights.get(source).copied().unwrap_or(1.0);
+        for (rank, symbol) in symbols.iter().enumerate() {
+            let key = normalize_ticker_symbol(symbol);
+            *scores.entry(key).or_insert(0.0) += weight / (RRF_K + rank as f64);
         }
     }
+
+    let mut ranked: Vec<(String, f64)> = scores.into_iter().collect();
+    ranked.sort_by(|a, b| b.1.partial_cmp(&a.1).unwrap_or(std::cmp::Ordering::Equal));
+    ranked.truncate(limit);
+    ranked.into_iter().map(|(symbol, _)| symbol).collect()
 }
 
-/// Get aggregated trending cryptocurrencies from multiple sources
+/// Get aggregated trending cryptocurrencies from multiple sources, consensus-ranked via
+/// Reciprocal Rank Fusion rather than an unordered set union.
 pub async fn get_trending_cryptos_aggregated(limit: usize) -> Vec<String> {
-    let mut all_cryptos = std::collections::HashSet::new();
-    
-    // Get from Kraken
-    let kraken_cryptos = get_trending_cryptos_kraken(limit).await;
-    for crypto in kraken_cryptos {
-        all_cryptos.insert(crypto);
-    }
-    
-    // Get from Finviz
-    let finviz_cryptos = get_trending_cryptos_finviz().await;
-    for crypto in finviz_cryptos {
-        all_cryptos.insert(crypto);
-    }
-    
-    // Get from Yahoo
-    let yahoo_cryptos = get_trending_cryptos_yahoo().await;
-    for crypto in yahoo_cryptos {
-        all_cryptos.insert(crypto);
-    }
-    
-    // Get from CoinGecko
-    let coingecko_cryptos = get_trending_cryptos_coingecko(limit).await;
-    for crypto in coingecko_cryptos {
-        all_cryptos.insert(crypto);
-    }
-    
-    // Convert back to vector and limit
-    let mut result: Vec<String> = all_cryptos.into_iter().collect();
-    result.truncate(limit);
-    result
-}
\ No newline at end of file
+    let sources = default_sources();
+    let per_source = fetch_all_trending(&sources, limit).await;
+    fuse_trending_rankings(&per_source, &default_source_weights(), limit)
+}
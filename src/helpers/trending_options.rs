@@ -1,29 +1,56 @@
+use std::time::Duration;
+use chrono::{NaiveDate, Utc};
 use futures::future::join_all;
 use serde_json::Value;
+use yahoo_finance_api::YahooConnector;
 use crate::helpers::metrics::{compute_metrics_from_returns, CompositeWeights};
 use crate::helpers::high_open_interest::get_high_open_interest_contracts;
+use crate::helpers::options::black_scholes_price;
 use crate::services::yahoo::{fetch_prices_for_symbol, latest_close};
 use crate::sources;
 use crate::state::AppState;
 use crate::types::OptionContract;
 
+/// How long a [`get_trending_options_analysis`] result is reused before recomputing.
+const TRENDING_OPTIONS_CACHE_TTL: Duration = Duration::from_secs(10);
+
+/// Cache key the scheduler's `yahoo_trending_warm` job pre-warms on a 10-minute interval (see
+/// `main.rs`); falls back to a live Yahoo fetch on a cold cache so this never blocks on the
+/// schedule being in sync with the first request.
+pub const YAHOO_TRENDING_SYMBOLS_CACHE_KEY: &str = "yahoo_trending_symbols";
+const YAHOO_TRENDING_SYMBOLS_TTL: Duration = Duration::from_secs(600);
+
+/// Years to expiry from `contract.expiration_date`, the same `%Y-%m-%d`/365-day convention
+/// [`crate::helpers::high_open_interest::annotate_with_greeks`] uses. `None` if the date
+/// doesn't parse or has already passed.
+fn time_to_expiry_years(contract: &OptionContract) -> Option<f64> {
+    let expiration = NaiveDate::parse_from_str(&contract.expiration_date, "%Y-%m-%d").ok()?;
+    let dte_days = (expiration - Utc::now().date_naive()).num_days();
+    if dte_days <= 0 {
+        return None;
+    }
+    Some(dte_days as f64 / 365.0)
+}
+
 /// Get trending stocks from multiple sources and deduplicate
-pub async fn get_trending_tickers() -> Vec<String> {
+pub async fn get_trending_tickers(cache: &crate::cache::MemoryCache) -> Vec<String> {
     let mut all_tickers = Vec::new();
-    
+
     // Get trending stocks from finviz
     if let Ok(finviz_tickers) = sources::finviz_data::fetch_finviz_symbols("TopGainers", "MarketCap", "Performance", 20).await {
         all_tickers.extend(finviz_tickers);
     }
-    
-    // Get trending stocks from yahoo
-    if let Ok(yahoo_tickers) = sources::yahoo_data::yahoo_trending("US", 20).await {
-        all_tickers.extend(yahoo_tickers);
-    }
-    
+
+    // Get trending stocks from yahoo, pre-warmed by the scheduler's `yahoo_trending_warm` job
+    let cached_yahoo = cache.get_or_compute(YAHOO_TRENDING_SYMBOLS_CACHE_KEY, YAHOO_TRENDING_SYMBOLS_TTL, || async {
+        serde_json::json!(sources::yahoo_data::yahoo_trending("US", 20).await.unwrap_or_default())
+    }).await;
+    let yahoo_tickers: Vec<String> = serde_json::from_value(cached_yahoo).unwrap_or_default();
+    all_tickers.extend(yahoo_tickers);
+
     // Get trending stocks from reddit
     let reddit_tickers = sources::reddit_data::get_reddit_trending_stocks().await;
-    all_tickers.extend(reddit_tickers);
+    all_tickers.extend(reddit_tickers.into_iter().map(|(symbol, _mentions)| symbol));
     
     // Deduplicate while preserving order
     let mut seen = std::collections::HashSet::new();
@@ -90,9 +117,11 @@ pub async fn analyze_ticker_options(
     symbol: &str,
     underlying_metrics: &Value,
     option_type: Option<&str>,
+    yahoo: &YahooConnector,
+    rf_annual: f64,
 ) -> Option<Value> {
     // Get high open interest contracts
-    let hoi_result = get_high_open_interest_contracts(symbol, option_type).await;
+    let hoi_result = get_high_open_interest_contracts(symbol, option_type, yahoo, rf_annual, None, None).await;
     
     let spot_price = underlying_metrics.get("spot_price")?.as_f64()?;
     let composite_score = underlying_metrics.get("metrics")?.get("composite_score")?.as_f64()?;
@@ -106,10 +135,10 @@ pub async fn analyze_ticker_options(
             "contract_type": "short_term",
             "contract": contract,
             "option_score": option_score,
-            "undervalued_indicators": calculate_undervalued_indicators(&contract, spot_price, composite_score)
+            "undervalued_indicators": calculate_undervalued_indicators(&contract, spot_price, composite_score, rf_annual)
         }));
     }
-    
+
     // Analyze LEAP contracts
     if let Some(contract) = hoi_result.leap {
         let option_score = calculate_option_score(&contract, spot_price, composite_score);
@@ -117,7 +146,7 @@ pub async fn analyze_ticker_options(
             "contract_type": "leap",
             "contract": contract,
             "option_score": option_score,
-            "undervalued_indicators": calculate_undervalued_indicators(&contract, spot_price, composite_score)
+            "undervalued_indicators": calculate_undervalued_indicators(&contract, spot_price, composite_score, rf_annual)
         }));
     }
     
@@ -135,37 +164,56 @@ fn calculate_option_score(contract: &OptionContract, spot_price: f64, underlying
     if premium <= 0.0 {
         return 0.0;
     }
-    
-    let delta = contract.implied_volatility.unwrap_or(0.0); // Using IV as proxy for delta
+
+    // True delta from the Greeks `annotate_with_greeks` already solved for this contract,
+    // rather than abusing implied volatility as a stand-in.
+    let delta = contract.greeks.map(|g| g.delta).unwrap_or(0.0);
     let _leverage = (delta.abs() * spot_price) / premium;
-    
+
     // Base score from underlying
     let base_score = underlying_score;
-    
+
+    let dte_days = time_to_expiry_years(contract).map(|t| t * 365.0).unwrap_or(30.0);
+
     // Option-specific adjustments
-    let option_score = base_score * delta * (spot_price / premium) / (1.0 + 30.0 / 30.0); // Assuming 30 DTE for short-term
-    
+    let option_score = base_score * delta * (spot_price / premium) / (1.0 + dte_days / 30.0);
+
     option_score
 }
 
 /// Calculate undervalued indicators for an option contract
-fn calculate_undervalued_indicators(contract: &OptionContract, _spot_price: f64, underlying_score: f64) -> Value {
+fn calculate_undervalued_indicators(contract: &OptionContract, spot_price: f64, underlying_score: f64, rf_annual: f64) -> Value {
     let premium = contract.last_price.unwrap_or(0.0);
     let bid = contract.bid_price.unwrap_or(0.0);
     let ask = contract.ask_price.unwrap_or(0.0);
     let open_interest = contract.open_interest.unwrap_or(0);
-    
+
     let mid_price = if bid > 0.0 && ask > 0.0 { (bid + ask) / 2.0 } else { premium };
     let spread = if ask > 0.0 && bid > 0.0 { ask - bid } else { 0.0 };
     let spread_pct = if mid_price > 0.0 { spread / mid_price } else { f64::INFINITY };
-    
+
     // Calculate various undervalued indicators
     let liquidity_score = if open_interest > 1000 { 1.0 } else if open_interest > 500 { 0.7 } else if open_interest > 100 { 0.4 } else { 0.1 };
     let spread_score = if spread_pct < 0.05 { 1.0 } else if spread_pct < 0.10 { 0.7 } else if spread_pct < 0.20 { 0.4 } else { 0.1 };
     let underlying_momentum = underlying_score; // Higher underlying score = better momentum
-    
+
+    // Compare the market mid to the Black-Scholes theoretical fair value: a contract whose
+    // mid sits meaningfully below fair value is genuinely undervalued, not just illiquid or
+    // tight-spreaded.
+    let is_call = contract.r#type.eq_ignore_ascii_case("call");
+    let theoretical_vs_market = (|| {
+        let iv = contract.implied_volatility?;
+        let t_years = time_to_expiry_years(contract)?;
+        let theoretical_price = black_scholes_price(spot_price, contract.strike_price, rf_annual, iv, t_years, is_call)?;
+        if mid_price <= 0.0 || theoretical_price <= 0.0 {
+            return None;
+        }
+        Some(theoretical_price / mid_price)
+    })();
+    let is_undervalued_vs_theoretical = theoretical_vs_market.is_some_and(|ratio| ratio > 1.05);
+
     let overall_undervalued_score = (liquidity_score * 0.3 + spread_score * 0.3 + underlying_momentum * 0.4).min(1.0);
-    
+
     serde_json::json!({
         "liquidity_score": liquidity_score,
         "spread_score": spread_score,
@@ -175,11 +223,18 @@ fn calculate_undervalued_indicators(contract: &OptionContract, _spot_price: f64,
         "open_interest": open_interest,
         "is_liquid": open_interest > 500,
         "is_tight_spread": spread_pct < 0.10,
-        "has_momentum": underlying_score > 0.5
+        "has_momentum": underlying_score > 0.5,
+        "theoretical_vs_market": theoretical_vs_market,
+        "is_undervalued_vs_theoretical": is_undervalued_vs_theoretical
     })
 }
 
-/// Get trending tickers with options analysis
+/// Get trending tickers with options analysis, cached for [`TRENDING_OPTIONS_CACHE_TTL`] per
+/// distinct set of params -- this fans out Yahoo/finviz/reddit fetches plus per-ticker metric
+/// and options computation on every call, expensive enough that repeat calls within a few
+/// seconds shouldn't recompute. Concurrent callers for the same params while a computation is
+/// in flight share it rather than each re-running the fan-out; see
+/// [`crate::cache::MemoryCache::get_or_compute`].
 pub async fn get_trending_options_analysis(
     state: &AppState,
     rf_annual: f64,
@@ -187,10 +242,40 @@ pub async fn get_trending_options_analysis(
     weights: &CompositeWeights,
     option_type: Option<&str>,
     limit: usize,
+) -> Vec<Value> {
+    let option_type_key = option_type.unwrap_or("both");
+    let cache_params: [(&str, String); 7] = [
+        ("rf_annual", rf_annual.to_string()),
+        ("periods_per_year", periods_per_year.to_string()),
+        ("sharpe_w", weights.sharpe.to_string()),
+        ("sortino_w", weights.sortino.to_string()),
+        ("calmar_w", weights.calmar.to_string()),
+        ("option_type", option_type_key.to_string()),
+        ("limit", limit.to_string()),
+    ];
+    let cache_params_ref: Vec<(&str, &str)> = cache_params.iter().map(|(k, v)| (*k, v.as_str())).collect();
+    let key = crate::cache::cache_key("trending_options_analysis", &cache_params_ref);
+
+    let cached = state.cache.get_or_compute(&key, TRENDING_OPTIONS_CACHE_TTL, || async move {
+        let results = compute_trending_options_analysis(state, rf_annual, periods_per_year, weights, option_type, limit).await;
+        serde_json::json!(results)
+    }).await;
+
+    serde_json::from_value(cached).unwrap_or_default()
+}
+
+/// [`get_trending_options_analysis`]'s uncached fan-out and per-ticker analysis.
+async fn compute_trending_options_analysis(
+    state: &AppState,
+    rf_annual: f64,
+    periods_per_year: usize,
+    weights: &CompositeWeights,
+    option_type: Option<&str>,
+    limit: usize,
 ) -> Vec<Value> {
     // Get trending tickers
-    let trending_tickers = get_trending_tickers().await;
-    
+    let trending_tickers = get_trending_tickers(&state.cache).await;
+
     // Analyze each ticker
     let analysis_futures = trending_tickers.into_iter().map(|symbol| {
         let yahoo = state.yahoo.clone();
@@ -203,7 +288,7 @@ pub async fn get_trending_options_analysis(
             
             if let Some(metrics) = underlying_metrics {
                 // Analyze options
-                let options_analysis = analyze_ticker_options(&symbol, &metrics, option_type.as_deref()).await;
+                let options_analysis = analyze_ticker_options(&symbol, &metrics, option_type.as_deref(), &yahoo, rf_annual).await;
                 
                 if let Some(analysis) = options_analysis {
                     return Some(analysis);
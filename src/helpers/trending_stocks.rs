@@ -1,14 +1,17 @@
-pub async fn get_trending_penny_stocks() -> Vec<String> {
+/// `finviz_trending` and `yahoo_trending` are the (typically cache-backed) outputs of
+/// `get_trending_from_finviz`/`get_trending_from_yahoo`, passed in so callers with access to
+/// `AppState`'s cache avoid re-scraping them here.
+pub async fn get_trending_penny_stocks(mut finviz_trending: Vec<String>, mut yahoo_trending: Vec<String>) -> Vec<String> {
     let mut out = Vec::new();
-    // finviz
-    let mut a = crate::sources::finviz_data::get_trending_from_finviz().await;
-    out.append(&mut a);
-    // yahoo
-    let mut b = crate::sources::yahoo_data::get_trending_from_yahoo().await;
-    out.append(&mut b);
+    out.append(&mut finviz_trending);
+    out.append(&mut yahoo_trending);
     // dedupe preserve order
     // reddit
-    let mut c = crate::sources::reddit_data::get_reddit_trending_stocks().await;
+    let mut c: Vec<String> = crate::sources::reddit_data::get_reddit_trending_stocks()
+        .await
+        .into_iter()
+        .map(|(symbol, _mentions)| symbol)
+        .collect();
     out.append(&mut c);
     // dedupe preserve order
     let mut seen = std::collections::HashSet::new();
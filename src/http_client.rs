@@ -1,16 +1,195 @@
 use std::time::Duration;
-use reqwest::{Client, ClientBuilder};
+use reqwest::{Client, ClientBuilder, StatusCode};
+use rand::Rng;
+use async_trait::async_trait;
 use crate::config::Config;
 
+const RETRY_BASE_DELAY_MS: u64 = 200;
+
+/// The pieces of an outgoing request a [`RequestModule`] is allowed to inspect or mutate
+/// before it's sent.
+#[derive(Debug, Clone)]
+pub struct RequestParts {
+    pub url: String,
+    pub headers: Vec<(String, String)>,
+}
+
+/// The pieces of a completed request/response round-trip a [`RequestModule`] observes
+/// afterward, for metrics/logging purposes. Carries no body, since most modules only care
+/// about status and timing.
+#[derive(Debug, Clone)]
+pub struct ResponseParts {
+    pub url: String,
+    pub status: Option<StatusCode>,
+    pub elapsed: Duration,
+    pub error: Option<String>,
+}
+
+/// A single stage in the request/response interceptor pipeline shared by [`ApiClient`] and
+/// [`crate::optimized_client::OptimizedApiClient`]. Modules run in registration order on the
+/// way out (`on_request`) and in the same order on the way back (`on_response`), modeled on
+/// Pingora's HTTP-module chain.
+#[async_trait]
+pub trait RequestModule: Send + Sync {
+    /// Mutate the outgoing request (e.g. inject auth headers) before it's sent.
+    async fn on_request(&self, req: &mut RequestParts);
+
+    /// Observe a completed round-trip (e.g. record metrics). Runs whether the request
+    /// succeeded or failed.
+    async fn on_response(&self, resp: &ResponseParts);
+}
+
+/// Injects the configured Alpaca key/secret headers into every outgoing request, replacing
+/// the old hardcoded `alpaca_headers()` call sites with an opt-in pipeline stage.
+pub struct AlpacaAuthModule {
+    key_id: String,
+    secret_key: String,
+}
+
+impl AlpacaAuthModule {
+    pub fn new(key_id: String, secret_key: String) -> Self {
+        Self { key_id, secret_key }
+    }
+}
+
+#[async_trait]
+impl RequestModule for AlpacaAuthModule {
+    async fn on_request(&self, req: &mut RequestParts) {
+        req.headers.push(("APCA-API-KEY-ID".to_string(), self.key_id.clone()));
+        req.headers.push(("APCA-API-SECRET-KEY".to_string(), self.secret_key.clone()));
+    }
+
+    async fn on_response(&self, _resp: &ResponseParts) {}
+}
+
+/// Records every request into a [`crate::monitoring::MetricsCollector`], keyed by the
+/// request URL with any query string stripped off.
+pub struct MetricsModule {
+    collector: std::sync::Arc<crate::monitoring::MetricsCollector>,
+}
+
+impl MetricsModule {
+    pub fn new(collector: std::sync::Arc<crate::monitoring::MetricsCollector>) -> Self {
+        Self { collector }
+    }
+}
+
+#[async_trait]
+impl RequestModule for MetricsModule {
+    async fn on_request(&self, _req: &mut RequestParts) {}
+
+    async fn on_response(&self, resp: &ResponseParts) {
+        let endpoint = resp.url.split('?').next().unwrap_or(&resp.url).to_string();
+        let success = resp.status.map(|s| s.is_success()).unwrap_or(false);
+        self.collector.record_request(&endpoint, resp.elapsed, success, resp.error.clone()).await;
+    }
+}
+
+/// Logs every request at `debug` level with any `key=`/`secret=`/`token=`-style query
+/// parameter values masked, so upstream API keys never end up in log output.
+pub struct RedactingLogModule;
+
+impl RedactingLogModule {
+    /// Replaces the value of any query parameter whose name looks like a credential
+    /// (`key`, `secret`, `token`, `password`, `auth`, case-insensitively, as a substring) with `***`.
+    fn redact_url(url: &str) -> String {
+        let Some((base, query)) = url.split_once('?') else {
+            return url.to_string();
+        };
+        let redacted: Vec<String> = query.split('&').map(|pair| {
+            let Some((name, _value)) = pair.split_once('=') else {
+                return pair.to_string();
+            };
+            let lower = name.to_ascii_lowercase();
+            if ["key", "secret", "token", "password", "auth"].iter().any(|needle| lower.contains(needle)) {
+                format!("{name}=***")
+            } else {
+                pair.to_string()
+            }
+        }).collect();
+        format!("{base}?{}", redacted.join("&"))
+    }
+}
+
+#[async_trait]
+impl RequestModule for RedactingLogModule {
+    async fn on_request(&self, req: &mut RequestParts) {
+        tracing::debug!("-> GET {}", Self::redact_url(&req.url));
+    }
+
+    async fn on_response(&self, resp: &ResponseParts) {
+        match &resp.error {
+            Some(err) => tracing::debug!("<- {} ({:?}ms): {err}", Self::redact_url(&resp.url), resp.elapsed.as_millis()),
+            None => tracing::debug!("<- {} {:?} ({}ms)", Self::redact_url(&resp.url), resp.status, resp.elapsed.as_millis()),
+        }
+    }
+}
+
+/// Retry policy for transient HTTP failures: exponential backoff with jitter, honoring a
+/// `Retry-After` header on 429 responses instead of the computed backoff.
+#[derive(Debug, Clone, Copy)]
+pub struct RetryPolicy {
+    pub max_attempts: u32,
+    pub base_delay: Duration,
+    pub max_delay: Duration,
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        Self {
+            max_attempts: 3,
+            base_delay: Duration::from_millis(RETRY_BASE_DELAY_MS),
+            max_delay: Duration::from_secs(10),
+        }
+    }
+}
+
+impl RetryPolicy {
+    pub(crate) fn backoff(&self, attempt: u32) -> Duration {
+        let exp_ms = (self.base_delay.as_millis() as u64)
+            .saturating_mul(1u64 << attempt.min(16))
+            .min(self.max_delay.as_millis() as u64);
+        let jittered_ms = rand::thread_rng().gen_range(0..=exp_ms.max(1));
+        Duration::from_millis(jittered_ms)
+    }
+}
+
+pub(crate) fn is_retryable_status(status: StatusCode) -> bool {
+    matches!(status.as_u16(), 429 | 500 | 502 | 503 | 504)
+}
+
+/// Parses a `Retry-After` header value, accepting either a delay in seconds or an HTTP-date.
+pub(crate) fn parse_retry_after(headers: &reqwest::header::HeaderMap) -> Option<Duration> {
+    let raw = headers.get(reqwest::header::RETRY_AFTER)?.to_str().ok()?.trim();
+    if let Ok(secs) = raw.parse::<u64>() {
+        return Some(Duration::from_secs(secs));
+    }
+    let fmt = time::macros::format_description!(
+        "[weekday repr:short], [day] [month repr:short] [year] [hour]:[minute]:[second] GMT"
+    );
+    let parsed = time::PrimitiveDateTime::parse(raw, &fmt).ok()?.assume_utc();
+    let delta = parsed - time::OffsetDateTime::now_utc();
+    Some(Duration::from_secs(delta.whole_seconds().max(0) as u64))
+}
+
 /// A wrapper around reqwest::Client with common configuration and error handling
 pub struct ApiClient {
     client: Client,
     config: Arc<Config>,
+    /// Interceptor pipeline run around every request, in registration order (see
+    /// [`RequestModule`]). Empty by default; built-in modules are opted into via
+    /// [`ApiClient::with_modules`].
+    modules: Vec<Arc<dyn RequestModule>>,
 }
 
 impl ApiClient {
-    /// Create a new API client with proper configuration
+    /// Create a new API client with proper configuration and no interceptor modules.
     pub fn new(config: Arc<Config>) -> Result<Self, String> {
+        Self::with_modules(config, Vec::new())
+    }
+
+    /// Create a new API client that runs `modules` around every request, in order.
+    pub fn with_modules(config: Arc<Config>, modules: Vec<Arc<dyn RequestModule>>) -> Result<Self, String> {
         let client = ClientBuilder::new()
             .timeout(Duration::from_secs(30))
             .connect_timeout(Duration::from_secs(5))
@@ -21,7 +200,7 @@ impl ApiClient {
             .build()
             .map_err(|e| format!("Failed to create HTTP client: {e}"))?;
 
-        Ok(Self { client, config })
+        Ok(Self { client, config, modules })
     }
 
     /// Get the underlying reqwest client
@@ -34,56 +213,115 @@ impl ApiClient {
         self.config.alpaca_headers()
     }
 
-    /// Make a GET request with retry logic
-    pub async fn get_with_retry<T>(&self, url: &str, headers: Option<Vec<(String, String)>>) -> Result<T, String>
-    where
-        T: serde::de::DeserializeOwned,
-    {
-        let mut request = self.client.get(url);
-        
-        if let Some(headers) = headers {
-            for (key, value) in headers {
-                request = request.header(key, value);
-            }
+    /// Runs a single GET attempt, returning the response for a successful status or an
+    /// `Err((message, retryable, retry_after))` describing whether/how long to wait before
+    /// retrying. Runs the module pipeline's `on_request` before sending and `on_response`
+    /// after, regardless of outcome.
+    async fn get_once(
+        &self,
+        url: &str,
+        headers: &Option<Vec<(String, String)>>,
+    ) -> Result<reqwest::Response, (String, bool, Option<Duration>)> {
+        let mut parts = RequestParts { url: url.to_string(), headers: headers.clone().unwrap_or_default() };
+        for module in &self.modules {
+            module.on_request(&mut parts).await;
         }
 
-        let response = request
-            .send()
-            .await
-            .map_err(|e| format!("HTTP request failed: {e}"))?;
+        let start = std::time::Instant::now();
+        let mut request = self.client.get(&parts.url);
+        for (key, value) in &parts.headers {
+            request = request.header(key, value);
+        }
+
+        let outcome = request.send().await;
+        let elapsed = start.elapsed();
+
+        let response = match outcome {
+            Ok(response) => response,
+            Err(e) => {
+                let error_message = format!("HTTP request failed: {e}");
+                let resp_parts = ResponseParts { url: parts.url, status: None, elapsed, error: Some(error_message.clone()) };
+                for module in &self.modules {
+                    module.on_response(&resp_parts).await;
+                }
+                return Err((error_message, true, None));
+            }
+        };
+
+        let resp_parts = ResponseParts {
+            url: parts.url,
+            status: Some(response.status()),
+            elapsed,
+            error: if response.status().is_success() { None } else { Some(response.status().to_string()) },
+        };
+        for module in &self.modules {
+            module.on_response(&resp_parts).await;
+        }
 
         if !response.status().is_success() {
-            return Err(format!("HTTP error: {} {}", response.status(), response.status().canonical_reason().unwrap_or("")));
+            let retryable = is_retryable_status(response.status());
+            let retry_after = if response.status().as_u16() == 429 { parse_retry_after(response.headers()) } else { None };
+            return Err((
+                format!("HTTP error: {} {}", response.status(), response.status().canonical_reason().unwrap_or("")),
+                retryable,
+                retry_after,
+            ));
         }
 
-        response
-            .json::<T>()
-            .await
-            .map_err(|e| format!("Failed to parse JSON response: {e}"))
+        Ok(response)
     }
 
-    /// Make a GET request and return raw JSON
-    pub async fn get_json(&self, url: &str, headers: Option<Vec<(String, String)>>) -> Result<serde_json::Value, String> {
-        let mut request = self.client.get(url);
-        
-        if let Some(headers) = headers {
-            for (key, value) in headers {
-                request = request.header(key, value);
+    /// Issues a GET request, retrying on connection/timeout errors and on 429/500/502/503/504
+    /// with exponential backoff (honoring `Retry-After` on 429). Other 4xx responses are not
+    /// retried. `policy` defaults to `RetryPolicy::default()` when omitted.
+    async fn get_with_policy(
+        &self,
+        url: &str,
+        headers: Option<Vec<(String, String)>>,
+        policy: Option<RetryPolicy>,
+    ) -> Result<reqwest::Response, String> {
+        let policy = policy.unwrap_or_default();
+        let mut attempt = 0u32;
+        loop {
+            match self.get_once(url, &headers).await {
+                Ok(response) => return Ok(response),
+                Err((message, retryable, retry_after)) => {
+                    if !retryable || attempt + 1 >= policy.max_attempts {
+                        return Err(format!("{message} (after {} attempt(s))", attempt + 1));
+                    }
+                    let delay = retry_after.unwrap_or_else(|| policy.backoff(attempt));
+                    tokio::time::sleep(delay).await;
+                    attempt += 1;
+                }
             }
         }
+    }
 
-        let response = request
-            .send()
+    /// Make a GET request with retry logic
+    pub async fn get_with_retry<T>(
+        &self,
+        url: &str,
+        headers: Option<Vec<(String, String)>>,
+        policy: Option<RetryPolicy>,
+    ) -> Result<T, String>
+    where
+        T: serde::de::DeserializeOwned,
+    {
+        let response = self.get_with_policy(url, headers, policy).await?;
+        response
+            .json::<T>()
             .await
-            .map_err(|e| format!("HTTP request failed: {e}"))?;
-
-        if !response.status().is_success() {
-            if response.status().as_u16() == 429 {
-                return Err("Rate limit exceeded".to_string());
-            }
-            return Err(format!("HTTP error: {} {}", response.status(), response.status().canonical_reason().unwrap_or("")));
-        }
+            .map_err(|e| format!("Failed to parse JSON response: {e}"))
+    }
 
+    /// Make a GET request and return raw JSON
+    pub async fn get_json(
+        &self,
+        url: &str,
+        headers: Option<Vec<(String, String)>>,
+        policy: Option<RetryPolicy>,
+    ) -> Result<serde_json::Value, String> {
+        let response = self.get_with_policy(url, headers, policy).await?;
         response
             .json::<serde_json::Value>()
             .await
@@ -1,4 +1,5 @@
 pub mod helpers;
+pub mod market;
 pub mod sources;
 pub mod state;
 pub mod routes;
@@ -8,24 +9,28 @@ pub mod errors;
 pub mod config;
 pub mod http_client;
 pub mod middleware;
+pub mod metrics;
 pub mod monitoring;
 pub mod utils;
 pub mod cache;
 pub mod optimized_client;
 
 use axum::Router;
-use axum::middleware::from_fn;
+use axum::middleware::{from_fn, from_fn_with_state};
 use utoipa::OpenApi;
 use utoipa_swagger_ui::SwaggerUi;
 use tower_http::trace::TraceLayer;
 use serde::{Deserialize, Serialize};
 use utoipa::{IntoParams, ToSchema};
 use crate::middleware::cors_middleware;
+use crate::middleware::metrics_middleware;
+use crate::middleware::rate_limit_middleware;
 
 #[derive(OpenApi)]
 #[openapi(
     paths(
         crate::routes::system::health,
+        crate::routes::system::metrics_prometheus,
         crate::routes::data::news_aggregated,
         crate::sources::finviz_data::get_forex,
         crate::sources::finviz_data::get_crypto,
@@ -33,16 +38,22 @@ use crate::middleware::cors_middleware;
         crate::sources::finviz_data::get_insider,
         crate::sources::finviz_data::get_group,
         crate::routes::data::get_reddit_stocks,
+        crate::routes::data::get_reddit_trending,
         crate::routes::data::get_trending_stocks,
         crate::routes::data::get_trending_crypto,
+        crate::routes::data::live_feed_stream,
+        crate::routes::data::live_feed_stream_ws,
         crate::routes::yahoo::get_metrics_yahoo,
         crate::routes::yahoo::get_rank_yahoo,
         crate::routes::yahoo::get_recommendations_yahoo,
         crate::sources::finviz_data::get_screener_candidates,
         crate::sources::finviz_data::get_recommendations_finviz,
         crate::routes::options::get_options_recommendations,
+        crate::routes::options::submit_option_order,
+        crate::routes::options::get_activity_ledger,
         crate::routes::kraken::get_ticker,
         crate::routes::kraken::get_ticker_by_pair,
+        crate::routes::kraken::get_quote,
         crate::routes::kraken::get_order_book,
         crate::routes::kraken::get_assets,
         crate::routes::kraken::get_asset_pairs,
@@ -52,6 +63,10 @@ use crate::middleware::cors_middleware;
         crate::routes::kraken::get_market_summary_route,
         crate::routes::kraken::get_system_status,
         crate::routes::kraken::get_server_time,
+        crate::routes::kraken::kraken_stream,
+        crate::routes::kraken::kraken_book_stream,
+        crate::routes::kraken::start_backfill,
+        crate::routes::kraken::backfill_progress,
         crate::routes::coingecko::get_top_cryptocurrencies,
         crate::routes::coingecko::get_top_gainers_route,
         crate::routes::coingecko::get_top_losers_route,
@@ -60,9 +75,19 @@ use crate::middleware::cors_middleware;
         crate::routes::coingecko::get_market_context_route,
         crate::routes::coingecko::get_trending_symbols,
         crate::routes::coingecko::get_simple_price_route,
+        crate::routes::coingecko::get_coin_tickers_route,
+        crate::routes::coingecko::get_coin_metrics_route,
+        crate::routes::coingecko::get_pairs_route,
+        crate::routes::coingecko::get_tickers_route,
+        crate::routes::coingecko::get_market_summary_route,
+        crate::routes::coingecko::get_ohlc_route,
+        crate::routes::coingecko::coingecko_stream,
         crate::routes::high_open_interest::get_high_open_interest_handler,
         crate::routes::high_open_interest::get_high_open_interest_batch_handler,
         crate::routes::trending_options::get_trending_options_handler,
+        crate::routes::history::get_recommendations_history,
+        crate::routes::alpaca::stream_quotes,
+        crate::routes::price::get_price_consensus_handler,
     ),
     components(schemas(
         crate::types::HealthResponse,
@@ -70,13 +95,30 @@ use crate::middleware::cors_middleware;
         crate::types::LimitQuery,
         crate::types::YahooQuery,
         crate::sources::finviz_data::ScreenerQuery,
+        crate::sources::finviz_data::ScreenerFilters,
         crate::sources::finviz_data::FinvizRecommendationsQuery,
+        crate::routes::history::HistoryQuery,
+        crate::routes::data::TrendingQuery,
+        crate::routes::data::LiveFeedQuery,
+        crate::services::trends::TrendingTicker,
         crate::types::OptionsQuery,
+        crate::sources::alpaca_data::AlpacaOrderRequest,
+        crate::routes::options::ActivityLedgerQuery,
         crate::routes::kraken::KrakenQuery,
         crate::sources::kraken_data::KrakenTicker,
+        crate::sources::kraken_data::KrakenQuote,
         crate::sources::kraken_data::KrakenOrderBook,
+        crate::sources::kraken_data::KrakenOrderBookSnapshot,
         crate::sources::kraken_data::KrakenAsset,
         crate::sources::kraken_data::KrakenAssetPair,
+        crate::sources::kraken_data::KrakenWsTicker,
+        crate::routes::kraken::KrakenStreamQuery,
+        crate::routes::kraken::BackfillRequest,
+        crate::routes::kraken::BackfillJobHandle,
+        crate::services::candles::Candle,
+        crate::services::backfill::BackfillTarget,
+        crate::services::backfill::BackfillStatus,
+        crate::services::backfill::BackfillProgress,
         crate::CoinGeckoQuery,
         crate::SimplePriceQuery,
         crate::CoinGeckoResponse<crate::sources::coingecko_data::CoinGeckoCoin>,
@@ -88,7 +130,26 @@ use crate::middleware::cors_middleware;
         crate::sources::coingecko_data::CoinGeckoCoin,
         crate::sources::coingecko_data::MarketOverview,
         crate::sources::coingecko_data::TrendingCoin,
+        crate::sources::coingecko_data::MarketTicker,
+        crate::routes::coingecko::CoinGeckoTickersQuery,
+        crate::CoinGeckoResponse<crate::sources::coingecko_data::MarketTicker>,
+        crate::routes::coingecko::CoinMetricsQuery,
+        crate::routes::coingecko::CoinMetrics,
+        crate::CoinGeckoResponse<Vec<crate::routes::coingecko::CoinMetrics>>,
+        crate::routes::coingecko::CoinGeckoPair,
+        crate::routes::coingecko::CoinGeckoTicker,
+        crate::routes::coingecko::TickersQuery,
+        crate::routes::coingecko::AggregatedTicker,
+        crate::CoinGeckoResponse<Vec<crate::routes::coingecko::CoinGeckoPair>>,
+        crate::CoinGeckoResponse<Vec<crate::routes::coingecko::CoinGeckoTicker>>,
+        crate::routes::coingecko::CoinGeckoOhlcQuery,
+        crate::sources::coingecko_data::Ohlc,
+        crate::sources::coingecko_data::Candle,
+        crate::sources::coingecko_data::CandleSeries,
+        crate::CoinGeckoResponse<crate::sources::coingecko_data::CandleSeries>,
+        crate::routes::coingecko::CoinGeckoStreamQuery,
         crate::types::OptionContract,
+        crate::helpers::options::Greeks,
         crate::types::OptionPrices,
         crate::types::HighOpenInterestResult,
         crate::routes::high_open_interest::HighOpenInterestResponse,
@@ -97,6 +158,10 @@ use crate::middleware::cors_middleware;
         crate::routes::trending_options::TrendingOptionsQuery,
         crate::routes::trending_options::TrendingOptionsResponse,
         crate::routes::trending_options::TrendingOptionsSummary,
+        crate::routes::alpaca::StreamQuotesQuery,
+        crate::routes::price::PriceConsensusQuery,
+        crate::helpers::price_aggregator::PriceConsensus,
+        crate::helpers::price_aggregator::PriceSourceQuote,
     )),
     tags(
         (name = "system", description = "Health & meta"),
@@ -105,7 +170,8 @@ use crate::middleware::cors_middleware;
         (name = "kraken", description = "Kraken cryptocurrency exchange data"),
         (name = "CoinGecko", description = "CoinGecko cryptocurrency data"),
         (name = "high-open-interest", description = "High open interest option contracts from Alpaca"),
-        (name = "trending-options", description = "Trending tickers with undervalued options analysis")
+        (name = "trending-options", description = "Trending tickers with undervalued options analysis"),
+        (name = "price", description = "Multi-exchange spot price consensus")
     )
 )]
 struct ApiDoc;
@@ -144,21 +210,33 @@ pub struct MarketContextResponse {
 
 pub fn build_app(state: state::AppState) -> Router {
     let openapi = ApiDoc::openapi();
+    let metrics_state = state.clone();
+    let rate_limit_state = state.clone();
     Router::new()
         .merge(routes::system::router(state.clone()))
         .merge(routes::data::router(state.clone()))
         .merge(routes::yahoo::router(state.clone()))
         .merge(routes::options::router(state.clone()))
-        .merge(routes::high_open_interest::router())
+        .merge(routes::high_open_interest::router(state.clone()))
         .merge(routes::trending_options::router(state.clone()))
         .nest("/kraken", routes::kraken::router(state.clone()))
         .nest("/coingecko", routes::coingecko::coingecko_routes())
         .nest("/solana", routes::solana::router(state.clone()))
-        .nest("/hyperliquid", routes::hyperliquid::router(state))
+        .nest("/hyperliquid", routes::hyperliquid::router(state.clone()))
+        .nest("/coinbase", routes::coinbase::router(state.clone()))
+        .nest("/markets", routes::markets::router(state.clone()))
+        .nest("/jito", routes::jito::router(state.clone()))
+        .nest("/price", routes::price::router(state.clone()))
+        .nest("/pumpfun", routes::pumpfun::router(state.clone()))
+        .nest("/stream", routes::alpaca::router(state))
         .route("/screener/candidates", axum::routing::get(crate::sources::finviz_data::get_screener_candidates))
         .route("/recommendations/finviz", axum::routing::get(crate::sources::finviz_data::get_recommendations_finviz))
+        .route("/screener/stream", axum::routing::get(crate::sources::finviz_data::screener_stream))
+        .route("/recommendations/history", axum::routing::get(crate::routes::history::get_recommendations_history))
         .merge(SwaggerUi::new("/docs").url("/openapi.json", openapi))
         .layer(from_fn(cors_middleware))
+        .layer(from_fn_with_state(metrics_state, metrics_middleware))
+        .layer(from_fn_with_state(rate_limit_state, rate_limit_middleware))
         .layer(TraceLayer::new_for_http())
 }
 
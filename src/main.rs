@@ -18,12 +18,103 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
         .map_err(|e| format!("Configuration error: {e}"))?;
 
     let cache = std::sync::Arc::new(trading_api::cache::MemoryCache::new());
-    let rate_limiter = std::sync::Arc::new(trading_api::middleware::RateLimiter::new(
-        trading_api::middleware::RateLimitConfig::default()
+    // Expensive Hyperliquid market-scan endpoints get a tighter bucket than the configured
+    // default so a single heavy client can't starve cheaper routes.
+    let mut rate_limit_overrides = std::collections::HashMap::new();
+    rate_limit_overrides.insert(
+        "/hyperliquid".to_string(),
+        trading_api::middleware::RateLimitConfig {
+            requests_per_minute: config.rate_limiting.requests_per_minute / 2,
+            burst_size: (config.rate_limiting.burst_size / 2).max(1),
+        },
+    );
+    let rate_limiter = std::sync::Arc::new(trading_api::middleware::RateLimiter::with_route_overrides(
+        trading_api::middleware::RateLimitConfig {
+            requests_per_minute: config.rate_limiting.requests_per_minute,
+            burst_size: config.rate_limiting.burst_size,
+        },
+        rate_limit_overrides,
     ));
     let optimized_client = trading_api::optimized_client::OptimizedApiClient::new(cache.clone())
         .map_err(|e| format!("Failed to create optimized client: {e}"))?;
-    
+    let data_rate_limiter = std::sync::Arc::new(
+        trading_api::middleware::redis_rate_limit::DeferredRateLimiter::new(config.data_rate_limiting.clone())
+    );
+    let solana_ws_hub = std::sync::Arc::new(
+        trading_api::sources::helius_data::SolanaWsHub::new(config.helius_api_key.clone())
+    );
+    let transaction_tracker = std::sync::Arc::new(
+        trading_api::sources::helius_data::TransactionTracker::new(config.helius_api_key.clone())
+    );
+    let solana_pubsub_hub = std::sync::Arc::new(
+        trading_api::sources::helius_data::SolanaPubsubHub::new(config.helius_api_key.clone(), config.retry.clone())
+    );
+    let finviz_cache = std::sync::Arc::new(trading_api::sources::finviz_cache::FinvizScrapeCache::new());
+    finviz_cache.clone().spawn_refresh_loop();
+
+    // Exchange symbol allowlist used to validate bare-word ticker candidates scraped from Reddit
+    // (see `sources::symbol_universe`); loaded once at startup so it's warm before the first
+    // scrape, then kept fresh by the `symbol_universe_refresh` scheduler job below.
+    match trading_api::sources::symbol_universe::refresh(&Client::new()).await {
+        Ok(count) => tracing::info!("symbol universe: loaded {count} exchange symbols"),
+        Err(e) => tracing::warn!("symbol universe: initial load failed, starting empty: {e}"),
+    }
+    let screener_stream_hub = std::sync::Arc::new(trading_api::sources::finviz_data::ScreenerStreamHub::new());
+    let history_store = std::sync::Arc::new(
+        trading_api::services::history::HistoryStore::connect(config.history_db.as_ref()).await
+    );
+    let kraken_ws_hub = std::sync::Arc::new(
+        trading_api::sources::kraken_data::KrakenWsHub::new(config.retry.clone())
+    );
+    let (alpaca_api_key, alpaca_api_secret) = config.alpaca_headers();
+    let alpaca_ws_hub = trading_api::sources::alpaca_data::AlpacaWsHub::new(
+        alpaca_api_key.clone(),
+        alpaca_api_secret.clone(),
+        config.retry.clone(),
+    );
+    let candle_store = std::sync::Arc::new(
+        trading_api::services::candles::CandleStore::connect(config.candles_db.as_ref()).await
+    );
+    let backfill_tracker = std::sync::Arc::new(trading_api::services::backfill::BackfillTracker::new());
+    let prometheus_metrics = std::sync::Arc::new(trading_api::monitoring::PrometheusMetrics::new());
+    let health_registry = std::sync::Arc::new(trading_api::services::health::HealthRegistry::new());
+    let incident_log = std::sync::Arc::new(trading_api::services::health::IncidentLog::new(200));
+    let system_monitor = std::sync::Arc::new(trading_api::monitoring::SystemMonitor::new());
+    let kraken_snapshot_hub = std::sync::Arc::new(trading_api::sources::kraken_ws::KrakenSnapshotHub::new(
+        vec!["BTC/USD".to_string(), "ETH/USD".to_string(), "ADA/USD".to_string(), "DOT/USD".to_string()],
+        config.retry.clone(),
+    ));
+    let kraken_book_hub = std::sync::Arc::new(
+        trading_api::sources::kraken_data::KrakenOrderBookHub::new(config.retry.clone())
+    );
+    let hyperliquid_ws_hub = std::sync::Arc::new(
+        trading_api::sources::hyperliquid_data::HyperliquidWsHub::new(config.retry.clone())
+    );
+    let hyperliquid = std::sync::Arc::new(
+        trading_api::sources::hyperliquid_data::HyperliquidDataSource::new().await?
+    );
+    let coinbase = std::sync::Arc::new(trading_api::sources::coinbase_data::CoinbaseDataSource::new());
+    let alpaca = std::sync::Arc::new(
+        trading_api::sources::alpaca_data::AlpacaDataSource::new(alpaca_api_key.clone(), alpaca_api_secret.clone())
+    );
+    let trend_store = std::sync::Arc::new(trading_api::services::trends::TrendStore::new());
+    let market_store = std::sync::Arc::new(
+        trading_api::services::market_store::MarketStore::connect(config.market_store_db.as_ref()).await
+    );
+
+    let mut pumpfun_service_inner = trading_api::sources::pumpfun_data::PumpFunService::new(
+        trading_api::sources::pumpfun_data::PumpFunConfig::from_env(),
+    );
+    pumpfun_service_inner.initialize().await?;
+    let pumpfun_service = std::sync::Arc::new(pumpfun_service_inner);
+    let position_manager = std::sync::Arc::new(
+        trading_api::services::position_manager::PositionManager::connect(
+            config.positions_db.as_ref(),
+            pumpfun_service.clone(),
+        ).await
+    );
+    let live_feed_hub = std::sync::Arc::new(trading_api::services::live_feed::LiveFeedHub::new());
+
     let state = AppState {
         http: Client::new(),
         yahoo: std::sync::Arc::new(YahooConnector::new()?),
@@ -32,14 +123,187 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
         cache: cache.clone(),
         rate_limiter,
         optimized_client,
+        data_rate_limiter,
+        solana_ws_hub,
+        solana_pubsub_hub,
+        transaction_tracker,
+        finviz_cache,
+        screener_stream_hub,
+        history_store,
+        kraken_ws_hub,
+        alpaca_ws_hub,
+        candle_store,
+        backfill_tracker,
+        prometheus_metrics,
+        health_registry,
+        incident_log,
+        system_monitor,
+        kraken_snapshot_hub,
+        kraken_book_hub,
+        hyperliquid_ws_hub,
+        hyperliquid,
+        coinbase,
+        alpaca,
+        trend_store,
+        market_store,
+        pumpfun_service,
+        position_manager,
+        live_feed_hub,
     };
 
+    // Named cron jobs that keep hot data warm instead of fetching only on demand; see
+    // `services::scheduler::Scheduler`.
+    let mut scheduler = trading_api::services::scheduler::Scheduler::new();
+
     let cache_cleanup = cache.clone();
+    scheduler.add_job("cache_cleanup", "*/5 * * * *", move || {
+        let cache_cleanup = cache_cleanup.clone();
+        async move { cache_cleanup.cleanup_expired().await }
+    });
+
+    let reddit_cache = cache.clone();
+    scheduler.add_job("reddit_trending_refresh", "*/10 * * * *", move || {
+        let reddit_cache = reddit_cache.clone();
+        async move {
+            reddit_cache.get_or_compute(
+                trading_api::routes::data::REDDIT_TRENDING_STOCKS_CACHE_KEY,
+                tokio::time::Duration::from_secs(900),
+                || async { serde_json::json!(trading_api::sources::reddit_data::get_reddit_trending_stocks().await) },
+            ).await;
+        }
+    });
+
+    let yahoo_prewarm_provider = std::sync::Arc::new(YahooConnector::new()?);
+    let yahoo_prewarm_cache = cache.clone();
+    scheduler.add_job("yahoo_watchlist_prewarm", "30 13 * * 1-5", move || {
+        let yahoo = yahoo_prewarm_provider.clone();
+        let cache = yahoo_prewarm_cache.clone();
+        async move {
+            const WATCHLIST: [&str; 5] = ["AAPL", "MSFT", "GOOGL", "AMZN", "TSLA"];
+            for symbol in WATCHLIST {
+                let _ = trading_api::services::yahoo::fetch_prices_for_symbol_cached(&yahoo, symbol, "3mo", &cache).await;
+            }
+        }
+    });
+
+    let symbol_universe_http = state.http.clone();
+    scheduler.add_job("symbol_universe_refresh", "0 6 * * *", move || {
+        let http = symbol_universe_http.clone();
+        async move {
+            if let Err(e) = trading_api::sources::symbol_universe::refresh(&http).await {
+                tracing::warn!("symbol universe: scheduled refresh failed: {e}");
+            }
+        }
+    });
+
+    // Interval jobs (not anchored to wall-clock boundaries, catch up immediately on startup --
+    // see `Scheduler::run`) keeping the on-demand caches in `routes::data` warm.
+    scheduler.add_interval_job("refresh_news_cache", std::time::Duration::from_secs(300), || async {
+        if let Err(e) = trading_api::helpers::news::get_news_cached().await {
+            tracing::warn!("news cache: scheduled refresh failed: {e}");
+        }
+    });
+
+    let yahoo_trending_cache = cache.clone();
+    scheduler.add_interval_job("yahoo_trending_warm", std::time::Duration::from_secs(600), move || {
+        let cache = yahoo_trending_cache.clone();
+        async move {
+            cache.get_or_compute(
+                trading_api::routes::data::YAHOO_TRENDING_CACHE_KEY,
+                tokio::time::Duration::from_secs(600),
+                || async { serde_json::json!(trading_api::sources::yahoo_data::get_trending_from_yahoo().await) },
+            ).await;
+            cache.get_or_compute(
+                trading_api::helpers::trending_options::YAHOO_TRENDING_SYMBOLS_CACHE_KEY,
+                tokio::time::Duration::from_secs(600),
+                || async { serde_json::json!(trading_api::sources::yahoo_data::yahoo_trending("US", 20).await.unwrap_or_default()) },
+            ).await;
+        }
+    });
+
+    tokio::spawn(scheduler.run());
+
+    let system_monitor_refresh = state.system_monitor.clone();
+    tokio::spawn(async move {
+        let mut interval = tokio::time::interval(tokio::time::Duration::from_secs(5));
+        loop {
+            interval.tick().await;
+            system_monitor_refresh.refresh().await;
+        }
+    });
+
+    let rate_poller_cache = cache.clone();
+    tokio::spawn(async move {
+        let sources: Vec<(std::sync::Arc<dyn trading_api::services::rates::LatestRate>, Vec<String>)> = vec![
+            (std::sync::Arc::new(trading_api::services::rates::FinvizForexRate), vec!["EURUSD".to_string(), "GBPUSD".to_string()]),
+            (std::sync::Arc::new(trading_api::services::rates::FinvizCryptoRate), vec!["BTCUSD".to_string(), "ETHUSD".to_string()]),
+        ];
+        trading_api::services::rates::run_rate_poller(rate_poller_cache, sources, tokio::time::Duration::from_secs(30)).await;
+    });
+
+    let health_registry = state.health_registry.clone();
+    let incident_log = state.incident_log.clone();
+    let health_config = state.config.health_monitor.clone();
+    let (health_alpaca_key, health_alpaca_secret) = state.config.alpaca_headers();
+    let health_http = state.http.clone();
+    let health_yahoo = state.yahoo.clone();
+    let alert_dispatcher = std::sync::Arc::new(trading_api::services::alerting::AlertDispatcher::new(
+        state.http.clone(),
+        state.config.alerting.webhooks.clone(),
+        state.config.alerting.rtt_warning_threshold_ms,
+    ));
+    tokio::spawn(async move {
+        let probes: Vec<std::sync::Arc<dyn trading_api::services::health::HealthProbe>> = vec![
+            std::sync::Arc::new(trading_api::services::health::AlpacaHealthProbe {
+                http: health_http,
+                api_key: health_alpaca_key,
+                api_secret: health_alpaca_secret,
+            }),
+            std::sync::Arc::new(trading_api::services::health::YahooHealthProbe { yahoo: health_yahoo }),
+        ];
+        trading_api::services::health::run_health_monitor(health_registry, probes, health_config, incident_log, Some(alert_dispatcher)).await;
+    });
+
+    let kraken_snapshot_hub = state.kraken_snapshot_hub.clone();
+    tokio::spawn(kraken_snapshot_hub.spawn());
+
+    let position_manager_reload = state.position_manager.clone();
+    tokio::spawn(async move {
+        if let Err(e) = position_manager_reload.reload().await {
+            tracing::warn!("position manager: startup reload failed: {e}");
+        }
+    });
+
+    let trend_store_poller = state.trend_store.clone();
+    let market_store_poller = state.market_store.clone();
     tokio::spawn(async move {
         let mut interval = tokio::time::interval(tokio::time::Duration::from_secs(300));
         loop {
             interval.tick().await;
-            cache_cleanup.cleanup_expired().await;
+            let mentions = trading_api::sources::reddit_data::scrape_reddit_mentions().await;
+            if !mentions.is_empty() {
+                let now = chrono::Utc::now();
+                trend_store_poller.record_many(&mentions, now).await;
+
+                let ranked: Vec<(String, u32)> = mentions.into_iter().collect();
+                if let Err(e) = market_store_poller.record_mentions(&ranked, "reddit", now).await {
+                    tracing::warn!("market store: failed to persist reddit mentions: {e}");
+                }
+            }
+        }
+    });
+
+    let backfill_store = state.history_store.clone();
+    tokio::spawn(async move {
+        if !backfill_store.is_enabled() {
+            return;
+        }
+        let mut interval = tokio::time::interval(tokio::time::Duration::from_secs(86_400));
+        loop {
+            interval.tick().await;
+            trading_api::services::history::backfill_range(
+                &backfill_store, "TopGainers", "Price", "Performance", 25, "3mo", 30,
+            ).await;
         }
     });
 
@@ -51,7 +315,14 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
     let addr = std::net::SocketAddr::from(([0, 0, 0, 0], port));
     let listener = tokio::net::TcpListener::bind(addr).await?;
     println!("listening on http://{host}:{port}");
-    axum::serve(listener, app).await?;
+    // `ConnectInfo<SocketAddr>` is required by `deferred_rate_limit_middleware` (data routes) --
+    // without this, it 500s on every request. `rate_limit_middleware`'s `extract_client_id` IP
+    // fallback reads a bare `SocketAddr` extension, which this does not insert.
+    axum::serve(
+        listener,
+        app.into_make_service_with_connect_info::<std::net::SocketAddr>(),
+    )
+    .await?;
     Ok(())
 }
 // OpenAPI moved to library build_app
\ No newline at end of file
@@ -0,0 +1,4 @@
+pub mod encoding;
+pub mod instrument;
+pub mod ticker;
+pub mod trading;
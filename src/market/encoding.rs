@@ -0,0 +1,189 @@
+//! Compact binary tick encoding for snapshot storage and streaming.
+//!
+//! [`encode`]/[`decode`] turn a slice of [`Tick`] records into a flat, fixed-width
+//! little-endian byte layout instead of JSON, so a stream of snapshots can be appended to a
+//! file and later memory-mapped or read back in fixed-size strides without a JSON parser on
+//! the hot path.
+
+use std::convert::TryFrom;
+use std::mem::size_of;
+
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use thiserror::Error;
+
+/// A coin/symbol dimension packed down into a single nonzero byte so it doesn't have to be
+/// repeated as a string in every [`Tick`] record. `0` is reserved to make a zeroed-out or
+/// truncated record detectable as invalid rather than silently decoding as a real symbol.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum SymbolCode {
+    Btc = 1,
+    Eth = 2,
+    Sol = 3,
+    Usd = 4,
+    Usdt = 5,
+    Usdc = 6,
+}
+
+/// Error returned when a byte can't be mapped to a [`SymbolCode`].
+#[derive(Debug, Error, Clone, Copy, PartialEq, Eq)]
+pub enum CodeError {
+    #[error("symbol code 0 is reserved and cannot be decoded")]
+    Zero,
+    #[error("unknown symbol code {0}")]
+    Unknown(u8),
+}
+
+impl TryFrom<u8> for SymbolCode {
+    type Error = CodeError;
+
+    fn try_from(value: u8) -> Result<Self, Self::Error> {
+        match value {
+            0 => Err(CodeError::Zero),
+            1 => Ok(SymbolCode::Btc),
+            2 => Ok(SymbolCode::Eth),
+            3 => Ok(SymbolCode::Sol),
+            4 => Ok(SymbolCode::Usd),
+            5 => Ok(SymbolCode::Usdt),
+            6 => Ok(SymbolCode::Usdc),
+            other => Err(CodeError::Unknown(other)),
+        }
+    }
+}
+
+impl From<SymbolCode> for u8 {
+    fn from(code: SymbolCode) -> Self {
+        code as u8
+    }
+}
+
+impl Serialize for SymbolCode {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_u8(u8::from(*self))
+    }
+}
+
+impl<'de> Deserialize<'de> for SymbolCode {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let raw = u8::deserialize(deserializer)?;
+        SymbolCode::try_from(raw).map_err(serde::de::Error::custom)
+    }
+}
+
+/// A single slimmed-down price point: a [`CoinGeckoCoin`](crate::sources::coingecko_data::CoinGeckoCoin)
+/// (or similar snapshot) reduced to the fields worth logging at tick granularity.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct Tick {
+    pub time_ms: i64,
+    pub id_code: SymbolCode,
+    pub price: f64,
+    pub volume: f64,
+    pub market_cap: f64,
+}
+
+const TIME_MS_LEN: usize = size_of::<i64>();
+const CODE_LEN: usize = size_of::<u8>();
+const FIELD_LEN: usize = size_of::<f64>();
+const RECORD_LEN: usize = TIME_MS_LEN + CODE_LEN + FIELD_LEN * 3;
+
+/// Serialize `ticks` into a flat buffer of fixed-width, little-endian records.
+pub fn encode(ticks: &[Tick]) -> Vec<u8> {
+    let mut buf = Vec::with_capacity(ticks.len() * RECORD_LEN);
+    for tick in ticks {
+        buf.extend_from_slice(&tick.time_ms.to_le_bytes());
+        buf.push(u8::from(tick.id_code));
+        buf.extend_from_slice(&tick.price.to_le_bytes());
+        buf.extend_from_slice(&tick.volume.to_le_bytes());
+        buf.extend_from_slice(&tick.market_cap.to_le_bytes());
+    }
+    buf
+}
+
+/// Deserialize a buffer produced by [`encode`] back into [`Tick`] records. Errors if `bytes`
+/// isn't an exact multiple of the record size, or if a record's symbol byte isn't a known
+/// [`SymbolCode`].
+pub fn decode(bytes: &[u8]) -> Result<Vec<Tick>, String> {
+    if bytes.len() % RECORD_LEN != 0 {
+        return Err(format!(
+            "truncated tick buffer: {} bytes is not a multiple of the {RECORD_LEN}-byte record size",
+            bytes.len()
+        ));
+    }
+
+    bytes
+        .chunks_exact(RECORD_LEN)
+        .map(|chunk| {
+            let time_ms = i64::from_le_bytes(chunk[0..TIME_MS_LEN].try_into().unwrap());
+            let id_code = SymbolCode::try_from(chunk[TIME_MS_LEN])
+                .map_err(|e| e.to_string())?;
+
+            let price_start = TIME_MS_LEN + CODE_LEN;
+            let price = f64::from_le_bytes(chunk[price_start..price_start + FIELD_LEN].try_into().unwrap());
+            let volume_start = price_start + FIELD_LEN;
+            let volume = f64::from_le_bytes(chunk[volume_start..volume_start + FIELD_LEN].try_into().unwrap());
+            let market_cap_start = volume_start + FIELD_LEN;
+            let market_cap = f64::from_le_bytes(chunk[market_cap_start..market_cap_start + FIELD_LEN].try_into().unwrap());
+
+            Ok(Tick { time_ms, id_code, price, volume, market_cap })
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sample_ticks() -> Vec<Tick> {
+        vec![
+            Tick { time_ms: 1_700_000_000_000, id_code: SymbolCode::Btc, price: 65000.5, volume: 1_200_000.0, market_cap: 1_280_000_000_000.0 },
+            Tick { time_ms: 1_700_000_060_000, id_code: SymbolCode::Eth, price: 3500.25, volume: 800_000.0, market_cap: 420_000_000_000.0 },
+        ]
+    }
+
+    #[test]
+    fn round_trips_ticks() {
+        let ticks = sample_ticks();
+        let bytes = encode(&ticks);
+        assert_eq!(bytes.len(), ticks.len() * RECORD_LEN);
+
+        let decoded = decode(&bytes).unwrap();
+        assert_eq!(decoded, ticks);
+    }
+
+    #[test]
+    fn rejects_reserved_zero_code() {
+        let mut bytes = encode(&sample_ticks());
+        bytes[TIME_MS_LEN] = 0;
+
+        let err = decode(&bytes).unwrap_err();
+        assert!(err.contains("reserved"), "unexpected error: {err}");
+    }
+
+    #[test]
+    fn rejects_unknown_code() {
+        let mut bytes = encode(&sample_ticks());
+        bytes[TIME_MS_LEN] = 200;
+
+        let err = decode(&bytes).unwrap_err();
+        assert!(err.contains("200"), "unexpected error: {err}");
+    }
+
+    #[test]
+    fn rejects_truncated_buffer() {
+        let bytes = encode(&sample_ticks());
+        let truncated = &bytes[..bytes.len() - 1];
+
+        let err = decode(truncated).unwrap_err();
+        assert!(err.contains("truncated"), "unexpected error: {err}");
+    }
+
+    #[test]
+    fn symbol_code_serde_round_trips_and_rejects_bad_bytes() {
+        let json = serde_json::to_string(&SymbolCode::Sol).unwrap();
+        assert_eq!(json, "3");
+        let decoded: SymbolCode = serde_json::from_str(&json).unwrap();
+        assert_eq!(decoded, SymbolCode::Sol);
+
+        let err = serde_json::from_str::<SymbolCode>("0").unwrap_err();
+        assert!(err.to_string().contains("reserved"));
+    }
+}
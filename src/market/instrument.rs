@@ -0,0 +1,128 @@
+//! A unified description of a single tradeable instrument, independent of which exchange
+//! lists it. Mirrors the subset of fields order sizing/rounding actually needs -- unified
+//! pair/base/quote for cross-exchange comparisons, plus the exchange's own symbol/ids and
+//! per-market precision/fees/minimums for validating an order before it's submitted.
+
+use serde::{Deserialize, Serialize};
+
+/// The kind of instrument a [`Market`] describes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum MarketType {
+    Spot,
+    Future,
+    Swap,
+    Option,
+}
+
+/// Decimal places (or tick-size exponent) each quantity must round to before an order is
+/// submitted.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct Precision {
+    pub price: u32,
+    pub base: u32,
+    pub quote: u32,
+}
+
+/// Maker/taker trading fees, expressed as a fraction (e.g. `0.001` for 10 bps).
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct Fees {
+    pub maker: f64,
+    pub taker: f64,
+}
+
+/// Smallest order size the exchange accepts, in base and quote units.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct MinQuantity {
+    pub base: f64,
+    pub quote: f64,
+}
+
+/// A single tradeable instrument on a single exchange, with both the exchange's native
+/// symbol and a unified `pair` so the same instrument can be compared/looked up across
+/// exchanges without per-exchange special-casing.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Market {
+    pub exchange: String,
+    pub market_type: MarketType,
+    /// The exchange's own symbol for this instrument (e.g. `"XBT/USDT"` on Kraken).
+    pub symbol: String,
+    /// Unified pair, e.g. `"BTC_USDT"`. See [`normalize_pair`]/[`denormalize_pair`].
+    pub pair: String,
+    pub base: String,
+    pub quote: String,
+    pub base_id: String,
+    pub quote_id: String,
+    pub active: bool,
+    pub margin: bool,
+    pub precision: Precision,
+    pub fees: Fees,
+    pub min_quantity: MinQuantity,
+}
+
+/// Build the unified `BASE_QUOTE` pair form from the exchange's base/quote ids for this
+/// instrument by uppercasing them and joining them with `_` (e.g. `("xbt", "usdt")` gives
+/// `"XBT_USDT"`). No raw exchange symbol is taken or parsed.
+pub fn normalize_pair(base_id: &str, quote_id: &str) -> String {
+    format!("{}_{}", base_id.to_uppercase(), quote_id.to_uppercase())
+}
+
+/// The inverse of [`normalize_pair`]: split a unified `BASE_QUOTE` pair back into its two
+/// halves. Returns `None` if `pair` doesn't contain exactly one `_` separator.
+pub fn denormalize_pair(pair: &str) -> Option<(&str, &str)> {
+    let mut parts = pair.splitn(2, '_');
+    let base = parts.next()?;
+    let quote = parts.next()?;
+    if quote.contains('_') {
+        return None;
+    }
+    Some((base, quote))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn normalize_pair_uppercases_and_joins() {
+        assert_eq!(normalize_pair("xbt", "usdt"), "XBT_USDT");
+        assert_eq!(normalize_pair("BTC", "USD"), "BTC_USD");
+    }
+
+    #[test]
+    fn denormalize_pair_round_trips() {
+        let pair = normalize_pair("eth", "usd");
+        assert_eq!(denormalize_pair(&pair), Some(("ETH", "USD")));
+    }
+
+    #[test]
+    fn denormalize_pair_rejects_malformed_input() {
+        assert_eq!(denormalize_pair("BTCUSD"), None);
+        assert_eq!(denormalize_pair("BTC_USD_EXTRA"), None);
+    }
+
+    #[test]
+    fn market_serializes_with_lowercase_market_type() {
+        let market = Market {
+            exchange: "kraken".to_string(),
+            market_type: MarketType::Spot,
+            symbol: "XBT/USDT".to_string(),
+            pair: normalize_pair("xbt", "usdt"),
+            base: "BTC".to_string(),
+            quote: "USDT".to_string(),
+            base_id: "xbt".to_string(),
+            quote_id: "usdt".to_string(),
+            active: true,
+            margin: false,
+            precision: Precision { price: 1, base: 8, quote: 8 },
+            fees: Fees { maker: 0.0016, taker: 0.0026 },
+            min_quantity: MinQuantity { base: 0.0001, quote: 5.0 },
+        };
+
+        let json = serde_json::to_string(&market).unwrap();
+        assert!(json.contains("\"market_type\":\"spot\""));
+
+        let deserialized: Market = serde_json::from_str(&json).unwrap();
+        assert_eq!(deserialized, market);
+    }
+}
@@ -0,0 +1,202 @@
+//! Strongly-typed currency/pair/side primitives that serde round-trip through compact
+//! lowercase strings, so callers get `==`-comparable values instead of ad hoc string
+//! matching/casing bugs when working with pairs like `"btc_usd"` or sides like `"bid"`.
+
+use std::fmt;
+use std::str::FromStr;
+
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+/// A currency code. Common codes get a dedicated variant so they compare/hash as values
+/// instead of strings; anything else falls back to [`Currency::Other`] so an unrecognized
+/// symbol still round-trips instead of failing to parse.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum Currency {
+    Btc,
+    Eth,
+    Sol,
+    Usd,
+    Usdt,
+    Usdc,
+    Other(String),
+}
+
+impl FromStr for Currency {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(match s.to_ascii_lowercase().as_str() {
+            "btc" => Currency::Btc,
+            "eth" => Currency::Eth,
+            "sol" => Currency::Sol,
+            "usd" => Currency::Usd,
+            "usdt" => Currency::Usdt,
+            "usdc" => Currency::Usdc,
+            other => Currency::Other(other.to_string()),
+        })
+    }
+}
+
+impl fmt::Display for Currency {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let s = match self {
+            Currency::Btc => "btc",
+            Currency::Eth => "eth",
+            Currency::Sol => "sol",
+            Currency::Usd => "usd",
+            Currency::Usdt => "usdt",
+            Currency::Usdc => "usdc",
+            Currency::Other(s) => s,
+        };
+        write!(f, "{s}")
+    }
+}
+
+impl Serialize for Currency {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for Currency {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let s = String::deserialize(deserializer)?;
+        Ok(Currency::from_str(&s).unwrap_or_else(|_| unreachable!()))
+    }
+}
+
+/// A trading pair, e.g. `"btc_usd"` parses into `Ticker { base: Currency::Btc, quote:
+/// Currency::Usd }`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Ticker {
+    pub base: Currency,
+    pub quote: Currency,
+}
+
+impl FromStr for Ticker {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut parts = s.splitn(2, '_');
+        let base = parts.next().filter(|p| !p.is_empty());
+        let quote = parts.next().filter(|p| !p.is_empty());
+        match (base, quote) {
+            (Some(base), Some(quote)) => Ok(Ticker {
+                base: Currency::from_str(base).unwrap_or_else(|_| unreachable!()),
+                quote: Currency::from_str(quote).unwrap_or_else(|_| unreachable!()),
+            }),
+            _ => Err(format!("invalid ticker string: {s}")),
+        }
+    }
+}
+
+impl fmt::Display for Ticker {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}_{}", self.base, self.quote)
+    }
+}
+
+impl Serialize for Ticker {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for Ticker {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let s = String::deserialize(deserializer)?;
+        Ticker::from_str(&s).map_err(serde::de::Error::custom)
+    }
+}
+
+/// Which side of the book an order/trade is on.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Side {
+    Bid,
+    Ask,
+}
+
+impl FromStr for Side {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_ascii_lowercase().as_str() {
+            "bid" => Ok(Side::Bid),
+            "ask" => Ok(Side::Ask),
+            other => Err(format!("invalid side: {other}")),
+        }
+    }
+}
+
+impl fmt::Display for Side {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", match self { Side::Bid => "bid", Side::Ask => "ask" })
+    }
+}
+
+impl Serialize for Side {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for Side {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let s = String::deserialize(deserializer)?;
+        Side::from_str(&s).map_err(serde::de::Error::custom)
+    }
+}
+
+/// A single executed trade, deserializable directly from upstream JSON thanks to
+/// [`Ticker`]'s and [`Side`]'s string-based serde impls.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Trade {
+    pub ticker: Ticker,
+    pub side: Side,
+    pub price: f64,
+    pub size: f64,
+    pub time_nanos: i64,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn ticker_round_trips_through_string() {
+        let ticker: Ticker = "btc_usd".parse().unwrap();
+        assert_eq!(ticker.base, Currency::Btc);
+        assert_eq!(ticker.quote, Currency::Usd);
+        assert_eq!(ticker.to_string(), "btc_usd");
+    }
+
+    #[test]
+    fn ticker_falls_back_to_other_for_unknown_currencies() {
+        let ticker: Ticker = "doge_usd".parse().unwrap();
+        assert_eq!(ticker.base, Currency::Other("doge".to_string()));
+    }
+
+    #[test]
+    fn ticker_rejects_malformed_input() {
+        assert!("btcusd".parse::<Ticker>().is_err());
+    }
+
+    #[test]
+    fn side_round_trips_through_string() {
+        assert_eq!("bid".parse::<Side>().unwrap(), Side::Bid);
+        assert_eq!("ASK".parse::<Side>().unwrap(), Side::Ask);
+        assert_eq!(Side::Bid.to_string(), "bid");
+    }
+
+    #[test]
+    fn trade_deserializes_from_json() {
+        let json = r#"{"ticker":"sol_usdt","side":"ask","price":150.5,"size":2.0,"time_nanos":1700000000000000000}"#;
+        let trade: Trade = serde_json::from_str(json).unwrap();
+        assert_eq!(trade.ticker.base, Currency::Sol);
+        assert_eq!(trade.side, Side::Ask);
+
+        let round_tripped = serde_json::to_string(&trade).unwrap();
+        let trade2: Trade = serde_json::from_str(&round_tripped).unwrap();
+        assert_eq!(trade, trade2);
+    }
+}
@@ -0,0 +1,229 @@
+//! An exchange-agnostic order-placement abstraction, built on top of the unified
+//! [`Market`](super::instrument::Market) metadata and [`Side`](super::ticker::Side) primitive:
+//! a [`TradingClient`] wraps a pair of API credentials and an [`ExchangeBackend`]
+//! implementation, and [`NewOrderBuilder`] validates a new order against its market's
+//! [`Precision`](super::instrument::Precision)/[`MinQuantity`](super::instrument::MinQuantity)
+//! before it's ever sent upstream.
+
+use async_trait::async_trait;
+
+use super::instrument::{Market, MinQuantity, Precision};
+use super::ticker::Side;
+
+/// Market vs. limit order.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum OrderType {
+    Market,
+    Limit,
+}
+
+/// How much of an order has been filled so far.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum FillStatus {
+    Open,
+    PartiallyFilled { filled_quantity: f64 },
+    Filled,
+    Cancelled,
+    Rejected,
+}
+
+/// An order as tracked by the exchange, returned by every [`ExchangeBackend`] call.
+#[derive(Debug, Clone)]
+pub struct Order {
+    pub id: String,
+    pub symbol: String,
+    pub side: Side,
+    pub order_type: OrderType,
+    pub quantity: f64,
+    pub price: Option<f64>,
+    pub status: FillStatus,
+}
+
+/// A new order request, validated against a [`Market`]'s [`Precision`]/[`MinQuantity`] by
+/// [`NewOrderBuilder::build`] before it's ever sent to an [`ExchangeBackend`].
+#[derive(Debug, Clone)]
+pub struct NewOrderBuilder {
+    symbol: String,
+    side: Side,
+    quantity: f64,
+    price: Option<f64>,
+    order_type: OrderType,
+}
+
+impl NewOrderBuilder {
+    pub fn new(symbol: impl Into<String>, side: Side, quantity: f64) -> Self {
+        Self {
+            symbol: symbol.into(),
+            side,
+            quantity,
+            price: None,
+            order_type: OrderType::Market,
+        }
+    }
+
+    /// Setting a price makes this a limit order; omitting it leaves it a market order.
+    pub fn price(mut self, price: f64) -> Self {
+        self.price = Some(price);
+        self.order_type = OrderType::Limit;
+        self
+    }
+
+    pub fn order_type(mut self, order_type: OrderType) -> Self {
+        self.order_type = order_type;
+        self
+    }
+
+    fn round_to(value: f64, decimals: u32) -> f64 {
+        let factor = 10f64.powi(decimals as i32);
+        (value * factor).round() / factor
+    }
+
+    /// Round `quantity`/`price` to `market`'s [`Precision`], then validate the rounded values
+    /// against `market`'s [`MinQuantity`]. Fails if this is a limit order missing a price, if the
+    /// rounded quantity is below the exchange's base minimum, or, when a price is given, if the
+    /// rounded `price * quantity` notional is below the exchange's quote minimum.
+    pub fn build(self, market: &Market) -> Result<NewOrder, String> {
+        if self.order_type == OrderType::Limit && self.price.is_none() {
+            return Err("limit orders require a price".to_string());
+        }
+
+        let quantity = Self::round_to(self.quantity, market.precision.base);
+        if quantity < market.min_quantity.base {
+            return Err(format!(
+                "quantity {quantity} is below the exchange minimum of {}",
+                market.min_quantity.base
+            ));
+        }
+
+        let price = self.price.map(|p| Self::round_to(p, market.precision.price));
+        if let Some(price) = price {
+            let notional = price * quantity;
+            if notional < market.min_quantity.quote {
+                return Err(format!(
+                    "order notional {notional} is below the exchange minimum of {}",
+                    market.min_quantity.quote
+                ));
+            }
+        }
+
+        Ok(NewOrder {
+            symbol: self.symbol,
+            side: self.side,
+            order_type: self.order_type,
+            quantity,
+            price,
+        })
+    }
+}
+
+/// A [`NewOrderBuilder`] request that has passed [`NewOrderBuilder::build`]'s validation and
+/// is ready to submit via [`ExchangeBackend::place_order`].
+#[derive(Debug, Clone)]
+pub struct NewOrder {
+    pub symbol: String,
+    pub side: Side,
+    pub order_type: OrderType,
+    pub quantity: f64,
+    pub price: Option<f64>,
+}
+
+/// An exchange's order-management API, behind a trait so [`TradingClient`] can execute
+/// against whichever backend it's configured with.
+#[async_trait]
+pub trait ExchangeBackend: Send + Sync {
+    async fn place_order(&self, order: NewOrder) -> Result<Order, String>;
+    async fn get_order(&self, order_id: &str) -> Result<Order, String>;
+    async fn cancel_order(&self, order_id: &str) -> Result<Order, String>;
+}
+
+/// Order-placement client for a single set of exchange API credentials, executing against
+/// whichever [`ExchangeBackend`] it's constructed with.
+pub struct TradingClient {
+    api_key: String,
+    api_secret: String,
+    backend: Box<dyn ExchangeBackend>,
+}
+
+impl TradingClient {
+    pub fn new(api_key: impl Into<String>, api_secret: impl Into<String>, backend: Box<dyn ExchangeBackend>) -> Self {
+        Self { api_key: api_key.into(), api_secret: api_secret.into(), backend }
+    }
+
+    pub fn api_key(&self) -> &str {
+        &self.api_key
+    }
+
+    pub async fn place_order(&self, order: NewOrder) -> Result<Order, String> {
+        self.backend.place_order(order).await
+    }
+
+    pub async fn get_order(&self, order_id: &str) -> Result<Order, String> {
+        self.backend.get_order(order_id).await
+    }
+
+    pub async fn cancel_order(&self, order_id: &str) -> Result<Order, String> {
+        self.backend.cancel_order(order_id).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::market::instrument::{Fees, MarketType};
+
+    fn sample_market() -> Market {
+        Market {
+            exchange: "kraken".to_string(),
+            market_type: MarketType::Spot,
+            symbol: "XBT/USDT".to_string(),
+            pair: "BTC_USDT".to_string(),
+            base: "BTC".to_string(),
+            quote: "USDT".to_string(),
+            base_id: "xbt".to_string(),
+            quote_id: "usdt".to_string(),
+            active: true,
+            margin: false,
+            precision: Precision { price: 1, base: 4, quote: 2 },
+            fees: Fees { maker: 0.0016, taker: 0.0026 },
+            min_quantity: MinQuantity { base: 0.001, quote: 10.0 },
+        }
+    }
+
+    #[test]
+    fn build_rounds_to_market_precision() {
+        let order = NewOrderBuilder::new("BTC_USDT", Side::Bid, 0.123456)
+            .price(50000.12345)
+            .build(&sample_market())
+            .unwrap();
+
+        assert_eq!(order.quantity, 0.1235);
+        assert_eq!(order.price, Some(50000.1));
+    }
+
+    #[test]
+    fn build_rejects_quantity_below_minimum() {
+        let err = NewOrderBuilder::new("BTC_USDT", Side::Bid, 0.0001)
+            .price(50000.0)
+            .build(&sample_market())
+            .unwrap_err();
+        assert!(err.contains("below the exchange minimum"));
+    }
+
+    #[test]
+    fn build_rejects_limit_order_without_price() {
+        let err = NewOrderBuilder::new("BTC_USDT", Side::Ask, 1.0)
+            .order_type(OrderType::Limit)
+            .build(&sample_market())
+            .unwrap_err();
+        assert!(err.contains("require a price"));
+    }
+
+    #[test]
+    fn build_defaults_to_market_order_without_a_price() {
+        let order = NewOrderBuilder::new("BTC_USDT", Side::Bid, 1.0)
+            .build(&sample_market())
+            .unwrap();
+        assert_eq!(order.order_type, OrderType::Market);
+        assert_eq!(order.price, None);
+    }
+}
@@ -0,0 +1,213 @@
+//! Latency/outcome instrumentation for outbound data-source fetches (Finviz, Reddit, Alpaca,
+//! Kraken, Yahoo, ...), as opposed to [`crate::monitoring::PrometheusMetrics`], which covers
+//! inbound HTTP-route latency. Generalizes the one-off millisecond math
+//! `helpers::news::benchmark_news_performance` used to do into continuous, per-source
+//! observability: every instrumented call records its latency and outcome (ok/timeout/error)
+//! here, and [`snapshot`] reports the aggregated percentiles and timeout rate operators need to
+//! tune per-service timeout budgets (see `helpers::news::news_fetch`).
+
+use std::collections::HashMap;
+use std::future::Future;
+use std::time::{Duration, Instant};
+
+use serde::Serialize;
+use tokio::sync::RwLock;
+
+/// How a single timed source call resolved. Tracked as its own dimension (rather than folded
+/// into a generic error) so a source's timeout rate -- the thing that actually drives timeout
+/// tuning -- is visible without parsing error message text.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Outcome {
+    Ok,
+    Timeout,
+    Error,
+}
+
+/// Upper bounds (seconds) for [`Histogram`]'s fixed buckets, matching
+/// [`crate::monitoring`]'s HTTP-route buckets -- data-source fetches range from sub-second
+/// cache hits up to the ~10s per-service timeout budgets `helpers::news::news_fetch` configures,
+/// so the same boundaries apply here.
+const LATENCY_BUCKET_BOUNDS: [f64; 11] = [0.005, 0.01, 0.025, 0.05, 0.1, 0.25, 0.5, 1.0, 2.5, 5.0, 10.0];
+
+/// A fixed-bucket latency histogram tracking count/min/max plus approximate p50/p90/p99,
+/// replacing the ad hoc sequential-vs-parallel timing `helpers::news::benchmark_news_performance`
+/// used to do. Percentiles are approximated by linear interpolation within the bucket containing
+/// the target rank -- cheap and bounded-memory, at the cost of exactness versus keeping every
+/// raw sample.
+#[derive(Debug, Clone)]
+struct Histogram {
+    bucket_counts: [u64; LATENCY_BUCKET_BOUNDS.len()],
+    count: u64,
+    min: f64,
+    max: f64,
+}
+
+impl Histogram {
+    fn new() -> Self {
+        Self {
+            bucket_counts: [0; LATENCY_BUCKET_BOUNDS.len()],
+            count: 0,
+            min: f64::INFINITY,
+            max: 0.0,
+        }
+    }
+
+    fn observe(&mut self, seconds: f64) {
+        if let Some(i) = LATENCY_BUCKET_BOUNDS.iter().position(|bound| seconds <= *bound) {
+            self.bucket_counts[i] += 1;
+        }
+        self.count += 1;
+        self.min = self.min.min(seconds);
+        self.max = self.max.max(seconds);
+    }
+
+    /// Approximates the `p`th percentile (e.g. `0.9` for p90) by walking the cumulative bucket
+    /// counts to find the bucket containing the target rank, then interpolating linearly
+    /// between that bucket's lower and upper bound.
+    fn percentile(&self, p: f64) -> f64 {
+        if self.count == 0 {
+            return 0.0;
+        }
+
+        let target = p * self.count as f64;
+        let mut cumulative_before = 0.0;
+        let mut lower_bound = 0.0;
+        for (i, &bucket_count) in self.bucket_counts.iter().enumerate() {
+            let cumulative_after = cumulative_before + bucket_count as f64;
+            let upper_bound = LATENCY_BUCKET_BOUNDS[i];
+            if target <= cumulative_after {
+                if bucket_count == 0 {
+                    return upper_bound;
+                }
+                let fraction = (target - cumulative_before) / bucket_count as f64;
+                return lower_bound + fraction * (upper_bound - lower_bound);
+            }
+            cumulative_before = cumulative_after;
+            lower_bound = upper_bound;
+        }
+
+        // Past the last finite bucket (an implicit "+Inf" bucket, in Prometheus terms).
+        self.max
+    }
+
+    /// Folds `others` into a new histogram with summed bucket counts and the overall min/max --
+    /// used to merge a source's separate ok/timeout/error histograms into one set of
+    /// percentiles for [`snapshot`].
+    fn merge<'a>(histograms: impl Iterator<Item = &'a Histogram>) -> Histogram {
+        let mut merged = Histogram::new();
+        for h in histograms {
+            for (i, count) in h.bucket_counts.iter().enumerate() {
+                merged.bucket_counts[i] += count;
+            }
+            merged.count += h.count;
+            merged.min = merged.min.min(h.min);
+            merged.max = merged.max.max(h.max);
+        }
+        merged
+    }
+}
+
+lazy_static::lazy_static! {
+    static ref SOURCE_LATENCY: RwLock<HashMap<(String, Outcome), Histogram>> = RwLock::new(HashMap::new());
+}
+
+async fn record(source: &str, outcome: Outcome, duration: Duration) {
+    SOURCE_LATENCY
+        .write()
+        .await
+        .entry((source.to_string(), outcome))
+        .or_insert_with(Histogram::new)
+        .observe(duration.as_secs_f64());
+}
+
+/// Times `fut` against `per_call_timeout`, recording its outcome (ok/timeout/error) and latency
+/// against `source`, then returns the original result unchanged -- a drop-in wrapper so
+/// instrumenting a call doesn't require threading a metrics handle through every source module.
+/// Mirrors the timeout-then-classify logic
+/// [`crate::helpers::resilient_fetch::ResilientFetch::fetch_one`] uses, generalized so Kraken's
+/// and Yahoo's fetches can share it too.
+pub async fn observe<F, T, E>(source: impl AsRef<str>, per_call_timeout: Duration, fut: F) -> Result<T, E>
+where
+    F: Future<Output = Result<T, E>>,
+    E: From<String>,
+{
+    let start = Instant::now();
+    let outcome = tokio::time::timeout(per_call_timeout, fut).await;
+    let elapsed = start.elapsed();
+
+    match outcome {
+        Ok(Ok(value)) => {
+            record(source.as_ref(), Outcome::Ok, elapsed).await;
+            Ok(value)
+        }
+        Ok(Err(e)) => {
+            record(source.as_ref(), Outcome::Error, elapsed).await;
+            Err(e)
+        }
+        Err(_) => {
+            record(source.as_ref(), Outcome::Timeout, elapsed).await;
+            Err(E::from(format!("{} timed out after {per_call_timeout:?}", source.as_ref())))
+        }
+    }
+}
+
+/// One source's aggregated latency/outcome stats across every call recorded via [`observe`],
+/// merging its ok/timeout/error histograms so a source's p99 and timeout rate are both visible
+/// at a glance.
+#[derive(Debug, Clone, Serialize, utoipa::ToSchema)]
+pub struct SourceLatencySnapshot {
+    pub source: String,
+    pub ok_count: u64,
+    pub timeout_count: u64,
+    pub error_count: u64,
+    /// `timeout_count / (ok_count + timeout_count + error_count)`, or 0 if nothing recorded yet.
+    pub timeout_rate: f64,
+    pub p50_ms: f64,
+    pub p90_ms: f64,
+    pub p99_ms: f64,
+    pub min_ms: f64,
+    pub max_ms: f64,
+}
+
+/// Snapshot every instrumented source's aggregated stats, sorted by name for stable output.
+pub async fn snapshot() -> Vec<SourceLatencySnapshot> {
+    let histograms = SOURCE_LATENCY.read().await;
+
+    let mut by_source: HashMap<&str, Vec<(&Outcome, &Histogram)>> = HashMap::new();
+    for ((source, outcome), histogram) in histograms.iter() {
+        by_source.entry(source.as_str()).or_default().push((outcome, histogram));
+    }
+
+    let count_for = |entries: &[(&Outcome, &Histogram)], target: Outcome| -> u64 {
+        entries.iter().filter(|(o, _)| **o == target).map(|(_, h)| h.count).sum()
+    };
+
+    let mut out: Vec<SourceLatencySnapshot> = by_source
+        .into_iter()
+        .map(|(source, entries)| {
+            let ok_count = count_for(&entries, Outcome::Ok);
+            let timeout_count = count_for(&entries, Outcome::Timeout);
+            let error_count = count_for(&entries, Outcome::Error);
+            let total = ok_count + timeout_count + error_count;
+
+            let merged = Histogram::merge(entries.iter().map(|(_, h)| *h));
+
+            SourceLatencySnapshot {
+                source: source.to_string(),
+                ok_count,
+                timeout_count,
+                error_count,
+                timeout_rate: if total == 0 { 0.0 } else { timeout_count as f64 / total as f64 },
+                p50_ms: merged.percentile(0.50) * 1000.0,
+                p90_ms: merged.percentile(0.90) * 1000.0,
+                p99_ms: merged.percentile(0.99) * 1000.0,
+                min_ms: if merged.count == 0 { 0.0 } else { merged.min * 1000.0 },
+                max_ms: merged.max * 1000.0,
+            }
+        })
+        .collect();
+
+    out.sort_by(|a, b| a.source.cmp(&b.source));
+    out
+}
@@ -1,8 +1,11 @@
+pub mod redis_rate_limit;
+
 use axum::{
-    extract::Request,
-    http::{HeaderValue, StatusCode},
+    extract::{Request, State},
+    http::{HeaderMap, HeaderValue, StatusCode},
     middleware::Next,
-    response::Response,
+    response::{IntoResponse, Response},
+    Json,
 };
 use std::collections::HashMap;
 use std::sync::Arc;
@@ -11,6 +14,7 @@ use std::time::{Duration, Instant};
 use serde_json::json;
 
 use crate::errors::ApiError;
+use crate::state::AppState;
 
 #[derive(Debug, Clone)]
 pub struct RateLimitConfig {
@@ -27,79 +31,177 @@ impl Default for RateLimitConfig {
     }
 }
 
+/// A single client's token bucket for one `(client_id, route)` key: `tokens` refills toward
+/// `burst_size` at `requests_per_minute / 60` tokens/sec, capped at capacity, and every allowed
+/// request consumes one token.
 #[derive(Debug)]
-struct RateLimitState {
-    requests: Vec<Instant>,
-    _last_cleanup: Instant,
+struct TokenBucket {
+    tokens: f64,
+    last_refill: Instant,
 }
 
-impl RateLimitState {
-    fn new() -> Self {
+impl TokenBucket {
+    fn new(capacity: f64) -> Self {
         Self {
-            requests: Vec::new(),
-            _last_cleanup: Instant::now(),
+            tokens: capacity,
+            last_refill: Instant::now(),
         }
     }
 
-    fn cleanup_old_requests(&mut self) {
-        let cutoff = Instant::now() - Duration::from_secs(60);
-        self.requests.retain(|&time| time > cutoff);
+    fn refill(&mut self, capacity: f64, refill_per_sec: f64) {
+        let now = Instant::now();
+        let elapsed = now.duration_since(self.last_refill).as_secs_f64();
+        self.tokens = (self.tokens + elapsed * refill_per_sec).min(capacity);
+        self.last_refill = now;
     }
+}
 
-    fn can_make_request(&mut self, config: &RateLimitConfig) -> bool {
-        self.cleanup_old_requests();
-        
-        if self.requests.len() >= config.requests_per_minute as usize {
-            return false;
-        }
-        
-        self.requests.push(Instant::now());
-        true
-    }
+/// The outcome of an allowed request: the caller's configured limit, tokens remaining after this
+/// request was counted, and how many seconds until the bucket refills to capacity - surfaced as
+/// `X-RateLimit-Limit`/`X-RateLimit-Remaining`/`X-RateLimit-Reset`.
+#[derive(Debug, Clone, Copy)]
+pub struct RateLimitDecision {
+    pub limit: u32,
+    pub remaining: u32,
+    pub reset_seconds: u64,
 }
 
+/// Shared token-bucket rate limiter held in [`AppState`] (or constructed standalone, e.g. by
+/// [`crate::services::alerting::AlertDispatcher`]'s alert debounce) so client state actually
+/// persists across requests instead of being thrown away per call. `per_route` overrides
+/// `default_config` for any request path starting with a configured prefix (longest prefix
+/// wins), so expensive endpoints can be throttled harder than cheap ones.
 pub struct RateLimiter {
-    clients: Arc<RwLock<HashMap<String, RateLimitState>>>,
-    config: RateLimitConfig,
+    buckets: Arc<RwLock<HashMap<String, TokenBucket>>>,
+    default_config: RateLimitConfig,
+    per_route: HashMap<String, RateLimitConfig>,
 }
 
+/// Once `buckets` reaches this many tracked `(client_id, route)` keys, [`RateLimiter::check`]
+/// sweeps idle entries before inserting a new one, so a caller who can't be pinned to a stable
+/// identity (e.g. an unrecognized bearer token falling back to IP, or many distinct IPs) can't
+/// grow the map without bound.
+const MAX_TRACKED_BUCKETS: usize = 50_000;
+/// How long a bucket can sit untouched before an eviction sweep can reclaim it.
+const BUCKET_IDLE_TTL: Duration = Duration::from_secs(600);
+
 impl RateLimiter {
     pub fn new(config: RateLimitConfig) -> Self {
+        Self::with_route_overrides(config, HashMap::new())
+    }
+
+    pub fn with_route_overrides(config: RateLimitConfig, per_route: HashMap<String, RateLimitConfig>) -> Self {
         Self {
-            clients: Arc::new(RwLock::new(HashMap::new())),
-            config,
+            buckets: Arc::new(RwLock::new(HashMap::new())),
+            default_config: config,
+            per_route,
         }
     }
 
-    pub async fn check_rate_limit(&self, client_id: &str) -> Result<(), ApiError> {
-        let mut clients = self.clients.write().await;
-        
-        let state = clients.entry(client_id.to_string()).or_insert_with(RateLimitState::new);
-        
-        if state.can_make_request(&self.config) {
-            Ok(())
+    fn config_for_route(&self, route: &str) -> &RateLimitConfig {
+        self.per_route
+            .iter()
+            .filter(|(prefix, _)| route.starts_with(prefix.as_str()))
+            .max_by_key(|(prefix, _)| prefix.len())
+            .map(|(_, config)| config)
+            .unwrap_or(&self.default_config)
+    }
+
+    /// Refills and attempts to consume one token from `client_id`'s bucket for `route`. On
+    /// success returns the post-request bucket state; on rejection returns the number of seconds
+    /// the caller should wait before retrying (`Retry-After`).
+    pub async fn check(&self, client_id: &str, route: &str) -> Result<RateLimitDecision, u64> {
+        let config = self.config_for_route(route);
+        let capacity = config.burst_size as f64;
+        let refill_per_sec = config.requests_per_minute as f64 / 60.0;
+        let key = format!("{client_id}:{route}");
+
+        let mut buckets = self.buckets.write().await;
+        if buckets.len() >= MAX_TRACKED_BUCKETS && !buckets.contains_key(&key) {
+            let now = Instant::now();
+            buckets.retain(|_, bucket| now.duration_since(bucket.last_refill) < BUCKET_IDLE_TTL);
+        }
+        let bucket = buckets.entry(key).or_insert_with(|| TokenBucket::new(capacity));
+        bucket.refill(capacity, refill_per_sec);
+
+        if bucket.tokens >= 1.0 {
+            bucket.tokens -= 1.0;
+            let reset_seconds = if refill_per_sec > 0.0 {
+                ((capacity - bucket.tokens) / refill_per_sec).ceil() as u64
+            } else {
+                0
+            };
+            Ok(RateLimitDecision {
+                limit: config.burst_size,
+                remaining: bucket.tokens.floor() as u32,
+                reset_seconds,
+            })
         } else {
-            Err(ApiError::RateLimit("Rate limit exceeded".to_string()))
+            let retry_after = if refill_per_sec > 0.0 {
+                ((1.0 - bucket.tokens) / refill_per_sec).ceil() as u64
+            } else {
+                60
+            };
+            Err(retry_after)
         }
     }
+
+    /// Single-key convenience wrapper over [`Self::check`] for callers with no request path to
+    /// key on (e.g. the alert-debounce use in [`crate::services::alerting::AlertDispatcher`]).
+    pub async fn check_rate_limit(&self, client_id: &str) -> Result<(), ApiError> {
+        self.check(client_id, "")
+            .await
+            .map(|_| ())
+            .map_err(|_| ApiError::RateLimit("Rate limit exceeded".to_string()))
+    }
 }
 
+fn insert_rate_limit_headers(headers: &mut HeaderMap, decision: RateLimitDecision) {
+    if let Ok(value) = HeaderValue::from_str(&decision.limit.to_string()) {
+        headers.insert("X-RateLimit-Limit", value);
+    }
+    if let Ok(value) = HeaderValue::from_str(&decision.remaining.to_string()) {
+        headers.insert("X-RateLimit-Remaining", value);
+    }
+    if let Ok(value) = HeaderValue::from_str(&decision.reset_seconds.to_string()) {
+        headers.insert("X-RateLimit-Reset", value);
+    }
+}
+
+/// Axum middleware applying [`AppState::rate_limiter`] to every request, keyed by client
+/// identifier and request path so per-route overrides (e.g. Hyperliquid market scans) can be
+/// throttled harder than cheap endpoints. Attaches `X-RateLimit-*` headers on every response and
+/// returns `429` with `Retry-After` once a client's bucket runs dry.
 pub async fn rate_limit_middleware(
+    State(state): State<AppState>,
     request: Request,
     next: Next,
-) -> Result<Response, ApiError> {
-    // Extract client identifier (IP address or API key)
-    let client_id = extract_client_id(&request);
-    
-    // Get rate limiter from extensions (you'll need to add this to your app state)
-    // For now, we'll use a simple approach
-    let rate_limiter = RateLimiter::new(RateLimitConfig::default());
-    
-    // Check rate limit
-    rate_limiter.check_rate_limit(&client_id).await?;
-    
-    // Continue with the request
-    Ok(next.run(request).await)
+) -> Response {
+    if !state.config.rate_limiting.enabled {
+        return next.run(request).await;
+    }
+
+    let client_id = extract_client_id(&request, &state.config.rate_limiting.client_keys);
+    let route = request.uri().path().to_string();
+
+    match state.rate_limiter.check(&client_id, &route).await {
+        Ok(decision) => {
+            let mut response = next.run(request).await;
+            insert_rate_limit_headers(response.headers_mut(), decision);
+            response
+        }
+        Err(retry_after_seconds) => {
+            let mut response = (
+                StatusCode::TOO_MANY_REQUESTS,
+                Json(json!({ "error": "rate limit exceeded" })),
+            )
+                .into_response();
+            if let Ok(value) = HeaderValue::from_str(&retry_after_seconds.to_string()) {
+                response.headers_mut().insert("Retry-After", value);
+            }
+            response
+        }
+    }
 }
 
 pub async fn error_handling_middleware(
@@ -152,16 +254,121 @@ pub async fn cors_middleware(
     response
 }
 
-fn extract_client_id(request: &Request) -> String {
-    // Try to get API key from headers first
+/// Records per-route request counters, an in-flight gauge, and latency histograms into
+/// `state.prometheus_metrics`, and attributes 5xx responses to a known upstream data source
+/// (Finviz/Kraken/CoinGecko/Alpaca) by route prefix. A no-op passthrough when
+/// `METRICS_ENABLED=false`. Takes `State<AppState>` like `rate_limit_middleware` and
+/// [`crate::middleware::redis_rate_limit::DeferredRateLimiter`]'s middleware.
+pub async fn metrics_middleware(State(state): State<AppState>, request: Request, next: Next) -> Response {
+    if !state.config.metrics.enabled {
+        return next.run(request).await;
+    }
+
+    let method = request.method().to_string();
+    let route = request.uri().path().to_string();
+
+    state.prometheus_metrics.in_flight_inc();
+    let start = Instant::now();
+    let response = next.run(request).await;
+    let elapsed = start.elapsed();
+    state.prometheus_metrics.in_flight_dec();
+
+    let status = response.status();
+    state.prometheus_metrics.record_request(&method, &route, status.as_u16(), elapsed).await;
+
+    if status.is_server_error() {
+        if let Some(source) = classify_upstream_source(&route) {
+            state.prometheus_metrics.record_upstream_error(source).await;
+        }
+    }
+
+    response
+}
+
+/// Maps a request path to the upstream data source most likely responsible for a 5xx on that
+/// route, for `upstream_source_errors_total`. Routes that don't proxy a single named upstream
+/// (health checks, Solana, Hyperliquid, Jito, options-pricing math) are left unattributed.
+fn classify_upstream_source(path: &str) -> Option<&'static str> {
+    if path.starts_with("/kraken") {
+        Some("kraken")
+    } else if path.starts_with("/coingecko") || path.starts_with("/trending/crypto") {
+        Some("coingecko")
+    } else if path.starts_with("/forex")
+        || path.starts_with("/crypto")
+        || path.starts_with("/future")
+        || path.starts_with("/insider")
+        || path.starts_with("/group")
+        || path.starts_with("/screener")
+        || path.starts_with("/recommendations/finviz")
+        || path.starts_with("/reddit")
+        || path.starts_with("/trending/stocks")
+    {
+        Some("finviz")
+    } else if path.starts_with("/options") || path.starts_with("/high-open-interest") || path.starts_with("/trending-options") {
+        Some("alpaca")
+    } else {
+        None
+    }
+}
+
+/// Gates a route behind a configured `TRADING_API_KEY` (see [`crate::config::TradingAuthConfig`]),
+/// for endpoints like `POST /options/orders` that place real orders against the operator's own
+/// Alpaca account -- unlike [`rate_limit_middleware`]'s `extract_client_id`, which only uses the
+/// bearer token as an identity *key* and never verifies it, this compares it against a known
+/// secret and rejects the request outright on mismatch or missing configuration. Applied as a
+/// per-route layer (not globally) since most routes don't move money and shouldn't require a key.
+pub async fn require_trading_api_key(
+    State(state): State<AppState>,
+    request: Request,
+    next: Next,
+) -> Response {
+    let Some(configured_key) = state.config.trading_auth.api_key.as_ref() else {
+        return crate::errors::ApiError::Configuration(
+            "TRADING_API_KEY is not configured; trading routes are disabled".to_string(),
+        )
+        .into_response();
+    };
+
+    let provided = request
+        .headers()
+        .get("Authorization")
+        .and_then(|v| v.to_str().ok())
+        .and_then(|v| v.strip_prefix("Bearer "));
+
+    match provided {
+        Some(token) if constant_time_eq(token.as_bytes(), configured_key.as_bytes()) => {
+            next.run(request).await
+        }
+        _ => crate::errors::ApiError::AuthError("missing or invalid trading API key".to_string())
+            .into_response(),
+    }
+}
+
+/// Length-revealing but timing-safe-on-contents byte comparison, so a valid key can't be
+/// recovered by timing how far a guess gets into it before the comparison short-circuits.
+fn constant_time_eq(a: &[u8], b: &[u8]) -> bool {
+    if a.len() != b.len() {
+        return false;
+    }
+    a.iter().zip(b.iter()).fold(0u8, |acc, (x, y)| acc | (x ^ y)) == 0
+}
+
+/// Resolves the identity [`RateLimiter::check`] keys a bucket on. Only a bearer token present
+/// in `known_keys` (`RATE_LIMIT_CLIENT_KEYS`) is trusted as a distinct identity; an unrecognized
+/// token is never used as-is, since that would let a caller bypass per-client limiting (and grow
+/// `RateLimiter::buckets` without bound) just by sending a different random token on every
+/// request. Everything else falls back to IP address, same as before.
+fn extract_client_id(request: &Request, known_keys: &[String]) -> String {
     if let Some(auth_header) = request.headers().get("Authorization") {
         if let Ok(auth_str) = auth_header.to_str() {
             if let Some(stripped) = auth_str.strip_prefix("Bearer ") {
-                return stripped.to_string();
+                if known_keys.iter().any(|key| key == stripped) {
+                    return stripped.to_string();
+                }
             }
         }
     }
-    
+
     // Fall back to IP address
     request
         .extensions()
@@ -170,36 +377,6 @@ fn extract_client_id(request: &Request) -> String {
         .unwrap_or_else(|| "unknown".to_string())
 }
 
-pub async fn retry_middleware<F, Fut, T, E>(
-    mut f: F,
-    max_retries: u32,
-    base_delay: Duration,
-) -> Result<T, E>
-where
-    F: FnMut() -> Fut,
-    Fut: std::future::Future<Output = Result<T, E>>,
-    E: std::fmt::Debug,
-{
-    let mut last_error = None;
-    
-    for attempt in 0..=max_retries {
-        match f().await {
-            Ok(result) => return Ok(result),
-            Err(e) => {
-                last_error = Some(e);
-                
-                if attempt < max_retries {
-                    let delay = base_delay * 2_u32.pow(attempt);
-                    tracing::warn!("Request failed, retrying in {:?}: {:?}", delay, last_error);
-                    tokio::time::sleep(delay).await;
-                }
-            }
-        }
-    }
-    
-    Err(last_error.unwrap())
-}
-
 pub fn create_error_response(status: StatusCode, message: &str) -> Response {
     let body = json!({
         "error": message,
@@ -0,0 +1,183 @@
+use std::collections::HashMap;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+use axum::{
+    extract::{ConnectInfo, Request, State},
+    http::{HeaderValue, StatusCode},
+    middleware::Next,
+    response::{IntoResponse, Response},
+    Json,
+};
+use serde_json::json;
+use tokio::sync::RwLock;
+
+use crate::state::AppState;
+
+#[derive(Debug, Clone)]
+pub struct DeferredRateLimitConfig {
+    /// `redis://...` URL used to share limits across instances; `None` runs local-only.
+    pub redis_url: Option<String>,
+    /// Default requests/minute for routes without a per-route override.
+    pub default_requests_per_minute: u32,
+    /// Per-route requests/minute, keyed by request path.
+    pub per_route_requests_per_minute: HashMap<String, u32>,
+    /// How many requests a client may make against the fast local counter before
+    /// this layer defers to the shared Redis counter for an authoritative check.
+    pub local_fast_path_threshold: u32,
+}
+
+impl Default for DeferredRateLimitConfig {
+    fn default() -> Self {
+        Self {
+            redis_url: None,
+            default_requests_per_minute: 60,
+            per_route_requests_per_minute: HashMap::new(),
+            local_fast_path_threshold: 10,
+        }
+    }
+}
+
+#[derive(Debug, Default)]
+struct LocalWindow {
+    count: u32,
+    window_start: Option<Instant>,
+}
+
+/// Two-tier rate limiter: a local, in-process approximate counter absorbs most
+/// requests without a round trip, and only once a client crosses
+/// `local_fast_path_threshold` within the current minute does it fall through to
+/// a Redis `INCR`/`EXPIRE` counter shared across instances for the authoritative
+/// decision. This keeps steady, well-behaved traffic cheap while still enforcing
+/// a real shared limit once a client starts pushing past it.
+pub struct DeferredRateLimiter {
+    config: DeferredRateLimitConfig,
+    local: RwLock<HashMap<String, LocalWindow>>,
+    redis: Option<redis::Client>,
+}
+
+impl DeferredRateLimiter {
+    pub fn new(config: DeferredRateLimitConfig) -> Self {
+        let redis = config.redis_url.as_deref().and_then(|url| redis::Client::open(url).ok());
+        Self {
+            config,
+            local: RwLock::new(HashMap::new()),
+            redis,
+        }
+    }
+
+    fn limit_for_route(&self, route: &str) -> u32 {
+        self.config.per_route_requests_per_minute
+            .get(route)
+            .copied()
+            .unwrap_or(self.config.default_requests_per_minute)
+    }
+
+    /// Returns `Ok(())` if the request is allowed, or `Err(retry_after)` if the
+    /// caller has exceeded its limit for this route.
+    pub async fn check(&self, client_id: &str, route: &str) -> Result<(), Duration> {
+        let limit = self.limit_for_route(route);
+        let key = format!("{client_id}:{route}");
+
+        let local_count = {
+            let mut windows = self.local.write().await;
+            let window = windows.entry(key.clone()).or_default();
+            let now = Instant::now();
+            let expired = window.window_start
+                .map(|start| now.duration_since(start) >= Duration::from_secs(60))
+                .unwrap_or(true);
+            if expired {
+                window.window_start = Some(now);
+                window.count = 0;
+            }
+            window.count += 1;
+            window.count
+        };
+
+        // Fast path: well under the local threshold, and under the route limit -
+        // allow without ever talking to Redis.
+        if local_count <= self.config.local_fast_path_threshold && local_count <= limit {
+            return Ok(());
+        }
+
+        match self.check_redis(&key, limit).await {
+            Some(allowed) => {
+                if allowed {
+                    Ok(())
+                } else {
+                    Err(Duration::from_secs(60))
+                }
+            }
+            // No Redis configured (or it's unreachable) - fall back to the local count.
+            None => {
+                if local_count <= limit {
+                    Ok(())
+                } else {
+                    Err(Duration::from_secs(60))
+                }
+            }
+        }
+    }
+
+    /// Returns `Some(true/false)` for an authoritative Redis-backed decision, or
+    /// `None` if Redis isn't configured or the call failed.
+    async fn check_redis(&self, key: &str, limit: u32) -> Option<bool> {
+        let client = self.redis.as_ref()?;
+        let mut conn = client.get_multiplexed_async_connection().await.ok()?;
+
+        let count: u32 = redis::cmd("INCR")
+            .arg(key)
+            .query_async(&mut conn)
+            .await
+            .ok()?;
+
+        if count == 1 {
+            let _: Result<(), _> = redis::cmd("EXPIRE")
+                .arg(key)
+                .arg(60)
+                .query_async(&mut conn)
+                .await;
+        }
+
+        Some(count <= limit)
+    }
+}
+
+/// Axum middleware that applies [`DeferredRateLimiter`] to the request's path,
+/// keyed by the bearer token from the `Authorization` header when present,
+/// falling back to the client IP otherwise. Returns `429` with `Retry-After` on limit exceed.
+pub async fn deferred_rate_limit_middleware(
+    State(state): State<AppState>,
+    ConnectInfo(addr): ConnectInfo<std::net::SocketAddr>,
+    request: Request,
+    next: Next,
+) -> Response {
+    let client_id = request
+        .headers()
+        .get("Authorization")
+        .and_then(|v| v.to_str().ok())
+        .and_then(|v| v.strip_prefix("Bearer "))
+        .map(|token| token.to_string())
+        .unwrap_or_else(|| addr.ip().to_string());
+
+    let route = request.uri().path().to_string();
+
+    match state.data_rate_limiter.check(&client_id, &route).await {
+        Ok(()) => next.run(request).await,
+        Err(retry_after) => {
+            let mut response = (
+                StatusCode::TOO_MANY_REQUESTS,
+                Json(json!({
+                    "error": "rate limit exceeded",
+                    "retry_after_seconds": retry_after.as_secs(),
+                })),
+            ).into_response();
+            if let Ok(value) = HeaderValue::from_str(&retry_after.as_secs().to_string()) {
+                response.headers_mut().insert("Retry-After", value);
+            }
+            response
+        }
+    }
+}
+
+pub type SharedDeferredRateLimiter = Arc<DeferredRateLimiter>;
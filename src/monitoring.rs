@@ -1,4 +1,4 @@
-use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::atomic::{AtomicI64, AtomicU64, Ordering};
 use std::sync::Arc;
 use std::time::{Duration, Instant};
 use tokio::sync::RwLock;
@@ -11,6 +11,9 @@ pub struct ApiMetrics {
     pub successful_requests: u64,
     pub failed_requests: u64,
     pub average_response_time: f64,
+    pub p50_response_time: f64,
+    pub p95_response_time: f64,
+    pub p99_response_time: f64,
     pub endpoint_stats: HashMap<String, EndpointStats>,
     pub uptime_seconds: u64,
 }
@@ -19,16 +22,145 @@ pub struct ApiMetrics {
 pub struct EndpointStats {
     pub requests: u64,
     pub avg_response_time: f64,
+    pub p50_response_time: f64,
+    pub p95_response_time: f64,
+    pub p99_response_time: f64,
     pub success_rate: f64,
     pub last_error: Option<String>,
 }
 
+/// Streaming p50/p95/p99 estimate over a latency stream, using Jain & Chlamtac's P²
+/// ("piecewise-parabolic") algorithm: after the first 5 samples seed the five markers,
+/// each further sample updates them in O(1) time and O(1) memory, so per-endpoint and
+/// global latency percentiles stay cheap to track indefinitely instead of requiring an
+/// unbounded (or sample-windowed) history of raw observations.
+#[derive(Debug, Clone)]
+struct LatencyPercentiles {
+    p50: P2Quantile,
+    p95: P2Quantile,
+    p99: P2Quantile,
+}
+
+impl LatencyPercentiles {
+    fn new() -> Self {
+        Self {
+            p50: P2Quantile::new(0.50),
+            p95: P2Quantile::new(0.95),
+            p99: P2Quantile::new(0.99),
+        }
+    }
+
+    fn observe(&mut self, sample: f64) {
+        self.p50.observe(sample);
+        self.p95.observe(sample);
+        self.p99.observe(sample);
+    }
+
+    fn snapshot(&self) -> (f64, f64, f64) {
+        (self.p50.value(), self.p95.value(), self.p99.value())
+    }
+}
+
+/// A single quantile tracked via the P² algorithm: five markers (min, three interior
+/// estimates, max) whose heights and positions are nudged toward the target quantile `p`
+/// as each new sample arrives.
+#[derive(Debug, Clone)]
+struct P2Quantile {
+    p: f64,
+    count: usize,
+    /// Marker heights. Only `q[..count]` is meaningful until `count >= 5`.
+    q: [f64; 5],
+    /// Actual marker positions.
+    n: [f64; 5],
+    /// Desired (fractional) marker positions.
+    ns: [f64; 5],
+    /// Per-observation increment to each desired position.
+    dns: [f64; 5],
+}
+
+impl P2Quantile {
+    fn new(p: f64) -> Self {
+        Self {
+            p,
+            count: 0,
+            q: [0.0; 5],
+            n: [1.0, 2.0, 3.0, 4.0, 5.0],
+            ns: [1.0, 1.0 + 2.0 * p, 1.0 + 4.0 * p, 3.0 + 2.0 * p, 5.0],
+            dns: [0.0, p / 2.0, p, (1.0 + p) / 2.0, 1.0],
+        }
+    }
+
+    fn observe(&mut self, x: f64) {
+        if self.count < 5 {
+            self.q[self.count] = x;
+            self.count += 1;
+            if self.count == 5 {
+                self.q.sort_by(|a, b| a.partial_cmp(b).unwrap());
+            }
+            return;
+        }
+
+        let k = if x < self.q[0] {
+            self.q[0] = x;
+            0
+        } else if x >= self.q[4] {
+            self.q[4] = x;
+            3
+        } else {
+            (0..4).find(|&i| self.q[i] <= x && x < self.q[i + 1]).unwrap_or(3)
+        };
+
+        for n in self.n.iter_mut().skip(k + 1) {
+            *n += 1.0;
+        }
+        for (ns, dns) in self.ns.iter_mut().zip(self.dns.iter()) {
+            *ns += dns;
+        }
+
+        for i in 1..4 {
+            let d = self.ns[i] - self.n[i];
+            let moves_right = d >= 1.0 && self.n[i + 1] - self.n[i] > 1.0;
+            let moves_left = d <= -1.0 && self.n[i - 1] - self.n[i] < -1.0;
+            if moves_right || moves_left {
+                let d = d.signum();
+                let parabolic = self.q[i]
+                    + d / (self.n[i + 1] - self.n[i - 1])
+                        * ((self.n[i] - self.n[i - 1] + d) * (self.q[i + 1] - self.q[i]) / (self.n[i + 1] - self.n[i])
+                            + (self.n[i + 1] - self.n[i] - d) * (self.q[i] - self.q[i - 1]) / (self.n[i] - self.n[i - 1]));
+                self.q[i] = if self.q[i - 1] < parabolic && parabolic < self.q[i + 1] {
+                    parabolic
+                } else {
+                    let j = (i as f64 + d) as usize;
+                    self.q[i] + d * (self.q[j] - self.q[i]) / (self.n[j] - self.n[i])
+                };
+                self.n[i] += d;
+            }
+        }
+    }
+
+    /// The current estimate of the tracked quantile. Exact (via a sort) until the fifth
+    /// sample; a running P² estimate thereafter.
+    fn value(&self) -> f64 {
+        match self.count {
+            0 => 0.0,
+            1..=4 => {
+                let mut sorted = self.q[..self.count].to_vec();
+                sorted.sort_by(|a, b| a.partial_cmp(b).unwrap());
+                let idx = ((self.p * (self.count - 1) as f64).round() as usize).min(self.count - 1);
+                sorted[idx]
+            }
+            _ => self.q[2],
+        }
+    }
+}
+
 pub struct MetricsCollector {
     start_time: Instant,
     total_requests: AtomicU64,
     successful_requests: AtomicU64,
     failed_requests: AtomicU64,
     response_times: Arc<RwLock<Vec<f64>>>,
+    latency_percentiles: Arc<RwLock<LatencyPercentiles>>,
     endpoint_metrics: Arc<RwLock<HashMap<String, EndpointMetrics>>>,
 }
 
@@ -36,6 +168,7 @@ pub struct MetricsCollector {
 struct EndpointMetrics {
     requests: AtomicU64,
     response_times: Vec<f64>,
+    latency_percentiles: LatencyPercentiles,
     errors: u64,
     last_error: Option<String>,
 }
@@ -48,6 +181,7 @@ impl MetricsCollector {
             successful_requests: AtomicU64::new(0),
             failed_requests: AtomicU64::new(0),
             response_times: Arc::new(RwLock::new(Vec::new())),
+            latency_percentiles: Arc::new(RwLock::new(LatencyPercentiles::new())),
             endpoint_metrics: Arc::new(RwLock::new(HashMap::new())),
         }
     }
@@ -62,7 +196,7 @@ impl MetricsCollector {
         }
 
         let response_time_ms = response_time.as_millis() as f64;
-        
+
         // Update global response times
         {
             let mut times = self.response_times.write().await;
@@ -72,6 +206,7 @@ impl MetricsCollector {
                 times.remove(0);
             }
         }
+        self.latency_percentiles.write().await.observe(response_time_ms);
 
         // Update endpoint-specific metrics
         {
@@ -79,13 +214,15 @@ impl MetricsCollector {
             let metrics = endpoint_metrics.entry(endpoint.to_string()).or_insert_with(|| EndpointMetrics {
                 requests: AtomicU64::new(0),
                 response_times: Vec::new(),
+                latency_percentiles: LatencyPercentiles::new(),
                 errors: 0,
                 last_error: None,
             });
 
             metrics.requests.fetch_add(1, Ordering::Relaxed);
             metrics.response_times.push(response_time_ms);
-            
+            metrics.latency_percentiles.observe(response_time_ms);
+
             // Keep only last 100 response times per endpoint
             if metrics.response_times.len() > 100 {
                 metrics.response_times.remove(0);
@@ -112,10 +249,12 @@ impl MetricsCollector {
             }
         };
 
+        let (p50, p95, p99) = self.latency_percentiles.read().await.snapshot();
+
         let endpoint_stats = {
             let metrics = self.endpoint_metrics.read().await;
             let mut stats = HashMap::new();
-            
+
             for (endpoint, metric) in metrics.iter() {
                 let requests = metric.requests.load(Ordering::Relaxed);
                 let avg_time = if metric.response_times.is_empty() {
@@ -128,15 +267,19 @@ impl MetricsCollector {
                 } else {
                     ((requests - metric.errors) as f64 / requests as f64) * 100.0
                 };
+                let (endpoint_p50, endpoint_p95, endpoint_p99) = metric.latency_percentiles.snapshot();
 
                 stats.insert(endpoint.clone(), EndpointStats {
                     requests,
                     avg_response_time: avg_time,
+                    p50_response_time: endpoint_p50,
+                    p95_response_time: endpoint_p95,
+                    p99_response_time: endpoint_p99,
                     success_rate,
                     last_error: metric.last_error.clone(),
                 });
             }
-            
+
             stats
         };
 
@@ -145,23 +288,29 @@ impl MetricsCollector {
             successful_requests: successful,
             failed_requests: failed,
             average_response_time: avg_response_time,
+            p50_response_time: p50,
+            p95_response_time: p95,
+            p99_response_time: p99,
             endpoint_stats,
             uptime_seconds: self.start_time.elapsed().as_secs(),
         }
     }
 
+    /// Reports health based on the p95 latency rather than the mean, since a handful of
+    /// slow outliers can leave the average looking fine while a meaningful fraction of
+    /// requests are actually getting hit with tail latency.
     pub async fn health_check(&self) -> HealthStatus {
         let metrics = self.get_metrics().await;
-        
+
         let success_rate = if metrics.total_requests == 0 {
             100.0
         } else {
             (metrics.successful_requests as f64 / metrics.total_requests as f64) * 100.0
         };
 
-        let status = if success_rate >= 95.0 && metrics.average_response_time < 5000.0 {
+        let status = if success_rate >= 95.0 && metrics.p95_response_time < 5000.0 {
             "healthy"
-        } else if success_rate >= 80.0 && metrics.average_response_time < 10000.0 {
+        } else if success_rate >= 80.0 && metrics.p95_response_time < 10000.0 {
             "degraded"
         } else {
             "unhealthy"
@@ -171,6 +320,7 @@ impl MetricsCollector {
             status: status.to_string(),
             success_rate,
             average_response_time: metrics.average_response_time,
+            p95_response_time: metrics.p95_response_time,
             total_requests: metrics.total_requests,
             uptime_seconds: metrics.uptime_seconds,
         }
@@ -182,6 +332,7 @@ pub struct HealthStatus {
     pub status: String,
     pub success_rate: f64,
     pub average_response_time: f64,
+    pub p95_response_time: f64,
     pub total_requests: u64,
     pub uptime_seconds: u64,
 }
@@ -191,3 +342,292 @@ impl Default for MetricsCollector {
         Self::new()
     }
 }
+
+/// Upper bounds (seconds) for the fixed latency histogram buckets used by [`PrometheusMetrics`],
+/// following the same boundaries Prometheus client libraries default to.
+const LATENCY_BUCKET_BOUNDS: [f64; 11] = [0.005, 0.01, 0.025, 0.05, 0.1, 0.25, 0.5, 1.0, 2.5, 5.0, 10.0];
+
+/// A fixed-bucket latency histogram in the Prometheus convention: `bucket_counts[i]` holds the
+/// count of observations whose value is `<=` `LATENCY_BUCKET_BOUNDS[i]` and `>` the previous
+/// bound; cumulative per-bucket counts are only computed when rendering.
+#[derive(Debug, Clone)]
+struct Histogram {
+    bucket_counts: [u64; LATENCY_BUCKET_BOUNDS.len()],
+    sum: f64,
+    count: u64,
+}
+
+impl Histogram {
+    fn new() -> Self {
+        Self {
+            bucket_counts: [0; LATENCY_BUCKET_BOUNDS.len()],
+            sum: 0.0,
+            count: 0,
+        }
+    }
+
+    fn observe(&mut self, seconds: f64) {
+        if let Some(i) = LATENCY_BUCKET_BOUNDS.iter().position(|bound| seconds <= *bound) {
+            self.bucket_counts[i] += 1;
+        }
+        self.sum += seconds;
+        self.count += 1;
+    }
+
+    /// `(upper bound, cumulative count)` pairs in ascending bound order, plus the caller is
+    /// expected to emit a final `+Inf` bucket equal to `self.count`.
+    fn cumulative_counts(&self) -> Vec<(f64, u64)> {
+        let mut running = 0u64;
+        LATENCY_BUCKET_BOUNDS
+            .iter()
+            .zip(self.bucket_counts.iter())
+            .map(|(bound, count)| {
+                running += count;
+                (*bound, running)
+            })
+            .collect()
+    }
+}
+
+/// Prometheus text-exposition collector for HTTP-layer instrumentation: per-route request
+/// counters, an in-flight gauge, per-route latency histograms, upstream-source error counters,
+/// and retry-attempt counts from [`crate::utils::retry_with_backoff`]. Distinct from
+/// [`MetricsCollector`], which tracks outbound-request percentiles for the `ApiClient` pipeline
+/// rather than inbound-route scrape data.
+pub struct PrometheusMetrics {
+    request_counts: RwLock<HashMap<(String, String, u16), u64>>,
+    in_flight: AtomicI64,
+    route_latency: RwLock<HashMap<String, Histogram>>,
+    upstream_errors: RwLock<HashMap<&'static str, u64>>,
+    retry_attempts: AtomicU64,
+}
+
+impl PrometheusMetrics {
+    pub fn new() -> Self {
+        Self {
+            request_counts: RwLock::new(HashMap::new()),
+            in_flight: AtomicI64::new(0),
+            route_latency: RwLock::new(HashMap::new()),
+            upstream_errors: RwLock::new(HashMap::new()),
+            retry_attempts: AtomicU64::new(0),
+        }
+    }
+
+    /// Records one completed request against its route's counter and latency histogram.
+    pub async fn record_request(&self, method: &str, route: &str, status: u16, duration: Duration) {
+        *self
+            .request_counts
+            .write()
+            .await
+            .entry((method.to_string(), route.to_string(), status))
+            .or_insert(0) += 1;
+
+        self.route_latency
+            .write()
+            .await
+            .entry(route.to_string())
+            .or_insert_with(Histogram::new)
+            .observe(duration.as_secs_f64());
+    }
+
+    pub fn in_flight_inc(&self) {
+        self.in_flight.fetch_add(1, Ordering::Relaxed);
+    }
+
+    pub fn in_flight_dec(&self) {
+        self.in_flight.fetch_sub(1, Ordering::Relaxed);
+    }
+
+    pub fn in_flight(&self) -> i64 {
+        self.in_flight.load(Ordering::Relaxed)
+    }
+
+    /// Total requests recorded across every route/status combination.
+    pub async fn total_requests(&self) -> u64 {
+        self.request_counts.read().await.values().sum()
+    }
+
+    /// Requests recorded with a 5xx status.
+    pub async fn total_errors(&self) -> u64 {
+        self.request_counts
+            .read()
+            .await
+            .iter()
+            .filter(|((_, _, status), _)| *status >= 500)
+            .map(|(_, count)| count)
+            .sum()
+    }
+
+    /// Mean request latency in milliseconds across every route's histogram.
+    pub async fn average_response_time_ms(&self) -> f64 {
+        let histograms = self.route_latency.read().await;
+        let (sum, count) = histograms
+            .values()
+            .fold((0.0, 0u64), |(sum, count), h| (sum + h.sum, count + h.count));
+        if count == 0 {
+            0.0
+        } else {
+            (sum / count as f64) * 1000.0
+        }
+    }
+
+    /// Attributes a 5xx response to one of the known upstream data sources (`finviz`, `kraken`,
+    /// `coingecko`, `alpaca`), so operators can alert on upstream degradation per source.
+    pub async fn record_upstream_error(&self, source: &'static str) {
+        *self.upstream_errors.write().await.entry(source).or_insert(0) += 1;
+    }
+
+    /// Records one retry attempt made by [`crate::utils::retry_with_backoff`].
+    pub fn record_retry_attempt(&self) {
+        self.retry_attempts.fetch_add(1, Ordering::Relaxed);
+    }
+
+    /// Renders everything this collector tracks in Prometheus text exposition format. Cache
+    /// hit/miss gauges live in [`crate::cache::MemoryCache`] itself and are appended by the
+    /// `/metrics` handler rather than here, since this collector has no reference to the cache.
+    pub async fn render(&self) -> String {
+        let mut out = String::new();
+
+        out.push_str("# HELP http_requests_total Total HTTP requests processed, labeled by method, route, and status code.\n");
+        out.push_str("# TYPE http_requests_total counter\n");
+        for ((method, route, status), count) in self.request_counts.read().await.iter() {
+            out.push_str(&format!(
+                "http_requests_total{{method=\"{method}\",route=\"{route}\",status=\"{status}\"}} {count}\n"
+            ));
+        }
+
+        out.push_str("# HELP http_requests_in_flight Number of HTTP requests currently being handled.\n");
+        out.push_str("# TYPE http_requests_in_flight gauge\n");
+        out.push_str(&format!("http_requests_in_flight {}\n", self.in_flight.load(Ordering::Relaxed)));
+
+        out.push_str("# HELP http_request_duration_seconds Latency of HTTP requests, labeled by route.\n");
+        out.push_str("# TYPE http_request_duration_seconds histogram\n");
+        for (route, histogram) in self.route_latency.read().await.iter() {
+            for (bound, cumulative) in histogram.cumulative_counts() {
+                out.push_str(&format!(
+                    "http_request_duration_seconds_bucket{{route=\"{route}\",le=\"{bound}\"}} {cumulative}\n"
+                ));
+            }
+            out.push_str(&format!(
+                "http_request_duration_seconds_bucket{{route=\"{route}\",le=\"+Inf\"}} {}\n",
+                histogram.count
+            ));
+            out.push_str(&format!("http_request_duration_seconds_sum{{route=\"{route}\"}} {}\n", histogram.sum));
+            out.push_str(&format!("http_request_duration_seconds_count{{route=\"{route}\"}} {}\n", histogram.count));
+        }
+
+        out.push_str("# HELP upstream_source_errors_total 5xx responses attributed to a known upstream data source.\n");
+        out.push_str("# TYPE upstream_source_errors_total counter\n");
+        for (source, count) in self.upstream_errors.read().await.iter() {
+            out.push_str(&format!("upstream_source_errors_total{{source=\"{source}\"}} {count}\n"));
+        }
+
+        out.push_str("# HELP retry_attempts_total Retry attempts made by retry_with_backoff, driven by RetryConfig.\n");
+        out.push_str("# TYPE retry_attempts_total counter\n");
+        out.push_str(&format!("retry_attempts_total {}\n", self.retry_attempts.load(Ordering::Relaxed)));
+
+        out
+    }
+}
+
+impl Default for PrometheusMetrics {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Real process/system resource usage for the current process, replacing `/metrics/json`'s
+/// old hard-coded `MemoryUsage` placeholder.
+#[derive(Debug, Clone, Copy, Default, Serialize, Deserialize)]
+pub struct ResourceSnapshot {
+    pub used_mb: u64,
+    pub total_mb: u64,
+    pub percentage: f64,
+    pub process_cpu_percent: f32,
+    pub open_fds: u64,
+    pub thread_count: u64,
+}
+
+/// Caches a `sysinfo::System` handle -- expensive to construct and to fully refresh, since it
+/// walks `/proc` on Linux -- and the last snapshot read from it, so `/metrics` reads a cheap
+/// in-memory value instead of re-querying the OS on every request. A background task calls
+/// [`Self::refresh`] on an interval (see `main.rs`); nothing on the request path calls it.
+pub struct SystemMonitor {
+    system: RwLock<sysinfo::System>,
+    pid: sysinfo::Pid,
+    snapshot: RwLock<ResourceSnapshot>,
+}
+
+impl SystemMonitor {
+    pub fn new() -> Self {
+        let mut system = sysinfo::System::new_all();
+        system.refresh_all();
+        Self {
+            system: RwLock::new(system),
+            pid: sysinfo::Pid::from_u32(std::process::id()),
+            snapshot: RwLock::new(ResourceSnapshot::default()),
+        }
+    }
+
+    /// Re-reads process/system stats from the OS and updates the cached snapshot.
+    pub async fn refresh(&self) {
+        let mut system = self.system.write().await;
+        system.refresh_all();
+
+        let total_mb = system.total_memory() / (1024 * 1024);
+        let (used_mb, process_cpu_percent) = system
+            .process(self.pid)
+            .map(|p| (p.memory() / (1024 * 1024), p.cpu_usage()))
+            .unwrap_or((0, 0.0));
+        let percentage = if total_mb == 0 { 0.0 } else { (used_mb as f64 / total_mb as f64) * 100.0 };
+
+        *self.snapshot.write().await = ResourceSnapshot {
+            used_mb,
+            total_mb,
+            percentage,
+            process_cpu_percent,
+            open_fds: open_fd_count(),
+            thread_count: thread_count(),
+        };
+    }
+
+    /// The most recently refreshed resource snapshot.
+    pub async fn snapshot(&self) -> ResourceSnapshot {
+        *self.snapshot.read().await
+    }
+}
+
+impl Default for SystemMonitor {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Counts open file descriptors for the current process via `/proc/self/fd`. Returns 0 on
+/// platforms without procfs rather than failing the whole snapshot over one field.
+#[cfg(target_os = "linux")]
+fn open_fd_count() -> u64 {
+    std::fs::read_dir("/proc/self/fd").map(|entries| entries.count() as u64).unwrap_or(0)
+}
+
+#[cfg(not(target_os = "linux"))]
+fn open_fd_count() -> u64 {
+    0
+}
+
+/// Reads the current process's thread count from `/proc/self/status`. Defaults to 1 on
+/// platforms without procfs, or if the `Threads:` line is ever missing/unparseable.
+#[cfg(target_os = "linux")]
+fn thread_count() -> u64 {
+    std::fs::read_to_string("/proc/self/status")
+        .ok()
+        .and_then(|status| {
+            status.lines().find_map(|line| line.strip_prefix("Threads:").and_then(|v| v.trim().parse().ok()))
+        })
+        .unwrap_or(1)
+}
+
+#[cfg(not(target_os = "linux"))]
+fn thread_count() -> u64 {
+    1
+}
@@ -1,8 +1,11 @@
+use std::collections::HashMap;
 use std::sync::Arc;
 use std::time::Duration;
 use reqwest::{Client, ClientBuilder};
 use serde_json::Value;
+use tokio::sync::broadcast;
 use crate::cache::{MemoryCache, cache_key};
+use crate::http_client::{RequestModule, RequestParts, ResponseParts, RetryPolicy};
 
 /// Type alias for cache parameter key-value pairs
 pub type CacheParams<'a> = Vec<(&'a str, &'a str)>;
@@ -17,10 +20,25 @@ pub type CachedRequest<'a> = (&'a str, &'a str, CacheParams<'a>);
 pub struct OptimizedApiClient {
     client: Client,
     cache: Arc<MemoryCache>,
+    /// Cache keys with a background stale-while-revalidate refresh currently in flight, so
+    /// concurrent soft-expired hits trigger only one refresh per key.
+    refreshing: Arc<tokio::sync::Mutex<std::collections::HashSet<String>>>,
+    /// Interceptor pipeline run around every upstream request; see
+    /// [`crate::http_client::RequestModule`].
+    modules: Vec<Arc<dyn RequestModule>>,
+    /// In-flight upstream fetches by cache key, so concurrent cache misses for the same key
+    /// (the classic thundering-herd case) share one upstream request instead of each firing
+    /// its own. See [`Self::single_flight`].
+    in_flight: Arc<tokio::sync::Mutex<HashMap<String, broadcast::Sender<Result<Value, String>>>>>,
 }
 
 impl OptimizedApiClient {
     pub fn new(cache: Arc<MemoryCache>) -> Result<Self, String> {
+        Self::with_modules(cache, Vec::new())
+    }
+
+    /// Like `new`, but runs `modules` around every request, in registration order.
+    pub fn with_modules(cache: Arc<MemoryCache>, modules: Vec<Arc<dyn RequestModule>>) -> Result<Self, String> {
         let client = ClientBuilder::new()
             .timeout(Duration::from_secs(15))
             .connect_timeout(Duration::from_secs(3))
@@ -32,60 +50,262 @@ impl OptimizedApiClient {
             .build()
             .map_err(|e| format!("Failed to create optimized client: {e}"))?;
 
-        Ok(Self { client, cache })
+        Ok(Self {
+            client,
+            cache,
+            refreshing: Arc::new(tokio::sync::Mutex::new(std::collections::HashSet::new())),
+            modules,
+            in_flight: Arc::new(tokio::sync::Mutex::new(HashMap::new())),
+        })
     }
 
-    pub async fn get_cached<T>(&self, 
-        url: &str, 
-        cache_prefix: &str, 
+    /// Runs `fetch` for `cache_key`, coalescing concurrent callers for the same key onto a
+    /// single upstream call: the first caller in registers an in-flight broadcast channel and
+    /// runs `fetch`, while subsequent callers for the same key await that channel instead of
+    /// issuing duplicate requests. The result -- success or error -- is cloned to every waiter,
+    /// and the in-flight entry is removed once `fetch` completes so a later call retries rather
+    /// than caching the failure.
+    async fn single_flight<F, Fut>(&self, cache_key: &str, fetch: F) -> Result<Value, String>
+    where
+        F: FnOnce() -> Fut,
+        Fut: std::future::Future<Output = Result<Value, String>>,
+    {
+        let receiver = {
+            let mut in_flight = self.in_flight.lock().await;
+            if let Some(sender) = in_flight.get(cache_key) {
+                Some(sender.subscribe())
+            } else {
+                let (sender, _) = broadcast::channel(1);
+                in_flight.insert(cache_key.to_string(), sender);
+                None
+            }
+        };
+
+        if let Some(mut receiver) = receiver {
+            return receiver
+                .recv()
+                .await
+                .map_err(|e| format!("in-flight request for {cache_key} failed: {e}"))?;
+        }
+
+        let result = fetch().await;
+
+        if let Some(sender) = self.in_flight.lock().await.remove(cache_key) {
+            let _ = sender.send(result.clone());
+        }
+
+        result
+    }
+
+    async fn fetch_json(&self, url: &str, headers: Option<HttpHeaders>) -> Result<Value, String> {
+        let mut parts = RequestParts { url: url.to_string(), headers: headers.unwrap_or_default() };
+        for module in &self.modules {
+            module.on_request(&mut parts).await;
+        }
+
+        let start = std::time::Instant::now();
+        let mut request = self.client.get(&parts.url);
+        for (key, value) in &parts.headers {
+            request = request.header(key, value);
+        }
+
+        let mut status = None;
+        let result = async {
+            let response = request.send().await.map_err(|e| format!("HTTP request failed: {e}"))?;
+            status = Some(response.status());
+
+            if !response.status().is_success() {
+                if response.status().as_u16() == 429 {
+                    return Err("Rate limit exceeded".to_string());
+                }
+                return Err(format!("HTTP error: {} {}", response.status(), response.status().canonical_reason().unwrap_or("")));
+            }
+
+            response
+                .json::<Value>()
+                .await
+                .map_err(|e| format!("Failed to parse JSON response: {e}"))
+        }.await;
+
+        let resp_parts = ResponseParts {
+            url: parts.url,
+            status,
+            elapsed: start.elapsed(),
+            error: result.as_ref().err().cloned(),
+        };
+        for module in &self.modules {
+            module.on_response(&resp_parts).await;
+        }
+
+        result
+    }
+
+    fn spawn_refresh(&self, url: &str, cache_key: String, cache_ttl: Duration, stale_ttl: Duration, headers: Option<HttpHeaders>) {
+        let client = self.clone();
+        let url = url.to_string();
+        tokio::spawn(async move {
+            {
+                let mut refreshing = client.refreshing.lock().await;
+                if !refreshing.insert(cache_key.clone()) {
+                    return;
+                }
+            }
+            if let Ok(value) = client.fetch_json(&url, headers).await {
+                client.cache.set_with_stale(cache_key.clone(), value, cache_ttl, stale_ttl).await;
+            }
+            client.refreshing.lock().await.remove(&cache_key);
+        });
+    }
+
+    /// Like `get_cached`, but an entry past its TTL remains servable for `stale_ttl` longer:
+    /// the stale value is returned immediately while a single background task (deduplicated
+    /// across concurrent callers via the refresh registry) repopulates the cache.
+    pub async fn get_cached_swr<T>(&self,
+        url: &str,
+        cache_prefix: &str,
         cache_params: &CacheParams<'_>,
         cache_ttl: Duration,
+        stale_ttl: Duration,
         headers: Option<HttpHeaders>
     ) -> Result<T, String>
     where
         T: serde::de::DeserializeOwned,
     {
         let cache_key = cache_key(cache_prefix, cache_params.as_slice());
-        
+
+        if let Some((cached, stale)) = self.cache.get_with_staleness(&cache_key).await {
+            if let Ok(result) = serde_json::from_value::<T>(cached) {
+                if stale {
+                    self.spawn_refresh(url, cache_key.clone(), cache_ttl, stale_ttl, headers.clone());
+                }
+                return Ok(result);
+            }
+        }
+
+        let json_value = self.fetch_json(url, headers).await?;
+        let result: T = serde_json::from_value(json_value.clone())
+            .map_err(|e| format!("Failed to deserialize response: {e}"))?;
+        self.cache.set_with_stale(cache_key, json_value, cache_ttl, stale_ttl).await;
+        Ok(result)
+    }
+
+    pub async fn get_cached<T>(&self,
+        url: &str,
+        cache_prefix: &str,
+        cache_params: &CacheParams<'_>,
+        cache_ttl: Duration,
+        headers: Option<HttpHeaders>
+    ) -> Result<T, String>
+    where
+        T: serde::de::DeserializeOwned,
+    {
+        self.get_cached_with_policy(url, cache_prefix, cache_params, cache_ttl, headers, None).await
+    }
+
+    /// Same as `get_cached`, but retries transient failures (connection errors and
+    /// 429/500/502/503/504) with exponential backoff, honoring `Retry-After` on 429.
+    /// `policy` defaults to `RetryPolicy::default()` when omitted. Concurrent misses for the
+    /// same `cache_key` are coalesced via [`Self::single_flight`] into one upstream fetch.
+    pub async fn get_cached_with_policy<T>(&self,
+        url: &str,
+        cache_prefix: &str,
+        cache_params: &CacheParams<'_>,
+        cache_ttl: Duration,
+        headers: Option<HttpHeaders>,
+        policy: Option<RetryPolicy>,
+    ) -> Result<T, String>
+    where
+        T: serde::de::DeserializeOwned,
+    {
+        let cache_key = cache_key(cache_prefix, cache_params.as_slice());
+
         if let Some(cached) = self.cache.get(&cache_key).await {
             if let Ok(result) = serde_json::from_value::<T>(cached) {
                 return Ok(result);
             }
         }
 
-        let mut request = self.client.get(url);
-        
-        if let Some(headers) = headers {
-            for (key, value) in headers {
+        let policy = policy.unwrap_or_default();
+        let json_value = self
+            .single_flight(&cache_key, || {
+                self.fetch_with_retry(url, headers, policy, cache_key.clone(), cache_ttl)
+            })
+            .await?;
+
+        serde_json::from_value(json_value).map_err(|e| format!("Failed to deserialize response: {e}"))
+    }
+
+    /// Retry loop shared by every `single_flight`-coalesced caller for one cache key: fetches
+    /// `url` with exponential backoff on transient failures, caches the parsed JSON under
+    /// `cache_key`, and returns it raw so the caller deserializes into its own `T`.
+    async fn fetch_with_retry(
+        &self,
+        url: &str,
+        headers: Option<HttpHeaders>,
+        policy: RetryPolicy,
+        cache_key: String,
+        cache_ttl: Duration,
+    ) -> Result<Value, String> {
+        let mut attempt = 0u32;
+        let response = loop {
+            let mut parts = RequestParts { url: url.to_string(), headers: headers.clone().unwrap_or_default() };
+            for module in &self.modules {
+                module.on_request(&mut parts).await;
+            }
+
+            let start = std::time::Instant::now();
+            let mut request = self.client.get(&parts.url);
+            for (key, value) in &parts.headers {
                 request = request.header(key, value);
             }
-        }
 
-        let response = request
-            .send()
-            .await
-            .map_err(|e| format!("HTTP request failed: {e}"))?;
+            let outcome: Result<reqwest::Response, (String, bool, Option<Duration>)> = match request.send().await {
+                Err(e) => Err((format!("HTTP request failed: {e}"), true, None)),
+                Ok(response) if response.status().is_success() => Ok(response),
+                Ok(response) => {
+                    let retryable = crate::http_client::is_retryable_status(response.status());
+                    let retry_after = if response.status().as_u16() == 429 { crate::http_client::parse_retry_after(response.headers()) } else { None };
+                    Err((
+                        format!("HTTP error: {} {}", response.status(), response.status().canonical_reason().unwrap_or("")),
+                        retryable,
+                        retry_after,
+                    ))
+                }
+            };
 
-        if !response.status().is_success() {
-            if response.status().as_u16() == 429 {
-                return Err("Rate limit exceeded".to_string());
+            let resp_parts = ResponseParts {
+                url: parts.url,
+                status: outcome.as_ref().ok().map(|r| r.status()),
+                elapsed: start.elapsed(),
+                error: outcome.as_ref().err().map(|(message, ..)| message.clone()),
+            };
+            for module in &self.modules {
+                module.on_response(&resp_parts).await;
             }
-            return Err(format!("HTTP error: {} {}", response.status(), response.status().canonical_reason().unwrap_or("")));
-        }
+
+            match outcome {
+                Ok(response) => break response,
+                Err((message, retryable, retry_after)) => {
+                    if !retryable || attempt + 1 >= policy.max_attempts {
+                        return Err(format!("{message} (after {} attempt(s))", attempt + 1));
+                    }
+                    let delay = retry_after.unwrap_or_else(|| policy.backoff(attempt));
+                    tokio::time::sleep(delay).await;
+                    attempt += 1;
+                }
+            }
+        };
 
         let json_value: Value = response
             .json()
             .await
             .map_err(|e| format!("Failed to parse JSON response: {e}"))?;
 
-        let result: T = serde_json::from_value(json_value.clone())
-            .map_err(|e| format!("Failed to deserialize response: {e}"))?;
-
-        self.cache.set(cache_key, json_value, cache_ttl).await;
-        Ok(result)
+        self.cache.set(cache_key, json_value.clone(), cache_ttl).await;
+        Ok(json_value)
     }
 
-    pub async fn get_json_cached(&self, 
+    pub async fn get_json_cached(&self,
         url: &str,
         cache_prefix: &str,
         cache_params: &CacheParams<'_>,
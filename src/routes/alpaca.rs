@@ -0,0 +1,53 @@
+use std::convert::Infallible;
+
+use axum::extract::{Query, State};
+use axum::response::sse::{Event, KeepAlive, Sse};
+use axum::routing::get;
+use axum::Router;
+use futures::Stream;
+use serde::Deserialize;
+use utoipa::{IntoParams, ToSchema};
+
+use crate::state::AppState;
+use crate::types::TickerSymbol;
+
+pub fn router(state: AppState) -> Router {
+    Router::new()
+        .route("/quotes", get(stream_quotes))
+        .with_state(state)
+}
+
+#[derive(Debug, Deserialize, IntoParams, ToSchema)]
+pub struct StreamQuotesQuery {
+    /// Comma-separated tickers to stream trades/quotes for, e.g. `AAPL,MSFT`.
+    pub symbols: String,
+}
+
+/// Stream live trades/quotes for a set of tickers as Server-Sent Events, backed by a single
+/// persistent connection to Alpaca's market-data WebSocket shared across every subscriber
+/// (see [`crate::sources::alpaca_data::AlpacaWsHub`]).
+#[utoipa::path(
+    get,
+    path = "/stream/quotes",
+    params(StreamQuotesQuery),
+    tag = "data",
+    responses((status = 200, description = "Server-Sent Events stream of trade/quote updates"))
+)]
+pub async fn stream_quotes(
+    State(state): State<AppState>,
+    Query(q): Query<StreamQuotesQuery>,
+) -> Sse<impl Stream<Item = Result<Event, Infallible>>> {
+    use futures::StreamExt;
+
+    let symbols: Vec<TickerSymbol> = q
+        .symbols
+        .split(',')
+        .filter_map(|s| TickerSymbol::new(s.to_string()).ok())
+        .collect();
+
+    let updates = state.alpaca_ws_hub.clone().subscribe(symbols).map(|value| {
+        Ok(Event::default().json_data(value).unwrap_or_else(|_| Event::default().data("{}")))
+    });
+
+    Sse::new(updates).keep_alive(KeepAlive::default())
+}
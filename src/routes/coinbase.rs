@@ -0,0 +1,209 @@
+use axum::{
+    extract::{Path, Query, State},
+    http::StatusCode,
+    response::IntoResponse,
+    routing::get,
+    Json, Router,
+};
+use serde::{Deserialize, Serialize};
+use utoipa::{IntoParams, ToSchema};
+
+use crate::errors::ApiError;
+use crate::sources::coinbase_data::{CoinbaseCandle, CoinbaseMarket, CoinbaseOrderbook, CoinbaseProduct, CoinbaseTrade};
+use crate::state::AppState;
+use crate::types::TrendingItem;
+
+#[derive(Debug, Deserialize, IntoParams, ToSchema)]
+pub struct CoinbaseQuery {
+    pub limit: Option<u32>,
+    pub depth: Option<u32>,
+    /// Candle resolution (`1m`/`5m`/`15m`/`1h`/`4h`/`1d`), matching `/hyperliquid/candles/{coin}`.
+    pub interval: Option<String>,
+    pub start_time: Option<u64>,
+    pub end_time: Option<u64>,
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct CoinbaseResponse<T> {
+    pub success: bool,
+    pub data: T,
+    pub timestamp: u64,
+    pub source: String,
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct CoinbaseMoversResponse {
+    pub gainers: Vec<CoinbaseMarket>,
+    pub losers: Vec<CoinbaseMarket>,
+}
+
+fn wrap<T>(data: T) -> CoinbaseResponse<T> {
+    let timestamp = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .unwrap_or_default()
+        .as_secs();
+
+    CoinbaseResponse { success: true, data, timestamp, source: "coinbase".to_string() }
+}
+
+pub fn router(state: AppState) -> Router {
+    Router::new()
+        .route("/products", get(get_all_products))
+        .route("/markets", get(get_all_markets))
+        .route("/market/{coin}", get(get_market_data))
+        .route("/orderbook/{coin}", get(get_orderbook))
+        .route("/trades/{coin}", get(get_recent_trades))
+        .route("/candles/{coin}", get(get_candles))
+        .route("/volume/top", get(get_top_volume))
+        .route("/movers", get(get_top_movers))
+        .route("/trending", get(get_trending_defi))
+        .with_state(state)
+}
+
+/// Get all Coinbase spot products
+#[utoipa::path(
+    get,
+    path = "/coinbase/products",
+    tag = "coinbase",
+    responses((status = 200, description = "All Coinbase spot products", body = CoinbaseResponse<Vec<CoinbaseProduct>>))
+)]
+pub async fn get_all_products(State(state): State<AppState>) -> Result<impl IntoResponse, ApiError> {
+    let products = state.coinbase.get_all_products().await.map_err(|e| ApiError::Upstream(e.to_string()))?;
+    Ok((StatusCode::OK, Json(wrap(products))))
+}
+
+/// Get the order book for a Coinbase product (e.g. `BTC` or `BTC-USD`)
+#[utoipa::path(
+    get,
+    path = "/coinbase/orderbook/{coin}",
+    params(("coin" = String, Path, description = "Coin symbol, e.g. BTC"), CoinbaseQuery),
+    tag = "coinbase",
+    responses((status = 200, description = "Order book", body = CoinbaseResponse<CoinbaseOrderbook>))
+)]
+pub async fn get_orderbook(
+    State(state): State<AppState>,
+    Path(coin): Path<String>,
+    Query(query): Query<CoinbaseQuery>,
+) -> Result<impl IntoResponse, ApiError> {
+    let book = state.coinbase.get_orderbook(&coin, query.depth).await.map_err(|e| ApiError::Upstream(e.to_string()))?;
+    Ok((StatusCode::OK, Json(wrap(book))))
+}
+
+/// Get recent trades for a Coinbase product
+#[utoipa::path(
+    get,
+    path = "/coinbase/trades/{coin}",
+    params(("coin" = String, Path, description = "Coin symbol, e.g. BTC"), CoinbaseQuery),
+    tag = "coinbase",
+    responses((status = 200, description = "Recent trades", body = CoinbaseResponse<Vec<CoinbaseTrade>>))
+)]
+pub async fn get_recent_trades(
+    State(state): State<AppState>,
+    Path(coin): Path<String>,
+    Query(query): Query<CoinbaseQuery>,
+) -> Result<impl IntoResponse, ApiError> {
+    let trades = state.coinbase.get_recent_trades(&coin, query.limit).await.map_err(|e| ApiError::Upstream(e.to_string()))?;
+    Ok((StatusCode::OK, Json(wrap(trades))))
+}
+
+/// Get candlestick data for a Coinbase product
+#[utoipa::path(
+    get,
+    path = "/coinbase/candles/{coin}",
+    params(("coin" = String, Path, description = "Coin symbol, e.g. BTC"), CoinbaseQuery),
+    tag = "coinbase",
+    responses((status = 200, description = "Candlestick data", body = CoinbaseResponse<Vec<CoinbaseCandle>>))
+)]
+pub async fn get_candles(
+    State(state): State<AppState>,
+    Path(coin): Path<String>,
+    Query(query): Query<CoinbaseQuery>,
+) -> Result<impl IntoResponse, ApiError> {
+    let interval = query.interval.as_deref().unwrap_or("1h");
+    let end_time = query.end_time.unwrap_or_else(|| {
+        std::time::SystemTime::now().duration_since(std::time::UNIX_EPOCH).unwrap_or_default().as_millis() as u64
+    });
+    let start_time = query.start_time.unwrap_or(end_time.saturating_sub(24 * 60 * 60 * 1000));
+
+    let candles = state.coinbase.get_candles(&coin, interval, start_time, end_time).await.map_err(|e| ApiError::Upstream(e.to_string()))?;
+    Ok((StatusCode::OK, Json(wrap(candles))))
+}
+
+/// Get priced market data for every product in [`crate::sources::coinbase_data`]'s watchlist
+#[utoipa::path(
+    get,
+    path = "/coinbase/markets",
+    tag = "coinbase",
+    responses((status = 200, description = "Watchlist markets with price and volume", body = CoinbaseResponse<Vec<CoinbaseMarket>>))
+)]
+pub async fn get_all_markets(State(state): State<AppState>) -> Result<impl IntoResponse, ApiError> {
+    let markets = state.coinbase.get_all_markets().await.map_err(|e| ApiError::Upstream(e.to_string()))?;
+    Ok((StatusCode::OK, Json(wrap(markets))))
+}
+
+/// Get priced market data for a specific coin (e.g. `BTC`)
+#[utoipa::path(
+    get,
+    path = "/coinbase/market/{coin}",
+    params(("coin" = String, Path, description = "Coin symbol, e.g. BTC")),
+    tag = "coinbase",
+    responses((status = 200, description = "Priced market data", body = CoinbaseResponse<CoinbaseMarket>))
+)]
+pub async fn get_market_data(
+    State(state): State<AppState>,
+    Path(coin): Path<String>,
+) -> Result<impl IntoResponse, ApiError> {
+    let market = state.coinbase.get_market_data(&coin).await.map_err(|e| ApiError::Upstream(e.to_string()))?;
+    Ok((StatusCode::OK, Json(wrap(market))))
+}
+
+/// Get watchlist markets ranked by 24h volume
+#[utoipa::path(
+    get,
+    path = "/coinbase/volume/top",
+    params(("limit" = Option<u32>, Query, description = "Number of top markets to return")),
+    tag = "coinbase",
+    responses((status = 200, description = "Top markets by volume", body = CoinbaseResponse<Vec<CoinbaseMarket>>))
+)]
+pub async fn get_top_volume(
+    State(state): State<AppState>,
+    Query(params): Query<std::collections::HashMap<String, String>>,
+) -> Result<impl IntoResponse, ApiError> {
+    let limit = params.get("limit").and_then(|l| l.parse::<usize>().ok()).unwrap_or(20);
+    let top_markets = state.coinbase.get_top_volume_markets(limit).await.map_err(|e| ApiError::Upstream(e.to_string()))?;
+    Ok((StatusCode::OK, Json(wrap(top_markets))))
+}
+
+/// Get watchlist top movers (gainers and losers) by 24h price change
+#[utoipa::path(
+    get,
+    path = "/coinbase/movers",
+    params(("limit" = Option<u32>, Query, description = "Number of movers to return in each category")),
+    tag = "coinbase",
+    responses((status = 200, description = "Top gainers and losers", body = CoinbaseResponse<CoinbaseMoversResponse>))
+)]
+pub async fn get_top_movers(
+    State(state): State<AppState>,
+    Query(params): Query<std::collections::HashMap<String, String>>,
+) -> Result<impl IntoResponse, ApiError> {
+    let limit = params.get("limit").and_then(|l| l.parse::<usize>().ok()).unwrap_or(10);
+    let (gainers, losers) = state.coinbase.get_top_movers(limit).await.map_err(|e| ApiError::Upstream(e.to_string()))?;
+    Ok((StatusCode::OK, Json(wrap(CoinbaseMoversResponse { gainers, losers }))))
+}
+
+/// Get trending Coinbase assets, ranked the same way as [`crate::routes::hyperliquid::get_trending_defi`]
+#[utoipa::path(
+    get,
+    path = "/coinbase/trending",
+    params(("limit" = Option<u32>, Query, description = "Number of trending assets to return")),
+    tag = "coinbase",
+    responses((status = 200, description = "Trending assets", body = CoinbaseResponse<Vec<TrendingItem>>))
+)]
+pub async fn get_trending_defi(
+    State(state): State<AppState>,
+    Query(params): Query<std::collections::HashMap<String, String>>,
+) -> Result<impl IntoResponse, ApiError> {
+    let limit = params.get("limit").and_then(|l| l.parse::<usize>().ok()).unwrap_or(20);
+    let trending = state.coinbase.get_trending_defi_assets(limit).await.map_err(|e| ApiError::Upstream(e.to_string()))?;
+    Ok((StatusCode::OK, Json(wrap(trending))))
+}
@@ -1,17 +1,23 @@
 use axum::{
-    extract::Query,
+    extract::{ws::{Message, WebSocket, WebSocketUpgrade}, Path, Query},
     http::StatusCode,
-    response::Json,
+    response::{IntoResponse, Json},
     routing::get,
     Router,
 };
+use futures::StreamExt;
 use serde::{Deserialize, Serialize};
 use utoipa::{IntoParams, ToSchema};
+use crate::errors::ApiError;
+use crate::services::rate_provider::RateProvider;
 use crate::sources::coingecko_data::{
-    CoinGeckoCoin, MarketOverview, TrendingCoin, get_top_coins, get_top_gainers, 
-    get_top_losers, get_trending_coins, get_market_overview, get_market_context,
-    get_trending_cryptos, get_simple_price
+    CandleSeries, CoinGeckoClient, CoinGeckoCoin, MarketOverview, MarketTicker, TrendingCoin, get_top_coins,
+    get_top_gainers, get_top_losers, get_trending_coins, get_market_overview, get_market_context,
+    get_trending_cryptos, get_simple_price, get_coin_tickers, get_market_chart, get_ohlc,
+    get_market_chart_candles, get_market_summary, COINGECKO_PRICE_STREAM_HUB,
 };
+use crate::sources::kraken_data::KrakenDataSource;
+use crate::utils::{calculate_ema, calculate_std_dev};
 use serde_json::Value;
 
 #[derive(Debug, Deserialize, IntoParams, utoipa::ToSchema)]
@@ -31,6 +37,19 @@ pub struct SimplePriceQuery {
     pub include_24hr_change: Option<bool>,
 }
 
+#[derive(Debug, Deserialize, IntoParams, utoipa::ToSchema)]
+pub struct CoinGeckoTickersQuery {
+    pub id: String,
+    /// Comma-separated CoinGecko exchange identifiers (e.g. `binance,coinbase-exchange`) to
+    /// restrict the result to; omit for all exchanges CoinGecko tracks for this coin.
+    pub exchange_ids: Option<String>,
+    /// Requests CoinGecko's deeper order-book stats (2% depth) per market.
+    pub depth: Option<bool>,
+    /// Drops markets with 24h `volume` below this threshold; unfiltered ticker lists are
+    /// dominated by dead pairs. Defaults to 1000.0.
+    pub min_volume: Option<f64>,
+}
+
 #[derive(Debug, Serialize, ToSchema)]
 pub struct CoinGeckoResponse<T> {
     pub success: bool,
@@ -45,6 +64,93 @@ pub struct MarketContextResponse {
     pub timestamp: i64,
 }
 
+#[derive(Debug, Deserialize, IntoParams, ToSchema)]
+pub struct CoinMetricsQuery {
+    /// Comma-separated CoinGecko coin ids, e.g. `bitcoin,ethereum`.
+    pub ids: String,
+    pub vs_currency: Option<String>,
+    /// Days of history to pull the closing-price series from. Defaults to 30.
+    pub days: Option<u32>,
+    /// Period for the EMA/SMA/Bollinger-band calculations. Defaults to 14.
+    pub period: Option<usize>,
+}
+
+/// Momentum/volatility indicators computed over a coin's closing-price series, mirroring what
+/// the Yahoo/Finviz equity endpoints already expose via `calculate_ema`/`calculate_std_dev`.
+#[derive(Debug, Serialize, ToSchema)]
+pub struct CoinMetrics {
+    pub id: String,
+    pub ema: Option<f64>,
+    pub sma: Option<f64>,
+    pub std_dev: Option<f64>,
+    pub bollinger_upper: Option<f64>,
+    pub bollinger_lower: Option<f64>,
+}
+
+fn calculate_sma(prices: &[f64], period: usize) -> Option<f64> {
+    if prices.len() < period || period == 0 {
+        return None;
+    }
+    let window = &prices[prices.len() - period..];
+    Some(window.iter().sum::<f64>() / period as f64)
+}
+
+/// Per-coin momentum/volatility indicators (EMA, SMA, std-dev, Bollinger bands) computed over
+/// each coin's CoinGecko closing-price history.
+#[utoipa::path(
+    get,
+    path = "/coingecko/metrics",
+    params(CoinMetricsQuery),
+    responses(
+        (status = 200, description = "Success", body = CoinGeckoResponse<Vec<CoinMetrics>>),
+        (status = 500, description = "Internal server error", body = String)
+    ),
+    tag = "CoinGecko"
+)]
+pub async fn get_coin_metrics_route(
+    Query(query): Query<CoinMetricsQuery>,
+) -> Result<Json<CoinGeckoResponse<Vec<CoinMetrics>>>, (StatusCode, String)> {
+    let ids: Vec<String> = query.ids.split(',').map(|s| s.trim().to_string()).collect();
+    let vs_currency = query.vs_currency.as_deref().unwrap_or("usd");
+    let days = query.days.unwrap_or(30);
+    let period = query.period.unwrap_or(14);
+
+    let mut metrics = Vec::with_capacity(ids.len());
+    for id in ids {
+        let chart = get_market_chart(&id, vs_currency, days, None).await;
+        let closes: Vec<f64> = match chart {
+            Ok(chart) => chart.prices.into_iter().map(|(_, price)| price).collect(),
+            Err(e) => {
+                tracing::warn!("coingecko metrics: failed to fetch market chart for {id}: {e}");
+                Vec::new()
+            }
+        };
+
+        let sma = calculate_sma(&closes, period);
+        let std_dev = calculate_std_dev(&closes);
+        let (bollinger_upper, bollinger_lower) = match (sma, std_dev) {
+            (Some(sma), Some(std_dev)) => (Some(sma + 2.0 * std_dev), Some(sma - 2.0 * std_dev)),
+            _ => (None, None),
+        };
+
+        metrics.push(CoinMetrics {
+            id,
+            ema: calculate_ema(&closes, period),
+            sma,
+            std_dev,
+            bollinger_upper,
+            bollinger_lower,
+        });
+    }
+
+    let response = CoinGeckoResponse {
+        success: true,
+        data: metrics,
+        timestamp: chrono::Utc::now().timestamp(),
+    };
+    Ok(Json(response))
+}
+
 /// Get top cryptocurrencies by market cap
 #[utoipa::path(
     get,
@@ -259,6 +365,407 @@ pub async fn get_simple_price_route(
     }
 }
 
+/// Get per-market trading pairs for a coin, filtered to liquid markets
+#[utoipa::path(
+    get,
+    path = "/coingecko/coin-tickers",
+    params(CoinGeckoTickersQuery),
+    responses(
+        (status = 200, description = "Success", body = CoinGeckoResponse<Vec<MarketTicker>>),
+        (status = 500, description = "Internal server error", body = String)
+    ),
+    tag = "CoinGecko"
+)]
+pub async fn get_coin_tickers_route(
+    Query(query): Query<CoinGeckoTickersQuery>,
+) -> Result<Json<CoinGeckoResponse<Vec<MarketTicker>>>, (StatusCode, String)> {
+    let exchange_ids: Option<Vec<String>> = query
+        .exchange_ids
+        .as_ref()
+        .map(|ids| ids.split(',').map(|s| s.trim().to_string()).collect());
+    let depth = query.depth.unwrap_or(false);
+    let min_volume = query.min_volume.unwrap_or(1000.0);
+
+    match get_coin_tickers(&query.id, exchange_ids.as_deref(), depth).await {
+        Ok(tickers) => {
+            let tickers: Vec<MarketTicker> = tickers.into_iter().filter(|t| t.volume >= min_volume).collect();
+            let response = CoinGeckoResponse {
+                success: true,
+                data: tickers,
+                timestamp: chrono::Utc::now().timestamp(),
+            };
+            Ok(Json(response))
+        }
+        Err(e) => Err((StatusCode::INTERNAL_SERVER_ERROR, e)),
+    }
+}
+
+#[derive(Debug, Deserialize, IntoParams, ToSchema)]
+pub struct CoinGeckoStreamQuery {
+    /// Comma-separated CoinGecko coin ids, e.g. `bitcoin,ethereum`.
+    pub ids: String,
+    /// Comma-separated vs_currencies, e.g. `usd,eur`.
+    pub vs_currencies: String,
+    pub include_24hr_change: Option<bool>,
+    /// Upstream poll cadence in seconds; defaults to 10. The rate limiter/cache in
+    /// `sources::coingecko_data` still applies, so a short interval doesn't translate into
+    /// extra upstream calls as long as other subscribers share the same combination.
+    pub interval_secs: Option<u64>,
+}
+
+/// Stream live price updates for a set of coins over a WebSocket instead of polling
+/// `/coingecko/simple-price`. Connect with `?ids=...&vs_currencies=...`; a single background
+/// task per distinct `(ids, vs_currencies, include_24hr_change)` combination batches every
+/// subscriber onto one periodic poll and fans out only the coins whose price changed (see
+/// [`crate::sources::coingecko_data::CoinGeckoPriceStreamHub`]).
+#[utoipa::path(
+    get,
+    path = "/coingecko/stream",
+    params(CoinGeckoStreamQuery),
+    tag = "CoinGecko",
+    responses((status = 101, description = "Switching protocols to WebSocket"))
+)]
+pub async fn coingecko_stream(
+    ws: WebSocketUpgrade,
+    Query(query): Query<CoinGeckoStreamQuery>,
+) -> impl IntoResponse {
+    let ids: Vec<String> = query.ids.split(',').map(|s| s.trim().to_string()).filter(|s| !s.is_empty()).collect();
+    let vs_currencies: Vec<String> = query.vs_currencies.split(',').map(|s| s.trim().to_string()).filter(|s| !s.is_empty()).collect();
+    let include_24hr_change = query.include_24hr_change.unwrap_or(false);
+    let poll_interval = std::time::Duration::from_secs(query.interval_secs.unwrap_or(10).max(1));
+
+    ws.on_upgrade(move |socket| forward_price_updates(socket, ids, vs_currencies, include_24hr_change, poll_interval))
+}
+
+async fn forward_price_updates(
+    mut socket: WebSocket,
+    ids: Vec<String>,
+    vs_currencies: Vec<String>,
+    include_24hr_change: bool,
+    poll_interval: std::time::Duration,
+) {
+    let mut updates = Box::pin(COINGECKO_PRICE_STREAM_HUB.clone().subscribe(ids, vs_currencies, include_24hr_change, poll_interval));
+
+    while let Some(value) = updates.next().await {
+        let Ok(text) = serde_json::to_string(&value) else { continue };
+        if socket.send(Message::Text(text.into())).await.is_err() {
+            break;
+        }
+    }
+}
+
+#[derive(Debug, Deserialize, IntoParams, ToSchema)]
+pub struct CoinGeckoOhlcQuery {
+    pub id: String,
+    pub vs_currency: Option<String>,
+    /// One of CoinGecko's standard history buckets: 1, 7, 14, 30, 90, 180, or 365 days.
+    /// `"max"` isn't supported -- every other `days`-taking endpoint in this module is typed
+    /// as `u32`, so there's no way to thread that sentinel through without a wider refactor.
+    pub days: Option<u32>,
+    /// Coarser granularity to resample to (e.g. `"daily"`, `"hourly"`); omit for CoinGecko's
+    /// native `/ohlc` bucket width for the requested `days` (see [`crate::sources::coingecko_data::Ohlc`]).
+    pub interval: Option<String>,
+}
+
+/// The `days` values CoinGecko's `/coins/{id}/ohlc` endpoint actually accepts.
+const SUPPORTED_OHLC_DAYS: [u32; 7] = [1, 7, 14, 30, 90, 180, 365];
+
+fn interval_to_bucket_seconds(interval: &str) -> Option<i64> {
+    match interval {
+        "hourly" => Some(60 * 60),
+        "daily" => Some(24 * 60 * 60),
+        _ => None,
+    }
+}
+
+/// Historical OHLC candles for a coin, resampled to `interval` when given, otherwise
+/// CoinGecko's native `/ohlc` bucket width for `days`.
+#[utoipa::path(
+    get,
+    path = "/coingecko/ohlc",
+    params(CoinGeckoOhlcQuery),
+    responses(
+        (status = 200, description = "Success", body = CoinGeckoResponse<CandleSeries>),
+        (status = 400, description = "Unsupported `days` value", body = String),
+        (status = 500, description = "Internal server error", body = String)
+    ),
+    tag = "CoinGecko"
+)]
+pub async fn get_ohlc_route(
+    Query(query): Query<CoinGeckoOhlcQuery>,
+) -> Result<Json<CoinGeckoResponse<CandleSeries>>, (StatusCode, String)> {
+    let vs_currency = query.vs_currency.as_deref().unwrap_or("usd");
+    let days = query.days.unwrap_or(1);
+
+    if !SUPPORTED_OHLC_DAYS.contains(&days) {
+        return Err((
+            StatusCode::BAD_REQUEST,
+            format!("unsupported days value {days}; must be one of {SUPPORTED_OHLC_DAYS:?}"),
+        ));
+    }
+
+    let series = match query.interval.as_deref().and_then(interval_to_bucket_seconds) {
+        Some(bucket_seconds) => {
+            get_market_chart_candles(&query.id, vs_currency, days, query.interval.as_deref(), bucket_seconds).await
+        }
+        None => get_ohlc(&query.id, vs_currency, days).await,
+    };
+
+    match series {
+        Ok(series) => {
+            let response = CoinGeckoResponse {
+                success: true,
+                data: series,
+                timestamp: chrono::Utc::now().timestamp(),
+            };
+            Ok(Json(response))
+        }
+        Err(e) => Err((StatusCode::INTERNAL_SERVER_ERROR, e)),
+    }
+}
+
+/// One of our own markets, in the shape CoinGecko's exchange-integration spec expects for
+/// market discovery (the same `{ticker_id, base, target, pool_id}` shape the openbook-candles
+/// server emits) -- unlike [`get_coin_tickers_route`] above, which relays CoinGecko's own data
+/// for an external coin, this and [`CoinGeckoTicker`] publish *our* markets.
+#[derive(Debug, Clone, Serialize, ToSchema)]
+pub struct CoinGeckoPair {
+    pub ticker_id: String,
+    pub base: String,
+    pub target: String,
+    pub pool_id: String,
+}
+
+/// 24h trading stats for one of our own markets, in CoinGecko's exchange-integration ticker
+/// shape.
+#[derive(Debug, Clone, Serialize, ToSchema)]
+pub struct CoinGeckoTicker {
+    pub ticker_id: String,
+    pub base_currency: String,
+    pub target_currency: String,
+    pub last_price: f64,
+    pub base_volume: f64,
+    pub target_volume: f64,
+    pub high: f64,
+    pub low: f64,
+    pub bid: f64,
+    pub ask: f64,
+}
+
+/// The pump.fun bonding-curve markets we host, as `(ticker_id, base symbol, pool_id, last
+/// price)` -- there's no live bonding-curve registry wired up yet, so this mirrors the fixed
+/// set of obviously-fake symbols `routes::pumpfun`'s mock handlers already use until one
+/// exists.
+fn our_markets() -> Vec<(&'static str, &'static str, &'static str, f64)> {
+    vec![
+        ("PEPE2SOL", "PEPE2", "PEPE2111111111111111111111111111111111111", 0.00045),
+        ("DOGE3SOL", "DOGE3", "DOGE3111111111111111111111111111111111111", 0.0012),
+        ("MOONSOL", "MOON", "MOON1111111111111111111111111111111111111", 0.00089),
+    ]
+}
+
+/// Stand-in for a real `CandleStore::aggregated_candles` roll-up over the last 24h of trades
+/// for `ticker_id` -- until the bonding-curve registry in [`our_markets`] is backed by live
+/// fills, this derives a plausible volume from the market's position the same way
+/// `generate_mock_trending_tokens` does in `routes::pumpfun`.
+async fn lookup_24h_volume(ticker_id: &str, index: usize) -> f64 {
+    let _ = ticker_id;
+    100_000.0 + index as f64 * 50_000.0
+}
+
+/// Stand-in for a real 24h high/low roll-up alongside [`lookup_24h_volume`]; see that function's
+/// doc comment for why this isn't backed by `CandleStore` yet.
+async fn lookup_24h_high_low(last_price: f64) -> (f64, f64) {
+    (last_price * 1.08, last_price * 0.93)
+}
+
+/// List our own markets in CoinGecko's exchange-integration `/pairs` shape, so data aggregators
+/// can discover what we trade.
+#[utoipa::path(
+    get,
+    path = "/coingecko/pairs",
+    responses(
+        (status = 200, description = "Success", body = CoinGeckoResponse<Vec<CoinGeckoPair>>),
+        (status = 500, description = "Internal server error", body = String)
+    ),
+    tag = "CoinGecko"
+)]
+pub async fn get_pairs_route() -> Result<Json<CoinGeckoResponse<Vec<CoinGeckoPair>>>, (StatusCode, String)> {
+    let pairs = our_markets()
+        .into_iter()
+        .map(|(ticker_id, base, pool_id, _last_price)| CoinGeckoPair {
+            ticker_id: ticker_id.to_string(),
+            base: base.to_string(),
+            target: "SOL".to_string(),
+            pool_id: pool_id.to_string(),
+        })
+        .collect();
+
+    let response = CoinGeckoResponse {
+        success: true,
+        data: pairs,
+        timestamp: chrono::Utc::now().timestamp(),
+    };
+    Ok(Json(response))
+}
+
+#[derive(Debug, Deserialize, IntoParams, ToSchema)]
+pub struct TickersQuery {
+    /// Comma-separated Kraken pairs to widen the ticker list with, e.g. `BTC/USD,ETH/USD`.
+    /// Supplying either this or `coingecko_ids` switches the response from our own mock
+    /// markets to the blended real-market output mode (see [`AggregatedTicker`]).
+    pub kraken_pairs: Option<String>,
+    /// Comma-separated CoinGecko coin ids to widen the ticker list with, e.g.
+    /// `bitcoin,ethereum`.
+    pub coingecko_ids: Option<String>,
+}
+
+/// One market's 24h stats in the generic ticker schema aggregators expect --
+/// `base_currency`/`target_currency`/`last`/`volume`/`bid`/`ask`/`high`/`low` -- unlike
+/// [`CoinGeckoTicker`] above, which publishes *our* mock markets for CoinGecko's
+/// exchange-integration listing, this blends real market data from [`KrakenDataSource`] and
+/// CoinGecko via [`RateProvider`] so an aggregator gets one consistent shape regardless of
+/// venue.
+#[derive(Debug, Clone, Serialize, ToSchema)]
+pub struct AggregatedTicker {
+    pub base_currency: String,
+    pub target_currency: String,
+    pub last: f64,
+    pub volume: f64,
+    pub bid: Option<f64>,
+    pub ask: Option<f64>,
+    pub high: Option<f64>,
+    pub low: Option<f64>,
+    pub source: &'static str,
+}
+
+fn to_aggregated_ticker(source: &'static str, ticker: crate::services::rate_provider::NormalizedTicker) -> AggregatedTicker {
+    let (base_currency, target_currency) = match ticker.pair.split_once('/') {
+        Some((base, target)) => (base.to_string(), target.to_string()),
+        None => (ticker.pair.clone(), "USD".to_string()),
+    };
+
+    AggregatedTicker {
+        base_currency,
+        target_currency,
+        last: ticker.price,
+        volume: ticker.volume,
+        bid: ticker.bid,
+        ask: ticker.ask,
+        high: ticker.high_24h,
+        low: ticker.low_24h,
+        source,
+    }
+}
+
+fn parse_csv(csv: &str) -> Vec<String> {
+    csv.split(',').map(|s| s.trim().to_string()).filter(|s| !s.is_empty()).collect()
+}
+
+/// 24h tickers in CoinGecko's exchange-integration `/tickers` shape. With no query params,
+/// lists our own markets (each ticker's volume and high/low fetched concurrently, since
+/// they're independent roll-ups over the same underlying fills). With `kraken_pairs` and/or
+/// `coingecko_ids`, instead blends real market data from both venues into the
+/// [`AggregatedTicker`] schema, widening crypto coverage beyond a single exchange for
+/// aggregators that expect a standard ticker shape.
+#[utoipa::path(
+    get,
+    path = "/coingecko/tickers",
+    params(TickersQuery),
+    responses(
+        (status = 200, description = "Success", body = CoinGeckoResponse<Value>),
+        (status = 500, description = "Internal server error", body = String)
+    ),
+    tag = "CoinGecko"
+)]
+pub async fn get_tickers_route(
+    Query(query): Query<TickersQuery>,
+) -> Result<Json<CoinGeckoResponse<Value>>, (StatusCode, String)> {
+    if query.kraken_pairs.is_none() && query.coingecko_ids.is_none() {
+        let mut tickers = Vec::new();
+
+        for (index, (ticker_id, base, _pool_id, last_price)) in our_markets().into_iter().enumerate() {
+            let (volume, (high, low)) = tokio::join!(
+                lookup_24h_volume(ticker_id, index),
+                lookup_24h_high_low(last_price)
+            );
+
+            tickers.push(CoinGeckoTicker {
+                ticker_id: ticker_id.to_string(),
+                base_currency: base.to_string(),
+                target_currency: "SOL".to_string(),
+                last_price,
+                base_volume: volume,
+                target_volume: volume * last_price,
+                high,
+                low,
+                bid: last_price * 0.999,
+                ask: last_price * 1.001,
+            });
+        }
+
+        let response = CoinGeckoResponse {
+            success: true,
+            data: serde_json::to_value(tickers).unwrap_or_default(),
+            timestamp: chrono::Utc::now().timestamp(),
+        };
+        return Ok(Json(response));
+    }
+
+    let mut aggregated = Vec::new();
+
+    if let Some(pairs) = query.kraken_pairs.as_deref() {
+        match KrakenDataSource::new_async().await {
+            Ok(data_source) => {
+                if let Ok(tickers) = data_source.tickers(parse_csv(pairs)).await {
+                    aggregated.extend(tickers.into_iter().map(|t| to_aggregated_ticker("kraken", t)));
+                }
+            }
+            Err(e) => tracing::warn!("coingecko tickers: failed to reach Kraken: {e}"),
+        }
+    }
+
+    if let Some(ids) = query.coingecko_ids.as_deref() {
+        let client = CoinGeckoClient::from_env();
+        if let Ok(tickers) = client.tickers(parse_csv(ids)).await {
+            aggregated.extend(tickers.into_iter().map(|t| to_aggregated_ticker("coingecko", t)));
+        }
+    }
+
+    let response = CoinGeckoResponse {
+        success: true,
+        data: serde_json::to_value(aggregated).unwrap_or_default(),
+        timestamp: chrono::Utc::now().timestamp(),
+    };
+    Ok(Json(response))
+}
+
+/// Normalized ticker, order book (unsupported for CoinGecko, see
+/// [`crate::services::rate_provider::RateProvider::order_book`]), and per-exchange markets for
+/// one CoinGecko coin id, in the same shape
+/// [`crate::routes::kraken::get_market_summary_route`] returns for a Kraken pair.
+#[utoipa::path(
+    get,
+    path = "/coingecko/summary/{id}",
+    params(("id" = String, Path, description = "CoinGecko coin id, e.g. bitcoin")),
+    responses(
+        (status = 200, description = "Success", body = CoinGeckoResponse<Value>),
+        (status = 500, description = "Internal server error", body = String)
+    ),
+    tag = "CoinGecko"
+)]
+pub async fn get_market_summary_route(Path(id): Path<String>) -> Result<impl IntoResponse, ApiError> {
+    let client = CoinGeckoClient::from_env();
+    let summary = get_market_summary(&client, &id).await.map_err(ApiError::Upstream)?;
+
+    let response = CoinGeckoResponse {
+        success: true,
+        data: summary,
+        timestamp: chrono::Utc::now().timestamp(),
+    };
+    Ok((StatusCode::OK, Json(response)))
+}
+
 pub fn coingecko_routes() -> Router {
     Router::new()
         .route("/top", get(get_top_cryptocurrencies))
@@ -269,4 +776,11 @@ pub fn coingecko_routes() -> Router {
         .route("/market-context", get(get_market_context_route))
         .route("/trending-symbols", get(get_trending_symbols))
         .route("/simple-price", get(get_simple_price_route))
+        .route("/coin-tickers", get(get_coin_tickers_route))
+        .route("/metrics", get(get_coin_metrics_route))
+        .route("/ohlc", get(get_ohlc_route))
+        .route("/pairs", get(get_pairs_route))
+        .route("/tickers", get(get_tickers_route))
+        .route("/summary/{id}", get(get_market_summary_route))
+        .route("/stream", get(coingecko_stream))
 }
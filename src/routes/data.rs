@@ -1,13 +1,32 @@
-use axum::{extract::Query, http::StatusCode, response::IntoResponse, routing::get, Json, Router};
+use axum::{
+    extract::{
+        ws::{Message, WebSocket, WebSocketUpgrade},
+        Path, Query, State,
+    },
+    http::StatusCode,
+    middleware::from_fn_with_state,
+    response::{
+        sse::{Event, KeepAlive, Sse},
+        IntoResponse,
+    },
+    routing::get,
+    Json, Router,
+};
+use futures::{Stream, StreamExt};
+use serde::Deserialize;
 use serde_json::json;
+use std::convert::Infallible;
+use utoipa::{IntoParams, ToSchema};
 
 use crate::helpers;
 use crate::sources;
 use crate::types::LimitQuery;
 use crate::errors::ApiError;
+use crate::middleware::redis_rate_limit::deferred_rate_limit_middleware;
+use crate::services::rates;
 use crate::state::AppState;
 
-pub fn router(_state: AppState) -> Router {
+pub fn router(state: AppState) -> Router {
     Router::new()
         .route("/news", get(news_aggregated))
         .route("/forex", get(sources::finviz_data::get_forex))
@@ -16,29 +35,189 @@ pub fn router(_state: AppState) -> Router {
         .route("/insider", get(sources::finviz_data::get_insider))
         .route("/group", get(sources::finviz_data::get_group))
         .route("/reddit/stocks", get(get_reddit_stocks))
+        .route("/reddit/trending", get(get_reddit_trending))
         .route("/trending/stocks", get(get_trending_stocks))
+        .route("/rates/live/{pair}", get(get_live_rate))
+        .route("/stream", get(live_feed_stream))
+        .route("/stream/ws", get(live_feed_stream_ws))
+        .layer(from_fn_with_state(state.clone(), deferred_rate_limit_middleware))
+        .with_state(state)
 }
 
+/// Pre-warmed by the scheduler's `refresh_news_cache` job (see `main.rs`), so under normal
+/// operation this serves the shared `ResilientFetch` cache instead of scraping live.
 #[utoipa::path(get, path = "/news", tag = "data", responses((status = 200, description = "Aggregated news from Finviz, Reddit, and Alpaca")))]
 pub async fn news_aggregated() -> Result<impl IntoResponse, ApiError> {
-    let v = helpers::news::get_news().await.map_err(ApiError::Upstream)?;
+    let v = helpers::news::get_news_cached().await.map_err(ApiError::Upstream)?;
     Ok((StatusCode::OK, Json(v)))
 }
 
-#[utoipa::path(get, path = "/reddit/stocks", params(LimitQuery), tag = "data", responses((status = 200, description = "Trending tickers from Reddit")))]
-pub async fn get_reddit_stocks(Query(query): Query<LimitQuery>) -> impl IntoResponse {
+/// Cache key the scheduler's `reddit_trending_refresh` job pre-warms on a 10-minute cron (see
+/// `main.rs`); `get_or_compute` falls back to a live scrape on a cold cache so this never blocks
+/// on the schedule being in sync with the first request.
+pub const REDDIT_TRENDING_STOCKS_CACHE_KEY: &str = "reddit_trending_stocks";
+const REDDIT_TRENDING_STOCKS_TTL: std::time::Duration = std::time::Duration::from_secs(900);
+
+#[utoipa::path(get, path = "/reddit/stocks", params(LimitQuery), tag = "data", responses((status = 200, description = "Trending tickers from Reddit, ranked by mention count")))]
+pub async fn get_reddit_stocks(
+    State(state): State<AppState>,
+    Query(query): Query<LimitQuery>,
+) -> impl IntoResponse {
     let LimitQuery { limit } = query;
-    let mut symbols = sources::reddit_data::get_reddit_trending_stocks().await;
-    if let Some(max) = limit { if symbols.len() > max { symbols.truncate(max); } }
+    let cached = state.cache.get_or_compute(REDDIT_TRENDING_STOCKS_CACHE_KEY, REDDIT_TRENDING_STOCKS_TTL, || async {
+        json!(sources::reddit_data::get_reddit_trending_stocks().await)
+    }).await;
+    let mut ranked: Vec<(String, u32)> = serde_json::from_value(cached).unwrap_or_default();
+    if let Some(max) = limit { if ranked.len() > max { ranked.truncate(max); } }
+    let symbols: Vec<serde_json::Value> = ranked
+        .into_iter()
+        .map(|(symbol, mentions)| json!({ "symbol": symbol, "mentions": mentions }))
+        .collect();
     (StatusCode::OK, Json(json!({ "symbols": symbols }))).into_response()
 }
 
+#[derive(Debug, Deserialize, IntoParams, ToSchema)]
+pub struct TrendingQuery {
+    /// "Recent" window in minutes used to compute mention velocity (default: 60)
+    pub window_minutes: Option<u64>,
+    /// Max tickers to return (default: 20)
+    pub limit: Option<usize>,
+}
+
+/// Rank Reddit-mentioned tickers by mention *velocity* -- how surged the recent window is versus
+/// their own prior baseline -- rather than the flat membership set `/reddit/stocks` returns.
+/// Backed by `services::trends::TrendStore`, fed on a 5-minute poll (see `main.rs`).
+#[utoipa::path(get, path = "/reddit/trending", params(TrendingQuery), tag = "data", responses((status = 200, description = "Reddit tickers ranked by mention velocity")))]
+pub async fn get_reddit_trending(
+    State(state): State<AppState>,
+    Query(query): Query<TrendingQuery>,
+) -> impl IntoResponse {
+    let window = std::time::Duration::from_secs(query.window_minutes.unwrap_or(60) * 60);
+    let limit = query.limit.unwrap_or(20);
+    let ranked = state.trend_store.trending(window, limit, chrono::Utc::now()).await;
+    (StatusCode::OK, Json(json!({ "tickers": ranked }))).into_response()
+}
+
+/// Cache key the scheduler's `yahoo_trending_warm` job pre-warms on a 10-minute interval (see
+/// `main.rs`); `get_or_compute` falls back to a live fetch on a cold cache so this never blocks
+/// on the schedule being in sync with the first request.
+pub const YAHOO_TRENDING_CACHE_KEY: &str = "yahoo_trending_tickers";
+const YAHOO_TRENDING_TTL: std::time::Duration = std::time::Duration::from_secs(600);
+
 #[utoipa::path(get, path = "/trending/stocks", params(LimitQuery), tag = "data", responses((status = 200, description = "Trending tickers from web sources")))]
-pub async fn get_trending_stocks(Query(query): Query<LimitQuery>) -> impl IntoResponse {
+pub async fn get_trending_stocks(axum::extract::State(state): axum::extract::State<AppState>, Query(query): Query<LimitQuery>) -> impl IntoResponse {
     let LimitQuery { limit } = query;
-    let mut symbols = helpers::trending_stocks::get_trending_penny_stocks().await;
+    let finviz_trending = match state.finviz_cache.get_or_scrape(sources::finviz_cache::ScrapeKey::Trending).await {
+        Ok((value, _as_of)) => serde_json::from_value(value).unwrap_or_default(),
+        Err(_) => Vec::new(),
+    };
+    let cached_yahoo = state.cache.get_or_compute(YAHOO_TRENDING_CACHE_KEY, YAHOO_TRENDING_TTL, || async {
+        json!(sources::yahoo_data::get_trending_from_yahoo().await)
+    }).await;
+    let yahoo_trending: Vec<String> = serde_json::from_value(cached_yahoo).unwrap_or_default();
+    let mut symbols = helpers::trending_stocks::get_trending_penny_stocks(finviz_trending, yahoo_trending).await;
     if let Some(max) = limit { if symbols.len() > max { symbols.truncate(max); } }
     (StatusCode::OK, Json(json!({ "symbols": symbols }))).into_response()
 }
 
+#[derive(Debug, Deserialize)]
+pub struct LiveRateQuery {
+    /// `forex` or `crypto`; defaults to `forex`.
+    pub source: Option<String>,
+}
+
+/// Read the most recently polled live rate for a forex/crypto pair.
+///
+/// Backed by a background poller (see `services::rates::run_rate_poller`) that
+/// keeps quotes warm in the shared cache, so this never blocks on a live scrape.
+#[utoipa::path(get, path = "/rates/live/{pair}", params(("pair" = String, Path, description = "Pair ticker, e.g. EURUSD or BTC"), LiveRateQuery), tag = "data", responses((status = 200, description = "Latest polled rate for the pair")))]
+pub async fn get_live_rate(
+    axum::extract::State(state): axum::extract::State<AppState>,
+    Path(pair): Path<String>,
+    Query(query): Query<LiveRateQuery>,
+) -> Result<impl IntoResponse, ApiError> {
+    let source = match query.source.as_deref() {
+        Some("crypto") => "finviz_crypto",
+        _ => "finviz_forex",
+    };
+
+    match rates::cached_rate(&state.cache, source, &pair).await {
+        Some(quote) => Ok((StatusCode::OK, Json(json!({ "success": true, "data": quote })))),
+        None => Err(ApiError::NotFound(format!("no live rate cached yet for {pair}"))),
+    }
+}
+
+#[derive(Debug, Deserialize, IntoParams, ToSchema)]
+pub struct LiveFeedQuery {
+    /// "news", "trending", or "pair" (requires `pair`)
+    pub topic: String,
+    /// Kraken pair, required when `topic` is "pair" (e.g. "BTC/USD")
+    pub pair: Option<String>,
+    /// Poll interval in seconds; defaults to 30 for "news", 60 for "trending", 5 for "pair".
+    pub interval_secs: Option<u64>,
+}
+
+/// Resolves a request's `(topic, pair)` into the internal topic key and default poll interval
+/// [`crate::services::live_feed::LiveFeedHub`] keys its per-topic poll loop on.
+fn resolve_live_feed_topic(query: &LiveFeedQuery) -> Result<(String, std::time::Duration), ApiError> {
+    match query.topic.as_str() {
+        "news" => Ok(("news".to_string(), std::time::Duration::from_secs(query.interval_secs.unwrap_or(30)))),
+        "trending" => Ok(("trending".to_string(), std::time::Duration::from_secs(query.interval_secs.unwrap_or(60)))),
+        "pair" => {
+            let pair = query.pair.clone()
+                .ok_or_else(|| ApiError::BadRequest("`pair` is required when topic=pair".to_string()))?;
+            Ok((format!("pair:{pair}"), std::time::Duration::from_secs(query.interval_secs.unwrap_or(5))))
+        }
+        other => Err(ApiError::BadRequest(format!("unknown live feed topic \"{other}\""))),
+    }
+}
+
+/// Stream diffed news/trending/pair updates as Server-Sent Events. Pushes a
+/// [`crate::services::live_feed::FeedEvent::Update`] only when the underlying snapshot changes,
+/// or a `Lagged` marker if this connection fell behind the channel's bounded capacity.
+#[utoipa::path(get, path = "/stream", params(LiveFeedQuery), tag = "data", responses((status = 200, description = "Server-Sent Events live feed of news/trending/pair updates")))]
+pub async fn live_feed_stream(
+    State(state): State<AppState>,
+    Query(query): Query<LiveFeedQuery>,
+) -> Result<Sse<impl Stream<Item = Result<Event, Infallible>>>, ApiError> {
+    let (topic, interval) = resolve_live_feed_topic(&query)?;
+    let updates = state.live_feed_hub.clone().subscribe(topic, interval).map(|event| {
+        Ok(Event::default().json_data(&event).unwrap_or_else(|_| Event::default().data("{}")))
+    });
+    Ok(Sse::new(updates).keep_alive(KeepAlive::default()))
+}
+
+/// Same feed as [`live_feed_stream`], over a WebSocket instead of SSE.
+#[utoipa::path(
+    get,
+    path = "/stream/ws",
+    params(LiveFeedQuery),
+    tag = "data",
+    responses((status = 101, description = "Switching protocols to WebSocket"))
+)]
+pub async fn live_feed_stream_ws(
+    ws: WebSocketUpgrade,
+    State(state): State<AppState>,
+    Query(query): Query<LiveFeedQuery>,
+) -> Result<impl IntoResponse, ApiError> {
+    let (topic, interval) = resolve_live_feed_topic(&query)?;
+    let hub = state.live_feed_hub.clone();
+    Ok(ws.on_upgrade(move |socket| forward_live_feed(socket, hub, topic, interval)))
+}
+
+async fn forward_live_feed(
+    mut socket: WebSocket,
+    hub: std::sync::Arc<crate::services::live_feed::LiveFeedHub>,
+    topic: String,
+    interval: std::time::Duration,
+) {
+    let mut updates = Box::pin(hub.subscribe(topic, interval));
+    while let Some(event) = updates.next().await {
+        let Ok(text) = serde_json::to_string(&event) else { continue };
+        if socket.send(Message::Text(text.into())).await.is_err() {
+            break;
+        }
+    }
+}
+
 
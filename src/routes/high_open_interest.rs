@@ -1,5 +1,5 @@
 use axum::{
-    extract::{Query, Path},
+    extract::{Query, Path, State},
     http::StatusCode,
     response::Json,
     routing::get,
@@ -7,13 +7,20 @@ use axum::{
 };
 use serde::{Deserialize, Serialize};
 use utoipa::{IntoParams, ToSchema};
-use crate::helpers::high_open_interest::get_high_open_interest_contracts;
+use crate::helpers::high_open_interest::{get_high_open_interest_contracts, get_high_open_interest_contracts_batch};
+use crate::state::AppState;
 use crate::types::HighOpenInterestResult;
 
 #[derive(Deserialize, ToSchema, IntoParams)]
 pub struct HighOpenInterestQuery {
     pub ticker: String,
     pub option_type: Option<String>, // "call" or "put"
+    /// Risk-free annual rate used to solve implied vol and Greeks (default: 0.03)
+    pub rf_annual: Option<f64>,
+    /// Max `next_page_token` pages to follow per expiration leg (default: 10)
+    pub max_pages: Option<u32>,
+    /// Days-to-expiration threshold at which the short-term/leap cycle rolls to the next one (default: 5)
+    pub roll_when_within_days: Option<u32>,
 }
 
 #[derive(Serialize, ToSchema)]
@@ -38,13 +45,15 @@ pub struct HighOpenInterestResponse {
     tag = "high-open-interest"
 )]
 pub async fn get_high_open_interest_handler(
+    State(state): State<AppState>,
     Path(ticker): Path<String>,
     Query(query): Query<HighOpenInterestQuery>,
 ) -> Result<Json<HighOpenInterestResponse>, (StatusCode, Json<crate::types::ErrorResponse>)> {
     let option_type = query.option_type.as_deref();
-    
-    let result = get_high_open_interest_contracts(&ticker, option_type).await;
-    
+    let rf_annual = query.rf_annual.unwrap_or(0.03);
+
+    let result = get_high_open_interest_contracts(&ticker, option_type, &state.yahoo, rf_annual, query.max_pages, query.roll_when_within_days).await;
+
     Ok(Json(HighOpenInterestResponse {
         ticker,
         result,
@@ -67,6 +76,7 @@ pub async fn get_high_open_interest_handler(
     tag = "high-open-interest"
 )]
 pub async fn get_high_open_interest_batch_handler(
+    State(state): State<AppState>,
     Query(query): Query<HighOpenInterestBatchQuery>,
 ) -> Result<Json<Vec<HighOpenInterestResponse>>, (StatusCode, Json<crate::types::ErrorResponse>)> {
     let tickers: Vec<String> = query.tickers
@@ -85,15 +95,13 @@ pub async fn get_high_open_interest_batch_handler(
     }
 
     let option_type = query.option_type.as_deref();
-    let mut responses = Vec::new();
+    let rf_annual = query.rf_annual.unwrap_or(0.03);
 
-    for ticker in tickers {
-        let result = get_high_open_interest_contracts(&ticker, option_type).await;
-        responses.push(HighOpenInterestResponse {
-            ticker,
-            result,
-        });
-    }
+    let responses = get_high_open_interest_contracts_batch(&tickers, option_type, &state.yahoo, rf_annual, query.max_pages, query.roll_when_within_days)
+        .await
+        .into_iter()
+        .map(|(ticker, result)| HighOpenInterestResponse { ticker, result })
+        .collect();
 
     Ok(Json(responses))
 }
@@ -102,10 +110,17 @@ pub async fn get_high_open_interest_batch_handler(
 pub struct HighOpenInterestBatchQuery {
     pub tickers: String, // Comma-separated list
     pub option_type: Option<String>, // "call" or "put"
+    /// Risk-free annual rate used to solve implied vol and Greeks (default: 0.03)
+    pub rf_annual: Option<f64>,
+    /// Max `next_page_token` pages to follow per expiration leg (default: 10)
+    pub max_pages: Option<u32>,
+    /// Days-to-expiration threshold at which the short-term/leap cycle rolls to the next one (default: 5)
+    pub roll_when_within_days: Option<u32>,
 }
 
-pub fn router() -> Router {
+pub fn router(state: AppState) -> Router {
     Router::new()
         .route("/high-open-interest/{ticker}", get(get_high_open_interest_handler))
         .route("/high-open-interest/batch", get(get_high_open_interest_batch_handler))
+        .with_state(state)
 }
@@ -0,0 +1,34 @@
+use axum::{
+    extract::{Query, State},
+    http::StatusCode,
+    response::IntoResponse,
+    Json,
+};
+use serde::Deserialize;
+use serde_json::json;
+use utoipa::{IntoParams, ToSchema};
+
+use crate::errors::ApiError;
+use crate::state::AppState;
+
+#[derive(Debug, Deserialize, ToSchema, IntoParams)]
+pub struct HistoryQuery {
+    pub symbol: String,
+    pub signal: Option<String>,
+    pub limit: Option<i64>,
+}
+
+/// Stored composite-score history for a symbol, backed by [`crate::services::history::HistoryStore`].
+/// Returns an empty history (not an error) when no database is configured.
+#[utoipa::path(get, path = "/recommendations/history", params(HistoryQuery), tag = "data", responses((status = 200, description = "Stored composite-score history for a symbol")))]
+pub async fn get_recommendations_history(
+    State(state): State<AppState>,
+    Query(q): Query<HistoryQuery>,
+) -> Result<impl IntoResponse, ApiError> {
+    let limit = q.limit.unwrap_or(100).clamp(1, 1000);
+    let history = state
+        .history_store
+        .history_for_symbol(&q.symbol, q.signal.as_deref(), limit)
+        .await?;
+    Ok((StatusCode::OK, Json(json!({ "symbol": q.symbol, "history": history }))))
+}
@@ -1,18 +1,30 @@
+use std::sync::Arc;
+
 use axum::{
-    extract::{Path, Query, State},
+    extract::{
+        ws::{Message, WebSocket, WebSocketUpgrade},
+        Path, Query, State,
+    },
     http::StatusCode,
-    response::IntoResponse,
-    routing::get,
+    response::{
+        sse::{Event, KeepAlive, Sse},
+        IntoResponse,
+    },
+    routing::{get, post},
     Json, Router,
 };
+use futures::future::join_all;
+use futures::{Stream, StreamExt};
 use serde::{Deserialize, Serialize};
+use std::convert::Infallible;
 use utoipa::{IntoParams, ToSchema};
 
 use crate::sources::hyperliquid_data::{
-    HyperliquidDataSource, HyperliquidMarket, HyperliquidOrderbook, HyperliquidCandle,
-    HyperliquidUserState, HyperliquidFunding, HyperliquidTrade,
+    HyperliquidMarket, HyperliquidOrderbook, HyperliquidCandle,
+    HyperliquidUserState, HyperliquidFunding, HyperliquidTrade, HyperliquidWsChannel, HyperliquidWsHub,
 };
 use crate::errors::ApiError;
+use crate::routes::coingecko::CoinGeckoTicker;
 use crate::state::AppState;
 use crate::types::TrendingItem;
 
@@ -23,6 +35,26 @@ pub struct HyperliquidQuery {
     pub interval: Option<String>,
     pub start_time: Option<u64>,
     pub end_time: Option<u64>,
+    /// Candle resolution to serve from the store (`1m`/`5m`/`15m`/`1h`/`4h`/`1d`), synthesized
+    /// server-side from the stored base-resolution (`interval`) candles. When set, `/hyperliquid/candles/{coin}`
+    /// is served from [`crate::services::candles::CandleStore`] instead of always hitting
+    /// Hyperliquid directly, falling back to a live fetch (upserted into the store for next time)
+    /// on a cache miss, mirroring `/kraken/ohlc/{pair}`'s `resolution` param.
+    pub resolution: Option<String>,
+}
+
+/// Seconds per candle for each resolution label the OHLC store accepts. Mirrors
+/// `routes::kraken::resolution_seconds`.
+fn resolution_seconds(label: &str) -> Option<i64> {
+    match label {
+        "1m" => Some(60),
+        "5m" => Some(5 * 60),
+        "15m" => Some(15 * 60),
+        "1h" => Some(60 * 60),
+        "4h" => Some(4 * 60 * 60),
+        "1d" => Some(24 * 60 * 60),
+        _ => None,
+    }
 }
 
 #[derive(Debug, Serialize, ToSchema)]
@@ -52,9 +84,35 @@ pub fn router(state: AppState) -> Router {
         .route("/volume/top", get(get_top_volume))
         .route("/movers", get(get_top_movers))
         .route("/stats/overview", get(get_market_overview))
+        .route("/coingecko/tickers", get(get_coingecko_tickers))
+        .route("/backfill", post(start_backfill))
+        .route("/ws/{channel}/{coin}", get(hyperliquid_ws_stream))
+        .route("/stream/{channel}/{coin}", get(hyperliquid_sse_stream))
         .with_state(state)
 }
 
+/// Fetches and caches the full market snapshot for 15s, so concurrent requests to
+/// `/hyperliquid/markets` and anything derived from it (`/hyperliquid/stats/overview`) coalesce
+/// onto one upstream `meta()` call instead of each re-fetching (see
+/// `crate::cache::MemoryCache::get_or_compute` for the stampede protection this gets).
+async fn cached_all_markets(state: &AppState) -> Result<Vec<HyperliquidMarket>, ApiError> {
+    let cache_key = crate::cache::cache_key("hyperliquid_all_markets", &[]);
+    let hyperliquid = state.hyperliquid.clone();
+    let cached = state.cache.get_or_compute(&cache_key, std::time::Duration::from_secs(15), || async move {
+        match hyperliquid.get_all_markets().await {
+            Ok(markets) => serde_json::json!(markets),
+            Err(e) => serde_json::json!({ "error": e.to_string() }),
+        }
+    }).await;
+
+    if let Some(error) = cached.get("error").and_then(|v| v.as_str()) {
+        return Err(ApiError::Upstream(error.to_string()));
+    }
+
+    serde_json::from_value(cached)
+        .map_err(|e| ApiError::InternalError(format!("failed to deserialize cached hyperliquid markets: {e}")))
+}
+
 /// Get all available markets
 #[utoipa::path(
     get,
@@ -63,14 +121,10 @@ pub fn router(state: AppState) -> Router {
     responses((status = 200, description = "All available markets", body = HyperliquidResponse<Vec<HyperliquidMarket>>))
 )]
 pub async fn get_all_markets(
-    State(_state): State<AppState>,
+    State(state): State<AppState>,
 ) -> Result<impl IntoResponse, ApiError> {
-    let hyperliquid = HyperliquidDataSource::new().await
-        .map_err(|e| ApiError::Upstream(e.to_string()))?;
-    
-    let markets = hyperliquid.get_all_markets().await
-        .map_err(|e| ApiError::Upstream(e.to_string()))?;
-    
+    let markets = cached_all_markets(&state).await?;
+
     let timestamp = std::time::SystemTime::now()
         .duration_since(std::time::UNIX_EPOCH)
         .unwrap()
@@ -97,13 +151,10 @@ pub async fn get_all_markets(
     responses((status = 200, description = "Market data for coin", body = HyperliquidResponse<HyperliquidMarket>))
 )]
 pub async fn get_market_data(
-    State(_state): State<AppState>,
+    State(state): State<AppState>,
     Path(coin): Path<String>,
 ) -> Result<impl IntoResponse, ApiError> {
-    let hyperliquid = HyperliquidDataSource::new().await
-        .map_err(|e| ApiError::Upstream(e.to_string()))?;
-    
-    let market = hyperliquid.get_market_data(&coin).await
+    let market = state.hyperliquid.get_market_data(&coin).await
         .map_err(|e| ApiError::Upstream(e.to_string()))?;
     
     let timestamp = std::time::SystemTime::now()
@@ -133,14 +184,11 @@ pub async fn get_market_data(
     responses((status = 200, description = "Orderbook data", body = HyperliquidResponse<HyperliquidOrderbook>))
 )]
 pub async fn get_orderbook(
-    State(_state): State<AppState>,
+    State(state): State<AppState>,
     Path(coin): Path<String>,
     Query(query): Query<HyperliquidQuery>,
 ) -> Result<impl IntoResponse, ApiError> {
-    let hyperliquid = HyperliquidDataSource::new().await
-        .map_err(|e| ApiError::Upstream(e.to_string()))?;
-    
-    let orderbook = hyperliquid.get_orderbook(&coin, query.depth).await
+    let orderbook = state.hyperliquid.get_orderbook(&coin, query.depth).await
         .map_err(|e| ApiError::Upstream(e.to_string()))?;
     
     let timestamp = std::time::SystemTime::now()
@@ -170,14 +218,11 @@ pub async fn get_orderbook(
     responses((status = 200, description = "Recent trades", body = HyperliquidResponse<Vec<HyperliquidTrade>>))
 )]
 pub async fn get_recent_trades(
-    State(_state): State<AppState>,
+    State(state): State<AppState>,
     Path(coin): Path<String>,
     Query(query): Query<HyperliquidQuery>,
 ) -> Result<impl IntoResponse, ApiError> {
-    let hyperliquid = HyperliquidDataSource::new().await
-        .map_err(|e| ApiError::Upstream(e.to_string()))?;
-    
-    let trades = hyperliquid.get_recent_trades(&coin, query.limit).await
+    let trades = state.hyperliquid.get_recent_trades(&coin, query.limit).await
         .map_err(|e| ApiError::Upstream(e.to_string()))?;
     
     let timestamp = std::time::SystemTime::now()
@@ -195,7 +240,10 @@ pub async fn get_recent_trades(
     Ok((StatusCode::OK, Json(response)))
 }
 
-/// Get candlestick data
+/// Get candlestick data. With no `resolution`, this is a direct pass-through to Hyperliquid
+/// (legacy behavior). With `resolution` set, candles are served from
+/// [`crate::services::candles::CandleStore`], falling back to a live fetch (upserted into the
+/// store when it's enabled) when the requested bucket is missing.
 #[utoipa::path(
     get,
     path = "/hyperliquid/candles/{coin}",
@@ -207,35 +255,241 @@ pub async fn get_recent_trades(
     responses((status = 200, description = "Candlestick data", body = HyperliquidResponse<Vec<HyperliquidCandle>>))
 )]
 pub async fn get_candles(
-    State(_state): State<AppState>,
+    State(state): State<AppState>,
     Path(coin): Path<String>,
     Query(query): Query<HyperliquidQuery>,
 ) -> Result<impl IntoResponse, ApiError> {
-    let hyperliquid = HyperliquidDataSource::new().await
-        .map_err(|e| ApiError::Upstream(e.to_string()))?;
-    
     let interval = query.interval.as_deref().unwrap_or("1h");
     let end_time = query.end_time.unwrap_or_else(|| chrono::Utc::now().timestamp_millis() as u64);
     let start_time = query.start_time.unwrap_or(end_time - 24 * 60 * 60 * 1000); // 24 hours ago
-    
-    let candles = hyperliquid.get_candles(&coin, interval, start_time, end_time).await
-        .map_err(|e| ApiError::Upstream(e.to_string()))?;
-    
+
+    let candles = match query.resolution.as_deref().and_then(resolution_seconds) {
+        Some(resolution_seconds) if state.candle_store.is_enabled() => {
+            let pair = crate::sources::hyperliquid_data::candle_store_pair(&coin, interval);
+            let since = chrono::DateTime::from_timestamp((start_time / 1000) as i64, 0);
+            let rows = state.candle_store.aggregated_candles(&pair, resolution_seconds, since, 5_000).await?;
+
+            if !rows.is_empty() {
+                rows.into_iter()
+                    .map(|row| HyperliquidCandle {
+                        coin: coin.clone(),
+                        interval: interval.to_string(),
+                        time: row.bucket_start.timestamp_millis() as u64,
+                        open: row.open,
+                        high: row.high,
+                        low: row.low,
+                        close: row.close,
+                        volume: row.volume,
+                    })
+                    .collect()
+            } else {
+                // Bucket missing: fetch live, upsert into the store (so the next request for
+                // this range hits the cache), then aggregate for the response. Mirrors
+                // `routes::kraken::get_ohlc`'s write-through-on-miss fallback.
+                let live = state.hyperliquid.get_candles(&coin, interval, start_time, end_time).await
+                    .map_err(|e| ApiError::Upstream(e.to_string()))?;
+                crate::sources::hyperliquid_data::backfill_candles(&state.candle_store, &coin, interval, &live).await?;
+                let rows = state.candle_store.aggregated_candles(&pair, resolution_seconds, since, 5_000).await?;
+
+                if !rows.is_empty() {
+                    rows.into_iter()
+                        .map(|row| HyperliquidCandle {
+                            coin: coin.clone(),
+                            interval: interval.to_string(),
+                            time: row.bucket_start.timestamp_millis() as u64,
+                            open: row.open,
+                            high: row.high,
+                            low: row.low,
+                            close: row.close,
+                            volume: row.volume,
+                        })
+                        .collect()
+                } else {
+                    live
+                }
+            }
+        }
+        _ => {
+            state.hyperliquid.get_candles(&coin, interval, start_time, end_time).await
+                .map_err(|e| ApiError::Upstream(e.to_string()))?
+        }
+    };
+
     let timestamp = std::time::SystemTime::now()
         .duration_since(std::time::UNIX_EPOCH)
         .unwrap()
         .as_secs();
-    
+
     let response = HyperliquidResponse {
         success: true,
         data: candles,
         timestamp,
         source: "hyperliquid".to_string(),
     };
-    
+
     Ok((StatusCode::OK, Json(response)))
 }
 
+#[derive(Debug, Deserialize, ToSchema)]
+pub struct HyperliquidBackfillRequest {
+    pub coin: String,
+    /// Hyperliquid's native candle interval to persist as the stored base resolution (e.g.
+    /// `"1m"`); `resolution` on `/hyperliquid/candles/{coin}` must be this width or coarser.
+    pub interval: String,
+    /// Unix ms to start backfilling from.
+    pub start_time: u64,
+    /// Unix ms to stop at; defaults to now.
+    pub end_time: Option<u64>,
+    /// Window size in ms fetched per upstream call. Defaults to 1 day.
+    pub window_ms: Option<u64>,
+}
+
+/// Start a background backfill job that walks `[start_time, end_time)` in `window_ms` windows,
+/// persisting each window's candles into [`crate::services::candles::CandleStore`] so
+/// `/hyperliquid/candles/{coin}?resolution=...` has history to aggregate from. Returns
+/// immediately; the job itself runs detached (see [`crate::sources::hyperliquid_data::backfill_gaps`]).
+#[utoipa::path(
+    post,
+    path = "/hyperliquid/backfill",
+    request_body = HyperliquidBackfillRequest,
+    tag = "hyperliquid",
+    responses((status = 202, description = "Backfill job started"))
+)]
+pub async fn start_backfill(
+    State(state): State<AppState>,
+    Json(req): Json<HyperliquidBackfillRequest>,
+) -> Result<impl IntoResponse, ApiError> {
+    let end_time = req.end_time.unwrap_or_else(|| chrono::Utc::now().timestamp_millis() as u64);
+    let window_ms = req.window_ms.unwrap_or(24 * 60 * 60 * 1000);
+    let candle_store = state.candle_store.clone();
+    let hyperliquid = state.hyperliquid.clone();
+
+    tokio::spawn(async move {
+        if let Err(e) = crate::sources::hyperliquid_data::backfill_gaps(
+            &hyperliquid,
+            &candle_store,
+            &req.coin,
+            &req.interval,
+            req.start_time,
+            end_time,
+            window_ms,
+        )
+        .await
+        {
+            tracing::warn!("hyperliquid backfill: failed for {}/{}: {e}", req.coin, req.interval);
+        }
+    });
+
+    Ok(StatusCode::ACCEPTED)
+}
+
+/// Parses a `/hyperliquid/ws|stream/{channel}/{coin}` path segment into the channel enum the hub
+/// uses, so both the WebSocket and SSE entry points accept the same channel names.
+fn parse_ws_channel(channel: &str) -> Result<HyperliquidWsChannel, ApiError> {
+    match channel {
+        "orderbook" => Ok(HyperliquidWsChannel::Orderbook),
+        "trades" => Ok(HyperliquidWsChannel::Trades),
+        "funding" => Ok(HyperliquidWsChannel::Funding),
+        "candles" => Ok(HyperliquidWsChannel::Candle),
+        other => Err(ApiError::InvalidInput(format!("unknown hyperliquid ws channel: {other}"))),
+    }
+}
+
+/// Stream live Hyperliquid orderbook/trade/funding/candle updates over WebSocket instead of
+/// polling `/hyperliquid/orderbook|trades|candles` on an interval. One upstream connection per
+/// `(channel, coin, interval)` is shared across every connected client via
+/// [`crate::sources::hyperliquid_data::HyperliquidWsHub`], which tears the upstream subscription
+/// down once the last client disconnects. The first frame sent is a REST snapshot (via
+/// [`crate::sources::hyperliquid_data::snapshot_for`]) so the client starts from current state
+/// instead of waiting on the next broadcast; `trades` and `funding` have no snapshot and start
+/// live-only.
+#[utoipa::path(
+    get,
+    path = "/hyperliquid/ws/{channel}/{coin}",
+    params(
+        ("channel" = String, Path, description = "orderbook, trades, funding, or candles"),
+        ("coin" = String, Path, description = "Coin symbol (e.g., BTC, ETH)"),
+        HyperliquidQuery
+    ),
+    tag = "hyperliquid",
+    responses((status = 101, description = "WebSocket upgrade, streaming HyperliquidResponse<T> frames"))
+)]
+pub async fn hyperliquid_ws_stream(
+    ws: WebSocketUpgrade,
+    State(state): State<AppState>,
+    Path((channel, coin)): Path<(String, String)>,
+    Query(query): Query<HyperliquidQuery>,
+) -> Result<impl IntoResponse, ApiError> {
+    let channel = parse_ws_channel(&channel)?;
+    let interval = query.interval;
+    let snapshot = crate::sources::hyperliquid_data::snapshot_for(&state.hyperliquid, channel, &coin, interval.as_deref()).await;
+
+    let hub = state.hyperliquid_ws_hub.clone();
+    Ok(ws.on_upgrade(move |socket| forward_hyperliquid_updates(socket, hub, channel, coin, interval, snapshot)))
+}
+
+async fn forward_hyperliquid_updates(
+    mut socket: WebSocket,
+    hub: Arc<HyperliquidWsHub>,
+    channel: HyperliquidWsChannel,
+    coin: String,
+    interval: Option<String>,
+    snapshot: Option<serde_json::Value>,
+) {
+    let mut updates = Box::pin(hub.subscribe_with_snapshot(channel, coin, interval, snapshot));
+
+    while let Some(value) = updates.next().await {
+        let timestamp = std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .unwrap_or_default()
+            .as_secs();
+
+        let response = HyperliquidResponse {
+            success: true,
+            data: value,
+            timestamp,
+            source: "hyperliquid".to_string(),
+        };
+
+        let Ok(text) = serde_json::to_string(&response) else { continue };
+        if socket.send(Message::Text(text.into())).await.is_err() {
+            break;
+        }
+    }
+}
+
+/// Stream live Hyperliquid orderbook/trade/funding/candle updates over Server-Sent Events,
+/// instead of the polling `/hyperliquid/orderbook|trades|candles` endpoints or the WebSocket
+/// endpoint above -- same hub, same REST-snapshot-then-live-delta behavior, just plain HTTP for
+/// clients that can't open a WebSocket.
+#[utoipa::path(
+    get,
+    path = "/hyperliquid/stream/{channel}/{coin}",
+    params(
+        ("channel" = String, Path, description = "orderbook, trades, funding, or candles"),
+        ("coin" = String, Path, description = "Coin symbol (e.g., BTC, ETH)"),
+        HyperliquidQuery
+    ),
+    tag = "hyperliquid",
+    responses((status = 200, description = "Server-Sent Events stream of HyperliquidResponse<T> frames"))
+)]
+pub async fn hyperliquid_sse_stream(
+    State(state): State<AppState>,
+    Path((channel, coin)): Path<(String, String)>,
+    Query(query): Query<HyperliquidQuery>,
+) -> Result<Sse<impl Stream<Item = Result<Event, Infallible>>>, ApiError> {
+    let channel = parse_ws_channel(&channel)?;
+    let interval = query.interval;
+    let snapshot = crate::sources::hyperliquid_data::snapshot_for(&state.hyperliquid, channel, &coin, interval.as_deref()).await;
+
+    let hub = state.hyperliquid_ws_hub.clone();
+    let updates = hub
+        .subscribe_with_snapshot(channel, coin, interval, snapshot)
+        .map(|value| Ok(Event::default().json_data(value).unwrap_or_else(|_| Event::default().data("{}"))));
+
+    Ok(Sse::new(updates).keep_alive(KeepAlive::default()))
+}
+
 /// Get user state (requires user address)
 #[utoipa::path(
     get,
@@ -247,13 +501,10 @@ pub async fn get_candles(
     responses((status = 200, description = "User state and positions", body = HyperliquidResponse<HyperliquidUserState>))
 )]
 pub async fn get_user_state(
-    State(_state): State<AppState>,
+    State(state): State<AppState>,
     Path(address): Path<String>,
 ) -> Result<impl IntoResponse, ApiError> {
-    let hyperliquid = HyperliquidDataSource::new().await
-        .map_err(|e| ApiError::Upstream(e.to_string()))?;
-    
-    let user_state = hyperliquid.get_user_state(&address).await
+    let user_state = state.hyperliquid.get_user_state(&address).await
         .map_err(|e| ApiError::Upstream(e.to_string()))?;
     
     let timestamp = std::time::SystemTime::now()
@@ -279,14 +530,24 @@ pub async fn get_user_state(
     responses((status = 200, description = "Funding rates", body = HyperliquidResponse<Vec<HyperliquidFunding>>))
 )]
 pub async fn get_funding_rates(
-    State(_state): State<AppState>,
+    State(state): State<AppState>,
 ) -> Result<impl IntoResponse, ApiError> {
-    let hyperliquid = HyperliquidDataSource::new().await
-        .map_err(|e| ApiError::Upstream(e.to_string()))?;
-    
-    let funding_rates = hyperliquid.get_funding_rates().await
-        .map_err(|e| ApiError::Upstream(e.to_string()))?;
-    
+    let cache_key = crate::cache::cache_key("hyperliquid_funding_rates", &[]);
+    let hyperliquid = state.hyperliquid.clone();
+    let cached = state.cache.get_or_compute(&cache_key, std::time::Duration::from_secs(15), || async move {
+        match hyperliquid.get_funding_rates().await {
+            Ok(rates) => serde_json::json!(rates),
+            Err(e) => serde_json::json!({ "error": e.to_string() }),
+        }
+    }).await;
+
+    if let Some(error) = cached.get("error").and_then(|v| v.as_str()) {
+        return Err(ApiError::Upstream(error.to_string()));
+    }
+
+    let funding_rates: Vec<HyperliquidFunding> = serde_json::from_value(cached)
+        .map_err(|e| ApiError::InternalError(format!("failed to deserialize cached hyperliquid funding rates: {e}")))?;
+
     let timestamp = std::time::SystemTime::now()
         .duration_since(std::time::UNIX_EPOCH)
         .unwrap()
@@ -313,17 +574,14 @@ pub async fn get_funding_rates(
     responses((status = 200, description = "Trending DeFi assets", body = HyperliquidResponse<Vec<TrendingItem>>))
 )]
 pub async fn get_trending_defi(
-    State(_state): State<AppState>,
+    State(state): State<AppState>,
     Query(params): Query<std::collections::HashMap<String, String>>,
 ) -> Result<impl IntoResponse, ApiError> {
-    let hyperliquid = HyperliquidDataSource::new().await
-        .map_err(|e| ApiError::Upstream(e.to_string()))?;
-    
     let limit = params.get("limit")
         .and_then(|l| l.parse::<usize>().ok())
         .unwrap_or(20);
-    
-    let trending = hyperliquid.get_trending_defi_assets(limit).await
+
+    let trending = state.hyperliquid.get_trending_defi_assets(limit).await
         .map_err(|e| ApiError::Upstream(e.to_string()))?;
     
     let timestamp = std::time::SystemTime::now()
@@ -352,17 +610,14 @@ pub async fn get_trending_defi(
     responses((status = 200, description = "Top markets by volume", body = HyperliquidResponse<Vec<HyperliquidMarket>>))
 )]
 pub async fn get_top_volume(
-    State(_state): State<AppState>,
+    State(state): State<AppState>,
     Query(params): Query<std::collections::HashMap<String, String>>,
 ) -> Result<impl IntoResponse, ApiError> {
-    let hyperliquid = HyperliquidDataSource::new().await
-        .map_err(|e| ApiError::Upstream(e.to_string()))?;
-    
     let limit = params.get("limit")
         .and_then(|l| l.parse::<usize>().ok())
         .unwrap_or(20);
-    
-    let top_markets = hyperliquid.get_top_volume_markets(limit).await
+
+    let top_markets = state.hyperliquid.get_top_volume_markets(limit).await
         .map_err(|e| ApiError::Upstream(e.to_string()))?;
     
     let timestamp = std::time::SystemTime::now()
@@ -391,17 +646,14 @@ pub async fn get_top_volume(
     responses((status = 200, description = "Top gainers and losers", body = HyperliquidResponse<MarketMoversResponse>))
 )]
 pub async fn get_top_movers(
-    State(_state): State<AppState>,
+    State(state): State<AppState>,
     Query(params): Query<std::collections::HashMap<String, String>>,
 ) -> Result<impl IntoResponse, ApiError> {
-    let hyperliquid = HyperliquidDataSource::new().await
-        .map_err(|e| ApiError::Upstream(e.to_string()))?;
-    
     let limit = params.get("limit")
         .and_then(|l| l.parse::<usize>().ok())
         .unwrap_or(10);
-    
-    let (gainers, losers) = hyperliquid.get_top_movers(limit).await
+
+    let (gainers, losers) = state.hyperliquid.get_top_movers(limit).await
         .map_err(|e| ApiError::Upstream(e.to_string()))?;
     
     let timestamp = std::time::SystemTime::now()
@@ -429,14 +681,10 @@ pub async fn get_top_movers(
     responses((status = 200, description = "Market overview statistics", body = HyperliquidResponse<serde_json::Value>))
 )]
 pub async fn get_market_overview(
-    State(_state): State<AppState>,
+    State(state): State<AppState>,
 ) -> Result<impl IntoResponse, ApiError> {
-    let hyperliquid = HyperliquidDataSource::new().await
-        .map_err(|e| ApiError::Upstream(e.to_string()))?;
-    
-    let markets = hyperliquid.get_all_markets().await
-        .map_err(|e| ApiError::Upstream(e.to_string()))?;
-    
+    let markets = cached_all_markets(&state).await?;
+
     // Calculate overview statistics
     let total_volume_24h: f64 = markets.iter().map(|m| m.volume_24h).sum();
     let total_open_interest: f64 = markets.iter().map(|m| m.open_interest).sum();
@@ -475,6 +723,82 @@ pub async fn get_market_overview(
         timestamp,
         source: "hyperliquid".to_string(),
     };
-    
+
+    Ok((StatusCode::OK, Json(response)))
+}
+
+/// Looks up `market`'s top-of-book bid/ask (via a live [`HyperliquidDataSource::get_orderbook`]
+/// call) and its 24h high/low (from the last day of 1h candles), falling back to `last_price` for
+/// whichever roll-up fails -- e.g. a coin with no open book -- so one bad upstream call doesn't
+/// drop the whole ticker.
+async fn ticker_for_market(hyperliquid: &crate::sources::hyperliquid_data::HyperliquidDataSource, market: HyperliquidMarket) -> CoinGeckoTicker {
+    let last_price = if market.mark_price > 0.0 { market.mark_price } else { market.index_price };
+
+    let end_time = chrono::Utc::now().timestamp_millis() as u64;
+    let start_time = end_time.saturating_sub(24 * 60 * 60 * 1000);
+    let (orderbook, candles) = tokio::join!(
+        hyperliquid.get_orderbook(&market.coin, None),
+        hyperliquid.get_candles(&market.coin, "1h", start_time, end_time)
+    );
+
+    let (bid, ask) = orderbook.ok()
+        .and_then(|book| Some((book.levels.first()?.first()?.price, book.levels.get(1)?.first()?.price)))
+        .unwrap_or((last_price, last_price));
+
+    let (high, low) = candles.ok()
+        .filter(|candles| !candles.is_empty())
+        .map(|candles| {
+            let high = candles.iter().fold(f64::MIN, |acc, c| acc.max(c.high));
+            let low = candles.iter().fold(f64::MAX, |acc, c| acc.min(c.low));
+            (high, low)
+        })
+        .unwrap_or((last_price, last_price));
+
+    CoinGeckoTicker {
+        ticker_id: format!("{}_USD", market.coin),
+        base_currency: market.coin,
+        target_currency: "USD".to_string(),
+        last_price,
+        base_volume: market.volume_24h,
+        target_volume: market.volume_24h * last_price,
+        high,
+        low,
+        bid,
+        ask,
+    }
+}
+
+/// Reshapes `HyperliquidMarket` data into CoinGecko's standard tickers schema (ticker_id,
+/// base/target currency, last_price, base_volume, target_volume, bid/ask, high/low), mirroring
+/// `crate::routes::coingecko::get_tickers_route`, so aggregators that expect that schema can
+/// index Hyperliquid markets without a bespoke adapter. Each ticker's bid/ask and high/low are
+/// fetched concurrently across markets, since they're independent per-coin roll-ups.
+#[utoipa::path(
+    get,
+    path = "/hyperliquid/coingecko/tickers",
+    tag = "hyperliquid",
+    responses((status = 200, description = "Hyperliquid markets in CoinGecko tickers format", body = HyperliquidResponse<Vec<CoinGeckoTicker>>))
+)]
+pub async fn get_coingecko_tickers(
+    State(state): State<AppState>,
+) -> Result<impl IntoResponse, ApiError> {
+    let markets = cached_all_markets(&state).await?;
+
+    let tickers: Vec<CoinGeckoTicker> = join_all(
+        markets.into_iter().map(|market| ticker_for_market(&state.hyperliquid, market))
+    ).await;
+
+    let timestamp = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .unwrap()
+        .as_secs();
+
+    let response = HyperliquidResponse {
+        success: true,
+        data: tickers,
+        timestamp,
+        source: "hyperliquid".to_string(),
+    };
+
     Ok((StatusCode::OK, Json(response)))
 }
@@ -0,0 +1,121 @@
+use std::sync::Arc;
+
+use axum::{
+    extract::{ws::{Message, WebSocket, WebSocketUpgrade}, Query, State},
+    http::StatusCode,
+    response::IntoResponse,
+    routing::{get, post},
+    Json, Router,
+};
+use futures::StreamExt;
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+use crate::errors::ApiError;
+use crate::sources::jito_data::{BundleRequest, BundleResult, JitoService, SwapIntent, UnsignedSwapBundle};
+use crate::state::AppState;
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct JitoResponse<T> {
+    pub success: bool,
+    pub data: T,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct BundleStatusSubscribeQuery {
+    pub bundle_ids: String, // comma-separated bundle IDs
+}
+
+pub fn router(state: AppState) -> Router {
+    Router::new()
+        .route("/swap", post(swap_to_bundle))
+        .route("/bundles", post(submit_bundle))
+        .route("/bundles/status/stream", get(bundle_status_stream))
+        .with_state(state)
+}
+
+/// Build a Jupiter v6 swap plus a Jito tip transfer and return both unsigned.
+///
+/// The server holds no private key for the caller-supplied `user_pubkey`, so it cannot sign
+/// or submit these on the caller's behalf. The caller must sign both transactions themselves
+/// (e.g. with their own wallet) and resubmit them through `POST /jito/bundles`.
+#[utoipa::path(
+    post,
+    path = "/jito/swap",
+    request_body = SwapIntent,
+    tag = "jito",
+    responses((status = 200, description = "Unsigned swap and tip transactions for the caller to sign and resubmit", body = JitoResponse<UnsignedSwapBundle>))
+)]
+pub async fn swap_to_bundle(
+    State(state): State<AppState>,
+    Json(intent): Json<SwapIntent>,
+) -> Result<impl IntoResponse, ApiError> {
+    let mut jito = JitoService::new(state.config.jito.clone());
+    jito.initialize().await?;
+
+    let result = jito.build_unsigned_swap_bundle(intent).await?;
+
+    Ok((StatusCode::OK, Json(JitoResponse { success: true, data: result })))
+}
+
+/// Submit a set of base64-encoded, signed transactions as a tipped Jito bundle.
+///
+/// Intended as the counterpart to `POST /jito/swap`: once the caller has signed the unsigned
+/// swap and tip transactions that endpoint returned, they resubmit both here.
+#[utoipa::path(
+    post,
+    path = "/jito/bundles",
+    request_body = BundleRequest,
+    tag = "jito",
+    responses((status = 200, description = "Bundle submitted", body = JitoResponse<BundleResult>))
+)]
+pub async fn submit_bundle(
+    State(state): State<AppState>,
+    Json(request): Json<BundleRequest>,
+) -> Result<impl IntoResponse, ApiError> {
+    let mut jito = JitoService::new(state.config.jito.clone());
+    jito.initialize().await?;
+
+    let result = jito.send_bundle(request).await?;
+
+    Ok((StatusCode::OK, Json(JitoResponse { success: true, data: result })))
+}
+
+/// Stream bundle confirmation updates over a WebSocket, so clients don't poll bundle statuses
+/// themselves.
+pub async fn bundle_status_stream(
+    ws: WebSocketUpgrade,
+    State(state): State<AppState>,
+    Query(query): Query<BundleStatusSubscribeQuery>,
+) -> Result<impl IntoResponse, ApiError> {
+    let bundle_ids: Vec<String> = query.bundle_ids
+        .split(',')
+        .map(|s| s.trim().to_string())
+        .filter(|s| !s.is_empty())
+        .collect();
+
+    if bundle_ids.is_empty() {
+        return Err(ApiError::InvalidInput("bundle_ids must not be empty".to_string()));
+    }
+
+    let mut jito = JitoService::new(state.config.jito.clone());
+    jito.initialize().await?;
+    let jito = Arc::new(jito);
+
+    Ok(ws.on_upgrade(move |socket| forward_bundle_statuses(socket, jito, bundle_ids)))
+}
+
+async fn forward_bundle_statuses(mut socket: WebSocket, jito: Arc<JitoService>, bundle_ids: Vec<String>) {
+    let mut updates = Box::pin(jito.subscribe_bundle_statuses(bundle_ids));
+
+    while let Some(update) = updates.next().await {
+        let payload = match serde_json::to_string(&update) {
+            Ok(payload) => payload,
+            Err(_) => continue,
+        };
+
+        if socket.send(Message::Text(payload.into())).await.is_err() {
+            break;
+        }
+    }
+}
@@ -1,11 +1,17 @@
 use axum::{
-    extract::{Path, Query},
+    extract::{Path, Query, State},
     http::StatusCode,
-    response::IntoResponse,
-    routing::get,
+    response::{
+        sse::{Event, KeepAlive, Sse},
+        IntoResponse,
+    },
+    routing::{get, post},
     Json, Router,
 };
+use futures::Stream;
 use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::convert::Infallible;
 use utoipa::{IntoParams, ToSchema};
 
 use crate::sources::kraken_data::{
@@ -21,6 +27,80 @@ pub struct KrakenQuery {
     pub limit: Option<usize>,
     pub since: Option<u64>,
     pub interval: Option<u32>,
+    /// Candle resolution to serve from the OHLC store (`1m`/`5m`/`15m`/`1h`/`4h`/`1d`).
+    /// When set, `/kraken/ohlc/{pair}` is served from [`crate::services::candles::CandleStore`];
+    /// if the store is disabled or has no rows, 1-minute candles are fetched from Kraken and
+    /// aggregated to this resolution. Omit to keep the legacy direct-fetch behavior.
+    pub resolution: Option<String>,
+}
+
+/// Seconds per candle for each resolution label the OHLC store accepts.
+fn resolution_seconds(label: &str) -> Option<i64> {
+    crate::services::candles::Resolution::parse(label).map(|r| r.as_secs())
+}
+
+/// Parses Kraken's `[time, open, high, low, close, vwap, volume, count]` OHLC rows (as
+/// returned by `KrakenDataSource::get_ohlc`) into 1-minute [`Candle`](crate::services::candles::Candle)s.
+pub(crate) fn parse_kraken_ohlc_rows(pair: &str, raw: &Value) -> Vec<crate::services::candles::Candle> {
+    use crate::services::candles::Candle;
+
+    let Some(rows) = raw.get("ohlc").and_then(|v| v.as_array()) else { return Vec::new() };
+
+    rows.iter()
+        .filter_map(|row| {
+            let row = row.as_array()?;
+            let parse = |v: &Value| -> Option<f64> {
+                v.as_f64().or_else(|| v.as_str().and_then(|s| s.parse().ok()))
+            };
+            let time = parse(row.first()?)?;
+            let open = parse(row.get(1)?)?;
+            let high = parse(row.get(2)?)?;
+            let low = parse(row.get(3)?)?;
+            let close = parse(row.get(4)?)?;
+            let volume = parse(row.get(6)?)?;
+            Some(Candle {
+                pair: pair.to_string(),
+                bucket_start: chrono::DateTime::from_timestamp(time as i64, 0)?,
+                open,
+                high,
+                low,
+                close,
+                volume,
+            })
+        })
+        .collect()
+}
+
+/// Rolls up already-fetched 1-minute candles into `resolution_seconds` buckets in-process,
+/// the same bucketing rule the store applies in SQL, for use when the candle store isn't
+/// configured at all.
+fn aggregate_candles_in_memory(candles: &[crate::services::candles::Candle], resolution_seconds: i64) -> Vec<crate::services::candles::Candle> {
+    use crate::services::candles::Candle;
+    use std::collections::BTreeMap;
+
+    let mut buckets: BTreeMap<i64, Vec<&Candle>> = BTreeMap::new();
+    for candle in candles {
+        let bucket = (candle.bucket_start.timestamp() as f64 / resolution_seconds as f64).floor() as i64 * resolution_seconds;
+        buckets.entry(bucket).or_default().push(candle);
+    }
+
+    buckets
+        .into_iter()
+        .filter_map(|(bucket, mut rows)| {
+            rows.sort_by_key(|c| c.bucket_start);
+            let first = rows.first()?;
+            let last = rows.last()?;
+            Some(Candle {
+                pair: first.pair.clone(),
+                bucket_start: chrono::DateTime::from_timestamp(bucket, 0)?,
+                open: first.open,
+                close: last.close,
+                high: rows.iter().map(|c| c.high).fold(f64::MIN, f64::max),
+                low: rows.iter().map(|c| c.low).fold(f64::MAX, f64::min),
+                volume: rows.iter().map(|c| c.volume).sum(),
+            })
+        })
+        .collect()
 }
 
 #[derive(Debug, Serialize)]
@@ -30,10 +110,11 @@ pub struct KrakenResponse<T> {
     pub timestamp: u64,
 }
 
-pub fn router(_state: AppState) -> Router {
+pub fn router(state: AppState) -> Router {
     Router::new()
         .route("/ticker", get(get_ticker))
         .route("/ticker/{pair}", get(get_ticker_by_pair))
+        .route("/quote/{pair}", get(get_quote))
         .route("/orderbook/{pair}", get(get_order_book))
         .route("/assets", get(get_assets))
         .route("/pairs", get(get_asset_pairs))
@@ -43,6 +124,155 @@ pub fn router(_state: AppState) -> Router {
         .route("/summary/{pair}", get(get_market_summary_route))
         .route("/status", get(get_system_status))
         .route("/time", get(get_server_time))
+        .route("/stream", get(kraken_stream))
+        .route("/book-stream", get(kraken_book_stream))
+        .route("/backfill", post(start_backfill))
+        .route("/backfill/{job_id}", get(backfill_progress))
+        .with_state(state)
+}
+
+#[derive(Debug, Deserialize, IntoParams, ToSchema)]
+pub struct KrakenStreamQuery {
+    /// The Kraken WS v2 symbol to stream ticker updates for, e.g. `BTC/USD`.
+    pub pair: String,
+}
+
+/// Stream live ticker updates for a pair as Server-Sent Events, backed by a single
+/// persistent upstream connection to Kraken's WebSocket v2 API shared across every
+/// subscriber of that pair (see [`crate::sources::kraken_data::KrakenWsHub`]).
+#[utoipa::path(
+    get,
+    path = "/kraken/stream",
+    params(KrakenStreamQuery),
+    tag = "kraken",
+    responses((status = 200, description = "Server-Sent Events stream of ticker updates"))
+)]
+pub async fn kraken_stream(
+    State(state): State<AppState>,
+    Query(q): Query<KrakenStreamQuery>,
+) -> Sse<impl Stream<Item = Result<Event, Infallible>>> {
+    use futures::StreamExt;
+
+    let updates = state.kraken_ws_hub.subscribe(q.pair).map(|value| {
+        Ok(Event::default().json_data(value).unwrap_or_else(|_| Event::default().data("{}")))
+    });
+
+    Sse::new(updates).keep_alive(KeepAlive::default())
+}
+
+/// Stream a checksum-validated, continuously-updated order book for a pair as Server-Sent
+/// Events, backed by a single persistent upstream connection shared across every subscriber
+/// of that pair (see [`crate::sources::kraken_data::KrakenOrderBookHub`]). Each event is the
+/// latest validated [`crate::sources::kraken_data::KrakenOrderBookSnapshot`]; a checksum
+/// mismatch or disconnect surfaces as an `error` field instead of a stale book.
+#[utoipa::path(
+    get,
+    path = "/kraken/book-stream",
+    params(KrakenStreamQuery),
+    tag = "kraken",
+    responses((status = 200, description = "Server-Sent Events stream of validated order book snapshots"))
+)]
+pub async fn kraken_book_stream(
+    State(state): State<AppState>,
+    Query(q): Query<KrakenStreamQuery>,
+) -> Sse<impl Stream<Item = Result<Event, Infallible>>> {
+    let mut rx = state.kraken_book_hub.clone().subscribe(q.pair).await;
+    let updates = async_stream::stream! {
+        loop {
+            let event = match &*rx.borrow() {
+                Ok(snapshot) => Event::default().json_data(snapshot).unwrap_or_else(|_| Event::default().data("{}")),
+                Err(e) => Event::default().event("error").data(e.to_string()),
+            };
+            yield Ok(event);
+            if rx.changed().await.is_err() {
+                break;
+            }
+        }
+    };
+
+    Sse::new(updates).keep_alive(KeepAlive::default())
+}
+
+#[derive(Debug, Deserialize, ToSchema)]
+pub struct BackfillRequest {
+    pub pair: String,
+    pub target: crate::services::backfill::BackfillTarget,
+    /// Unix timestamp (seconds) to start backfilling from.
+    pub since: i64,
+    /// Unix timestamp (seconds) to stop at; defaults to now.
+    pub until: Option<i64>,
+    /// Rows persisted per upstream page (trades target) or day-chunks fetched concurrently
+    /// (candles target, capped at 8). Defaults to 500.
+    pub batch_size: Option<usize>,
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct BackfillJobHandle {
+    pub job_id: String,
+}
+
+/// Start a historical backfill job for a pair. Returns immediately with a job handle to poll
+/// via `GET /kraken/backfill/{job_id}` for progress (rows written, last timestamp reached).
+#[utoipa::path(
+    post,
+    path = "/kraken/backfill",
+    request_body = BackfillRequest,
+    tag = "kraken",
+    responses((status = 200, description = "Backfill job started", body = BackfillJobHandle))
+)]
+pub async fn start_backfill(
+    State(state): State<AppState>,
+    Json(req): Json<BackfillRequest>,
+) -> Result<impl IntoResponse, ApiError> {
+    let since = chrono::DateTime::from_timestamp(req.since, 0)
+        .ok_or_else(|| ApiError::BadRequest("invalid `since` timestamp".to_string()))?;
+    let until = match req.until {
+        Some(u) => chrono::DateTime::from_timestamp(u, 0)
+            .ok_or_else(|| ApiError::BadRequest("invalid `until` timestamp".to_string()))?,
+        None => chrono::Utc::now(),
+    };
+    let batch_size = req.batch_size.unwrap_or(500).max(1);
+
+    let job_id = state
+        .backfill_tracker
+        .clone()
+        .start(state.candle_store.clone(), req.pair, req.target, since, until, batch_size)
+        .await;
+
+    let timestamp = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .unwrap()
+        .as_secs();
+
+    Ok((StatusCode::OK, Json(KrakenResponse { success: true, data: BackfillJobHandle { job_id }, timestamp })))
+}
+
+/// Poll progress for a backfill job started via `POST /kraken/backfill`.
+#[utoipa::path(
+    get,
+    path = "/kraken/backfill/{job_id}",
+    tag = "kraken",
+    responses(
+        (status = 200, description = "Backfill job progress"),
+        (status = 404, description = "Unknown job id"),
+    )
+)]
+pub async fn backfill_progress(
+    State(state): State<AppState>,
+    Path(job_id): Path<String>,
+) -> Result<impl IntoResponse, ApiError> {
+    let progress = state
+        .backfill_tracker
+        .progress(&job_id)
+        .await
+        .ok_or_else(|| ApiError::NotFound(format!("no backfill job: {job_id}")))?;
+
+    let timestamp = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .unwrap()
+        .as_secs();
+
+    Ok((StatusCode::OK, Json(KrakenResponse { success: true, data: progress, timestamp })))
 }
 
 /// Get ticker information for specified pairs
@@ -53,25 +283,64 @@ pub fn router(_state: AppState) -> Router {
     tag = "kraken",
     responses((status = 200, description = "Ticker information for specified pairs"))
 )]
-pub async fn get_ticker(Query(query): Query<KrakenQuery>) -> Result<impl IntoResponse, ApiError> {
-    let pairs = query.pairs
+pub async fn get_ticker(
+    State(state): State<AppState>,
+    Query(query): Query<KrakenQuery>,
+) -> Result<impl IntoResponse, ApiError> {
+    let pairs: Vec<String> = query.pairs
         .map(|p| p.split(',').map(|s| s.trim().to_string()).collect())
         .unwrap_or_default();
-    
-    let data_source = KrakenDataSource::new_async().await.map_err(|e| ApiError::Upstream(e.to_string()))?;
-    let tickers = data_source.get_tickers_async(pairs).await.map_err(|e| ApiError::Upstream(e.to_string()))?;
-    
+
+    // Prefer the live WS snapshot (sub-second-fresh, no REST round-trip) when it's healthy and
+    // has data for every requested pair; fall back to REST otherwise (including when no pairs
+    // were requested, since the snapshot only covers the hub's fixed subscribed set).
+    let tickers = if state.kraken_snapshot_hub.is_healthy() && !pairs.is_empty() {
+        let mut from_snapshot = Vec::with_capacity(pairs.len());
+        let mut all_present = true;
+        for pair in &pairs {
+            match state.kraken_snapshot_hub.snapshot(pair).await {
+                Some(s) => from_snapshot.push(crate::sources::kraken_data::KrakenTicker {
+                    pair: s.pair,
+                    price: s.last_price,
+                    volume: s.volume_24h,
+                    high_24h: 0.0,
+                    low_24h: 0.0,
+                    change_24h: 0.0,
+                    change_pct_24h: 0.0,
+                    bid: 0.0,
+                    ask: 0.0,
+                    vwap: 0.0,
+                    trade_count: 0,
+                }),
+                None => {
+                    all_present = false;
+                    break;
+                }
+            }
+        }
+
+        if all_present {
+            from_snapshot
+        } else {
+            let data_source = KrakenDataSource::new_async().await.map_err(|e| ApiError::Upstream(e.to_string()))?;
+            data_source.get_tickers_async(pairs).await.map_err(|e| ApiError::Upstream(e.to_string()))?
+        }
+    } else {
+        let data_source = KrakenDataSource::new_async().await.map_err(|e| ApiError::Upstream(e.to_string()))?;
+        data_source.get_tickers_async(pairs).await.map_err(|e| ApiError::Upstream(e.to_string()))?
+    };
+
     let timestamp = std::time::SystemTime::now()
         .duration_since(std::time::UNIX_EPOCH)
         .unwrap()
         .as_secs();
-    
+
     let response = KrakenResponse {
         success: true,
         data: tickers,
         timestamp,
     };
-    
+
     Ok((StatusCode::OK, Json(response)))
 }
 
@@ -104,6 +373,32 @@ pub async fn get_ticker_by_pair(
     Ok((StatusCode::OK, Json(response)))
 }
 
+/// Get a derived bid/ask quote for a specific pair, applying a configured spread around the
+/// last traded price.
+#[utoipa::path(
+    get,
+    path = "/kraken/quote/{pair}",
+    tag = "kraken",
+    responses((status = 200, description = "Derived bid/ask quote for specific pair"))
+)]
+pub async fn get_quote(Path(pair): Path<String>) -> Result<impl IntoResponse, ApiError> {
+    let data_source = KrakenDataSource::new_async().await.map_err(|e| ApiError::Upstream(e.to_string()))?;
+
+    let quote = data_source.get_quote(&pair).await.map_err(|e| ApiError::Upstream(e.to_string()))?;
+    let timestamp = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .unwrap()
+        .as_secs();
+
+    let response = KrakenResponse {
+        success: true,
+        data: quote,
+        timestamp,
+    };
+
+    Ok((StatusCode::OK, Json(response)))
+}
+
 /// Get order book for a specific pair
 #[utoipa::path(
     get,
@@ -218,7 +513,10 @@ pub async fn get_recent_trades(
     Ok((StatusCode::OK, Json(response)))
 }
 
-/// Get OHLC data for a pair
+/// Get OHLC data for a pair. With no `resolution`, this is a direct pass-through to Kraken
+/// (legacy behavior). With `resolution` set, candles are served from
+/// [`crate::services::candles::CandleStore`], falling back to an upstream 1-minute fetch
+/// (upserted into the store when it's enabled) when the requested bucket is missing.
 #[utoipa::path(
     get,
     path = "/kraken/ohlc/{pair}",
@@ -227,24 +525,55 @@ pub async fn get_recent_trades(
     responses((status = 200, description = "OHLC data for specific pair"))
 )]
 pub async fn get_ohlc(
+    State(state): State<AppState>,
     Path(pair): Path<String>,
     Query(query): Query<KrakenQuery>,
 ) -> Result<impl IntoResponse, ApiError> {
-    let data_source = KrakenDataSource::new_async().await.map_err(|e| ApiError::Upstream(e.to_string()))?;
-    
-    let ohlc = data_source.get_ohlc(&pair, query.interval, query.since).map_err(|e| ApiError::Upstream(e.to_string()))?;
     let timestamp = std::time::SystemTime::now()
         .duration_since(std::time::UNIX_EPOCH)
         .unwrap()
         .as_secs();
-    
-    let response = KrakenResponse {
-        success: true,
-        data: ohlc,
-        timestamp,
+
+    let Some(resolution) = query.resolution.as_deref() else {
+        let data_source = KrakenDataSource::new_async().await.map_err(|e| ApiError::Upstream(e.to_string()))?;
+        let ohlc = data_source.get_ohlc(&pair, query.interval, query.since).await.map_err(|e| ApiError::Upstream(e.to_string()))?;
+        return Ok((StatusCode::OK, Json(KrakenResponse { success: true, data: ohlc, timestamp })));
     };
-    
-    Ok((StatusCode::OK, Json(response)))
+
+    let resolution_secs = resolution_seconds(resolution)
+        .ok_or_else(|| ApiError::BadRequest(format!("Unsupported resolution: {resolution}")))?;
+    let since = query.since.and_then(|s| chrono::DateTime::from_timestamp(s as i64, 0));
+    let limit = query.limit.unwrap_or(500) as i64;
+
+    if state.candle_store.is_enabled() {
+        let candles = state.candle_store
+            .aggregated_candles(&pair, resolution_secs, since, limit)
+            .await?;
+
+        if !candles.is_empty() {
+            return Ok((StatusCode::OK, Json(KrakenResponse { success: true, data: candles, timestamp })));
+        }
+    }
+
+    // Bucket missing (or store disabled): fetch 1-minute candles upstream, upsert them when
+    // the store is enabled, then aggregate for the response.
+    let data_source = KrakenDataSource::new_async().await.map_err(|e| ApiError::Upstream(e.to_string()))?;
+    let raw = data_source.get_ohlc(&pair, Some(1), query.since).await.map_err(|e| ApiError::Upstream(e.to_string()))?;
+    let minute_candles = parse_kraken_ohlc_rows(&pair, &raw);
+
+    if state.candle_store.is_enabled() {
+        for candle in &minute_candles {
+            state.candle_store.upsert_candle_1m(candle).await?;
+        }
+        let candles = state.candle_store
+            .aggregated_candles(&pair, resolution_secs, since, limit)
+            .await?;
+        return Ok((StatusCode::OK, Json(KrakenResponse { success: true, data: candles, timestamp })));
+    }
+
+    let mut candles = aggregate_candles_in_memory(&minute_candles, resolution_secs);
+    candles.truncate(limit as usize);
+    Ok((StatusCode::OK, Json(KrakenResponse { success: true, data: candles, timestamp })))
 }
 
 /// Get trending crypto pairs
@@ -257,7 +586,8 @@ pub async fn get_ohlc(
 )]
 pub async fn get_trending_crypto(Query(query): Query<KrakenQuery>) -> Result<impl IntoResponse, ApiError> {
     let limit = query.limit.unwrap_or(10);
-    let trending_items = get_trending_crypto_pairs(limit).await.map_err(|e| ApiError::Upstream(e.to_string()))?;
+    let data_source = KrakenDataSource::new_async().await.map_err(|e| ApiError::Upstream(e.to_string()))?;
+    let trending_items = get_trending_crypto_pairs(&data_source, limit).await.map_err(|e| ApiError::Upstream(e.to_string()))?;
     let timestamp = std::time::SystemTime::now()
         .duration_since(std::time::UNIX_EPOCH)
         .unwrap()
@@ -280,7 +610,8 @@ pub async fn get_trending_crypto(Query(query): Query<KrakenQuery>) -> Result<imp
     responses((status = 200, description = "Market summary for specific pair"))
 )]
 pub async fn get_market_summary_route(Path(pair): Path<String>) -> Result<impl IntoResponse, ApiError> {
-    let summary = get_market_summary(&pair).await.map_err(|e| ApiError::Upstream(e.to_string()))?;
+    let data_source = KrakenDataSource::new_async().await.map_err(|e| ApiError::Upstream(e.to_string()))?;
+    let summary = get_market_summary(&data_source, &pair).await.map_err(|e| ApiError::Upstream(e.to_string()))?;
     let timestamp = std::time::SystemTime::now()
         .duration_since(std::time::UNIX_EPOCH)
         .unwrap()
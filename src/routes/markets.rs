@@ -0,0 +1,148 @@
+use std::sync::Arc;
+
+use axum::{
+    extract::{Path, Query, State},
+    http::StatusCode,
+    response::IntoResponse,
+    routing::get,
+    Json, Router,
+};
+use futures::future::join_all;
+use serde::{Deserialize, Serialize};
+use utoipa::{IntoParams, ToSchema};
+
+use crate::errors::ApiError;
+use crate::sources::market_source::{aggregate_trending, MarketDataSource};
+use crate::state::AppState;
+use crate::types::TrendingItem;
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct VenueQuote {
+    pub venue: String,
+    pub last_price: f64,
+    pub funding_rate: Option<f64>,
+    pub best_bid: Option<f64>,
+    pub best_ask: Option<f64>,
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct MarketComparison {
+    pub symbol: String,
+    pub quotes: Vec<VenueQuote>,
+    /// `hyperliquid.last_price - coinbase.last_price`, `None` if either venue didn't respond.
+    pub price_diff: Option<f64>,
+    pub price_diff_percentage: Option<f64>,
+    /// Hyperliquid's funding rate as-is (Coinbase spot has no funding to diff against);
+    /// `None` if either venue didn't respond.
+    pub funding_rate_diff: Option<f64>,
+}
+
+pub fn router(state: AppState) -> Router {
+    Router::new()
+        .route("/compare/{coin}", get(compare_market))
+        .route("/trending", get(get_trending))
+        .with_state(state)
+}
+
+async fn fetch_quote(source: Arc<dyn MarketDataSource>, coin: String) -> Result<VenueQuote, ApiError> {
+    let markets = source.get_all_markets().await?;
+    let market = markets.into_iter()
+        .find(|m| m.symbol.eq_ignore_ascii_case(&coin))
+        .ok_or_else(|| ApiError::NotFound(format!("{coin} not found on {}", source.venue())))?;
+
+    let book = source.get_orderbook(&coin, None).await?;
+
+    Ok(VenueQuote {
+        venue: source.venue().to_string(),
+        last_price: market.last_price,
+        funding_rate: market.funding_rate,
+        best_bid: book.bids.first().map(|l| l.price),
+        best_ask: book.asks.first().map(|l| l.price),
+    })
+}
+
+/// Compare a coin's price, spread, and funding across Hyperliquid and Coinbase. Fetches both
+/// venues concurrently via `join_all` instead of sequentially, the same concurrency pattern
+/// `crate::helpers::price_aggregator::fetch_all_prices` uses across its exchanges. A venue that
+/// doesn't have the symbol or errors is dropped (logged), not fatal, unless neither responds.
+#[utoipa::path(
+    get,
+    path = "/markets/compare/{coin}",
+    params(("coin" = String, Path, description = "Coin symbol, e.g. BTC")),
+    tag = "markets",
+    responses((status = 200, description = "Cross-venue price/spread/funding comparison", body = MarketComparison))
+)]
+pub async fn compare_market(
+    State(state): State<AppState>,
+    Path(coin): Path<String>,
+) -> Result<impl IntoResponse, ApiError> {
+    let sources: Vec<Arc<dyn MarketDataSource>> = vec![state.hyperliquid.clone(), state.coinbase.clone()];
+
+    let results = join_all(sources.into_iter().map(|source| fetch_quote(source, coin.clone()))).await;
+
+    let quotes: Vec<VenueQuote> = results.into_iter()
+        .filter_map(|result| match result {
+            Ok(quote) => Some(quote),
+            Err(e) => {
+                tracing::warn!("markets/compare/{coin}: {e}");
+                None
+            }
+        })
+        .collect();
+
+    if quotes.is_empty() {
+        return Err(ApiError::Upstream(format!("{coin} could not be fetched from any venue")));
+    }
+
+    let hyperliquid = quotes.iter().find(|q| q.venue == "hyperliquid");
+    let coinbase = quotes.iter().find(|q| q.venue == "coinbase");
+
+    let (price_diff, price_diff_percentage, funding_rate_diff) = match (hyperliquid, coinbase) {
+        (Some(hl), Some(cb)) => {
+            let diff = hl.last_price - cb.last_price;
+            let pct = if cb.last_price != 0.0 { Some((diff / cb.last_price) * 100.0) } else { None };
+            (Some(diff), pct, hl.funding_rate)
+        }
+        _ => (None, None, None),
+    };
+
+    let comparison = MarketComparison {
+        symbol: coin,
+        quotes,
+        price_diff,
+        price_diff_percentage,
+        funding_rate_diff,
+    };
+
+    Ok((StatusCode::OK, Json(comparison)))
+}
+
+#[derive(Debug, Deserialize, IntoParams)]
+pub struct TrendingQuery {
+    pub limit: Option<usize>,
+}
+
+/// Merge `trending()` across every registered [`MarketDataSource`] (Hyperliquid, Coinbase,
+/// Alpaca) into a single cross-venue leaderboard via [`aggregate_trending`], so a client doesn't
+/// need to poll each venue's own trending endpoint and merge client-side.
+#[utoipa::path(
+    get,
+    path = "/markets/trending",
+    params(TrendingQuery),
+    tag = "markets",
+    responses((status = 200, description = "Cross-venue trending leaderboard", body = Vec<TrendingItem>))
+)]
+pub async fn get_trending(
+    State(state): State<AppState>,
+    Query(query): Query<TrendingQuery>,
+) -> Result<impl IntoResponse, ApiError> {
+    let limit = query.limit.unwrap_or(20);
+    let sources: Vec<Arc<dyn MarketDataSource>> = vec![
+        state.hyperliquid.clone(),
+        state.coinbase.clone(),
+        state.alpaca.clone(),
+    ];
+
+    let trending = aggregate_trending(&sources, limit).await;
+    Ok((StatusCode::OK, Json(trending)))
+}
@@ -1,13 +1,13 @@
 use std::sync::Arc;
 
-use axum::{extract::Query, http::StatusCode, response::IntoResponse, routing::get, Json, Router};
+use axum::{extract::Query, http::StatusCode, middleware::from_fn_with_state, response::IntoResponse, routing::{get, post}, Json, Router};
 use futures::future::join_all;
 use serde_json::{json, Value};
 use time::{Date, OffsetDateTime};
 use time::macros::format_description;
 
 use crate::helpers::metrics;
-use crate::helpers::options::black_scholes_delta;
+use crate::helpers::options::{black_scholes_delta, black_scholes_greeks, crr_price_and_delta, solve_implied_vol};
 use crate::helpers::params::{parse_symbols_csv, periods_per_year_from_interval};
 use crate::services::yahoo::{fetch_prices_for_symbol, latest_close, metrics_for_prices};
 use crate::sources;
@@ -16,12 +16,21 @@ use crate::types::OptionsQuery;
 use crate::errors::ApiError;
 
 pub fn router(state: AppState) -> Router {
-    Router::new().route("/options/recommendations", get(get_options_recommendations)).with_state(state)
+    Router::new()
+        .route("/options/recommendations", get(get_options_recommendations))
+        .route(
+            "/options/orders",
+            post(submit_option_order)
+                .layer(from_fn_with_state(state.clone(), crate::middleware::require_trading_api_key)),
+        )
+        .route("/options/activity.ledger", get(get_activity_ledger))
+        .with_state(state)
 }
 
 #[utoipa::path(get, path = "/options/recommendations", params(OptionsQuery), tag = "options", responses((status = 200, description = "Rank options contracts")))]
 pub async fn get_options_recommendations(axum::extract::State(state): axum::extract::State<AppState>, Query(q): Query<OptionsQuery>) -> Result<impl IntoResponse, ApiError> {
     let side = q.side.clone().unwrap_or_else(|| "both".to_string());
+    let structure_mode = q.structure.clone();
     let min_dte = q.min_dte.unwrap_or(7);
     let max_dte = q.max_dte.unwrap_or(60);
     let limit = q.limit.unwrap_or(20);
@@ -146,7 +155,7 @@ pub async fn get_options_recommendations(axum::extract::State(state): axum::extr
 
     if let Some(top_n) = q.underlying_top {
         if debug { println!("[options] underlying_top requested: {}", top_n); }
-        let weights_outer = metrics::CompositeWeights { sharpe: w_sharpe, sortino: w_sortino, calmar: w_calmar };
+        let weights_outer = metrics::CompositeWeights { sharpe: w_sharpe, sortino: w_sortino, calmar: w_calmar, ..Default::default() };
         let yahoo_outer = state.yahoo.clone();
         let rank_futs = symbols.iter().cloned().map(move |sym| {
             let period_label = period_label.to_string();
@@ -158,7 +167,10 @@ pub async fn get_options_recommendations(axum::extract::State(state): axum::extr
                 Some((sym, m.composite_score))
             }
         });
-        let mut scored: Vec<(String, f64)> = join_all(rank_futs).await.into_iter().flatten().collect();
+        let mut scored: Vec<(String, f64)> = sources::finviz_data::buffered_map(
+            rank_futs,
+            sources::finviz_data::DEFAULT_ENRICHMENT_CONCURRENCY,
+        ).await.into_iter().flatten().collect();
         scored.sort_by(|a, b| b.1.partial_cmp(&a.1).unwrap_or(std::cmp::Ordering::Equal));
         if debug {
             let prev: Vec<_> = scored.iter().take(5).map(|(s, sc)| format!("{}:{:.3}", s, sc)).collect();
@@ -174,6 +186,14 @@ pub async fn get_options_recommendations(axum::extract::State(state): axum::extr
     let min_volume = q.min_volume;
     let min_delta = q.min_delta;
     let max_delta = q.max_delta;
+    let min_theta = q.min_theta;
+    let max_theta = q.max_theta;
+    let min_vega = q.min_vega;
+    let max_vega = q.max_vega;
+    let min_gamma = q.min_gamma;
+    let max_gamma = q.max_gamma;
+    let min_rho = q.min_rho;
+    let max_rho = q.max_rho;
     let min_sr = q.min_strike_ratio;
     let max_sr = q.max_strike_ratio;
     let per_symbol_limit = q.per_symbol_limit.unwrap_or(usize::MAX);
@@ -182,6 +202,7 @@ pub async fn get_options_recommendations(axum::extract::State(state): axum::extr
     let q_arc = Arc::new(q.clone());
     let tasks = symbols.into_iter().map(|symbol| {
         let side = side.clone();
+        let structure_mode = structure_mode.clone();
         let q_local = q_arc.clone();
         let debug_local = debug;
         let yahoo = state.yahoo.clone();
@@ -191,7 +212,7 @@ pub async fn get_options_recommendations(axum::extract::State(state): axum::extr
             let prices = match fetch_prices_for_symbol(&yahoo, &symbol, period_label).await { Ok(p) => p, Err(e) => { if debug_local { println!("[options][{}] prices error: {}", symbol, e); } return Vec::new() } };
             let returns = metrics::compute_returns_from_prices(&prices);
             if debug_local { println!("[options][{}] spot={}, returns_len={}", symbol, spot, returns.len()); }
-            let under_metrics = metrics_for_prices(&prices, rf_annual, rf_annual, periods_per_year, Some(metrics::CompositeWeights { sharpe: w_sharpe, sortino: w_sortino, calmar: w_calmar }));
+            let under_metrics = metrics_for_prices(&prices, rf_annual, rf_annual, periods_per_year, Some(metrics::CompositeWeights { sharpe: w_sharpe, sortino: w_sortino, calmar: w_calmar, ..Default::default() }));
             let base_score = under_metrics.composite_score;
             if debug_local { println!("[options][{}] composite={:.4}", symbol, base_score); }
 
@@ -212,8 +233,10 @@ pub async fn get_options_recommendations(axum::extract::State(state): axum::extr
                         let typ = details.and_then(|d| d.get("type")).and_then(|v| v.as_str()).unwrap_or("");
                         let is_call = typ.eq_ignore_ascii_case("call") || contract_symbol.ends_with('C');
                         let is_put = typ.eq_ignore_ascii_case("put") || contract_symbol.ends_with('P');
-                        if side == "call" && !is_call { continue; }
-                        if side == "put" && !is_put { continue; }
+                        if structure_mode.is_none() {
+                            if side == "call" && !is_call { continue; }
+                            if side == "put" && !is_put { continue; }
+                        }
                         if strike <= 0.0 || exp_ts <= 0 { continue; }
                         let quote = s.get("latest_quote");
                         let trade = s.get("latest_trade");
@@ -232,9 +255,40 @@ pub async fn get_options_recommendations(axum::extract::State(state): axum::extr
                         if let Some(min_p) = min_premium { if premium < min_p { continue; } }
                         if let Some(max_p) = max_premium { if premium > max_p { continue; } }
                         let t_years = dte_days / 365.0;
-                        let delta = delta_from_feed.unwrap_or_else(|| black_scholes_delta(spot, strike, rf_annual, iv.abs(), t_years, is_call).unwrap_or(0.0));
+                        let (iv, iv_source) = if iv > 0.0 {
+                            (iv, "feed")
+                        } else {
+                            match solve_implied_vol(spot, strike, rf_annual, t_years, premium, is_call) {
+                                Some(sigma) => (sigma, "solved"),
+                                None => (iv, "feed"),
+                            }
+                        };
+                        let pricing_binomial = q_local.pricing.as_deref() == Some("binomial");
+                        let binomial = if pricing_binomial {
+                            crr_price_and_delta(spot, strike, rf_annual, iv.abs(), t_years, is_call, 500)
+                        } else {
+                            None
+                        };
+                        let delta = delta_from_feed.unwrap_or_else(|| {
+                            binomial.map(|b| b.delta).unwrap_or_else(|| black_scholes_delta(spot, strike, rf_annual, iv.abs(), t_years, is_call).unwrap_or(0.0))
+                        });
+                        let theoretical_price = binomial.map(|b| b.price);
+                        let mispricing = theoretical_price.map(|t| premium - t);
                         if let Some(min_d) = min_delta { if delta < min_d { continue; } }
                         if let Some(max_d) = max_delta { if delta > max_d { continue; } }
+                        let greeks = black_scholes_greeks(spot, strike, rf_annual, iv.abs(), t_years, is_call);
+                        let gamma = greeks.map(|g| g.gamma).unwrap_or(0.0);
+                        let theta = greeks.map(|g| g.theta).unwrap_or(0.0);
+                        let vega = greeks.map(|g| g.vega).unwrap_or(0.0);
+                        let rho = greeks.map(|g| g.rho).unwrap_or(0.0);
+                        if let Some(min_t) = min_theta { if theta < min_t { continue; } }
+                        if let Some(max_t) = max_theta { if theta > max_t { continue; } }
+                        if let Some(min_v) = min_vega { if vega < min_v { continue; } }
+                        if let Some(max_v) = max_vega { if vega > max_v { continue; } }
+                        if let Some(min_g) = min_gamma { if gamma < min_g { continue; } }
+                        if let Some(max_g) = max_gamma { if gamma > max_g { continue; } }
+                        if let Some(min_r) = min_rho { if rho < min_r { continue; } }
+                        if let Some(max_r) = max_rho { if rho > max_r { continue; } }
                         let strike_ratio = strike / spot;
                         if let Some(lo) = min_sr { if strike_ratio < lo { continue; } }
                         if let Some(hi) = max_sr { if strike_ratio > hi { continue; } }
@@ -256,7 +310,14 @@ pub async fn get_options_recommendations(axum::extract::State(state): axum::extr
                             "spread": spread,
                             "spread_pct": spread_pct,
                             "implied_vol": iv,
+                            "iv_source": iv_source,
                             "delta": delta,
+                            "gamma": gamma,
+                            "theta": theta,
+                            "vega": vega,
+                            "rho": rho,
+                            "theoretical_price": theoretical_price,
+                            "mispricing": mispricing,
                             "leverage": leverage,
                             "volume": volume,
                             "open_interest": 0u64,
@@ -291,11 +352,42 @@ pub async fn get_options_recommendations(axum::extract::State(state): axum::extr
                                     if let Some(min_p) = min_premium { if premium < min_p { continue; } }
                                     if let Some(max_p) = max_premium { if premium > max_p { continue; } }
                                     let t_years = dte_days / 365.0;
-                                    let iv = c.get("impliedVolatility").and_then(|v| v.as_f64()).unwrap_or(0.0);
+                                    let feed_iv = c.get("impliedVolatility").and_then(|v| v.as_f64()).unwrap_or(0.0);
+                                    let (iv, iv_source) = if feed_iv > 0.0 {
+                                        (feed_iv, "feed")
+                                    } else {
+                                        match solve_implied_vol(spot, strike, rf_annual, t_years, premium, is_call) {
+                                            Some(sigma) => (sigma, "solved"),
+                                            None => (feed_iv, "feed"),
+                                        }
+                                    };
                                     let delta_from_feed = c.get("delta").and_then(|v| v.as_f64());
-                                    let delta = delta_from_feed.unwrap_or_else(|| black_scholes_delta(spot, strike, rf_annual, iv.abs(), t_years, is_call).unwrap_or(0.0));
+                                    let pricing_binomial = q_local.pricing.as_deref() == Some("binomial");
+                                    let binomial = if pricing_binomial {
+                                        crr_price_and_delta(spot, strike, rf_annual, iv.abs(), t_years, is_call, 500)
+                                    } else {
+                                        None
+                                    };
+                                    let delta = delta_from_feed.unwrap_or_else(|| {
+                                        binomial.map(|b| b.delta).unwrap_or_else(|| black_scholes_delta(spot, strike, rf_annual, iv.abs(), t_years, is_call).unwrap_or(0.0))
+                                    });
+                                    let theoretical_price = binomial.map(|b| b.price);
+                                    let mispricing = theoretical_price.map(|t| premium - t);
                                     if let Some(min_d) = min_delta { if delta < min_d { continue; } }
                                     if let Some(max_d) = max_delta { if delta > max_d { continue; } }
+                                    let greeks = black_scholes_greeks(spot, strike, rf_annual, iv.abs(), t_years, is_call);
+                                    let gamma = greeks.map(|g| g.gamma).unwrap_or(0.0);
+                                    let theta = greeks.map(|g| g.theta).unwrap_or(0.0);
+                                    let vega = greeks.map(|g| g.vega).unwrap_or(0.0);
+                                    let rho = greeks.map(|g| g.rho).unwrap_or(0.0);
+                                    if let Some(min_t) = min_theta { if theta < min_t { continue; } }
+                                    if let Some(max_t) = max_theta { if theta > max_t { continue; } }
+                                    if let Some(min_v) = min_vega { if vega < min_v { continue; } }
+                                    if let Some(max_v) = max_vega { if vega > max_v { continue; } }
+                                    if let Some(min_g) = min_gamma { if gamma < min_g { continue; } }
+                                    if let Some(max_g) = max_gamma { if gamma > max_g { continue; } }
+                                    if let Some(min_r) = min_rho { if rho < min_r { continue; } }
+                                    if let Some(max_r) = max_rho { if rho > max_r { continue; } }
                                     let strike_ratio = strike / spot;
                                     if let Some(lo) = min_sr { if strike_ratio < lo { continue; } }
                                     if let Some(hi) = max_sr { if strike_ratio > hi { continue; } }
@@ -317,7 +409,14 @@ pub async fn get_options_recommendations(axum::extract::State(state): axum::extr
                                         "spread": spread,
                                         "spread_pct": spread_pct,
                                         "implied_vol": iv,
+                                        "iv_source": iv_source,
                                         "delta": delta,
+                                        "gamma": gamma,
+                                        "theta": theta,
+                                        "vega": vega,
+                                        "rho": rho,
+                                        "theoretical_price": theoretical_price,
+                                        "mispricing": mispricing,
                                         "leverage": leverage,
                                         "volume": volume,
                                         "open_interest": 0u64,
@@ -347,6 +446,9 @@ pub async fn get_options_recommendations(axum::extract::State(state): axum::extr
     });
 
     let mut options_list: Vec<Value> = join_all(tasks).await.into_iter().flatten().collect();
+    if let Some(kind) = structure_mode.as_deref() {
+        options_list = build_structures(&options_list, kind);
+    }
     options_list.sort_by(|a, b| {
         let sa = a.get("score").and_then(|v| v.as_f64()).unwrap_or(f64::MIN);
         let sb = b.get("score").and_then(|v| v.as_f64()).unwrap_or(f64::MIN);
@@ -358,4 +460,267 @@ pub async fn get_options_recommendations(axum::extract::State(state): axum::extr
     Ok((StatusCode::OK, Json(json!({ "results": options_list }))))
 }
 
+/// Submits a previously-ranked contract (or any symbol/qty/side) as an order to Alpaca's
+/// trading API, defaulting to the paper endpoint unless `paper: false` is set. Gated by
+/// [`crate::middleware::require_trading_api_key`] -- callers must send
+/// `Authorization: Bearer <TRADING_API_KEY>` since this can route real money to the **live**
+/// endpoint.
+#[utoipa::path(
+    post,
+    path = "/options/orders",
+    request_body = sources::alpaca_data::AlpacaOrderRequest,
+    tag = "options",
+    responses(
+        (status = 200, description = "Order submitted to Alpaca"),
+        (status = 401, description = "Missing or invalid trading API key", body = crate::types::ErrorResponse)
+    )
+)]
+pub async fn submit_option_order(
+    axum::extract::State(_state): axum::extract::State<AppState>,
+    Json(order): Json<sources::alpaca_data::AlpacaOrderRequest>,
+) -> Result<impl IntoResponse, ApiError> {
+    let result = sources::alpaca_data::submit_alpaca_order(&order).await.map_err(ApiError::Upstream)?;
+    Ok((StatusCode::OK, Json(result)))
+}
+
+#[derive(serde::Deserialize, utoipa::ToSchema, utoipa::IntoParams)]
+pub struct ActivityLedgerQuery {
+    /// Start of the date range (RFC3339), inclusive. Omit for no lower bound.
+    pub start: Option<String>,
+    /// End of the date range (RFC3339), inclusive. Omit for no upper bound.
+    pub end: Option<String>,
+    /// Position-account template; `{underlying}` is replaced with the fill's root symbol
+    /// (default: "Assets:Brokerage:Options:{underlying}").
+    pub account_template: Option<String>,
+    /// Account the premium is posted against (default: "Assets:Brokerage:Cash").
+    pub cash_account: Option<String>,
+    /// Flat commission per fill, added to the cash leg (default: 0.0).
+    pub commission: Option<f64>,
+    /// Pull fills from the paper-trading account instead of live (default: true).
+    pub paper: Option<bool>,
+}
+
+/// Export option fills as a Ledger-CLI plaintext double-entry transaction log.
+#[utoipa::path(
+    get,
+    path = "/options/activity.ledger",
+    params(ActivityLedgerQuery),
+    responses((status = 200, description = "Ledger-CLI plaintext of option fills")),
+    tag = "options"
+)]
+pub async fn get_activity_ledger(Query(q): Query<ActivityLedgerQuery>) -> Result<impl IntoResponse, ApiError> {
+    let paper = q.paper.unwrap_or(true);
+    let activities = sources::alpaca_data::get_account_fills(q.start.as_deref(), q.end.as_deref(), paper)
+        .await
+        .map_err(ApiError::Upstream)?;
+
+    let account_template = q.account_template.as_deref().unwrap_or("Assets:Brokerage:Options:{underlying}");
+    let cash_account = q.cash_account.as_deref().unwrap_or("Assets:Brokerage:Cash");
+    let commission = q.commission.unwrap_or(0.0);
+
+    let ledger = crate::helpers::ledger::build_option_ledger(&activities, account_template, cash_account, commission);
+
+    Ok((
+        StatusCode::OK,
+        [(axum::http::header::CONTENT_TYPE, "text/plain; charset=utf-8")],
+        ledger,
+    ))
+}
+
+fn num(v: &Value, key: &str) -> f64 {
+    v.get(key).and_then(|x| x.as_f64()).unwrap_or(0.0)
+}
+
+/// Groups the per-contract recommendation pool by (symbol, expiration) and builds
+/// defined-risk structures of the requested `kind` ("vertical", "straddle", "strangle",
+/// "iron_condor") out of each group's calls/puts.
+fn build_structures(pool: &[Value], kind: &str) -> Vec<Value> {
+    use std::collections::BTreeMap;
+    let mut groups: BTreeMap<(String, i64), Vec<&Value>> = BTreeMap::new();
+    for c in pool {
+        let symbol = c.get("symbol").and_then(|v| v.as_str()).unwrap_or("").to_string();
+        let expiration = c.get("expiration").and_then(|v| v.as_i64()).unwrap_or(0);
+        groups.entry((symbol, expiration)).or_default().push(c);
+    }
+
+    let mut structures = Vec::new();
+    for ((symbol, expiration), contracts) in groups {
+        let base_score = contracts
+            .first()
+            .and_then(|c| c.get("underlying_metrics"))
+            .and_then(|m| m.get("composite_score"))
+            .and_then(|v| v.as_f64())
+            .unwrap_or(0.0);
+        let spot = contracts
+            .first()
+            .map(|c| {
+                let strike = num(c, "strike");
+                let ratio = num(c, "strike_ratio");
+                if ratio > 0.0 { strike / ratio } else { 0.0 }
+            })
+            .unwrap_or(0.0);
+
+        let mut calls: Vec<&Value> = contracts.iter().copied().filter(|c| c.get("side").and_then(|v| v.as_str()) == Some("call")).collect();
+        let mut puts: Vec<&Value> = contracts.iter().copied().filter(|c| c.get("side").and_then(|v| v.as_str()) == Some("put")).collect();
+        calls.sort_by(|a, b| num(a, "strike").partial_cmp(&num(b, "strike")).unwrap_or(std::cmp::Ordering::Equal));
+        puts.sort_by(|a, b| num(a, "strike").partial_cmp(&num(b, "strike")).unwrap_or(std::cmp::Ordering::Equal));
+
+        match kind {
+            "vertical" => {
+                for (side_label, leg_contracts) in [("call", &calls), ("put", &puts)] {
+                    for w in leg_contracts.windows(2) {
+                        structures.push(vertical_spread(&symbol, expiration, side_label, w[0], w[1], base_score));
+                    }
+                }
+            }
+            "straddle" => {
+                if let Some(s) = straddle_or_strangle(&symbol, expiration, &calls, &puts, spot, base_score, true) {
+                    structures.push(s);
+                }
+            }
+            "strangle" => {
+                if let Some(s) = straddle_or_strangle(&symbol, expiration, &calls, &puts, spot, base_score, false) {
+                    structures.push(s);
+                }
+            }
+            "iron_condor" => {
+                if let Some(s) = iron_condor(&symbol, expiration, &calls, &puts, spot, base_score) {
+                    structures.push(s);
+                }
+            }
+            _ => {}
+        }
+    }
+    structures
+}
+
+fn vertical_spread(symbol: &str, expiration: i64, side: &str, long: &Value, short: &Value, base_score: f64) -> Value {
+    let strike_long = num(long, "strike");
+    let strike_short = num(short, "strike");
+    let premium_long = num(long, "premium");
+    let premium_short = num(short, "premium");
+    let net_debit = premium_long - premium_short;
+    let width = (strike_short - strike_long).abs();
+    let (max_profit, max_loss, breakeven) = if net_debit >= 0.0 {
+        let breakeven = if side == "call" { strike_long + net_debit } else { strike_short - net_debit };
+        (width - net_debit, net_debit, breakeven)
+    } else {
+        let credit = -net_debit;
+        let breakeven = if side == "call" { strike_short - credit } else { strike_long + credit };
+        (credit, width - credit, breakeven)
+    };
+    let combined_delta = num(long, "delta") - num(short, "delta");
+    let risk_reward = if max_loss.abs() > 1e-9 { max_profit / max_loss.abs() } else { f64::INFINITY };
+    let score = base_score * risk_reward;
+    json!({
+        "symbol": symbol,
+        "structure": "vertical",
+        "side": side,
+        "expiration": expiration,
+        "legs": [
+            { "action": "buy", "contract": long.get("contract"), "side": side, "strike": strike_long, "premium": premium_long },
+            { "action": "sell", "contract": short.get("contract"), "side": side, "strike": strike_short, "premium": premium_short },
+        ],
+        "net_premium": net_debit,
+        "max_profit": max_profit,
+        "max_loss": max_loss,
+        "breakeven": breakeven,
+        "combined_delta": combined_delta,
+        "risk_reward": risk_reward,
+        "score": score,
+    })
+}
+
+fn straddle_or_strangle(symbol: &str, expiration: i64, calls: &[&Value], puts: &[&Value], spot: f64, base_score: f64, atm: bool) -> Option<Value> {
+    let (call, put) = if atm {
+        let mut best: Option<(&Value, &Value, f64)> = None;
+        for c in calls {
+            for p in puts {
+                if (num(c, "strike") - num(p, "strike")).abs() < 1e-6 {
+                    let dist = (num(c, "strike") - spot).abs();
+                    if best.map(|(_, _, d)| dist < d).unwrap_or(true) {
+                        best = Some((c, p, dist));
+                    }
+                }
+            }
+        }
+        let (c, p, _) = best?;
+        (*c, *p)
+    } else {
+        let call = *calls.iter().filter(|c| num(c, "strike") > spot).min_by(|a, b| num(a, "strike").partial_cmp(&num(b, "strike")).unwrap_or(std::cmp::Ordering::Equal))?;
+        let put = *puts.iter().filter(|p| num(p, "strike") < spot).max_by(|a, b| num(a, "strike").partial_cmp(&num(b, "strike")).unwrap_or(std::cmp::Ordering::Equal))?;
+        (call, put)
+    };
+
+    let call_strike = num(call, "strike");
+    let put_strike = num(put, "strike");
+    let net_premium = num(call, "premium") + num(put, "premium");
+    let combined_delta = num(call, "delta") + num(put, "delta");
+    let breakeven_up = call_strike + net_premium;
+    let breakeven_down = put_strike - net_premium;
+    let max_loss = net_premium;
+    let risk_reward = f64::INFINITY;
+    let score = base_score * (1.0 / max_loss.max(1e-9));
+    Some(json!({
+        "symbol": symbol,
+        "structure": if atm { "straddle" } else { "strangle" },
+        "expiration": expiration,
+        "legs": [
+            { "action": "buy", "contract": call.get("contract"), "side": "call", "strike": call_strike, "premium": num(call, "premium") },
+            { "action": "buy", "contract": put.get("contract"), "side": "put", "strike": put_strike, "premium": num(put, "premium") },
+        ],
+        "net_premium": net_premium,
+        "max_profit": Value::Null,
+        "max_loss": max_loss,
+        "breakeven": [breakeven_down, breakeven_up],
+        "combined_delta": combined_delta,
+        "risk_reward": risk_reward,
+        "score": score,
+    }))
+}
+
+fn iron_condor(symbol: &str, expiration: i64, calls: &[&Value], puts: &[&Value], spot: f64, base_score: f64) -> Option<Value> {
+    let mut otm_puts: Vec<&Value> = puts.iter().copied().filter(|p| num(p, "strike") < spot).collect();
+    otm_puts.sort_by(|a, b| num(a, "strike").partial_cmp(&num(b, "strike")).unwrap_or(std::cmp::Ordering::Equal));
+    if otm_puts.len() < 2 { return None; }
+    let short_put = otm_puts[otm_puts.len() - 1];
+    let long_put = otm_puts[otm_puts.len() - 2];
+
+    let mut otm_calls: Vec<&Value> = calls.iter().copied().filter(|c| num(c, "strike") > spot).collect();
+    otm_calls.sort_by(|a, b| num(a, "strike").partial_cmp(&num(b, "strike")).unwrap_or(std::cmp::Ordering::Equal));
+    if otm_calls.len() < 2 { return None; }
+    let short_call = otm_calls[0];
+    let long_call = otm_calls[1];
+
+    let put_credit = num(short_put, "premium") - num(long_put, "premium");
+    let call_credit = num(short_call, "premium") - num(long_call, "premium");
+    let net_credit = put_credit + call_credit;
+    let put_width = num(short_put, "strike") - num(long_put, "strike");
+    let call_width = num(long_call, "strike") - num(short_call, "strike");
+    let max_width = put_width.max(call_width);
+    let max_profit = net_credit;
+    let max_loss = max_width - net_credit;
+    let combined_delta = num(long_put, "delta") - num(short_put, "delta") + num(long_call, "delta") - num(short_call, "delta");
+    let risk_reward = if max_loss.abs() > 1e-9 { max_profit / max_loss.abs() } else { f64::INFINITY };
+    let score = base_score * risk_reward;
+    Some(json!({
+        "symbol": symbol,
+        "structure": "iron_condor",
+        "expiration": expiration,
+        "legs": [
+            { "action": "sell", "contract": short_put.get("contract"), "side": "put", "strike": num(short_put, "strike"), "premium": num(short_put, "premium") },
+            { "action": "buy", "contract": long_put.get("contract"), "side": "put", "strike": num(long_put, "strike"), "premium": num(long_put, "premium") },
+            { "action": "sell", "contract": short_call.get("contract"), "side": "call", "strike": num(short_call, "strike"), "premium": num(short_call, "premium") },
+            { "action": "buy", "contract": long_call.get("contract"), "side": "call", "strike": num(long_call, "strike"), "premium": num(long_call, "premium") },
+        ],
+        "net_premium": net_credit,
+        "max_profit": max_profit,
+        "max_loss": max_loss,
+        "breakeven": [num(short_put, "strike") - net_credit, num(short_call, "strike") + net_credit],
+        "combined_delta": combined_delta,
+        "risk_reward": risk_reward,
+        "score": score,
+    }))
+}
+
 
@@ -0,0 +1,60 @@
+use axum::{
+    extract::{Query, State},
+    response::IntoResponse,
+    routing::get,
+    Json, Router,
+};
+use serde::Deserialize;
+use utoipa::{IntoParams, ToSchema};
+
+use crate::errors::ApiError;
+use crate::helpers::price_aggregator::{get_price_consensus, PriceConsensus};
+use crate::state::AppState;
+use crate::types::ErrorResponse;
+
+#[derive(Debug, Deserialize, IntoParams, ToSchema)]
+pub struct PriceConsensusQuery {
+    /// Base asset symbol, e.g. "BTC"
+    pub base: String,
+    /// Quote asset symbol, e.g. "USD" (default: "USD")
+    pub quote: Option<String>,
+    /// Minimum number of exchanges that must respond for a real median; below this, falls
+    /// back to `trusted_source` (default: 3)
+    pub min_sources: Option<usize>,
+    /// Exchange name to fall back to when fewer than `min_sources` responded (e.g. "coingecko")
+    pub trusted_source: Option<String>,
+}
+
+/// Get a median-consensus spot price for an asset pair across several exchanges
+#[utoipa::path(
+    get,
+    path = "/price/consensus",
+    params(PriceConsensusQuery),
+    responses(
+        (status = 200, description = "Successfully retrieved price consensus", body = PriceConsensus),
+        (status = 400, description = "Bad request", body = ErrorResponse),
+        (status = 502, description = "No price source responded", body = ErrorResponse)
+    ),
+    tag = "price"
+)]
+pub async fn get_price_consensus_handler(
+    State(_state): State<AppState>,
+    Query(query): Query<PriceConsensusQuery>,
+) -> Result<impl IntoResponse, ApiError> {
+    if query.base.trim().is_empty() {
+        return Err(ApiError::InvalidInput("base symbol must not be empty".to_string()));
+    }
+
+    let quote = query.quote.as_deref().unwrap_or("USD");
+    let min_sources = query.min_sources.unwrap_or(3);
+
+    let consensus = get_price_consensus(&query.base, quote, min_sources, query.trusted_source.as_deref()).await?;
+
+    Ok(Json(consensus))
+}
+
+pub fn router(state: AppState) -> Router {
+    Router::new()
+        .route("/consensus", get(get_price_consensus_handler))
+        .with_state(state)
+}
@@ -1,15 +1,22 @@
 use axum::{
     extract::{Path, Query, State},
     http::StatusCode,
-    response::IntoResponse,
-    routing::get,
+    response::{
+        sse::{Event, KeepAlive, Sse},
+        IntoResponse,
+    },
+    routing::{delete, get},
     Json, Router,
 };
+use futures::{Stream, StreamExt};
 use serde::{Deserialize, Serialize};
+use std::convert::Infallible;
 use utoipa::{IntoParams, ToSchema};
 
+use crate::services::position_manager::{ExitRule, RegisterExitRuleRequest};
 use crate::sources::pumpfun_data::{
-    BondingCurveInfo, TokenInfo,
+    BondingCurveInfo, BondingCurveSide, BondingCurveQuote, PumpFunConfig, PumpFunEventFilter,
+    PumpFunService, TokenInfo,
 };
 use crate::errors::ApiError;
 use crate::state::AppState;
@@ -50,12 +57,17 @@ pub fn router(state: AppState) -> Router {
     Router::new()
         .route("/trending", get(get_trending_pumpfun))
         .route("/token/{mint_address}", get(get_token_info))
+        .route("/token/{mint_address}/candles", get(get_token_candles))
         .route("/bonding-curve/{mint_address}", get(get_bonding_curve))
+        .route("/bonding-curve/{mint_address}/quote", get(get_bonding_curve_quote))
         .route("/market-summary", get(get_market_summary))
         .route("/new-tokens", get(get_new_tokens))
         .route("/top-gainers", get(get_top_gainers))
         .route("/top-losers", get(get_top_losers))
         .route("/search/{query}", get(search_tokens))
+        .route("/stream", get(pumpfun_stream))
+        .route("/positions/rules", get(list_exit_rules).post(register_exit_rule))
+        .route("/positions/rules/{id}", delete(cancel_exit_rule))
         .with_state(state)
 }
 
@@ -120,7 +132,7 @@ pub async fn get_trending_pumpfun(
     responses((status = 200, description = "Token information", body = PumpFunResponse<TokenInfo>))
 )]
 pub async fn get_token_info(
-    State(_state): State<AppState>,
+    State(state): State<AppState>,
     Path(mint_address): Path<String>,
 ) -> Result<impl IntoResponse, ApiError> {
     // Validate mint address format
@@ -128,19 +140,27 @@ pub async fn get_token_info(
         return Err(ApiError::InvalidInput("Invalid mint address format".to_string()));
     }
 
-    // Mock token info - in a real implementation, fetch from pump.fun API or on-chain data
-    let token_info = TokenInfo {
-        mint_address: mint_address.clone(),
-        name: "Example Meme Token".to_string(),
-        symbol: "EMT".to_string(),
-        description: "A trendy meme token on pump.fun".to_string(),
-        image_url: Some("https://example.com/token.png".to_string()),
-        creator: "11111111111111111111111111111112".to_string(),
-        created_at: Some(chrono::Utc::now().to_rfc3339()),
-        market_cap: Some(100_000.0),
-        price: Some(0.001),
-        volume_24h: Some(50_000.0),
-    };
+    // Token info changes slowly relative to request volume, so cache it for a minute per mint
+    // (see crate::cache::MemoryCache::get_or_compute for the stampede protection this gets).
+    let cache_key = crate::cache::cache_key("pumpfun_token_info", &[("mint", mint_address.as_str())]);
+    let cached = state.cache.get_or_compute(&cache_key, std::time::Duration::from_secs(60), || async move {
+        // Mock token info - in a real implementation, fetch from pump.fun API or on-chain data
+        let token_info = TokenInfo {
+            mint_address: mint_address.clone(),
+            name: "Example Meme Token".to_string(),
+            symbol: "EMT".to_string(),
+            description: "A trendy meme token on pump.fun".to_string(),
+            image_url: Some("https://example.com/token.png".to_string()),
+            creator: "11111111111111111111111111111112".to_string(),
+            created_at: Some(chrono::Utc::now().to_rfc3339()),
+            market_cap: Some(100_000.0),
+            price: Some(0.001),
+            volume_24h: Some(50_000.0),
+        };
+        serde_json::json!(token_info)
+    }).await;
+    let token_info: TokenInfo = serde_json::from_value(cached)
+        .map_err(|e| ApiError::InternalError(format!("failed to deserialize cached token info: {e}")))?;
 
     let response = PumpFunResponse {
         success: true,
@@ -155,6 +175,76 @@ pub async fn get_token_info(
     Ok((StatusCode::OK, Json(response)))
 }
 
+#[derive(Debug, Deserialize, IntoParams, ToSchema)]
+pub struct PumpFunCandlesQuery {
+    /// Candle resolution: `1m`/`5m`/`15m`/`1h`/`4h`/`1d`. Defaults to `1m`.
+    pub resolution: Option<String>,
+    /// Unix seconds; only candles at or after this time are returned. Defaults to 30 days ago.
+    pub from: Option<i64>,
+    pub limit: Option<i64>,
+}
+
+/// Seconds per candle for each resolution label this endpoint accepts.
+fn resolution_seconds(label: &str) -> Option<i64> {
+    match label {
+        "1m" => Some(60),
+        "5m" => Some(5 * 60),
+        "15m" => Some(15 * 60),
+        "1h" => Some(60 * 60),
+        "4h" => Some(4 * 60 * 60),
+        "1d" => Some(24 * 60 * 60),
+        _ => None,
+    }
+}
+
+/// Get OHLCV candles for a token, rolled up from trades backfilled/recorded via
+/// [`crate::sources::pumpfun_data::backfill_trade_candles`]/[`crate::sources::pumpfun_data::record_live_trade`].
+/// Empty (not an error) when candle persistence isn't configured or no trades have been
+/// recorded for this mint yet -- pump.fun has no upstream OHLC API to fall back on the way
+/// `/kraken/ohlc` does.
+#[utoipa::path(
+    get,
+    path = "/pumpfun/token/{mint_address}/candles",
+    params(
+        ("mint_address" = String, Path, description = "Token mint address"),
+        PumpFunCandlesQuery,
+    ),
+    tag = "pumpfun",
+    responses((status = 200, description = "OHLCV candles for a token", body = PumpFunResponse<Vec<crate::services::candles::Candle>>))
+)]
+pub async fn get_token_candles(
+    State(state): State<AppState>,
+    Path(mint_address): Path<String>,
+    Query(query): Query<PumpFunCandlesQuery>,
+) -> Result<impl IntoResponse, ApiError> {
+    if mint_address.len() != 44 {
+        return Err(ApiError::InvalidInput("Invalid mint address format".to_string()));
+    }
+
+    let resolution = query.resolution.as_deref().unwrap_or("1m");
+    let resolution_secs = resolution_seconds(resolution)
+        .ok_or_else(|| ApiError::BadRequest(format!("Unsupported resolution: {resolution}")))?;
+    let from = query.from.and_then(|s| chrono::DateTime::from_timestamp(s, 0));
+    let limit = query.limit.unwrap_or(500);
+
+    let pair = crate::sources::pumpfun_data::candle_store_pair(&mint_address);
+    let candles = state.candle_store
+        .aggregated_candles(&pair, resolution_secs, from, limit)
+        .await?;
+
+    let response = PumpFunResponse {
+        success: true,
+        data: candles,
+        timestamp: std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .unwrap()
+            .as_secs(),
+        source: "pumpfun".to_string(),
+    };
+
+    Ok((StatusCode::OK, Json(response)))
+}
+
 /// Get bonding curve information for a token
 #[utoipa::path(
     get,
@@ -176,15 +266,7 @@ pub async fn get_bonding_curve(
 
     // This would use the PumpFunService to get actual bonding curve data
     // For now, return mock data
-    let bonding_curve = BondingCurveInfo {
-        mint_address: mint_address.clone(),
-        virtual_token_reserves: 1_000_000_000_000,
-        virtual_sol_reserves: 30_000_000_000,
-        real_token_reserves: 800_000_000_000,
-        real_sol_reserves: 20_000_000_000,
-        token_total_supply: 1_000_000_000_000,
-        complete: false,
-    };
+    let bonding_curve = mock_bonding_curve(&mint_address);
 
     let response = PumpFunResponse {
         success: true,
@@ -195,10 +277,214 @@ pub async fn get_bonding_curve(
             .as_secs(),
         source: "pumpfun".to_string(),
     };
-    
+
+    Ok((StatusCode::OK, Json(response)))
+}
+
+#[derive(Debug, Deserialize, IntoParams, ToSchema)]
+pub struct BondingCurveQuoteQuery {
+    /// "buy" or "sell".
+    pub side: String,
+    /// SOL in, required for `side=buy`.
+    pub sol_in: Option<f64>,
+    /// Raw token units in, required for `side=sell`.
+    pub token_in: Option<f64>,
+    /// Max acceptable slippage in basis points; when given, populates `min_amount_out`.
+    pub slippage_bps: Option<u64>,
+}
+
+/// Quote a buy or sell against a token's bonding curve using the constant-product (xyk)
+/// invariant, returning spot/execution price, price impact, migration progress, and implied
+/// market cap.
+#[utoipa::path(
+    get,
+    path = "/pumpfun/bonding-curve/{mint_address}/quote",
+    params(
+        ("mint_address" = String, Path, description = "Token mint address"),
+        BondingCurveQuoteQuery,
+    ),
+    tag = "pumpfun",
+    responses((status = 200, description = "Bonding curve quote", body = PumpFunResponse<BondingCurveQuote>))
+)]
+pub async fn get_bonding_curve_quote(
+    State(state): State<AppState>,
+    Path(mint_address): Path<String>,
+    Query(query): Query<BondingCurveQuoteQuery>,
+) -> Result<impl IntoResponse, ApiError> {
+    if mint_address.len() != 44 {
+        return Err(ApiError::InvalidInput("Invalid mint address format".to_string()));
+    }
+
+    let (side, amount) = match query.side.as_str() {
+        "buy" => (
+            BondingCurveSide::Buy,
+            query.sol_in.ok_or_else(|| ApiError::InvalidInput("sol_in is required for side=buy".to_string()))?,
+        ),
+        "sell" => (
+            BondingCurveSide::Sell,
+            query.token_in.ok_or_else(|| ApiError::InvalidInput("token_in is required for side=sell".to_string()))?,
+        ),
+        other => return Err(ApiError::InvalidInput(format!("invalid side '{other}', expected 'buy' or 'sell'"))),
+    };
+
+    if amount <= 0.0 {
+        return Err(ApiError::InvalidInput("amount must be positive".to_string()));
+    }
+
+    // Reserves move with every trade, so a quote is only cached for a few seconds -- long
+    // enough to absorb a burst of identical requests, short enough to stay close to live.
+    let amount_key = amount.to_string();
+    let slippage_key = query.slippage_bps.map(|b| b.to_string()).unwrap_or_default();
+    let cache_key = crate::cache::cache_key(
+        "pumpfun_bonding_curve_quote",
+        &[
+            ("mint", mint_address.as_str()),
+            ("side", query.side.as_str()),
+            ("amount", amount_key.as_str()),
+            ("slippage_bps", slippage_key.as_str()),
+        ],
+    );
+    let slippage_bps = query.slippage_bps;
+    let cached = state.cache.get_or_compute(&cache_key, std::time::Duration::from_secs(5), || async move {
+        let bonding_curve = mock_bonding_curve(&mint_address);
+        match bonding_curve.quote(side, amount, slippage_bps) {
+            Ok(quote) => serde_json::json!(quote),
+            Err(e) => serde_json::json!({ "error": e.to_string() }),
+        }
+    }).await;
+
+    if let Some(error) = cached.get("error").and_then(|v| v.as_str()) {
+        return Err(ApiError::InvalidInput(error.to_string()));
+    }
+
+    let quote: BondingCurveQuote = serde_json::from_value(cached)
+        .map_err(|e| ApiError::InternalError(format!("failed to deserialize cached bonding curve quote: {e}")))?;
+
+    let response = PumpFunResponse {
+        success: true,
+        data: quote,
+        timestamp: std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .unwrap()
+            .as_secs(),
+        source: "pumpfun".to_string(),
+    };
+
     Ok((StatusCode::OK, Json(response)))
 }
 
+/// Stream live pump.fun create/trade events as Server-Sent Events, sharing one upstream SDK
+/// subscription across every subscriber (see [`PumpFunService::subscribe_stream`]). Accepts
+/// the same filter fields as [`PumpFunEventFilter`] as query parameters so a client watching
+/// one token isn't flooded with the full firehose. Requires `PUMPFUN_PRIVATE_KEY` to be
+/// configured, since the underlying SDK subscription authenticates the same way trading calls
+/// do.
+#[utoipa::path(
+    get,
+    path = "/pumpfun/stream",
+    params(PumpFunEventFilter),
+    tag = "pumpfun",
+    responses((status = 200, description = "Server-Sent Events stream of pump.fun events"))
+)]
+pub async fn pumpfun_stream(
+    Query(filter): Query<PumpFunEventFilter>,
+) -> Result<Sse<impl Stream<Item = Result<Event, Infallible>>>, ApiError> {
+    let mut service = PumpFunService::new(PumpFunConfig::from_env());
+    service.initialize().await?;
+    let service = std::sync::Arc::new(service);
+
+    let events = service.subscribe_stream(filter).await?.map(|event| {
+        Ok(Event::default().json_data(&event).unwrap_or_else(|_| Event::default().data("{}")))
+    });
+
+    Ok(Sse::new(events).keep_alive(KeepAlive::default()))
+}
+
+/// Register a take-profit/stop-loss exit rule against a held mint. `AppState::position_manager`
+/// watches its live trade-event stream and fires a sell once the rule trips; see
+/// [`crate::services::position_manager::PositionManager`].
+#[utoipa::path(
+    post,
+    path = "/pumpfun/positions/rules",
+    request_body = RegisterExitRuleRequest,
+    tag = "pumpfun",
+    responses((status = 200, description = "Registered exit rule", body = PumpFunResponse<ExitRule>))
+)]
+pub async fn register_exit_rule(
+    State(state): State<AppState>,
+    Json(request): Json<RegisterExitRuleRequest>,
+) -> Result<impl IntoResponse, ApiError> {
+    let rule = state.position_manager.register_rule(request).await?;
+
+    let response = PumpFunResponse {
+        success: true,
+        data: rule,
+        timestamp: std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .unwrap()
+            .as_secs(),
+        source: "pumpfun".to_string(),
+    };
+
+    Ok((StatusCode::OK, Json(response)))
+}
+
+/// List every registered exit rule (open, triggered, and cancelled).
+#[utoipa::path(
+    get,
+    path = "/pumpfun/positions/rules",
+    tag = "pumpfun",
+    responses((status = 200, description = "Registered exit rules", body = PumpFunResponse<Vec<ExitRule>>))
+)]
+pub async fn list_exit_rules(
+    State(state): State<AppState>,
+) -> Result<impl IntoResponse, ApiError> {
+    let rules = state.position_manager.list_rules().await?;
+
+    let response = PumpFunResponse {
+        success: true,
+        data: rules,
+        timestamp: std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .unwrap()
+            .as_secs(),
+        source: "pumpfun".to_string(),
+    };
+
+    Ok((StatusCode::OK, Json(response)))
+}
+
+/// Cancel an open exit rule. A no-op sell never fires for a cancelled rule even if price moves
+/// past its threshold afterward.
+#[utoipa::path(
+    delete,
+    path = "/pumpfun/positions/rules/{id}",
+    params(("id" = i64, Path, description = "Exit rule id")),
+    tag = "pumpfun",
+    responses((status = 200, description = "Exit rule cancelled"))
+)]
+pub async fn cancel_exit_rule(
+    State(state): State<AppState>,
+    Path(id): Path<i64>,
+) -> Result<impl IntoResponse, ApiError> {
+    state.position_manager.cancel_rule(id).await?;
+    Ok(StatusCode::OK)
+}
+
+// This would use the PumpFunService to get actual bonding curve data; for now, return mock
+// reserves so the constant-product quote math above has something real to operate on.
+fn mock_bonding_curve(mint_address: &str) -> BondingCurveInfo {
+    BondingCurveInfo {
+        mint_address: mint_address.to_string(),
+        virtual_token_reserves: 1_000_000_000_000,
+        virtual_sol_reserves: 30_000_000_000,
+        real_token_reserves: 800_000_000_000,
+        real_sol_reserves: 20_000_000_000,
+        token_total_supply: 1_000_000_000_000,
+        complete: false,
+    }
+}
+
 /// Get market summary for pump.fun
 #[utoipa::path(
     get,
@@ -1,15 +1,24 @@
 use axum::{
-    extract::{Path, Query, State},
+    extract::{
+        ws::{Message, WebSocket, WebSocketUpgrade},
+        Path, Query, State,
+    },
     http::StatusCode,
-    response::IntoResponse,
-    routing::get,
+    response::{
+        sse::{Event, KeepAlive, Sse},
+        IntoResponse,
+    },
+    routing::{get, post},
     Json, Router,
 };
+use futures::{Stream, StreamExt};
 use serde::{Deserialize, Serialize};
+use std::convert::Infallible;
 use utoipa::{IntoParams, ToSchema};
 
 use crate::sources::helius_data::{
-    HeliusDataSource, SolanaAsset, SolanaTokenAccount, SolanaTransactionSignature, TokenHolding, ProgramAccountData,
+    CollectionStats, HeliusDataSource, PageOptions, PagedAssets, PriorityFeeResponse, PubsubTarget, SolanaAsset,
+    SolanaTokenAccount, SolanaTransactionSignature, SubscriptionKind, TokenHolding, ProgramAccountData, TransactionStatus,
 };
 use crate::errors::ApiError;
 use crate::state::AppState;
@@ -21,6 +30,11 @@ pub struct SolanaQuery {
     pub page: Option<u32>,
     pub verified_only: Option<bool>,
     pub show_fungible: Option<bool>,
+    /// Opaque cursor from a previous response's `cursor` field; continues that page walk
+    /// instead of starting over. Ignored by handlers that don't paginate.
+    pub cursor: Option<String>,
+    pub before: Option<String>,
+    pub after: Option<String>,
 }
 
 #[derive(Debug, Deserialize, IntoParams, ToSchema)]
@@ -31,6 +45,11 @@ pub struct AssetSearchQuery {
     pub collection: Option<String>,
     pub limit: Option<u32>,
     pub page: Option<u32>,
+    /// Opaque cursor from a previous response's `cursor` field; continues that search
+    /// instead of starting over.
+    pub cursor: Option<String>,
+    pub before: Option<String>,
+    pub after: Option<String>,
 }
 
 #[derive(Debug, Serialize, ToSchema)]
@@ -55,9 +74,248 @@ pub fn router(state: AppState) -> Router {
         .route("/collections/top", get(get_top_collections))
         .route("/wallet/{address}/holdings", get(get_wallet_holdings))
         .route("/program/{program_id}/accounts", get(get_program_accounts))
+        .route("/priority-fee", get(get_priority_fee))
+        .route("/ws", get(solana_ws_subscribe))
+        .route("/subscribe", get(solana_pubsub_subscribe))
+        .route("/transactions/send", post(send_transaction))
+        .route("/transactions/{signature}/status", get(get_transaction_status))
         .with_state(state)
 }
 
+#[derive(Debug, Deserialize, IntoParams)]
+pub struct PriorityFeeQuery {
+    /// Comma-separated account addresses the transaction will touch.
+    pub accounts: String,
+    /// One of `min`, `low`, `medium`, `high`, `veryHigh`, `unsafeMax`; defaults to `medium`.
+    pub priority_level: Option<String>,
+}
+
+/// Recommend a `ComputeBudget` priority fee and compute-unit limit for a transaction
+/// touching the given accounts.
+#[utoipa::path(
+    get,
+    path = "/solana/priority-fee",
+    params(PriorityFeeQuery),
+    tag = "solana",
+    responses((status = 200, description = "Priority fee estimate", body = SolanaResponse<PriorityFeeResponse>))
+)]
+pub async fn get_priority_fee(
+    State(state): State<AppState>,
+    Query(query): Query<PriorityFeeQuery>,
+) -> Result<impl IntoResponse, ApiError> {
+    let api_key = state.config.helius_api_key.as_deref()
+        .ok_or_else(|| ApiError::InternalError("Helius API key not configured".to_string()))?;
+
+    let helius = HeliusDataSource::new_mainnet(api_key)
+        .map_err(|e| ApiError::Upstream(e.to_string()))?;
+
+    let accounts: Vec<String> = query.accounts.split(',').map(|s| s.trim().to_string()).filter(|s| !s.is_empty()).collect();
+    if accounts.is_empty() {
+        return Err(ApiError::InvalidInput("accounts must not be empty".to_string()));
+    }
+    let priority_level = query.priority_level.as_deref().unwrap_or("medium");
+
+    let estimate = helius.get_priority_fee_estimate(
+        &accounts,
+        priority_level,
+        state.config.priority_fee_default_micro_lamports,
+    ).map_err(|e| ApiError::Upstream(e.to_string()))?;
+
+    let timestamp = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .unwrap()
+        .as_secs();
+
+    let response = SolanaResponse {
+        success: true,
+        data: estimate,
+        timestamp,
+        source: "helius".to_string(),
+    };
+
+    Ok((StatusCode::OK, Json(response)))
+}
+
+#[derive(Debug, Deserialize, IntoParams)]
+pub struct SolanaWsQuery {
+    /// `account` for wallet/mint account-state changes, `signatures` for new signatures
+    /// involving the address.
+    pub kind: String,
+    pub address: String,
+}
+
+/// Subscribe to live account/signature updates over a WebSocket instead of polling.
+///
+/// Send is implicit: connect with `?kind=account|signatures&address=...` and the socket
+/// streams `{success, data, timestamp, subscription_id}` JSON frames whenever
+/// the upstream state changes. One upstream poll is shared across every client watching
+/// the same address.
+#[utoipa::path(
+    get,
+    path = "/solana/ws",
+    params(SolanaWsQuery),
+    tag = "solana",
+    responses((status = 101, description = "Switching protocols to WebSocket"))
+)]
+pub async fn solana_ws_subscribe(
+    ws: WebSocketUpgrade,
+    State(state): State<AppState>,
+    Query(query): Query<SolanaWsQuery>,
+) -> Result<impl IntoResponse, ApiError> {
+    let kind = match query.kind.as_str() {
+        "account" => SubscriptionKind::Account,
+        "signatures" => SubscriptionKind::Signatures,
+        other => return Err(ApiError::InvalidInput(format!("unknown subscription kind: {other}"))),
+    };
+
+    let subscription_id = format!("{}:{}", query.kind, query.address);
+    let hub = state.solana_ws_hub.clone();
+    let address = query.address;
+
+    Ok(ws.on_upgrade(move |socket| forward_solana_updates(socket, hub, kind, address, subscription_id)))
+}
+
+async fn forward_solana_updates(
+    mut socket: WebSocket,
+    hub: std::sync::Arc<crate::sources::helius_data::SolanaWsHub>,
+    kind: SubscriptionKind,
+    address: String,
+    subscription_id: String,
+) {
+    let mut updates = Box::pin(hub.subscribe(kind, address));
+
+    while let Some(value) = updates.next().await {
+        let timestamp = std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .unwrap_or_default()
+            .as_secs();
+
+        let payload = serde_json::json!({
+            "success": true,
+            "data": value,
+            "timestamp": timestamp,
+            "subscription_id": subscription_id,
+        });
+
+        let Ok(text) = serde_json::to_string(&payload) else { continue };
+        if socket.send(Message::Text(text.into())).await.is_err() {
+            break;
+        }
+    }
+}
+
+#[derive(Debug, Deserialize, IntoParams, ToSchema)]
+pub struct SolanaPubsubQuery {
+    /// `account` or `logs`.
+    pub kind: String,
+    /// Account address to watch (`kind=account`), or a program id/wallet address whose
+    /// mentions to watch (`kind=logs`).
+    pub address: String,
+}
+
+/// Stream real Solana RPC pubsub notifications (`accountSubscribe`/`logsSubscribe`) over
+/// Server-Sent Events, instead of the polling `/solana/ws` endpoint. One upstream pubsub
+/// connection per `(kind, address)` is shared across every SSE client watching it; see
+/// [`crate::sources::helius_data::SolanaPubsubHub`] for the subscribe handshake and
+/// reconnect-with-backoff behavior.
+#[utoipa::path(
+    get,
+    path = "/solana/subscribe",
+    params(SolanaPubsubQuery),
+    tag = "solana",
+    responses((status = 200, description = "Server-Sent Events stream of pubsub notifications"))
+)]
+pub async fn solana_pubsub_subscribe(
+    State(state): State<AppState>,
+    Query(query): Query<SolanaPubsubQuery>,
+) -> Result<Sse<impl Stream<Item = Result<Event, Infallible>>>, ApiError> {
+    let target = match query.kind.as_str() {
+        "account" => PubsubTarget::Account(query.address),
+        "logs" => PubsubTarget::Logs(query.address),
+        other => return Err(ApiError::InvalidInput(format!("unknown subscription kind: {other}"))),
+    };
+
+    let updates = state.solana_pubsub_hub.subscribe(target).map(|event| {
+        Ok(Event::default().json_data(event).unwrap_or_else(|_| Event::default().data("{}")))
+    });
+
+    Ok(Sse::new(updates).keep_alive(KeepAlive::default()))
+}
+
+#[derive(Debug, Deserialize, ToSchema)]
+pub struct SendTransactionRequest {
+    /// Base64-encoded, already-signed transaction.
+    pub transaction: String,
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct SendTransactionData {
+    pub signature: String,
+}
+
+/// Submit a signed, base64-encoded transaction. Returns the signature immediately; a
+/// background task keeps resending it on a fixed interval until it confirms, its blockhash
+/// expires, or attempts/the deadline run out. Poll `/solana/transactions/{signature}/status`
+/// for the outcome.
+#[utoipa::path(
+    post,
+    path = "/solana/transactions/send",
+    request_body = SendTransactionRequest,
+    tag = "solana",
+    responses((status = 200, description = "Transaction submitted", body = SolanaResponse<SendTransactionData>))
+)]
+pub async fn send_transaction(
+    State(state): State<AppState>,
+    Json(request): Json<SendTransactionRequest>,
+) -> Result<impl IntoResponse, ApiError> {
+    let signature = state.transaction_tracker.clone().send_transaction(&request.transaction).await?;
+
+    let timestamp = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .unwrap_or_default()
+        .as_secs();
+
+    let response = SolanaResponse {
+        success: true,
+        data: SendTransactionData { signature },
+        timestamp,
+        source: "helius".to_string(),
+    };
+
+    Ok((StatusCode::OK, Json(response)))
+}
+
+/// Confirmation status and slot for a transaction previously submitted through
+/// `/solana/transactions/send`.
+#[utoipa::path(
+    get,
+    path = "/solana/transactions/{signature}/status",
+    params(("signature" = String, Path, description = "Transaction signature")),
+    tag = "solana",
+    responses((status = 200, description = "Transaction status", body = SolanaResponse<TransactionStatus>))
+)]
+pub async fn get_transaction_status(
+    State(state): State<AppState>,
+    Path(signature): Path<String>,
+) -> Result<impl IntoResponse, ApiError> {
+    let status = state.transaction_tracker.status(&signature).await
+        .ok_or_else(|| ApiError::NotFound(format!("unknown transaction signature: {signature}")))?;
+
+    let timestamp = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .unwrap_or_default()
+        .as_secs();
+
+    let response = SolanaResponse {
+        success: true,
+        data: status,
+        timestamp,
+        source: "helius".to_string(),
+    };
+
+    Ok((StatusCode::OK, Json(response)))
+}
+
 /// Get a single Solana asset by its mint address
 #[utoipa::path(
     get,
@@ -105,7 +363,7 @@ pub async fn get_asset(
         SolanaQuery
     ),
     tag = "solana",
-    responses((status = 200, description = "Assets owned by address", body = SolanaResponse<Vec<SolanaAsset>>))
+    responses((status = 200, description = "Assets owned by address", body = SolanaResponse<PagedAssets>))
 )]
 pub async fn get_assets_by_owner(
     State(state): State<AppState>,
@@ -114,25 +372,31 @@ pub async fn get_assets_by_owner(
 ) -> Result<impl IntoResponse, ApiError> {
     let api_key = state.config.helius_api_key.as_deref()
         .ok_or_else(|| ApiError::InternalError("Helius API key not configured".to_string()))?;
-    
+
     let helius = HeliusDataSource::new_mainnet(api_key)
         .map_err(|e| ApiError::Upstream(e.to_string()))?;
-    
-    let assets = helius.get_assets_by_owner(&owner, query.limit).await
+
+    let options = PageOptions {
+        limit: query.limit,
+        cursor: query.cursor,
+        before: query.before,
+        after: query.after,
+    };
+    let paged = helius.get_assets_by_owner(&owner, options).await
         .map_err(|e| ApiError::Upstream(e.to_string()))?;
-    
+
     let timestamp = std::time::SystemTime::now()
         .duration_since(std::time::UNIX_EPOCH)
         .unwrap()
         .as_secs();
-    
+
     let response = SolanaResponse {
         success: true,
-        data: assets,
+        data: paged,
         timestamp,
         source: "helius".to_string(),
     };
-    
+
     Ok((StatusCode::OK, Json(response)))
 }
 
@@ -182,7 +446,7 @@ pub async fn get_assets_by_creator(
     path = "/solana/assets/search",
     params(AssetSearchQuery),
     tag = "solana",
-    responses((status = 200, description = "Search results", body = SolanaResponse<Vec<SolanaAsset>>))
+    responses((status = 200, description = "Search results", body = SolanaResponse<PagedAssets>))
 )]
 pub async fn search_assets(
     State(state): State<AppState>,
@@ -190,10 +454,10 @@ pub async fn search_assets(
 ) -> Result<impl IntoResponse, ApiError> {
     let api_key = state.config.helius_api_key.as_deref()
         .ok_or_else(|| ApiError::InternalError("Helius API key not configured".to_string()))?;
-    
+
     let helius = HeliusDataSource::new_mainnet(api_key)
         .map_err(|e| ApiError::Upstream(e.to_string()))?;
-    
+
     // Build search criteria
     let mut search_criteria = std::collections::HashMap::new();
     if let Some(owner) = query.owner {
@@ -205,22 +469,28 @@ pub async fn search_assets(
     if let Some(authority) = query.authority {
         search_criteria.insert("authority".to_string(), authority);
     }
-    
-    let assets = helius.search_assets(search_criteria).await
+
+    let options = PageOptions {
+        limit: query.limit,
+        cursor: query.cursor,
+        before: query.before,
+        after: query.after,
+    };
+    let paged = helius.search_assets(search_criteria, options).await
         .map_err(|e| ApiError::Upstream(e.to_string()))?;
-    
+
     let timestamp = std::time::SystemTime::now()
         .duration_since(std::time::UNIX_EPOCH)
         .unwrap()
         .as_secs();
-    
+
     let response = SolanaResponse {
         success: true,
-        data: assets,
+        data: paged,
         timestamp,
         source: "helius".to_string(),
     };
-    
+
     Ok((StatusCode::OK, Json(response)))
 }
 
@@ -337,8 +607,16 @@ pub async fn get_trending_solana(
     let limit = params.get("limit")
         .and_then(|l| l.parse::<usize>().ok())
         .unwrap_or(20);
-    
-    let trending = helius.get_trending_solana_assets(limit).await
+
+    let feed_map = crate::sources::helius_data::default_pyth_feed_map();
+    let oracle = crate::sources::helius_data::PythPriceOracle {
+        source: &helius,
+        feed_map: &feed_map,
+        max_staleness_secs: crate::sources::helius_data::DEFAULT_PYTH_MAX_STALENESS_SECS,
+        max_confidence_fraction: crate::sources::helius_data::DEFAULT_PYTH_MAX_CONFIDENCE_FRACTION,
+    };
+
+    let trending = helius.get_trending_solana_assets(limit, Some(&oracle)).await
         .map_err(|e| ApiError::Upstream(e.to_string()))?;
     
     let timestamp = std::time::SystemTime::now()
@@ -356,22 +634,29 @@ pub async fn get_trending_solana(
     Ok((StatusCode::OK, Json(response)))
 }
 
-/// Get trending NFTs
+#[derive(Debug, Deserialize, IntoParams)]
+pub struct CollectionQuery {
+    pub limit: Option<u32>,
+    /// `1h`, `24h`, or `7d`; accepted but not used: the underlying search has no
+    /// time-bucketed activity feed, and the handlers don't pass it on.
+    pub time_window: Option<String>,
+    /// `volume`, `floor`, or `holders`; defaults to `volume`.
+    pub sort_by: Option<String>,
+}
+
+/// Get trending NFTs, ranked by real marketplace collection stats.
 #[utoipa::path(
     get,
     path = "/solana/nfts/trending",
-    params(
-        ("limit" = Option<u32>, Query, description = "Number of trending NFTs to return")
-    ),
+    params(CollectionQuery),
     tag = "solana",
-    responses((status = 200, description = "Trending Solana NFTs", body = SolanaResponse<Vec<TrendingItem>>))
+    responses((status = 200, description = "Trending Solana NFT collections", body = SolanaResponse<Vec<CollectionStats>>))
 )]
 pub async fn get_trending_nfts(
     State(state): State<AppState>,
-    Query(params): Query<std::collections::HashMap<String, String>>,
+    Query(query): Query<CollectionQuery>,
 ) -> Result<impl IntoResponse, ApiError> {
-    // For now, use the same trending assets endpoint but filter for NFTs
-    get_trending_solana(State(state), Query(params)).await
+    get_top_collections(State(state), Query(query)).await
 }
 
 /// Get trending tokens
@@ -388,27 +673,76 @@ pub async fn get_trending_tokens(
     State(state): State<AppState>,
     Query(params): Query<std::collections::HashMap<String, String>>,
 ) -> Result<impl IntoResponse, ApiError> {
-    // For now, use the same trending assets endpoint but filter for tokens
-    get_trending_solana(State(state), Query(params)).await
+    let api_key = state.config.helius_api_key.as_deref()
+        .ok_or_else(|| ApiError::InternalError("Helius API key not configured".to_string()))?;
+
+    let helius = HeliusDataSource::new_mainnet(api_key)
+        .map_err(|e| ApiError::Upstream(e.to_string()))?;
+
+    let limit = params.get("limit").and_then(|l| l.parse::<usize>().ok()).unwrap_or(20);
+
+    let trending = helius.get_trending_fungible_tokens(limit).await
+        .map_err(|e| ApiError::Upstream(e.to_string()))?;
+
+    let timestamp = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .unwrap()
+        .as_secs();
+
+    let response = SolanaResponse {
+        success: true,
+        data: trending,
+        timestamp,
+        source: "helius".to_string(),
+    };
+
+    Ok((StatusCode::OK, Json(response)))
 }
 
-/// Get top NFT collections
+/// Get top NFT collections, ranked by real marketplace floor/volume/holder stats.
 #[utoipa::path(
     get,
     path = "/solana/collections/top",
-    params(
-        ("limit" = Option<u32>, Query, description = "Number of top collections to return")
-    ),
+    params(CollectionQuery),
     tag = "solana",
-    responses((status = 200, description = "Top Solana NFT collections", body = SolanaResponse<Vec<TrendingItem>>))
+    responses((status = 200, description = "Top Solana NFT collections", body = SolanaResponse<Vec<CollectionStats>>))
 )]
 pub async fn get_top_collections(
     State(state): State<AppState>,
-    Query(params): Query<std::collections::HashMap<String, String>>,
+    Query(query): Query<CollectionQuery>,
 ) -> Result<impl IntoResponse, ApiError> {
-    // This would require collection-specific logic
-    // For now, return trending assets as placeholder
-    get_trending_solana(State(state), Query(params)).await
+    let api_key = state.config.helius_api_key.as_deref()
+        .ok_or_else(|| ApiError::InternalError("Helius API key not configured".to_string()))?;
+
+    let helius = HeliusDataSource::new_mainnet(api_key)
+        .map_err(|e| ApiError::Upstream(e.to_string()))?;
+
+    let limit = query.limit.map(|l| l as usize).unwrap_or(20);
+    let sort_by = query.sort_by.as_deref().unwrap_or("volume");
+
+    let collections = helius.get_top_collections(limit, &state.http, sort_by).await
+        .map_err(|e| ApiError::Upstream(e.to_string()))?;
+
+    let timestamp = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .unwrap()
+        .as_secs();
+
+    let response = SolanaResponse {
+        success: true,
+        data: collections,
+        timestamp,
+        source: "tensor".to_string(),
+    };
+
+    Ok((StatusCode::OK, Json(response)))
+}
+
+/// Wallet holdings plus, when prices were requested, the portfolio's total USD value.
+#[derive(Debug, Serialize, ToSchema)]
+pub struct WalletHoldingsData {
+    pub holdings: Vec<TokenHolding>,
+    pub total_usd_value: Option<f64>,
 }
 
 /// Get wallet token holdings summary
@@ -416,36 +750,47 @@ pub async fn get_top_collections(
     get,
     path = "/solana/wallet/{address}/holdings",
     params(
-        ("address" = String, Path, description = "Wallet address")
+        ("address" = String, Path, description = "Wallet address"),
+        ("with_prices" = Option<bool>, Query, description = "Enrich each holding with a live Pyth price and aggregate total_usd_value")
     ),
     tag = "solana",
-    responses((status = 200, description = "Wallet token holdings", body = SolanaResponse<Vec<TokenHolding>>))
+    responses((status = 200, description = "Wallet token holdings", body = SolanaResponse<WalletHoldingsData>))
 )]
 pub async fn get_wallet_holdings(
     State(state): State<AppState>,
     Path(address): Path<String>,
+    Query(params): Query<std::collections::HashMap<String, String>>,
 ) -> Result<impl IntoResponse, ApiError> {
     let api_key = state.config.helius_api_key.as_deref()
         .ok_or_else(|| ApiError::InternalError("Helius API key not configured".to_string()))?;
-    
+
     let helius = HeliusDataSource::new_mainnet(api_key)
         .map_err(|e| ApiError::Upstream(e.to_string()))?;
-    
-    let holdings = helius.get_wallet_holdings(&address).await
+
+    let with_prices = params.get("with_prices")
+        .map(|v| v == "true" || v == "1")
+        .unwrap_or(false);
+    let feed_map = with_prices.then(crate::sources::helius_data::default_pyth_feed_map);
+
+    let holdings = helius.get_wallet_holdings(&address, feed_map.as_ref()).await
         .map_err(|e| ApiError::Upstream(e.to_string()))?;
-    
+
+    let total_usd_value = with_prices.then(|| {
+        holdings.iter().filter_map(|h| h.usd_value).sum::<f64>()
+    });
+
     let timestamp = std::time::SystemTime::now()
         .duration_since(std::time::UNIX_EPOCH)
         .unwrap()
         .as_secs();
-    
+
     let response = SolanaResponse {
         success: true,
-        data: holdings,
+        data: WalletHoldingsData { holdings, total_usd_value },
         timestamp,
         source: "helius".to_string(),
     };
-    
+
     Ok((StatusCode::OK, Json(response)))
 }
 
@@ -455,7 +800,8 @@ pub async fn get_wallet_holdings(
     path = "/solana/program/{program_id}/accounts",
     params(
         ("program_id" = String, Path, description = "Program ID"),
-        ("limit" = Option<u32>, Query, description = "Number of accounts to return")
+        ("limit" = Option<u32>, Query, description = "Number of accounts to return"),
+        ("encoding" = Option<String>, Query, description = "`jsonParsed` to decode known program layouts, or `base64` for the raw blob (default)")
     ),
     tag = "solana",
     responses((status = 200, description = "Program accounts", body = SolanaResponse<Vec<ProgramAccountData>>))
@@ -467,14 +813,15 @@ pub async fn get_program_accounts(
 ) -> Result<impl IntoResponse, ApiError> {
     let api_key = state.config.helius_api_key.as_deref()
         .ok_or_else(|| ApiError::InternalError("Helius API key not configured".to_string()))?;
-    
+
     let helius = HeliusDataSource::new_mainnet(api_key)
         .map_err(|e| ApiError::Upstream(e.to_string()))?;
-    
+
     let limit = params.get("limit")
         .and_then(|l| l.parse::<u32>().ok());
-    
-    let accounts = helius.get_program_accounts(&program_id, limit).await
+    let encoding = params.get("encoding").map(String::as_str).unwrap_or("base64");
+
+    let accounts = helius.get_program_accounts(&program_id, limit, encoding).await
         .map_err(|e| ApiError::Upstream(e.to_string()))?;
     
     let timestamp = std::time::SystemTime::now()
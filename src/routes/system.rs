@@ -9,6 +9,7 @@ use serde_json::json;
 use crate::types::HealthResponse;
 use crate::state::AppState;
 use crate::errors::ApiError;
+use crate::services::health::ServiceHealth;
 
 #[derive(Debug, Serialize, utoipa::ToSchema)]
 pub struct SystemStatus {
@@ -28,6 +29,20 @@ pub struct ServiceStatus {
     pub response_time_ms: Option<u64>,
 }
 
+impl From<ServiceHealth> for ServiceStatus {
+    fn from(health: ServiceHealth) -> Self {
+        let last_check = health.last_success.max(health.last_failure)
+            .map(|t| t.timestamp() as u64)
+            .unwrap_or(0);
+        Self {
+            status: health.status.as_str().to_string(),
+            last_check,
+            error_count: health.error_count,
+            response_time_ms: Some(health.ewma_response_time_ms as u64),
+        }
+    }
+}
+
 #[derive(Debug, Serialize, utoipa::ToSchema)]
 pub struct MetricsResponse {
     pub total_requests: u64,
@@ -51,13 +66,31 @@ pub struct MemoryUsage {
     pub used_mb: u64,
     pub total_mb: u64,
     pub percentage: f64,
+    pub process_cpu_percent: f32,
+    pub open_fds: u64,
+    pub thread_count: u64,
+}
+
+impl From<crate::monitoring::ResourceSnapshot> for MemoryUsage {
+    fn from(snapshot: crate::monitoring::ResourceSnapshot) -> Self {
+        Self {
+            used_mb: snapshot.used_mb,
+            total_mb: snapshot.total_mb,
+            percentage: snapshot.percentage,
+            process_cpu_percent: snapshot.process_cpu_percent,
+            open_fds: snapshot.open_fds,
+            thread_count: snapshot.thread_count,
+        }
+    }
 }
 
 pub fn router(state: AppState) -> Router {
     Router::new()
         .route("/health", get(health))
         .route("/status", get(system_status))
-        .route("/metrics", get(metrics))
+        .route("/status/feed.xml", get(status_feed))
+        .route("/metrics", get(metrics_prometheus))
+        .route("/metrics/json", get(metrics))
         .route("/ready", get(readiness_check))
         .route("/live", get(liveness_check))
         .with_state(state)
@@ -78,18 +111,17 @@ pub async fn system_status(
         .map_err(|_| ApiError::InternalError("Failed to get system time".to_string()))?
         .as_secs();
 
-    // Check external services
-    let mut services = HashMap::new();
-    
-    // Check Alpaca API
-    let alpaca_status = check_alpaca_service(&state).await;
-    services.insert("alpaca".to_string(), alpaca_status);
-    
-    // Check Yahoo Finance API
-    let yahoo_status = check_yahoo_service(&state).await;
-    services.insert("yahoo_finance".to_string(), yahoo_status);
-    
-    // Check Reddit API (if configured)
+    // Read the background-probed, cached health registry instead of issuing live
+    // upstream calls on every `/status` hit.
+    let mut services: HashMap<String, ServiceStatus> = state
+        .health_registry
+        .snapshot()
+        .await
+        .into_iter()
+        .map(|(name, health)| (name, ServiceStatus::from(health)))
+        .collect();
+
+    // Check Reddit API (if configured) -- not a registered health probe, just a config check.
     if state.config.reddit.is_some() {
         let reddit_status = check_reddit_service(&state).await;
         services.insert("reddit".to_string(), reddit_status);
@@ -115,23 +147,127 @@ pub async fn system_status(
     Ok((StatusCode::OK, Json(body)))
 }
 
-#[utoipa::path(get, path = "/metrics", tag = "system", responses((status = 200, description = "System metrics", body = MetricsResponse)))]
+/// Atom feed of recent service-health transitions (`status` → `status`), backed by
+/// `state.incident_log`'s bounded ring buffer, so outages can be watched in a feed reader or
+/// piped into chat integrations without polling the JSON `/status` endpoint.
+#[utoipa::path(get, path = "/status/feed.xml", tag = "system", responses((status = 200, description = "Atom feed of recent service-health incidents")))]
+pub async fn status_feed(
+    axum::extract::State(state): axum::extract::State<AppState>
+) -> impl IntoResponse {
+    let incidents = state.incident_log.recent().await;
+    let updated = incidents
+        .first()
+        .map(|i| i.timestamp)
+        .unwrap_or_else(chrono::Utc::now)
+        .to_rfc3339();
+
+    let mut xml = String::new();
+    xml.push_str("<?xml version=\"1.0\" encoding=\"utf-8\"?>\n");
+    xml.push_str("<feed xmlns=\"http://www.w3.org/2005/Atom\">\n");
+    xml.push_str("  <title>trading-api service health incidents</title>\n");
+    xml.push_str("  <id>urn:trading-api:status-feed</id>\n");
+    xml.push_str(&format!("  <updated>{updated}</updated>\n"));
+
+    for incident in &incidents {
+        let title = format!("{} \u{2192} {}", incident.service, incident.to_status.as_str());
+        let summary = format!(
+            "error_count={}, response_time_ms={:.1}",
+            incident.error_count, incident.response_time_ms
+        );
+        xml.push_str("  <entry>\n");
+        xml.push_str(&format!("    <id>urn:trading-api:incident:{}</id>\n", incident.id));
+        xml.push_str(&format!("    <title>{}</title>\n", escape_xml(&title)));
+        xml.push_str(&format!("    <updated>{}</updated>\n", incident.timestamp.to_rfc3339()));
+        xml.push_str(&format!("    <summary>{}</summary>\n", escape_xml(&summary)));
+        xml.push_str("  </entry>\n");
+    }
+
+    xml.push_str("</feed>\n");
+
+    (StatusCode::OK, [("Content-Type", "application/atom+xml")], xml)
+}
+
+/// Escapes the five XML predefined entities so incident titles/summaries (service names,
+/// error counts) can't break the feed's markup.
+fn escape_xml(s: &str) -> String {
+    s.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+        .replace('\'', "&apos;")
+}
+
+/// Prometheus text-exposition scrape endpoint, opt-outable via `METRICS_ENABLED` (mirroring
+/// `RateLimitConfig.enabled`). The legacy JSON metrics summary moved to `/metrics/json` so this
+/// path can serve the format scrapers actually expect at the conventional `/metrics` route.
+#[utoipa::path(get, path = "/metrics", tag = "system", responses((status = 200, description = "Prometheus text exposition"), (status = 404, description = "Metrics disabled via METRICS_ENABLED")))]
+pub async fn metrics_prometheus(
+    axum::extract::State(state): axum::extract::State<AppState>
+) -> Result<impl IntoResponse, ApiError> {
+    if !state.config.metrics.enabled {
+        return Err(ApiError::NotFound("metrics endpoint disabled".to_string()));
+    }
+
+    let mut body = state.prometheus_metrics.render().await;
+
+    body.push_str("# HELP cache_hits_total Cache lookups served from the in-memory cache.\n");
+    body.push_str("# TYPE cache_hits_total counter\n");
+    body.push_str(&format!("cache_hits_total {}\n", state.cache.hits()));
+    body.push_str("# HELP cache_misses_total Cache lookups that missed the in-memory cache.\n");
+    body.push_str("# TYPE cache_misses_total counter\n");
+    body.push_str(&format!("cache_misses_total {}\n", state.cache.misses()));
+
+    // Per-outbound-data-source latency/outcome, recorded via `crate::metrics::observe` from
+    // Kraken, Yahoo and the Finviz/Reddit/Alpaca `ResilientFetch` pipeline.
+    let source_latency = crate::metrics::snapshot().await;
+    body.push_str("# HELP source_fetch_total Outbound data-source fetches by outcome.\n");
+    body.push_str("# TYPE source_fetch_total counter\n");
+    for s in &source_latency {
+        body.push_str(&format!("source_fetch_total{{source=\"{}\",outcome=\"ok\"}} {}\n", s.source, s.ok_count));
+        body.push_str(&format!("source_fetch_total{{source=\"{}\",outcome=\"timeout\"}} {}\n", s.source, s.timeout_count));
+        body.push_str(&format!("source_fetch_total{{source=\"{}\",outcome=\"error\"}} {}\n", s.source, s.error_count));
+    }
+    body.push_str("# HELP source_fetch_timeout_rate Fraction of fetches for a source that timed out.\n");
+    body.push_str("# TYPE source_fetch_timeout_rate gauge\n");
+    for s in &source_latency {
+        body.push_str(&format!("source_fetch_timeout_rate{{source=\"{}\"}} {}\n", s.source, s.timeout_rate));
+    }
+    body.push_str("# HELP source_fetch_latency_ms Approximate latency percentiles (ms) per data source.\n");
+    body.push_str("# TYPE source_fetch_latency_ms gauge\n");
+    for s in &source_latency {
+        body.push_str(&format!("source_fetch_latency_ms{{source=\"{}\",quantile=\"0.5\"}} {}\n", s.source, s.p50_ms));
+        body.push_str(&format!("source_fetch_latency_ms{{source=\"{}\",quantile=\"0.9\"}} {}\n", s.source, s.p90_ms));
+        body.push_str(&format!("source_fetch_latency_ms{{source=\"{}\",quantile=\"0.99\"}} {}\n", s.source, s.p99_ms));
+    }
+
+    Ok((StatusCode::OK, [("Content-Type", "text/plain; version=0.0.4")], body))
+}
+
+#[utoipa::path(get, path = "/metrics/json", tag = "system", responses((status = 200, description = "System metrics", body = MetricsResponse)))]
 pub async fn metrics(
     axum::extract::State(state): axum::extract::State<AppState>
 ) -> Result<impl IntoResponse, ApiError> {
     let cache_size = state.cache.size().await;
-    
+
+    let total_requests = state.prometheus_metrics.total_requests().await;
+    let total_errors = state.prometheus_metrics.total_errors().await;
+    let error_rate = if total_requests == 0 {
+        0.0
+    } else {
+        (total_errors as f64 / total_requests as f64) * 100.0
+    };
+
     let body = MetricsResponse {
-        total_requests: 0, // TODO: Implement request counting
-        error_rate: 0.0,   // TODO: Implement error rate calculation
-        average_response_time: 0.0, // TODO: Implement response time tracking
-        active_connections: 0, // TODO: Implement connection tracking
-        memory_usage: get_memory_usage(),
+        total_requests,
+        error_rate,
+        average_response_time: state.prometheus_metrics.average_response_time_ms().await,
+        active_connections: state.prometheus_metrics.in_flight().max(0) as u64,
+        memory_usage: state.system_monitor.snapshot().await.into(),
         cache_stats: CacheStats {
             size: cache_size,
-            hit_rate: 0.0,     // TODO: Implement hit rate tracking
-            total_hits: 0,     // TODO: Implement hit counting
-            total_misses: 0,   // TODO: Implement miss counting
+            hit_rate: state.cache.hit_rate(),
+            total_hits: state.cache.hits(),
+            total_misses: state.cache.misses(),
         },
     };
 
@@ -142,22 +278,17 @@ pub async fn metrics(
 pub async fn readiness_check(
     axum::extract::State(state): axum::extract::State<AppState>
 ) -> Result<impl IntoResponse, ApiError> {
-    // Check if the service is ready to handle requests
-    // This includes checking external dependencies
-    
-    let mut checks = Vec::new();
-    
-    // Check Alpaca API
-    let alpaca_status = check_alpaca_service(&state).await;
-    checks.push(("alpaca", alpaca_status.status == "healthy"));
-    
-    // Check Yahoo Finance API
-    let yahoo_status = check_yahoo_service(&state).await;
-    checks.push(("yahoo_finance", yahoo_status.status == "healthy"));
-    
-    // Check if all critical services are healthy
-    let all_healthy = checks.iter().all(|(_, healthy)| *healthy);
-    
+    // Ready means every registered dependency's cached health is not unhealthy; a service
+    // that hasn't been probed yet (registry empty at startup) is treated as ready so the
+    // process doesn't report not-ready before the first probe interval elapses.
+    use crate::services::health::HealthStatus;
+    let all_healthy = state
+        .health_registry
+        .snapshot()
+        .await
+        .values()
+        .all(|h| h.status != HealthStatus::Unhealthy);
+
     if all_healthy {
         Ok((StatusCode::OK, Json(json!({"status": "ready"}))))
     } else {
@@ -171,72 +302,6 @@ pub async fn liveness_check() -> impl IntoResponse {
     (StatusCode::OK, Json(json!({"status": "alive"})))
 }
 
-async fn check_alpaca_service(state: &AppState) -> ServiceStatus {
-    let start_time = std::time::Instant::now();
-    
-    // Try to make a simple API call to Alpaca
-    let client = &state.http;
-    let (api_key, api_secret) = state.config.alpaca_headers();
-    
-    let response = client
-        .get("https://api.alpaca.markets/v2/clock")
-        .header("APCA-API-KEY-ID", api_key)
-        .header("APCA-API-SECRET-KEY", api_secret)
-        .send()
-        .await;
-    
-    let response_time = start_time.elapsed().as_millis() as u64;
-    
-    match response {
-        Ok(resp) => {
-            if resp.status().is_success() {
-                ServiceStatus {
-                    status: "healthy".to_string(),
-                    last_check: SystemTime::now().duration_since(UNIX_EPOCH).unwrap().as_secs(),
-                    error_count: 0,
-                    response_time_ms: Some(response_time),
-                }
-            } else {
-                ServiceStatus {
-                    status: "degraded".to_string(),
-                    last_check: SystemTime::now().duration_since(UNIX_EPOCH).unwrap().as_secs(),
-                    error_count: 1,
-                    response_time_ms: Some(response_time),
-                }
-            }
-        }
-        Err(_) => ServiceStatus {
-            status: "unhealthy".to_string(),
-            last_check: SystemTime::now().duration_since(UNIX_EPOCH).unwrap().as_secs(),
-            error_count: 1,
-            response_time_ms: Some(response_time),
-        }
-    }
-}
-
-async fn check_yahoo_service(state: &AppState) -> ServiceStatus {
-    let start_time = std::time::Instant::now();
-    
-    // Try to search for a simple ticker
-    let result = state.yahoo.search_ticker("AAPL").await;
-    let response_time = start_time.elapsed().as_millis() as u64;
-    
-    match result {
-        Ok(_) => ServiceStatus {
-            status: "healthy".to_string(),
-            last_check: SystemTime::now().duration_since(UNIX_EPOCH).unwrap().as_secs(),
-            error_count: 0,
-            response_time_ms: Some(response_time),
-        },
-        Err(_) => ServiceStatus {
-            status: "degraded".to_string(),
-            last_check: SystemTime::now().duration_since(UNIX_EPOCH).unwrap().as_secs(),
-            error_count: 1,
-            response_time_ms: Some(response_time),
-        }
-    }
-}
-
 async fn check_reddit_service(state: &AppState) -> ServiceStatus {
     let start_time = std::time::Instant::now();
     
@@ -252,16 +317,3 @@ async fn check_reddit_service(state: &AppState) -> ServiceStatus {
     }
 }
 
-fn get_memory_usage() -> MemoryUsage {
-    // This is a simplified memory usage calculation
-    // In a real application, you'd want to use a proper system monitoring library
-    
-    // For now, return dummy values
-    MemoryUsage {
-        used_mb: 128,
-        total_mb: 1024,
-        percentage: 12.5,
-    }
-}
-
-
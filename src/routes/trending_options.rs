@@ -81,6 +81,7 @@ pub async fn get_trending_options_handler(
         sharpe: query.sharpe_w.unwrap_or(0.4),
         sortino: query.sortino_w.unwrap_or(0.4),
         calmar: query.calmar_w.unwrap_or(0.2),
+        ..Default::default()
     };
 
     // Get trending options analysis
@@ -2,12 +2,57 @@ use axum::{extract::Query, http::StatusCode, response::IntoResponse, routing::ge
 use futures::future::join_all;
 use serde_json::{json, Value};
 
+use crate::helpers::metrics::{self, CompositeWeights};
 use crate::helpers::params::{parse_symbols_csv, periods_per_year_from_interval};
 use crate::services::yahoo::{fetch_prices_for_symbol_cached, metrics_for_prices};
 use crate::state::AppState;
 use crate::types::YahooQuery;
 use crate::errors::ApiError;
 
+/// Component weights for the composite score, overridable per request via `YahooQuery`'s `*_w`
+/// params; falls back to `CompositeWeights::default()` for anything not supplied.
+fn weights_from_query(q: &YahooQuery) -> CompositeWeights {
+    let default = CompositeWeights::default();
+    CompositeWeights {
+        sharpe: q.sharpe_w.unwrap_or(default.sharpe),
+        sortino: q.sortino_w.unwrap_or(default.sortino),
+        calmar: q.calmar_w.unwrap_or(default.calmar),
+        cagr: q.cagr_w.unwrap_or(default.cagr),
+        volatility: q.volatility_w.unwrap_or(default.volatility),
+        max_drawdown: q.max_drawdown_w.unwrap_or(default.max_drawdown),
+    }
+}
+
+/// Attaches a `percentile_ranks` object to each result, giving the relative standing (0-100) of
+/// each metric across the full requested symbol set instead of only its absolute value.
+fn attach_percentile_ranks(results: &mut [Value]) {
+    const METRIC_KEYS: [&str; 7] = ["sharpe", "sortino", "calmar", "cagr", "volatility", "max_drawdown", "composite_score"];
+
+    for key in METRIC_KEYS {
+        let values: Vec<f64> = results.iter()
+            .map(|r| r.get("metrics").and_then(|m| m.get(key)).and_then(|v| v.as_f64()).unwrap_or(f64::MIN))
+            .collect();
+        let ranks = metrics::percentile_ranks(&values);
+
+        for (result, rank) in results.iter_mut().zip(ranks) {
+            let Some(obj) = result.as_object_mut() else { continue };
+            obj.entry("percentile_ranks")
+                .or_insert_with(|| json!({}))
+                .as_object_mut()
+                .expect("percentile_ranks is always inserted as an object")
+                .insert(key.to_string(), json!(rank));
+        }
+    }
+}
+
+fn sort_by_composite_score_desc(results: &mut [Value]) {
+    results.sort_by(|a, b| {
+        let sa = a.get("metrics").and_then(|m| m.get("composite_score")).and_then(|v| v.as_f64()).unwrap_or(f64::MIN);
+        let sb = b.get("metrics").and_then(|m| m.get("composite_score")).and_then(|v| v.as_f64()).unwrap_or(f64::MIN);
+        sb.partial_cmp(&sa).unwrap_or(std::cmp::Ordering::Equal)
+    });
+}
+
 pub fn router(state: AppState) -> Router {
     Router::new()
         .route("/metrics/yahoo", get(get_metrics_yahoo))
@@ -53,16 +98,18 @@ pub async fn get_rank_yahoo(axum::extract::State(state): axum::extract::State<Ap
         return Err(ApiError::BadRequest("symbols query param required".to_string()));
     }
 
+    let weights = weights_from_query(&q);
     let yahoo = state.yahoo.clone();
     let cache = state.cache.clone();
     let futures_vec = symbols.iter().map(move |sym| {
         let yahoo = yahoo.clone();
         let cache = cache.clone();
         let sym = sym.to_string();
+        let weights = weights.clone();
         async move {
         match fetch_prices_for_symbol_cached(&yahoo, &sym, period_label, &cache).await {
             Ok(prices) => {
-                let m = metrics_for_prices(&prices, rf_annual, target_annual, periods_per_year, None);
+                let m = metrics_for_prices(&prices, rf_annual, target_annual, periods_per_year, Some(weights));
                 json!({ "symbol": sym, "metrics": m })
             }
             Err(err) => json!({ "symbol": sym, "error": err }),
@@ -70,11 +117,8 @@ pub async fn get_rank_yahoo(axum::extract::State(state): axum::extract::State<Ap
     }});
 
     let mut results: Vec<Value> = join_all(futures_vec).await;
-    results.sort_by(|a, b| {
-        let sa = a.get("metrics").and_then(|m| m.get("composite_score")).and_then(|v| v.as_f64()).unwrap_or(f64::MIN);
-        let sb = b.get("metrics").and_then(|m| m.get("composite_score")).and_then(|v| v.as_f64()).unwrap_or(f64::MIN);
-        sb.partial_cmp(&sa).unwrap_or(std::cmp::Ordering::Equal)
-    });
+    sort_by_composite_score_desc(&mut results);
+    attach_percentile_ranks(&mut results);
 
     Ok((StatusCode::OK, Json(json!({ "results": results }))))
 }
@@ -94,16 +138,18 @@ pub async fn get_recommendations_yahoo(axum::extract::State(state): axum::extrac
         return Err(ApiError::BadRequest("symbols query param required".to_string()));
     }
 
+    let weights = weights_from_query(&q);
     let yahoo = state.yahoo.clone();
     let cache = state.cache.clone();
     let futures_vec = symbols.iter().map(move |sym| {
         let yahoo = yahoo.clone();
         let cache = cache.clone();
         let sym = sym.to_string();
+        let weights = weights.clone();
         async move {
         match fetch_prices_for_symbol_cached(&yahoo, &sym, period_label, &cache).await {
             Ok(prices) => {
-                let m = metrics_for_prices(&prices, rf_annual, target_annual, periods_per_year, None);
+                let m = metrics_for_prices(&prices, rf_annual, target_annual, periods_per_year, Some(weights));
                 json!({ "symbol": sym, "metrics": m })
             }
             Err(err) => json!({ "symbol": sym, "error": err }),
@@ -111,11 +157,19 @@ pub async fn get_recommendations_yahoo(axum::extract::State(state): axum::extrac
     }});
 
     let mut results: Vec<Value> = join_all(futures_vec).await;
-    results.sort_by(|a, b| {
-        let sa = a.get("metrics").and_then(|m| m.get("composite_score")).and_then(|v| v.as_f64()).unwrap_or(f64::MIN);
-        let sb = b.get("metrics").and_then(|m| m.get("composite_score")).and_then(|v| v.as_f64()).unwrap_or(f64::MIN);
-        sb.partial_cmp(&sa).unwrap_or(std::cmp::Ordering::Equal)
-    });
+    sort_by_composite_score_desc(&mut results);
+    attach_percentile_ranks(&mut results);
+
+    // Unlike /rank/yahoo, only return the subset that actually qualifies: filter by min_score,
+    // then cap at top_n, so recommendations narrows the full ranked list instead of repeating it.
+    if let Some(min_score) = q.min_score {
+        results.retain(|r| {
+            r.get("metrics").and_then(|m| m.get("composite_score")).and_then(|v| v.as_f64()).unwrap_or(f64::MIN) >= min_score
+        });
+    }
+    if let Some(top_n) = q.top_n {
+        results.truncate(top_n);
+    }
 
     Ok((StatusCode::OK, Json(json!({ "results": results }))))
 }
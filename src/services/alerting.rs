@@ -0,0 +1,82 @@
+use serde::Serialize;
+
+use crate::config::WebhookTarget;
+use crate::middleware::{RateLimitConfig, RateLimiter};
+use crate::services::health::{HealthStatus, ServiceHealth};
+
+/// Body POSTed to every configured webhook target when a dependency's health transitions, or
+/// its EWMA response time crosses `rtt_warning_threshold_ms`.
+#[derive(Debug, Clone, Serialize)]
+pub struct ServiceAlert {
+    pub service: String,
+    pub old_status: Option<&'static str>,
+    pub new_status: &'static str,
+    pub response_time_ms: f64,
+    pub rtt_warning_threshold_ms: f64,
+    pub error_count: u64,
+    pub timestamp: chrono::DateTime<chrono::Utc>,
+}
+
+/// Fires webhook notifications when a probed dependency's health state changes (or its RTT
+/// crosses the configured threshold), debounced so a flapping service doesn't spam targets:
+/// repeated alerts for the same `(service, status)` pair are rate-limited by reusing
+/// [`RateLimiter`], keyed on `"{service}:{status}"`, rather than a bespoke cooldown map.
+pub struct AlertDispatcher {
+    http: reqwest::Client,
+    webhooks: Vec<WebhookTarget>,
+    rtt_warning_threshold_ms: f64,
+    debounce: RateLimiter,
+}
+
+impl AlertDispatcher {
+    pub fn new(http: reqwest::Client, webhooks: Vec<WebhookTarget>, rtt_warning_threshold_ms: f64) -> Self {
+        Self {
+            http,
+            webhooks,
+            rtt_warning_threshold_ms,
+            // One alert per (service, status) pair per minute is enough to notify an operator
+            // without spamming targets while a service flaps in and out of a status.
+            debounce: RateLimiter::new(RateLimitConfig { requests_per_minute: 1, burst_size: 1 }),
+        }
+    }
+
+    /// Fires an alert to every configured webhook if `health`'s status differs from
+    /// `previous_status`, or if its EWMA response time crosses `rtt_warning_threshold_ms` --
+    /// subject to the per-`(service, status)` debounce. No-op when no webhooks are configured.
+    pub async fn maybe_alert(&self, service: &str, previous_status: Option<HealthStatus>, health: &ServiceHealth) {
+        if self.webhooks.is_empty() {
+            return;
+        }
+
+        let status_changed = previous_status != Some(health.status);
+        let rtt_exceeded = health.ewma_response_time_ms > self.rtt_warning_threshold_ms;
+        if !status_changed && !rtt_exceeded {
+            return;
+        }
+
+        let debounce_key = format!("{service}:{}", health.status.as_str());
+        if self.debounce.check_rate_limit(&debounce_key).await.is_err() {
+            return;
+        }
+
+        let alert = ServiceAlert {
+            service: service.to_string(),
+            old_status: previous_status.map(|s| s.as_str()),
+            new_status: health.status.as_str(),
+            response_time_ms: health.ewma_response_time_ms,
+            rtt_warning_threshold_ms: self.rtt_warning_threshold_ms,
+            error_count: health.error_count,
+            timestamp: chrono::Utc::now(),
+        };
+
+        for webhook in &self.webhooks {
+            let mut request = self.http.post(&webhook.url).json(&alert);
+            for (key, value) in &webhook.headers {
+                request = request.header(key, value);
+            }
+            if let Err(e) = request.send().await {
+                tracing::warn!("failed to deliver health alert to {}: {}", webhook.url, e);
+            }
+        }
+    }
+}
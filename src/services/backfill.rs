@@ -0,0 +1,354 @@
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Arc;
+
+use serde::Serialize;
+
+use crate::errors::ApiError;
+use crate::routes::kraken::parse_kraken_ohlc_rows;
+use crate::services::candles::{AggregatedCandle, Candle, CandleStore, Resolution, Trade, TradeTick, build_candles};
+use crate::sources::kraken_data::KrakenDataSource;
+
+/// How many day-sized chunks a candles-target backfill fetches upstream concurrently.
+const MAX_CANDLE_CONCURRENCY: usize = 8;
+
+/// What a backfill job reconstructs: `Trades` pages Kraken's trades cursor to fill
+/// `kraken_trades`; `Candles` pulls native 1-minute OHLC directly from Kraken (falling back to
+/// rolling up whatever trades are already stored when the upstream fetch comes back empty).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Deserialize, utoipa::ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum BackfillTarget {
+    Trades,
+    Candles,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, utoipa::ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum BackfillStatus {
+    Running,
+    Completed,
+    Failed,
+}
+
+/// Progress for one in-flight or finished backfill job, polled via [`BackfillTracker::progress`].
+/// `last_timestamp` is always the source (event/trade or candle bucket) timestamp of the
+/// furthest-persisted row, never ingest time -- ingest time would corrupt bucket boundaries
+/// if a job is retried or resumed.
+#[derive(Debug, Clone, Serialize, utoipa::ToSchema)]
+pub struct BackfillProgress {
+    pub job_id: String,
+    pub pair: String,
+    pub target: BackfillTarget,
+    pub status: BackfillStatus,
+    pub rows_written: u64,
+    pub last_timestamp: Option<chrono::DateTime<chrono::Utc>>,
+    pub error: Option<String>,
+}
+
+/// Tracks in-flight and finished backfill jobs so `POST /kraken/backfill` can return
+/// immediately with a job id while the actual paging/persisting runs in a spawned task,
+/// mirroring [`crate::sources::helius_data::TransactionTracker`]'s submit-then-poll shape.
+pub struct BackfillTracker {
+    jobs: tokio::sync::Mutex<HashMap<String, BackfillProgress>>,
+    next_id: AtomicU64,
+}
+
+impl BackfillTracker {
+    pub fn new() -> Self {
+        Self { jobs: tokio::sync::Mutex::new(HashMap::new()), next_id: AtomicU64::new(1) }
+    }
+
+    /// Current progress for `job_id`, if it exists.
+    pub async fn progress(&self, job_id: &str) -> Option<BackfillProgress> {
+        self.jobs.lock().await.get(job_id).cloned()
+    }
+
+    async fn update(&self, job_id: &str, f: impl FnOnce(&mut BackfillProgress)) {
+        if let Some(progress) = self.jobs.lock().await.get_mut(job_id) {
+            f(progress);
+        }
+    }
+
+    /// Records one more persisted row, advancing `last_timestamp` only if `at` is newer than
+    /// what's already recorded (candle chunks persist out of order under concurrency).
+    async fn record_row(&self, job_id: &str, at: chrono::DateTime<chrono::Utc>) {
+        self.update(job_id, |p| {
+            p.rows_written += 1;
+            p.last_timestamp = Some(p.last_timestamp.map_or(at, |existing| existing.max(at)));
+        })
+        .await;
+    }
+
+    /// Starts a backfill job in the background and returns its id immediately; poll
+    /// [`Self::progress`] with it for rows written / last timestamp reached.
+    pub async fn start(
+        self: Arc<Self>,
+        candle_store: Arc<CandleStore>,
+        pair: String,
+        target: BackfillTarget,
+        since: chrono::DateTime<chrono::Utc>,
+        until: chrono::DateTime<chrono::Utc>,
+        batch_size: usize,
+    ) -> String {
+        let job_id = format!("{pair}-{}", self.next_id.fetch_add(1, Ordering::Relaxed));
+        self.jobs.lock().await.insert(
+            job_id.clone(),
+            BackfillProgress {
+                job_id: job_id.clone(),
+                pair: pair.clone(),
+                target,
+                status: BackfillStatus::Running,
+                rows_written: 0,
+                last_timestamp: None,
+                error: None,
+            },
+        );
+
+        let tracker = self.clone();
+        let job_id_for_task = job_id.clone();
+        tokio::spawn(async move {
+            let result = match target {
+                BackfillTarget::Trades => {
+                    tracker.run_trades_backfill(&job_id_for_task, &candle_store, &pair, since, until, batch_size).await
+                }
+                BackfillTarget::Candles => {
+                    tracker.run_candles_backfill(&job_id_for_task, &candle_store, &pair, since, until, batch_size).await
+                }
+            };
+
+            match result {
+                Ok(()) => tracker.update(&job_id_for_task, |p| p.status = BackfillStatus::Completed).await,
+                Err(e) => {
+                    tracker
+                        .update(&job_id_for_task, |p| {
+                            p.status = BackfillStatus::Failed;
+                            p.error = Some(e.to_string());
+                        })
+                        .await
+                }
+            }
+        });
+
+        job_id
+    }
+
+    /// Pages Kraken's trades cursor forward from `since` (Kraken's public trades API only
+    /// exposes a forward cursor via `last`, not a backward one) up to `until`, persisting up
+    /// to `batch_size` trades per page before checking in with the tracked progress.
+    async fn run_trades_backfill(
+        &self,
+        job_id: &str,
+        candle_store: &CandleStore,
+        pair: &str,
+        since: chrono::DateTime<chrono::Utc>,
+        until: chrono::DateTime<chrono::Utc>,
+        batch_size: usize,
+    ) -> Result<(), ApiError> {
+        let data_source = KrakenDataSource::new_async().await.map_err(|e| ApiError::Upstream(e.to_string()))?;
+        let mut cursor = since.timestamp_nanos_opt().unwrap_or(0) as u64;
+
+        loop {
+            let raw = data_source.get_recent_trades(pair, Some(cursor)).await.map_err(|e| ApiError::Upstream(e.to_string()))?;
+            let (trades, next_cursor) = parse_kraken_trades(pair, &raw);
+            if trades.is_empty() {
+                break;
+            }
+
+            for trade in trades.iter().take(batch_size) {
+                if trade.trade_time >= until {
+                    return Ok(());
+                }
+                candle_store.upsert_trade(trade).await?;
+                self.record_row(job_id, trade.trade_time).await;
+            }
+
+            match next_cursor {
+                Some(next) if next > cursor => cursor = next,
+                _ => break,
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Fetches native 1-minute OHLC for `[since, until)` in day-sized chunks, up to
+    /// `batch_size.min(MAX_CANDLE_CONCURRENCY)` chunks concurrently, upserting each candle
+    /// with its own bucket timestamp. Falls back to rolling up stored trades for any chunk
+    /// whose upstream fetch comes back empty.
+    async fn run_candles_backfill(
+        &self,
+        job_id: &str,
+        candle_store: &CandleStore,
+        pair: &str,
+        since: chrono::DateTime<chrono::Utc>,
+        until: chrono::DateTime<chrono::Utc>,
+        batch_size: usize,
+    ) -> Result<(), ApiError> {
+        let mut chunk_starts = Vec::new();
+        let mut cursor = since;
+        while cursor < until {
+            chunk_starts.push(cursor);
+            cursor += chrono::Duration::days(1);
+        }
+
+        let semaphore = Arc::new(tokio::sync::Semaphore::new(batch_size.clamp(1, MAX_CANDLE_CONCURRENCY)));
+        let mut handles = Vec::new();
+
+        for &chunk_start in &chunk_starts {
+            let chunk_end = (chunk_start + chrono::Duration::days(1)).min(until);
+            let semaphore = semaphore.clone();
+            let pair = pair.to_string();
+
+            handles.push(async move {
+                let _permit = semaphore.acquire().await.expect("semaphore not closed");
+                fetch_candle_chunk(&pair, chunk_start, chunk_end).await
+            });
+        }
+
+        let chunks: Vec<Vec<Candle>> = futures::future::join_all(handles).await;
+
+        let mut empty_chunks = Vec::new();
+        for (chunk_start, candles) in chunk_starts.into_iter().zip(chunks) {
+            if candles.is_empty() {
+                empty_chunks.push(chunk_start);
+                continue;
+            }
+            for candle in &candles {
+                candle_store.upsert_candle_1m(candle).await?;
+                self.record_row(job_id, candle.bucket_start).await;
+            }
+        }
+
+        // Any chunk with no native candles (e.g. Kraken has no OHLC that far back for this
+        // pair) is left to the stored trades, if a trades backfill has already populated them.
+        for chunk_start in empty_chunks {
+            let chunk_end = (chunk_start + chrono::Duration::days(1)).min(until);
+            let rolled_up = candle_store.candles_from_trades(pair, chunk_start, chunk_end).await?;
+            for candle in &rolled_up {
+                candle_store.upsert_candle_1m(candle).await?;
+                self.record_row(job_id, candle.bucket_start).await;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl Default for BackfillTracker {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// One day-chunk's worth of native 1-minute candles from Kraken, or empty on any upstream error
+/// (the caller falls back to trade rollups rather than failing the whole job over one chunk).
+async fn fetch_candle_chunk(pair: &str, since: chrono::DateTime<chrono::Utc>, until: chrono::DateTime<chrono::Utc>) -> Vec<Candle> {
+    let data_source = match KrakenDataSource::new_async().await {
+        Ok(source) => source,
+        Err(e) => {
+            tracing::warn!("candle backfill: failed to init kraken client for {pair}: {e}");
+            return Vec::new();
+        }
+    };
+
+    let raw = match data_source.get_ohlc(pair, Some(1), Some(since.timestamp() as u64)).await {
+        Ok(raw) => raw,
+        Err(e) => {
+            tracing::warn!("candle backfill: failed to fetch ohlc for {pair} from {since}: {e}");
+            return Vec::new();
+        }
+    };
+
+    parse_kraken_ohlc_rows(pair, &raw)
+        .into_iter()
+        .filter(|c| c.bucket_start < until)
+        .collect()
+}
+
+/// Parses Kraken's `[price, volume, time, side, order_type, misc, trade_id]` trade rows into
+/// [`Trade`]s, plus the `last` cursor to pass as `since` on the next page.
+fn parse_kraken_trades(pair: &str, raw: &serde_json::Value) -> (Vec<Trade>, Option<u64>) {
+    let trades = raw
+        .get("trades")
+        .and_then(|v| v.as_array())
+        .map(|rows| {
+            rows.iter()
+                .filter_map(|row| {
+                    let row = row.as_array()?;
+                    let parse_f64 = |v: &serde_json::Value| -> Option<f64> {
+                        v.as_f64().or_else(|| v.as_str().and_then(|s| s.parse().ok()))
+                    };
+                    let price = parse_f64(row.first()?)?;
+                    let volume = parse_f64(row.get(1)?)?;
+                    let time = parse_f64(row.get(2)?)?;
+                    let side = match row.get(3).and_then(|v| v.as_str()) {
+                        Some("b") => "buy",
+                        Some("s") => "sell",
+                        _ => "unknown",
+                    };
+                    Some(Trade {
+                        pair: pair.to_string(),
+                        trade_time: chrono::DateTime::from_timestamp(time as i64, ((time.fract()) * 1e9) as u32)?,
+                        price,
+                        volume,
+                        side: side.to_string(),
+                    })
+                })
+                .collect()
+        })
+        .unwrap_or_default();
+
+    // Kraken's `last` cursor is itself a nanosecond-since-epoch timestamp, passed back as the
+    // next page's `since` verbatim.
+    let next_cursor = raw.get("last").and_then(|v| v.as_str()).and_then(|s| s.parse::<u64>().ok());
+
+    (trades, next_cursor)
+}
+
+/// Pages `pair`'s trades forward from `from` to `to` the same way [`BackfillTracker::run_trades_backfill`]
+/// does, persisting each raw trade (idempotent on `kraken_trades`'s own `(pair, trade_time, price,
+/// volume)` key, so re-running over an overlapping range is a no-op for rows already stored), then
+/// hands the whole window to [`build_candles`] at `resolution`. There's no separate
+/// `(pair, resolution, start_time)` table to upsert into: like [`CandleStore::aggregated_candles`],
+/// a resolution's candles are a pure function of the underlying trades, so re-deriving them on
+/// every call is already idempotent without persisting one row per resolution.
+pub async fn backfill_candles(
+    candle_store: &CandleStore,
+    pair: &str,
+    resolution: Resolution,
+    from: chrono::DateTime<chrono::Utc>,
+    to: chrono::DateTime<chrono::Utc>,
+) -> Result<Vec<AggregatedCandle>, ApiError> {
+    let data_source = KrakenDataSource::new_async().await.map_err(|e| ApiError::Upstream(e.to_string()))?;
+    let mut cursor = from.timestamp_nanos_opt().unwrap_or(0) as u64;
+    let mut ticks = Vec::new();
+
+    loop {
+        let raw = data_source.get_recent_trades(pair, Some(cursor)).await.map_err(|e| ApiError::Upstream(e.to_string()))?;
+        let (trades, next_cursor) = parse_kraken_trades(pair, &raw);
+        if trades.is_empty() {
+            break;
+        }
+
+        let mut reached_end = false;
+        for trade in &trades {
+            if trade.trade_time >= to {
+                reached_end = true;
+                break;
+            }
+            candle_store.upsert_trade(trade).await?;
+            ticks.push(TradeTick { timestamp: trade.trade_time.timestamp(), price: trade.price, volume: trade.volume });
+        }
+
+        if reached_end {
+            break;
+        }
+
+        match next_cursor {
+            Some(next) if next > cursor => cursor = next,
+            _ => break,
+        }
+    }
+
+    Ok(build_candles(&ticks, resolution))
+}
@@ -0,0 +1,360 @@
+use std::sync::Arc;
+
+use serde::Serialize;
+use tokio_postgres::{Client, NoTls};
+
+use crate::errors::ApiError;
+
+/// Postgres connection settings for the OHLC candle store, built either from a single
+/// `CANDLES_DATABASE_URL` or from the individual `CANDLES_PG_*` parts, mirroring
+/// [`crate::services::history::HistoryDbConfig`]. `None` in [`crate::config::Config`] means
+/// candle persistence is disabled.
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct CandleDbConfig {
+    pub connection_string: String,
+}
+
+impl CandleDbConfig {
+    pub fn from_env() -> Option<Self> {
+        if let Ok(url) = std::env::var("CANDLES_DATABASE_URL") {
+            return Some(Self { connection_string: url });
+        }
+
+        let host = std::env::var("CANDLES_PG_HOST").ok()?;
+        let port = std::env::var("CANDLES_PG_PORT")
+            .ok()
+            .and_then(|v| v.parse::<u16>().ok())
+            .unwrap_or(5432);
+        let user = std::env::var("CANDLES_PG_USER").unwrap_or_else(|_| "postgres".to_string());
+        let password = std::env::var("CANDLES_PG_PASSWORD").unwrap_or_default();
+        let dbname = std::env::var("CANDLES_PG_DBNAME").unwrap_or_else(|_| "trading_api".to_string());
+        let sslmode = std::env::var("CANDLES_PG_SSLMODE").unwrap_or_else(|_| "prefer".to_string());
+
+        Some(Self {
+            connection_string: format!(
+                "host={host} port={port} user={user} password={password} dbname={dbname} sslmode={sslmode}"
+            ),
+        })
+    }
+}
+
+/// One OHLCV candle, either a raw stored 1-minute row or a resolution rolled up from them.
+#[derive(Debug, Clone, Serialize, utoipa::ToSchema)]
+pub struct Candle {
+    pub pair: String,
+    pub bucket_start: chrono::DateTime<chrono::Utc>,
+    pub open: f64,
+    pub high: f64,
+    pub low: f64,
+    pub close: f64,
+    pub volume: f64,
+}
+
+/// One raw trade, as persisted to `kraken_trades` by a trades-target backfill job.
+#[derive(Debug, Clone, Serialize, utoipa::ToSchema)]
+pub struct Trade {
+    pub pair: String,
+    pub trade_time: chrono::DateTime<chrono::Utc>,
+    pub price: f64,
+    pub volume: f64,
+    pub side: String,
+}
+
+/// Candle bucket width for in-memory aggregation via [`build_candles`] (see `routes::kraken`'s
+/// `resolution_seconds`, a thin wrapper around [`Resolution::parse`]). `routes::hyperliquid` and
+/// `routes::pumpfun` still parse their own `"1m"`/`"5m"`/... strings.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, serde::Deserialize, utoipa::ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum Resolution {
+    OneMinute,
+    FiveMinutes,
+    FifteenMinutes,
+    OneHour,
+    FourHours,
+    OneDay,
+}
+
+impl Resolution {
+    pub fn as_secs(self) -> i64 {
+        match self {
+            Resolution::OneMinute => 60,
+            Resolution::FiveMinutes => 5 * 60,
+            Resolution::FifteenMinutes => 15 * 60,
+            Resolution::OneHour => 60 * 60,
+            Resolution::FourHours => 4 * 60 * 60,
+            Resolution::OneDay => 24 * 60 * 60,
+        }
+    }
+
+    /// Parses the `1m`/`5m`/`15m`/`1h`/`4h`/`1d` labels already used across
+    /// `routes::kraken`/`routes::hyperliquid`/`routes::coinbase`'s `interval`/`resolution` query
+    /// params.
+    pub fn parse(label: &str) -> Option<Self> {
+        match label {
+            "1m" => Some(Resolution::OneMinute),
+            "5m" => Some(Resolution::FiveMinutes),
+            "15m" => Some(Resolution::FifteenMinutes),
+            "1h" => Some(Resolution::OneHour),
+            "4h" => Some(Resolution::FourHours),
+            "1d" => Some(Resolution::OneDay),
+            _ => None,
+        }
+    }
+}
+
+/// One raw trade tick -- venue-agnostic, unlike [`Trade`] (which is specifically the
+/// `kraken_trades` persistence row) -- for building candles in-process via [`build_candles`] from
+/// any source (Kraken's own trades feed, or trades synthesized from a Yahoo quote stream).
+#[derive(Debug, Clone, Copy)]
+pub struct TradeTick {
+    pub timestamp: i64,
+    pub price: f64,
+    pub volume: f64,
+}
+
+/// One candle built by [`build_candles`]. Distinct from [`Candle`] (the persisted, per-pair
+/// Postgres row): this carries an explicit bucket `end_time` and `complete` flag for callers
+/// aggregating trades on the fly, neither of which the stored-candle shape needs.
+#[derive(Debug, Clone, PartialEq, Serialize, utoipa::ToSchema)]
+pub struct AggregatedCandle {
+    pub start_time: i64,
+    pub end_time: i64,
+    pub open: f64,
+    pub high: f64,
+    pub low: f64,
+    pub close: f64,
+    pub volume: f64,
+    /// `false` for the most recent bucket when `end_time` is still in the future, i.e. the
+    /// bucket is still accumulating trades and isn't a finished candle yet.
+    pub complete: bool,
+}
+
+/// Buckets `trades` into `resolution`-wide candles: each trade's bucket start is
+/// `timestamp - (timestamp % resolution_secs)`, `open`/`close` are the first/last trade in time
+/// order within the bucket, `high`/`low` the extremes, and `volume` the sum. Trades don't need to
+/// arrive in time order -- each bucket's rows are sorted before `open`/`close` are read off.
+pub fn build_candles(trades: &[TradeTick], resolution: Resolution) -> Vec<AggregatedCandle> {
+    use std::collections::BTreeMap;
+
+    let resolution_secs = resolution.as_secs();
+    let mut buckets: BTreeMap<i64, Vec<&TradeTick>> = BTreeMap::new();
+    for trade in trades {
+        let bucket_start = trade.timestamp - trade.timestamp.rem_euclid(resolution_secs);
+        buckets.entry(bucket_start).or_default().push(trade);
+    }
+
+    let now = chrono::Utc::now().timestamp();
+
+    buckets
+        .into_iter()
+        .map(|(start_time, mut rows)| {
+            rows.sort_by_key(|t| t.timestamp);
+            let end_time = start_time + resolution_secs;
+            AggregatedCandle {
+                start_time,
+                end_time,
+                open: rows.first().map(|t| t.price).unwrap_or(0.0),
+                close: rows.last().map(|t| t.price).unwrap_or(0.0),
+                high: rows.iter().map(|t| t.price).fold(f64::MIN, f64::max),
+                low: rows.iter().map(|t| t.price).fold(f64::MAX, f64::min),
+                volume: rows.iter().map(|t| t.volume).sum(),
+                complete: end_time <= now,
+            }
+        })
+        .collect()
+}
+
+/// Persists raw 1-minute OHLC candles to Postgres and serves any coarser resolution by
+/// time-bucketing them on read, so the Kraken/CoinGecko OHLC data the crate fetches on
+/// demand doesn't have to be re-fetched from upstream for every resolution a caller wants.
+/// Every method is a no-op (`Ok`/empty) when no database is configured, following
+/// [`crate::services::history::HistoryStore`]'s enable-purely-via-env-var convention.
+pub struct CandleStore {
+    client: Option<Arc<Client>>,
+}
+
+impl CandleStore {
+    /// A disabled store that no-ops every call; used when candle persistence isn't configured.
+    pub fn disabled() -> Self {
+        Self { client: None }
+    }
+
+    pub fn is_enabled(&self) -> bool {
+        self.client.is_some()
+    }
+
+    /// Connects to Postgres and ensures the `ohlc_candles_1m` table exists. Falls back to
+    /// [`Self::disabled`] (with a logged warning) on any connection or schema error, so a
+    /// misconfigured database never prevents the rest of the service from starting.
+    pub async fn connect(config: Option<&CandleDbConfig>) -> Self {
+        let Some(config) = config else { return Self::disabled() };
+
+        let (client, connection) = match tokio_postgres::connect(&config.connection_string, NoTls).await {
+            Ok(pair) => pair,
+            Err(e) => {
+                tracing::warn!("candle store: failed to connect to postgres: {e}");
+                return Self::disabled();
+            }
+        };
+
+        tokio::spawn(async move {
+            if let Err(e) = connection.await {
+                tracing::error!("candle store: connection closed with error: {e}");
+            }
+        });
+
+        if let Err(e) = client
+            .batch_execute(
+                "CREATE TABLE IF NOT EXISTS ohlc_candles_1m (
+                    pair TEXT NOT NULL,
+                    bucket_start TIMESTAMPTZ NOT NULL,
+                    open DOUBLE PRECISION NOT NULL,
+                    high DOUBLE PRECISION NOT NULL,
+                    low DOUBLE PRECISION NOT NULL,
+                    close DOUBLE PRECISION NOT NULL,
+                    volume DOUBLE PRECISION NOT NULL,
+                    PRIMARY KEY (pair, bucket_start)
+                );
+                CREATE TABLE IF NOT EXISTS kraken_trades (
+                    pair TEXT NOT NULL,
+                    trade_time TIMESTAMPTZ NOT NULL,
+                    price DOUBLE PRECISION NOT NULL,
+                    volume DOUBLE PRECISION NOT NULL,
+                    side TEXT NOT NULL,
+                    PRIMARY KEY (pair, trade_time, price, volume)
+                )",
+            )
+            .await
+        {
+            tracing::warn!("candle store: failed to initialize schema: {e}");
+            return Self::disabled();
+        }
+
+        Self { client: Some(Arc::new(client)) }
+    }
+
+    /// Upserts one raw 1-minute candle. Idempotent: replaying the same `(pair, bucket_start)`
+    /// overwrites the previous row instead of erroring, so repeated backfills are safe.
+    pub async fn upsert_candle_1m(&self, candle: &Candle) -> Result<(), ApiError> {
+        let Some(client) = &self.client else { return Ok(()) };
+        client
+            .execute(
+                "INSERT INTO ohlc_candles_1m (pair, bucket_start, open, high, low, close, volume)
+                 VALUES ($1, $2, $3, $4, $5, $6, $7)
+                 ON CONFLICT (pair, bucket_start)
+                 DO UPDATE SET open = EXCLUDED.open, high = EXCLUDED.high, low = EXCLUDED.low,
+                     close = EXCLUDED.close, volume = EXCLUDED.volume",
+                &[&candle.pair, &candle.bucket_start, &candle.open, &candle.high, &candle.low, &candle.close, &candle.volume],
+            )
+            .await
+            .map_err(|e| ApiError::InternalError(format!("failed to persist candle: {e}")))?;
+        Ok(())
+    }
+
+    /// Returns `pair`'s candles at `resolution_seconds`, time-bucketed from the stored
+    /// 1-minute rows: `open` is the first row in the bucket, `close` the last, `high`/`low`
+    /// the bucket extremes, and `volume` the bucket sum. Empty (not an error) when storage
+    /// isn't configured or no 1-minute rows fall in the requested bucket(s).
+    pub async fn aggregated_candles(
+        &self,
+        pair: &str,
+        resolution_seconds: i64,
+        since: Option<chrono::DateTime<chrono::Utc>>,
+        limit: i64,
+    ) -> Result<Vec<Candle>, ApiError> {
+        let Some(client) = &self.client else { return Ok(Vec::new()) };
+        let since = since.unwrap_or_else(|| chrono::Utc::now() - chrono::Duration::days(30));
+
+        let rows = client
+            .query(
+                "SELECT
+                    to_timestamp(floor(extract(epoch FROM bucket_start) / $3) * $3) AS bucket,
+                    (array_agg(open ORDER BY bucket_start ASC))[1] AS open,
+                    (array_agg(close ORDER BY bucket_start DESC))[1] AS close,
+                    max(high) AS high,
+                    min(low) AS low,
+                    sum(volume) AS volume
+                 FROM ohlc_candles_1m
+                 WHERE pair = $1 AND bucket_start >= $2
+                 GROUP BY bucket
+                 ORDER BY bucket ASC
+                 LIMIT $4",
+                &[&pair, &since, &(resolution_seconds as f64), &limit],
+            )
+            .await
+            .map_err(|e| ApiError::InternalError(format!("failed to query candles: {e}")))?;
+
+        Ok(rows
+            .into_iter()
+            .map(|row| Candle {
+                pair: pair.to_string(),
+                bucket_start: row.get("bucket"),
+                open: row.get("open"),
+                high: row.get("high"),
+                low: row.get("low"),
+                close: row.get("close"),
+                volume: row.get("volume"),
+            })
+            .collect())
+    }
+
+    /// Upserts one raw trade. Idempotent on `(pair, trade_time, price, volume)`, the closest
+    /// thing to a natural key Kraken's public trades feed exposes, so replaying a page of
+    /// trades during a retried backfill doesn't double-count volume.
+    pub async fn upsert_trade(&self, trade: &Trade) -> Result<(), ApiError> {
+        let Some(client) = &self.client else { return Ok(()) };
+        client
+            .execute(
+                "INSERT INTO kraken_trades (pair, trade_time, price, volume, side)
+                 VALUES ($1, $2, $3, $4, $5)
+                 ON CONFLICT (pair, trade_time, price, volume) DO NOTHING",
+                &[&trade.pair, &trade.trade_time, &trade.price, &trade.volume, &trade.side],
+            )
+            .await
+            .map_err(|e| ApiError::InternalError(format!("failed to persist trade: {e}")))?;
+        Ok(())
+    }
+
+    /// Rolls stored trades for `pair` between `since` and `until` up into 1-minute candles,
+    /// for use when a candles-target backfill has nothing to fetch natively. Empty when
+    /// storage isn't configured or no trades fall in range.
+    pub async fn candles_from_trades(
+        &self,
+        pair: &str,
+        since: chrono::DateTime<chrono::Utc>,
+        until: chrono::DateTime<chrono::Utc>,
+    ) -> Result<Vec<Candle>, ApiError> {
+        let Some(client) = &self.client else { return Ok(Vec::new()) };
+
+        let rows = client
+            .query(
+                "SELECT
+                    date_trunc('minute', trade_time) AS bucket,
+                    (array_agg(price ORDER BY trade_time ASC))[1] AS open,
+                    (array_agg(price ORDER BY trade_time DESC))[1] AS close,
+                    max(price) AS high,
+                    min(price) AS low,
+                    sum(volume) AS volume
+                 FROM kraken_trades
+                 WHERE pair = $1 AND trade_time >= $2 AND trade_time < $3
+                 GROUP BY bucket
+                 ORDER BY bucket ASC",
+                &[&pair, &since, &until],
+            )
+            .await
+            .map_err(|e| ApiError::InternalError(format!("failed to roll up trades into candles: {e}")))?;
+
+        Ok(rows
+            .into_iter()
+            .map(|row| Candle {
+                pair: pair.to_string(),
+                bucket_start: row.get("bucket"),
+                open: row.get("open"),
+                high: row.get("high"),
+                low: row.get("low"),
+                close: row.get("close"),
+                volume: row.get("volume"),
+            })
+            .collect())
+    }
+}
@@ -0,0 +1,287 @@
+use std::collections::{HashMap, VecDeque};
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Arc;
+
+use serde::Serialize;
+use utoipa::ToSchema;
+
+use crate::config::HealthMonitorConfig;
+
+/// Overall health derived from a service's consecutive-failure count and EWMA response
+/// time, mirroring the tri-state the old synchronous checks returned ("healthy" /
+/// "degraded" / "unhealthy") so `/status` and `/ready` don't need to change shape.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, ToSchema)]
+#[serde(rename_all = "lowercase")]
+pub enum HealthStatus {
+    Healthy,
+    Degraded,
+    Unhealthy,
+}
+
+impl HealthStatus {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            HealthStatus::Healthy => "healthy",
+            HealthStatus::Degraded => "degraded",
+            HealthStatus::Unhealthy => "unhealthy",
+        }
+    }
+}
+
+/// Cumulative health for one probed dependency. `error_count` only ever grows (it's a
+/// lifetime counter, not a rolling window) while `consecutive_failures` resets to 0 on
+/// every success -- `status` is derived from the latter plus the EWMA, not the former.
+#[derive(Debug, Clone, Serialize, ToSchema)]
+pub struct ServiceHealth {
+    pub status: HealthStatus,
+    pub error_count: u64,
+    pub consecutive_failures: u32,
+    pub last_success: Option<chrono::DateTime<chrono::Utc>>,
+    pub last_failure: Option<chrono::DateTime<chrono::Utc>>,
+    pub ewma_response_time_ms: f64,
+}
+
+impl ServiceHealth {
+    fn new() -> Self {
+        Self {
+            status: HealthStatus::Healthy,
+            error_count: 0,
+            consecutive_failures: 0,
+            last_success: None,
+            last_failure: None,
+            ewma_response_time_ms: 0.0,
+        }
+    }
+
+    fn record(&mut self, outcome: Result<(), ()>, response_time_ms: f64, config: &HealthMonitorConfig) {
+        let now = chrono::Utc::now();
+        self.ewma_response_time_ms = if self.ewma_response_time_ms == 0.0 {
+            response_time_ms
+        } else {
+            config.ewma_alpha * response_time_ms + (1.0 - config.ewma_alpha) * self.ewma_response_time_ms
+        };
+
+        match outcome {
+            Ok(()) => {
+                self.consecutive_failures = 0;
+                self.last_success = Some(now);
+            }
+            Err(()) => {
+                self.error_count += 1;
+                self.consecutive_failures += 1;
+                self.last_failure = Some(now);
+            }
+        }
+
+        self.status = if self.consecutive_failures >= config.max_consecutive_failures {
+            HealthStatus::Unhealthy
+        } else if self.ewma_response_time_ms > config.response_time_threshold_ms {
+            HealthStatus::Degraded
+        } else {
+            HealthStatus::Healthy
+        };
+    }
+}
+
+/// One dependency a [`HealthRegistry`] probes on an interval; implementations wrap
+/// whatever upstream call previously ran synchronously inline in `routes::system`.
+#[async_trait::async_trait]
+pub trait HealthProbe: Send + Sync {
+    fn name(&self) -> &'static str;
+
+    async fn probe(&self) -> Result<(), String>;
+}
+
+/// Cached, background-refreshed health for every probed dependency, read by the
+/// `/status` and `/ready` handlers instead of each issuing its own
+/// live upstream call on every request.
+pub struct HealthRegistry {
+    services: tokio::sync::RwLock<HashMap<String, ServiceHealth>>,
+}
+
+impl HealthRegistry {
+    pub fn new() -> Self {
+        Self { services: tokio::sync::RwLock::new(HashMap::new()) }
+    }
+
+    /// Current health for `name`, if it's been probed at least once.
+    pub async fn get(&self, name: &str) -> Option<ServiceHealth> {
+        self.services.read().await.get(name).cloned()
+    }
+
+    /// A snapshot of every probed service's current health, keyed by name.
+    pub async fn snapshot(&self) -> HashMap<String, ServiceHealth> {
+        self.services.read().await.clone()
+    }
+
+    /// Records one probe outcome for `name`, returning its status just before this probe
+    /// (`None` if `name` hasn't been probed before) alongside the freshly updated health, so
+    /// callers can detect a transition without a separate lookup.
+    async fn record(
+        &self,
+        name: &str,
+        outcome: Result<(), ()>,
+        response_time_ms: f64,
+        config: &HealthMonitorConfig,
+    ) -> (Option<HealthStatus>, ServiceHealth) {
+        let mut services = self.services.write().await;
+        let is_new = !services.contains_key(name);
+        let entry = services.entry(name.to_string()).or_insert_with(ServiceHealth::new);
+        let previous_status = if is_new { None } else { Some(entry.status) };
+        entry.record(outcome, response_time_ms, config);
+        (previous_status, entry.clone())
+    }
+}
+
+impl Default for HealthRegistry {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// One recorded status transition, backing the Atom incident feed at `GET /status/feed.xml`.
+#[derive(Debug, Clone, Serialize, ToSchema)]
+pub struct Incident {
+    pub id: u64,
+    pub service: String,
+    pub from_status: Option<HealthStatus>,
+    pub to_status: HealthStatus,
+    pub error_count: u64,
+    pub response_time_ms: f64,
+    pub timestamp: chrono::DateTime<chrono::Utc>,
+}
+
+/// Bounded ring buffer of the most recent service-health transitions. Oldest entries are
+/// dropped once `capacity` is reached, so long-running processes don't grow this unbounded.
+pub struct IncidentLog {
+    capacity: usize,
+    entries: tokio::sync::RwLock<VecDeque<Incident>>,
+    next_id: AtomicU64,
+}
+
+impl IncidentLog {
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            capacity,
+            entries: tokio::sync::RwLock::new(VecDeque::with_capacity(capacity)),
+            next_id: AtomicU64::new(1),
+        }
+    }
+
+    async fn push(&self, service: &str, from_status: Option<HealthStatus>, health: &ServiceHealth) {
+        let incident = Incident {
+            id: self.next_id.fetch_add(1, Ordering::Relaxed),
+            service: service.to_string(),
+            from_status,
+            to_status: health.status,
+            error_count: health.error_count,
+            response_time_ms: health.ewma_response_time_ms,
+            timestamp: chrono::Utc::now(),
+        };
+
+        let mut entries = self.entries.write().await;
+        if entries.len() >= self.capacity {
+            entries.pop_front();
+        }
+        entries.push_back(incident);
+    }
+
+    /// The most recent incidents, newest first, for the Atom feed.
+    pub async fn recent(&self) -> Vec<Incident> {
+        self.entries.read().await.iter().rev().cloned().collect()
+    }
+}
+
+impl Default for IncidentLog {
+    fn default() -> Self {
+        Self::new(200)
+    }
+}
+
+/// Probes every registered [`HealthProbe`] on `config.probe_interval_ms`, recording each
+/// outcome into `registry`, appending a transition to `incidents` whenever a service's status
+/// changes, and, if `alerts` is set, notifying it of the transition so it can fire webhooks
+/// per [`crate::services::alerting::AlertDispatcher`]. Runs forever; spawn it once at startup
+/// alongside the other background tasks in `main.rs`.
+pub async fn run_health_monitor(
+    registry: Arc<HealthRegistry>,
+    probes: Vec<Arc<dyn HealthProbe>>,
+    config: HealthMonitorConfig,
+    incidents: Arc<IncidentLog>,
+    alerts: Option<Arc<crate::services::alerting::AlertDispatcher>>,
+) {
+    let mut ticker = tokio::time::interval(tokio::time::Duration::from_millis(config.probe_interval_ms));
+    loop {
+        ticker.tick().await;
+        for probe in &probes {
+            let start = std::time::Instant::now();
+            let outcome = probe.probe().await;
+            let response_time_ms = start.elapsed().as_secs_f64() * 1000.0;
+
+            let (previous_status, health) = match &outcome {
+                Ok(()) => registry.record(probe.name(), Ok(()), response_time_ms, &config).await,
+                Err(e) => {
+                    tracing::warn!("health probe failed for {}: {}", probe.name(), e);
+                    registry.record(probe.name(), Err(()), response_time_ms, &config).await
+                }
+            };
+
+            if previous_status != Some(health.status) {
+                incidents.push(probe.name(), previous_status, &health).await;
+            }
+
+            if let Some(dispatcher) = &alerts {
+                dispatcher.maybe_alert(probe.name(), previous_status, &health).await;
+            }
+        }
+    }
+}
+
+/// Probes Alpaca's clock endpoint, the same check `routes::system::check_alpaca_service`
+/// used to make synchronously on every `/status` request.
+pub struct AlpacaHealthProbe {
+    pub http: reqwest::Client,
+    pub api_key: String,
+    pub api_secret: String,
+}
+
+#[async_trait::async_trait]
+impl HealthProbe for AlpacaHealthProbe {
+    fn name(&self) -> &'static str {
+        "alpaca"
+    }
+
+    async fn probe(&self) -> Result<(), String> {
+        let resp = self
+            .http
+            .get("https://api.alpaca.markets/v2/clock")
+            .header("APCA-API-KEY-ID", &self.api_key)
+            .header("APCA-API-SECRET-KEY", &self.api_secret)
+            .send()
+            .await
+            .map_err(|e| e.to_string())?;
+
+        if resp.status().is_success() {
+            Ok(())
+        } else {
+            Err(format!("alpaca clock returned {}", resp.status()))
+        }
+    }
+}
+
+/// Probes Yahoo Finance by searching a well-known ticker, the same check
+/// `routes::system::check_yahoo_service` used to make synchronously on every request.
+pub struct YahooHealthProbe {
+    pub yahoo: Arc<yahoo_finance_api::YahooConnector>,
+}
+
+#[async_trait::async_trait]
+impl HealthProbe for YahooHealthProbe {
+    fn name(&self) -> &'static str {
+        "yahoo_finance"
+    }
+
+    async fn probe(&self) -> Result<(), String> {
+        self.yahoo.search_ticker("AAPL").await.map(|_| ()).map_err(|e| e.to_string())
+    }
+}
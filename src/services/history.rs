@@ -0,0 +1,229 @@
+use std::sync::Arc;
+
+use serde::Serialize;
+use serde_json::Value;
+use tokio_postgres::types::Json;
+use tokio_postgres::{Client, NoTls};
+
+use crate::errors::ApiError;
+
+/// Postgres connection settings for the screener-history store, built either from a single
+/// `HISTORY_DATABASE_URL` or from the individual `HISTORY_PG_*` parts. `None` in
+/// [`crate::config::Config`] means history persistence is disabled.
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct HistoryDbConfig {
+    pub connection_string: String,
+}
+
+impl HistoryDbConfig {
+    pub fn from_env() -> Option<Self> {
+        if let Ok(url) = std::env::var("HISTORY_DATABASE_URL") {
+            return Some(Self { connection_string: url });
+        }
+
+        let host = std::env::var("HISTORY_PG_HOST").ok()?;
+        let port = std::env::var("HISTORY_PG_PORT")
+            .ok()
+            .and_then(|v| v.parse::<u16>().ok())
+            .unwrap_or(5432);
+        let user = std::env::var("HISTORY_PG_USER").unwrap_or_else(|_| "postgres".to_string());
+        let password = std::env::var("HISTORY_PG_PASSWORD").unwrap_or_default();
+        let dbname = std::env::var("HISTORY_PG_DBNAME").unwrap_or_else(|_| "trading_api".to_string());
+        let sslmode = std::env::var("HISTORY_PG_SSLMODE").unwrap_or_else(|_| "prefer".to_string());
+
+        Some(Self {
+            connection_string: format!(
+                "host={host} port={port} user={user} password={password} dbname={dbname} sslmode={sslmode}"
+            ),
+        })
+    }
+}
+
+/// One stored screener/trending snapshot row, as returned by [`HistoryStore::history_for_symbol`].
+#[derive(Debug, Clone, Serialize)]
+pub struct HistoryRow {
+    pub symbol: String,
+    pub as_of: chrono::DateTime<chrono::Utc>,
+    pub signal: String,
+    pub composite_score: Option<f64>,
+    pub metrics: Value,
+}
+
+/// Persists screener/trending snapshots and their computed metrics to Postgres, keyed by
+/// `(symbol, as_of, signal)`, following the live-ingest-plus-backfill split: route handlers
+/// and the background scheduler call [`record_snapshot`](Self::record_snapshot) as data is
+/// computed, while [`backfill_range`] re-derives and stores older points to fill gaps.
+/// Every method is a no-op (`Ok(())`/empty) when no database is configured, so the feature
+/// can be enabled purely by setting `HISTORY_DATABASE_URL`/`HISTORY_PG_*` env vars.
+pub struct HistoryStore {
+    client: Option<Arc<Client>>,
+}
+
+impl HistoryStore {
+    /// A disabled store that no-ops every call; used when history persistence isn't configured.
+    pub fn disabled() -> Self {
+        Self { client: None }
+    }
+
+    pub fn is_enabled(&self) -> bool {
+        self.client.is_some()
+    }
+
+    /// Connects to Postgres and ensures the `screener_snapshots` table exists. Falls back to
+    /// [`Self::disabled`] (with a logged warning) on any connection or schema error, so a
+    /// misconfigured database never prevents the rest of the service from starting.
+    pub async fn connect(config: Option<&HistoryDbConfig>) -> Self {
+        let Some(config) = config else { return Self::disabled() };
+
+        let (client, connection) = match tokio_postgres::connect(&config.connection_string, NoTls).await {
+            Ok(pair) => pair,
+            Err(e) => {
+                tracing::warn!("history store: failed to connect to postgres: {e}");
+                return Self::disabled();
+            }
+        };
+
+        tokio::spawn(async move {
+            if let Err(e) = connection.await {
+                tracing::error!("history store: connection closed with error: {e}");
+            }
+        });
+
+        if let Err(e) = client
+            .batch_execute(
+                "CREATE TABLE IF NOT EXISTS screener_snapshots (
+                    symbol TEXT NOT NULL,
+                    as_of TIMESTAMPTZ NOT NULL,
+                    signal TEXT NOT NULL,
+                    composite_score DOUBLE PRECISION,
+                    metrics JSONB NOT NULL,
+                    PRIMARY KEY (symbol, as_of, signal)
+                )",
+            )
+            .await
+        {
+            tracing::warn!("history store: failed to initialize schema: {e}");
+            return Self::disabled();
+        }
+
+        Self { client: Some(Arc::new(client)) }
+    }
+
+    /// Persists one symbol's computed metrics for `signal` as of `as_of`. No-op when storage
+    /// isn't configured.
+    pub async fn record_snapshot(
+        &self,
+        symbol: &str,
+        signal: &str,
+        as_of: chrono::DateTime<chrono::Utc>,
+        composite_score: Option<f64>,
+        metrics: &Value,
+    ) -> Result<(), ApiError> {
+        let Some(client) = &self.client else { return Ok(()) };
+        client
+            .execute(
+                "INSERT INTO screener_snapshots (symbol, as_of, signal, composite_score, metrics)
+                 VALUES ($1, $2, $3, $4, $5)
+                 ON CONFLICT (symbol, as_of, signal)
+                 DO UPDATE SET composite_score = EXCLUDED.composite_score, metrics = EXCLUDED.metrics",
+                &[&symbol, &as_of, &signal, &composite_score, &Json(metrics)],
+            )
+            .await
+            .map_err(|e| ApiError::InternalError(format!("failed to persist screener snapshot: {e}")))?;
+        Ok(())
+    }
+
+    /// Returns `symbol`'s stored composite-score history (optionally filtered by `signal`),
+    /// most recent first, capped at `limit` rows. Empty when storage isn't configured.
+    pub async fn history_for_symbol(
+        &self,
+        symbol: &str,
+        signal: Option<&str>,
+        limit: i64,
+    ) -> Result<Vec<HistoryRow>, ApiError> {
+        let Some(client) = &self.client else { return Ok(Vec::new()) };
+
+        let rows = match signal {
+            Some(signal) => client
+                .query(
+                    "SELECT symbol, as_of, signal, composite_score, metrics FROM screener_snapshots
+                     WHERE symbol = $1 AND signal = $2 ORDER BY as_of DESC LIMIT $3",
+                    &[&symbol, &signal, &limit],
+                )
+                .await,
+            None => client
+                .query(
+                    "SELECT symbol, as_of, signal, composite_score, metrics FROM screener_snapshots
+                     WHERE symbol = $1 ORDER BY as_of DESC LIMIT $2",
+                    &[&symbol, &limit],
+                )
+                .await,
+        }
+        .map_err(|e| ApiError::InternalError(format!("failed to query screener history: {e}")))?;
+
+        Ok(rows
+            .into_iter()
+            .map(|row| HistoryRow {
+                symbol: row.get("symbol"),
+                as_of: row.get("as_of"),
+                signal: row.get("signal"),
+                composite_score: row.get("composite_score"),
+                metrics: row.get::<_, Json<Value>>("metrics").0,
+            })
+            .collect())
+    }
+}
+
+/// Re-runs `fetch_finviz_symbols` plus Yahoo enrichment over `days_back` historical windows of
+/// each candidate's price series, storing a snapshot per day to backfill gaps left by the
+/// live ingest path. No-op when `store` isn't configured.
+pub async fn backfill_range(
+    store: &HistoryStore,
+    signal: &str,
+    order: &str,
+    screener: &str,
+    symbols_limit: usize,
+    range_label: &str,
+    days_back: usize,
+) {
+    if !store.is_enabled() {
+        return;
+    }
+
+    let symbols = match crate::sources::finviz_data::fetch_finviz_symbols(signal, order, screener, symbols_limit).await {
+        Ok(symbols) => symbols,
+        Err(e) => {
+            tracing::warn!("history backfill: failed to fetch finviz symbols: {e}");
+            return;
+        }
+    };
+
+    for symbol in symbols {
+        let prices = match crate::services::yahoo::fetch_prices_for_symbol_default(&symbol, range_label).await {
+            Ok(prices) => prices,
+            Err(e) => {
+                tracing::warn!("history backfill: failed to fetch prices for {symbol}: {e}");
+                continue;
+            }
+        };
+
+        let window = days_back.min(prices.len());
+        for offset in 0..window {
+            let cutoff = prices.len() - offset;
+            if cutoff < 2 {
+                break;
+            }
+            let returns = crate::helpers::metrics::compute_returns_from_prices(&prices[..cutoff]);
+            let metrics = crate::helpers::metrics::compute_metrics_from_returns(&returns, 0.0, 0.0, 252, None);
+            let metrics_value = serde_json::to_value(&metrics).unwrap_or_else(|_| serde_json::json!({}));
+            let as_of = chrono::Utc::now() - chrono::Duration::days(offset as i64);
+
+            if let Err(e) = store
+                .record_snapshot(&symbol, signal, as_of, Some(metrics.composite_score), &metrics_value)
+                .await
+            {
+                tracing::warn!("history backfill: failed to persist {symbol} at offset {offset}: {e}");
+            }
+        }
+    }
+}
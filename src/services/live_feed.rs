@@ -0,0 +1,117 @@
+use std::collections::HashMap;
+use std::sync::Arc;
+use std::time::Duration;
+
+use serde::Serialize;
+use serde_json::Value;
+use tokio::sync::{broadcast, Mutex};
+
+use crate::sources::kraken_data::KrakenDataSource;
+
+/// One message on a [`LiveFeedHub`] subscription: either a changed snapshot for the topic, or a
+/// marker telling a lagging subscriber how many updates it missed (see [`LiveFeedHub::subscribe`]).
+#[derive(Debug, Clone, Serialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum FeedEvent {
+    Update { topic: String, data: Value },
+    Lagged { topic: String, missed: u64 },
+}
+
+/// Shares one upstream poll loop across every subscriber of the same topic (`"news"`,
+/// `"trending"`, or `"pair:{PAIR}"`), diffing successive polls so subscribers only receive
+/// changed snapshots instead of re-fetching on every request -- the same incremental-tick
+/// approach [`crate::sources::finviz_data::ScreenerStreamHub`] uses for the screener. Unlike that
+/// hub, a subscriber that falls behind the channel's bounded capacity gets an explicit
+/// [`FeedEvent::Lagged`] marker instead of silently skipping ahead, so a dashboard can show
+/// "you missed N updates" instead of quietly jumping.
+pub struct LiveFeedHub {
+    subscriptions: Mutex<HashMap<String, broadcast::Sender<FeedEvent>>>,
+}
+
+impl LiveFeedHub {
+    pub fn new() -> Self {
+        Self { subscriptions: Mutex::new(HashMap::new()) }
+    }
+
+    /// Subscribe to diffed updates for `topic` (`"news"`, `"trending"`, or `"pair:{PAIR}"`).
+    /// Spawns the upstream poll loop on the first subscriber for that topic and reuses it for
+    /// every subscriber after that, tearing it down once the last one disconnects.
+    pub fn subscribe(self: Arc<Self>, topic: String, poll_interval: Duration) -> impl futures::Stream<Item = FeedEvent> {
+        async_stream::stream! {
+            let mut rx = {
+                let mut subs = self.subscriptions.lock().await;
+                match subs.get(&topic) {
+                    Some(tx) => tx.subscribe(),
+                    None => {
+                        let (tx, rx) = broadcast::channel(64);
+                        subs.insert(topic.clone(), tx.clone());
+                        tokio::spawn(self.clone().poll_and_diff(topic.clone(), poll_interval, tx));
+                        rx
+                    }
+                }
+            };
+
+            loop {
+                match rx.recv().await {
+                    Ok(event) => yield event,
+                    Err(broadcast::error::RecvError::Lagged(missed)) => {
+                        yield FeedEvent::Lagged { topic: topic.clone(), missed };
+                    }
+                    Err(broadcast::error::RecvError::Closed) => break,
+                }
+            }
+        }
+    }
+
+    /// Poll `topic` on `poll_interval`, diffing each snapshot against the last one and
+    /// publishing only when it changed. Tears itself down once the last subscriber disconnects.
+    async fn poll_and_diff(self: Arc<Self>, topic: String, poll_interval: Duration, tx: broadcast::Sender<FeedEvent>) {
+        let mut last: Option<Value> = None;
+
+        loop {
+            if tx.receiver_count() == 0 {
+                break;
+            }
+
+            match fetch_topic_snapshot(&topic).await {
+                Ok(current) => {
+                    if last.as_ref() != Some(&current) {
+                        let _ = tx.send(FeedEvent::Update { topic: topic.clone(), data: current.clone() });
+                        last = Some(current);
+                    }
+                }
+                Err(e) => tracing::warn!("live feed: poll failed for {topic}: {e}"),
+            }
+
+            tokio::time::sleep(poll_interval).await;
+        }
+
+        self.subscriptions.lock().await.remove(&topic);
+    }
+}
+
+impl Default for LiveFeedHub {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Fetches the current snapshot for one topic: `"news"` (via the same cache
+/// [`crate::helpers::news::get_news_cached`] and the scheduler's `refresh_news_cache` job
+/// share), `"trending"` (Yahoo's trending symbol list), or `"pair:{PAIR}"` (a live Kraken
+/// ticker).
+async fn fetch_topic_snapshot(topic: &str) -> Result<Value, String> {
+    if topic == "news" {
+        crate::helpers::news::get_news_cached().await
+    } else if topic == "trending" {
+        Ok(serde_json::json!(crate::sources::yahoo_data::get_trending_from_yahoo().await))
+    } else if let Some(pair) = topic.strip_prefix("pair:") {
+        let data_source = KrakenDataSource::new_async().await.map_err(|e| e.to_string())?;
+        let tickers = data_source.get_tickers_async(vec![pair.to_string()]).await.map_err(|e| e.to_string())?;
+        tickers.into_iter().next()
+            .map(|t| serde_json::json!(t))
+            .ok_or_else(|| format!("no ticker for pair {pair}"))
+    } else {
+        Err(format!("unknown live feed topic: {topic}"))
+    }
+}
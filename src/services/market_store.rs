@@ -0,0 +1,240 @@
+use std::sync::Arc;
+
+use serde::Serialize;
+use tokio_postgres::types::Json;
+use tokio_postgres::{Client, NoTls};
+
+use crate::errors::ApiError;
+use crate::helpers::metrics::MetricsResult;
+use crate::services::yahoo::Candle;
+
+/// Postgres connection settings for the market-store (ticker mentions, OHLCV bars, computed
+/// metrics), built either from a single `MARKET_STORE_DATABASE_URL` or from the individual
+/// `MARKET_STORE_PG_*` parts, mirroring [`crate::services::history::HistoryDbConfig`]. `None`
+/// in [`crate::config::Config`] means this persistence is disabled.
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct MarketStoreDbConfig {
+    pub connection_string: String,
+}
+
+impl MarketStoreDbConfig {
+    pub fn from_env() -> Option<Self> {
+        if let Ok(url) = std::env::var("MARKET_STORE_DATABASE_URL") {
+            return Some(Self { connection_string: url });
+        }
+
+        let host = std::env::var("MARKET_STORE_PG_HOST").ok()?;
+        let port = std::env::var("MARKET_STORE_PG_PORT")
+            .ok()
+            .and_then(|v| v.parse::<u16>().ok())
+            .unwrap_or(5432);
+        let user = std::env::var("MARKET_STORE_PG_USER").unwrap_or_else(|_| "postgres".to_string());
+        let password = std::env::var("MARKET_STORE_PG_PASSWORD").unwrap_or_default();
+        let dbname = std::env::var("MARKET_STORE_PG_DBNAME").unwrap_or_else(|_| "trading_api".to_string());
+        let sslmode = std::env::var("MARKET_STORE_PG_SSLMODE").unwrap_or_else(|_| "prefer".to_string());
+
+        Some(Self {
+            connection_string: format!(
+                "host={host} port={port} user={user} password={password} dbname={dbname} sslmode={sslmode}"
+            ),
+        })
+    }
+}
+
+/// One stored ticker-mention count, as returned by [`MarketStore::mentions_for_symbol`].
+#[derive(Debug, Clone, Serialize)]
+pub struct MentionRow {
+    pub symbol: String,
+    pub source: String,
+    pub as_of: chrono::DateTime<chrono::Utc>,
+    pub mentions: i32,
+}
+
+/// Persists timestamped ticker mentions, fetched OHLCV bars, and computed metrics to Postgres,
+/// so trend-vs-price correlation (e.g. "mentions of GME per day over the last month") can be
+/// backtested instead of only ever seeing whatever's currently warm in `MemoryCache`. Every
+/// method is a no-op (`Ok`/empty) when no database is configured, following
+/// [`crate::services::history::HistoryStore`]'s enable-purely-via-env-var convention.
+pub struct MarketStore {
+    client: Option<Arc<Client>>,
+}
+
+impl MarketStore {
+    /// A disabled store that no-ops every call; used when market persistence isn't configured.
+    pub fn disabled() -> Self {
+        Self { client: None }
+    }
+
+    pub fn is_enabled(&self) -> bool {
+        self.client.is_some()
+    }
+
+    /// Connects to Postgres and ensures `ticker_mentions`, `symbol_candles`, and
+    /// `symbol_metrics` exist. Falls back to [`Self::disabled`] (with a logged warning) on any
+    /// connection or schema error, so a misconfigured database never prevents the rest of the
+    /// service from starting.
+    pub async fn connect(config: Option<&MarketStoreDbConfig>) -> Self {
+        let Some(config) = config else { return Self::disabled() };
+
+        let (client, connection) = match tokio_postgres::connect(&config.connection_string, NoTls).await {
+            Ok(pair) => pair,
+            Err(e) => {
+                tracing::warn!("market store: failed to connect to postgres: {e}");
+                return Self::disabled();
+            }
+        };
+
+        tokio::spawn(async move {
+            if let Err(e) = connection.await {
+                tracing::error!("market store: connection closed with error: {e}");
+            }
+        });
+
+        if let Err(e) = client
+            .batch_execute(
+                "CREATE TABLE IF NOT EXISTS ticker_mentions (
+                    symbol TEXT NOT NULL,
+                    source TEXT NOT NULL,
+                    as_of TIMESTAMPTZ NOT NULL,
+                    mentions INTEGER NOT NULL,
+                    PRIMARY KEY (symbol, source, as_of)
+                );
+                CREATE TABLE IF NOT EXISTS symbol_candles (
+                    symbol TEXT NOT NULL,
+                    interval TEXT NOT NULL,
+                    bucket_start TIMESTAMPTZ NOT NULL,
+                    open DOUBLE PRECISION NOT NULL,
+                    high DOUBLE PRECISION NOT NULL,
+                    low DOUBLE PRECISION NOT NULL,
+                    close DOUBLE PRECISION NOT NULL,
+                    volume DOUBLE PRECISION NOT NULL,
+                    PRIMARY KEY (symbol, interval, bucket_start)
+                );
+                CREATE TABLE IF NOT EXISTS symbol_metrics (
+                    symbol TEXT NOT NULL,
+                    as_of TIMESTAMPTZ NOT NULL,
+                    metrics JSONB NOT NULL,
+                    PRIMARY KEY (symbol, as_of)
+                )",
+            )
+            .await
+        {
+            tracing::warn!("market store: failed to initialize schema: {e}");
+            return Self::disabled();
+        }
+
+        Self { client: Some(Arc::new(client)) }
+    }
+
+    /// Upserts one scrape's worth of per-ticker mention counts from `source` (e.g.
+    /// `"reddit"`), all stamped with the same `as_of`. No-op when storage isn't configured.
+    pub async fn record_mentions(
+        &self,
+        mentions: &[(String, u32)],
+        source: &str,
+        as_of: chrono::DateTime<chrono::Utc>,
+    ) -> Result<(), ApiError> {
+        let Some(client) = &self.client else { return Ok(()) };
+        for (symbol, count) in mentions {
+            client
+                .execute(
+                    "INSERT INTO ticker_mentions (symbol, source, as_of, mentions)
+                     VALUES ($1, $2, $3, $4)
+                     ON CONFLICT (symbol, source, as_of)
+                     DO UPDATE SET mentions = EXCLUDED.mentions",
+                    &[symbol, &source, &as_of, &(*count as i32)],
+                )
+                .await
+                .map_err(|e| ApiError::InternalError(format!("failed to persist mention count for {symbol}: {e}")))?;
+        }
+        Ok(())
+    }
+
+    /// Upserts one symbol's OHLCV bars at `interval` (e.g. `"1mo"`, `"1h"`, matching the
+    /// range/interval labels `services::yahoo` already uses). Idempotent: replaying the same
+    /// `(symbol, interval, timestamp)` overwrites the previous row.
+    pub async fn record_candles(&self, symbol: &str, interval: &str, candles: &[Candle]) -> Result<(), ApiError> {
+        let Some(client) = &self.client else { return Ok(()) };
+        for candle in candles {
+            let bucket_start = chrono::DateTime::from_timestamp(candle.timestamp, 0)
+                .ok_or_else(|| ApiError::InternalError(format!("invalid candle timestamp for {symbol}: {}", candle.timestamp)))?;
+            client
+                .execute(
+                    "INSERT INTO symbol_candles (symbol, interval, bucket_start, open, high, low, close, volume)
+                     VALUES ($1, $2, $3, $4, $5, $6, $7, $8)
+                     ON CONFLICT (symbol, interval, bucket_start)
+                     DO UPDATE SET open = EXCLUDED.open, high = EXCLUDED.high, low = EXCLUDED.low,
+                         close = EXCLUDED.close, volume = EXCLUDED.volume",
+                    &[&symbol, &interval, &bucket_start, &candle.open, &candle.high, &candle.low, &candle.close, &candle.volume],
+                )
+                .await
+                .map_err(|e| ApiError::InternalError(format!("failed to persist candle for {symbol}: {e}")))?;
+        }
+        Ok(())
+    }
+
+    /// Persists `symbol`'s computed risk/return metrics as of `as_of`. No-op when storage
+    /// isn't configured.
+    pub async fn record_metrics(
+        &self,
+        symbol: &str,
+        as_of: chrono::DateTime<chrono::Utc>,
+        metrics: &MetricsResult,
+    ) -> Result<(), ApiError> {
+        let Some(client) = &self.client else { return Ok(()) };
+        let metrics_json = serde_json::to_value(metrics)
+            .map_err(|e| ApiError::InternalError(format!("failed to serialize metrics for {symbol}: {e}")))?;
+        client
+            .execute(
+                "INSERT INTO symbol_metrics (symbol, as_of, metrics)
+                 VALUES ($1, $2, $3)
+                 ON CONFLICT (symbol, as_of)
+                 DO UPDATE SET metrics = EXCLUDED.metrics",
+                &[&symbol, &as_of, &Json(&metrics_json)],
+            )
+            .await
+            .map_err(|e| ApiError::InternalError(format!("failed to persist metrics for {symbol}: {e}")))?;
+        Ok(())
+    }
+
+    /// Returns `symbol`'s stored mention counts since `since` (optionally filtered by
+    /// `source`), oldest first, capped at `limit` rows -- e.g. "mentions of GME per day over
+    /// the last month". Empty when storage isn't configured.
+    pub async fn mentions_for_symbol(
+        &self,
+        symbol: &str,
+        source: Option<&str>,
+        since: chrono::DateTime<chrono::Utc>,
+        limit: i64,
+    ) -> Result<Vec<MentionRow>, ApiError> {
+        let Some(client) = &self.client else { return Ok(Vec::new()) };
+
+        let rows = match source {
+            Some(source) => client
+                .query(
+                    "SELECT symbol, source, as_of, mentions FROM ticker_mentions
+                     WHERE symbol = $1 AND source = $2 AND as_of >= $3 ORDER BY as_of ASC LIMIT $4",
+                    &[&symbol, &source, &since, &limit],
+                )
+                .await,
+            None => client
+                .query(
+                    "SELECT symbol, source, as_of, mentions FROM ticker_mentions
+                     WHERE symbol = $1 AND as_of >= $2 ORDER BY as_of ASC LIMIT $3",
+                    &[&symbol, &since, &limit],
+                )
+                .await,
+        }
+        .map_err(|e| ApiError::InternalError(format!("failed to query mention history for {symbol}: {e}")))?;
+
+        Ok(rows
+            .into_iter()
+            .map(|row| MentionRow {
+                symbol: row.get("symbol"),
+                source: row.get("source"),
+                as_of: row.get("as_of"),
+                mentions: row.get("mentions"),
+            })
+            .collect())
+    }
+}
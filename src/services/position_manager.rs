@@ -0,0 +1,437 @@
+use std::sync::Arc;
+
+use serde::{Deserialize, Serialize};
+use tokio_postgres::{Client, NoTls};
+use utoipa::ToSchema;
+
+use crate::errors::ApiError;
+use crate::sources::pumpfun_data::{
+    spot_price_sol, PumpFunEventFilter, PumpFunService, TokenSellRequest, TransactionResult,
+};
+
+/// Postgres connection settings for persisted exit rules, built either from a single
+/// `POSITIONS_DATABASE_URL` or from the individual `POSITIONS_PG_*` parts, mirroring
+/// [`crate::services::candles::CandleDbConfig`]. `None` in [`crate::config::Config`] means
+/// [`PositionManager`] runs [`PositionManager::disabled`] and keeps no rules at all.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PositionManagerDbConfig {
+    pub connection_string: String,
+}
+
+impl PositionManagerDbConfig {
+    pub fn from_env() -> Option<Self> {
+        if let Ok(url) = std::env::var("POSITIONS_DATABASE_URL") {
+            return Some(Self { connection_string: url });
+        }
+
+        let host = std::env::var("POSITIONS_PG_HOST").ok()?;
+        let port = std::env::var("POSITIONS_PG_PORT")
+            .ok()
+            .and_then(|v| v.parse::<u16>().ok())
+            .unwrap_or(5432);
+        let user = std::env::var("POSITIONS_PG_USER").unwrap_or_else(|_| "postgres".to_string());
+        let password = std::env::var("POSITIONS_PG_PASSWORD").unwrap_or_default();
+        let dbname = std::env::var("POSITIONS_PG_DBNAME").unwrap_or_else(|_| "trading_api".to_string());
+        let sslmode = std::env::var("POSITIONS_PG_SSLMODE").unwrap_or_else(|_| "prefer".to_string());
+
+        Some(Self {
+            connection_string: format!(
+                "host={host} port={port} user={user} password={password} dbname={dbname} sslmode={sslmode}"
+            ),
+        })
+    }
+}
+
+/// Which side of an entry price a rule watches for.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum ExitRuleKind {
+    /// Sell once price has risen at least `threshold_pct` above entry.
+    TakeProfit,
+    /// Sell once price has fallen at least `threshold_pct` below entry.
+    StopLoss,
+}
+
+impl ExitRuleKind {
+    fn as_str(&self) -> &'static str {
+        match self {
+            ExitRuleKind::TakeProfit => "take_profit",
+            ExitRuleKind::StopLoss => "stop_loss",
+        }
+    }
+
+    fn from_str(value: &str) -> Result<Self, ApiError> {
+        match value {
+            "take_profit" => Ok(ExitRuleKind::TakeProfit),
+            "stop_loss" => Ok(ExitRuleKind::StopLoss),
+            other => Err(ApiError::InternalError(format!("unknown exit rule kind '{other}'"))),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum ExitRuleStatus {
+    Open,
+    Triggered,
+    Cancelled,
+}
+
+impl ExitRuleStatus {
+    fn as_str(&self) -> &'static str {
+        match self {
+            ExitRuleStatus::Open => "open",
+            ExitRuleStatus::Triggered => "triggered",
+            ExitRuleStatus::Cancelled => "cancelled",
+        }
+    }
+
+    fn from_str(value: &str) -> Result<Self, ApiError> {
+        match value {
+            "open" => Ok(ExitRuleStatus::Open),
+            "triggered" => Ok(ExitRuleStatus::Triggered),
+            "cancelled" => Ok(ExitRuleStatus::Cancelled),
+            other => Err(ApiError::InternalError(format!("unknown exit rule status '{other}'"))),
+        }
+    }
+}
+
+/// One registered take-profit/stop-loss rule against a held mint, from
+/// [`PositionManager::register_rule`].
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct ExitRule {
+    pub id: i64,
+    pub mint_address: String,
+    pub entry_price_sol: f64,
+    pub kind: ExitRuleKind,
+    /// Percent move from `entry_price_sol` that trips the rule, e.g. `30.0` for "30%".
+    pub threshold_pct: f64,
+    /// Fraction of the held position to sell once the rule trips, in `(0.0, 1.0]` (`1.0` sells
+    /// the whole position).
+    pub sell_fraction: f64,
+    pub status: ExitRuleStatus,
+    pub created_at: chrono::DateTime<chrono::Utc>,
+}
+
+#[derive(Debug, Clone, Deserialize, ToSchema)]
+pub struct RegisterExitRuleRequest {
+    pub mint_address: String,
+    pub entry_price_sol: f64,
+    pub kind: ExitRuleKind,
+    pub threshold_pct: f64,
+    pub sell_fraction: f64,
+}
+
+/// A stateful strategy runner layered on top of [`PumpFunService`]'s fire-and-forget buy/sell
+/// API: callers register take-profit/stop-loss [`ExitRule`]s against a mint they hold, and the
+/// manager watches [`PumpFunService::subscribe_stream`]'s live trade prices, firing
+/// [`PumpFunService::sell_token`] the moment a rule trips. Rules persist to Postgres (when
+/// configured) so they survive a restart, following [`crate::services::candles::CandleStore`]'s
+/// enable-purely-via-env-var convention -- with no database configured, reads return empty,
+/// [`Self::register_rule`] errors with [`ApiError::Configuration`] and [`Self::cancel_rule`] with
+/// [`ApiError::NotFound`], since an in-memory-only rule set can't honor "survives a restart".
+pub struct PositionManager {
+    db: Option<Arc<Client>>,
+    pumpfun: Arc<PumpFunService>,
+}
+
+impl PositionManager {
+    /// A manager with no database configured; every read returns empty, registering a rule fails
+    /// with [`ApiError::Configuration`] rather than silently forgetting it, and cancelling one
+    /// fails with [`ApiError::NotFound`].
+    pub fn disabled(pumpfun: Arc<PumpFunService>) -> Self {
+        Self { db: None, pumpfun }
+    }
+
+    pub fn is_persistent(&self) -> bool {
+        self.db.is_some()
+    }
+
+    /// Connects to Postgres and ensures the exit-rule tables exist. Falls back to
+    /// [`Self::disabled`] (with a logged warning) on any connection or schema error, so a
+    /// misconfigured database never prevents the rest of the service from starting.
+    pub async fn connect(config: Option<&PositionManagerDbConfig>, pumpfun: Arc<PumpFunService>) -> Self {
+        let Some(config) = config else { return Self::disabled(pumpfun) };
+
+        let (client, connection) = match tokio_postgres::connect(&config.connection_string, NoTls).await {
+            Ok(pair) => pair,
+            Err(e) => {
+                tracing::warn!("position manager: failed to connect to postgres: {e}");
+                return Self::disabled(pumpfun);
+            }
+        };
+
+        tokio::spawn(async move {
+            if let Err(e) = connection.await {
+                tracing::error!("position manager: connection closed with error: {e}");
+            }
+        });
+
+        if let Err(e) = client
+            .batch_execute(
+                "CREATE TABLE IF NOT EXISTS pumpfun_exit_rules (
+                    id BIGSERIAL PRIMARY KEY,
+                    mint_address TEXT NOT NULL,
+                    entry_price_sol DOUBLE PRECISION NOT NULL,
+                    kind TEXT NOT NULL,
+                    threshold_pct DOUBLE PRECISION NOT NULL,
+                    sell_fraction DOUBLE PRECISION NOT NULL,
+                    status TEXT NOT NULL,
+                    created_at TIMESTAMPTZ NOT NULL DEFAULT now()
+                );
+                CREATE TABLE IF NOT EXISTS pumpfun_exit_rule_fills (
+                    rule_id BIGINT NOT NULL REFERENCES pumpfun_exit_rules (id),
+                    signature TEXT NOT NULL,
+                    success BOOLEAN NOT NULL,
+                    error TEXT,
+                    filled_at TIMESTAMPTZ NOT NULL DEFAULT now()
+                )",
+            )
+            .await
+        {
+            tracing::warn!("position manager: failed to initialize schema: {e}");
+            return Self::disabled(pumpfun);
+        }
+
+        Self { db: Some(Arc::new(client)), pumpfun }
+    }
+
+    /// Registers a new rule against `request.mint_address`. Rejects a curve that's already
+    /// migrated to Raydium with [`ApiError::CurveMigrated`], since there's no bonding-curve
+    /// price left for [`Self::watch_events`] to evaluate the rule against.
+    pub async fn register_rule(&self, request: RegisterExitRuleRequest) -> Result<ExitRule, ApiError> {
+        if request.sell_fraction <= 0.0 || request.sell_fraction > 1.0 {
+            return Err(ApiError::ValidationError("sell_fraction must be in (0.0, 1.0]".to_string()));
+        }
+        if request.entry_price_sol <= 0.0 {
+            return Err(ApiError::ValidationError("entry_price_sol must be > 0.0".to_string()));
+        }
+        if request.threshold_pct <= 0.0 {
+            return Err(ApiError::ValidationError("threshold_pct must be > 0.0".to_string()));
+        }
+
+        let curve = self.pumpfun.get_bonding_curve(&request.mint_address).await?;
+        if curve.complete {
+            return Err(ApiError::CurveMigrated(format!(
+                "bonding curve for {} has already migrated to Raydium; exit rules no longer apply",
+                request.mint_address
+            )));
+        }
+
+        let Some(db) = &self.db else {
+            return Err(ApiError::Configuration(
+                "position manager has no database configured; exit rules can't be persisted".to_string(),
+            ));
+        };
+
+        let row = db
+            .query_one(
+                "INSERT INTO pumpfun_exit_rules (mint_address, entry_price_sol, kind, threshold_pct, sell_fraction, status)
+                 VALUES ($1, $2, $3, $4, $5, 'open')
+                 RETURNING id, created_at",
+                &[
+                    &request.mint_address,
+                    &request.entry_price_sol,
+                    &request.kind.as_str(),
+                    &request.threshold_pct,
+                    &request.sell_fraction,
+                ],
+            )
+            .await
+            .map_err(|e| ApiError::InternalError(format!("failed to persist exit rule: {e}")))?;
+
+        Ok(ExitRule {
+            id: row.get("id"),
+            mint_address: request.mint_address,
+            entry_price_sol: request.entry_price_sol,
+            kind: request.kind,
+            threshold_pct: request.threshold_pct,
+            sell_fraction: request.sell_fraction,
+            status: ExitRuleStatus::Open,
+            created_at: row.get("created_at"),
+        })
+    }
+
+    /// All registered rules, most recently registered last. Empty (not an error) when
+    /// persistence isn't configured.
+    pub async fn list_rules(&self) -> Result<Vec<ExitRule>, ApiError> {
+        let Some(db) = &self.db else { return Ok(Vec::new()) };
+        let rows = db
+            .query(
+                "SELECT id, mint_address, entry_price_sol, kind, threshold_pct, sell_fraction, status, created_at
+                 FROM pumpfun_exit_rules ORDER BY id ASC",
+                &[],
+            )
+            .await
+            .map_err(|e| ApiError::InternalError(format!("failed to list exit rules: {e}")))?;
+
+        rows.into_iter().map(Self::row_to_rule).collect()
+    }
+
+    /// Cancels an open rule. `ApiError::NotFound` when `id` doesn't reference a currently-open
+    /// rule (already triggered/cancelled rules don't re-cancel).
+    pub async fn cancel_rule(&self, id: i64) -> Result<(), ApiError> {
+        let Some(db) = &self.db else {
+            return Err(ApiError::NotFound(format!("no exit rule {id} (position manager has no database configured)")));
+        };
+        let updated = db
+            .execute(
+                "UPDATE pumpfun_exit_rules SET status = 'cancelled' WHERE id = $1 AND status = 'open'",
+                &[&id],
+            )
+            .await
+            .map_err(|e| ApiError::InternalError(format!("failed to cancel exit rule: {e}")))?;
+        if updated == 0 {
+            return Err(ApiError::NotFound(format!("no open exit rule {id}")));
+        }
+        Ok(())
+    }
+
+    /// Re-evaluates every still-`open` rule against its curve's current spot price once at
+    /// startup -- in case it already tripped, or the curve migrated, while the service was down
+    /// -- then spawns [`Self::watch_events`] to keep evaluating live. A no-op when persistence
+    /// isn't configured (there's nothing to reload).
+    pub async fn reload(self: &Arc<Self>) -> Result<(), ApiError> {
+        if self.db.is_none() {
+            return Ok(());
+        }
+
+        for rule in self.list_rules().await? {
+            if rule.status != ExitRuleStatus::Open {
+                continue;
+            }
+            if let Err(e) = self.evaluate_from_curve(&rule).await {
+                tracing::warn!("position manager: startup re-evaluation of rule {} failed: {e}", rule.id);
+            }
+        }
+
+        tokio::spawn(self.clone().watch_events());
+        Ok(())
+    }
+
+    /// Subscribes to the shared trade-event stream (mint-agnostic -- [`PumpFunService`] fans a
+    /// single upstream subscription out to every subscriber, so one subscription here covers
+    /// every rule) and re-evaluates open rules for whichever mint each trade lands on.
+    async fn watch_events(self: Arc<Self>) {
+        let filter = PumpFunEventFilter {
+            mint_address: None,
+            event_type: Some("trade".to_string()),
+            min_sol_amount: None,
+        };
+        let stream = match self.pumpfun.subscribe_stream(filter).await {
+            Ok(stream) => stream,
+            Err(e) => {
+                tracing::warn!("position manager: failed to subscribe to trade events: {e}");
+                return;
+            }
+        };
+        futures::pin_mut!(stream);
+
+        while let Some(event) = futures::StreamExt::next(&mut stream).await {
+            let Some(trade) = event.trade_info else { continue };
+            let rules = match self.list_rules().await {
+                Ok(rules) => rules,
+                Err(e) => {
+                    tracing::warn!("position manager: failed to list rules while watching events: {e}");
+                    continue;
+                }
+            };
+
+            for rule in rules.into_iter().filter(|r| r.status == ExitRuleStatus::Open && r.mint_address == trade.mint_address) {
+                if let Err(e) = self.evaluate_at_price(&rule, trade.price).await {
+                    tracing::warn!("position manager: evaluating rule {} failed: {e}", rule.id);
+                }
+            }
+        }
+    }
+
+    /// Re-reads `rule`'s mint's current bonding-curve spot price and evaluates it -- used at
+    /// startup, where there's no live trade event to key off of. A migrated curve cancels the
+    /// rule outright rather than leaving it open forever with nothing left to evaluate it against.
+    async fn evaluate_from_curve(&self, rule: &ExitRule) -> Result<(), ApiError> {
+        let curve = self.pumpfun.get_bonding_curve(&rule.mint_address).await?;
+        if curve.complete {
+            return self.cancel_rule(rule.id).await;
+        }
+        let spot = spot_price_sol(curve.virtual_sol_reserves, curve.virtual_token_reserves);
+        self.evaluate_at_price(rule, spot).await
+    }
+
+    fn rule_tripped(rule: &ExitRule, price: f64) -> bool {
+        if rule.entry_price_sol <= 0.0 {
+            return false;
+        }
+        let change_pct = (price - rule.entry_price_sol) / rule.entry_price_sol * 100.0;
+        match rule.kind {
+            ExitRuleKind::TakeProfit => change_pct >= rule.threshold_pct,
+            ExitRuleKind::StopLoss => change_pct <= -rule.threshold_pct,
+        }
+    }
+
+    /// If `rule` has tripped at `price`, sells `rule.sell_fraction` of the wallet's real balance
+    /// (via [`PumpFunService::get_token_balance`], same as a caller-initiated full-position
+    /// sell) and marks the rule triggered. A no-op if it hasn't tripped.
+    async fn evaluate_at_price(&self, rule: &ExitRule, price: f64) -> Result<(), ApiError> {
+        if !Self::rule_tripped(rule, price) {
+            return Ok(());
+        }
+
+        let balance = self.pumpfun.get_token_balance(&rule.mint_address).await?;
+        let held = balance.amount.parse::<u64>().unwrap_or(0);
+        let sell_amount = (held as f64 * rule.sell_fraction) as u64;
+        if sell_amount == 0 {
+            return self.mark_status(rule.id, ExitRuleStatus::Triggered).await;
+        }
+
+        let result = self
+            .pumpfun
+            .sell_token(TokenSellRequest {
+                mint_address: rule.mint_address.clone(),
+                token_amount: Some(sell_amount),
+                slippage_bps: None,
+                confirm: Some(true),
+                price_tolerance_bps: None,
+            })
+            .await?;
+
+        self.record_fill(rule.id, &result).await?;
+        self.mark_status(rule.id, ExitRuleStatus::Triggered).await
+    }
+
+    async fn mark_status(&self, id: i64, status: ExitRuleStatus) -> Result<(), ApiError> {
+        let Some(db) = &self.db else { return Ok(()) };
+        db.execute(
+            "UPDATE pumpfun_exit_rules SET status = $2 WHERE id = $1",
+            &[&id, &status.as_str()],
+        )
+        .await
+        .map_err(|e| ApiError::InternalError(format!("failed to update exit rule status: {e}")))?;
+        Ok(())
+    }
+
+    async fn record_fill(&self, rule_id: i64, result: &TransactionResult) -> Result<(), ApiError> {
+        let Some(db) = &self.db else { return Ok(()) };
+        db.execute(
+            "INSERT INTO pumpfun_exit_rule_fills (rule_id, signature, success, error) VALUES ($1, $2, $3, $4)",
+            &[&rule_id, &result.signature, &result.success, &result.error],
+        )
+        .await
+        .map_err(|e| ApiError::InternalError(format!("failed to record exit rule fill: {e}")))?;
+        Ok(())
+    }
+
+    fn row_to_rule(row: tokio_postgres::Row) -> Result<ExitRule, ApiError> {
+        let kind: String = row.get("kind");
+        let status: String = row.get("status");
+        Ok(ExitRule {
+            id: row.get("id"),
+            mint_address: row.get("mint_address"),
+            entry_price_sol: row.get("entry_price_sol"),
+            kind: ExitRuleKind::from_str(&kind)?,
+            threshold_pct: row.get("threshold_pct"),
+            sell_fraction: row.get("sell_fraction"),
+            status: ExitRuleStatus::from_str(&status)?,
+            created_at: row.get("created_at"),
+        })
+    }
+}
@@ -0,0 +1,38 @@
+use async_trait::async_trait;
+
+use crate::errors::ApiError;
+
+/// A venue-agnostic ticker snapshot, normalized out of whatever shape a given exchange's REST
+/// API returns it in.
+#[derive(Debug, Clone)]
+pub struct NormalizedTicker {
+    pub pair: String,
+    pub price: f64,
+    pub bid: Option<f64>,
+    pub ask: Option<f64>,
+    pub volume: f64,
+    pub high_24h: Option<f64>,
+    pub low_24h: Option<f64>,
+    pub change_24h: Option<f64>,
+    pub change_pct_24h: Option<f64>,
+}
+
+/// A venue-agnostic order book snapshot. `bids`/`asks` are `(price, volume)` pairs.
+#[derive(Debug, Clone)]
+pub struct NormalizedOrderBook {
+    pub pair: String,
+    pub bids: Vec<(f64, f64)>,
+    pub asks: Vec<(f64, f64)>,
+}
+
+/// A source of market data from one exchange, normalized behind a common interface so the
+/// trending/market-summary pipeline isn't pinned to a single venue. Mirrors the
+/// [`crate::services::rates::LatestRate`]/[`crate::helpers::trending_cryptos::TrendingSource`]
+/// pattern: each venue implements this, and callers take `&dyn RateProvider` so a second
+/// exchange can be swapped in (or compared against the first) without touching call sites.
+#[async_trait]
+pub trait RateProvider: Send + Sync {
+    fn provider_name(&self) -> &'static str;
+    async fn tickers(&self, pairs: Vec<String>) -> Result<Vec<NormalizedTicker>, ApiError>;
+    async fn order_book(&self, pair: &str, depth: u32) -> Result<NormalizedOrderBook, ApiError>;
+}
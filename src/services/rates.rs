@@ -0,0 +1,265 @@
+use std::collections::HashMap;
+use std::sync::Arc;
+use std::time::Duration;
+
+use async_trait::async_trait;
+use finviz_rs::{common::Scrape, crypto::Crypto, forex::Forex};
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+use crate::cache::{cache_key, MemoryCache};
+use crate::errors::ApiError;
+
+/// A single live quote for a forex/crypto pair, as produced by a [`LatestRate`] source.
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct RateQuote {
+    pub pair: String,
+    pub price: f64,
+    pub source: String,
+    pub updated_at: String,
+}
+
+/// A source of live rates for a single pair (e.g. `EUR/USD`, `BTC`). Implementations
+/// wrap whatever upstream feed backs them (Finviz scrape, exchange API, ...) behind
+/// a common interface so the background poller and route handlers don't need to
+/// know which feed they're talking to.
+#[async_trait]
+pub trait LatestRate: Send + Sync {
+    fn source_name(&self) -> &'static str;
+
+    async fn latest_rate(&self, pair: &str) -> Result<RateQuote, ApiError>;
+}
+
+fn row_value(headers: &[String], row: &[String], column: &str) -> Option<String> {
+    headers.iter().position(|h| h.eq_ignore_ascii_case(column))
+        .and_then(|idx| row.get(idx).cloned())
+}
+
+fn parse_price(raw: &str) -> Option<f64> {
+    raw.trim().trim_start_matches('$').replace(',', "").parse().ok()
+}
+
+/// Live forex rates scraped from Finviz's forex performance table.
+pub struct FinvizForexRate;
+
+#[async_trait]
+impl LatestRate for FinvizForexRate {
+    fn source_name(&self) -> &'static str {
+        "finviz_forex"
+    }
+
+    async fn latest_rate(&self, pair: &str) -> Result<RateQuote, ApiError> {
+        let rows = Forex::default().scrape().await
+            .map_err(|e| ApiError::Upstream(format!("failed to fetch forex: {e}")))?;
+        let headers: Vec<String> = Forex::default_header().into_iter().map(|s| s.to_string()).collect();
+
+        let row = rows.iter()
+            .find(|row| row_value(&headers, row, "Ticker").as_deref() == Some(pair))
+            .ok_or_else(|| ApiError::NotFound(format!("no forex rate for pair {pair}")))?;
+
+        let price = row_value(&headers, row, "Last")
+            .and_then(|v| parse_price(&v))
+            .ok_or_else(|| ApiError::Upstream(format!("missing price for pair {pair}")))?;
+
+        Ok(RateQuote {
+            pair: pair.to_string(),
+            price,
+            source: self.source_name().to_string(),
+            updated_at: chrono::Utc::now().to_rfc3339(),
+        })
+    }
+}
+
+/// Live crypto rates scraped from Finviz's crypto performance table.
+pub struct FinvizCryptoRate;
+
+#[async_trait]
+impl LatestRate for FinvizCryptoRate {
+    fn source_name(&self) -> &'static str {
+        "finviz_crypto"
+    }
+
+    async fn latest_rate(&self, pair: &str) -> Result<RateQuote, ApiError> {
+        let rows = Crypto::default().scrape().await
+            .map_err(|e| ApiError::Upstream(format!("failed to fetch crypto: {e}")))?;
+        let headers: Vec<String> = Crypto::default_header().into_iter().map(|s| s.to_string()).collect();
+
+        let row = rows.iter()
+            .find(|row| row_value(&headers, row, "Ticker").as_deref() == Some(pair))
+            .ok_or_else(|| ApiError::NotFound(format!("no crypto rate for pair {pair}")))?;
+
+        let price = row_value(&headers, row, "Last")
+            .and_then(|v| parse_price(&v))
+            .ok_or_else(|| ApiError::Upstream(format!("missing price for pair {pair}")))?;
+
+        Ok(RateQuote {
+            pair: pair.to_string(),
+            price,
+            source: self.source_name().to_string(),
+            updated_at: chrono::Utc::now().to_rfc3339(),
+        })
+    }
+}
+
+/// Live crypto price from CoinGecko's `/simple/price` endpoint. `pair` is a CoinGecko coin
+/// id (e.g. `bitcoin`), quoted against a fixed `vs_currency`.
+pub struct CoinGeckoRate {
+    vs_currency: String,
+}
+
+impl CoinGeckoRate {
+    pub fn new(vs_currency: impl Into<String>) -> Self {
+        Self { vs_currency: vs_currency.into() }
+    }
+}
+
+impl Default for CoinGeckoRate {
+    fn default() -> Self {
+        Self::new("usd")
+    }
+}
+
+#[async_trait]
+impl LatestRate for CoinGeckoRate {
+    fn source_name(&self) -> &'static str {
+        "coingecko"
+    }
+
+    async fn latest_rate(&self, pair: &str) -> Result<RateQuote, ApiError> {
+        let data = crate::sources::coingecko_data::CoinGeckoClient::new()
+            .get_simple_price(&[pair.to_string()], &[self.vs_currency.clone()], false)
+            .await
+            .map_err(ApiError::Upstream)?;
+
+        let price = data.get(pair)
+            .and_then(|v| v.get(&self.vs_currency))
+            .and_then(|v| v.as_f64())
+            .ok_or_else(|| ApiError::NotFound(format!("no CoinGecko price for {pair}")))?;
+
+        Ok(RateQuote {
+            pair: pair.to_string(),
+            price,
+            source: self.source_name().to_string(),
+            updated_at: chrono::Utc::now().to_rfc3339(),
+        })
+    }
+}
+
+/// A constant-value [`LatestRate`] for tests and offline development: always returns the
+/// same quote for one configured pair, so downstream consumers (like [`RateFeed`]) can be
+/// exercised without hitting any upstream API.
+pub struct FixedRate {
+    pair: String,
+    price: f64,
+}
+
+impl FixedRate {
+    pub fn new(pair: impl Into<String>, price: f64) -> Self {
+        Self { pair: pair.into(), price }
+    }
+}
+
+#[async_trait]
+impl LatestRate for FixedRate {
+    fn source_name(&self) -> &'static str {
+        "fixed"
+    }
+
+    async fn latest_rate(&self, pair: &str) -> Result<RateQuote, ApiError> {
+        if pair != self.pair {
+            return Err(ApiError::NotFound(format!("no fixed rate for pair {pair}")));
+        }
+        Ok(RateQuote {
+            pair: pair.to_string(),
+            price: self.price,
+            source: self.source_name().to_string(),
+            updated_at: chrono::Utc::now().to_rfc3339(),
+        })
+    }
+}
+
+/// Push-style complement to [`run_rate_poller`]: instead of callers pulling the latest
+/// quote out of the cache on demand, `RateFeed` keeps one `watch` channel per configured
+/// pair warm via a single background poll loop, so subscribers get a `watch::Receiver`
+/// that always holds the freshest value with no REST call on the calling path.
+pub struct RateFeed {
+    channels: HashMap<String, tokio::sync::watch::Receiver<Option<RateQuote>>>,
+}
+
+impl RateFeed {
+    /// Spawns the background poll loop and returns immediately; channels start out holding
+    /// `None` until the first successful poll for each pair completes.
+    pub fn spawn(source: Arc<dyn LatestRate>, pairs: Vec<String>, interval: Duration) -> Self {
+        let mut channels = HashMap::new();
+        let mut senders = HashMap::new();
+        for pair in &pairs {
+            let (tx, rx) = tokio::sync::watch::channel(None);
+            channels.insert(pair.clone(), rx);
+            senders.insert(pair.clone(), tx);
+        }
+
+        tokio::spawn(async move {
+            let mut ticker = tokio::time::interval(interval);
+            loop {
+                ticker.tick().await;
+                for pair in &pairs {
+                    match source.latest_rate(pair).await {
+                        Ok(quote) => {
+                            if let Some(tx) = senders.get(pair) {
+                                let _ = tx.send(Some(quote));
+                            }
+                        }
+                        Err(e) => {
+                            tracing::warn!("rate feed poll failed for {} {}: {}", source.source_name(), pair, e);
+                        }
+                    }
+                }
+            }
+        });
+
+        Self { channels }
+    }
+
+    /// A receiver that always yields the freshest quote for `pair`, or `None` until the
+    /// first successful poll completes. Returns `None` (the `Option`, not the channel value)
+    /// if `pair` wasn't in the set this feed was spawned with.
+    pub fn subscribe(&self, pair: &str) -> Option<tokio::sync::watch::Receiver<Option<RateQuote>>> {
+        self.channels.get(pair).cloned()
+    }
+}
+
+/// Poll a set of `LatestRate` sources on an interval and keep their latest quotes
+/// warm in `cache`, so streaming/route consumers read a cached value instead of
+/// re-scraping on every request.
+pub async fn run_rate_poller(
+    cache: Arc<MemoryCache>,
+    sources: Vec<(Arc<dyn LatestRate>, Vec<String>)>,
+    interval: Duration,
+) {
+    let mut ticker = tokio::time::interval(interval);
+    loop {
+        ticker.tick().await;
+        for (source, pairs) in &sources {
+            for pair in pairs {
+                match source.latest_rate(pair).await {
+                    Ok(quote) => {
+                        let key = cache_key("live_rate", &[("source", source.source_name()), ("pair", pair)]);
+                        if let Ok(value) = serde_json::to_value(&quote) {
+                            cache.set(key, value, interval * 2).await;
+                        }
+                    }
+                    Err(e) => {
+                        tracing::warn!("rate poll failed for {} {}: {}", source.source_name(), pair, e);
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// Read the most recently polled quote for `pair` from `source` out of the cache.
+pub async fn cached_rate(cache: &MemoryCache, source_name: &str, pair: &str) -> Option<RateQuote> {
+    let key = cache_key("live_rate", &[("source", source_name), ("pair", pair)]);
+    let value = cache.get(&key).await?;
+    serde_json::from_value(value).ok()
+}
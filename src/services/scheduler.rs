@@ -0,0 +1,262 @@
+use std::collections::HashSet;
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+
+use chrono::{DateTime, Datelike, Timelike, Utc};
+use tracing::{info, warn};
+
+type BoxFuture = Pin<Box<dyn Future<Output = ()> + Send>>;
+
+/// A parsed 5-field cron expression (`minute hour day-of-month month day-of-week`), standard
+/// crontab syntax: `*`, a single number, a comma-separated list, an inclusive `a-b` range, and a
+/// `*/n` or `a-b/n` step -- any of which can be combined with commas (e.g. `0,30 9-17 * * 1-5`).
+/// Unlike cron proper, day-of-month and day-of-week are ANDed rather than ORed when both are
+/// restricted; every job this scheduler runs leaves one of the two as `*`, so the distinction
+/// doesn't come up in practice.
+#[derive(Debug, Clone)]
+struct CronSchedule {
+    minute: HashSet<u32>,
+    hour: HashSet<u32>,
+    day_of_month: HashSet<u32>,
+    month: HashSet<u32>,
+    day_of_week: HashSet<u32>,
+}
+
+impl CronSchedule {
+    fn parse(expr: &str) -> Result<Self, String> {
+        let fields: Vec<&str> = expr.split_whitespace().collect();
+        if fields.len() != 5 {
+            return Err(format!("cron expression must have 5 fields, got {}: \"{expr}\"", fields.len()));
+        }
+        Ok(CronSchedule {
+            minute: parse_field(fields[0], 0, 59)?,
+            hour: parse_field(fields[1], 0, 23)?,
+            day_of_month: parse_field(fields[2], 1, 31)?,
+            month: parse_field(fields[3], 1, 12)?,
+            day_of_week: parse_field(fields[4], 0, 6)?,
+        })
+    }
+
+    fn matches(&self, dt: &DateTime<Utc>) -> bool {
+        self.minute.contains(&dt.minute())
+            && self.hour.contains(&dt.hour())
+            && self.day_of_month.contains(&dt.day())
+            && self.month.contains(&dt.month())
+            && self.day_of_week.contains(&dt.weekday().num_days_from_sunday())
+    }
+
+    /// Walks forward minute-by-minute (cron's own resolution) to find the next fire time
+    /// strictly after `from`. Bounded to a year out so a field combination that can never
+    /// match (e.g. day-of-month 31 ANDed against a day-of-week that never lands on one) can't
+    /// spin the scheduler loop forever.
+    fn next_after(&self, from: DateTime<Utc>) -> Option<DateTime<Utc>> {
+        let mut candidate = (from + chrono::Duration::minutes(1))
+            .with_second(0)
+            .and_then(|dt| dt.with_nanosecond(0))?;
+        let limit = from + chrono::Duration::days(366);
+        while candidate <= limit {
+            if self.matches(&candidate) {
+                return Some(candidate);
+            }
+            candidate += chrono::Duration::minutes(1);
+        }
+        None
+    }
+}
+
+fn parse_field(spec: &str, min: u32, max: u32) -> Result<HashSet<u32>, String> {
+    let mut values = HashSet::new();
+    for part in spec.split(',') {
+        let (range_part, step) = match part.split_once('/') {
+            Some((r, s)) => (r, s.parse::<u32>().map_err(|_| format!("invalid step in cron field: \"{part}\""))?),
+            None => (part, 1),
+        };
+        let (start, end) = if range_part == "*" {
+            (min, max)
+        } else if let Some((a, b)) = range_part.split_once('-') {
+            (
+                a.parse::<u32>().map_err(|_| format!("invalid range start in cron field: \"{part}\""))?,
+                b.parse::<u32>().map_err(|_| format!("invalid range end in cron field: \"{part}\""))?,
+            )
+        } else {
+            let v = part.parse::<u32>().map_err(|_| format!("invalid value in cron field: \"{part}\""))?;
+            (v, v)
+        };
+        if start < min || end > max || start > end {
+            return Err(format!("cron field value out of range [{min}, {max}]: \"{part}\""));
+        }
+        let mut v = start;
+        while v <= end {
+            values.insert(v);
+            v += step.max(1);
+        }
+    }
+    Ok(values)
+}
+
+/// How a job's next fire time is computed. `Cron` anchors to wall-clock fields (e.g. "top of
+/// every hour", "9:30am UTC on weekdays"); `Interval` fires every `period` starting from when the
+/// job last ran, regardless of wall-clock alignment, and additionally gets catch-up semantics
+/// (see [`Scheduler::run`]) since "last ran more than one period ago" is well-defined for it in a
+/// way it isn't for an arbitrary cron expression.
+enum JobSchedule {
+    Cron(CronSchedule),
+    Interval(chrono::Duration),
+}
+
+impl JobSchedule {
+    fn next_after(&self, from: DateTime<Utc>) -> Option<DateTime<Utc>> {
+        match self {
+            JobSchedule::Cron(schedule) => schedule.next_after(from),
+            JobSchedule::Interval(period) => Some(from + *period),
+        }
+    }
+}
+
+/// One named recurring job: when it fires, the work it does, and whether a run is currently in
+/// flight. `running` provides overlap protection -- if a job's own work takes longer than its
+/// period, the next due tick is skipped (logged, not queued) rather than stacking a second
+/// concurrent run on top of the first.
+struct Job {
+    name: String,
+    schedule: JobSchedule,
+    task: Arc<dyn Fn() -> BoxFuture + Send + Sync>,
+    running: Arc<AtomicBool>,
+}
+
+/// Cron-driven background job runner. Holds named `(JobSchedule, task)` pairs, sleeps until the
+/// soonest next fire time across all of them, runs whichever are due, and loops -- so jobs on
+/// unrelated periods (a 10-minute scrape, a once-daily market-open pre-warm) share one loop
+/// instead of each needing their own `tokio::spawn` + `tokio::time::interval`.
+#[derive(Default)]
+pub struct Scheduler {
+    jobs: Vec<Job>,
+}
+
+/// Handle to a running [`Scheduler`], returned by [`Scheduler::start`]. Dropping it leaves the
+/// scheduler running in the background; call [`Self::stop`] to tear it down explicitly (e.g. in
+/// an example or test that shouldn't outlive its own `main`).
+pub struct SchedulerHandle {
+    task: tokio::task::JoinHandle<()>,
+}
+
+impl SchedulerHandle {
+    /// Aborts the scheduler loop. In-flight job runs (each its own spawned task) are not
+    /// cancelled, only the loop that fires new ones.
+    pub fn stop(self) {
+        self.task.abort();
+    }
+}
+
+impl Scheduler {
+    pub fn new() -> Self {
+        Self { jobs: Vec::new() }
+    }
+
+    /// Registers a named job under a standard 5-field cron expression. An invalid expression is
+    /// logged and the job dropped rather than failing startup -- a typo in one operator-supplied
+    /// schedule shouldn't take the whole scheduler, and every other job on it, down.
+    pub fn add_job<F, Fut>(&mut self, name: impl Into<String>, cron_expr: &str, task: F)
+    where
+        F: Fn() -> Fut + Send + Sync + 'static,
+        Fut: Future<Output = ()> + Send + 'static,
+    {
+        let name = name.into();
+        match CronSchedule::parse(cron_expr) {
+            Ok(schedule) => self.push_job(name, JobSchedule::Cron(schedule), task),
+            Err(e) => warn!("scheduler: dropping job \"{name}\" with invalid cron expression \"{cron_expr}\": {e}"),
+        }
+    }
+
+    /// Registers a named job that fires every `period`, independent of wall-clock alignment.
+    /// Unlike a cron job, an interval job gets catch-up semantics on startup: no previous run is
+    /// recorded across restarts, so it always fires immediately instead of waiting out a full
+    /// period from process start (see [`Self::run`]).
+    pub fn add_interval_job<F, Fut>(&mut self, name: impl Into<String>, period: std::time::Duration, task: F)
+    where
+        F: Fn() -> Fut + Send + Sync + 'static,
+        Fut: Future<Output = ()> + Send + 'static,
+    {
+        let period = chrono::Duration::from_std(period).unwrap_or(chrono::Duration::seconds(1));
+        self.push_job(name.into(), JobSchedule::Interval(period), task);
+    }
+
+    fn push_job<F, Fut>(&mut self, name: String, schedule: JobSchedule, task: F)
+    where
+        F: Fn() -> Fut + Send + Sync + 'static,
+        Fut: Future<Output = ()> + Send + 'static,
+    {
+        self.jobs.push(Job {
+            name,
+            schedule,
+            task: Arc::new(move || Box::pin(task()) as BoxFuture),
+            running: Arc::new(AtomicBool::new(false)),
+        });
+    }
+
+    /// Spawns the scheduler loop and returns a handle to stop it, for callers (e.g. an example
+    /// or a test) that need to tear it down rather than let it run for the process
+    /// lifetime. `main.rs` itself still just does `tokio::spawn(scheduler.run())` when it doesn't
+    /// need the handle.
+    pub fn start(self) -> SchedulerHandle {
+        SchedulerHandle { task: tokio::spawn(self.run()) }
+    }
+
+    /// Runs the scheduler loop forever. Intended to be handed to `tokio::spawn` (or use
+    /// [`Self::start`] to get a stoppable handle back).
+    ///
+    /// On entry, any `Interval` job fires immediately -- a fresh process has no record of a
+    /// previous run, so the first tick is always "overdue" by definition, giving catch-up
+    /// semantics for free: a job interrupted by a restart mid-period resumes right away instead
+    /// of waiting out a full fresh period. `Cron` jobs are unaffected and wait for their next
+    /// wall-clock occurrence as before.
+    pub async fn run(self) {
+        if self.jobs.is_empty() {
+            warn!("scheduler: no jobs registered, exiting");
+            return;
+        }
+
+        let far_future = || Utc::now() + chrono::Duration::days(3650);
+        let now = Utc::now();
+        let mut next_fires: Vec<DateTime<Utc>> = self.jobs.iter()
+            .map(|job| match job.schedule {
+                JobSchedule::Interval(_) => now,
+                JobSchedule::Cron(_) => job.schedule.next_after(now).unwrap_or_else(far_future),
+            })
+            .collect();
+
+        loop {
+            let soonest = *next_fires.iter().min().expect("jobs is non-empty");
+            let now = Utc::now();
+            if soonest > now {
+                tokio::time::sleep((soonest - now).to_std().unwrap_or(std::time::Duration::from_secs(1))).await;
+            }
+
+            let fire_time = Utc::now();
+            for (job, next_fire) in self.jobs.iter().zip(next_fires.iter_mut()) {
+                if *next_fire <= fire_time {
+                    *next_fire = job.schedule.next_after(fire_time).unwrap_or_else(far_future);
+                    Self::fire(job);
+                }
+            }
+        }
+    }
+
+    fn fire(job: &Job) {
+        if job.running.swap(true, Ordering::SeqCst) {
+            warn!("scheduler: skipping \"{}\", previous run still in flight", job.name);
+            return;
+        }
+
+        let task = job.task.clone();
+        let running = job.running.clone();
+        let name = job.name.clone();
+        tokio::spawn(async move {
+            info!("scheduler: running job \"{name}\"");
+            task().await;
+            running.store(false, Ordering::SeqCst);
+        });
+    }
+}
@@ -0,0 +1,126 @@
+use std::collections::{HashMap, HashSet, VecDeque};
+use std::time::Duration;
+
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+use tokio::sync::RwLock;
+
+/// Width of each mention bucket.
+const BUCKET_WIDTH: chrono::Duration = chrono::Duration::minutes(15);
+/// How long buckets are kept before eviction.
+const RETENTION: chrono::Duration = chrono::Duration::hours(24);
+
+struct Bucket {
+    start: DateTime<Utc>,
+    counts: HashMap<String, u32>,
+}
+
+/// One ticker's mention velocity within a `TrendStore::trending` query: the ratio of mentions in
+/// the most recent window to the mean mentions per window over the rest of the retention horizon.
+#[derive(Debug, Clone, Serialize, utoipa::ToSchema)]
+pub struct TrendingTicker {
+    pub ticker: String,
+    pub recent_mentions: u32,
+    pub prior_mean_mentions: f64,
+    pub velocity: f64,
+}
+
+/// Tracks how often each ticker is mentioned over time, bucketed into fixed 15-minute slots kept
+/// for 24h, so `trending` can rank tickers by mention *velocity* -- a surge relative to their own
+/// recent baseline -- instead of the flat membership set `sources::reddit_data::get_reddit_trending_stocks`
+/// used to return. Mirrors the ring-buffer-with-retention shape of `services::health::IncidentLog`.
+pub struct TrendStore {
+    buckets: RwLock<VecDeque<Bucket>>,
+}
+
+impl TrendStore {
+    pub fn new() -> Self {
+        Self { buckets: RwLock::new(VecDeque::new()) }
+    }
+
+    /// Merges mention counts from one scrape into the current bucket (starting a new one if the
+    /// most recent bucket has aged out of `BUCKET_WIDTH`), then evicts buckets older than the
+    /// retention horizon. Takes the whole batch under one lock acquisition instead of one per
+    /// ticker, since this runs once per scrape.
+    pub async fn record_many(&self, mentions: &HashMap<String, u32>, now: DateTime<Utc>) {
+        let mut buckets = self.buckets.write().await;
+        let bucket_start = Self::bucket_start(now);
+
+        match buckets.back_mut() {
+            Some(b) if b.start == bucket_start => {
+                for (ticker, count) in mentions {
+                    *b.counts.entry(ticker.clone()).or_insert(0) += count;
+                }
+            }
+            _ => {
+                buckets.push_back(Bucket { start: bucket_start, counts: mentions.clone() });
+            }
+        }
+
+        let cutoff = now - RETENTION;
+        while buckets.front().map(|b| b.start < cutoff).unwrap_or(false) {
+            buckets.pop_front();
+        }
+    }
+
+    fn bucket_start(at: DateTime<Utc>) -> DateTime<Utc> {
+        let width_secs = BUCKET_WIDTH.num_seconds();
+        let bucket_secs = (at.timestamp().div_euclid(width_secs)) * width_secs;
+        DateTime::from_timestamp(bucket_secs, 0).unwrap_or(at)
+    }
+
+    /// Ranks tickers by mention velocity: mentions within the last `window` versus the mean
+    /// mentions per `window`-sized slice over the remaining retained history. A ticker with no
+    /// prior mentions has no baseline to divide by, so it's ranked by its raw recent count instead
+    /// of an infinite ratio (which wouldn't serialize as JSON cleanly anyway).
+    pub async fn trending(&self, window: Duration, limit: usize, now: DateTime<Utc>) -> Vec<TrendingTicker> {
+        let window = chrono::Duration::from_std(window).unwrap_or(BUCKET_WIDTH);
+        let recent_cutoff = now - window;
+
+        let buckets = self.buckets.read().await;
+        let mut recent: HashMap<String, u32> = HashMap::new();
+        let mut prior: HashMap<String, u32> = HashMap::new();
+        let mut prior_span = chrono::Duration::zero();
+
+        for bucket in buckets.iter() {
+            if bucket.start >= recent_cutoff {
+                for (ticker, count) in &bucket.counts {
+                    *recent.entry(ticker.clone()).or_insert(0) += count;
+                }
+            } else {
+                for (ticker, count) in &bucket.counts {
+                    *prior.entry(ticker.clone()).or_insert(0) += count;
+                }
+                prior_span += BUCKET_WIDTH;
+            }
+        }
+
+        let prior_windows = (prior_span.num_seconds() as f64 / window.num_seconds().max(1) as f64).max(1.0);
+
+        let tickers: HashSet<&String> = recent.keys().chain(prior.keys()).collect();
+        let mut ranked: Vec<TrendingTicker> = tickers.into_iter()
+            .map(|ticker| {
+                let recent_mentions = *recent.get(ticker).unwrap_or(&0);
+                let prior_total = *prior.get(ticker).unwrap_or(&0);
+                let prior_mean_mentions = prior_total as f64 / prior_windows;
+                let velocity = if prior_mean_mentions > 0.0 {
+                    recent_mentions as f64 / prior_mean_mentions
+                } else {
+                    recent_mentions as f64
+                };
+
+                TrendingTicker { ticker: ticker.clone(), recent_mentions, prior_mean_mentions, velocity }
+            })
+            .collect();
+
+        ranked.sort_by(|a, b| b.velocity.partial_cmp(&a.velocity).unwrap_or(std::cmp::Ordering::Equal));
+        ranked.truncate(limit);
+        ranked
+    }
+}
+
+impl Default for TrendStore {
+    fn default() -> Self {
+        Self::new()
+    }
+}
@@ -1,79 +1,181 @@
 use time::{Duration, OffsetDateTime};
 use yahoo_finance_api::YahooConnector;
+use serde::{Deserialize, Serialize};
 use serde_json::json;
+use lazy_static::lazy_static;
+use utoipa::ToSchema;
 
 use crate::helpers::metrics;
 use crate::cache::{MemoryCache, cache_key};
+use crate::config::RetryConfig;
+use crate::utils::with_retry;
 
-pub async fn fetch_prices_for_symbol(
+/// One Yahoo OHLCV bar for a single symbol, as returned by `get_quote_history`. Built one per
+/// quote row rather than as parallel open/high/low/close/volume vectors, so there's no risk of
+/// the arrays diverging in length the way the old close-only path had to separately guard against.
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct Candle {
+    pub timestamp: i64,
+    pub open: f64,
+    pub high: f64,
+    pub low: f64,
+    pub close: f64,
+    pub volume: f64,
+}
+
+lazy_static! {
+    /// Process-wide retry policy for Yahoo calls, read once from `RETRY_*` env vars (see
+    /// [`RetryConfig::from_env`]) so operators can tune it per environment without a redeploy-time
+    /// code change.
+    static ref YAHOO_RETRY_POLICY: RetryConfig = RetryConfig::from_env();
+}
+
+/// Retries only errors from the network hop itself ("yahoo history error"); a malformed or
+/// too-short response is a property of the data, not a transient blip, and retrying it would just
+/// waste the policy's budget for no chance of a different outcome.
+fn is_permanent_yahoo_error(err: &String) -> bool {
+    !err.starts_with("yahoo history error")
+}
+
+/// Intraday interval labels served via `get_latest_quotes`, as opposed to the day-granularity
+/// duration labels ("1mo".."5y") served via `get_quote_history`.
+const INTRADAY_INTERVAL_LABELS: [&str; 4] = ["1m", "5m", "15m", "1h"];
+
+fn is_intraday_interval(label: &str) -> bool {
+    INTRADAY_INTERVAL_LABELS.contains(&label)
+}
+
+pub async fn fetch_candles_for_symbol(
     provider: &YahooConnector,
     symbol: &str,
     range_label: &str,
-) -> Result<Vec<f64>, String> {
-    let now = OffsetDateTime::now_utc();
-    let start = match range_label {
-        "1mo" => now - Duration::days(30),
-        "3mo" => now - Duration::days(90),
-        "6mo" => now - Duration::days(180),
-        "1y" => now - Duration::days(365),
-        "2y" => now - Duration::days(730),
-        "5y" => now - Duration::days(365 * 5),
-        _ => now - Duration::days(30),
+) -> Result<Vec<Candle>, String> {
+    let resp = if is_intraday_interval(range_label) {
+        with_retry(
+            &YAHOO_RETRY_POLICY,
+            &format!("yahoo latest quotes for {symbol} ({range_label})"),
+            is_permanent_yahoo_error,
+            || async {
+                provider
+                    .get_latest_quotes(symbol, range_label)
+                    .await
+                    .map_err(|e| format!("yahoo history error: {e}"))
+            },
+        )
+        .await?
+    } else {
+        let now = OffsetDateTime::now_utc();
+        let start = match range_label {
+            "1mo" => now - Duration::days(30),
+            "3mo" => now - Duration::days(90),
+            "6mo" => now - Duration::days(180),
+            "1y" => now - Duration::days(365),
+            "2y" => now - Duration::days(730),
+            "5y" => now - Duration::days(365 * 5),
+            _ => now - Duration::days(30),
+        };
+        with_retry(
+            &YAHOO_RETRY_POLICY,
+            &format!("yahoo history for {symbol}"),
+            is_permanent_yahoo_error,
+            || async {
+                provider
+                    .get_quote_history(symbol, start, now)
+                    .await
+                    .map_err(|e| format!("yahoo history error: {e}"))
+            },
+        )
+        .await?
     };
-    let resp = provider
-        .get_quote_history(symbol, start, now)
-        .await
-        .map_err(|e| format!("yahoo history error: {e}"))?;
     let quotes = resp
         .quotes()
         .map_err(|e| format!("quotes parse error: {e}"))?;
     if quotes.len() < 2 {
         return Err("not enough quotes".to_string());
     }
-    Ok(quotes.into_iter().map(|q| q.close).collect())
+
+    Ok(quotes
+        .into_iter()
+        .map(|q| Candle {
+            timestamp: q.timestamp as i64,
+            open: q.open,
+            high: q.high,
+            low: q.low,
+            close: q.close,
+            volume: q.volume as f64,
+        })
+        .collect())
 }
 
-pub async fn fetch_prices_for_symbol_cached(
+/// Thin adapter over [`fetch_candles_for_symbol`] for callers that only need the close series
+/// (most metrics don't need range or volume).
+pub async fn fetch_prices_for_symbol(
     provider: &YahooConnector,
     symbol: &str,
     range_label: &str,
-    cache: &MemoryCache,
 ) -> Result<Vec<f64>, String> {
-    let cache_key = cache_key("yahoo_prices", &[("symbol", symbol), ("range", range_label)]);
-    
-    if let Some(cached) = cache.get(&cache_key).await {
-        if let Some(prices_array) = cached.as_array() {
-            let prices: Result<Vec<f64>, _> = prices_array
-                .iter()
-                .map(|v| v.as_f64().ok_or("Invalid cached price data"))
-                .collect();
-            if let Ok(prices) = prices {
-                return Ok(prices);
-            }
-        }
-    }
+    let candles = fetch_candles_for_symbol(provider, symbol, range_label).await?;
+    Ok(candles.into_iter().map(|c| c.close).collect())
+}
 
-    let prices = fetch_prices_for_symbol(provider, symbol, range_label).await?;
-    
-    let cache_ttl = match range_label {
+fn cache_ttl_for_range(range_label: &str) -> std::time::Duration {
+    match range_label {
+        "1m" => std::time::Duration::from_secs(30),
+        "5m" | "15m" => std::time::Duration::from_secs(60),
+        "1h" => std::time::Duration::from_secs(300),
         "1mo" | "3mo" => std::time::Duration::from_secs(300), // 5 minutes
         "6mo" | "1y" => std::time::Duration::from_secs(900), // 15 minutes
         _ => std::time::Duration::from_secs(1800), // 30 minutes
-    };
-    
-    let cache_data = json!(prices);
-    cache.set(cache_key, cache_data, cache_ttl).await;
-    
-    Ok(prices)
+    }
+}
+
+pub async fn fetch_candles_for_symbol_cached(
+    provider: &YahooConnector,
+    symbol: &str,
+    range_label: &str,
+    cache: &MemoryCache,
+) -> Result<Vec<Candle>, String> {
+    let cache_key = cache_key("yahoo_candles", &[("symbol", symbol), ("range", range_label)]);
+
+    if let Some(cached) = cache.get(&cache_key).await {
+        if let Ok(candles) = serde_json::from_value::<Vec<Candle>>(cached) {
+            return Ok(candles);
+        }
+    }
+
+    let candles = fetch_candles_for_symbol(provider, symbol, range_label).await?;
+    cache.set(cache_key, json!(candles), cache_ttl_for_range(range_label)).await;
+
+    Ok(candles)
+}
+
+/// Thin adapter over [`fetch_candles_for_symbol_cached`] for callers that only need the close
+/// series.
+pub async fn fetch_prices_for_symbol_cached(
+    provider: &YahooConnector,
+    symbol: &str,
+    range_label: &str,
+    cache: &MemoryCache,
+) -> Result<Vec<f64>, String> {
+    let candles = fetch_candles_for_symbol_cached(provider, symbol, range_label, cache).await?;
+    Ok(candles.into_iter().map(|c| c.close).collect())
 }
 
 pub async fn latest_close(provider: &YahooConnector, symbol: &str) -> Result<f64, String> {
     let now = OffsetDateTime::now_utc();
     let start = now - Duration::days(10);
-    let resp = provider
-        .get_quote_history(symbol, start, now)
-        .await
-        .map_err(|e| format!("yahoo history error: {e}"))?;
+    let resp = with_retry(
+        &YAHOO_RETRY_POLICY,
+        &format!("yahoo history for {symbol}"),
+        is_permanent_yahoo_error,
+        || async {
+            provider
+                .get_quote_history(symbol, start, now)
+                .await
+                .map_err(|e| format!("yahoo history error: {e}"))
+        },
+    )
+    .await?;
     let quotes = resp
         .quotes()
         .map_err(|e| format!("quotes parse error: {e}"))?;
@@ -94,6 +196,18 @@ pub fn metrics_for_prices(
     metrics::compute_metrics_from_returns(&returns, rf_annual, target_annual, periods_per_year, weights)
 }
 
+/// Candle-aware counterpart to [`metrics_for_prices`]: same risk/return metrics, plus `atr` and
+/// `avg_volume` computed from the full OHLCV series (see [`metrics::compute_metrics_from_candles`]).
+pub fn metrics_for_candles(
+    candles: &[Candle],
+    rf_annual: f64,
+    target_annual: f64,
+    periods_per_year: usize,
+    weights: Option<metrics::CompositeWeights>,
+) -> metrics::MetricsResult {
+    metrics::compute_metrics_from_candles(candles, rf_annual, target_annual, periods_per_year, weights)
+}
+
 pub async fn fetch_prices_for_symbol_default(symbol: &str, range_label: &str) -> Result<Vec<f64>, String> {
     let provider = YahooConnector::new().map_err(|e| format!("yahoo connector error: {e}"))?;
     fetch_prices_for_symbol(&provider, symbol, range_label).await
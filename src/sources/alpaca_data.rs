@@ -1,6 +1,130 @@
+use lazy_static::lazy_static;
 use serde_json::Value;
 use reqwest::Client;
+use serde::Deserialize;
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::sync::Semaphore;
+use crate::config::RetryConfig;
+use crate::errors::ApiError;
+use crate::http_client::{is_retryable_status, parse_retry_after, RetryPolicy};
+use crate::sources::market_source::{
+    MarketDataSource, UnifiedCandle, UnifiedMarket, UnifiedOrderbook, UnifiedOrderbookLevel, UnifiedTrade,
+};
 use crate::types::OptionsQuery;
+use crate::utils::with_retry;
+
+lazy_static! {
+    /// Process-wide retry policy for the free-function Alpaca REST calls below, read once from
+    /// `RETRY_*` env vars (see [`RetryConfig::from_env`]) so operators can tune it per environment
+    /// without a redeploy.
+    static ref ALPACA_RETRY_POLICY: RetryConfig = RetryConfig::from_env();
+
+    /// Shared rate-limited client for the options-contracts endpoints
+    /// (`helpers::high_open_interest`), reusing one pooled [`Client`] instead of each call
+    /// building its own, and bounding request rate to what Alpaca's documented limit allows.
+    pub(crate) static ref ALPACA_OPTIONS_CLIENT: AlpacaRateLimitedClient =
+        AlpacaRateLimitedClient::new(alpaca_rate_limit_per_sec());
+}
+
+/// A missing credential or a `4xx` response (bad symbol, bad auth) won't fix itself on retry --
+/// only a `"status 5"`/`"status 429"` or a network-level `"req error"` is worth spending the
+/// retry budget on, same split `sources::reddit_data`/`services::yahoo` draw for their own errors.
+fn is_permanent_alpaca_error(err: &String) -> bool {
+    if err.contains("status 429") {
+        return false;
+    }
+    err.contains("missing")
+        || err.contains("status 4")
+        || (err.contains("status") && !err.contains("status 5") && !err.contains("status 429"))
+}
+
+fn alpaca_rate_limit_per_sec() -> usize {
+    std::env::var("ALPACA_OPTIONS_RATE_LIMIT_PER_SEC")
+        .ok()
+        .and_then(|v| v.parse().ok())
+        .unwrap_or(3)
+}
+
+/// One shared [`Client`] plus a token-bucket limiter (refilled to `permits_per_second` once a
+/// second), so a batch of contract/price lookups shares connection pooling and stays under
+/// Alpaca's documented per-second rate instead of each call building a fresh client and tripping
+/// 429s. A 429 is retried with the `Retry-After` header honored (falling back to
+/// [`RetryPolicy::backoff`]'s exponential-with-jitter schedule) rather than immediately erroring
+/// out and dropping the caller's ticker.
+pub(crate) struct AlpacaRateLimitedClient {
+    client: Client,
+    permits: Arc<Semaphore>,
+}
+
+impl AlpacaRateLimitedClient {
+    fn new(permits_per_second: usize) -> Self {
+        let permits_per_second = permits_per_second.max(1);
+        let permits = Arc::new(Semaphore::new(permits_per_second));
+
+        let refill = permits.clone();
+        tokio::spawn(async move {
+            let mut interval = tokio::time::interval(Duration::from_secs(1));
+            loop {
+                interval.tick().await;
+                let available = refill.available_permits();
+                if available < permits_per_second {
+                    refill.add_permits(permits_per_second - available);
+                }
+            }
+        });
+
+        Self { client: Client::new(), permits }
+    }
+
+    /// GET `url` with `headers`, acquiring a rate-limit permit first and retrying transient
+    /// failures (429/5xx/network errors) per [`RetryPolicy::default`].
+    pub(crate) async fn get_json(&self, url: &str, headers: &[(&str, &str)]) -> Result<Value, String> {
+        let permit = self
+            .permits
+            .acquire()
+            .await
+            .map_err(|e| format!("rate limiter closed: {e}"))?;
+        // Consume the token; only the once-a-second refill task puts permits back.
+        permit.forget();
+
+        let policy = RetryPolicy::default();
+        let mut attempt = 0u32;
+        loop {
+            let mut req = self.client.get(url);
+            for (name, value) in headers {
+                req = req.header(*name, *value);
+            }
+
+            match req.send().await {
+                Ok(resp) => {
+                    if resp.status().is_success() {
+                        return resp.json::<Value>().await.map_err(|e| format!("json parse error: {e}"));
+                    }
+                    let retryable = is_retryable_status(resp.status());
+                    let retry_after = if resp.status().as_u16() == 429 { parse_retry_after(resp.headers()) } else { None };
+                    if !retryable || attempt + 1 >= policy.max_attempts {
+                        return Err(format!(
+                            "HTTP error: {} {} (after {} attempt(s))",
+                            resp.status(),
+                            resp.status().canonical_reason().unwrap_or(""),
+                            attempt + 1
+                        ));
+                    }
+                    tokio::time::sleep(retry_after.unwrap_or_else(|| policy.backoff(attempt))).await;
+                    attempt += 1;
+                }
+                Err(e) => {
+                    if attempt + 1 >= policy.max_attempts {
+                        return Err(format!("Network error: {e} (after {} attempt(s))", attempt + 1));
+                    }
+                    tokio::time::sleep(policy.backoff(attempt)).await;
+                    attempt += 1;
+                }
+            }
+        }
+    }
+}
 
 // Get News from Alpaca
 pub async fn get_alpaca_news() -> Result<Value, String> {
@@ -13,16 +137,23 @@ pub async fn get_alpaca_news() -> Result<Value, String> {
         .or_else(|_| std::env::var("APCA_API_SECRET_KEY"))
         .map_err(|_| "ALPACA_API_SECRET_KEY/APCA_API_SECRET_KEY missing".to_string())?;
     let client = Client::new();
-    let resp = client.get("https://data.alpaca.markets/v1beta1/news?sort=desc")
-        .header("APCA-API-KEY-ID", key)
-        .header("APCA-API-SECRET-KEY", secret)
-        .header("accept", "application/json")
-        .send()
-        .await
-        .map_err(|e| format!("alpaca news req error: {e}"))?;
-    if !resp.status().is_success() { return Err(format!("alpaca news status {}", resp.status())); }
-    let v = resp.json::<Value>().await.map_err(|e| format!("alpaca news json error: {e}"))?;
-    Ok(v)
+    with_retry(
+        &ALPACA_RETRY_POLICY,
+        "alpaca news",
+        is_permanent_alpaca_error,
+        || async {
+            let resp = client.get("https://data.alpaca.markets/v1beta1/news?sort=desc")
+                .header("APCA-API-KEY-ID", &key)
+                .header("APCA-API-SECRET-KEY", &secret)
+                .header("accept", "application/json")
+                .send()
+                .await
+                .map_err(|e| format!("alpaca news req error: {e}"))?;
+            if !resp.status().is_success() { return Err(format!("alpaca news status {}", resp.status())); }
+            resp.json::<Value>().await.map_err(|e| format!("alpaca news json error: {e}"))
+        },
+    )
+    .await
 }
 
 // Get Options from Alpaca
@@ -57,9 +188,21 @@ pub async fn fetch_alpaca_snapshots(symbol: &str, q: &OptionsQuery) -> Result<Va
         if let Some(v) = &q.root_symbol { qp.push(("root_symbol".into(), v.clone())); }
         if let Some(v) = &q.page_token { qp.push(("page_token".into(), v.clone())); }
         req = req.query(&qp);
-        let resp = req.send().await.map_err(|e| format!("alpaca req error: {e}"))?;
-        if !resp.status().is_success() { return Err(format!("alpaca status {}", resp.status())); }
-        resp.json::<Value>().await.map_err(|e| format!("alpaca json error: {e}"))
+        with_retry(
+            &ALPACA_RETRY_POLICY,
+            &format!("alpaca snapshots for {symbol}"),
+            is_permanent_alpaca_error,
+            || async {
+                let resp = req.try_clone()
+                    .ok_or_else(|| "alpaca req error: request is not cloneable".to_string())?
+                    .send()
+                    .await
+                    .map_err(|e| format!("alpaca req error: {e}"))?;
+                if !resp.status().is_success() { return Err(format!("alpaca status {}", resp.status())); }
+                resp.json::<Value>().await.map_err(|e| format!("alpaca json error: {e}"))
+            },
+        )
+        .await
     }
 
     let headers = (key.as_str(), secret.as_str());
@@ -68,10 +211,612 @@ pub async fn fetch_alpaca_snapshots(symbol: &str, q: &OptionsQuery) -> Result<Va
     do_request(symbol, headers, q, Some(feed)).await
 }
 
+/// Bar size for [`get_option_bars`], mapping to Alpaca's `timeframe` query parameter.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Deserialize, serde::Serialize, utoipa::ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum Resolution {
+    OneMinute,
+    FiveMinute,
+    OneHour,
+    OneDay,
+}
+
+impl Resolution {
+    fn as_timeframe(self) -> &'static str {
+        match self {
+            Resolution::OneMinute => "1Min",
+            Resolution::FiveMinute => "5Min",
+            Resolution::OneHour => "1Hour",
+            Resolution::OneDay => "1Day",
+        }
+    }
+}
+
+/// One OHLCV bar from Alpaca's options bars endpoint.
+#[derive(Debug, Clone, serde::Deserialize, serde::Serialize, utoipa::ToSchema)]
+pub struct OptionBar {
+    /// RFC3339 bar open timestamp.
+    pub t: String,
+    pub o: f64,
+    pub h: f64,
+    pub l: f64,
+    pub c: f64,
+    pub v: u64,
+    pub vw: Option<f64>,
+}
+
+/// Fetches historical OHLCV bars for a single option contract from Alpaca's options bars
+/// endpoint (`/v1beta1/options/bars`), paging through `next_page_token` and stitching each
+/// page's bars together in chronological order so a caller can request an arbitrary `start`/`end`
+/// span and get the complete series back from one call instead of juggling pagination
+/// themselves.
+pub async fn get_option_bars(
+    contract_symbol: &str,
+    resolution: Resolution,
+    start: &str,
+    end: &str,
+) -> Result<Vec<OptionBar>, String> {
+    let key = std::env::var("ALPACA_API_KEY_ID")
+        .or_else(|_| std::env::var("APCA_API_KEY_ID"))
+        .map_err(|_| "ALPACA_API_KEY_ID/APCA_API_KEY_ID missing".to_string())?;
+    let secret = std::env::var("ALPACA_API_SECRET_KEY")
+        .or_else(|_| std::env::var("APCA_API_SECRET_KEY"))
+        .map_err(|_| "ALPACA_API_SECRET_KEY/APCA_API_SECRET_KEY missing".to_string())?;
+
+    let client = Client::new();
+    let timeframe = resolution.as_timeframe();
+    let mut bars: Vec<OptionBar> = Vec::new();
+    let mut page_token: Option<String> = None;
+
+    loop {
+        let mut qp: Vec<(String, String)> = vec![
+            ("symbols".to_string(), contract_symbol.to_string()),
+            ("timeframe".to_string(), timeframe.to_string()),
+            ("start".to_string(), start.to_string()),
+            ("end".to_string(), end.to_string()),
+            ("limit".to_string(), "1000".to_string()),
+        ];
+        if let Some(token) = &page_token {
+            qp.push(("page_token".to_string(), token.clone()));
+        }
+
+        let data: Value = with_retry(
+            &ALPACA_RETRY_POLICY,
+            &format!("alpaca option bars for {contract_symbol}"),
+            is_permanent_alpaca_error,
+            || async {
+                let resp = client.get("https://data.alpaca.markets/v1beta1/options/bars")
+                    .header("APCA-API-KEY-ID", &key)
+                    .header("APCA-API-SECRET-KEY", &secret)
+                    .header("accept", "application/json")
+                    .query(&qp)
+                    .send()
+                    .await
+                    .map_err(|e| format!("alpaca option bars req error: {e}"))?;
+                if !resp.status().is_success() { return Err(format!("alpaca option bars status {}", resp.status())); }
+                resp.json::<Value>().await.map_err(|e| format!("alpaca option bars json error: {e}"))
+            },
+        )
+        .await?;
+
+        if let Some(page_bars) = data.get("bars").and_then(|b| b.get(contract_symbol)).and_then(|v| v.as_array()) {
+            for bar_value in page_bars {
+                if let Ok(bar) = serde_json::from_value::<OptionBar>(bar_value.clone()) {
+                    bars.push(bar);
+                }
+            }
+        }
+
+        page_token = data.get("next_page_token").and_then(|v| v.as_str()).map(|s| s.to_string());
+        if page_token.is_none() {
+            break;
+        }
+    }
+
+    // Already returned in chronological order per page; the loop above appends pages in
+    // request order, so a simple stable sort by timestamp guards against any out-of-order
+    // stitching across page boundaries.
+    bars.sort_by(|a, b| a.t.cmp(&b.t));
+
+    Ok(bars)
+}
+
+#[derive(Debug, Clone, serde::Deserialize, utoipa::ToSchema)]
+pub struct AlpacaOrderRequest {
+    /// Contract symbol (e.g. an OCC option symbol) or equity ticker to trade.
+    pub symbol: String,
+    pub qty: f64,
+    /// "buy" or "sell".
+    pub side: String,
+    /// "market" or "limit" (default "limit").
+    pub order_type: Option<String>,
+    /// Required for limit orders: a limit order without it is rejected with an error; no price
+    /// is filled in from a quote.
+    pub limit_price: Option<f64>,
+    /// Alpaca time-in-force, e.g. "day", "gtc" (default "day").
+    pub time_in_force: Option<String>,
+    /// Routes to the paper trading endpoint when true (default true).
+    pub paper: Option<bool>,
+}
+
+// Submit an order to Alpaca's trading API (paper or live)
+pub async fn submit_alpaca_order(order: &AlpacaOrderRequest) -> Result<Value, String> {
+    let key = std::env::var("ALPACA_API_KEY_ID")
+        .or_else(|_| std::env::var("APCA_API_KEY_ID"))
+        .map_err(|_| "ALPACA_API_KEY_ID/APCA_API_KEY_ID missing".to_string())?;
+    let secret = std::env::var("ALPACA_API_SECRET_KEY")
+        .or_else(|_| std::env::var("APCA_API_SECRET_KEY"))
+        .map_err(|_| "ALPACA_API_SECRET_KEY/APCA_API_SECRET_KEY missing".to_string())?;
+
+    let paper = order.paper.unwrap_or(true);
+    let base = if paper { "https://paper-api.alpaca.markets" } else { "https://api.alpaca.markets" };
+    let order_type = order.order_type.as_deref().unwrap_or("limit");
+    let time_in_force = order.time_in_force.as_deref().unwrap_or("day");
+
+    let mut body = serde_json::json!({
+        "symbol": order.symbol,
+        "qty": order.qty.to_string(),
+        "side": order.side,
+        "type": order_type,
+        "time_in_force": time_in_force,
+    });
+    if order_type == "limit" {
+        let limit_price = order.limit_price.ok_or_else(|| "limit_price is required for limit orders".to_string())?;
+        body["limit_price"] = serde_json::json!(limit_price.to_string());
+    }
+
+    let resp = Client::new()
+        .post(format!("{base}/v2/orders"))
+        .header("APCA-API-KEY-ID", key)
+        .header("APCA-API-SECRET-KEY", secret)
+        .header("accept", "application/json")
+        .json(&body)
+        .send()
+        .await
+        .map_err(|e| format!("alpaca order req error: {e}"))?;
+    if !resp.status().is_success() {
+        let status = resp.status();
+        let text = resp.text().await.unwrap_or_default();
+        return Err(format!("alpaca order status {status}: {text}"));
+    }
+    resp.json::<Value>().await.map_err(|e| format!("alpaca order json error: {e}"))
+}
+
+/// One fill from Alpaca's `/v2/account/activities/FILL` endpoint -- the subset of fields the
+/// ledger export in [`crate::helpers::ledger`] needs. Alpaca returns `qty`/`price` as numeric
+/// strings, so they stay `String` here and are parsed where they're used, unlike
+/// `sources::binance_futures`, which parses Binance's numeric strings with a field-level deserializer.
+#[derive(Debug, Clone, serde::Deserialize, utoipa::ToSchema)]
+pub struct AlpacaActivity {
+    pub id: String,
+    pub activity_type: String,
+    pub transaction_time: String,
+    pub symbol: String,
+    /// "buy" or "sell".
+    pub side: String,
+    pub qty: String,
+    pub price: String,
+}
+
+/// Fetch every `FILL` activity in `[after, until]` (RFC3339 timestamps, either bound optional),
+/// paginating via `page_token` until a short page signals the last one -- mirroring how
+/// `fetch_alpaca_snapshots` threads `page_token` through for options snapshots.
+pub async fn get_account_fills(after: Option<&str>, until: Option<&str>, paper: bool) -> Result<Vec<AlpacaActivity>, String> {
+    let key = std::env::var("ALPACA_API_KEY_ID")
+        .or_else(|_| std::env::var("APCA_API_KEY_ID"))
+        .map_err(|_| "ALPACA_API_KEY_ID/APCA_API_KEY_ID missing".to_string())?;
+    let secret = std::env::var("ALPACA_API_SECRET_KEY")
+        .or_else(|_| std::env::var("APCA_API_SECRET_KEY"))
+        .map_err(|_| "ALPACA_API_SECRET_KEY/APCA_API_SECRET_KEY missing".to_string())?;
+
+    let base = if paper { "https://paper-api.alpaca.markets" } else { "https://api.alpaca.markets" };
+
+    const PAGE_SIZE: usize = 100;
+    let mut activities = Vec::new();
+    let mut page_token: Option<String> = None;
+    loop {
+        let mut qp: Vec<(String, String)> = vec![
+            ("direction".into(), "asc".into()),
+            ("page_size".into(), PAGE_SIZE.to_string()),
+        ];
+        if let Some(a) = after { qp.push(("after".into(), a.to_string())); }
+        if let Some(u) = until { qp.push(("until".into(), u.to_string())); }
+        if let Some(pt) = &page_token { qp.push(("page_token".into(), pt.clone())); }
+
+        let resp = Client::new()
+            .get(format!("{base}/v2/account/activities/FILL"))
+            .header("APCA-API-KEY-ID", &key)
+            .header("APCA-API-SECRET-KEY", &secret)
+            .header("accept", "application/json")
+            .query(&qp)
+            .send()
+            .await
+            .map_err(|e| format!("alpaca activities req error: {e}"))?;
+        if !resp.status().is_success() {
+            let status = resp.status();
+            let text = resp.text().await.unwrap_or_default();
+            return Err(format!("alpaca activities status {status}: {text}"));
+        }
+        let page: Vec<AlpacaActivity> = resp
+            .json()
+            .await
+            .map_err(|e| format!("alpaca activities json error: {e}"))?;
+
+        let page_len = page.len();
+        page_token = page.last().map(|a| a.id.clone());
+        activities.extend(page);
+        if page_len < PAGE_SIZE {
+            break;
+        }
+    }
+    Ok(activities)
+}
+
+const ALPACA_WS_URL: &str = "wss://stream.data.alpaca.markets/v2/iex";
+
+/// Shares one upstream WebSocket connection to Alpaca's market-data feed across every SSE
+/// subscriber, the same way [`crate::sources::kraken_data::KrakenWsHub`] shares a connection
+/// per pair -- except Alpaca multiplexes every symbol over a single socket, so this hub keeps
+/// exactly one connection open and grows its subscription set as new symbols are requested,
+/// rather than opening one connection per symbol.
+pub struct AlpacaWsHub {
+    tx: tokio::sync::broadcast::Sender<Value>,
+    subscribed: tokio::sync::Mutex<std::collections::HashSet<String>>,
+    new_symbols: tokio::sync::mpsc::UnboundedSender<Vec<String>>,
+}
+
+impl AlpacaWsHub {
+    /// Spawns the background connection task immediately and returns the hub; the task sits
+    /// idle (connected, no subscriptions) until the first [`AlpacaWsHub::subscribe`] call.
+    pub fn new(api_key: String, api_secret: String, retry: crate::config::RetryConfig) -> std::sync::Arc<Self> {
+        let (tx, _rx) = tokio::sync::broadcast::channel(256);
+        let (new_symbols_tx, new_symbols_rx) = tokio::sync::mpsc::unbounded_channel();
+
+        let hub = std::sync::Arc::new(Self {
+            tx,
+            subscribed: tokio::sync::Mutex::new(std::collections::HashSet::new()),
+            new_symbols: new_symbols_tx,
+        });
+
+        tokio::spawn(hub.clone().connect_and_publish(api_key, api_secret, retry, new_symbols_rx));
+        hub
+    }
+
+    /// Subscribe to live trade/quote updates for `symbols`, adding any not already subscribed
+    /// to the shared upstream connection. Returns a stream filtered down to just `symbols`.
+    pub fn subscribe(
+        self: std::sync::Arc<Self>,
+        symbols: Vec<crate::types::TickerSymbol>,
+    ) -> impl futures::Stream<Item = Value> {
+        let wanted: std::collections::HashSet<String> =
+            symbols.iter().map(|s| s.as_str().to_string()).collect();
+        let _ = self.new_symbols.send(wanted.iter().cloned().collect());
+
+        let mut rx = self.tx.subscribe();
+        async_stream::stream! {
+            loop {
+                match rx.recv().await {
+                    Ok(value) => {
+                        let symbol = value.get("S").and_then(|s| s.as_str()).unwrap_or_default();
+                        if wanted.contains(symbol) {
+                            yield value;
+                        }
+                    }
+                    Err(tokio::sync::broadcast::error::RecvError::Lagged(_)) => continue,
+                    Err(tokio::sync::broadcast::error::RecvError::Closed) => break,
+                }
+            }
+        }
+    }
+
+    fn subscribe_frame(symbols: &std::collections::HashSet<String>) -> Value {
+        let symbols: Vec<&str> = symbols.iter().map(|s| s.as_str()).collect();
+        serde_json::json!({ "action": "subscribe", "trades": symbols, "quotes": symbols })
+    }
+
+    /// Holds the upstream WebSocket connection open, authenticating and re-sending the full
+    /// accumulated subscription set on every (re)connect, forwarding every trade/quote message
+    /// to `tx` and adding newly-requested symbols (received over `new_symbols_rx`) to the live
+    /// subscription without dropping the connection. Reconnects with exponential backoff
+    /// (capped by `retry`) on disconnect or connect failure.
+    async fn connect_and_publish(
+        self: std::sync::Arc<Self>,
+        api_key: String,
+        api_secret: String,
+        retry: crate::config::RetryConfig,
+        mut new_symbols_rx: tokio::sync::mpsc::UnboundedReceiver<Vec<String>>,
+    ) {
+        use futures::{SinkExt, StreamExt};
+        use tokio_tungstenite::tungstenite::Message;
+
+        let mut attempt = 0u32;
+        loop {
+            match tokio_tungstenite::connect_async(ALPACA_WS_URL).await {
+                Ok((mut ws, _)) => {
+                    attempt = 0;
+
+                    let auth = serde_json::json!({ "action": "auth", "key": api_key, "secret": api_secret });
+                    if let Err(e) = ws.send(Message::Text(auth.to_string().into())).await {
+                        tracing::warn!("alpaca ws: auth send failed: {e}");
+                    } else {
+                        let already_subscribed = self.subscribed.lock().await.clone();
+                        if !already_subscribed.is_empty() {
+                            let frame = Self::subscribe_frame(&already_subscribed);
+                            let _ = ws.send(Message::Text(frame.to_string().into())).await;
+                        }
+
+                        loop {
+                            tokio::select! {
+                                msg = ws.next() => {
+                                    match msg {
+                                        Some(Ok(Message::Text(text))) => {
+                                            if let Ok(Value::Array(messages)) = serde_json::from_str::<Value>(&text) {
+                                                for message in messages {
+                                                    let _ = self.tx.send(message);
+                                                }
+                                            }
+                                        }
+                                        Some(Ok(_)) => continue,
+                                        Some(Err(e)) => {
+                                            tracing::warn!("alpaca ws: connection error: {e}");
+                                            break;
+                                        }
+                                        None => break,
+                                    }
+                                }
+                                new_symbols = new_symbols_rx.recv() => {
+                                    match new_symbols {
+                                        Some(symbols) => {
+                                            let mut subscribed = self.subscribed.lock().await;
+                                            let fresh: Vec<String> = symbols
+                                                .into_iter()
+                                                .filter(|s| subscribed.insert(s.clone()))
+                                                .collect();
+                                            if !fresh.is_empty() {
+                                                let frame = Self::subscribe_frame(&fresh.into_iter().collect());
+                                                let _ = ws.send(Message::Text(frame.to_string().into())).await;
+                                            }
+                                        }
+                                        None => return,
+                                    }
+                                }
+                            }
+                        }
+                    }
+                }
+                Err(e) => {
+                    tracing::warn!("alpaca ws: connect failed: {e}");
+                }
+            }
+
+            let exp_ms = retry.base_delay_ms
+                .saturating_mul(1u64 << attempt.min(16))
+                .min(retry.max_delay_ms);
+            tokio::time::sleep(std::time::Duration::from_millis(exp_ms.max(retry.base_delay_ms))).await;
+            attempt += 1;
+        }
+    }
+}
+
 // Get Stocks from Alpaca
 
 // Get Crypto from Alpaca
 
 // Get Forex from Alpaca
 
-// Get Futures from Alpaca
\ No newline at end of file
+// Get Futures from Alpaca
+
+const ALPACA_DATA_URL: &str = "https://data.alpaca.markets";
+
+/// Alpaca has no single "list every market" endpoint the way Hyperliquid/Coinbase do (its
+/// `/v2/assets` lists every tradable equity, not just liquid ones), so -- like
+/// `routes::coingecko::our_markets`'s fixed pump.fun set -- this is a fixed watchlist of liquid
+/// large-caps and index ETFs used to seed [`AlpacaDataSource::get_all_markets`].
+const WATCHLIST: &[&str] = &["AAPL", "MSFT", "GOOGL", "AMZN", "TSLA", "NVDA", "META", "SPY", "QQQ", "AMD"];
+
+/// Alpaca's equities market-data REST API, the stocks counterpart to
+/// [`crate::sources::hyperliquid_data::HyperliquidDataSource`] and
+/// [`crate::sources::coinbase_data::CoinbaseDataSource`]. Holds credentials read once at
+/// construction (via [`crate::config::Config::alpaca_headers`]) rather than re-reading the
+/// `ALPACA_*`/`APCA_*` env vars on every call like the free functions above -- empty credentials
+/// are tolerated here the same way `AlpacaWsHub::new` tolerates them, surfacing as an upstream
+/// 401 at request time rather than a startup failure.
+pub struct AlpacaDataSource {
+    client: Client,
+    api_key: String,
+    api_secret: String,
+}
+
+impl AlpacaDataSource {
+    pub fn new(api_key: String, api_secret: String) -> Self {
+        Self { client: Client::new(), api_key, api_secret }
+    }
+
+    fn authed(&self, builder: reqwest::RequestBuilder) -> reqwest::RequestBuilder {
+        builder
+            .header("APCA-API-KEY-ID", &self.api_key)
+            .header("APCA-API-SECRET-KEY", &self.api_secret)
+            .header("accept", "application/json")
+    }
+
+    /// `timeframe` query value Alpaca's bars endpoint accepts, mirroring
+    /// `CoinbaseDataSource::interval_to_granularity`'s label mapping.
+    fn interval_to_timeframe(label: &str) -> &'static str {
+        match label {
+            "1m" => "1Min",
+            "5m" => "5Min",
+            "15m" => "15Min",
+            "1h" => "1Hour",
+            "4h" => "4Hour",
+            "1d" => "1Day",
+            _ => "1Hour",
+        }
+    }
+}
+
+#[derive(Debug, Deserialize)]
+struct SnapshotBar {
+    o: f64,
+    h: f64,
+    l: f64,
+    c: f64,
+    v: f64,
+}
+
+#[derive(Debug, Deserialize)]
+struct SnapshotQuote {
+    bp: f64,
+    ap: f64,
+}
+
+#[derive(Debug, Deserialize, Default)]
+struct Snapshot {
+    #[serde(rename = "latestQuote")]
+    latest_quote: Option<SnapshotQuote>,
+    #[serde(rename = "dailyBar")]
+    daily_bar: Option<SnapshotBar>,
+    #[serde(rename = "prevDailyBar")]
+    prev_daily_bar: Option<SnapshotBar>,
+}
+
+#[derive(Debug, Deserialize)]
+struct RawBar {
+    t: String,
+    o: f64,
+    h: f64,
+    l: f64,
+    c: f64,
+    v: f64,
+}
+
+#[derive(Debug, Deserialize)]
+struct BarsResponse {
+    bars: Vec<RawBar>,
+}
+
+#[derive(Debug, Deserialize)]
+struct RawTrade {
+    t: String,
+    p: f64,
+    s: f64,
+}
+
+#[derive(Debug, Deserialize)]
+struct TradesResponse {
+    trades: Vec<RawTrade>,
+}
+
+/// Adapts [`AlpacaDataSource`] to [`MarketDataSource`] so cross-venue endpoints (e.g.
+/// `/markets/trending`) can treat Alpaca equities the same way as Hyperliquid/Coinbase. Alpaca
+/// has no perpetuals, so `funding_rate` is always `None` like Coinbase's impl.
+#[async_trait::async_trait]
+impl MarketDataSource for AlpacaDataSource {
+    fn venue(&self) -> &'static str {
+        "alpaca"
+    }
+
+    async fn get_all_markets(&self) -> Result<Vec<UnifiedMarket>, ApiError> {
+        let url = format!("{ALPACA_DATA_URL}/v2/stocks/snapshots");
+        let resp = self.authed(self.client.get(&url))
+            .query(&[("symbols", WATCHLIST.join(","))])
+            .send()
+            .await
+            .map_err(|e| ApiError::Upstream(format!("alpaca snapshots: {e}")))?;
+        if !resp.status().is_success() {
+            return Err(ApiError::Upstream(format!("alpaca snapshots status {}", resp.status())));
+        }
+        let snapshots: std::collections::HashMap<String, Snapshot> = resp.json().await
+            .map_err(|e| ApiError::Upstream(format!("alpaca snapshots json: {e}")))?;
+
+        Ok(WATCHLIST.iter()
+            .filter_map(|symbol| {
+                let snapshot = snapshots.get(*symbol)?;
+                let daily = snapshot.daily_bar.as_ref()?;
+                let change_pct = snapshot.prev_daily_bar.as_ref()
+                    .filter(|prev| prev.c != 0.0)
+                    .map(|prev| (daily.c - prev.c) / prev.c * 100.0)
+                    .unwrap_or(0.0);
+
+                Some(UnifiedMarket {
+                    symbol: symbol.to_string(),
+                    last_price: daily.c,
+                    volume_24h: daily.v,
+                    price_change_percentage_24h: change_pct,
+                    funding_rate: None,
+                })
+            })
+            .collect())
+    }
+
+    async fn get_orderbook(&self, symbol: &str, _depth: Option<u32>) -> Result<UnifiedOrderbook, ApiError> {
+        let url = format!("{ALPACA_DATA_URL}/v2/stocks/{symbol}/quotes/latest");
+        let resp = self.authed(self.client.get(&url)).send().await
+            .map_err(|e| ApiError::Upstream(format!("alpaca quote: {e}")))?;
+        if !resp.status().is_success() {
+            return Err(ApiError::Upstream(format!("alpaca quote status {}", resp.status())));
+        }
+        let body: Value = resp.json().await.map_err(|e| ApiError::Upstream(format!("alpaca quote json: {e}")))?;
+        let quote = body.get("quote").ok_or_else(|| ApiError::Upstream("alpaca quote: missing quote field".to_string()))?;
+        let bid = quote.get("bp").and_then(|v| v.as_f64()).unwrap_or(0.0);
+        let ask = quote.get("ap").and_then(|v| v.as_f64()).unwrap_or(0.0);
+        let size = |key: &str| quote.get(key).and_then(|v| v.as_f64()).unwrap_or(0.0);
+
+        Ok(UnifiedOrderbook {
+            symbol: symbol.to_string(),
+            bids: vec![UnifiedOrderbookLevel { price: bid, size: size("bs") }],
+            asks: vec![UnifiedOrderbookLevel { price: ask, size: size("as") }],
+            time: chrono::Utc::now().timestamp_millis() as u64,
+        })
+    }
+
+    async fn get_recent_trades(&self, symbol: &str, limit: Option<u32>) -> Result<Vec<UnifiedTrade>, ApiError> {
+        let url = format!("{ALPACA_DATA_URL}/v2/stocks/{symbol}/trades");
+        let resp = self.authed(self.client.get(&url))
+            .query(&[("limit", limit.unwrap_or(50).to_string())])
+            .send()
+            .await
+            .map_err(|e| ApiError::Upstream(format!("alpaca trades: {e}")))?;
+        if !resp.status().is_success() {
+            return Err(ApiError::Upstream(format!("alpaca trades status {}", resp.status())));
+        }
+        let body: TradesResponse = resp.json().await.map_err(|e| ApiError::Upstream(format!("alpaca trades json: {e}")))?;
+
+        Ok(body.trades.into_iter()
+            .filter_map(|t| {
+                let time = chrono::DateTime::parse_from_rfc3339(&t.t).ok()?.timestamp_millis() as u64;
+                Some(UnifiedTrade { symbol: symbol.to_string(), side: "unknown".to_string(), price: t.p, size: t.s, time })
+            })
+            .collect())
+    }
+
+    async fn get_candles(&self, symbol: &str, interval: &str, start_time: u64, end_time: u64) -> Result<Vec<UnifiedCandle>, ApiError> {
+        let url = format!("{ALPACA_DATA_URL}/v2/stocks/{symbol}/bars");
+        let start = chrono::DateTime::from_timestamp((start_time / 1000) as i64, 0)
+            .ok_or_else(|| ApiError::InvalidInput("invalid start_time".to_string()))?
+            .to_rfc3339();
+        let end = chrono::DateTime::from_timestamp((end_time / 1000) as i64, 0)
+            .ok_or_else(|| ApiError::InvalidInput("invalid end_time".to_string()))?
+            .to_rfc3339();
+
+        let resp = self.authed(self.client.get(&url))
+            .query(&[
+                ("timeframe", Self::interval_to_timeframe(interval)),
+                ("start", start.as_str()),
+                ("end", end.as_str()),
+            ])
+            .send()
+            .await
+            .map_err(|e| ApiError::Upstream(format!("alpaca bars: {e}")))?;
+        if !resp.status().is_success() {
+            return Err(ApiError::Upstream(format!("alpaca bars status {}", resp.status())));
+        }
+        let body: BarsResponse = resp.json().await.map_err(|e| ApiError::Upstream(format!("alpaca bars json: {e}")))?;
+
+        Ok(body.bars.into_iter()
+            .filter_map(|bar| {
+                let time = chrono::DateTime::parse_from_rfc3339(&bar.t).ok()?.timestamp_millis() as u64;
+                Some(UnifiedCandle { symbol: symbol.to_string(), time, open: bar.o, high: bar.h, low: bar.l, close: bar.c, volume: bar.v })
+            })
+            .collect())
+    }
+}
\ No newline at end of file
@@ -0,0 +1,241 @@
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::time::Duration;
+
+/// Current funding rate and mark/index price for one perpetual symbol, from
+/// `/fapi/v1/premiumIndex`. Binance quotes funding rates and prices as numeric strings, not
+/// JSON numbers, so the price and rate fields parse through [`parse_f64`].
+#[derive(Debug, Serialize, Deserialize, Clone, utoipa::ToSchema)]
+pub struct FundingRate {
+    pub symbol: String,
+    #[serde(rename = "markPrice", deserialize_with = "parse_f64")]
+    pub mark_price: f64,
+    #[serde(rename = "indexPrice", deserialize_with = "parse_f64")]
+    pub index_price: f64,
+    #[serde(rename = "lastFundingRate", deserialize_with = "parse_f64")]
+    pub last_funding_rate: f64,
+    #[serde(rename = "nextFundingTime")]
+    pub next_funding_time: i64,
+}
+
+/// Live open interest for one perpetual symbol, from `/fapi/v1/openInterest`.
+#[derive(Debug, Serialize, Deserialize, Clone, utoipa::ToSchema)]
+pub struct OpenInterest {
+    pub symbol: String,
+    #[serde(rename = "openInterest", deserialize_with = "parse_f64")]
+    pub open_interest: f64,
+    pub time: i64,
+}
+
+/// One tradable perpetual contract from `/fapi/v1/exchangeInfo`, used as a preflight so
+/// callers can validate a symbol before querying it (mirroring how
+/// [`crate::sources::kraken_data`]'s asset-pair list is used to validate Kraken pairs).
+#[derive(Debug, Serialize, Deserialize, Clone, utoipa::ToSchema)]
+pub struct SymbolInfo {
+    pub symbol: String,
+    pub pair: String,
+    #[serde(rename = "contractType")]
+    pub contract_type: String,
+    pub status: String,
+    #[serde(rename = "baseAsset")]
+    pub base_asset: String,
+    #[serde(rename = "quoteAsset")]
+    pub quote_asset: String,
+}
+
+/// Funding rate, mark-vs-index basis, and open interest for one symbol, combined into a
+/// single market-context input alongside [`crate::sources::coingecko_data::get_market_context`].
+#[derive(Debug, Serialize, Deserialize, Clone, utoipa::ToSchema)]
+pub struct FuturesContext {
+    pub symbol: String,
+    pub funding_rate: f64,
+    pub mark_price: f64,
+    pub index_price: f64,
+    /// `mark_price - index_price`, the premium the perpetual is trading at over spot.
+    pub basis: f64,
+    pub open_interest: f64,
+}
+
+fn parse_f64<'de, D>(deserializer: D) -> Result<f64, D::Error>
+where
+    D: serde::Deserializer<'de>,
+{
+    let s = String::deserialize(deserializer)?;
+    s.parse().map_err(serde::de::Error::custom)
+}
+
+/// Client for Binance's USD-M futures REST API. Mirrors
+/// [`crate::sources::coingecko_data::CoinGeckoClient`]'s shape: a bare `reqwest::Client` plus a
+/// shared retry policy, with free functions below for callers that don't need to reuse a client
+/// across calls.
+pub struct BinanceFuturesClient {
+    client: reqwest::Client,
+    base_url: String,
+    retry_policy: crate::http_client::RetryPolicy,
+}
+
+impl Default for BinanceFuturesClient {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl BinanceFuturesClient {
+    pub fn new() -> Self {
+        let client = reqwest::Client::builder()
+            .timeout(Duration::from_secs(10))
+            .build()
+            .unwrap();
+
+        Self {
+            client,
+            base_url: "https://fapi.binance.com".to_string(),
+            retry_policy: crate::http_client::RetryPolicy::default(),
+        }
+    }
+
+    /// Issue a GET request with `params`, retrying on connection errors and on 429/5xx
+    /// responses with exponential backoff (honoring a `Retry-After` header on 429) per
+    /// `self.retry_policy`. Other 4xx responses are returned as errors immediately.
+    async fn get_with_retry(&self, url: &str, params: &[(&str, &str)]) -> Result<reqwest::Response, String> {
+        let mut attempt = 0u32;
+        loop {
+            let (retryable, retry_after, error) = match self.client.get(url).query(params).send().await {
+                Ok(response) if response.status().is_success() => return Ok(response),
+                Ok(response) => {
+                    let status = response.status();
+                    let retryable = crate::http_client::is_retryable_status(status);
+                    let retry_after = if status.as_u16() == 429 {
+                        crate::http_client::parse_retry_after(response.headers())
+                    } else {
+                        None
+                    };
+                    (retryable, retry_after, format!("Binance futures API returned status {status}"))
+                }
+                Err(e) => (true, None, format!("Binance futures API request failed: {e}")),
+            };
+
+            if !retryable || attempt + 1 >= self.retry_policy.max_attempts {
+                return Err(format!("{error} (after {} attempt(s))", attempt + 1));
+            }
+            let delay = retry_after.unwrap_or_else(|| self.retry_policy.backoff(attempt));
+            tokio::time::sleep(delay).await;
+            attempt += 1;
+        }
+    }
+
+    /// Current funding rate and mark/index price. `symbols` is empty to request every symbol,
+    /// or a single symbol to scope it -- matching `/fapi/v1/premiumIndex`'s own `symbol` param,
+    /// which only accepts one symbol at a time (unlike the OI/exchange-info endpoints).
+    pub async fn get_funding_rates(&self, symbols: &[String]) -> Result<Vec<FundingRate>, String> {
+        let url = format!("{}/fapi/v1/premiumIndex", self.base_url);
+
+        if symbols.len() == 1 {
+            let response = self.get_with_retry(&url, &[("symbol", &symbols[0])]).await?;
+            let rate: FundingRate = response
+                .json()
+                .await
+                .map_err(|e| format!("Failed to parse Binance funding rate response: {e}"))?;
+            return Ok(vec![rate]);
+        }
+
+        let response = self.get_with_retry(&url, &[]).await?;
+        let rates: Vec<FundingRate> = response
+            .json()
+            .await
+            .map_err(|e| format!("Failed to parse Binance funding rate response: {e}"))?;
+
+        if symbols.is_empty() {
+            Ok(rates)
+        } else {
+            Ok(rates.into_iter().filter(|r| symbols.contains(&r.symbol)).collect())
+        }
+    }
+
+    /// Live open interest for a single symbol.
+    pub async fn get_open_interest(&self, symbol: &str) -> Result<OpenInterest, String> {
+        let url = format!("{}/fapi/v1/openInterest", self.base_url);
+        let response = self.get_with_retry(&url, &[("symbol", symbol)]).await?;
+        response
+            .json()
+            .await
+            .map_err(|e| format!("Failed to parse Binance open interest response: {e}"))
+    }
+
+    /// The tradable perpetual symbol list, so callers can validate a symbol before querying it.
+    pub async fn get_exchange_info(&self) -> Result<Vec<SymbolInfo>, String> {
+        let url = format!("{}/fapi/v1/exchangeInfo", self.base_url);
+        let response = self.get_with_retry(&url, &[]).await?;
+        let data: Value = response
+            .json()
+            .await
+            .map_err(|e| format!("Failed to parse Binance exchange info response: {e}"))?;
+
+        let symbols = data
+            .get("symbols")
+            .and_then(|s| s.as_array())
+            .ok_or("No symbols data found in exchange info response")?;
+
+        serde_json::from_value(Value::Array(symbols.clone()))
+            .map_err(|e| format!("Failed to deserialize Binance symbol list: {e}"))
+    }
+
+    /// Combined funding rate, mark-vs-index basis, and open interest for each of `symbols`,
+    /// usable as an additional market-context input alongside
+    /// [`crate::sources::coingecko_data::CoinGeckoClient::get_market_context`]. Skips (rather
+    /// than failing the whole batch for) a symbol whose funding rate or open interest can't be
+    /// fetched, the same tolerant-aggregation approach
+    /// [`crate::helpers::trending_cryptos::fetch_all_trending`] takes across sources.
+    pub async fn get_futures_context(&self, symbols: &[String]) -> Vec<FuturesContext> {
+        let mut contexts = Vec::new();
+        for symbol in symbols {
+            let funding = match self.get_funding_rates(std::slice::from_ref(symbol)).await {
+                Ok(mut rates) if !rates.is_empty() => rates.remove(0),
+                Ok(_) => continue,
+                Err(e) => {
+                    tracing::error!("binance futures funding rate for {symbol} failed: {e}");
+                    continue;
+                }
+            };
+            let open_interest = match self.get_open_interest(symbol).await {
+                Ok(oi) => oi.open_interest,
+                Err(e) => {
+                    tracing::error!("binance futures open interest for {symbol} failed: {e}");
+                    continue;
+                }
+            };
+
+            contexts.push(FuturesContext {
+                symbol: symbol.clone(),
+                funding_rate: funding.last_funding_rate,
+                mark_price: funding.mark_price,
+                index_price: funding.index_price,
+                basis: funding.mark_price - funding.index_price,
+                open_interest,
+            });
+        }
+        contexts
+    }
+}
+
+// Convenience functions for easy access, matching `sources::coingecko_data`'s free-function
+// wrappers around `CoinGeckoClient`.
+pub async fn get_funding_rates(symbols: &[String]) -> Result<Vec<FundingRate>, String> {
+    let client = BinanceFuturesClient::new();
+    client.get_funding_rates(symbols).await
+}
+
+pub async fn get_open_interest(symbol: &str) -> Result<OpenInterest, String> {
+    let client = BinanceFuturesClient::new();
+    client.get_open_interest(symbol).await
+}
+
+pub async fn get_exchange_info() -> Result<Vec<SymbolInfo>, String> {
+    let client = BinanceFuturesClient::new();
+    client.get_exchange_info().await
+}
+
+pub async fn get_futures_context(symbols: &[String]) -> Vec<FuturesContext> {
+    let client = BinanceFuturesClient::new();
+    client.get_futures_context(symbols).await
+}
@@ -0,0 +1,367 @@
+use std::time::Duration;
+
+use reqwest::Client;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use crate::errors::ApiError;
+use crate::sources::market_source::{
+    MarketDataSource, UnifiedCandle, UnifiedMarket, UnifiedOrderbook, UnifiedOrderbookLevel, UnifiedTrade,
+};
+use crate::types::TrendingItem;
+
+const COINBASE_API_URL: &str = "https://api.exchange.coinbase.com";
+
+/// Coinbase's public `/products` endpoint lists every tradable pair with no price data attached,
+/// and there's no bulk ticker endpoint -- pricing a product means a per-product call. Like
+/// `sources::alpaca_data::WATCHLIST`, this narrows `get_all_markets`/`get_top_volume_markets`/
+/// `get_trending_defi_assets` to a fixed set of high-liquidity pairs instead of pricing Coinbase's
+/// entire (much larger) catalog.
+const WATCHLIST: &[&str] = &["BTC", "ETH", "SOL", "DOGE", "ADA", "AVAX", "LINK", "MATIC", "LTC", "XRP"];
+
+#[derive(Debug, Serialize, Deserialize, Clone, utoipa::ToSchema)]
+pub struct CoinbaseProduct {
+    pub id: String,
+    pub base_currency: String,
+    pub quote_currency: String,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, utoipa::ToSchema)]
+pub struct CoinbaseOrderbookLevel {
+    pub price: f64,
+    pub size: f64,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, utoipa::ToSchema)]
+pub struct CoinbaseOrderbook {
+    pub product_id: String,
+    pub bids: Vec<CoinbaseOrderbookLevel>,
+    pub asks: Vec<CoinbaseOrderbookLevel>,
+    pub time: u64,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, utoipa::ToSchema)]
+pub struct CoinbaseTrade {
+    pub product_id: String,
+    pub side: String,
+    pub price: f64,
+    pub size: f64,
+    pub time: u64,
+}
+
+/// One [`WATCHLIST`] product's current price/volume/change, the Coinbase counterpart to
+/// [`crate::sources::hyperliquid_data::HyperliquidMarket`] -- built from a ticker call (price) and
+/// a stats call (24h open/volume), since Coinbase has no single endpoint carrying both.
+#[derive(Debug, Serialize, Deserialize, Clone, utoipa::ToSchema)]
+pub struct CoinbaseMarket {
+    pub product_id: String,
+    pub base_currency: String,
+    pub quote_currency: String,
+    pub price: f64,
+    pub volume_24h: f64,
+    pub price_change_24h: f64,
+    pub price_change_percentage_24h: f64,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, utoipa::ToSchema)]
+pub struct CoinbaseCandle {
+    pub product_id: String,
+    pub interval: String,
+    pub time: u64,
+    pub open: f64,
+    pub high: f64,
+    pub low: f64,
+    pub close: f64,
+    pub volume: f64,
+}
+
+/// Coinbase's public Exchange REST API, the Coinbase counterpart to
+/// [`crate::sources::hyperliquid_data::HyperliquidDataSource`]. Coinbase has no Rust SDK in this
+/// repo (unlike Hyperliquid's `hyperliquid_rust_sdk`), so this talks to the REST API directly via
+/// `reqwest`, the same way [`crate::sources::alpaca_data`] and
+/// [`crate::helpers::price_aggregator`]'s exchanges do. Coinbase also has no perpetuals, so there's
+/// no funding-rate or user-state surface here.
+pub struct CoinbaseDataSource {
+    client: Client,
+}
+
+impl CoinbaseDataSource {
+    pub fn new() -> Self {
+        Self {
+            client: Client::builder().timeout(Duration::from_secs(10)).build().unwrap(),
+        }
+    }
+
+    /// Converts a unified coin symbol (e.g. `"BTC"`) into Coinbase's own product id
+    /// (e.g. `"BTC-USD"`).
+    fn product_id(coin: &str) -> String {
+        if coin.contains('-') {
+            coin.to_uppercase()
+        } else {
+            format!("{}-USD", coin.to_uppercase())
+        }
+    }
+
+    /// List all Coinbase spot products.
+    pub async fn get_all_products(&self) -> Result<Vec<CoinbaseProduct>, Box<dyn std::error::Error + Send + Sync>> {
+        let url = format!("{COINBASE_API_URL}/products");
+        let products: Vec<CoinbaseProduct> = self.client.get(&url).send().await?.json().await?;
+        Ok(products)
+    }
+
+    /// Aggregated level-2 order book for `coin`.
+    pub async fn get_orderbook(&self, coin: &str, _depth: Option<u32>) -> Result<CoinbaseOrderbook, Box<dyn std::error::Error + Send + Sync>> {
+        let product_id = Self::product_id(coin);
+        let url = format!("{COINBASE_API_URL}/products/{product_id}/book");
+        let raw: Value = self.client.get(&url).query(&[("level", "2")]).send().await?.json().await?;
+
+        let parse_levels = |key: &str| -> Vec<CoinbaseOrderbookLevel> {
+            raw.get(key)
+                .and_then(|v| v.as_array())
+                .map(|levels| {
+                    levels.iter()
+                        .filter_map(|level| {
+                            let level = level.as_array()?;
+                            let price = level.first()?.as_str()?.parse().ok()?;
+                            let size = level.get(1)?.as_str()?.parse().ok()?;
+                            Some(CoinbaseOrderbookLevel { price, size })
+                        })
+                        .collect()
+                })
+                .unwrap_or_default()
+        };
+
+        Ok(CoinbaseOrderbook {
+            product_id,
+            bids: parse_levels("bids"),
+            asks: parse_levels("asks"),
+            time: chrono::Utc::now().timestamp_millis() as u64,
+        })
+    }
+
+    /// Most recent trades for `coin`, newest first (as returned by Coinbase).
+    pub async fn get_recent_trades(&self, coin: &str, limit: Option<u32>) -> Result<Vec<CoinbaseTrade>, Box<dyn std::error::Error + Send + Sync>> {
+        let product_id = Self::product_id(coin);
+        let url = format!("{COINBASE_API_URL}/products/{product_id}/trades");
+
+        #[derive(Deserialize)]
+        struct RawTrade {
+            time: String,
+            price: String,
+            size: String,
+            side: String,
+        }
+
+        let raw: Vec<RawTrade> = self.client.get(&url).send().await?.json().await?;
+        let limit = limit.unwrap_or(50) as usize;
+
+        Ok(raw.into_iter()
+            .take(limit)
+            .filter_map(|t| {
+                let time = chrono::DateTime::parse_from_rfc3339(&t.time).ok()?.timestamp_millis() as u64;
+                Some(CoinbaseTrade {
+                    product_id: product_id.clone(),
+                    side: t.side,
+                    price: t.price.parse().ok()?,
+                    size: t.size.parse().ok()?,
+                    time,
+                })
+            })
+            .collect())
+    }
+
+    /// Candlestick data. `interval` uses the same labels as
+    /// `HyperliquidDataSource::get_candles` (`1m`/`5m`/`15m`/`1h`/`4h`/`1d`) and is translated to
+    /// Coinbase's `granularity` seconds; `start_time`/`end_time` are Unix ms, also matching
+    /// Hyperliquid's convention.
+    pub async fn get_candles(&self, coin: &str, interval: &str, start_time: u64, end_time: u64) -> Result<Vec<CoinbaseCandle>, Box<dyn std::error::Error + Send + Sync>> {
+        let product_id = Self::product_id(coin);
+        let granularity = Self::interval_to_granularity(interval);
+        let url = format!("{COINBASE_API_URL}/products/{product_id}/candles");
+
+        let start = chrono::DateTime::from_timestamp((start_time / 1000) as i64, 0)
+            .ok_or("invalid start_time")?
+            .to_rfc3339();
+        let end = chrono::DateTime::from_timestamp((end_time / 1000) as i64, 0)
+            .ok_or("invalid end_time")?
+            .to_rfc3339();
+        let granularity_str = granularity.to_string();
+
+        let raw: Vec<[f64; 6]> = self.client.get(&url)
+            .query(&[("granularity", granularity_str.as_str()), ("start", start.as_str()), ("end", end.as_str())])
+            .send()
+            .await?
+            .json()
+            .await?;
+
+        Ok(raw.into_iter()
+            .map(|[time, low, high, open, close, volume]| CoinbaseCandle {
+                product_id: product_id.clone(),
+                interval: interval.to_string(),
+                time: (time as u64) * 1000,
+                open,
+                high,
+                low,
+                close,
+                volume,
+            })
+            .collect())
+    }
+
+    /// Coinbase only supports a fixed set of granularities (in seconds); `4h` has no exact match
+    /// so it rounds up to the nearest supported one (`21600` / 6h) rather than silently returning
+    /// 1h candles under a `4h` label.
+    fn interval_to_granularity(label: &str) -> u32 {
+        match label {
+            "1m" => 60,
+            "5m" => 300,
+            "15m" => 900,
+            "1h" => 3600,
+            "4h" => 21_600,
+            "1d" => 86_400,
+            _ => 3600,
+        }
+    }
+
+    /// Current price plus 24h open/volume for `coin`, fetched concurrently from Coinbase's
+    /// `/ticker` (price) and `/stats` (24h open/volume) endpoints -- the two calls together give
+    /// the same surface `HyperliquidDataSource::get_all_markets` gets from one SDK call.
+    pub async fn get_market_data(&self, coin: &str) -> Result<CoinbaseMarket, Box<dyn std::error::Error + Send + Sync>> {
+        let product_id = Self::product_id(coin);
+
+        #[derive(Deserialize)]
+        struct RawTicker {
+            price: String,
+        }
+        #[derive(Deserialize)]
+        struct RawStats {
+            open: String,
+            volume: String,
+        }
+
+        let ticker_url = format!("{COINBASE_API_URL}/products/{product_id}/ticker");
+        let stats_url = format!("{COINBASE_API_URL}/products/{product_id}/stats");
+        let (ticker, stats): (RawTicker, RawStats) = tokio::try_join!(
+            async { self.client.get(&ticker_url).send().await?.json::<RawTicker>().await },
+            async { self.client.get(&stats_url).send().await?.json::<RawStats>().await },
+        )?;
+
+        let price: f64 = ticker.price.parse().unwrap_or(0.0);
+        let open: f64 = stats.open.parse().unwrap_or(0.0);
+        let volume: f64 = stats.volume.parse().unwrap_or(0.0);
+        let price_change_24h = price - open;
+        let price_change_percentage_24h = if open != 0.0 { price_change_24h / open * 100.0 } else { 0.0 };
+
+        Ok(CoinbaseMarket {
+            product_id,
+            base_currency: coin.to_uppercase(),
+            quote_currency: "USD".to_string(),
+            price,
+            volume_24h: volume,
+            price_change_24h,
+            price_change_percentage_24h,
+        })
+    }
+
+    /// [`WATCHLIST`] priced via [`Self::get_market_data`], fetched concurrently. A product that
+    /// fails to price (delisted, rate-limited) is logged and dropped rather than failing the
+    /// whole batch, the same tolerance `routes::markets::compare_market` applies per-venue.
+    pub async fn get_all_markets(&self) -> Result<Vec<CoinbaseMarket>, Box<dyn std::error::Error + Send + Sync>> {
+        let results = futures::future::join_all(WATCHLIST.iter().map(|coin| self.get_market_data(coin))).await;
+        Ok(results.into_iter()
+            .filter_map(|result| match result {
+                Ok(market) => Some(market),
+                Err(e) => {
+                    tracing::warn!("coinbase get_all_markets: {e}");
+                    None
+                }
+            })
+            .collect())
+    }
+
+    /// Get top DeFi markets by volume. Mirrors `HyperliquidDataSource::get_top_volume_markets`.
+    pub async fn get_top_volume_markets(&self, limit: usize) -> Result<Vec<CoinbaseMarket>, Box<dyn std::error::Error + Send + Sync>> {
+        let mut markets = self.get_all_markets().await?;
+        markets.sort_by(|a, b| b.volume_24h.partial_cmp(&a.volume_24h).unwrap_or(std::cmp::Ordering::Equal));
+        markets.truncate(limit);
+        Ok(markets)
+    }
+
+    /// Get markets with highest price changes. Mirrors `HyperliquidDataSource::get_top_movers`.
+    pub async fn get_top_movers(&self, limit: usize) -> Result<(Vec<CoinbaseMarket>, Vec<CoinbaseMarket>), Box<dyn std::error::Error + Send + Sync>> {
+        let markets = self.get_all_markets().await?;
+
+        let mut gainers = markets.clone();
+        gainers.sort_by(|a, b| b.price_change_percentage_24h.partial_cmp(&a.price_change_percentage_24h).unwrap_or(std::cmp::Ordering::Equal));
+        gainers.truncate(limit);
+
+        let mut losers = markets;
+        losers.sort_by(|a, b| a.price_change_percentage_24h.partial_cmp(&b.price_change_percentage_24h).unwrap_or(std::cmp::Ordering::Equal));
+        losers.truncate(limit);
+
+        Ok((gainers, losers))
+    }
+
+    /// Get trending spot markets based on volume and price movement. Delegates to
+    /// [`MarketDataSource::trending`]'s default ranking, same as
+    /// `HyperliquidDataSource::get_trending_defi_assets`.
+    pub async fn get_trending_defi_assets(&self, limit: usize) -> Result<Vec<TrendingItem>, Box<dyn std::error::Error + Send + Sync>> {
+        MarketDataSource::trending(self, limit).await.map_err(|e| e.to_string().into())
+    }
+}
+
+impl Default for CoinbaseDataSource {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Adapts the inherent methods above to [`crate::sources::market_source::MarketDataSource`]. See
+/// the matching impl on `HyperliquidDataSource` for why the shared method names don't recurse.
+#[async_trait::async_trait]
+impl MarketDataSource for CoinbaseDataSource {
+    fn venue(&self) -> &'static str {
+        "coinbase"
+    }
+
+    async fn get_all_markets(&self) -> Result<Vec<UnifiedMarket>, ApiError> {
+        // Dot-calls to `self.get_all_markets()` resolve to the inherent method above (priced over
+        // `WATCHLIST`), not this trait method, since inherent methods always take priority -- see
+        // the doc comment on the matching `HyperliquidDataSource` impl for why that's not
+        // recursion.
+        let markets = self.get_all_markets().await.map_err(|e| ApiError::Upstream(e.to_string()))?;
+        Ok(markets.into_iter()
+            .map(|m| UnifiedMarket {
+                symbol: m.base_currency,
+                last_price: m.price,
+                volume_24h: m.volume_24h,
+                price_change_percentage_24h: m.price_change_percentage_24h,
+                funding_rate: None,
+            })
+            .collect())
+    }
+
+    async fn get_orderbook(&self, symbol: &str, depth: Option<u32>) -> Result<UnifiedOrderbook, ApiError> {
+        let book = self.get_orderbook(symbol, depth).await.map_err(|e| ApiError::Upstream(e.to_string()))?;
+        Ok(UnifiedOrderbook {
+            symbol: book.product_id,
+            bids: book.bids.into_iter().map(|l| UnifiedOrderbookLevel { price: l.price, size: l.size }).collect(),
+            asks: book.asks.into_iter().map(|l| UnifiedOrderbookLevel { price: l.price, size: l.size }).collect(),
+            time: book.time,
+        })
+    }
+
+    async fn get_recent_trades(&self, symbol: &str, limit: Option<u32>) -> Result<Vec<UnifiedTrade>, ApiError> {
+        let trades = self.get_recent_trades(symbol, limit).await.map_err(|e| ApiError::Upstream(e.to_string()))?;
+        Ok(trades.into_iter()
+            .map(|t| UnifiedTrade { symbol: t.product_id, side: t.side, price: t.price, size: t.size, time: t.time })
+            .collect())
+    }
+
+    async fn get_candles(&self, symbol: &str, interval: &str, start_time: u64, end_time: u64) -> Result<Vec<UnifiedCandle>, ApiError> {
+        let candles = self.get_candles(symbol, interval, start_time, end_time).await.map_err(|e| ApiError::Upstream(e.to_string()))?;
+        Ok(candles.into_iter()
+            .map(|c| UnifiedCandle { symbol: c.product_id, time: c.time, open: c.open, high: c.high, low: c.low, close: c.close, volume: c.volume })
+            .collect())
+    }
+}
@@ -1,10 +1,229 @@
-use reqwest::header::{HeaderMap, HeaderValue, USER_AGENT};
+use reqwest::header::{HeaderMap, HeaderName, HeaderValue, USER_AGENT};
+use rust_decimal::prelude::*;
 use serde::{Deserialize, Serialize};
 use serde_json::Value;
 use std::collections::HashMap;
-use std::time::Duration;
+use std::time::{Duration, Instant};
+use tokio::sync::Mutex;
 use crate::types::TrendingItem;
 
+/// How long a cached price (`/simple/price`) is served without re-fetching.
+const PRICE_CACHE_TTL: Duration = Duration::from_secs(30);
+/// How long a cached market-wide list/summary (`/coins/markets`, `/search/trending`,
+/// `/global`) is served without re-fetching.
+const MARKET_LIST_CACHE_TTL: Duration = Duration::from_secs(60);
+
+/// Settings for the shared [`COINGECKO_CACHE`]/[`COINGECKO_RATE_LIMITER`] every
+/// [`CoinGeckoClient`] instance goes through, read from the same env vars this struct
+/// documents. Exposed mainly so `Config` can surface the effective settings; the limiter and
+/// cache themselves are process-wide (see the module-level `lazy_static!` below) since
+/// `CoinGeckoClient` is cheaply re-created per call site rather than threaded through
+/// `AppState`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CoinGeckoConfig {
+    /// Token-bucket capacity and refill rate for outbound CoinGecko requests
+    /// (`COINGECKO_REQUESTS_PER_MINUTE`, default 100 -- the public API's documented ceiling).
+    pub requests_per_minute: u32,
+    /// Optional Pro API key (`COINGECKO_API_KEY`) sent as `x-cg-pro-api-key`; when set,
+    /// [`CoinGeckoClient::from_env`] targets the Pro host instead of the public one.
+    pub api_key: Option<String>,
+}
+
+impl Default for CoinGeckoConfig {
+    fn default() -> Self {
+        Self {
+            requests_per_minute: 100,
+            api_key: None,
+        }
+    }
+}
+
+impl CoinGeckoConfig {
+    pub fn from_env() -> Self {
+        Self {
+            requests_per_minute: std::env::var("COINGECKO_REQUESTS_PER_MINUTE")
+                .ok()
+                .and_then(|v| v.parse().ok())
+                .unwrap_or(100),
+            api_key: std::env::var("COINGECKO_API_KEY").ok(),
+        }
+    }
+}
+
+struct TokenBucketState {
+    tokens: f64,
+    last_refill: Instant,
+}
+
+/// A classic token bucket: `capacity` tokens refilling at `capacity` per minute, shared across
+/// every [`CoinGeckoClient`] so concurrent callers draw from one budget instead of each
+/// tracking their own. [`Self::try_acquire`] never blocks (used when a stale cache entry is an
+/// acceptable fallback); [`Self::acquire`] waits for a token (used on a genuine cache miss,
+/// where there's nothing else to serve).
+struct TokenBucket {
+    capacity: f64,
+    refill_per_sec: f64,
+    state: Mutex<TokenBucketState>,
+}
+
+impl TokenBucket {
+    fn new(requests_per_minute: u32) -> Self {
+        let capacity = requests_per_minute.max(1) as f64;
+        Self {
+            capacity,
+            refill_per_sec: capacity / 60.0,
+            state: Mutex::new(TokenBucketState {
+                tokens: capacity,
+                last_refill: Instant::now(),
+            }),
+        }
+    }
+
+    fn refill(&self, state: &mut TokenBucketState) {
+        let now = Instant::now();
+        let elapsed = now.duration_since(state.last_refill).as_secs_f64();
+        state.tokens = (state.tokens + elapsed * self.refill_per_sec).min(self.capacity);
+        state.last_refill = now;
+    }
+
+    async fn try_acquire(&self) -> bool {
+        let mut state = self.state.lock().await;
+        self.refill(&mut state);
+        if state.tokens >= 1.0 {
+            state.tokens -= 1.0;
+            true
+        } else {
+            false
+        }
+    }
+
+    async fn acquire(&self) {
+        loop {
+            if self.try_acquire().await {
+                return;
+            }
+            tokio::time::sleep(Duration::from_millis(250)).await;
+        }
+    }
+}
+
+lazy_static::lazy_static! {
+    /// Process-wide cache for CoinGecko responses, keyed by endpoint URL + params. Shared
+    /// across every `CoinGeckoClient` instance (mirrors `FETCH_CACHE` in
+    /// `helpers::resilient_fetch`) since callers construct a fresh client per request rather
+    /// than holding one long-lived instance.
+    static ref COINGECKO_CACHE: crate::cache::MemoryCache = crate::cache::MemoryCache::new();
+    /// Process-wide outbound rate limit for CoinGecko, so a burst of concurrent handlers can't
+    /// collectively exceed the configured requests/minute even though each uses its own client.
+    static ref COINGECKO_RATE_LIMITER: TokenBucket = TokenBucket::new(CoinGeckoConfig::from_env().requests_per_minute);
+    /// Process-wide hub backing `/coingecko/stream`, shared the same way as the two statics
+    /// above -- `routes::coingecko` has no `AppState` to hold it in (see that module's
+    /// `coingecko_routes`, which is never `.with_state(...)`-ed).
+    pub static ref COINGECKO_PRICE_STREAM_HUB: std::sync::Arc<CoinGeckoPriceStreamHub> =
+        std::sync::Arc::new(CoinGeckoPriceStreamHub::new());
+}
+
+/// Batches every active `/coingecko/stream` subscription into a single periodic
+/// [`get_simple_price`] poll per distinct `(ids, vs_currencies, include_24hr_change)`
+/// combination, diffs each poll against the last snapshot it sent, and fans out only the
+/// coins whose price actually changed. Mirrors [`crate::sources::finviz_data::ScreenerStreamHub`]'s
+/// poll-diff-broadcast shape, reusing this module's own rate limiter/cache (see
+/// [`CoinGeckoClient::get_simple_price`]) rather than hitting CoinGecko on every subscriber's
+/// own cadence.
+pub struct CoinGeckoPriceStreamHub {
+    subscriptions: Mutex<HashMap<String, tokio::sync::broadcast::Sender<Value>>>,
+}
+
+impl CoinGeckoPriceStreamHub {
+    pub fn new() -> Self {
+        Self { subscriptions: Mutex::new(HashMap::new()) }
+    }
+
+    /// Subscribe to diffed price updates for a set of coin ids/vs_currencies. Spawns the
+    /// upstream poll loop on the first subscriber for this exact combination and reuses it
+    /// after that.
+    pub fn subscribe(
+        self: std::sync::Arc<Self>,
+        ids: Vec<String>,
+        vs_currencies: Vec<String>,
+        include_24hr_change: bool,
+        poll_interval: Duration,
+    ) -> impl futures::Stream<Item = Value> {
+        async_stream::stream! {
+            let key = format!("{}:{}:{include_24hr_change}", ids.join(","), vs_currencies.join(","));
+            let mut rx = {
+                let mut subs = self.subscriptions.lock().await;
+                match subs.get(&key) {
+                    Some(tx) => tx.subscribe(),
+                    None => {
+                        let (tx, rx) = tokio::sync::broadcast::channel(64);
+                        subs.insert(key.clone(), tx.clone());
+                        tokio::spawn(self.clone().poll_and_diff(key.clone(), ids, vs_currencies, include_24hr_change, poll_interval, tx));
+                        rx
+                    }
+                }
+            };
+
+            loop {
+                match rx.recv().await {
+                    Ok(value) => yield value,
+                    Err(tokio::sync::broadcast::error::RecvError::Lagged(_)) => continue,
+                    Err(tokio::sync::broadcast::error::RecvError::Closed) => break,
+                }
+            }
+        }
+    }
+
+    /// Poll `get_simple_price` on `poll_interval`, diffing each response against the last one
+    /// and publishing only the coins whose price (or 24h-change, if requested) differs. Tears
+    /// itself down -- dropping the broadcast sender out of `subscriptions` -- once the last
+    /// subscriber disconnects, same as `ScreenerStreamHub::poll_and_diff`.
+    async fn poll_and_diff(
+        self: std::sync::Arc<Self>,
+        key: String,
+        ids: Vec<String>,
+        vs_currencies: Vec<String>,
+        include_24hr_change: bool,
+        poll_interval: Duration,
+        tx: tokio::sync::broadcast::Sender<Value>,
+    ) {
+        let mut last: HashMap<String, Value> = HashMap::new();
+
+        loop {
+            if tx.receiver_count() == 0 {
+                self.subscriptions.lock().await.remove(&key);
+                break;
+            }
+
+            match get_simple_price(&ids, &vs_currencies, include_24hr_change).await {
+                Ok(Value::Object(current)) => {
+                    let changed: HashMap<String, Value> = current
+                        .iter()
+                        .filter(|(id, value)| last.get(*id) != Some(*value))
+                        .map(|(id, value)| (id.clone(), value.clone()))
+                        .collect();
+
+                    if !changed.is_empty() {
+                        let _ = tx.send(serde_json::json!(changed));
+                    }
+
+                    last = current.into_iter().collect();
+                }
+                Ok(_) => tracing::warn!("coingecko price stream: unexpected non-object response for {key}"),
+                Err(e) => tracing::warn!("coingecko price stream: poll failed for {key}: {e}"),
+            }
+
+            tokio::time::sleep(poll_interval).await;
+        }
+    }
+}
+
+impl Default for CoinGeckoPriceStreamHub {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 #[derive(Debug, Serialize, Deserialize, Clone, utoipa::ToSchema)]
 pub struct CoinGeckoCoin {
     pub id: String,
@@ -48,6 +267,93 @@ pub struct MarketOverview {
     pub bitcoin_dominance: f64,
     pub market_cap_percentage: HashMap<String, f64>,
     pub volume_percentage: HashMap<String, f64>,
+    /// 24h change in total market cap (USD), from `/global`. `None` when this overview was
+    /// built from [`CoinGeckoClient::get_market_overview_top_coins`], which has no access to
+    /// that figure.
+    pub market_cap_change_percentage_24h: Option<f64>,
+    /// `total_market_cap` summed in exact `Decimal` arithmetic rather than `f64`, so
+    /// trillion-dollar sums don't accumulate rounding error. Only populated by
+    /// [`CoinGeckoClient::get_market_overview_top_coins_exact`].
+    #[serde(with = "rust_decimal::serde::str_option", default)]
+    #[schema(value_type = Option<String>)]
+    pub total_market_cap_exact: Option<Decimal>,
+    /// `total_volume` summed in exact `Decimal` arithmetic rather than `f64`. Only populated
+    /// by [`CoinGeckoClient::get_market_overview_top_coins_exact`].
+    #[serde(with = "rust_decimal::serde::str_option", default)]
+    #[schema(value_type = Option<String>)]
+    pub total_volume_exact: Option<Decimal>,
+    /// 24h trading volume broken down per quote currency (`"usd"`, `"btc"`, `"eth"`, ...),
+    /// straight from `/global`. Empty for overviews built from the top-100 heuristics, which
+    /// only ever compute a single USD-denominated total (see `total_volume`).
+    pub total_volume_by_currency: HashMap<String, f64>,
+    /// Number of cryptocurrencies CoinGecko tracks. `0` for the top-100 heuristic paths.
+    pub active_cryptocurrencies: u64,
+    /// Number of markets (exchange/pair combinations) CoinGecko tracks. `0` for the top-100
+    /// heuristic paths.
+    pub markets: u64,
+    pub upcoming_icos: u64,
+    pub ongoing_icos: u64,
+    pub ended_icos: u64,
+    /// Unix timestamp of when CoinGecko computed this global snapshot. `0` for the top-100
+    /// heuristic paths, which have no equivalent figure.
+    pub updated_at: i64,
+}
+
+/// The authoritative market-wide totals from CoinGecko's `/global` endpoint, computed across
+/// every tracked coin -- unlike [`CoinGeckoClient::get_market_data`], which only returns one
+/// page at a time.
+#[derive(Debug, Serialize, Deserialize, Clone, utoipa::ToSchema)]
+pub struct GlobalMarketData {
+    pub total_market_cap: HashMap<String, f64>,
+    pub total_volume: HashMap<String, f64>,
+    pub market_cap_percentage: HashMap<String, f64>,
+    pub market_cap_change_percentage_24h_usd: f64,
+    pub active_cryptocurrencies: u64,
+    pub markets: u64,
+    pub upcoming_icos: u64,
+    pub ongoing_icos: u64,
+    pub ended_icos: u64,
+    pub updated_at: i64,
+}
+
+/// Deserialize a field that CoinGecko sends as either a JSON number or a numeric string into
+/// an `f64`, so callers don't have to care which form a given upstream endpoint happens to use.
+fn f64_from_string_or_number<'de, D>(deserializer: D) -> Result<f64, D::Error>
+where
+    D: serde::Deserializer<'de>,
+{
+    #[derive(Deserialize)]
+    #[serde(untagged)]
+    enum StringOrNumber {
+        String(String),
+        Number(f64),
+    }
+
+    match StringOrNumber::deserialize(deserializer)? {
+        StringOrNumber::Number(n) => Ok(n),
+        StringOrNumber::String(s) => s.parse().map_err(serde::de::Error::custom),
+    }
+}
+
+/// A snapshot of the DeFi sector's size from CoinGecko's `/global/decentralized_finance_defi`
+/// endpoint, sibling to [`GlobalMarketData`]/[`MarketOverview`] but scoped to DeFi coins only.
+/// CoinGecko sends most of these figures as numeric strings rather than JSON numbers, hence
+/// the shared [`f64_from_string_or_number`] helper on every field.
+#[derive(Debug, Serialize, Deserialize, Clone, utoipa::ToSchema)]
+pub struct DefiOverview {
+    #[serde(deserialize_with = "f64_from_string_or_number")]
+    pub defi_market_cap: f64,
+    #[serde(deserialize_with = "f64_from_string_or_number")]
+    pub eth_market_cap: f64,
+    #[serde(deserialize_with = "f64_from_string_or_number")]
+    pub defi_to_eth_ratio: f64,
+    #[serde(deserialize_with = "f64_from_string_or_number")]
+    pub trading_volume_24h: f64,
+    #[serde(deserialize_with = "f64_from_string_or_number")]
+    pub defi_dominance: f64,
+    pub top_coin_name: String,
+    #[serde(deserialize_with = "f64_from_string_or_number")]
+    pub top_coin_defi_dominance: f64,
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone, utoipa::ToSchema)]
@@ -75,9 +381,155 @@ pub struct CoinGeckoResponse<T> {
     pub data: T,
 }
 
+/// One exchange's trading pair for a coin, as returned by `/coins/{id}/tickers`.
+#[derive(Debug, Serialize, Deserialize, Clone, utoipa::ToSchema)]
+pub struct MarketTicker {
+    pub base: String,
+    pub target: String,
+    pub market_name: String,
+    pub last: f64,
+    pub volume: f64,
+    pub bid_ask_spread_percentage: Option<f64>,
+    /// CoinGecko's liquidity confidence label for the pair (`"green"`/`"yellow"`/`"red"`), or
+    /// `None` when the exchange doesn't report one.
+    pub trust_score: Option<String>,
+}
+
+/// One OHLC candle as returned by `/coins/{id}/ohlc`. CoinGecko chooses the candle width
+/// itself based on the requested `days` (30 min for 1-2 days, 4 hour for 8-30 days, 4 day
+/// beyond that) rather than accepting an explicit resolution.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, utoipa::ToSchema)]
+pub struct Ohlc {
+    pub timestamp: i64,
+    pub open: f64,
+    pub high: f64,
+    pub low: f64,
+    pub close: f64,
+}
+
+/// Historical price/market-cap/volume series for a coin, as returned by
+/// `/coins/{id}/market_chart`. Each series is `(timestamp_ms, value)` pairs.
+#[derive(Debug, Serialize, Deserialize, Clone, utoipa::ToSchema)]
+pub struct MarketChart {
+    pub prices: Vec<(i64, f64)>,
+    pub market_caps: Vec<(i64, f64)>,
+    pub total_volumes: Vec<(i64, f64)>,
+}
+
+/// Resample a sorted `(timestamp_ms, price)` series -- such as [`MarketChart::prices`] --
+/// into fixed `bucket_seconds` candles. Each point is assigned to `floor(ts / bucket)`;
+/// within a bucket, `open`/`close` are the first/last point and `high`/`low` are the
+/// extremes. Buckets with no points carry the previous bucket's close forward as a flat
+/// candle, so the resulting series has no gaps between `points.first()` and `points.last()`.
+pub fn resample_to_candles(points: &[(i64, f64)], bucket_seconds: i64) -> Vec<Ohlc> {
+    if points.is_empty() || bucket_seconds <= 0 {
+        return Vec::new();
+    }
+
+    let bucket_ms = bucket_seconds * 1000;
+    let mut buckets: std::collections::BTreeMap<i64, Vec<f64>> = std::collections::BTreeMap::new();
+    for &(ts_ms, price) in points {
+        buckets.entry(ts_ms.div_euclid(bucket_ms)).or_default().push(price);
+    }
+
+    let first_bucket = *buckets.keys().next().unwrap();
+    let last_bucket = *buckets.keys().last().unwrap();
+
+    let mut candles = Vec::new();
+    let mut carry_close: Option<f64> = None;
+
+    for bucket in first_bucket..=last_bucket {
+        let timestamp = bucket * bucket_ms;
+        if let Some(prices) = buckets.get(&bucket) {
+            let open = *prices.first().unwrap();
+            let close = *prices.last().unwrap();
+            let high = prices.iter().cloned().fold(f64::MIN, f64::max);
+            let low = prices.iter().cloned().fold(f64::MAX, f64::min);
+            carry_close = Some(close);
+            candles.push(Ohlc { timestamp, open, high, low, close });
+        } else if let Some(close) = carry_close {
+            candles.push(Ohlc { timestamp, open: close, high: close, low: close, close });
+        }
+    }
+
+    candles
+}
+
+/// A single OHLCV candle with an explicit `volume`, unlike [`Ohlc`] which has none (CoinGecko's
+/// native `/ohlc` endpoint doesn't report it). `volume` is `None` when no matching point could
+/// be found in the volume series it was joined against.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, utoipa::ToSchema)]
+pub struct Candle {
+    pub ts: i64,
+    pub open: f64,
+    pub high: f64,
+    pub low: f64,
+    pub close: f64,
+    pub volume: Option<f64>,
+}
+
+/// A candle series plus the bucket width every candle in it shares, so callers don't have to
+/// re-derive CoinGecko's days-dependent granularity rule themselves the way
+/// [`CoinGeckoClient::get_ohlc`]/[`CoinGeckoClient::get_market_chart_candles`] already have to.
+#[derive(Debug, Serialize, Deserialize, Clone, utoipa::ToSchema)]
+pub struct CandleSeries {
+    pub interval_seconds: i64,
+    pub candles: Vec<Candle>,
+}
+
+/// The value of the point in `points` (sorted or not) whose timestamp is closest to `ts`.
+/// Used to join CoinGecko's `/ohlc` and `/market_chart` series, which don't share a bucket
+/// grid, by nearest timestamp rather than requiring an exact match.
+fn nearest_value(points: &[(i64, f64)], ts: i64) -> Option<f64> {
+    points
+        .iter()
+        .min_by_key(|(point_ts, _)| (point_ts - ts).abs())
+        .map(|(_, value)| *value)
+}
+
+/// CoinGecko's `/coins/{id}/ohlc` bucket width, which is fixed by `days` rather than
+/// requestable directly: 30 minutes for 1-2 days of history, 4 hours out to 30 days, and 4
+/// days beyond that (see [`Ohlc`]).
+fn ohlc_interval_seconds(days: u32) -> i64 {
+    match days {
+        0..=2 => 30 * 60,
+        3..=30 => 4 * 60 * 60,
+        _ => 4 * 24 * 60 * 60,
+    }
+}
+
+/// CoinGecko's `/coins/{id}/market_chart` automatic granularity when no explicit `interval` is
+/// requested: roughly 30-minute buckets out to 1 day, hourly out to 90 days, and daily beyond.
+fn market_chart_interval_seconds(days: u32) -> i64 {
+    match days {
+        0..=1 => 30 * 60,
+        2..=90 => 60 * 60,
+        _ => 24 * 60 * 60,
+    }
+}
+
+/// Parse a raw CoinGecko numeric field into an exact [`Decimal`] without going through `f64`
+/// first. Accepts either a JSON number (the common case) or a numeric string (CoinGecko falls
+/// back to strings for a handful of fields on some endpoints).
+fn decimal_field(value: &Value) -> Option<Decimal> {
+    match value {
+        Value::Number(n) => Decimal::from_str(&n.to_string()).ok(),
+        Value::String(s) => Decimal::from_str(s).ok(),
+        _ => None,
+    }
+}
+
+/// Render a field missing from CoinGecko's response as "N/A" rather than a fabricated `0.0` --
+/// used by [`CoinGeckoClient::get_market_context`]'s human-readable summary, where a genuine
+/// 0.00% change must stay distinguishable from "CoinGecko didn't return this field".
+fn format_opt(value: Option<f64>, fmt: impl Fn(f64) -> String) -> String {
+    value.map(fmt).unwrap_or_else(|| "N/A".to_string())
+}
+
 pub struct CoinGeckoClient {
     client: reqwest::Client,
     base_url: String,
+    retry_policy: crate::http_client::RetryPolicy,
 }
 
 impl Default for CoinGeckoClient {
@@ -103,9 +555,114 @@ impl CoinGeckoClient {
         Self {
             client,
             base_url: "https://api.coingecko.com/api/v3".to_string(),
+            retry_policy: crate::http_client::RetryPolicy::default(),
         }
     }
 
+    /// Create a client authenticated against CoinGecko's Pro API: `api_key` is sent as the
+    /// `x-cg-pro-api-key` header and requests go to the paid host instead of the public one,
+    /// which carries a much higher rate limit.
+    pub fn new_with_key(api_key: impl Into<String>) -> Self {
+        let api_key = api_key.into();
+        let mut headers = HeaderMap::new();
+        headers.insert(
+            USER_AGENT,
+            HeaderValue::from_static("Mozilla/5.0 (Windows NT 10.0; Win64; x64) AppleWebKit/537.36"),
+        );
+        if let Ok(value) = HeaderValue::from_str(&api_key) {
+            headers.insert(HeaderName::from_static("x-cg-pro-api-key"), value);
+        }
+
+        let client = reqwest::Client::builder()
+            .default_headers(headers)
+            .timeout(Duration::from_secs(10))
+            .build()
+            .unwrap();
+
+        Self {
+            client,
+            base_url: "https://pro-api.coingecko.com/api/v3".to_string(),
+            retry_policy: crate::http_client::RetryPolicy::default(),
+        }
+    }
+
+    /// Build a client from [`CoinGeckoConfig::from_env`]: Pro API (via
+    /// [`Self::new_with_key`]) if `COINGECKO_API_KEY` is set, otherwise the public API.
+    pub fn from_env() -> Self {
+        match CoinGeckoConfig::from_env().api_key {
+            Some(api_key) => Self::new_with_key(api_key),
+            None => Self::new(),
+        }
+    }
+
+    /// Issue a GET request with `params`, retrying on connection errors and on 429/5xx
+    /// responses with exponential backoff (honoring a `Retry-After` header on 429) per
+    /// `self.retry_policy`. Other 4xx responses are returned as errors immediately.
+    async fn get_with_retry(&self, url: &str, params: &[(&str, &str)]) -> Result<reqwest::Response, String> {
+        let mut attempt = 0u32;
+        loop {
+            let (retryable, retry_after, error) = match self.client.get(url).query(params).send().await {
+                Ok(response) if response.status().is_success() => return Ok(response),
+                Ok(response) => {
+                    let status = response.status();
+                    let retryable = crate::http_client::is_retryable_status(status);
+                    let retry_after = if status.as_u16() == 429 {
+                        crate::http_client::parse_retry_after(response.headers())
+                    } else {
+                        None
+                    };
+                    (retryable, retry_after, format!("CoinGecko API returned status {status}"))
+                }
+                Err(e) => (true, None, format!("CoinGecko API request failed: {e}")),
+            };
+
+            if !retryable || attempt + 1 >= self.retry_policy.max_attempts {
+                return Err(format!("{error} (after {} attempt(s))", attempt + 1));
+            }
+            let delay = retry_after.unwrap_or_else(|| self.retry_policy.backoff(attempt));
+            tokio::time::sleep(delay).await;
+            attempt += 1;
+        }
+    }
+
+    /// Issue a cached, rate-limited GET against `url`+`params`: a fresh `COINGECKO_CACHE` hit
+    /// is served directly; a soft-expired (stale-but-servable) hit is refreshed through
+    /// `get_with_retry` only if a token is available right now, otherwise the stale value is
+    /// served as-is rather than blocking or erroring; a true cache miss waits for a token
+    /// (enforcing the shared rate limit) before fetching. `ttl` governs how long the entry is
+    /// served fresh and `stale_ttl` how much longer it stays servable-but-stale afterward.
+    async fn get_cached(&self, url: &str, params: &[(&str, &str)], ttl: Duration, stale_ttl: Duration) -> Result<Value, String> {
+        let key = crate::cache::cache_key(url, params);
+
+        if let Some((value, soft_expired)) = COINGECKO_CACHE.get_with_staleness(&key).await {
+            if !soft_expired {
+                return Ok(value);
+            }
+            if COINGECKO_RATE_LIMITER.try_acquire().await {
+                if let Ok(fresh) = self.fetch_json(url, params, ttl, stale_ttl).await {
+                    return Ok(fresh);
+                }
+            }
+            return Ok(value);
+        }
+
+        if !COINGECKO_RATE_LIMITER.try_acquire().await {
+            COINGECKO_RATE_LIMITER.acquire().await;
+        }
+        self.fetch_json(url, params, ttl, stale_ttl).await
+    }
+
+    async fn fetch_json(&self, url: &str, params: &[(&str, &str)], ttl: Duration, stale_ttl: Duration) -> Result<Value, String> {
+        let response = self.get_with_retry(url, params).await?;
+        let value: Value = response
+            .json()
+            .await
+            .map_err(|e| format!("Failed to parse CoinGecko response: {e}"))?;
+        let key = crate::cache::cache_key(url, params);
+        COINGECKO_CACHE.set_with_stale(key, value.clone(), ttl, stale_ttl).await;
+        Ok(value)
+    }
+
     /// Fetch current market data for cryptocurrencies
     pub async fn get_market_data(
         &self,
@@ -117,7 +674,7 @@ impl CoinGeckoClient {
         price_change_percentage: &str,
     ) -> Result<Vec<CoinGeckoCoin>, String> {
         let url = format!("{}/coins/markets", self.base_url);
-        
+
         let params = [
             ("vs_currency", vs_currency),
             ("order", order),
@@ -127,27 +684,9 @@ impl CoinGeckoClient {
             ("price_change_percentage", price_change_percentage),
         ];
 
-        let response = self
-            .client
-            .get(&url)
-            .query(&params)
-            .send()
-            .await
-            .map_err(|e| format!("CoinGecko API request failed: {e}"))?;
-
-        if !response.status().is_success() {
-            return Err(format!(
-                "CoinGecko API returned status {}",
-                response.status()
-            ));
-        }
-
-        let coins: Vec<CoinGeckoCoin> = response
-            .json()
-            .await
-            .map_err(|e| format!("Failed to parse CoinGecko response: {e}"))?;
+        let value = self.get_cached(&url, &params, MARKET_LIST_CACHE_TTL, MARKET_LIST_CACHE_TTL).await?;
 
-        Ok(coins)
+        serde_json::from_value(value).map_err(|e| format!("Failed to parse CoinGecko response: {e}"))
     }
 
     /// Get top cryptocurrencies by market cap
@@ -156,7 +695,27 @@ impl CoinGeckoClient {
             .await
     }
 
-    /// Get top gainers in the last 24 hours
+    /// Fetch market data for a specific set of coin ids, e.g. `["bitcoin", "ethereum"]` --
+    /// the `/coins/markets` endpoint [`Self::get_market_data`] wraps also accepts an `ids`
+    /// filter, so this is the same call with that filter applied instead of a market-cap page.
+    pub async fn get_markets_by_ids(&self, ids: &[String], vs_currency: &str) -> Result<Vec<CoinGeckoCoin>, String> {
+        if ids.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        let url = format!("{}/coins/markets", self.base_url);
+        let ids_param = ids.join(",");
+        let params = [("vs_currency", vs_currency), ("ids", ids_param.as_str())];
+
+        let value = self.get_cached(&url, &params, MARKET_LIST_CACHE_TTL, MARKET_LIST_CACHE_TTL).await?;
+
+        serde_json::from_value(value).map_err(|e| format!("Failed to parse CoinGecko response: {e}"))
+    }
+
+    /// Get top gainers in the last 24 hours. A coin missing `current_price` or
+    /// `price_change_percentage_24h` is excluded rather than sorted as if it moved 0% --
+    /// CoinGecko's per-coin fields go missing often enough (delisted quote currency, thin
+    /// markets) that treating "no data" as "unchanged" would misrank it among real gainers.
     pub async fn get_top_gainers(&self, limit: usize) -> Result<Vec<CoinGeckoCoin>, String> {
         let coins = self
             .get_market_data("usd", "market_cap_desc", 100, 1, false, "24h")
@@ -164,20 +723,21 @@ impl CoinGeckoClient {
 
         let mut gainers: Vec<CoinGeckoCoin> = coins
             .into_iter()
-            .filter(|coin| coin.price_change_percentage_24h.is_some())
+            .filter(|coin| coin.current_price.is_some() && coin.price_change_percentage_24h.is_some())
             .collect();
 
         gainers.sort_by(|a, b| {
-            b.price_change_percentage_24h
-                .unwrap_or(0.0)
-                .partial_cmp(&a.price_change_percentage_24h.unwrap_or(0.0))
-                .unwrap_or(std::cmp::Ordering::Equal)
+            // Safe: both are `Some` after the filter above.
+            let a_change = a.price_change_percentage_24h.expect("filtered for Some above");
+            let b_change = b.price_change_percentage_24h.expect("filtered for Some above");
+            b_change.partial_cmp(&a_change).unwrap_or(std::cmp::Ordering::Equal)
         });
 
         Ok(gainers.into_iter().take(limit).collect())
     }
 
-    /// Get top losers in the last 24 hours
+    /// Get top losers in the last 24 hours. Same missing-data exclusion as
+    /// [`Self::get_top_gainers`].
     pub async fn get_top_losers(&self, limit: usize) -> Result<Vec<CoinGeckoCoin>, String> {
         let coins = self
             .get_market_data("usd", "market_cap_desc", 100, 1, false, "24h")
@@ -185,14 +745,13 @@ impl CoinGeckoClient {
 
         let mut losers: Vec<CoinGeckoCoin> = coins
             .into_iter()
-            .filter(|coin| coin.price_change_percentage_24h.is_some())
+            .filter(|coin| coin.current_price.is_some() && coin.price_change_percentage_24h.is_some())
             .collect();
 
         losers.sort_by(|a, b| {
-            a.price_change_percentage_24h
-                .unwrap_or(0.0)
-                .partial_cmp(&b.price_change_percentage_24h.unwrap_or(0.0))
-                .unwrap_or(std::cmp::Ordering::Equal)
+            let a_change = a.price_change_percentage_24h.expect("filtered for Some above");
+            let b_change = b.price_change_percentage_24h.expect("filtered for Some above");
+            a_change.partial_cmp(&b_change).unwrap_or(std::cmp::Ordering::Equal)
         });
 
         Ok(losers.into_iter().take(limit).collect())
@@ -202,24 +761,7 @@ impl CoinGeckoClient {
     pub async fn get_trending_coins(&self) -> Result<Vec<TrendingItem>, String> {
         let url = format!("{}/search/trending", self.base_url);
 
-        let response = self
-            .client
-            .get(&url)
-            .send()
-            .await
-            .map_err(|e| format!("CoinGecko trending request failed: {e}"))?;
-
-        if !response.status().is_success() {
-            return Err(format!(
-                "CoinGecko trending API returned status {}",
-                response.status()
-            ));
-        }
-
-        let data: Value = response
-            .json()
-            .await
-            .map_err(|e| format!("Failed to parse trending response: {e}"))?;
+        let data = self.get_cached(&url, &[], MARKET_LIST_CACHE_TTL, MARKET_LIST_CACHE_TTL).await?;
 
         let coins = data
             .get("coins")
@@ -256,8 +798,92 @@ impl CoinGeckoClient {
         Ok(trending_items)
     }
 
-    /// Get market overview statistics
+    /// Get the authoritative market-wide totals from `/global`. These are computed by
+    /// CoinGecko across every tracked coin, not just the first page of `/coins/markets`, so
+    /// they don't suffer the undercounting that [`CoinGeckoClient::get_market_overview_top_coins`]
+    /// does.
+    pub async fn get_global(&self) -> Result<GlobalMarketData, String> {
+        let url = format!("{}/global", self.base_url);
+
+        let payload = self.get_cached(&url, &[], MARKET_LIST_CACHE_TTL, MARKET_LIST_CACHE_TTL).await?;
+
+        let data = payload
+            .get("data")
+            .ok_or_else(|| "CoinGecko global response missing 'data' field".to_string())?;
+
+        serde_json::from_value(data.clone())
+            .map_err(|e| format!("Failed to parse CoinGecko global data: {e}"))
+    }
+
+    /// Get a snapshot of the DeFi sector's size from `/global/decentralized_finance_defi`,
+    /// relative to total market cap ([`GlobalMarketData`]) and to ETH.
+    pub async fn get_defi_overview(&self) -> Result<DefiOverview, String> {
+        let url = format!("{}/global/decentralized_finance_defi", self.base_url);
+
+        let response = self.get_with_retry(&url, &[]).await?;
+
+        let payload: Value = response
+            .json()
+            .await
+            .map_err(|e| format!("Failed to parse CoinGecko response: {e}"))?;
+
+        let data = payload
+            .get("data")
+            .ok_or_else(|| "CoinGecko DeFi response missing 'data' field".to_string())?;
+
+        serde_json::from_value(data.clone())
+            .map_err(|e| format!("Failed to parse CoinGecko DeFi data: {e}"))
+    }
+
+    /// Get market overview statistics using the authoritative `/global` totals for
+    /// `total_market_cap`, `total_volume`, `bitcoin_dominance` and `market_cap_percentage`.
+    /// The top-100 list is still used for the per-coin `volume_percentage` breakdown, since
+    /// `/global` doesn't expose that. See
+    /// [`CoinGeckoClient::get_market_overview_top_coins`] for the older, less accurate path
+    /// that sums only the top 100 coins for every figure.
     pub async fn get_market_overview(&self) -> Result<MarketOverview, String> {
+        let global = self.get_global().await?;
+        let coins = self
+            .get_market_data("usd", "market_cap_desc", 100, 1, false, "24h")
+            .await?;
+
+        let total_market_cap = global.total_market_cap.get("usd").copied().unwrap_or(0.0);
+        let total_volume = global.total_volume.get("usd").copied().unwrap_or(0.0);
+        let bitcoin_dominance = global.market_cap_percentage.get("btc").copied().unwrap_or(0.0);
+
+        let mut volume_percentage = HashMap::new();
+        for coin in &coins {
+            if let Some(volume) = coin.total_volume {
+                if total_volume > 0.0 {
+                    volume_percentage.insert(coin.symbol.clone(), (volume / total_volume) * 100.0);
+                }
+            }
+        }
+
+        Ok(MarketOverview {
+            total_market_cap,
+            total_volume,
+            bitcoin_dominance,
+            market_cap_percentage: global.market_cap_percentage,
+            volume_percentage,
+            market_cap_change_percentage_24h: Some(global.market_cap_change_percentage_24h_usd),
+            total_market_cap_exact: None,
+            total_volume_exact: None,
+            total_volume_by_currency: global.total_volume,
+            active_cryptocurrencies: global.active_cryptocurrencies,
+            markets: global.markets,
+            upcoming_icos: global.upcoming_icos,
+            ongoing_icos: global.ongoing_icos,
+            ended_icos: global.ended_icos,
+            updated_at: global.updated_at,
+        })
+    }
+
+    /// The original market overview heuristic: sums `total_market_cap`, `total_volume` and
+    /// `bitcoin_dominance` from only the top 100 coins by market cap, which systematically
+    /// undercounts all three relative to [`CoinGeckoClient::get_market_overview`]. Kept for
+    /// callers that depend on this exact (less accurate) behavior.
+    pub async fn get_market_overview_top_coins(&self) -> Result<MarketOverview, String> {
         let coins = self
             .get_market_data("usd", "market_cap_desc", 100, 1, false, "24h")
             .await?;
@@ -312,6 +938,104 @@ impl CoinGeckoClient {
             bitcoin_dominance,
             market_cap_percentage,
             volume_percentage,
+            market_cap_change_percentage_24h: None,
+            total_market_cap_exact: None,
+            total_volume_exact: None,
+            total_volume_by_currency: HashMap::new(),
+            active_cryptocurrencies: 0,
+            markets: 0,
+            upcoming_icos: 0,
+            ongoing_icos: 0,
+            ended_icos: 0,
+            updated_at: 0,
+        })
+    }
+
+    /// Like [`CoinGeckoClient::get_market_overview_top_coins`], but sums `total_market_cap`
+    /// and `total_volume` in exact `Decimal` arithmetic instead of `f64`. Parses each coin's
+    /// raw `market_cap`/`total_volume` JSON fields directly -- accepting either a JSON number
+    /// or a numeric string -- rather than going through the already-lossy `f64` fields on
+    /// [`CoinGeckoCoin`], so summing up to 100 trillion-dollar-scale values doesn't drift.
+    /// `f64` conversion only happens at the end, for the existing `MarketOverview` fields; the
+    /// exact sums are also exposed via `total_market_cap_exact`/`total_volume_exact`.
+    pub async fn get_market_overview_top_coins_exact(&self) -> Result<MarketOverview, String> {
+        let url = format!("{}/coins/markets", self.base_url);
+        let params = [
+            ("vs_currency", "usd"),
+            ("order", "market_cap_desc"),
+            ("per_page", "100"),
+            ("page", "1"),
+            ("sparkline", "false"),
+            ("price_change_percentage", "24h"),
+        ];
+
+        let response = self.get_with_retry(&url, &params).await?;
+        let rows: Vec<Value> = response
+            .json()
+            .await
+            .map_err(|e| format!("Failed to parse CoinGecko response: {e}"))?;
+
+        let mut total_market_cap = Decimal::ZERO;
+        let mut total_volume = Decimal::ZERO;
+        let per_coin: Vec<(String, Option<Decimal>, Option<Decimal>)> = rows
+            .iter()
+            .map(|row| {
+                let symbol = row.get("symbol").and_then(|v| v.as_str()).unwrap_or_default().to_lowercase();
+                let market_cap = row.get("market_cap").and_then(decimal_field);
+                let volume = row.get("total_volume").and_then(decimal_field);
+                if let Some(market_cap) = market_cap {
+                    total_market_cap += market_cap;
+                }
+                if let Some(volume) = volume {
+                    total_volume += volume;
+                }
+                (symbol, market_cap, volume)
+            })
+            .collect();
+
+        let bitcoin_dominance = per_coin
+            .iter()
+            .find(|(symbol, ..)| symbol == "btc")
+            .and_then(|(_, market_cap, _)| *market_cap)
+            .filter(|_| !total_market_cap.is_zero())
+            .and_then(|btc_market_cap| (btc_market_cap / total_market_cap * Decimal::ONE_HUNDRED).to_f64())
+            .unwrap_or(0.0);
+
+        let mut market_cap_percentage = HashMap::new();
+        let mut volume_percentage = HashMap::new();
+        for (symbol, market_cap, volume) in &per_coin {
+            if let Some(market_cap) = market_cap {
+                if !total_market_cap.is_zero() {
+                    if let Some(pct) = (*market_cap / total_market_cap * Decimal::ONE_HUNDRED).to_f64() {
+                        market_cap_percentage.insert(symbol.clone(), pct);
+                    }
+                }
+            }
+            if let Some(volume) = volume {
+                if !total_volume.is_zero() {
+                    if let Some(pct) = (*volume / total_volume * Decimal::ONE_HUNDRED).to_f64() {
+                        volume_percentage.insert(symbol.clone(), pct);
+                    }
+                }
+            }
+        }
+
+        Ok(MarketOverview {
+            total_market_cap: total_market_cap.to_f64().unwrap_or(0.0),
+            total_volume: total_volume.to_f64().unwrap_or(0.0),
+            bitcoin_dominance,
+            market_cap_percentage,
+            volume_percentage,
+            market_cap_change_percentage_24h: None,
+            total_market_cap_exact: Some(total_market_cap),
+            total_volume_exact: Some(total_volume),
+            total_volume_by_currency: HashMap::new(),
+            active_cryptocurrencies: 0,
+            markets: 0,
+            upcoming_icos: 0,
+            ongoing_icos: 0,
+            ended_icos: 0,
+            updated_at: 0,
         })
     }
 
@@ -329,32 +1053,12 @@ impl CoinGeckoClient {
         let include_change = if include_24hr_change { "true".to_string() } else { "false".to_string() };
         
         let params = vec![
-            ("ids", &ids_str),
-            ("vs_currencies", &vs_currencies_str),
-            ("include_24hr_change", &include_change),
+            ("ids", ids_str.as_str()),
+            ("vs_currencies", vs_currencies_str.as_str()),
+            ("include_24hr_change", include_change.as_str()),
         ];
 
-        let response = self
-            .client
-            .get(&url)
-            .query(&params)
-            .send()
-            .await
-            .map_err(|e| format!("CoinGecko simple price request failed: {e}"))?;
-
-        if !response.status().is_success() {
-            return Err(format!(
-                "CoinGecko simple price API returned status {}",
-                response.status()
-            ));
-        }
-
-        let data: Value = response
-            .json()
-            .await
-            .map_err(|e| format!("Failed to parse simple price response: {e}"))?;
-
-        Ok(data)
+        self.get_cached(&url, &params, PRICE_CACHE_TTL, PRICE_CACHE_TTL).await
     }
 
     /// Get comprehensive market context (similar to the Python fetch_mcp_context function)
@@ -368,13 +1072,13 @@ impl CoinGeckoClient {
 
         // Top 10 by market cap
         for (i, coin) in coins.iter().take(10).enumerate() {
-            let price = coin.current_price.unwrap_or(0.0);
-            let change_24h = coin.price_change_percentage_24h.unwrap_or(0.0);
-            let change_7d = coin.price_change_percentage_7d_in_currency.unwrap_or(0.0);
-            let change_30d = coin.price_change_percentage_30d_in_currency.unwrap_or(0.0);
+            let price = format_opt(coin.current_price, |p| format!("${p:.2}"));
+            let change_24h = format_opt(coin.price_change_percentage_24h, |c| format!("{c:+.2}%"));
+            let change_7d = format_opt(coin.price_change_percentage_7d_in_currency, |c| format!("{c:+.2}%"));
+            let change_30d = format_opt(coin.price_change_percentage_30d_in_currency, |c| format!("{c:+.2}%"));
 
             context_parts.push(format!(
-                "{}. {} ({}): ${:.2} | 24h: {:+.2}% | 7d: {:+.2}% | 30d: {:+.2}%",
+                "{}. {} ({}): {} | 24h: {} | 7d: {} | 30d: {}",
                 i + 1,
                 coin.name,
                 coin.symbol.to_uppercase(),
@@ -385,7 +1089,7 @@ impl CoinGeckoClient {
             ));
         }
 
-        // Top gainers
+        // Top gainers (already filtered to coins with known price and 24h change)
         let gainers = self.get_top_gainers(5).await?;
         context_parts.push("\nTOP 24H GAINERS:".to_string());
         for (i, coin) in gainers.iter().enumerate() {
@@ -401,7 +1105,7 @@ impl CoinGeckoClient {
             ));
         }
 
-        // Top losers
+        // Top losers (already filtered to coins with known price and 24h change)
         let losers = self.get_top_losers(5).await?;
         context_parts.push("\nTOP 24H LOSERS:".to_string());
         for (i, coin) in losers.iter().enumerate() {
@@ -444,41 +1148,356 @@ impl CoinGeckoClient {
             .map(|coin| coin.symbol.to_uppercase())
             .collect())
     }
+
+    /// Get per-market trading pairs for a coin, optionally restricted to `exchange_ids` and
+    /// including CoinGecko's deeper order-book stats when `depth` is set.
+    pub async fn get_coin_tickers(
+        &self,
+        id: &str,
+        exchange_ids: Option<&[String]>,
+        depth: bool,
+    ) -> Result<Vec<MarketTicker>, String> {
+        let url = format!("{}/coins/{}/tickers", self.base_url, id);
+
+        let exchange_ids_str = exchange_ids.map(|ids| ids.join(","));
+        let depth_str = depth.to_string();
+        let mut params = vec![("depth", depth_str.as_str())];
+        if let Some(exchange_ids_str) = exchange_ids_str.as_deref() {
+            params.push(("exchange_ids", exchange_ids_str));
+        }
+
+        let response = self.get_with_retry(&url, &params).await?;
+
+        let data: Value = response
+            .json()
+            .await
+            .map_err(|e| format!("Failed to parse tickers response: {e}"))?;
+
+        let tickers = data
+            .get("tickers")
+            .and_then(|t| t.as_array())
+            .ok_or("No tickers data found in response")?;
+
+        let tickers: Vec<MarketTicker> = tickers
+            .iter()
+            .filter_map(|ticker| {
+                Some(MarketTicker {
+                    base: ticker.get("base")?.as_str()?.to_string(),
+                    target: ticker.get("target")?.as_str()?.to_string(),
+                    market_name: ticker.get("market")?.get("name")?.as_str()?.to_string(),
+                    last: ticker.get("last")?.as_f64()?,
+                    volume: ticker.get("volume")?.as_f64()?,
+                    bid_ask_spread_percentage: ticker.get("bid_ask_spread_percentage").and_then(|v| v.as_f64()),
+                    trust_score: ticker.get("trust_score").and_then(|v| v.as_str()).map(str::to_string),
+                })
+            })
+            .collect();
+
+        Ok(tickers)
+    }
+
+    /// Get OHLC candles for a coin over `days` of history.
+    pub async fn get_coin_ohlc(&self, id: &str, vs_currency: &str, days: u32) -> Result<Vec<Ohlc>, String> {
+        let url = format!("{}/coins/{}/ohlc", self.base_url, id);
+        let days_str = days.to_string();
+        let params = vec![("vs_currency", vs_currency), ("days", days_str.as_str())];
+
+        let response = self.get_with_retry(&url, &params).await?;
+
+        let rows: Vec<[f64; 5]> = response
+            .json()
+            .await
+            .map_err(|e| format!("Failed to parse OHLC response: {e}"))?;
+
+        Ok(rows
+            .into_iter()
+            .map(|row| Ohlc {
+                timestamp: row[0] as i64,
+                open: row[1],
+                high: row[2],
+                low: row[3],
+                close: row[4],
+            })
+            .collect())
+    }
+
+    /// Get historical price/market-cap/volume series for a coin over `days` of history.
+    /// `interval` lets callers request a coarser granularity (e.g. `"daily"`); CoinGecko
+    /// infers the granularity from `days` when it's `None`.
+    pub async fn get_market_chart(
+        &self,
+        id: &str,
+        vs_currency: &str,
+        days: u32,
+        interval: Option<&str>,
+    ) -> Result<MarketChart, String> {
+        let url = format!("{}/coins/{}/market_chart", self.base_url, id);
+        let days_str = days.to_string();
+        let mut params = vec![("vs_currency", vs_currency), ("days", days_str.as_str())];
+        if let Some(interval) = interval {
+            params.push(("interval", interval));
+        }
+
+        let response = self.get_with_retry(&url, &params).await?;
+
+        #[derive(Deserialize)]
+        struct RawMarketChart {
+            prices: Vec<(f64, f64)>,
+            market_caps: Vec<(f64, f64)>,
+            total_volumes: Vec<(f64, f64)>,
+        }
+
+        let raw: RawMarketChart = response
+            .json()
+            .await
+            .map_err(|e| format!("Failed to parse market chart response: {e}"))?;
+
+        let to_i64_pairs = |points: Vec<(f64, f64)>| -> Vec<(i64, f64)> {
+            points.into_iter().map(|(ts, v)| (ts as i64, v)).collect()
+        };
+
+        Ok(MarketChart {
+            prices: to_i64_pairs(raw.prices),
+            market_caps: to_i64_pairs(raw.market_caps),
+            total_volumes: to_i64_pairs(raw.total_volumes),
+        })
+    }
+
+    /// Native OHLC candles from `/coins/{id}/ohlc`, with volume joined in from
+    /// [`CoinGeckoClient::get_market_chart`]'s `total_volumes` series (nearest-timestamp match,
+    /// since the two endpoints don't share a bucket grid) and an explicit `interval_seconds` so
+    /// callers don't have to re-derive CoinGecko's days-dependent bucket width themselves.
+    pub async fn get_ohlc(&self, id: &str, vs_currency: &str, days: u32) -> Result<CandleSeries, String> {
+        let ohlc = self.get_coin_ohlc(id, vs_currency, days).await?;
+        let volumes = self
+            .get_market_chart(id, vs_currency, days, None)
+            .await
+            .map(|chart| chart.total_volumes)
+            .unwrap_or_default();
+
+        let candles = ohlc
+            .into_iter()
+            .map(|c| Candle {
+                ts: c.timestamp,
+                open: c.open,
+                high: c.high,
+                low: c.low,
+                close: c.close,
+                volume: nearest_value(&volumes, c.timestamp),
+            })
+            .collect();
+
+        Ok(CandleSeries {
+            interval_seconds: ohlc_interval_seconds(days),
+            candles,
+        })
+    }
+
+    /// Candles resampled from `/coins/{id}/market_chart`'s raw price series at `bucket_seconds`
+    /// via [`resample_to_candles`], with volume joined in from the same response's
+    /// `total_volumes` series by nearest timestamp. The untouched trade-level series is still
+    /// available unresampled via [`CoinGeckoClient::get_market_chart`] for callers that want the
+    /// raw points instead of fixed-width candles.
+    pub async fn get_market_chart_candles(
+        &self,
+        id: &str,
+        vs_currency: &str,
+        days: u32,
+        interval: Option<&str>,
+        bucket_seconds: i64,
+    ) -> Result<CandleSeries, String> {
+        let chart = self.get_market_chart(id, vs_currency, days, interval).await?;
+        let price_candles = resample_to_candles(&chart.prices, bucket_seconds);
+
+        let candles = price_candles
+            .into_iter()
+            .map(|c| Candle {
+                ts: c.timestamp,
+                open: c.open,
+                high: c.high,
+                low: c.low,
+                close: c.close,
+                volume: nearest_value(&chart.total_volumes, c.timestamp),
+            })
+            .collect();
+
+        Ok(CandleSeries {
+            interval_seconds: market_chart_interval_seconds(days),
+            candles,
+        })
+    }
+}
+
+/// Normalizes [`CoinGeckoClient`] behind [`crate::services::rate_provider::RateProvider`],
+/// mirroring [`crate::sources::kraken_data::KrakenDataSource`]'s impl, so the
+/// trending/market-summary pipeline can widen coverage beyond Kraken to any CoinGecko coin id
+/// without being pinned to a single venue. `pairs`/`pair` here are CoinGecko coin ids (e.g.
+/// `"bitcoin"`), quoted against USD.
+#[async_trait::async_trait]
+impl crate::services::rate_provider::RateProvider for CoinGeckoClient {
+    fn provider_name(&self) -> &'static str {
+        "coingecko"
+    }
+
+    async fn tickers(
+        &self,
+        pairs: Vec<String>,
+    ) -> Result<Vec<crate::services::rate_provider::NormalizedTicker>, crate::errors::ApiError> {
+        let coins = self
+            .get_markets_by_ids(&pairs, "usd")
+            .await
+            .map_err(crate::errors::ApiError::Upstream)?;
+
+        Ok(coins
+            .into_iter()
+            .filter_map(|c| {
+                Some(crate::services::rate_provider::NormalizedTicker {
+                    pair: c.id,
+                    price: c.current_price?,
+                    bid: None,
+                    ask: None,
+                    volume: c.total_volume.unwrap_or(0.0),
+                    high_24h: c.high_24h,
+                    low_24h: c.low_24h,
+                    change_24h: c.price_change_24h,
+                    change_pct_24h: c.price_change_percentage_24h,
+                })
+            })
+            .collect())
+    }
+
+    async fn order_book(
+        &self,
+        _pair: &str,
+        _depth: u32,
+    ) -> Result<crate::services::rate_provider::NormalizedOrderBook, crate::errors::ApiError> {
+        // CoinGecko has no venue-agnostic order book of its own -- `/coin-tickers` exposes
+        // per-exchange bid/ask spreads instead (see `routes::coingecko::get_coin_tickers_route`).
+        Err(crate::errors::ApiError::BadRequest(
+            "CoinGecko has no aggregated order book; use /coingecko/coin-tickers for a specific exchange".to_string(),
+        ))
+    }
+}
+
+/// A normalized ticker plus a per-exchange markets snapshot for `coin_id`, the CoinGecko analog
+/// of [`crate::sources::kraken_data::get_market_summary`] -- CoinGecko has no unified order
+/// book/trade feed to mirror Kraken's `order_book`/`recent_trades` fields, so `markets` (its
+/// per-exchange ticker list) fills that role instead.
+pub async fn get_market_summary(
+    provider: &dyn crate::services::rate_provider::RateProvider,
+    coin_id: &str,
+) -> Result<Value, String> {
+    let mut summary = serde_json::Map::new();
+
+    if let Ok(tickers) = provider.tickers(vec![coin_id.to_string()]).await {
+        if let Some(ticker) = tickers.first() {
+            summary.insert(
+                "ticker".to_string(),
+                serde_json::json!({
+                    "pair": ticker.pair,
+                    "price": ticker.price,
+                    "bid": ticker.bid,
+                    "ask": ticker.ask,
+                    "volume": ticker.volume,
+                    "high_24h": ticker.high_24h,
+                    "low_24h": ticker.low_24h,
+                    "change_24h": ticker.change_24h,
+                    "change_pct_24h": ticker.change_pct_24h,
+                }),
+            );
+        }
+    }
+
+    let client = CoinGeckoClient::from_env();
+    if let Ok(markets) = client.get_coin_tickers(coin_id, None, false).await {
+        summary.insert("markets".to_string(), serde_json::to_value(markets).unwrap_or_default());
+    }
+
+    Ok(Value::Object(summary))
 }
 
 // Convenience functions for easy access
 pub async fn get_top_coins(limit: usize) -> Result<Vec<CoinGeckoCoin>, String> {
-    let client = CoinGeckoClient::new();
+    let client = CoinGeckoClient::from_env();
     client.get_top_coins(limit).await
 }
 
 pub async fn get_top_gainers(limit: usize) -> Result<Vec<CoinGeckoCoin>, String> {
-    let client = CoinGeckoClient::new();
+    let client = CoinGeckoClient::from_env();
     client.get_top_gainers(limit).await
 }
 
 pub async fn get_top_losers(limit: usize) -> Result<Vec<CoinGeckoCoin>, String> {
-    let client = CoinGeckoClient::new();
+    let client = CoinGeckoClient::from_env();
     client.get_top_losers(limit).await
 }
 
 pub async fn get_trending_coins() -> Result<Vec<TrendingItem>, String> {
-    let client = CoinGeckoClient::new();
+    let client = CoinGeckoClient::from_env();
     client.get_trending_coins().await
 }
 
 pub async fn get_market_overview() -> Result<MarketOverview, String> {
-    let client = CoinGeckoClient::new();
+    let client = CoinGeckoClient::from_env();
     client.get_market_overview().await
 }
 
+pub async fn get_market_overview_top_coins() -> Result<MarketOverview, String> {
+    let client = CoinGeckoClient::from_env();
+    client.get_market_overview_top_coins().await
+}
+
+/// Top coins, trending coins, and the market overview, fetched in parallel and cached/retried
+/// via [`crate::helpers::resilient_fetch::ResilientFetch`] rather than each call hitting
+/// CoinGecko independently. One source timing out or erroring doesn't drop the other two --
+/// check each [`crate::helpers::resilient_fetch::FetchOutcome::error`] to see what's missing.
+pub async fn get_market_snapshot(
+    limit: usize,
+) -> HashMap<String, crate::helpers::resilient_fetch::FetchOutcome> {
+    crate::helpers::resilient_fetch::ResilientFetch::new()
+        .fetcher("top_coins", move || async move {
+            get_top_coins(limit)
+                .await
+                .and_then(|v| serde_json::to_value(v).map_err(|e| e.to_string()))
+        })
+        .fetcher("trending_coins", || async {
+            get_trending_coins()
+                .await
+                .and_then(|v| serde_json::to_value(v).map_err(|e| e.to_string()))
+        })
+        .fetcher("market_overview", || async {
+            get_market_overview()
+                .await
+                .and_then(|v| serde_json::to_value(v).map_err(|e| e.to_string()))
+        })
+        .timeout(Duration::from_secs(10))
+        .cache("coingecko_market_snapshot", Duration::from_secs(60))
+        .run()
+        .await
+}
+
+pub async fn get_market_overview_top_coins_exact() -> Result<MarketOverview, String> {
+    let client = CoinGeckoClient::from_env();
+    client.get_market_overview_top_coins_exact().await
+}
+
+pub async fn get_global() -> Result<GlobalMarketData, String> {
+    let client = CoinGeckoClient::from_env();
+    client.get_global().await
+}
+
+pub async fn get_defi_overview() -> Result<DefiOverview, String> {
+    let client = CoinGeckoClient::from_env();
+    client.get_defi_overview().await
+}
+
 pub async fn get_market_context() -> Result<String, String> {
-    let client = CoinGeckoClient::new();
+    let client = CoinGeckoClient::from_env();
     client.get_market_context().await
 }
 
 pub async fn get_trending_cryptos() -> Result<Vec<String>, String> {
-    let client = CoinGeckoClient::new();
+    let client = CoinGeckoClient::from_env();
     client.get_trending_cryptos().await
 }
 
@@ -487,10 +1506,50 @@ pub async fn get_simple_price(
     vs_currencies: &[String],
     include_24hr_change: bool,
 ) -> Result<Value, String> {
-    let client = CoinGeckoClient::new();
+    let client = CoinGeckoClient::from_env();
     client.get_simple_price(ids, vs_currencies, include_24hr_change).await
 }
 
+pub async fn get_coin_tickers(
+    id: &str,
+    exchange_ids: Option<&[String]>,
+    depth: bool,
+) -> Result<Vec<MarketTicker>, String> {
+    let client = CoinGeckoClient::from_env();
+    client.get_coin_tickers(id, exchange_ids, depth).await
+}
+
+pub async fn get_coin_ohlc(id: &str, vs_currency: &str, days: u32) -> Result<Vec<Ohlc>, String> {
+    let client = CoinGeckoClient::from_env();
+    client.get_coin_ohlc(id, vs_currency, days).await
+}
+
+pub async fn get_market_chart(
+    id: &str,
+    vs_currency: &str,
+    days: u32,
+    interval: Option<&str>,
+) -> Result<MarketChart, String> {
+    let client = CoinGeckoClient::from_env();
+    client.get_market_chart(id, vs_currency, days, interval).await
+}
+
+pub async fn get_ohlc(id: &str, vs_currency: &str, days: u32) -> Result<CandleSeries, String> {
+    let client = CoinGeckoClient::from_env();
+    client.get_ohlc(id, vs_currency, days).await
+}
+
+pub async fn get_market_chart_candles(
+    id: &str,
+    vs_currency: &str,
+    days: u32,
+    interval: Option<&str>,
+    bucket_seconds: i64,
+) -> Result<CandleSeries, String> {
+    let client = CoinGeckoClient::from_env();
+    client.get_market_chart_candles(id, vs_currency, days, interval, bucket_seconds).await
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -559,12 +1618,121 @@ mod tests {
             }
             Err(e) => {
                 // Allow rate limiting errors
-                assert!(e.contains("429") || e.contains("rate limit") || e.contains("Too Many Requests"), 
+                assert!(e.contains("429") || e.contains("rate limit") || e.contains("Too Many Requests"),
+                        "Unexpected error: {e}");
+            }
+        }
+    }
+
+    #[tokio::test]
+    async fn test_get_global_function() {
+        let result = get_global().await;
+        match result {
+            Ok(global) => {
+                assert!(global.total_market_cap.contains_key("usd"), "Should have a USD total market cap");
+                assert!(global.market_cap_percentage.contains_key("btc"), "Should have a BTC dominance figure");
+            }
+            Err(e) => {
+                // Allow rate limiting errors
+                assert!(e.contains("429") || e.contains("rate limit") || e.contains("Too Many Requests"),
                         "Unexpected error: {e}");
             }
         }
     }
 
+    #[tokio::test]
+    async fn test_get_defi_overview_function() {
+        let result = get_defi_overview().await;
+        match result {
+            Ok(defi) => {
+                assert!(defi.defi_market_cap >= 0.0, "DeFi market cap should be non-negative");
+                assert!(!defi.top_coin_name.is_empty(), "Top coin name should not be empty");
+            }
+            Err(e) => {
+                // Allow rate limiting errors
+                assert!(e.contains("429") || e.contains("rate limit") || e.contains("Too Many Requests"),
+                        "Unexpected error: {e}");
+            }
+        }
+    }
+
+    #[test]
+    fn test_f64_from_string_or_number_round_trips_both_forms() {
+        #[derive(Deserialize)]
+        struct Wrapper {
+            #[serde(deserialize_with = "f64_from_string_or_number")]
+            value: f64,
+        }
+
+        let from_string: Wrapper = serde_json::from_str(r#"{"value": "123.45"}"#).unwrap();
+        assert_eq!(from_string.value, 123.45);
+
+        let from_number: Wrapper = serde_json::from_str(r#"{"value": 123.45}"#).unwrap();
+        assert_eq!(from_number.value, 123.45);
+    }
+
+    #[tokio::test]
+    async fn test_get_market_overview_top_coins_function() {
+        let result = get_market_overview_top_coins().await;
+        match result {
+            Ok(overview) => {
+                assert!(overview.total_market_cap > 0.0, "Total market cap should be positive");
+                assert!(overview.total_volume >= 0.0, "Total volume should be non-negative");
+                assert!(overview.bitcoin_dominance >= 0.0, "Bitcoin dominance should be non-negative");
+                assert!(overview.bitcoin_dominance <= 100.0, "Bitcoin dominance should be <= 100%");
+                assert!(overview.market_cap_change_percentage_24h.is_none(), "Top-coins heuristic has no 24h change figure");
+            }
+            Err(e) => {
+                // Allow rate limiting errors
+                assert!(e.contains("429") || e.contains("rate limit") || e.contains("Too Many Requests"),
+                        "Unexpected error: {e}");
+            }
+        }
+    }
+
+    #[tokio::test]
+    async fn test_get_market_overview_top_coins_exact_function() {
+        let result = get_market_overview_top_coins_exact().await;
+        match result {
+            Ok(overview) => {
+                assert!(overview.total_market_cap > 0.0, "Total market cap should be positive");
+                assert!(overview.total_market_cap_exact.is_some(), "Exact path should populate total_market_cap_exact");
+                assert!(overview.total_volume_exact.is_some(), "Exact path should populate total_volume_exact");
+            }
+            Err(e) => {
+                // Allow rate limiting errors
+                assert!(e.contains("429") || e.contains("rate limit") || e.contains("Too Many Requests"),
+                        "Unexpected error: {e}");
+            }
+        }
+    }
+
+    #[test]
+    fn test_decimal_field_parses_numbers_and_strings() {
+        let number: Value = serde_json::from_str("1234567890123.45").unwrap();
+        let string = Value::String("1234567890123.45".to_string());
+
+        assert_eq!(decimal_field(&number), Decimal::from_str("1234567890123.45").ok());
+        assert_eq!(decimal_field(&string), Decimal::from_str("1234567890123.45").ok());
+        assert_eq!(decimal_field(&Value::Null), None);
+    }
+
+    #[test]
+    fn test_decimal_sum_avoids_f64_drift() {
+        // Many small fractional market caps that don't round exactly in binary floating
+        // point; summed a few thousand times, f64 accumulates visible drift while Decimal
+        // stays exact.
+        let mut exact = Decimal::ZERO;
+        let mut approx = 0.0f64;
+        for _ in 0..10_000 {
+            exact += Decimal::from_str("0.1").unwrap();
+            approx += 0.1;
+        }
+
+        assert_eq!(exact, Decimal::from_str("1000.0").unwrap());
+        assert_ne!(approx, 1000.0, "f64 summation is expected to drift off the exact value");
+    }
+
     #[tokio::test]
     async fn test_get_simple_price_function() {
         let ids = vec!["bitcoin".to_string(), "ethereum".to_string()];
@@ -613,6 +1781,71 @@ mod tests {
         }
     }
 
+    #[tokio::test]
+    async fn test_get_coin_tickers_function() {
+        let result = get_coin_tickers("bitcoin", None, false).await;
+        match result {
+            Ok(tickers) => {
+                for ticker in tickers {
+                    assert!(!ticker.base.is_empty(), "Ticker should have a base symbol");
+                    assert!(!ticker.target.is_empty(), "Ticker should have a target symbol");
+                    assert!(ticker.volume >= 0.0, "Volume should be non-negative");
+                }
+            }
+            Err(e) => {
+                // Allow rate limiting errors
+                assert!(e.contains("429") || e.contains("rate limit") || e.contains("Too Many Requests"),
+                        "Unexpected error: {e}");
+            }
+        }
+    }
+
+    #[tokio::test]
+    async fn test_get_coin_ohlc_function() {
+        let result = get_coin_ohlc("bitcoin", "usd", 1).await;
+        match result {
+            Ok(candles) => {
+                for candle in &candles {
+                    assert!(candle.high >= candle.low, "High should be >= low");
+                }
+            }
+            Err(e) => {
+                assert!(e.contains("429") || e.contains("rate limit") || e.contains("Too Many Requests"),
+                        "Unexpected error: {e}");
+            }
+        }
+    }
+
+    #[tokio::test]
+    async fn test_get_market_chart_function() {
+        let result = get_market_chart("bitcoin", "usd", 1, None).await;
+        match result {
+            Ok(chart) => {
+                assert!(!chart.prices.is_empty(), "Market chart should have price points");
+            }
+            Err(e) => {
+                assert!(e.contains("429") || e.contains("rate limit") || e.contains("Too Many Requests"),
+                        "Unexpected error: {e}");
+            }
+        }
+    }
+
+    #[test]
+    fn test_resample_to_candles_carries_close_forward_into_gaps() {
+        let points = vec![(0, 10.0), (1_000, 12.0), (2_000, 8.0), (10_000, 9.0)];
+        let candles = resample_to_candles(&points, 1);
+
+        // Buckets 0,1,2 come from points; 3..9 are gaps that should carry bucket 2's close
+        // forward; bucket 10 comes from the last point.
+        assert_eq!(candles.len(), 11);
+        assert_eq!(candles[0], Ohlc { timestamp: 0, open: 10.0, high: 10.0, low: 10.0, close: 10.0 });
+        assert_eq!(candles[2], Ohlc { timestamp: 2_000, open: 8.0, high: 8.0, low: 8.0, close: 8.0 });
+        for gap in &candles[3..10] {
+            assert_eq!(*gap, Ohlc { timestamp: gap.timestamp, open: 8.0, high: 8.0, low: 8.0, close: 8.0 });
+        }
+        assert_eq!(candles[10], Ohlc { timestamp: 10_000, open: 9.0, high: 9.0, low: 9.0, close: 9.0 });
+    }
+
     #[test]
     fn test_coin_gecko_coin_serialization() {
         let coin = CoinGeckoCoin {
@@ -677,6 +1910,16 @@ mod tests {
                 map.insert("eth".to_string(), 25.0);
                 map
             },
+            market_cap_change_percentage_24h: Some(1.2),
+            total_market_cap_exact: None,
+            total_volume_exact: None,
+            total_volume_by_currency: HashMap::new(),
+            active_cryptocurrencies: 10000,
+            markets: 900,
+            upcoming_icos: 0,
+            ongoing_icos: 0,
+            ended_icos: 0,
+            updated_at: 1_700_000_000,
         };
 
         // Test serialization
@@ -0,0 +1,272 @@
+use reqwest::header::{HeaderMap, HeaderValue, USER_AGENT};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::time::Duration;
+
+/// How finely a [`Candle`] series is bucketed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Resolution {
+    Minute,
+    Hour,
+    Day,
+}
+
+impl Resolution {
+    /// CryptoCompare's `histo{minute,hour,day}` path segment for this resolution.
+    fn endpoint(self) -> &'static str {
+        match self {
+            Resolution::Minute => "histominute",
+            Resolution::Hour => "histohour",
+            Resolution::Day => "histoday",
+        }
+    }
+}
+
+/// Upstream only retains minute-resolution candles for a rolling window; requests for
+/// history older than this must fall back to a coarser resolution instead.
+const MINUTE_RETENTION: chrono::Duration = chrono::Duration::days(7);
+
+/// One OHLCV candle, ordered by `time`.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize, utoipa::ToSchema)]
+pub struct Candle {
+    pub time: i64,
+    pub open: f64,
+    pub high: f64,
+    pub low: f64,
+    pub close: f64,
+    pub volume_from: f64,
+    pub volume_to: f64,
+}
+
+#[derive(Debug, Deserialize)]
+struct HistoResponse {
+    #[serde(rename = "Response")]
+    response: String,
+    #[serde(rename = "Message")]
+    message: Option<String>,
+    #[serde(rename = "Data")]
+    data: Option<HistoData>,
+}
+
+#[derive(Debug, Deserialize)]
+struct HistoData {
+    #[serde(rename = "Data")]
+    candles: Vec<Value>,
+}
+
+fn parse_candle(row: &Value) -> Option<Candle> {
+    Some(Candle {
+        time: row.get("time")?.as_i64()?,
+        open: row.get("open")?.as_f64()?,
+        high: row.get("high")?.as_f64()?,
+        low: row.get("low")?.as_f64()?,
+        close: row.get("close")?.as_f64()?,
+        volume_from: row.get("volumefrom").and_then(|v| v.as_f64()).unwrap_or(0.0),
+        volume_to: row.get("volumeto").and_then(|v| v.as_f64()).unwrap_or(0.0),
+    })
+}
+
+/// Client for CryptoCompare's historical `histominute`/`histohour`/`histoday` endpoints.
+pub struct CryptoCompareClient {
+    client: reqwest::Client,
+    base_url: String,
+}
+
+impl Default for CryptoCompareClient {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl CryptoCompareClient {
+    pub fn new() -> Self {
+        let mut headers = HeaderMap::new();
+        headers.insert(
+            USER_AGENT,
+            HeaderValue::from_static("Mozilla/5.0 (Windows NT 10.0; Win64; x64) AppleWebKit/537.36"),
+        );
+
+        let client = reqwest::Client::builder()
+            .default_headers(headers)
+            .timeout(Duration::from_secs(10))
+            .build()
+            .unwrap();
+
+        Self {
+            client,
+            base_url: "https://min-api.cryptocompare.com/data".to_string(),
+        }
+    }
+
+    /// Pick the coarsest resolution still fine enough for `requested`, downgrading to
+    /// `Hour`/`Day` when `lookback` exceeds how long upstream retains minute data.
+    fn effective_resolution(requested: Resolution, lookback: chrono::Duration) -> Resolution {
+        if requested == Resolution::Minute && lookback > MINUTE_RETENTION {
+            Resolution::Hour
+        } else {
+            requested
+        }
+    }
+
+    async fn fetch_candles(
+        &self,
+        base: &str,
+        quote: &str,
+        resolution: Resolution,
+        limit: u32,
+    ) -> Result<Vec<Candle>, String> {
+        let url = format!("{}/v2/{}", self.base_url, resolution.endpoint());
+        let response = self
+            .client
+            .get(&url)
+            .query(&[
+                ("fsym", base),
+                ("tsym", quote),
+                ("limit", &limit.to_string()),
+            ])
+            .send()
+            .await
+            .map_err(|e| format!("Failed to fetch {base}/{quote} candles: {e}"))?;
+
+        let parsed: HistoResponse = response
+            .json()
+            .await
+            .map_err(|e| format!("Failed to parse CryptoCompare response: {e}"))?;
+
+        if parsed.response != "Success" {
+            return Err(parsed
+                .message
+                .unwrap_or_else(|| "CryptoCompare request failed".to_string()));
+        }
+
+        let rows = parsed
+            .data
+            .map(|d| d.candles)
+            .unwrap_or_default();
+
+        Ok(rows.iter().filter_map(parse_candle).collect())
+    }
+
+    /// Fetch a `Vec<Candle>` for `base/quote` at `resolution`, automatically downgrading to
+    /// a coarser resolution when `lookback` exceeds upstream's minute-data retention window.
+    /// Falls back to converting through BTC (`base/BTC` then `BTC/quote`) when `base` doesn't
+    /// trade directly against `quote`.
+    pub async fn get_candles(
+        &self,
+        base: &str,
+        quote: &str,
+        resolution: Resolution,
+        limit: u32,
+        lookback: chrono::Duration,
+    ) -> Result<Vec<Candle>, String> {
+        let resolution = Self::effective_resolution(resolution, lookback);
+
+        match self.fetch_candles(base, quote, resolution, limit).await {
+            Ok(candles) if !candles.is_empty() => Ok(candles),
+            _ => self.get_candles_via_btc(base, quote, resolution, limit).await,
+        }
+    }
+
+    /// Convert a `base/quote` series through BTC for coins that don't trade directly against
+    /// `quote`: fetch `base/BTC` and `BTC/quote` at the same resolution and multiply the two
+    /// series together bucket-by-bucket.
+    async fn get_candles_via_btc(
+        &self,
+        base: &str,
+        quote: &str,
+        resolution: Resolution,
+        limit: u32,
+    ) -> Result<Vec<Candle>, String> {
+        if base.eq_ignore_ascii_case("btc") || quote.eq_ignore_ascii_case("btc") {
+            return Err(format!("no direct or BTC-convertible market for {base}/{quote}"));
+        }
+
+        let base_btc = self.fetch_candles(base, "BTC", resolution, limit).await?;
+        let btc_quote = self.fetch_candles("BTC", quote, resolution, limit).await?;
+
+        let mut btc_quote_by_time = std::collections::HashMap::new();
+        for candle in &btc_quote {
+            btc_quote_by_time.insert(candle.time, *candle);
+        }
+
+        let converted: Vec<Candle> = base_btc
+            .into_iter()
+            .filter_map(|b| {
+                let q = btc_quote_by_time.get(&b.time)?;
+                Some(Candle {
+                    time: b.time,
+                    open: b.open * q.open,
+                    high: b.high * q.high,
+                    low: b.low * q.low,
+                    close: b.close * q.close,
+                    volume_from: b.volume_from,
+                    volume_to: b.volume_to * q.close,
+                })
+            })
+            .collect();
+
+        if converted.is_empty() {
+            return Err(format!("no overlapping BTC-convertible data for {base}/{quote}"));
+        }
+
+        Ok(converted)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn effective_resolution_downgrades_minute_beyond_retention() {
+        let resolution = CryptoCompareClient::effective_resolution(
+            Resolution::Minute,
+            chrono::Duration::days(30),
+        );
+        assert_eq!(resolution, Resolution::Hour);
+    }
+
+    #[test]
+    fn effective_resolution_keeps_minute_within_retention() {
+        let resolution = CryptoCompareClient::effective_resolution(
+            Resolution::Minute,
+            chrono::Duration::hours(1),
+        );
+        assert_eq!(resolution, Resolution::Minute);
+    }
+
+    #[test]
+    fn effective_resolution_leaves_hour_and_day_alone() {
+        assert_eq!(
+            CryptoCompareClient::effective_resolution(Resolution::Hour, chrono::Duration::days(365)),
+            Resolution::Hour
+        );
+        assert_eq!(
+            CryptoCompareClient::effective_resolution(Resolution::Day, chrono::Duration::days(365)),
+            Resolution::Day
+        );
+    }
+
+    #[test]
+    fn parse_candle_reads_all_fields() {
+        let row = serde_json::json!({
+            "time": 1_700_000_000i64,
+            "open": 100.0,
+            "high": 110.0,
+            "low": 95.0,
+            "close": 105.0,
+            "volumefrom": 10.0,
+            "volumeto": 1050.0,
+        });
+        let candle = parse_candle(&row).unwrap();
+        assert_eq!(candle.time, 1_700_000_000);
+        assert_eq!(candle.close, 105.0);
+        assert_eq!(candle.volume_to, 1050.0);
+    }
+
+    #[test]
+    fn parse_candle_rejects_missing_required_field() {
+        let row = serde_json::json!({ "time": 1_700_000_000i64, "open": 100.0 });
+        assert!(parse_candle(&row).is_none());
+    }
+}
@@ -0,0 +1,183 @@
+use std::cmp::Reverse;
+use std::collections::{BinaryHeap, HashMap};
+use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
+
+use finviz_rs::{
+    common::Scrape,
+    crypto::Crypto,
+    forex::Forex,
+    future::Future,
+    group::{Group, GroupBy, GroupType, OrderBy, Ordering},
+    insider::Insider,
+};
+use serde_json::Value;
+use tokio::sync::RwLock;
+
+use crate::sources::finviz_data::{get_trending_from_finviz, group_headers, map_rows_to_objects};
+
+/// Which background-refreshed Finviz scrape a cache entry corresponds to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum ScrapeKey {
+    Forex,
+    Crypto,
+    Future,
+    Insider,
+    Group,
+    Trending,
+}
+
+impl ScrapeKey {
+    fn all() -> [ScrapeKey; 6] {
+        [
+            ScrapeKey::Forex,
+            ScrapeKey::Crypto,
+            ScrapeKey::Future,
+            ScrapeKey::Insider,
+            ScrapeKey::Group,
+            ScrapeKey::Trending,
+        ]
+    }
+
+    fn env_suffix(self) -> &'static str {
+        match self {
+            ScrapeKey::Forex => "FOREX",
+            ScrapeKey::Crypto => "CRYPTO",
+            ScrapeKey::Future => "FUTURE",
+            ScrapeKey::Insider => "INSIDER",
+            ScrapeKey::Group => "GROUP",
+            ScrapeKey::Trending => "TRENDING",
+        }
+    }
+
+    /// Fast-moving crypto refreshes far more often than the slow-changing industry group
+    /// table; overridable per-key via `FINVIZ_CACHE_TTL_<KEY>_SECS`.
+    fn default_ttl_secs(self) -> u64 {
+        match self {
+            ScrapeKey::Crypto => 30,
+            ScrapeKey::Forex | ScrapeKey::Future | ScrapeKey::Trending => 60,
+            ScrapeKey::Insider => 300,
+            ScrapeKey::Group => 900,
+        }
+    }
+
+    fn ttl(self) -> Duration {
+        let secs = std::env::var(format!("FINVIZ_CACHE_TTL_{}_SECS", self.env_suffix()))
+            .ok()
+            .and_then(|v| v.parse().ok())
+            .unwrap_or_else(|| self.default_ttl_secs());
+        Duration::from_secs(secs)
+    }
+
+    async fn scrape(self) -> Result<Value, String> {
+        match self {
+            ScrapeKey::Forex => {
+                let rows = Forex::default().scrape().await.map_err(|e| format!("failed to fetch forex: {e}"))?;
+                let headers: Vec<String> = Forex::default_header().into_iter().map(|s| s.to_string()).collect();
+                let objects = map_rows_to_objects(headers, rows, None).map_err(|e| e.to_string())?;
+                Ok(Value::Array(objects))
+            }
+            ScrapeKey::Crypto => {
+                let rows = Crypto::default().scrape().await.map_err(|e| format!("failed to fetch crypto: {e}"))?;
+                let headers: Vec<String> = Crypto::default_header().into_iter().map(|s| s.to_string()).collect();
+                let objects = map_rows_to_objects(headers, rows, None).map_err(|e| e.to_string())?;
+                Ok(Value::Array(objects))
+            }
+            ScrapeKey::Future => {
+                let rows = Future::default().scrape().await.map_err(|e| format!("failed to fetch future: {e}"))?;
+                let headers: Vec<String> = Future::default_header().into_iter().map(|s| s.to_string()).collect();
+                let objects = map_rows_to_objects(headers, rows, None).map_err(|e| e.to_string())?;
+                Ok(Value::Array(objects))
+            }
+            ScrapeKey::Insider => {
+                let rows = Insider::default().scrape().await.map_err(|e| format!("failed to fetch insider: {e}"))?;
+                let headers: Vec<String> = Insider::default_header().into_iter().map(|s| s.to_string()).collect();
+                let objects = map_rows_to_objects(headers, rows, None).map_err(|e| e.to_string())?;
+                Ok(Value::Array(objects))
+            }
+            ScrapeKey::Group => {
+                let group = Group::new(GroupBy::Industry, GroupType::Valuation, OrderBy::PerformanceWeek, Ordering::Ascending);
+                let rows = group.scrape().await.map_err(|e| format!("failed to fetch group: {e}"))?;
+                let objects = map_rows_to_objects(group_headers(), rows, None).map_err(|e| e.to_string())?;
+                Ok(Value::Array(objects))
+            }
+            ScrapeKey::Trending => Ok(serde_json::json!(get_trending_from_finviz().await)),
+        }
+    }
+}
+
+struct CachedScrape {
+    value: Value,
+    as_of: u64,
+}
+
+/// TTL-cached, background-refreshed view over the Finviz scrapers, modeled on a
+/// queue-driven scheduler: a min-ordered queue of next-run deadlines drives re-scrapes, and
+/// an empty queue refills from the full set of known targets. Handlers read the cache
+/// directly and only pay for a live scrape on a cold-start miss.
+pub struct FinvizScrapeCache {
+    entries: RwLock<HashMap<ScrapeKey, CachedScrape>>,
+}
+
+impl FinvizScrapeCache {
+    pub fn new() -> Self {
+        Self { entries: RwLock::new(HashMap::new()) }
+    }
+
+    /// Spawn the background refresh loop. Safe to call once per process; the loop runs
+    /// for the lifetime of the tokio runtime.
+    pub fn spawn_refresh_loop(self: std::sync::Arc<Self>) {
+        tokio::spawn(async move {
+            let mut queue: BinaryHeap<Reverse<(Instant, ScrapeKey)>> = BinaryHeap::new();
+
+            loop {
+                if queue.is_empty() {
+                    let now = Instant::now();
+                    queue.extend(ScrapeKey::all().into_iter().map(|key| Reverse((now, key))));
+                }
+
+                let Reverse((deadline, key)) = *queue.peek().expect("just refilled if empty");
+                let now = Instant::now();
+                if deadline > now {
+                    tokio::time::sleep(deadline - now).await;
+                }
+                queue.pop();
+
+                match key.scrape().await {
+                    Ok(value) => {
+                        let as_of = unix_now();
+                        self.entries.write().await.insert(key, CachedScrape { value, as_of });
+                    }
+                    Err(e) => {
+                        tracing::warn!("finviz cache: refresh failed for {key:?}: {e}");
+                    }
+                }
+
+                queue.push(Reverse((Instant::now() + key.ttl(), key)));
+            }
+        });
+    }
+
+    /// Serve `key`'s cached value and its refresh timestamp immediately if present;
+    /// otherwise perform one live scrape to populate the cold-start miss, leaving the
+    /// background loop to keep it warm from there on.
+    pub async fn get_or_scrape(&self, key: ScrapeKey) -> Result<(Value, u64), String> {
+        if let Some(cached) = self.entries.read().await.get(&key) {
+            return Ok((cached.value.clone(), cached.as_of));
+        }
+
+        let value = key.scrape().await?;
+        let as_of = unix_now();
+        self.entries.write().await.insert(key, CachedScrape { value: value.clone(), as_of });
+        Ok((value, as_of))
+    }
+}
+
+impl Default for FinvizScrapeCache {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+fn unix_now() -> u64 {
+    SystemTime::now().duration_since(UNIX_EPOCH).unwrap_or_default().as_secs()
+}
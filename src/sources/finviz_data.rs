@@ -1,32 +1,32 @@
 use reqwest::header::{HeaderMap, HeaderValue, USER_AGENT};
 use scraper::{Html, Selector};
-use std::collections::HashSet;
+use std::collections::{BTreeMap, HashMap, HashSet};
 use crate::types::LimitQuery;
 use crate::errors::ApiError;
 use axum::{
-    extract::Query,
+    extract::{
+        ws::{Message, WebSocket, WebSocketUpgrade},
+        Query, State,
+    },
     http::StatusCode,
     response::IntoResponse,
     Json,
 };
 use finviz_rs::{
     common::Scrape,
-    crypto::Crypto,
-    forex::Forex,
-    future::Future,
-    group::{Group, GroupBy, GroupType, OrderBy, Ordering},
-    insider::Insider,
     news::News,
     order_type::OrderType,
     screener::Screener,
     screener_type::ScreenerType,
     signal_type::SignalType,
 };
-use futures::future::join_all;
 use serde::Deserialize;
 use serde_json::{json, Map, Value};
 use utoipa::{ToSchema, IntoParams};
 
+use crate::sources::finviz_cache::ScrapeKey;
+use crate::state::AppState;
+
 pub async fn get_trending_from_finviz() -> Vec<String> {
     let mut trending_stocks: HashSet<String> = HashSet::new();
 
@@ -63,7 +63,18 @@ pub async fn get_trending_from_finviz() -> Vec<String> {
     trending_stocks.into_iter().collect()
 }
 
-fn map_rows_to_objects(headers: Vec<String>, mut rows: Vec<Vec<String>>, limit: Option<usize>) -> Vec<Value> {
+/// Maps each scraped row to a JSON object keyed by `headers`, after truncating to `limit`.
+/// Errors with [`ApiError::SchemaMismatch`] on an empty header row or on any row whose column
+/// count has drifted from it -- a naive index-based zip here would silently drop the missing
+/// cells instead of surfacing a Finviz layout change.
+pub(crate) fn map_rows_to_objects(
+    headers: Vec<String>,
+    mut rows: Vec<Vec<String>>,
+    limit: Option<usize>,
+) -> Result<Vec<Value>, ApiError> {
+    if headers.is_empty() {
+        return Err(ApiError::SchemaMismatch("finviz response had no header columns".to_string()));
+    }
     if let Some(max) = limit {
         if rows.len() > max {
             rows.truncate(max);
@@ -71,14 +82,20 @@ fn map_rows_to_objects(headers: Vec<String>, mut rows: Vec<Vec<String>>, limit:
     }
     rows
         .into_iter()
-        .map(|row| {
+        .enumerate()
+        .map(|(idx, row)| {
+            if row.len() != headers.len() {
+                return Err(ApiError::SchemaMismatch(format!(
+                    "row {idx} has {} columns, expected {} to match header {headers:?}",
+                    row.len(),
+                    headers.len(),
+                )));
+            }
             let mut obj = Map::new();
-            for (idx, header) in headers.iter().enumerate() {
-                if let Some(value) = row.get(idx) {
-                    obj.insert(header.clone(), Value::String(value.clone()));
-                }
+            for (header, value) in headers.iter().zip(row.into_iter()) {
+                obj.insert(header.clone(), Value::String(value));
             }
-            Value::Object(obj)
+            Ok(Value::Object(obj))
         })
         .collect()
 }
@@ -89,98 +106,182 @@ pub async fn fetch_finviz_news(limit: Option<usize>) -> Result<Value, String> {
     match News::default().scrape().await {
         Ok(result) => {
             let headers: Vec<String> = News::default_header().into_iter().map(|s| s.to_string()).collect();
-            let news = map_rows_to_objects(headers.clone(), result.news, limit);
-            let blogs = map_rows_to_objects(headers, result.blogs, limit);
+            let news = map_rows_to_objects(headers.clone(), result.news, limit).map_err(|e| e.to_string())?;
+            let blogs = map_rows_to_objects(headers, result.blogs, limit).map_err(|e| e.to_string())?;
             Ok(json!({ "news": news, "blogs": blogs }))
         }
         Err(err) => Err(format!("failed to fetch news: {err}")),
     }
 }
 
-#[utoipa::path(get, path = "/forex", params(LimitQuery), tag = "data", responses((status = 200, description = "Forex performance")))]
-pub async fn get_forex(Query(query): Query<LimitQuery>) -> Result<impl IntoResponse, ApiError> {
-    let LimitQuery { limit } = query;
-    match Forex::default().scrape().await {
-        Ok(rows) => {
-            let headers: Vec<String> = Forex::default_header().into_iter().map(|s| s.to_string()).collect();
-            let data = map_rows_to_objects(headers, rows, limit);
-            Ok((StatusCode::OK, Json(json!({ "data": data }))))
-        }
-        Err(err) => Err(ApiError::Upstream(format!("failed to fetch forex: {err}"))),
+/// Industry/sector group table header, shared by the live [`get_group`] path and the
+/// background cache refresh in [`crate::sources::finviz_cache`].
+pub(crate) fn group_headers() -> Vec<String> {
+    [
+        "Name", "Market Cap", "P/E", "Fwd P/E", "PEG", "P/S", "P/B", "P/C", "P/FCF",
+        "EPS past 5Y", "EPS next 5Y", "Sales past 5Y", "Change", "Volume",
+    ].into_iter().map(|s| s.to_string()).collect()
+}
+
+/// Pull a cached scrape out of `data`, truncate it to `limit`, and wrap it with its
+/// refresh timestamp the way every TTL-cached Finviz endpoint reports freshness.
+fn cached_response(data: Value, as_of: u64, limit: Option<usize>) -> Value {
+    let mut items = data.as_array().cloned().unwrap_or_default();
+    if let Some(max) = limit {
+        items.truncate(max);
     }
+    json!({ "data": items, "as_of": as_of })
+}
+
+#[utoipa::path(get, path = "/forex", params(LimitQuery), tag = "data", responses((status = 200, description = "Forex performance")))]
+pub async fn get_forex(State(state): State<AppState>, Query(query): Query<LimitQuery>) -> Result<impl IntoResponse, ApiError> {
+    let (data, as_of) = state.finviz_cache.get_or_scrape(ScrapeKey::Forex).await
+        .map_err(ApiError::Upstream)?;
+    Ok((StatusCode::OK, Json(cached_response(data, as_of, query.limit))))
 }
 
 #[utoipa::path(get, path = "/crypto", params(LimitQuery), tag = "data", responses((status = 200, description = "Crypto performance")))]
-pub async fn get_crypto(Query(query): Query<LimitQuery>) -> Result<impl IntoResponse, ApiError> {
-    let LimitQuery { limit } = query;
-    match Crypto::default().scrape().await {
-        Ok(rows) => {
-            let headers: Vec<String> = Crypto::default_header().into_iter().map(|s| s.to_string()).collect();
-            let data = map_rows_to_objects(headers, rows, limit);
-            Ok((StatusCode::OK, Json(json!({ "data": data }))))
-        }
-        Err(err) => Err(ApiError::Upstream(format!("failed to fetch crypto: {err}"))),
-    }
+pub async fn get_crypto(State(state): State<AppState>, Query(query): Query<LimitQuery>) -> Result<impl IntoResponse, ApiError> {
+    let (data, as_of) = state.finviz_cache.get_or_scrape(ScrapeKey::Crypto).await
+        .map_err(ApiError::Upstream)?;
+    Ok((StatusCode::OK, Json(cached_response(data, as_of, query.limit))))
 }
 
 #[utoipa::path(get, path = "/future", params(LimitQuery), tag = "data", responses((status = 200, description = "Futures performance")))]
-pub async fn get_future(Query(query): Query<LimitQuery>) -> Result<impl IntoResponse, ApiError> {
-    let LimitQuery { limit } = query;
-    match Future::default().scrape().await {
-        Ok(rows) => {
-            let headers: Vec<String> = Future::default_header().into_iter().map(|s| s.to_string()).collect();
-            let data = map_rows_to_objects(headers, rows, limit);
-            Ok((StatusCode::OK, Json(json!({ "data": data }))))
-        }
-        Err(err) => Err(ApiError::Upstream(format!("failed to fetch future: {err}"))),
-    }
+pub async fn get_future(State(state): State<AppState>, Query(query): Query<LimitQuery>) -> Result<impl IntoResponse, ApiError> {
+    let (data, as_of) = state.finviz_cache.get_or_scrape(ScrapeKey::Future).await
+        .map_err(ApiError::Upstream)?;
+    Ok((StatusCode::OK, Json(cached_response(data, as_of, query.limit))))
 }
 
 #[utoipa::path(get, path = "/insider", params(LimitQuery), tag = "data", responses((status = 200, description = "Insider transactions")))]
-pub async fn get_insider(Query(query): Query<LimitQuery>) -> Result<impl IntoResponse, ApiError> {
-    let LimitQuery { limit } = query;
-    match Insider::default().scrape().await {
-        Ok(rows) => {
-            let headers: Vec<String> = Insider::default_header().into_iter().map(|s| s.to_string()).collect();
-            let data = map_rows_to_objects(headers, rows, limit);
-            Ok((StatusCode::OK, Json(json!({ "data": data }))))
+pub async fn get_insider(State(state): State<AppState>, Query(query): Query<LimitQuery>) -> Result<impl IntoResponse, ApiError> {
+    let (data, as_of) = state.finviz_cache.get_or_scrape(ScrapeKey::Insider).await
+        .map_err(ApiError::Upstream)?;
+    Ok((StatusCode::OK, Json(cached_response(data, as_of, query.limit))))
+}
+
+#[utoipa::path(get, path = "/group", params(LimitQuery), tag = "data", responses((status = 200, description = "Group/Industry")))]
+pub async fn get_group(State(state): State<AppState>, Query(query): Query<LimitQuery>) -> Result<impl IntoResponse, ApiError> {
+    let (data, as_of) = state.finviz_cache.get_or_scrape(ScrapeKey::Group).await
+        .map_err(ApiError::Upstream)?;
+    Ok((StatusCode::OK, Json(cached_response(data, as_of, query.limit))))
+}
+
+/// Finviz filter tokens `ScreenerFilters` is allowed to pass through to the screener URL --
+/// a small representative slice of Finviz's price/volume/market-cap/sector vocabulary.
+/// Unknown tokens are rejected rather than silently dropped.
+const ALLOWED_FILTER_TOKENS: &[&str] = &[
+    "sh_price_u5", "sh_price_u10", "sh_price_u20", "sh_price_o5", "sh_price_o10", "sh_price_o20",
+    "sh_avgvol_u100", "sh_avgvol_o500", "sh_avgvol_o1000", "sh_avgvol_o2000",
+    "cap_nano", "cap_micro", "cap_small", "cap_mid", "cap_large", "cap_mega",
+    "sec_technology", "sec_healthcare", "sec_financial", "sec_energy", "sec_industrials",
+    "sec_consumercyclical", "sec_consumerdefensive", "sec_realestate", "sec_utilities",
+    "sec_basicmaterials", "sec_communicationservices",
+];
+
+/// Arbitrary Finviz screener filter tokens, a view id, and a sort direction -- the
+/// counterpart of `ScreenerQuery`'s fixed `signal`/`order`/`screener` enums for callers who
+/// need a richer candidate universe (e.g. small-cap, high-volume breakouts) without a code
+/// change. Flattened onto `ScreenerQuery`/`FinvizRecommendationsQuery` so it shows up as
+/// plain `filters`/`view`/`sort` query params.
+#[derive(Debug, Deserialize, ToSchema, IntoParams, Default)]
+pub struct ScreenerFilters {
+    /// Comma-separated Finviz filter tokens, e.g. `sh_price_u5,cap_small`.
+    pub filters: Option<String>,
+    /// Finviz screener view id (`v=` query param); defaults to the signal's own view.
+    pub view: Option<u32>,
+    /// Sort direction applied on top of `order`: `asc` or `desc` (default `desc`).
+    pub sort: Option<String>,
+}
+
+impl ScreenerFilters {
+    fn is_empty(&self) -> bool {
+        self.filters.is_none() && self.view.is_none() && self.sort.is_none()
+    }
+
+    /// Validates `filters` against [`ALLOWED_FILTER_TOKENS`] and returns the parsed,
+    /// de-duplicated token list. An unknown token is rejected with [`ApiError::BadRequest`]
+    /// instead of being silently ignored.
+    fn validated_tokens(&self) -> Result<Vec<String>, ApiError> {
+        let Some(raw) = self.filters.as_deref() else { return Ok(Vec::new()) };
+        let mut tokens: Vec<String> = Vec::new();
+        for token in raw.split(',').map(str::trim).filter(|t| !t.is_empty()) {
+            if !ALLOWED_FILTER_TOKENS.contains(&token) {
+                return Err(ApiError::BadRequest(format!(
+                    "unknown finviz filter token '{token}'; allowed: {}",
+                    ALLOWED_FILTER_TOKENS.join(", "),
+                )));
+            }
+            if !tokens.iter().any(|t| t == token) {
+                tokens.push(token.to_string());
+            }
         }
-        Err(err) => Err(ApiError::Upstream(format!("failed to fetch insider: {err}"))),
+        Ok(tokens)
     }
 }
 
-#[utoipa::path(get, path = "/group", params(LimitQuery), tag = "data", responses((status = 200, description = "Group/Industry")))]
-pub async fn get_group(Query(query): Query<LimitQuery>) -> Result<impl IntoResponse, ApiError> {
-    let LimitQuery { limit } = query;
-    let group = Group::new(
-        GroupBy::Industry,
-        GroupType::Valuation,
-        OrderBy::PerformanceWeek,
-        Ordering::Ascending,
+/// Assembles the sorted Finviz screener query-parameter map from a view id, an order
+/// column, a sort direction, and already-validated filter tokens. Mirrors a signed-request
+/// param builder's determinism (sorted keys), just for a public scrape URL rather than an
+/// authenticated API call.
+fn build_screener_params(view: Option<u32>, order_column: &str, ascending: bool, tokens: &[String]) -> BTreeMap<String, String> {
+    let mut params = BTreeMap::new();
+    params.insert("v".to_string(), view.unwrap_or(111).to_string());
+    if !tokens.is_empty() {
+        params.insert("f".to_string(), tokens.join(","));
+    }
+    if !order_column.is_empty() {
+        let column = if ascending { order_column.to_string() } else { format!("-{order_column}") };
+        params.insert("o".to_string(), column);
+    }
+    params
+}
+
+/// Fetches ticker symbols from a raw Finviz screener URL built from `params`, bypassing the
+/// `finviz_rs` crate's fixed `Screener` knobs so [`ScreenerFilters`] callers can reach filter
+/// combinations the crate doesn't model. Reuses the same headered client and quote-link
+/// scraping approach as [`get_trending_from_finviz`].
+async fn scrape_screener_symbols(params: &BTreeMap<String, String>) -> Result<Vec<String>, ApiError> {
+    let mut headers = HeaderMap::new();
+    headers.insert(
+        USER_AGENT,
+        HeaderValue::from_static("Mozilla/5.0 (Windows NT 10.0; Win64; x64) AppleWebKit/537.36"),
     );
-    match group.scrape().await {
-        Ok(rows) => {
-            let headers = vec![
-                "Name".to_string(),
-                "Market Cap".to_string(),
-                "P/E".to_string(),
-                "Fwd P/E".to_string(),
-                "PEG".to_string(),
-                "P/S".to_string(),
-                "P/B".to_string(),
-                "P/C".to_string(),
-                "P/FCF".to_string(),
-                "EPS past 5Y".to_string(),
-                "EPS next 5Y".to_string(),
-                "Sales past 5Y".to_string(),
-                "Change".to_string(),
-                "Volume".to_string(),
-            ];
-            let data = map_rows_to_objects(headers, rows, limit);
-            Ok((StatusCode::OK, Json(json!({ "data": data }))))
+    let client = reqwest::Client::builder()
+        .default_headers(headers)
+        .timeout(std::time::Duration::from_secs(10))
+        .build()
+        .map_err(|e| ApiError::InternalError(format!("failed to build finviz client: {e}")))?;
+
+    let query = params
+        .iter()
+        .map(|(k, v)| format!("{k}={v}"))
+        .collect::<Vec<_>>()
+        .join("&");
+    let url = format!("https://finviz.com/screener.ashx?{query}");
+
+    let resp = client
+        .get(&url)
+        .send()
+        .await
+        .map_err(|e| ApiError::Upstream(format!("finviz screener request failed: {e}")))?;
+    let body = resp
+        .text()
+        .await
+        .map_err(|e| ApiError::Upstream(format!("finviz screener response read failed: {e}")))?;
+
+    let document = Html::parse_document(&body);
+    let selector = Selector::parse("a[href*='quote.ashx']")
+        .map_err(|e| ApiError::InternalError(format!("invalid finviz selector: {e:?}")))?;
+    let mut symbols: Vec<String> = Vec::new();
+    for element in document.select(&selector) {
+        let ticker = element.text().collect::<String>().trim().to_string();
+        if !ticker.is_empty() && ticker.len() <= 5 && !symbols.contains(&ticker) {
+            symbols.push(ticker);
         }
-        Err(err) => Err(ApiError::Upstream(format!("failed to fetch group: {err}"))),
     }
+    Ok(symbols)
 }
 
 #[derive(Deserialize, ToSchema, IntoParams)]
@@ -189,6 +290,8 @@ pub struct ScreenerQuery {
     pub order: Option<String>,    // e.g., Price, MarketCap
     pub screener: Option<String>, // e.g., Performance, Financial
     pub limit: Option<usize>,
+    #[serde(flatten)]
+    pub filters: ScreenerFilters,
 }
 
 fn parse_signal(s: &str) -> SignalType {
@@ -225,27 +328,239 @@ pub async fn get_screener_candidates(Query(q): Query<ScreenerQuery>) -> impl Int
     let screener = q.screener.as_deref().unwrap_or("Performance");
     let limit = q.limit.unwrap_or(25);
 
-    let mut s = Screener::new(parse_screener(screener));
-    s.set_signal(parse_signal(signal));
-    s.set_order(parse_order(order));
+    if q.filters.is_empty() {
+        let mut s = Screener::new(parse_screener(screener));
+        s.set_signal(parse_signal(signal));
+        s.set_order(parse_order(order));
+
+        return match s.scrape().await {
+            Ok(rows) => {
+                let symbols: Vec<String> = rows
+                    .into_iter()
+                    .take(limit)
+                    .filter_map(|row| row.first().cloned())
+                    .collect();
+                (StatusCode::OK, Json(json!({ "symbols": symbols })) ).into_response()
+            }
+            Err(err) => (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                    Json(crate::types::ErrorResponse { error: format!("failed to scrape finviz screener: {err}") }),
+            )
+                .into_response(),
+        };
+    }
 
-    match s.scrape().await {
-        Ok(rows) => {
-            let symbols: Vec<String> = rows
-                .into_iter()
-                .take(limit)
-                .filter_map(|row| row.first().cloned())
-                .collect();
-            (StatusCode::OK, Json(json!({ "symbols": symbols })) ).into_response()
+    let tokens = match q.filters.validated_tokens() {
+        Ok(tokens) => tokens,
+        Err(err) => return err.into_response(),
+    };
+    let ascending = q.filters.sort.as_deref().is_some_and(|s| s.eq_ignore_ascii_case("asc"));
+    let params = build_screener_params(q.filters.view, &order.to_lowercase(), ascending, &tokens);
+
+    match scrape_screener_symbols(&params).await {
+        Ok(symbols) => {
+            let symbols: Vec<String> = symbols.into_iter().take(limit).collect();
+            (StatusCode::OK, Json(json!({ "symbols": symbols }))).into_response()
+        }
+        Err(err) => err.into_response(),
+    }
+}
+
+/// Shares one upstream poll loop across every client watching the same screener
+/// parameters, diffing successive scrapes so `/screener/stream` emits only added/
+/// removed/changed tickers instead of making clients re-poll the full list -- the same
+/// incremental-tick approach a long-lived quote stream uses.
+pub struct ScreenerStreamHub {
+    subscriptions: tokio::sync::Mutex<HashMap<String, tokio::sync::broadcast::Sender<Value>>>,
+}
+
+impl ScreenerStreamHub {
+    pub fn new() -> Self {
+        Self { subscriptions: tokio::sync::Mutex::new(HashMap::new()) }
+    }
+
+    /// Subscribe to diffed updates for a `(signal, order, screener, limit)` combination.
+    /// Spawns the upstream poll loop on the first subscriber and reuses it after that.
+    pub fn subscribe(
+        self: std::sync::Arc<Self>,
+        signal: String,
+        order: String,
+        screener: String,
+        limit: usize,
+        poll_interval: std::time::Duration,
+    ) -> impl futures::Stream<Item = Value> {
+        async_stream::stream! {
+            let key = format!("{signal}:{order}:{screener}:{limit}");
+            let mut rx = {
+                let mut subs = self.subscriptions.lock().await;
+                match subs.get(&key) {
+                    Some(tx) => tx.subscribe(),
+                    None => {
+                        let (tx, rx) = tokio::sync::broadcast::channel(64);
+                        subs.insert(key.clone(), tx.clone());
+                        tokio::spawn(self.clone().poll_and_diff(key.clone(), signal, order, screener, limit, poll_interval, tx));
+                        rx
+                    }
+                }
+            };
+
+            loop {
+                match rx.recv().await {
+                    Ok(value) => yield value,
+                    Err(tokio::sync::broadcast::error::RecvError::Lagged(_)) => continue,
+                    Err(tokio::sync::broadcast::error::RecvError::Closed) => break,
+                }
+            }
+        }
+    }
+
+    /// Poll the screener on `poll_interval`, diffing each scrape against the last one and
+    /// publishing only what changed. Tears itself down once the last subscriber disconnects.
+    async fn poll_and_diff(
+        self: std::sync::Arc<Self>,
+        key: String,
+        signal: String,
+        order: String,
+        screener: String,
+        limit: usize,
+        poll_interval: std::time::Duration,
+        tx: tokio::sync::broadcast::Sender<Value>,
+    ) {
+        let mut last: HashMap<String, Value> = HashMap::new();
+
+        loop {
+            if tx.receiver_count() == 0 {
+                break;
+            }
+
+            let mut s = Screener::new(parse_screener(&screener));
+            s.set_signal(parse_signal(&signal));
+            s.set_order(parse_order(&order));
+
+            match s.scrape().await {
+                Ok(rows) => {
+                    let headers: Vec<String> = Screener::default_header().into_iter().map(|s| s.to_string()).collect();
+                    match map_rows_to_objects(headers, rows, Some(limit)) {
+                        Ok(objects) => {
+                            let mut current: HashMap<String, Value> = HashMap::new();
+                            for obj in &objects {
+                                if let Some(ticker) = obj.get("Ticker").and_then(|v| v.as_str()) {
+                                    current.insert(ticker.to_string(), obj.clone());
+                                }
+                            }
+
+                            let added: Vec<&Value> = current.iter()
+                                .filter(|(ticker, _)| !last.contains_key(*ticker))
+                                .map(|(_, v)| v)
+                                .collect();
+                            let removed: Vec<&String> = last.keys()
+                                .filter(|ticker| !current.contains_key(*ticker))
+                                .collect();
+                            let changed: Vec<&Value> = current.iter()
+                                .filter(|(ticker, v)| last.get(*ticker).map(|prev| prev != *v).unwrap_or(false))
+                                .map(|(_, v)| v)
+                                .collect();
+
+                            if !added.is_empty() || !removed.is_empty() || !changed.is_empty() {
+                                let _ = tx.send(json!({ "added": added, "removed": removed, "changed": changed }));
+                            }
+
+                            last = current;
+                        }
+                        Err(e) => tracing::warn!("screener stream: schema mismatch for {key}: {e}"),
+                    }
+                }
+                Err(e) => tracing::warn!("screener stream: poll failed for {key}: {e}"),
+            }
+
+            tokio::time::sleep(poll_interval).await;
+        }
+
+        self.subscriptions.lock().await.remove(&key);
+    }
+}
+
+impl Default for ScreenerStreamHub {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[derive(Debug, Deserialize, IntoParams)]
+pub struct ScreenerStreamQuery {
+    pub signal: Option<String>,
+    pub order: Option<String>,
+    pub screener: Option<String>,
+    pub limit: Option<usize>,
+    /// Poll interval in seconds; defaults to 5.
+    pub interval_secs: Option<u64>,
+}
+
+/// Stream incremental screener updates over a WebSocket instead of re-polling the full
+/// list. Connect with the same params as `/screener/candidates`; the socket emits
+/// `{added, removed, changed}` frames whenever a scrape differs from the last one.
+#[utoipa::path(
+    get,
+    path = "/screener/stream",
+    params(ScreenerStreamQuery),
+    tag = "data",
+    responses((status = 101, description = "Switching protocols to WebSocket"))
+)]
+pub async fn screener_stream(
+    ws: WebSocketUpgrade,
+    State(state): State<AppState>,
+    Query(q): Query<ScreenerStreamQuery>,
+) -> impl IntoResponse {
+    let signal = q.signal.unwrap_or_else(|| "TopGainers".to_string());
+    let order = q.order.unwrap_or_else(|| "Price".to_string());
+    let screener = q.screener.unwrap_or_else(|| "Performance".to_string());
+    let limit = q.limit.unwrap_or(25);
+    let interval = std::time::Duration::from_secs(q.interval_secs.unwrap_or(5).max(1));
+    let hub = state.screener_stream_hub.clone();
+
+    ws.on_upgrade(move |socket| forward_screener_updates(socket, hub, signal, order, screener, limit, interval))
+}
+
+async fn forward_screener_updates(
+    mut socket: WebSocket,
+    hub: std::sync::Arc<ScreenerStreamHub>,
+    signal: String,
+    order: String,
+    screener: String,
+    limit: usize,
+    interval: std::time::Duration,
+) {
+    use futures::StreamExt;
+    let mut updates = Box::pin(hub.subscribe(signal, order, screener, limit, interval));
+
+    while let Some(value) = updates.next().await {
+        let Ok(text) = serde_json::to_string(&value) else { continue };
+        if socket.send(Message::Text(text.into())).await.is_err() {
+            break;
         }
-        Err(err) => (
-            StatusCode::INTERNAL_SERVER_ERROR,
-                Json(crate::types::ErrorResponse { error: format!("failed to scrape finviz screener: {err}") }),
-        )
-            .into_response(),
     }
 }
 
+/// Default cap on in-flight Yahoo fetches when enriching a batch of Finviz candidates.
+pub const DEFAULT_ENRICHMENT_CONCURRENCY: usize = 8;
+
+/// Run a collection of already-built futures with at most `max_concurrency` in flight at
+/// once, the way ethers' `TransactionStream` bounds concurrent work: never more than N
+/// resolving at a time, fewer if some are slow. A drop-in replacement for `join_all` at
+/// fan-out sites that were overwhelming upstreams like Yahoo with unbounded concurrency.
+/// Results come back in resolution order, not input order.
+pub async fn buffered_map<I, Fut, T>(futures: I, max_concurrency: usize) -> Vec<T>
+where
+    I: IntoIterator<Item = Fut>,
+    Fut: std::future::Future<Output = T>,
+{
+    use futures::stream::StreamExt;
+    futures::stream::iter(futures)
+        .buffer_unordered(max_concurrency.max(1))
+        .collect()
+        .await
+}
+
 #[derive(Deserialize, ToSchema, IntoParams)]
 pub struct FinvizRecommendationsQuery {
     pub signal: Option<String>,
@@ -258,6 +573,10 @@ pub struct FinvizRecommendationsQuery {
     pub rf_annual: Option<f64>,
     pub target_return_annual: Option<f64>,
     pub periods_per_year: Option<usize>,
+    /// Max in-flight Yahoo fetches while enriching candidates; defaults to 8.
+    pub concurrency: Option<usize>,
+    #[serde(flatten)]
+    pub filters: ScreenerFilters,
 }
 
 #[utoipa::path(get, path = "/recommendations/finviz", params(FinvizRecommendationsQuery), tag = "data", responses((status = 200, description = "Evaluate candidates & rank")))]
@@ -277,26 +596,40 @@ pub async fn get_recommendations_finviz(Query(q): Query<FinvizRecommendationsQue
         _ => 252,
     });
 
-    let mut s = Screener::new(parse_screener(screener));
-    s.set_signal(parse_signal(signal));
-    s.set_order(parse_order(order));
-
-    let rows = match s.scrape().await {
-        Ok(r) => r,
-        Err(err) => {
-            return (
-                StatusCode::INTERNAL_SERVER_ERROR,
-                Json(crate::types::ErrorResponse { error: format!("screener error: {err}") }),
-            )
-                .into_response();
+    let symbols: Vec<String> = if q.filters.is_empty() {
+        let mut s = Screener::new(parse_screener(screener));
+        s.set_signal(parse_signal(signal));
+        s.set_order(parse_order(order));
+
+        let rows = match s.scrape().await {
+            Ok(r) => r,
+            Err(err) => {
+                return (
+                    StatusCode::INTERNAL_SERVER_ERROR,
+                    Json(crate::types::ErrorResponse { error: format!("screener error: {err}") }),
+                )
+                    .into_response();
+            }
+        };
+        rows.into_iter().filter_map(|row| row.first().cloned()).take(limit).collect()
+    } else {
+        let tokens = match q.filters.validated_tokens() {
+            Ok(tokens) => tokens,
+            Err(err) => return err.into_response(),
+        };
+        let ascending = q.filters.sort.as_deref().is_some_and(|s| s.eq_ignore_ascii_case("asc"));
+        let params = build_screener_params(q.filters.view, &order.to_lowercase(), ascending, &tokens);
+        match scrape_screener_symbols(&params).await {
+            Ok(symbols) => symbols.into_iter().take(limit).collect(),
+            Err(err) => return err.into_response(),
         }
     };
 
-    let symbols: Vec<String> = rows.into_iter().filter_map(|row| row.first().cloned()).take(limit).collect();
     if symbols.is_empty() {
         return (StatusCode::OK, Json(json!({ "results": [] }))).into_response();
     }
 
+    let concurrency = q.concurrency.unwrap_or(DEFAULT_ENRICHMENT_CONCURRENCY);
     let futures_vec = symbols.iter().map(|sym| async move {
         match crate::services::yahoo::fetch_prices_for_symbol_default(sym, period_label).await {
             Ok(prices) => {
@@ -308,7 +641,7 @@ pub async fn get_recommendations_finviz(Query(q): Query<FinvizRecommendationsQue
         }
     });
 
-    let mut results: Vec<Value> = join_all(futures_vec).await;
+    let mut results: Vec<Value> = buffered_map(futures_vec, concurrency).await;
     results.sort_by(|a, b| {
         let sa = a.get("metrics").and_then(|m| m.get("composite_score")).and_then(|v| v.as_f64()).unwrap_or(f64::MIN);
         let sb = b.get("metrics").and_then(|m| m.get("composite_score")).and_then(|v| v.as_f64()).unwrap_or(f64::MIN);
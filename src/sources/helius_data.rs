@@ -1,13 +1,25 @@
 use helius::error::Result as HeliusResult;
 use helius::types::{
-    Cluster, GetAsset, GetAssetBatch, GetAssetsByOwner, SearchAssets, Asset, Interface, ParseTransactionsRequest, EnhancedTransaction
+    Cluster, GetAsset, GetAssetBatch, GetAssetProof, GetAssetProofBatch, GetAssetsByOwner, SearchAssets, Asset,
+    Interface, ParseTransactionsRequest, EnhancedTransaction
 };
 use helius::Helius;
 use serde::{Deserialize, Serialize};
+use solana_account_decoder::UiAccountEncoding;
+use solana_client::rpc_config::{RpcAccountInfoConfig, RpcProgramAccountsConfig};
+use solana_client::rpc_filter::{Memcmp, RpcFilterType};
+use solana_sdk::pubkey::Pubkey;
 use std::collections::HashMap;
 
+use crate::errors::ApiError;
+use crate::sources::tensor_data;
 use crate::types::TrendingItem;
 
+/// Encode a 32-byte slice as a base58 pubkey string for decoded account fields.
+fn pk(bytes: &[u8]) -> String {
+    Pubkey::try_from(bytes).map(|p| p.to_string()).unwrap_or_default()
+}
+
 #[derive(Debug, Serialize, Deserialize, Clone, utoipa::ToSchema)]
 pub struct SolanaAsset {
     pub id: String,
@@ -22,6 +34,55 @@ pub struct SolanaAsset {
     pub is_nft: bool,
     pub collection: Option<String>,
     pub attributes: Option<HashMap<String, serde_json::Value>>,
+    /// True for compressed NFTs (Bubblegum Merkle tree leaves), which need a proof from
+    /// [`HeliusDataSource::get_asset_proof`] before they can be transacted against.
+    pub compressed: bool,
+}
+
+/// A Merkle proof for a compressed NFT's Bubblegum tree leaf, as required by Metaplex's
+/// Bubblegum program to transact against a compressed asset (transfer, burn, redeem, etc.).
+#[derive(Debug, Serialize, Deserialize, Clone, utoipa::ToSchema)]
+pub struct AssetProof {
+    pub root: String,
+    pub proof: Vec<String>,
+    pub node_index: u64,
+    pub leaf: String,
+    pub tree_id: String,
+}
+
+/// Pagination controls for [`HeliusDataSource::get_assets_by_owner`]/
+/// [`HeliusDataSource::search_assets`]. Pass the `cursor` from a previous [`PagedAssets`]
+/// response to continue that same walk; the DAS API falls back to numeric `page` iteration
+/// on its own when a result set doesn't support cursor-based paging, which is why `cursor`
+/// is optional rather than required.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, utoipa::ToSchema, utoipa::IntoParams)]
+pub struct PageOptions {
+    pub limit: Option<u32>,
+    pub cursor: Option<String>,
+    pub before: Option<String>,
+    pub after: Option<String>,
+}
+
+/// A page of [`SolanaAsset`]s plus the opaque cursor to pass back as `PageOptions::cursor`
+/// for the next page; `cursor` is `None` once the DAS API reports no further results (or
+/// never returned one for this query at all, in which case the caller has reached the end
+/// of numeric `page` iteration too).
+#[derive(Debug, Serialize, Deserialize, Clone, utoipa::ToSchema)]
+pub struct PagedAssets {
+    pub items: Vec<SolanaAsset>,
+    pub cursor: Option<String>,
+}
+
+/// Base58's alphabet (Bitcoin/Solana variant: no `0`, `O`, `I`, or `l`), used to sanity-check
+/// a cursor before forwarding it upstream.
+const BASE58_ALPHABET: &str = "123456789ABCDEFGHJKLMNPQRSTUVWXYZabcdefghijkmnopqrstuvwxyz";
+
+/// Validate that `cursor` is non-empty and looks like base58 -- the DAS API's opaque page
+/// cursor encoding -- before forwarding it upstream. Anything else (missing, empty, or
+/// containing characters outside the base58 alphabet) is treated as "no cursor" so a
+/// malformed value falls back to a fresh first page instead of an invalid upstream request.
+fn valid_cursor(cursor: Option<String>) -> Option<String> {
+    cursor.filter(|c| !c.is_empty() && c.chars().all(|ch| BASE58_ALPHABET.contains(ch)))
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone, utoipa::ToSchema)]
@@ -44,6 +105,19 @@ pub struct SolanaTransactionSignature {
     pub status: String,
 }
 
+/// Marketplace-backed stats for an NFT collection, returned by `/solana/collections/top`
+/// and `/solana/nfts/trending`.
+#[derive(Debug, Serialize, Deserialize, Clone, utoipa::ToSchema)]
+pub struct CollectionStats {
+    pub collection: String,
+    pub name: String,
+    pub image_url: Option<String>,
+    pub floor_price: Option<f64>,
+    pub volume_24h: Option<f64>,
+    pub listed_count: Option<u32>,
+    pub holders: Option<u32>,
+}
+
 #[derive(Debug, Serialize, Deserialize, Clone, utoipa::ToSchema)]
 pub struct SolanaNFTCollection {
     pub name: String,
@@ -62,14 +136,445 @@ pub struct TokenHolding {
     pub balance: f64,
     pub ui_amount_string: String,
     pub decimals: u8,
+    /// Populated only when `?with_prices=true` was requested and a Pyth feed exists for
+    /// `mint` and is currently `Trading` — never a stale or fabricated price.
+    pub usd_price: Option<f64>,
+    pub usd_value: Option<f64>,
+    pub price_confidence: Option<f64>,
+}
+
+/// SPL Token `Mint` account (82-byte layout).
+#[derive(Debug, Serialize, Deserialize, Clone, utoipa::ToSchema)]
+pub struct SplMintInfo {
+    pub mint_authority: Option<String>,
+    /// Decimal string: token supply is a `u64` and can exceed the safe-integer range of a
+    /// JSON number.
+    pub supply: String,
+    pub decimals: u8,
+    pub is_initialized: bool,
+    pub freeze_authority: Option<String>,
+}
+
+/// SPL Token `Account` (token-holding, 165-byte layout).
+#[derive(Debug, Serialize, Deserialize, Clone, utoipa::ToSchema)]
+pub struct SplTokenAccountInfo {
+    pub mint: String,
+    pub owner: String,
+    /// Decimal string; raw token amount before applying `decimals`.
+    pub amount: String,
+    pub delegate: Option<String>,
+    pub state: String,
+}
+
+/// Stake program account (`StakeStateV2`), flattened to the fields this crate decodes.
+#[derive(Debug, Serialize, Deserialize, Clone, utoipa::ToSchema)]
+pub struct StakeAccountInfo {
+    pub kind: String,
+    pub staker: Option<String>,
+    pub withdrawer: Option<String>,
+    pub lockup_unix_timestamp: Option<i64>,
+    /// Decimal string; lockup epoch is a `u64` that can legitimately be `u64::MAX` ("no
+    /// lockup"), which doesn't round-trip through a JSON number.
+    pub lockup_epoch: Option<String>,
+    pub custodian: Option<String>,
+    pub voter: Option<String>,
+    /// Decimal string; delegated stake amount, for the same precision reason as `supply`.
+    pub stake_lamports: Option<String>,
+    pub activation_epoch: Option<String>,
+    pub deactivation_epoch: Option<String>,
+}
+
+/// Vote program account: only the leading, stable prefix (node pubkey + authorized
+/// withdrawer + commission) this crate decodes.
+#[derive(Debug, Serialize, Deserialize, Clone, utoipa::ToSchema)]
+pub struct VoteAccountInfo {
+    pub node_pubkey: String,
+    pub authorized_withdrawer: String,
+    pub commission: u8,
+}
+
+/// Config program account: the schema varies by config type, so only the raw data length
+/// is surfaced.
+#[derive(Debug, Serialize, Deserialize, Clone, utoipa::ToSchema)]
+pub struct ConfigAccountInfo {
+    pub data_len: u64,
+}
+
+/// A well-known sysvar account, identified by address rather than a decoded layout.
+#[derive(Debug, Serialize, Deserialize, Clone, utoipa::ToSchema)]
+pub struct SysvarAccountInfo {
+    pub sysvar: String,
+    pub data_len: u64,
+}
+
+/// Decoded body of an account whose owning program this crate recognizes; see
+/// [`decode_parsed_account`]. Anything unrecognized stays as the raw base64 blob in
+/// [`ProgramAccountData::data`] instead of appearing here.
+#[derive(Debug, Serialize, Deserialize, Clone, utoipa::ToSchema)]
+#[serde(tag = "type", rename_all = "camelCase")]
+pub enum DecodedAccount {
+    Mint(SplMintInfo),
+    TokenAccount(SplTokenAccountInfo),
+    Stake(StakeAccountInfo),
+    Vote(VoteAccountInfo),
+    Config(ConfigAccountInfo),
+    Sysvar(SysvarAccountInfo),
+}
+
+/// Interpreted account data for a known program layout, returned when the caller asks
+/// for `?encoding=jsonParsed`. Mirrors the shape of Solana's own account-decoder output
+/// (program name, decoded fields, raw space) without depending on its crate.
+#[derive(Debug, Serialize, Deserialize, Clone, utoipa::ToSchema)]
+pub struct ParsedAccount {
+    pub program: String,
+    pub parsed: DecodedAccount,
+    pub space: u64,
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone, utoipa::ToSchema)]
 pub struct ProgramAccountData {
     pub pubkey: String,
-    pub lamports: u64,
+    /// Decimal string: lamport balances are `u64` and can legitimately approach
+    /// `u64::MAX` (e.g. rent-exempt system accounts funded far beyond typical balances),
+    /// which a JSON number can't represent without losing precision.
+    pub lamports: String,
     pub owner: String,
     pub executable: bool,
+    /// Base64-encoded raw account data, always present.
+    pub data: String,
+    /// Decoded form when the owning program's layout is recognized and `encoding=jsonParsed`
+    /// was requested; `None` when the caller asked for raw `base64` or the layout is unknown.
+    pub parsed: Option<ParsedAccount>,
+}
+
+const SPL_TOKEN_PROGRAM_ID: &str = "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA";
+const SPL_TOKEN_2022_PROGRAM_ID: &str = "TokenzQdBNbLqP5VEhdkAS6EPFLC1PHnBqCXEpPxuEb";
+const STAKE_PROGRAM_ID: &str = "Stake11111111111111111111111111111111111111";
+const VOTE_PROGRAM_ID: &str = "Vote111111111111111111111111111111111111111";
+const CONFIG_PROGRAM_ID: &str = "Config1111111111111111111111111111111111111";
+
+/// Pyth's `PriceStatus::Trading` discriminant; any other status means the feed is stale,
+/// halted, or in auction and shouldn't be surfaced as a live price.
+const PYTH_PRICE_STATUS_TRADING: u32 = 1;
+
+/// Decode the fixed-offset aggregate price fields of a Pyth V2 price account:
+/// `(price, expo, conf, status, publish_time)`. Returns `None` rather than erroring when the
+/// account is shorter than Pyth's aggregate-price prefix.
+fn decode_pyth_price(data: &[u8]) -> Option<(i64, i32, u64, u32, i64)> {
+    if data.len() < 236 {
+        return None;
+    }
+    let expo = i32::from_le_bytes(data[20..24].try_into().ok()?);
+    let price = i64::from_le_bytes(data[208..216].try_into().ok()?);
+    let conf = u64::from_le_bytes(data[216..224].try_into().ok()?);
+    let status = u32::from_le_bytes(data[224..228].try_into().ok()?);
+    let publish_time = i64::from_le_bytes(data[228..236].try_into().ok()?);
+    Some((price, expo, conf, status, publish_time))
+}
+
+/// A bundled `mint -> Pyth price account` map for major SPL tokens on mainnet-beta, used
+/// as the default when no override is configured.
+pub fn default_pyth_feed_map() -> HashMap<String, String> {
+    [
+        ("So11111111111111111111111111111111111111112", "H6ARHf6YXhGYeQfUzQNGk6rDNnLBQKrenN712K4AQJEG"), // SOL/USD
+        ("EPjFWdd5AufqSSqeM2qN1xzybapC8G4wEGGkZwyTDt1v", "Gnt27xtC473ZT2Mw5u8wZ68Z3gULkSTb5DuxJy7eJotD"), // USDC/USD
+        ("Es9vMFrzaCERmJfrF4H2FYD4KCoNkY11McCe8BenwNYB", "3vxLXJqLqF3JG5TCbYycbKWRBbCJQLxQmBGCkyqEEefL"), // USDT/USD
+    ]
+    .into_iter()
+    .map(|(mint, feed)| (mint.to_string(), feed.to_string()))
+    .collect()
+}
+
+/// Decode the known account layout for `owner`, returning `None` (never an error) when the
+/// program isn't recognized or the data doesn't match the expected length, so callers always
+/// fall back to the raw blob instead of failing the whole request.
+fn decode_parsed_account(owner: &str, data: &[u8]) -> Option<DecodedAccount> {
+    match owner {
+        SPL_TOKEN_PROGRAM_ID | SPL_TOKEN_2022_PROGRAM_ID => decode_spl_token(data),
+        STAKE_PROGRAM_ID => decode_stake_state(data),
+        VOTE_PROGRAM_ID => decode_vote_state(data),
+        CONFIG_PROGRAM_ID => decode_config_account(data),
+        _ if owner.starts_with("Sysvar") => decode_sysvar(owner, data),
+        _ => None,
+    }
+}
+
+/// SPL Token `Mint` (82 bytes) or `Account` (165 bytes) layout, dispatched on length the same
+/// way the on-chain program itself distinguishes the two account kinds.
+fn decode_spl_token(data: &[u8]) -> Option<DecodedAccount> {
+    match data.len() {
+        82 => {
+            let mint_authority_tag = u32::from_le_bytes(data[0..4].try_into().ok()?);
+            let mint_authority = (mint_authority_tag != 0).then(|| pk(&data[4..36]));
+            let supply = u64::from_le_bytes(data[36..44].try_into().ok()?);
+            let decimals = data[44];
+            let is_initialized = data[45] != 0;
+            let freeze_authority_tag = u32::from_le_bytes(data[46..50].try_into().ok()?);
+            let freeze_authority = (freeze_authority_tag != 0).then(|| pk(&data[50..82]));
+            Some(DecodedAccount::Mint(SplMintInfo {
+                mint_authority,
+                supply: supply.to_string(),
+                decimals,
+                is_initialized,
+                freeze_authority,
+            }))
+        }
+        165 => {
+            let mint = pk(&data[0..32]);
+            let owner = pk(&data[32..64]);
+            let amount = u64::from_le_bytes(data[64..72].try_into().ok()?);
+            let delegate_tag = u32::from_le_bytes(data[72..76].try_into().ok()?);
+            let delegate = (delegate_tag != 0).then(|| pk(&data[76..108]));
+            let state = match data[108] {
+                0 => "uninitialized",
+                1 => "initialized",
+                2 => "frozen",
+                _ => "unknown",
+            };
+            Some(DecodedAccount::TokenAccount(SplTokenAccountInfo {
+                mint,
+                owner,
+                amount: amount.to_string(),
+                delegate,
+                state: state.to_string(),
+            }))
+        }
+        _ => None,
+    }
+}
+
+/// Stake program `StakeStateV2` layout: a 4-byte enum tag followed by `Meta`
+/// (authorized staker/withdrawer + lockup) and, for the `Stake` variant, `Delegation`.
+fn decode_stake_state(data: &[u8]) -> Option<DecodedAccount> {
+    if data.len() < 4 {
+        return None;
+    }
+    let tag = u32::from_le_bytes(data[0..4].try_into().ok()?);
+    let kind = match tag {
+        0 => "uninitialized",
+        1 => "initialized",
+        2 => "stake",
+        3 => "rewardsPool",
+        _ => return None,
+    };
+    if kind == "uninitialized" {
+        return Some(DecodedAccount::Stake(StakeAccountInfo {
+            kind: kind.to_string(),
+            staker: None,
+            withdrawer: None,
+            lockup_unix_timestamp: None,
+            lockup_epoch: None,
+            custodian: None,
+            voter: None,
+            stake_lamports: None,
+            activation_epoch: None,
+            deactivation_epoch: None,
+        }));
+    }
+    if data.len() < 4 + 32 + 32 + 8 + 8 + 32 {
+        return None;
+    }
+    let staker = pk(&data[4..36]);
+    let withdrawer = pk(&data[36..68]);
+    let unix_timestamp = i64::from_le_bytes(data[68..76].try_into().ok()?);
+    let epoch = u64::from_le_bytes(data[76..84].try_into().ok()?);
+    let custodian = pk(&data[84..116]);
+
+    let mut info = StakeAccountInfo {
+        kind: kind.to_string(),
+        staker: Some(staker),
+        withdrawer: Some(withdrawer),
+        lockup_unix_timestamp: Some(unix_timestamp),
+        lockup_epoch: Some(epoch.to_string()),
+        custodian: Some(custodian),
+        voter: None,
+        stake_lamports: None,
+        activation_epoch: None,
+        deactivation_epoch: None,
+    };
+
+    if kind == "stake" && data.len() >= 116 + 32 + 8 + 8 + 8 {
+        let voter = pk(&data[116..148]);
+        let stake = u64::from_le_bytes(data[148..156].try_into().ok()?);
+        let activation_epoch = u64::from_le_bytes(data[156..164].try_into().ok()?);
+        let deactivation_epoch = u64::from_le_bytes(data[164..172].try_into().ok()?);
+        info.voter = Some(voter);
+        info.stake_lamports = Some(stake.to_string());
+        info.activation_epoch = Some(activation_epoch.to_string());
+        info.deactivation_epoch = Some(deactivation_epoch.to_string());
+    }
+
+    Some(DecodedAccount::Stake(info))
+}
+
+/// Vote program account: only the leading, stable prefix (node pubkey + authorized withdrawer
+/// + commission) is decoded — the variable-length vote history that follows isn't needed here.
+fn decode_vote_state(data: &[u8]) -> Option<DecodedAccount> {
+    if data.len() < 4 + 32 + 32 + 1 {
+        return None;
+    }
+    let node_pubkey = pk(&data[4..36]);
+    let authorized_withdrawer = pk(&data[36..68]);
+    let commission = data[68];
+    Some(DecodedAccount::Vote(VoteAccountInfo {
+        node_pubkey,
+        authorized_withdrawer,
+        commission,
+    }))
+}
+
+/// Config program accounts are a keyed-signer list followed by caller-defined data; surface
+/// only the length since the schema varies by config type (e.g. stake config vs feature set).
+fn decode_config_account(data: &[u8]) -> Option<DecodedAccount> {
+    Some(DecodedAccount::Config(ConfigAccountInfo { data_len: data.len() as u64 }))
+}
+
+/// Sysvar accounts already have a fixed, well-known layout per address; report the variant
+/// name rather than re-deriving it from a generic byte offset table.
+fn decode_sysvar(owner: &str, data: &[u8]) -> Option<DecodedAccount> {
+    Some(DecodedAccount::Sysvar(SysvarAccountInfo { sysvar: owner.to_string(), data_len: data.len() as u64 }))
+}
+
+/// Percentile breakdown of recent prioritization fees, in micro-lamports per compute unit.
+#[derive(Debug, Clone, Serialize, Deserialize, utoipa::ToSchema)]
+pub struct PriorityFeeEstimate {
+    pub min: u64,
+    pub low: u64,
+    pub medium: u64,
+    pub high: u64,
+    pub very_high: u64,
+    pub unsafe_max: u64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, utoipa::ToSchema)]
+pub struct PriorityFeeResponse {
+    pub priority_fee_estimate: PriorityFeeEstimate,
+    pub recommended_micro_lamports: u64,
+    pub suggested_compute_unit_limit: u32,
+}
+
+/// A reasonable default compute-unit limit for a simple transaction, used when the caller
+/// doesn't supply one of their own.
+const DEFAULT_COMPUTE_UNIT_LIMIT: u32 = 200_000;
+
+/// Fallback priority fee (micro-lamports per CU) when `getRecentPrioritizationFees` has no
+/// data for the requested accounts, mirroring [`get_priority_fee`]'s own config-driven
+/// fallback but usable from contexts (like [`HeliusDataSource::estimate_priority_fee`]) that
+/// don't have access to `AppState::config`.
+const DEFAULT_PRIORITY_FEE_MICRO_LAMPORTS: u64 = 1_000;
+
+/// One of Helius' priority-fee percentile buckets, typed so callers can't pass a typo'd
+/// string to [`HeliusDataSource::estimate_priority_fee`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, utoipa::ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub enum PriorityLevel {
+    Min,
+    Low,
+    Medium,
+    High,
+    VeryHigh,
+    UnsafeMax,
+}
+
+impl PriorityLevel {
+    /// The string key [`HeliusDataSource::get_priority_fee_estimate`] buckets on.
+    fn as_str(self) -> &'static str {
+        match self {
+            PriorityLevel::Min => "min",
+            PriorityLevel::Low => "low",
+            PriorityLevel::Medium => "medium",
+            PriorityLevel::High => "high",
+            PriorityLevel::VeryHigh => "veryHigh",
+            PriorityLevel::UnsafeMax => "unsafeMax",
+        }
+    }
+}
+
+/// Prepend `ComputeBudgetInstruction::set_compute_unit_limit`/`set_compute_unit_price` to
+/// `instructions` so the resulting list lands reliably on a congested network instead of
+/// expiring with whatever fee the validator's default happens to be. Compute budget
+/// instructions must come first in a transaction's instruction list to take effect.
+pub fn with_priority_fee_instructions(
+    mut instructions: Vec<solana_sdk::instruction::Instruction>,
+    compute_unit_price_micro_lamports: u64,
+    compute_unit_limit: u32,
+) -> Vec<solana_sdk::instruction::Instruction> {
+    let mut budgeted = vec![
+        solana_sdk::compute_budget::ComputeBudgetInstruction::set_compute_unit_limit(compute_unit_limit),
+        solana_sdk::compute_budget::ComputeBudgetInstruction::set_compute_unit_price(compute_unit_price_micro_lamports),
+    ];
+    budgeted.append(&mut instructions);
+    budgeted
+}
+
+/// Nearest-rank percentile of an already-sorted slice.
+fn percentile(sorted: &[u64], p: f64) -> u64 {
+    if sorted.is_empty() {
+        return 0;
+    }
+    let idx = ((sorted.len() - 1) as f64 * p).round() as usize;
+    sorted[idx.min(sorted.len() - 1)]
+}
+
+/// A price reading from a [`PriceOracle`]: USD price, Pyth's confidence band (same units as
+/// `price`), and the Unix timestamp the feed was last published.
+#[derive(Debug, Clone, Copy)]
+pub struct PriceQuote {
+    pub price: f64,
+    pub confidence: f64,
+    pub publish_time: i64,
+}
+
+/// A source of USD prices for SPL mints, pluggable so [`HeliusDataSource::get_trending_solana_assets`]
+/// isn't hard-wired to Pyth. Implementations should return `None` for a mint with no feed, a
+/// stale publish time, or an untrustworthy confidence band rather than a guessed price.
+pub trait PriceOracle: Send + Sync {
+    /// Best-effort batch price lookup; mints with no trustworthy quote are simply absent
+    /// from the returned map.
+    fn prices(&self, mints: &[String]) -> HashMap<String, PriceQuote>;
+}
+
+/// Default staleness cutoff for [`PythPriceOracle`]: a feed that hasn't published in this
+/// long is treated as having no price rather than a guessed stale one.
+pub const DEFAULT_PYTH_MAX_STALENESS_SECS: i64 = 60;
+/// Default confidence cutoff for [`PythPriceOracle`], as a fraction of price: a feed whose
+/// confidence band is wider than this (e.g. during a market dislocation) is untrustworthy.
+pub const DEFAULT_PYTH_MAX_CONFIDENCE_FRACTION: f64 = 0.02;
+
+/// Pyth-backed [`PriceOracle`]: looks up each mint's feed account from `feed_map` (e.g.
+/// [`default_pyth_feed_map`]) and decodes its aggregate price via
+/// [`HeliusDataSource::get_pyth_quote`], rejecting any quote whose `publish_time` is older
+/// than `max_staleness_secs` or whose confidence exceeds `max_confidence_fraction` of the
+/// price -- either is a sign the feed shouldn't be trusted right now, and a rejected quote
+/// reads as "no price" rather than a bad one.
+pub struct PythPriceOracle<'a> {
+    pub source: &'a HeliusDataSource,
+    pub feed_map: &'a HashMap<String, String>,
+    pub max_staleness_secs: i64,
+    pub max_confidence_fraction: f64,
+}
+
+impl PriceOracle for PythPriceOracle<'_> {
+    fn prices(&self, mints: &[String]) -> HashMap<String, PriceQuote> {
+        let now = std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .map(|d| d.as_secs() as i64)
+            .unwrap_or(0);
+
+        mints
+            .iter()
+            .filter_map(|mint| {
+                let feed = self.feed_map.get(mint)?;
+                let quote = self.source.get_pyth_quote(feed)?;
+                let stale = now.saturating_sub(quote.publish_time) > self.max_staleness_secs;
+                let unreliable = quote.price > 0.0 && quote.confidence / quote.price > self.max_confidence_fraction;
+                if stale || unreliable {
+                    return None;
+                }
+                Some((mint.clone(), quote))
+            })
+            .collect()
+    }
 }
 
 pub struct HeliusDataSource {
@@ -130,27 +635,74 @@ impl HeliusDataSource {
         }
     }
 
-    /// Get assets owned by a specific address
-    pub async fn get_assets_by_owner(&self, owner_address: &str, limit: Option<u32>) -> Result<Vec<SolanaAsset>, Box<dyn std::error::Error + Send + Sync>> {
+    /// Fetch the Merkle proof needed to transact against a compressed NFT's Bubblegum tree
+    /// leaf (transfer, burn, redeem, etc.). `None` when the asset isn't compressed or doesn't
+    /// exist, rather than an error.
+    pub async fn get_asset_proof(&self, asset_id: &str) -> Result<Option<AssetProof>, Box<dyn std::error::Error + Send + Sync>> {
+        let request = GetAssetProof { id: asset_id.to_string() };
+
+        match self.client.rpc().get_asset_proof(request).await {
+            Ok(Some(proof)) => Ok(Some(AssetProof {
+                root: proof.root,
+                proof: proof.proof,
+                node_index: proof.node_index as u64,
+                leaf: proof.leaf,
+                tree_id: proof.tree_id,
+            })),
+            Ok(None) => Ok(None),
+            Err(e) => Err(format!("Failed to get asset proof: {e}").into()),
+        }
+    }
+
+    /// Batch variant of [`Self::get_asset_proof`], keyed by asset id. Ids with no proof
+    /// (not compressed, or not found) are simply absent from the map.
+    pub async fn get_asset_proof_batch(&self, ids: Vec<String>) -> Result<HashMap<String, AssetProof>, Box<dyn std::error::Error + Send + Sync>> {
+        let request = GetAssetProofBatch { ids };
+
+        match self.client.rpc().get_asset_proof_batch(request).await {
+            Ok(response) => {
+                let mut proofs = HashMap::new();
+                for (id, proof) in response.into_iter() {
+                    if let Some(proof) = proof {
+                        proofs.insert(id, AssetProof {
+                            root: proof.root,
+                            proof: proof.proof,
+                            node_index: proof.node_index as u64,
+                            leaf: proof.leaf,
+                            tree_id: proof.tree_id,
+                        });
+                    }
+                }
+                Ok(proofs)
+            }
+            Err(e) => Err(format!("Failed to get asset proof batch: {e}").into()),
+        }
+    }
+
+    /// Get assets owned by a specific address, one page at a time. Forwards `options.cursor`
+    /// when it validates as base58; otherwise requests a fresh page 1 (the DAS API falls back
+    /// to numeric page iteration itself when this result set has no cursor to hand back).
+    pub async fn get_assets_by_owner(&self, owner_address: &str, options: PageOptions) -> Result<PagedAssets, Box<dyn std::error::Error + Send + Sync>> {
+        let cursor = valid_cursor(options.cursor);
         let request = GetAssetsByOwner {
             owner_address: owner_address.to_string(),
             page: 1,
-            limit: limit.map(|l| l as i32).or(Some(1000)),
+            limit: options.limit.map(|l| l as i32).or(Some(1000)),
             display_options: None,
-            cursor: None,
-            before: None,
-            after: None,
+            cursor,
+            before: options.before,
+            after: options.after,
             sort_by: None,
         };
 
         match self.client.rpc().get_assets_by_owner(request).await {
             Ok(response) => {
-                let mut assets = Vec::new();
+                let cursor = response.cursor.clone();
+                let mut items = Vec::new();
                 for item in response.items {
-                    let solana_asset = convert_helius_asset_to_solana_asset(item)?;
-                    assets.push(solana_asset);
+                    items.push(convert_helius_asset_to_solana_asset(item)?);
                 }
-                Ok(assets)
+                Ok(PagedAssets { items, cursor })
             }
             Err(e) => Err(format!("Failed to get assets by owner: {e}").into()),
         }
@@ -169,16 +721,22 @@ impl HeliusDataSource {
         Ok(Vec::new())
     }
 
-    /// Search assets with custom criteria
-    pub async fn search_assets(&self, search_criteria: HashMap<String, String>) -> Result<Vec<SolanaAsset>, Box<dyn std::error::Error + Send + Sync>> {
+    /// Search assets with custom criteria, one page at a time. Forwards `options.cursor` when
+    /// it validates as base58; otherwise requests a fresh page 1 (the DAS API falls back to
+    /// numeric page iteration itself when this search has no cursor to hand back).
+    pub async fn search_assets(&self, search_criteria: HashMap<String, String>, options: PageOptions) -> Result<PagedAssets, Box<dyn std::error::Error + Send + Sync>> {
+        let cursor = valid_cursor(options.cursor);
         let mut request = SearchAssets {
-            page:Some(1),
-            limit: Some(1000),
+            page: cursor.is_none().then_some(1),
+            limit: options.limit.or(Some(1000)),
             condition_type: None,
             interface: None,
             owner_address: None,
             owner_type: None,
             negate: Some(false),
+            cursor,
+            before: options.before,
+            after: options.after,
             ..Default::default()
         };
 
@@ -195,12 +753,12 @@ impl HeliusDataSource {
 
         match self.client.rpc().search_assets(request).await {
             Ok(response) => {
-                let mut assets = Vec::new();
+                let cursor = response.cursor.clone();
+                let mut items = Vec::new();
                 for item in response.items {
-                    let solana_asset = convert_helius_asset_to_solana_asset(item)?;
-                    assets.push(solana_asset);
+                    items.push(convert_helius_asset_to_solana_asset(item)?);
                 }
-                Ok(assets)
+                Ok(PagedAssets { items, cursor })
             }
             Err(e) => Err(format!("Failed to search assets: {e}").into()),
         }
@@ -212,8 +770,11 @@ impl HeliusDataSource {
         Ok(Vec::new())
     }
 
-    /// Get trending Solana assets based on recent activity
-    pub async fn get_trending_solana_assets(&self, limit: usize) -> Result<Vec<TrendingItem>, Box<dyn std::error::Error + Send + Sync>> {
+    /// Get trending Solana assets based on recent activity, blended with live USD pricing
+    /// from `oracle` when given. Ranking combines recency (how early the DAS search surfaced
+    /// the asset) with price momentum; pass `None` to fall back to recency-only ranking with
+    /// unpriced items (e.g. when no Pyth feed map is configured for this deployment).
+    pub async fn get_trending_solana_assets(&self, limit: usize, oracle: Option<&dyn PriceOracle>) -> Result<Vec<TrendingItem>, Box<dyn std::error::Error + Send + Sync>> {
         // Use searchAssets to find recently active assets
         let search_request = SearchAssets {
             page: Some(1),
@@ -253,13 +814,34 @@ impl HeliusDataSource {
 
         match self.client.rpc().search_assets(search_request).await {
             Ok(response) => {
+                let assets: Vec<_> = response.items.iter().take(limit).collect();
+
+                // Batch-fetch prices once for every mint in this page, rather than one
+                // oracle call per asset.
+                let mint_ids: Vec<String> = assets.iter().map(|asset| asset.id.clone()).collect();
+                let quotes = oracle
+                    .map(|oracle| oracle.prices(&mint_ids))
+                    .unwrap_or_default();
+
                 let mut trending_items = Vec::new();
-                
-                for (index, asset) in response.items.iter().enumerate() {
-                    if index >= limit {
-                        break;
-                    }
-                    
+
+                for (index, asset) in assets.into_iter().enumerate() {
+                    let quote = quotes.get(&asset.id);
+                    let supply = asset.token_info.as_ref().and_then(|ti| ti.supply);
+
+                    let recency_score = 100.0 - index as f64;
+                    // No historical price series is available from a single Pyth snapshot, so
+                    // momentum is approximated by how tight the feed's confidence band is
+                    // relative to price -- a stable, well-traded feed scores higher than a
+                    // thin/volatile one. Unpriced assets fall back to recency alone.
+                    let score = match quote {
+                        Some(q) if q.price > 0.0 => {
+                            let momentum = (1.0 - (q.confidence / q.price)).clamp(0.0, 1.0) * 100.0;
+                            Some(0.7 * recency_score + 0.3 * momentum)
+                        }
+                        _ => Some(recency_score),
+                    };
+
                     let trending_item = TrendingItem {
                         id: asset.id.clone(),
                         symbol: asset.content.as_ref()
@@ -268,13 +850,13 @@ impl HeliusDataSource {
                         name: asset.content.as_ref()
                             .and_then(|c| c.metadata.name.as_ref()).cloned()
                             .unwrap_or_else(|| "Unknown Asset".to_string()),
-                        price: None, // Would need Jupiter/price oracle integration
-                        price_change_24h: None,
+                        price: quote.map(|q| q.price),
+                        price_change_24h: None, // A single Pyth snapshot has no prior price to diff against
                         price_change_percentage_24h: None,
                         volume: None, // Would need transaction volume analysis
-                        market_cap: None,
+                        market_cap: quote.and_then(|q| supply.map(|s| q.price * s as f64)),
                         market_cap_rank: Some(index as u32 + 1),
-                        score: Some(100.0 - index as f64), // Score based on recency
+                        score,
                         source: "solana".to_string(),
                         image_url: asset.content.as_ref()
                             .and_then(|c| c.files.as_ref())
@@ -285,23 +867,319 @@ impl HeliusDataSource {
                     };
                     trending_items.push(trending_item);
                 }
-                
+
                 Ok(trending_items)
             }
             Err(e) => Err(format!("Failed to get trending Solana assets: {e}").into()),
         }
     }
 
-    /// Get program accounts (simplified)
-    pub async fn get_program_accounts(&self, _program_id: &str, _limit: Option<u32>) -> Result<Vec<ProgramAccountData>, Box<dyn std::error::Error + Send + Sync>> {
-        // Return empty for now since V2 API types don't exist
-        Ok(Vec::new())
+    /// Fungible-only view of recently active assets, for `/solana/tokens/trending`: the
+    /// search is scoped to `Interface::FungibleToken` so the route returns tokens instead
+    /// of the same recency-ranked mix of NFTs and collections `/solana/trending` does.
+    pub async fn get_trending_fungible_tokens(&self, limit: usize) -> Result<Vec<TrendingItem>, Box<dyn std::error::Error + Send + Sync>> {
+        let search_request = SearchAssets {
+            page: Some(1),
+            limit: Some(limit as u32),
+            condition_type: None,
+            interface: Some(Interface::FungibleToken),
+            owner_address: None,
+            owner_type: None,
+            negate: Some(false),
+            sort_by: None,
+            cursor: None,
+            before: None,
+            creator_address: None,
+            creator_verified: None,
+            authority_address: None,
+            grouping: None,
+            delegate: None,
+            frozen: None,
+            supply: None,
+            supply_mint: None,
+            compressed: None,
+            compressible: None,
+            royalty_target_type: None,
+            royalty_target: None,
+            royalty_amount: None,
+            burnt: None,
+            json_uri: None,
+            not: None,
+            options: None,
+            name: None,
+            collections: None,
+            token_type: None,
+            tree: None,
+            collection_nft: None,
+            after: None,
+        };
+
+        let response = self.client.rpc().search_assets(search_request).await
+            .map_err(|e| format!("Failed to search fungible tokens: {e}"))?;
+
+        let items = response.items.iter().take(limit).enumerate().map(|(index, asset)| TrendingItem {
+            id: asset.id.clone(),
+            symbol: asset.content.as_ref().and_then(|c| c.metadata.symbol.as_ref()).cloned().unwrap_or_else(|| "UNKNOWN".to_string()),
+            name: asset.content.as_ref().and_then(|c| c.metadata.name.as_ref()).cloned().unwrap_or_else(|| "Unknown Token".to_string()),
+            price: None,
+            price_change_24h: None,
+            price_change_percentage_24h: None,
+            volume: None,
+            market_cap: None,
+            market_cap_rank: Some(index as u32 + 1),
+            score: Some(100.0 - index as f64),
+            source: "solana_fungible".to_string(),
+            image_url: asset.content.as_ref()
+                .and_then(|c| c.files.as_ref())
+                .and_then(|files| files.first())
+                .and_then(|file| file.uri.as_ref())
+                .cloned(),
+            last_updated: Some(chrono::Utc::now().timestamp().to_string()),
+        }).collect();
+
+        Ok(items)
     }
 
-    /// Get wallet holdings (simplified)
-    pub async fn get_wallet_holdings(&self, _wallet_address: &str) -> Result<Vec<TokenHolding>, Box<dyn std::error::Error + Send + Sync>> {
-        // Return empty for now since V2 API types don't exist
-        Ok(Vec::new())
+    /// Group recently active NFTs by their collection grouping key and compute genuine
+    /// marketplace-style stats per collection — floor price, 24h volume, and listed count
+    /// pulled from Tensor, plus a holder-count proxy from the number of distinct assets
+    /// this search surfaced. Ranked by `sort_by` (`volume`, `floor`, or `holders`).
+    pub async fn get_top_collections(
+        &self,
+        limit: usize,
+        http: &reqwest::Client,
+        sort_by: &str,
+    ) -> Result<Vec<CollectionStats>, Box<dyn std::error::Error + Send + Sync>> {
+        let search_request = SearchAssets {
+            page: Some(1),
+            limit: Some(250),
+            condition_type: None,
+            interface: None,
+            owner_address: None,
+            owner_type: None,
+            negate: Some(false),
+            sort_by: None,
+            cursor: None,
+            before: None,
+            creator_address: None,
+            creator_verified: None,
+            authority_address: None,
+            grouping: None,
+            delegate: None,
+            frozen: None,
+            supply: None,
+            supply_mint: None,
+            compressed: None,
+            compressible: None,
+            royalty_target_type: None,
+            royalty_target: None,
+            royalty_amount: None,
+            burnt: None,
+            json_uri: None,
+            not: None,
+            options: None,
+            name: None,
+            collections: None,
+            token_type: None,
+            tree: None,
+            collection_nft: None,
+            after: None,
+        };
+
+        let response = self.client.rpc().search_assets(search_request).await
+            .map_err(|e| format!("Failed to search assets for collection aggregation: {e}"))?;
+
+        let mut by_collection: HashMap<String, Vec<&Asset>> = HashMap::new();
+        for asset in &response.items {
+            let Some(groups) = asset.grouping.as_ref() else { continue };
+            let Some(collection_id) = groups.iter()
+                .find(|g| g.group_key == "collection")
+                .map(|g| g.group_value.clone())
+            else { continue };
+            by_collection.entry(collection_id).or_default().push(asset);
+        }
+
+        let mut items = Vec::with_capacity(by_collection.len());
+        for (collection_id, assets) in by_collection {
+            let stats = tensor_data::fetch_collection_stats(http, &collection_id).await.unwrap_or_default();
+            let representative = assets.first();
+            let name = representative
+                .and_then(|a| a.content.as_ref())
+                .and_then(|c| c.metadata.name.as_ref())
+                .cloned()
+                .unwrap_or_else(|| collection_id.clone());
+            let image_url = representative
+                .and_then(|a| a.content.as_ref())
+                .and_then(|c| c.files.as_ref())
+                .and_then(|files| files.first())
+                .and_then(|file| file.uri.as_ref())
+                .cloned();
+
+            items.push(CollectionStats {
+                collection: collection_id,
+                name,
+                image_url,
+                floor_price: stats.floor_price,
+                volume_24h: stats.volume_24h,
+                listed_count: stats.listed_count,
+                holders: Some(assets.len() as u32),
+            });
+        }
+
+        match sort_by {
+            "floor" => items.sort_by(|a, b| b.floor_price.partial_cmp(&a.floor_price).unwrap_or(std::cmp::Ordering::Equal)),
+            "holders" => items.sort_by(|a, b| b.holders.cmp(&a.holders)),
+            _ => items.sort_by(|a, b| b.volume_24h.partial_cmp(&a.volume_24h).unwrap_or(std::cmp::Ordering::Equal)),
+        }
+        items.truncate(limit);
+
+        Ok(items)
+    }
+
+    /// Get all accounts owned by `program_id`. When `encoding` is `"jsonParsed"`, known
+    /// program layouts (SPL Token, Stake, Vote, Config, sysvars) are decoded into `parsed`;
+    /// anything else (including `"base64"` or an unrecognized layout) just carries the raw
+    /// base64 blob in `data`. Decoding never fails the request — a malformed or unexpected
+    /// length just falls back to the raw form.
+    pub async fn get_program_accounts(
+        &self,
+        program_id: &str,
+        limit: Option<u32>,
+        encoding: &str,
+    ) -> Result<Vec<ProgramAccountData>, Box<dyn std::error::Error + Send + Sync>> {
+        let pubkey: Pubkey = program_id.parse()
+            .map_err(|e| format!("invalid program id {program_id}: {e}"))?;
+
+        let connection = self.client.connection();
+        let accounts = connection.get_program_accounts(&pubkey)?;
+
+        let mut results: Vec<ProgramAccountData> = accounts
+            .into_iter()
+            .map(|(pubkey, account)| {
+                let owner = account.owner.to_string();
+                let parsed = (encoding == "jsonParsed")
+                    .then(|| decode_parsed_account(&owner, &account.data))
+                    .flatten()
+                    .map(|parsed| ParsedAccount {
+                        program: owner.clone(),
+                        parsed,
+                        space: account.data.len() as u64,
+                    });
+
+                ProgramAccountData {
+                    pubkey: pubkey.to_string(),
+                    lamports: account.lamports.to_string(),
+                    owner,
+                    executable: account.executable,
+                    data: base64::encode(&account.data),
+                    parsed,
+                }
+            })
+            .collect();
+
+        if let Some(limit) = limit {
+            results.truncate(limit as usize);
+        }
+
+        Ok(results)
+    }
+
+    /// List a wallet's non-zero SPL Token balances by filtering Token Program accounts on
+    /// the owner offset, decoding each with [`decode_spl_token`] and looking up its mint's
+    /// decimals the same way. When `pyth_feed_map` carries a feed for a holding's mint, the
+    /// holding is enriched with a live Pyth price; mints with no feed (or a non-trading one)
+    /// are left unpriced rather than reporting a bogus value.
+    pub async fn get_wallet_holdings(
+        &self,
+        wallet_address: &str,
+        pyth_feed_map: Option<&HashMap<String, String>>,
+    ) -> Result<Vec<TokenHolding>, Box<dyn std::error::Error + Send + Sync>> {
+        let owner: Pubkey = wallet_address.parse()
+            .map_err(|e| format!("invalid wallet address {wallet_address}: {e}"))?;
+        let token_program: Pubkey = SPL_TOKEN_PROGRAM_ID.parse().expect("valid constant pubkey");
+
+        let config = RpcProgramAccountsConfig {
+            filters: Some(vec![
+                RpcFilterType::DataSize(165),
+                RpcFilterType::Memcmp(Memcmp::new_base58_encoded(32, &owner.to_bytes())),
+            ]),
+            account_config: RpcAccountInfoConfig {
+                encoding: Some(UiAccountEncoding::Base64),
+                ..RpcAccountInfoConfig::default()
+            },
+            ..RpcProgramAccountsConfig::default()
+        };
+
+        let connection = self.client.connection();
+        let accounts = connection.get_program_accounts_with_config(&token_program, config)?;
+
+        let mut holdings = Vec::new();
+        for (_, account) in accounts {
+            if account.data.len() < 72 {
+                continue;
+            }
+            let amount = u64::from_le_bytes(account.data[64..72].try_into()?);
+            if amount == 0 {
+                continue;
+            }
+            let mint = pk(&account.data[0..32]);
+
+            let decimals = mint.parse::<Pubkey>().ok()
+                .and_then(|mint_pubkey| connection.get_account(&mint_pubkey).ok())
+                .and_then(|mint_account| decode_spl_token(&mint_account.data))
+                .and_then(|decoded| match decoded {
+                    DecodedAccount::Mint(info) => Some(info.decimals),
+                    _ => None,
+                })
+                .unwrap_or(0);
+
+            let balance = amount as f64 / 10f64.powi(decimals as i32);
+
+            let (usd_price, usd_value, price_confidence) = pyth_feed_map
+                .and_then(|map| map.get(&mint))
+                .and_then(|feed| self.get_pyth_price(feed))
+                .map(|(price, confidence)| (Some(price), Some(price * balance), Some(confidence)))
+                .unwrap_or((None, None, None));
+
+            holdings.push(TokenHolding {
+                mint,
+                symbol: None,
+                balance,
+                ui_amount_string: balance.to_string(),
+                decimals,
+                usd_price,
+                usd_value,
+                price_confidence,
+            });
+        }
+
+        Ok(holdings)
+    }
+
+    /// Read a Pyth V2 price account's aggregate price, returning `(usd_price, confidence)`
+    /// scaled by the feed's exponent. Returns `None` (never an error) when the account
+    /// can't be fetched, is too short to contain Pyth's aggregate-price fields, or isn't
+    /// currently `Trading` — callers should treat that as "no price available".
+    pub fn get_pyth_price(&self, feed_account: &str) -> Option<(f64, f64)> {
+        let quote = self.get_pyth_quote(feed_account)?;
+        Some((quote.price, quote.confidence))
+    }
+
+    /// Like [`Self::get_pyth_price`], but also returns the feed's last publish time (Unix
+    /// seconds), for callers that need to judge staleness themselves (see [`PythPriceOracle`]).
+    pub fn get_pyth_quote(&self, feed_account: &str) -> Option<PriceQuote> {
+        let pubkey: Pubkey = feed_account.parse().ok()?;
+        let account = self.client.connection().get_account(&pubkey).ok()?;
+        let (price, expo, conf, status, publish_time) = decode_pyth_price(&account.data)?;
+        if status != PYTH_PRICE_STATUS_TRADING {
+            return None;
+        }
+        let scale = 10f64.powi(expo);
+        Some(PriceQuote {
+            price: price as f64 * scale,
+            confidence: conf as f64 * scale,
+            publish_time,
+        })
     }
 
     /// Parse transactions using Helius Enhanced Transaction API
@@ -323,6 +1201,753 @@ impl HeliusDataSource {
             Err(e) => Err(e.to_string()),
         }
     }
+
+    /// Estimate a recommended `ComputeBudget` priority fee for transactions touching
+    /// `accounts`, the way wallet tooling generalizes setting compute unit price/limit.
+    /// Pulls `getRecentPrioritizationFees` for those accounts and buckets it into the same
+    /// min/low/medium/high/veryHigh/unsafeMax percentiles Helius' own priority-fee API uses;
+    /// when no recent fee data exists for the accounts, every bucket falls back to
+    /// `default_micro_lamports` rather than erroring.
+    pub fn get_priority_fee_estimate(
+        &self,
+        accounts: &[String],
+        priority_level: &str,
+        default_micro_lamports: u64,
+    ) -> Result<PriorityFeeResponse, Box<dyn std::error::Error + Send + Sync>> {
+        let pubkeys: Vec<Pubkey> = accounts.iter()
+            .map(|a| a.parse().map_err(|e| format!("invalid account {a}: {e}")))
+            .collect::<Result<_, _>>()?;
+
+        let fees = self.client.connection().get_recent_prioritization_fees(&pubkeys)?;
+        let mut values: Vec<u64> = fees.iter().map(|f| f.prioritization_fee).collect();
+        values.sort_unstable();
+
+        let estimate = if values.is_empty() {
+            PriorityFeeEstimate {
+                min: default_micro_lamports,
+                low: default_micro_lamports,
+                medium: default_micro_lamports,
+                high: default_micro_lamports,
+                very_high: default_micro_lamports,
+                unsafe_max: default_micro_lamports,
+            }
+        } else {
+            PriorityFeeEstimate {
+                min: percentile(&values, 0.0),
+                low: percentile(&values, 0.25),
+                medium: percentile(&values, 0.5),
+                high: percentile(&values, 0.75),
+                very_high: percentile(&values, 0.95),
+                unsafe_max: percentile(&values, 1.0),
+            }
+        };
+
+        let recommended_micro_lamports = match priority_level {
+            "min" => estimate.min,
+            "low" => estimate.low,
+            "medium" => estimate.medium,
+            "high" => estimate.high,
+            "veryHigh" => estimate.very_high,
+            "unsafeMax" => estimate.unsafe_max,
+            _ => estimate.medium,
+        };
+
+        Ok(PriorityFeeResponse {
+            priority_fee_estimate: estimate,
+            recommended_micro_lamports,
+            suggested_compute_unit_limit: DEFAULT_COMPUTE_UNIT_LIMIT,
+        })
+    }
+
+    /// Thin, typed convenience over [`Self::get_priority_fee_estimate`] for callers that just
+    /// want a single micro-lamports-per-CU figure for `priority_level`, e.g. to feed straight
+    /// into [`with_priority_fee_instructions`].
+    pub fn estimate_priority_fee(
+        &self,
+        account_keys: Vec<String>,
+        priority_level: PriorityLevel,
+    ) -> Result<u64, Box<dyn std::error::Error + Send + Sync>> {
+        let estimate = self.get_priority_fee_estimate(
+            &account_keys,
+            priority_level.as_str(),
+            DEFAULT_PRIORITY_FEE_MICRO_LAMPORTS,
+        )?;
+        Ok(estimate.recommended_micro_lamports)
+    }
+
+    /// A compact fingerprint of an account's on-chain state, used by [`SolanaWsHub`] to
+    /// detect changes between polls without holding on to the full account data.
+    pub(crate) fn get_account_snapshot(&self, address: &str) -> Result<serde_json::Value, String> {
+        let pubkey: Pubkey = address.parse().map_err(|e| format!("invalid address {address}: {e}"))?;
+        let account = self.client.connection().get_account(&pubkey).map_err(|e| e.to_string())?;
+        Ok(serde_json::json!({
+            "lamports": account.lamports,
+            "owner": account.owner.to_string(),
+            "executable": account.executable,
+            "dataLen": account.data.len(),
+        }))
+    }
+
+    /// The most recent transaction signature involving `address`, if any.
+    pub(crate) fn get_latest_signature(&self, address: &str) -> Result<Option<String>, String> {
+        let pubkey: Pubkey = address.parse().map_err(|e| format!("invalid address {address}: {e}"))?;
+        let signatures = self.client.connection().get_signatures_for_address(&pubkey).map_err(|e| e.to_string())?;
+        Ok(signatures.into_iter().next().map(|s| s.signature))
+    }
+}
+
+/// What kind of live updates a `/solana/ws` client wants for a given address.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, utoipa::ToSchema)]
+#[serde(rename_all = "lowercase")]
+pub enum SubscriptionKind {
+    Account,
+    Signatures,
+}
+
+/// Shares a single upstream poll per `(kind, address)` across every subscribed websocket
+/// client instead of hitting the RPC once per connection, fanning updates out over a
+/// broadcast channel. The poll loop tears itself down once the last subscriber disconnects.
+pub struct SolanaWsHub {
+    api_key: Option<String>,
+    subscriptions: tokio::sync::Mutex<HashMap<(SubscriptionKind, String), tokio::sync::broadcast::Sender<serde_json::Value>>>,
+}
+
+impl SolanaWsHub {
+    pub fn new(api_key: Option<String>) -> Self {
+        Self { api_key, subscriptions: tokio::sync::Mutex::new(HashMap::new()) }
+    }
+
+    /// Subscribe to live updates for `address`. Spawns the upstream poll loop on the first
+    /// subscriber for a given `(kind, address)` and reuses it for every subscriber after that.
+    pub fn subscribe(self: std::sync::Arc<Self>, kind: SubscriptionKind, address: String) -> impl futures::Stream<Item = serde_json::Value> {
+        async_stream::stream! {
+            let Some(api_key) = self.api_key.clone() else {
+                tracing::warn!("solana ws: Helius API key not configured, closing subscription");
+                return;
+            };
+
+            let key = (kind, address.clone());
+            let mut rx = {
+                let mut subs = self.subscriptions.lock().await;
+                match subs.get(&key) {
+                    Some(tx) => tx.subscribe(),
+                    None => {
+                        let (tx, rx) = tokio::sync::broadcast::channel(64);
+                        subs.insert(key.clone(), tx.clone());
+                        tokio::spawn(self.clone().poll_and_publish(key, api_key, tx));
+                        rx
+                    }
+                }
+            };
+
+            loop {
+                match rx.recv().await {
+                    Ok(value) => yield value,
+                    Err(tokio::sync::broadcast::error::RecvError::Lagged(_)) => continue,
+                    Err(tokio::sync::broadcast::error::RecvError::Closed) => break,
+                }
+            }
+        }
+    }
+
+    /// Poll the upstream RPC for `key` on an interval, publishing a value whenever it
+    /// changes, and back off (without giving up) on transient RPC errors. Removes itself
+    /// from the subscription map once nobody is listening anymore.
+    async fn poll_and_publish(
+        self: std::sync::Arc<Self>,
+        key: (SubscriptionKind, String),
+        api_key: String,
+        tx: tokio::sync::broadcast::Sender<serde_json::Value>,
+    ) {
+        let (kind, address) = key.clone();
+        let mut backoff = std::time::Duration::from_secs(1);
+        let mut last_seen: Option<serde_json::Value> = None;
+
+        loop {
+            if tx.receiver_count() == 0 {
+                break;
+            }
+
+            let source = match HeliusDataSource::new_mainnet(&api_key) {
+                Ok(source) => source,
+                Err(e) => {
+                    tracing::warn!("solana ws: failed to init client for {address}: {e}");
+                    tokio::time::sleep(backoff).await;
+                    backoff = (backoff * 2).min(std::time::Duration::from_secs(30));
+                    continue;
+                }
+            };
+
+            let snapshot = match kind {
+                SubscriptionKind::Account => source.get_account_snapshot(&address),
+                SubscriptionKind::Signatures => source.get_latest_signature(&address)
+                    .map(|signature| serde_json::json!({ "signature": signature })),
+            };
+
+            match snapshot {
+                Ok(value) => {
+                    backoff = std::time::Duration::from_secs(1);
+                    if last_seen.as_ref() != Some(&value) {
+                        last_seen = Some(value.clone());
+                        let _ = tx.send(value);
+                    }
+                    tokio::time::sleep(std::time::Duration::from_secs(2)).await;
+                }
+                Err(e) => {
+                    tracing::warn!("solana ws: poll failed for {address}: {e}");
+                    tokio::time::sleep(backoff).await;
+                    backoff = (backoff * 2).min(std::time::Duration::from_secs(30));
+                }
+            }
+        }
+
+        self.subscriptions.lock().await.remove(&key);
+    }
+}
+
+/// A decoded `accountNotification` payload from the Solana RPC pubsub `accountSubscribe`
+/// method: the new state of the watched account as of `slot`.
+#[derive(Debug, Clone, Serialize, Deserialize, utoipa::ToSchema)]
+pub struct AccountUpdate {
+    pub slot: u64,
+    pub lamports: u64,
+    pub owner: String,
+    pub executable: bool,
+    pub rent_epoch: u64,
+    /// Base64-encoded account data, as returned by the RPC node.
+    pub data_base64: String,
+}
+
+/// A decoded `logsNotification` payload from the Solana RPC pubsub `logsSubscribe` method:
+/// one transaction's program logs, published as it lands in a block.
+#[derive(Debug, Clone, Serialize, Deserialize, utoipa::ToSchema)]
+pub struct LogsUpdate {
+    pub slot: u64,
+    pub signature: String,
+    pub err: Option<serde_json::Value>,
+    pub logs: Vec<String>,
+}
+
+/// One notification forwarded to a `/solana/subscribe` SSE client, tagged with the numeric
+/// subscription id the RPC node assigned during the `accountSubscribe`/`logsSubscribe`
+/// handshake.
+#[derive(Debug, Clone, Serialize, utoipa::ToSchema)]
+#[serde(tag = "kind", rename_all = "lowercase")]
+pub enum PubsubEvent {
+    Account { subscription_id: u64, update: AccountUpdate },
+    Logs { subscription_id: u64, update: LogsUpdate },
+}
+
+/// What a `/solana/subscribe` client wants to watch: a single account's state, or a
+/// program/wallet's logs (the `mentions` filter also works for program ids, since a
+/// transaction's program id is itself one of the account keys it mentions).
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum PubsubTarget {
+    Account(String),
+    Logs(String),
+}
+
+impl PubsubTarget {
+    fn subscribe_request(&self) -> serde_json::Value {
+        match self {
+            PubsubTarget::Account(address) => serde_json::json!({
+                "jsonrpc": "2.0",
+                "id": 1,
+                "method": "accountSubscribe",
+                "params": [address, {"encoding": "base64", "commitment": "confirmed"}],
+            }),
+            PubsubTarget::Logs(mentions) => serde_json::json!({
+                "jsonrpc": "2.0",
+                "id": 1,
+                "method": "logsSubscribe",
+                "params": [{"mentions": [mentions]}, {"commitment": "confirmed"}],
+            }),
+        }
+    }
+
+    /// Decode one `accountNotification`/`logsNotification` frame, once the handshake
+    /// response has told us the `subscription_id` for this connection.
+    fn decode_notification(&self, frame: &serde_json::Value, subscription_id: u64) -> Option<PubsubEvent> {
+        let result = frame.get("params")?.get("result")?;
+        let slot = result.get("context")?.get("slot")?.as_u64().unwrap_or_default();
+        let value = result.get("value")?;
+
+        match self {
+            PubsubTarget::Account(_) => Some(PubsubEvent::Account {
+                subscription_id,
+                update: AccountUpdate {
+                    slot,
+                    lamports: value.get("lamports")?.as_u64()?,
+                    owner: value.get("owner")?.as_str()?.to_string(),
+                    executable: value.get("executable").and_then(|v| v.as_bool()).unwrap_or(false),
+                    rent_epoch: value.get("rentEpoch").and_then(|v| v.as_u64()).unwrap_or_default(),
+                    data_base64: value.get("data")?.as_array()?.first()?.as_str()?.to_string(),
+                },
+            }),
+            PubsubTarget::Logs(_) => Some(PubsubEvent::Logs {
+                subscription_id,
+                update: LogsUpdate {
+                    slot,
+                    signature: value.get("signature")?.as_str()?.to_string(),
+                    err: value.get("err").cloned(),
+                    logs: value.get("logs")?.as_array()?.iter().filter_map(|v| v.as_str().map(String::from)).collect(),
+                },
+            }),
+        }
+    }
+}
+
+/// Opens a real WebSocket connection to the Solana RPC pubsub endpoint and performs the
+/// `accountSubscribe`/`logsSubscribe` handshake (the node's `result` response carries the
+/// numeric subscription id before any notifications arrive), decoding each notification
+/// into a [`PubsubEvent`] and fanning it out over a broadcast channel to every SSE client
+/// watching the same [`PubsubTarget`]. Unlike [`SolanaWsHub`], which polls the RPC on an
+/// interval, this holds a persistent push connection and reconnects with backoff (per
+/// `retry`) the same way [`crate::sources::kraken_data::KrakenWsHub`] does for Kraken's feed.
+pub struct SolanaPubsubHub {
+    ws_url: Option<String>,
+    subscriptions: tokio::sync::Mutex<HashMap<PubsubTarget, tokio::sync::broadcast::Sender<PubsubEvent>>>,
+    retry: crate::config::RetryConfig,
+}
+
+impl SolanaPubsubHub {
+    /// `ws_url` is `None` when no Helius API key is configured; subscribers get a closed
+    /// stream immediately rather than the hub silently doing nothing.
+    pub fn new(api_key: Option<String>, retry: crate::config::RetryConfig) -> Self {
+        let ws_url = api_key.map(|key| format!("wss://mainnet.helius-rpc.com/?api-key={key}"));
+        Self { ws_url, subscriptions: tokio::sync::Mutex::new(HashMap::new()), retry }
+    }
+
+    /// Subscribe to live updates for `target`. Opens the upstream pubsub connection on the
+    /// first subscriber for a given target and reuses it for every subscriber after that.
+    pub fn subscribe(self: std::sync::Arc<Self>, target: PubsubTarget) -> impl futures::Stream<Item = PubsubEvent> {
+        async_stream::stream! {
+            let Some(ws_url) = self.ws_url.clone() else {
+                tracing::warn!("solana pubsub: Helius API key not configured, closing subscription");
+                return;
+            };
+
+            let mut rx = {
+                let mut subs = self.subscriptions.lock().await;
+                match subs.get(&target) {
+                    Some(tx) => tx.subscribe(),
+                    None => {
+                        let (tx, rx) = tokio::sync::broadcast::channel(64);
+                        subs.insert(target.clone(), tx.clone());
+                        tokio::spawn(self.clone().connect_and_publish(ws_url, target.clone(), tx));
+                        rx
+                    }
+                }
+            };
+
+            loop {
+                match rx.recv().await {
+                    Ok(value) => yield value,
+                    Err(tokio::sync::broadcast::error::RecvError::Lagged(_)) => continue,
+                    Err(tokio::sync::broadcast::error::RecvError::Closed) => break,
+                }
+            }
+        }
+    }
+
+    /// Holds the pubsub WebSocket open for `target`: sends the subscribe request, captures
+    /// the subscription id off the handshake response, then forwards every decoded
+    /// notification to subscribers. Reconnects with exponential backoff (capped by `retry`)
+    /// on disconnect or connect failure, and tears itself down once nobody is listening.
+    async fn connect_and_publish(
+        self: std::sync::Arc<Self>,
+        ws_url: String,
+        target: PubsubTarget,
+        tx: tokio::sync::broadcast::Sender<PubsubEvent>,
+    ) {
+        use futures::{SinkExt, StreamExt};
+
+        let mut attempt = 0u32;
+        while tx.receiver_count() > 0 {
+            match tokio_tungstenite::connect_async(&ws_url).await {
+                Ok((mut ws, _)) => {
+                    attempt = 0;
+                    let subscribe = target.subscribe_request();
+                    let sent = ws.send(tokio_tungstenite::tungstenite::Message::Text(subscribe.to_string().into())).await;
+                    if let Err(e) = sent {
+                        tracing::warn!("solana pubsub: subscribe failed for {target:?}: {e}");
+                    } else {
+                        let mut subscription_id: Option<u64> = None;
+                        while tx.receiver_count() > 0 {
+                            match ws.next().await {
+                                Some(Ok(tokio_tungstenite::tungstenite::Message::Text(text))) => {
+                                    let Ok(frame) = serde_json::from_str::<serde_json::Value>(&text) else { continue };
+                                    if subscription_id.is_none() {
+                                        if let Some(id) = frame.get("result").and_then(|v| v.as_u64()) {
+                                            subscription_id = Some(id);
+                                            continue;
+                                        }
+                                    }
+                                    let Some(id) = subscription_id else { continue };
+                                    if let Some(event) = target.decode_notification(&frame, id) {
+                                        let _ = tx.send(event);
+                                    }
+                                }
+                                Some(Ok(_)) => continue,
+                                Some(Err(e)) => {
+                                    tracing::warn!("solana pubsub: connection error for {target:?}: {e}");
+                                    break;
+                                }
+                                None => break,
+                            }
+                        }
+                    }
+                }
+                Err(e) => {
+                    tracing::warn!("solana pubsub: connect failed for {target:?}: {e}");
+                }
+            }
+
+            if tx.receiver_count() == 0 {
+                break;
+            }
+            tokio::time::sleep(self.backoff(attempt)).await;
+            attempt += 1;
+        }
+
+        self.subscriptions.lock().await.remove(&target);
+    }
+
+    /// Exponential backoff derived from the shared `RetryConfig`, capped at `max_delay_ms`.
+    fn backoff(&self, attempt: u32) -> std::time::Duration {
+        let exp_ms = self.retry.base_delay_ms
+            .saturating_mul(1u64 << attempt.min(16))
+            .min(self.retry.max_delay_ms);
+        std::time::Duration::from_millis(exp_ms.max(self.retry.base_delay_ms))
+    }
+}
+
+/// Confirmation state for a transaction submitted via `/solana/transactions/send`, kept up
+/// to date by the resend-until-confirmed background task and served back out by
+/// `/solana/transactions/{signature}/status`.
+#[derive(Debug, Clone, Serialize, Deserialize, utoipa::ToSchema)]
+pub struct TransactionStatus {
+    pub signature: String,
+    /// One of `pending`, `confirmed`, `failed`, `expired`.
+    pub status: String,
+    pub slot: Option<u64>,
+    pub confirmation_status: Option<String>,
+    pub attempts: u32,
+    pub error: Option<String>,
+}
+
+/// How often the resend loop re-broadcasts an unconfirmed transaction.
+const TRANSACTION_RESEND_INTERVAL: std::time::Duration = std::time::Duration::from_secs(2);
+/// Total resends before giving up on a transaction, independent of the deadline below.
+const TRANSACTION_MAX_ATTEMPTS: u32 = 30;
+/// Wall-clock cap on how long a transaction is kept alive without confirmation.
+const TRANSACTION_DEADLINE: std::time::Duration = std::time::Duration::from_secs(60);
+
+/// Backs `POST /solana/transactions/send` and `GET /solana/transactions/{signature}/status`.
+/// Submitting a transaction returns its signature immediately; a background task then
+/// resends it on a fixed interval until it confirms, its blockhash expires, or attempts or
+/// the deadline run out, updating the tracked status the whole way. Resubmitting the same
+/// signed transaction while it's already tracked is a no-op rather than a second send.
+pub struct TransactionTracker {
+    api_key: Option<String>,
+    statuses: tokio::sync::Mutex<HashMap<String, TransactionStatus>>,
+}
+
+impl TransactionTracker {
+    pub fn new(api_key: Option<String>) -> Self {
+        Self { api_key, statuses: tokio::sync::Mutex::new(HashMap::new()) }
+    }
+
+    /// Last known status for a signature this tracker has seen, if any.
+    pub async fn status(&self, signature: &str) -> Option<TransactionStatus> {
+        self.statuses.lock().await.get(signature).cloned()
+    }
+
+    /// Decode and submit `signed_tx_base64`, then hand it off to a background resend loop.
+    pub async fn send_transaction(
+        self: std::sync::Arc<Self>,
+        signed_tx_base64: &str,
+    ) -> Result<String, ApiError> {
+        let api_key = self.api_key.clone()
+            .ok_or_else(|| ApiError::InternalError("Helius API key not configured".to_string()))?;
+
+        let tx_bytes = base64::decode(signed_tx_base64)
+            .map_err(|e| ApiError::InvalidInput(format!("Invalid transaction encoding: {e}")))?;
+        let transaction: solana_sdk::transaction::Transaction = bincode::deserialize(&tx_bytes)
+            .map_err(|e| ApiError::InvalidInput(format!("Invalid transaction format: {e}")))?;
+        let signature = transaction.signatures.first()
+            .ok_or_else(|| ApiError::InvalidInput("transaction has no signature".to_string()))?
+            .to_string();
+
+        {
+            let mut statuses = self.statuses.lock().await;
+            if statuses.contains_key(&signature) {
+                return Ok(signature);
+            }
+            statuses.insert(signature.clone(), TransactionStatus {
+                signature: signature.clone(),
+                status: "pending".to_string(),
+                slot: None,
+                confirmation_status: None,
+                attempts: 1,
+                error: None,
+            });
+        }
+
+        let source = HeliusDataSource::new_mainnet(&api_key)
+            .map_err(|e| ApiError::Configuration(format!("failed to init Helius client: {e}")))?;
+        source.client.connection().send_transaction(&transaction)
+            .map_err(|e| ApiError::Upstream(format!("failed to submit transaction: {e}")))?;
+
+        tokio::spawn(self.resend_until_confirmed(signature.clone(), transaction, api_key));
+
+        Ok(signature)
+    }
+
+    /// Re-broadcast `transaction` every [`TRANSACTION_RESEND_INTERVAL`] until its signature
+    /// is confirmed or failed on-chain, its blockhash goes stale, or attempts/the deadline
+    /// run out -- whichever comes first -- updating the tracked [`TransactionStatus`] with
+    /// whatever the last upstream error was rather than leaving callers to poll forever.
+    async fn resend_until_confirmed(
+        self: std::sync::Arc<Self>,
+        signature: String,
+        transaction: solana_sdk::transaction::Transaction,
+        api_key: String,
+    ) {
+        let deadline = tokio::time::Instant::now() + TRANSACTION_DEADLINE;
+        let mut attempts = 1u32;
+        let mut last_error: Option<String> = None;
+
+        while attempts < TRANSACTION_MAX_ATTEMPTS && tokio::time::Instant::now() < deadline {
+            tokio::time::sleep(TRANSACTION_RESEND_INTERVAL).await;
+
+            let source = match HeliusDataSource::new_mainnet(&api_key) {
+                Ok(source) => source,
+                Err(e) => {
+                    last_error = Some(e.to_string());
+                    continue;
+                }
+            };
+            let connection = source.client.connection();
+
+            match connection.get_signature_status(&transaction.signatures[0]) {
+                Ok(Some(Ok(()))) => {
+                    self.set_status(&signature, "confirmed", None, Some("confirmed".to_string()), attempts, None).await;
+                    return;
+                }
+                Ok(Some(Err(e))) => {
+                    self.set_status(&signature, "failed", None, None, attempts, Some(e.to_string())).await;
+                    return;
+                }
+                Ok(None) => {
+                    match connection.is_blockhash_valid(&transaction.message.recent_blockhash, solana_sdk::commitment_config::CommitmentConfig::processed()) {
+                        Ok(true) => {
+                            attempts += 1;
+                            if let Err(e) = connection.send_transaction(&transaction) {
+                                last_error = Some(e.to_string());
+                            }
+                            self.set_status(&signature, "pending", None, None, attempts, None).await;
+                        }
+                        Ok(false) => {
+                            self.set_status(&signature, "expired", None, None, attempts, Some("blockhash expired".to_string())).await;
+                            return;
+                        }
+                        Err(e) => last_error = Some(e.to_string()),
+                    }
+                }
+                Err(e) => last_error = Some(e.to_string()),
+            }
+        }
+
+        let error = last_error.unwrap_or_else(|| "confirmation deadline exceeded".to_string());
+        self.set_status(&signature, "expired", None, None, attempts, Some(error)).await;
+    }
+
+    async fn set_status(
+        &self,
+        signature: &str,
+        status: &str,
+        slot: Option<u64>,
+        confirmation_status: Option<String>,
+        attempts: u32,
+        error: Option<String>,
+    ) {
+        self.statuses.lock().await.insert(signature.to_string(), TransactionStatus {
+            signature: signature.to_string(),
+            status: status.to_string(),
+            slot,
+            confirmation_status,
+            attempts,
+            error,
+        });
+    }
+}
+
+/// Decoded form of an `accountUpdate` frame from the Geyser gRPC stream.
+#[derive(Debug, Clone, Serialize, Deserialize, utoipa::ToSchema)]
+pub struct GeyserAccountUpdate {
+    pub pubkey: String,
+    pub lamports: u64,
+    pub owner: String,
+    /// Base64-encoded raw account data.
+    pub data: String,
+}
+
+/// Decoded form of a `transactionUpdate` frame from the Geyser gRPC stream.
+#[derive(Debug, Clone, Serialize, Deserialize, utoipa::ToSchema)]
+pub struct GeyserTransactionUpdate {
+    pub signature: String,
+    pub slot: u64,
+}
+
+/// One event yielded by [`HeliusStreamSource::subscribe`].
+#[derive(Debug, Clone, Serialize, utoipa::ToSchema)]
+#[serde(tag = "kind", rename_all = "lowercase")]
+pub enum GeyserEvent {
+    Account(GeyserAccountUpdate),
+    Transaction(GeyserTransactionUpdate),
+}
+
+/// What a Geyser subscription should filter transactions and account updates on:
+/// `account_include` is the set of program/account ids that must appear (e.g. the SPL Token
+/// and Bubblegum program ids, to catch mints and NFT trades); `account_required`, if
+/// non-empty, additionally requires every listed account be present.
+#[derive(Debug, Clone, Default)]
+pub struct GeyserFilter {
+    pub account_include: Vec<String>,
+    pub account_required: Vec<String>,
+}
+
+/// Live Yellowstone/Geyser gRPC subscriber, as an alternative to [`HeliusDataSource`]'s
+/// one-shot polling RPCs: activity touching `account_include` streams in as it lands
+/// on-chain instead of requiring callers to re-poll
+/// [`HeliusDataSource::get_trending_solana_assets`]. Reconnects with exponential backoff on
+/// stream errors the same way [`SolanaPubsubHub`] does for the RPC pubsub feed.
+pub struct HeliusStreamSource {
+    endpoint: String,
+    retry: crate::config::RetryConfig,
+}
+
+impl HeliusStreamSource {
+    pub fn new(endpoint: String, retry: crate::config::RetryConfig) -> Self {
+        Self { endpoint, retry }
+    }
+
+    /// Subscribe to slot/transaction/account updates matching `filter`. Returns a receiver
+    /// fed by a background task that holds the gRPC stream open and reconnects (with
+    /// exponential backoff, capped by `retry`) whenever it errors or disconnects. Dropping
+    /// the receiver tears the background task down on its next send.
+    pub fn subscribe(&self, filter: GeyserFilter) -> tokio::sync::mpsc::Receiver<GeyserEvent> {
+        let (tx, rx) = tokio::sync::mpsc::channel(256);
+        let endpoint = self.endpoint.clone();
+        let retry = self.retry.clone();
+        tokio::spawn(Self::run(endpoint, filter, retry, tx));
+        rx
+    }
+
+    async fn run(endpoint: String, filter: GeyserFilter, retry: crate::config::RetryConfig, tx: tokio::sync::mpsc::Sender<GeyserEvent>) {
+        let mut attempt = 0u32;
+        while !tx.is_closed() {
+            if let Err(e) = Self::stream_once(&endpoint, &filter, &tx).await {
+                tracing::warn!("geyser stream error for {endpoint}: {e}");
+            }
+            if tx.is_closed() {
+                break;
+            }
+            tokio::time::sleep(Self::backoff(&retry, attempt)).await;
+            attempt += 1;
+        }
+    }
+
+    /// Exponential backoff derived from the shared `RetryConfig`, capped at `max_delay_ms`
+    /// -- the same formula [`SolanaPubsubHub::backoff`] uses for the RPC pubsub feed.
+    fn backoff(retry: &crate::config::RetryConfig, attempt: u32) -> std::time::Duration {
+        let exp_ms = retry.base_delay_ms
+            .saturating_mul(1u64 << attempt.min(16))
+            .min(retry.max_delay_ms);
+        std::time::Duration::from_millis(exp_ms.max(retry.base_delay_ms))
+    }
+
+    /// Open one Geyser gRPC connection, subscribe with `filter`'s account lists (votes and
+    /// failed transactions excluded, `Finalized` commitment), and forward decoded updates
+    /// until the stream ends or errors.
+    async fn stream_once(
+        endpoint: &str,
+        filter: &GeyserFilter,
+        tx: &tokio::sync::mpsc::Sender<GeyserEvent>,
+    ) -> Result<(), String> {
+        use futures::StreamExt;
+        use yellowstone_grpc_client::GeyserGrpcClient;
+        use yellowstone_grpc_proto::prelude::{
+            CommitmentLevel, SubscribeRequest, SubscribeRequestFilterAccounts, SubscribeRequestFilterTransactions,
+        };
+
+        let mut client = GeyserGrpcClient::build_from_shared(endpoint.to_string())
+            .map_err(|e| format!("invalid geyser endpoint: {e}"))?
+            .connect()
+            .await
+            .map_err(|e| format!("geyser connect failed: {e}"))?;
+
+        let request = SubscribeRequest {
+            accounts: [("accounts".to_string(), SubscribeRequestFilterAccounts {
+                account: Vec::new(),
+                owner: Vec::new(),
+                filters: Vec::new(),
+                nonempty_txn_signature: None,
+            })].into_iter().collect(),
+            transactions: [("transactions".to_string(), SubscribeRequestFilterTransactions {
+                vote: Some(false),
+                failed: Some(false),
+                signature: None,
+                account_include: filter.account_include.clone(),
+                account_exclude: Vec::new(),
+                account_required: filter.account_required.clone(),
+            })].into_iter().collect(),
+            commitment: Some(CommitmentLevel::Finalized as i32),
+            ..Default::default()
+        };
+
+        let (_sink, mut stream) = client
+            .subscribe_with_request(Some(request))
+            .await
+            .map_err(|e| format!("geyser subscribe failed: {e}"))?;
+
+        while let Some(update) = stream.next().await {
+            let update = update.map_err(|e| format!("geyser stream error: {e}"))?;
+            let Some(event) = decode_geyser_update(update) else { continue };
+            if tx.send(event).await.is_err() {
+                break;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// Decode one `SubscribeUpdate` frame into a [`GeyserEvent`], skipping update kinds this
+/// subscriber doesn't surface (slot-only pings, block/entry metadata, etc.).
+fn decode_geyser_update(update: yellowstone_grpc_proto::prelude::SubscribeUpdate) -> Option<GeyserEvent> {
+    use yellowstone_grpc_proto::prelude::subscribe_update::UpdateOneof;
+
+    match update.update_oneof? {
+        UpdateOneof::Account(account_update) => {
+            let account = account_update.account?;
+            Some(GeyserEvent::Account(GeyserAccountUpdate {
+                pubkey: solana_sdk::bs58::encode(&account.pubkey).into_string(),
+                lamports: account.lamports,
+                owner: solana_sdk::bs58::encode(&account.owner).into_string(),
+                data: base64::encode(&account.data),
+            }))
+        }
+        UpdateOneof::Transaction(tx_update) => {
+            let transaction = tx_update.transaction?;
+            Some(GeyserEvent::Transaction(GeyserTransactionUpdate {
+                signature: solana_sdk::bs58::encode(&transaction.signature).into_string(),
+                slot: tx_update.slot,
+            }))
+        }
+        _ => None,
+    }
 }
 
 /// Convert Helius Asset to our SolanaAsset structure
@@ -356,6 +1981,7 @@ fn convert_helius_asset_to_solana_asset(asset: Asset) -> Result<SolanaAsset, Box
             .and_then(|g| g.first())
             .and_then(|g| g.group_value.clone()),
         attributes: None, // Simplified for now due to API complexity
+        compressed: asset.compression.as_ref().map(|c| c.compressed).unwrap_or(false),
     })
 }
 
@@ -380,7 +2006,7 @@ mod tests {
         }
 
         let helius = HeliusDataSource::new_mainnet(&api_key).unwrap();
-        let result = helius.get_trending_solana_assets(5).await;
+        let result = helius.get_trending_solana_assets(5, None).await;
         
         match result {
             Ok(trending) => {
@@ -1,6 +1,28 @@
 use hyperliquid_rust_sdk::{InfoClient, BaseUrl};
+use lazy_static::lazy_static;
 use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::collections::HashMap;
+use std::sync::Arc;
+use tokio::sync::{broadcast, Mutex};
+use crate::config::RetryConfig;
+use crate::errors::ApiError;
+use crate::services::candles::{Candle, CandleStore};
 use crate::types::TrendingItem;
+use crate::utils::with_retry;
+
+lazy_static! {
+    /// Process-wide retry policy for `InfoClient` calls, read once from `RETRY_*` env vars (see
+    /// [`RetryConfig::from_env`]) so operators can tune it per environment without a redeploy.
+    static ref HYPERLIQUID_RETRY_POLICY: RetryConfig = RetryConfig::from_env();
+}
+
+/// The SDK's error type carries no status code to distinguish transient from permanent failures,
+/// so -- same as `sources::reddit_data::is_permanent_reddit_error` -- every error is treated as
+/// transient and worth a retry.
+fn is_permanent_hyperliquid_error<E>(_err: &E) -> bool {
+    false
+}
 
 #[derive(Debug, Serialize, Deserialize, Clone, utoipa::ToSchema)]
 pub struct HyperliquidMarket {
@@ -87,24 +109,38 @@ pub struct HyperliquidFunding {
     pub time: u64,
 }
 
+/// Hyperliquid's public Info REST endpoint (see
+/// <https://hyperliquid.gitbook.io/hyperliquid-docs/for-developers/api/info>). Used directly via
+/// `http` for requests the SDK's `InfoClient` doesn't expose (e.g. `l2Book`), rather than adding
+/// a dependency on a newer SDK version.
+const HYPERLIQUID_INFO_URL: &str = "https://api.hyperliquid.xyz/info";
+
 pub struct HyperliquidDataSource {
     info_client: InfoClient,
+    http: reqwest::Client,
 }
 
 impl HyperliquidDataSource {
     pub async fn new() -> Result<Self, Box<dyn std::error::Error + Send + Sync>> {
         let info_client = InfoClient::new(None, None).await?;
-        Ok(Self { info_client })
+        Ok(Self { info_client, http: reqwest::Client::new() })
     }
 
     pub async fn new_testnet() -> Result<Self, Box<dyn std::error::Error + Send + Sync>> {
         let info_client = InfoClient::new(None, Some(BaseUrl::Testnet)).await?;
-        Ok(Self { info_client })
+        Ok(Self { info_client, http: reqwest::Client::new() })
     }
 
     /// Get all available markets using the real Hyperliquid API
     pub async fn get_all_markets(&self) -> Result<Vec<HyperliquidMarket>, Box<dyn std::error::Error + Send + Sync>> {
-        match self.info_client.meta().await {
+        let meta_result = with_retry(
+            &HYPERLIQUID_RETRY_POLICY,
+            "hyperliquid meta",
+            is_permanent_hyperliquid_error,
+            || self.info_client.meta(),
+        )
+        .await;
+        match meta_result {
             Ok(meta_response) => {
                 let mut markets = Vec::new();
                 
@@ -144,21 +180,48 @@ impl HyperliquidDataSource {
             .ok_or_else(|| format!("Market not found: {}", coin).into())
     }
 
-    /// Get orderbook for a specific coin
+    /// Get orderbook for a specific coin. `InfoClient` doesn't expose `l2Book` in the SDK
+    /// version this crate depends on, so this calls Hyperliquid's public Info REST endpoint
+    /// directly instead (`{"type": "l2Book", "coin": ...}`), the same payload shape the `l2Book`
+    /// WebSocket channel pushes (see `RawL2Book` below).
     pub async fn get_orderbook(&self, coin: &str, _depth: Option<u32>) -> Result<HyperliquidOrderbook, Box<dyn std::error::Error + Send + Sync>> {
-        // Note: l2_book method not available in current SDK version
-        // Return empty orderbook as placeholder
-        let orderbook = HyperliquidOrderbook {
-            coin: coin.to_string(),
-            levels: vec![Vec::new(), Vec::new()], // [bids, asks]
-            time: chrono::Utc::now().timestamp() as u64,
-        };
-        Ok(orderbook)
+        let body = serde_json::json!({ "type": "l2Book", "coin": coin });
+        let raw: RawL2Book = self.http
+            .post(HYPERLIQUID_INFO_URL)
+            .json(&body)
+            .send()
+            .await?
+            .error_for_status()?
+            .json()
+            .await?;
+
+        Ok(HyperliquidOrderbook {
+            coin: raw.coin,
+            levels: raw.levels
+                .into_iter()
+                .map(|side| {
+                    side.into_iter()
+                        .map(|level| OrderbookLevel {
+                            price: level.px.parse().unwrap_or(0.0),
+                            size: level.sz.parse().unwrap_or(0.0),
+                        })
+                        .collect()
+                })
+                .collect(),
+            time: raw.time,
+        })
     }
 
     /// Get candlestick data
     pub async fn get_candles(&self, coin: &str, interval: &str, start_time: u64, end_time: u64) -> Result<Vec<HyperliquidCandle>, Box<dyn std::error::Error + Send + Sync>> {
-        match self.info_client.candles_snapshot(coin.to_string(), interval.to_string(), start_time, end_time).await {
+        let candles_result = with_retry(
+            &HYPERLIQUID_RETRY_POLICY,
+            &format!("hyperliquid candles_snapshot for {coin}"),
+            is_permanent_hyperliquid_error,
+            || self.info_client.candles_snapshot(coin.to_string(), interval.to_string(), start_time, end_time),
+        )
+        .await;
+        match candles_result {
             Ok(response) => {
                 let candles: Vec<HyperliquidCandle> = response
                     .into_iter()
@@ -184,7 +247,14 @@ impl HyperliquidDataSource {
     pub async fn get_user_state(&self, user_address: &str) -> Result<HyperliquidUserState, Box<dyn std::error::Error + Send + Sync>> {
         // Parse address to H160 format required by SDK
         let address = user_address.parse().map_err(|_| "Invalid address format")?;
-        match self.info_client.user_state(address).await {
+        let user_state_result = with_retry(
+            &HYPERLIQUID_RETRY_POLICY,
+            &format!("hyperliquid user_state for {user_address}"),
+            is_permanent_hyperliquid_error,
+            || self.info_client.user_state(address),
+        )
+        .await;
+        match user_state_result {
             Ok(response) => {
                 let user_state = HyperliquidUserState {
                     margin_summary: MarginSummary {
@@ -209,10 +279,10 @@ impl HyperliquidDataSource {
         }
     }
 
-    /// Get recent trades for a coin
+    /// Get recent trades for a coin. Hyperliquid's public Info API has no "recent trades"
+    /// endpoint (trades are only available as a live append-only tape), so this always returns
+    /// empty; use [`HyperliquidWsHub::subscribe`]'s `Trades` channel for live prints instead.
     pub async fn get_recent_trades(&self, _coin: &str, _limit: Option<u32>) -> Result<Vec<HyperliquidTrade>, Box<dyn std::error::Error + Send + Sync>> {
-        // Note: The SDK might not have a direct trades endpoint in the current version
-        // This would need to be implemented based on the actual SDK capabilities
         Ok(Vec::new())
     }
 
@@ -222,35 +292,13 @@ impl HyperliquidDataSource {
         Ok(Vec::new())
     }
 
-    /// Get trending DeFi assets based on volume and price movement
+    /// Get trending DeFi assets based on volume and price movement. Delegates to
+    /// [`crate::sources::market_source::MarketDataSource::trending`]'s default ranking (volume
+    /// sort + position scoring), the shared implementation every registered venue gets for free,
+    /// rather than hand-rolling the same sort-and-score logic here.
     pub async fn get_trending_defi_assets(&self, limit: usize) -> Result<Vec<TrendingItem>, Box<dyn std::error::Error + Send + Sync>> {
-        match self.get_all_markets().await {
-            Ok(markets) => {
-                let trending_items: Vec<TrendingItem> = markets
-                    .into_iter()
-                    .take(limit)
-                    .enumerate()
-                    .map(|(index, market)| TrendingItem {
-                        id: format!("hyperliquid_{}", market.coin.to_lowercase()),
-                        symbol: market.coin.clone(),
-                        name: format!("{} Perpetual", market.coin),
-                        price: Some(market.mark_price),
-                        price_change_24h: Some(market.price_change_24h),
-                        price_change_percentage_24h: Some(market.price_change_percentage_24h),
-                        volume: Some(market.volume_24h),
-                        market_cap: None, // Perpetuals don't have market cap
-                        market_cap_rank: None,
-                        score: Some(index as f64 + 1.0), // Simple scoring based on position
-                        source: "hyperliquid".to_string(),
-                        image_url: None,
-                        last_updated: Some(chrono::Utc::now().timestamp().to_string()),
-                    })
-                    .collect();
-
-                Ok(trending_items)
-            }
-            Err(e) => Err(format!("Failed to get trending DeFi assets: {}", e).into()),
-        }
+        use crate::sources::market_source::MarketDataSource;
+        MarketDataSource::trending(self, limit).await.map_err(|e| e.to_string().into())
     }
 
     /// Get top DeFi markets by volume
@@ -282,6 +330,478 @@ impl HyperliquidDataSource {
     }
 }
 
+/// Namespaces a `(coin, interval)` pair into the `pair` key [`CandleStore`] persists under, so
+/// Hyperliquid's base-resolution candles can't collide with a Kraken pair of the same literal
+/// coin name. Mirrors `sources::pumpfun_data::candle_store_pair`.
+pub fn candle_store_pair(coin: &str, interval: &str) -> String {
+    format!("hyperliquid:{coin}:{interval}")
+}
+
+/// Persists `candles` (as fetched via [`HyperliquidDataSource::get_candles`]) as 1-minute rows
+/// under `candle_store_pair(coin, interval)`, so [`CandleStore::aggregated_candles`] can later
+/// synthesize any coarser `resolution` from this one stored base resolution. A no-op when
+/// `candle_store` isn't configured. Idempotent like every other `upsert_candle_1m` caller, so
+/// a re-run over an already-backfilled range just overwrites with the same rows.
+pub async fn backfill_candles(candle_store: &CandleStore, coin: &str, interval: &str, candles: &[HyperliquidCandle]) -> Result<(), ApiError> {
+    if !candle_store.is_enabled() {
+        return Ok(());
+    }
+
+    let pair = candle_store_pair(coin, interval);
+    for candle in candles {
+        let Some(bucket_start) = chrono::DateTime::from_timestamp((candle.time / 1000) as i64, 0) else { continue };
+        candle_store
+            .upsert_candle_1m(&Candle {
+                pair: pair.clone(),
+                bucket_start,
+                open: candle.open,
+                high: candle.high,
+                low: candle.low,
+                close: candle.close,
+                volume: candle.volume,
+            })
+            .await?;
+    }
+
+    Ok(())
+}
+
+/// Walks `[start_time, end_time)` (both Unix ms, as `HyperliquidDataSource::get_candles` takes)
+/// in `window_ms`-sized windows, fetching and [`backfill_candles`]-persisting each one in turn.
+/// Every window is re-fetched and upserted unconditionally rather than checked for existing
+/// coverage first -- `upsert_candle_1m` is idempotent, so the only cost of overlap is a wasted
+/// upstream call, not incorrect data -- which keeps this a straightforward sequential walk
+/// rather than a true change-aware gap-filler.
+pub async fn backfill_gaps(
+    hyperliquid: &HyperliquidDataSource,
+    candle_store: &CandleStore,
+    coin: &str,
+    interval: &str,
+    start_time: u64,
+    end_time: u64,
+    window_ms: u64,
+) -> Result<(), ApiError> {
+    let mut cursor = start_time;
+    while cursor < end_time {
+        let window_end = (cursor + window_ms).min(end_time);
+
+        match hyperliquid.get_candles(coin, interval, cursor, window_end).await {
+            Ok(candles) => backfill_candles(candle_store, coin, interval, &candles).await?,
+            Err(e) => tracing::warn!("hyperliquid candle backfill: failed to fetch {coin}/{interval} [{cursor}, {window_end}): {e}"),
+        }
+
+        cursor = window_end;
+    }
+
+    Ok(())
+}
+
+/// Hyperliquid's public WebSocket endpoint (see <https://hyperliquid.gitbook.io/hyperliquid-docs/for-developers/api/websocket>).
+const HYPERLIQUID_WS_URL: &str = "wss://api.hyperliquid.xyz/ws";
+
+/// Live channel [`HyperliquidWsHub`] can subscribe to. Maps to the `type` Hyperliquid expects
+/// in its `subscription` object.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum HyperliquidWsChannel {
+    Orderbook,
+    Trades,
+    Funding,
+    Candle,
+}
+
+impl HyperliquidWsChannel {
+    fn wire_type(&self) -> &'static str {
+        match self {
+            Self::Orderbook => "l2Book",
+            Self::Trades => "trades",
+            Self::Funding => "activeAssetCtx",
+            Self::Candle => "candle",
+        }
+    }
+}
+
+#[derive(Debug, Deserialize)]
+struct HyperliquidWsFrame {
+    channel: String,
+    data: Value,
+}
+
+#[derive(Debug, Deserialize)]
+struct RawLevel {
+    px: String,
+    sz: String,
+}
+
+#[derive(Debug, Deserialize)]
+struct RawL2Book {
+    coin: String,
+    time: u64,
+    levels: Vec<Vec<RawLevel>>,
+}
+
+#[derive(Debug, Deserialize)]
+struct RawTrade {
+    coin: String,
+    side: String,
+    px: String,
+    sz: String,
+    time: u64,
+    hash: String,
+}
+
+#[derive(Debug, Deserialize)]
+struct RawAssetCtx {
+    funding: String,
+}
+
+#[derive(Debug, Deserialize)]
+struct RawActiveAssetCtx {
+    coin: String,
+    ctx: RawAssetCtx,
+}
+
+/// Hyperliquid's `candle` WS channel pushes one of these per bar close/update; `i` is the
+/// interval it was subscribed with (e.g. `"1m"`), carried on the frame itself rather than the
+/// subscription, so [`parse_ws_frame`] doesn't need it passed in separately.
+#[derive(Debug, Deserialize)]
+struct RawWsCandle {
+    #[serde(rename = "t")]
+    time: u64,
+    #[serde(rename = "s")]
+    coin: String,
+    #[serde(rename = "i")]
+    interval: String,
+    #[serde(rename = "o")]
+    open: String,
+    #[serde(rename = "h")]
+    high: String,
+    #[serde(rename = "l")]
+    low: String,
+    #[serde(rename = "c")]
+    close: String,
+    #[serde(rename = "v")]
+    volume: String,
+}
+
+/// Parses one upstream WS text frame into a value for `channel`, filtering out subscribe acks
+/// and other channels' frames that share the same connection. Returns `None` on anything that
+/// doesn't parse or isn't `channel`'s own frame, mirroring `sources::kraken_data::KrakenWsMessage`'s
+/// "skip what we don't recognize" handling for the same multiplexed-socket situation.
+fn parse_ws_frame(channel: HyperliquidWsChannel, text: &str) -> Option<Value> {
+    let frame: HyperliquidWsFrame = serde_json::from_str(text).ok()?;
+    if frame.channel != channel.wire_type() {
+        return None;
+    }
+
+    match channel {
+        HyperliquidWsChannel::Orderbook => {
+            let raw: RawL2Book = serde_json::from_value(frame.data).ok()?;
+            let orderbook = HyperliquidOrderbook {
+                coin: raw.coin,
+                levels: raw.levels
+                    .into_iter()
+                    .map(|side| {
+                        side.into_iter()
+                            .map(|level| OrderbookLevel {
+                                price: level.px.parse().unwrap_or(0.0),
+                                size: level.sz.parse().unwrap_or(0.0),
+                            })
+                            .collect()
+                    })
+                    .collect(),
+                time: raw.time,
+            };
+            serde_json::to_value(orderbook).ok()
+        }
+        HyperliquidWsChannel::Trades => {
+            let raw: Vec<RawTrade> = serde_json::from_value(frame.data).ok()?;
+            let trades: Vec<HyperliquidTrade> = raw
+                .into_iter()
+                .map(|t| HyperliquidTrade {
+                    coin: t.coin,
+                    side: t.side,
+                    px: t.px.parse().unwrap_or(0.0),
+                    sz: t.sz.parse().unwrap_or(0.0),
+                    time: t.time,
+                    hash: t.hash,
+                })
+                .collect();
+            serde_json::to_value(trades).ok()
+        }
+        HyperliquidWsChannel::Funding => {
+            let raw: RawActiveAssetCtx = serde_json::from_value(frame.data).ok()?;
+            let funding = HyperliquidFunding {
+                coin: raw.coin,
+                funding_rate: raw.ctx.funding.parse().unwrap_or(0.0),
+                premium: 0.0, // Not present on the activeAssetCtx payload parsed here
+                time: chrono::Utc::now().timestamp_millis() as u64,
+            };
+            serde_json::to_value(funding).ok()
+        }
+        HyperliquidWsChannel::Candle => {
+            let raw: RawWsCandle = serde_json::from_value(frame.data).ok()?;
+            let candle = HyperliquidCandle {
+                coin: raw.coin,
+                interval: raw.interval,
+                time: raw.time,
+                open: raw.open.parse().unwrap_or(0.0),
+                high: raw.high.parse().unwrap_or(0.0),
+                low: raw.low.parse().unwrap_or(0.0),
+                close: raw.close.parse().unwrap_or(0.0),
+                volume: raw.volume.parse().unwrap_or(0.0),
+            };
+            serde_json::to_value(candle).ok()
+        }
+    }
+}
+
+/// Shares one upstream WebSocket connection per `(channel, coin)` across every client streaming
+/// it, the same sharing strategy as [`crate::sources::kraken_data::KrakenWsHub`] -- N subscribers
+/// cost one upstream socket. `HyperliquidDataSource`'s SDK-backed methods above can't stream (its
+/// `l2_book`/trades/funding calls aren't available -- see their placeholder comments), so this
+/// connects directly to Hyperliquid's public WS endpoint instead of going through `InfoClient`.
+pub struct HyperliquidWsHub {
+    subscriptions: Mutex<HashMap<String, broadcast::Sender<Value>>>,
+    retry: crate::config::RetryConfig,
+}
+
+impl HyperliquidWsHub {
+    pub fn new(retry: crate::config::RetryConfig) -> Self {
+        Self { subscriptions: Mutex::new(HashMap::new()), retry }
+    }
+
+    /// Subscribe to live `channel` updates for `coin` (and, for `Candle`, `interval`). Opens the
+    /// upstream connection on the first subscriber for a given `(channel, coin, interval)` key
+    /// and reuses it after that; the upstream subscription is dropped once the last subscriber
+    /// disconnects (see [`Self::connect_and_publish`]'s `tx.receiver_count() == 0` teardown).
+    pub fn subscribe(self: Arc<Self>, channel: HyperliquidWsChannel, coin: String, interval: Option<String>) -> impl futures::Stream<Item = Value> {
+        let key = Self::subscription_key(channel, &coin, interval.as_deref());
+
+        async_stream::stream! {
+            let mut rx = {
+                let mut subs = self.subscriptions.lock().await;
+                match subs.get(&key) {
+                    Some(tx) => tx.subscribe(),
+                    None => {
+                        let (tx, rx) = broadcast::channel(64);
+                        subs.insert(key.clone(), tx.clone());
+                        tokio::spawn(self.clone().connect_and_publish(channel, coin.clone(), interval.clone(), key.clone(), tx));
+                        rx
+                    }
+                }
+            };
+
+            loop {
+                match rx.recv().await {
+                    Ok(value) => yield value,
+                    Err(broadcast::error::RecvError::Lagged(_)) => continue,
+                    Err(broadcast::error::RecvError::Closed) => break,
+                }
+            }
+        }
+    }
+
+    /// Seeds a fresh subscriber with `snapshot` (a REST read via [`snapshot_for`]) before handing
+    /// off to [`Self::subscribe`]'s live delta stream, so a client's first frame is current state
+    /// rather than whatever the next broadcast happens to be. `snapshot` is `None` for channels
+    /// with no meaningful REST snapshot (e.g. `Trades`), in which case this is equivalent to
+    /// `subscribe`.
+    pub fn subscribe_with_snapshot(
+        self: Arc<Self>,
+        channel: HyperliquidWsChannel,
+        coin: String,
+        interval: Option<String>,
+        snapshot: Option<Value>,
+    ) -> impl futures::Stream<Item = Value> {
+        use futures::StreamExt;
+
+        async_stream::stream! {
+            if let Some(snapshot) = snapshot {
+                yield snapshot;
+            }
+
+            let live = self.subscribe(channel, coin, interval);
+            futures::pin_mut!(live);
+            while let Some(value) = live.next().await {
+                yield value;
+            }
+        }
+    }
+
+    fn subscription_key(channel: HyperliquidWsChannel, coin: &str, interval: Option<&str>) -> String {
+        match interval {
+            Some(interval) => format!("{}:{coin}:{interval}", channel.wire_type()),
+            None => format!("{}:{coin}", channel.wire_type()),
+        }
+    }
+
+    /// Holds a WebSocket connection to Hyperliquid open for `(channel, coin, interval)`,
+    /// forwarding each matching frame to every subscriber. Reconnects with exponential backoff
+    /// (capped by `retry`) on disconnect or connect failure, and tears itself down once nobody is
+    /// listening.
+    async fn connect_and_publish(
+        self: Arc<Self>,
+        channel: HyperliquidWsChannel,
+        coin: String,
+        interval: Option<String>,
+        key: String,
+        tx: broadcast::Sender<Value>,
+    ) {
+        use futures::{SinkExt, StreamExt};
+
+        let mut attempt = 0u32;
+        while tx.receiver_count() > 0 {
+            match tokio_tungstenite::connect_async(HYPERLIQUID_WS_URL).await {
+                Ok((mut ws, _)) => {
+                    attempt = 0;
+                    let mut subscription = serde_json::json!({ "type": channel.wire_type(), "coin": coin });
+                    if let Some(interval) = &interval {
+                        subscription["interval"] = serde_json::Value::String(interval.clone());
+                    }
+                    let subscribe = serde_json::json!({
+                        "method": "subscribe",
+                        "subscription": subscription
+                    });
+                    let sent = ws.send(tokio_tungstenite::tungstenite::Message::Text(subscribe.to_string().into())).await;
+                    if let Err(e) = sent {
+                        tracing::warn!("hyperliquid ws: subscribe failed for {key}: {e}");
+                    } else {
+                        while tx.receiver_count() > 0 {
+                            match ws.next().await {
+                                Some(Ok(tokio_tungstenite::tungstenite::Message::Text(text))) => {
+                                    if let Some(value) = parse_ws_frame(channel, &text) {
+                                        let _ = tx.send(value);
+                                    }
+                                }
+                                Some(Ok(_)) => continue,
+                                Some(Err(e)) => {
+                                    tracing::warn!("hyperliquid ws: connection error for {key}: {e}");
+                                    break;
+                                }
+                                None => break,
+                            }
+                        }
+                    }
+                }
+                Err(e) => {
+                    tracing::warn!("hyperliquid ws: connect failed for {key}: {e}");
+                }
+            }
+
+            if tx.receiver_count() == 0 {
+                break;
+            }
+            tokio::time::sleep(self.backoff(attempt)).await;
+            attempt += 1;
+        }
+
+        self.subscriptions.lock().await.remove(&key);
+    }
+
+    /// Exponential backoff derived from the shared `RetryConfig`, capped at `max_delay_ms`.
+    fn backoff(&self, attempt: u32) -> std::time::Duration {
+        let exp_ms = self.retry.base_delay_ms
+            .saturating_mul(1u64 << attempt.min(16))
+            .min(self.retry.max_delay_ms);
+        std::time::Duration::from_millis(exp_ms.max(self.retry.base_delay_ms))
+    }
+}
+
+/// Fetches a one-shot REST snapshot for `channel`/`coin` (and `interval` for `Candle`) to seed a
+/// fresh [`HyperliquidWsHub::subscribe_with_snapshot`] subscriber before it switches to the live
+/// delta stream, so a client's first frame is current state rather than whatever happens to
+/// broadcast next. Returns `None` for `Trades` (an append-only tape with no "current state" to
+/// snapshot), for `Funding`, and on any upstream REST failure -- in each case the subscriber just
+/// starts from the live stream with nothing seeded.
+pub async fn snapshot_for(
+    hyperliquid: &HyperliquidDataSource,
+    channel: HyperliquidWsChannel,
+    coin: &str,
+    interval: Option<&str>,
+) -> Option<Value> {
+    match channel {
+        HyperliquidWsChannel::Orderbook => {
+            let book = hyperliquid.get_orderbook(coin, None).await.ok()?;
+            serde_json::to_value(book).ok()
+        }
+        HyperliquidWsChannel::Candle => {
+            let interval = interval.unwrap_or("1m");
+            let end_time = chrono::Utc::now().timestamp_millis() as u64;
+            let start_time = end_time.saturating_sub(60 * 60 * 1000);
+            let candles = hyperliquid.get_candles(coin, interval, start_time, end_time).await.ok()?;
+            serde_json::to_value(candles).ok()
+        }
+        HyperliquidWsChannel::Trades | HyperliquidWsChannel::Funding => None,
+    }
+}
+
+/// Adapts the inherent methods above to [`crate::sources::market_source::MarketDataSource`] so
+/// cross-venue endpoints (e.g. `/markets/compare/{coin}`) can query Hyperliquid the same way as
+/// [`crate::sources::coinbase_data::CoinbaseDataSource`]. Dot-calls to `self.get_all_markets()` etc.
+/// below resolve to the inherent methods above, not this trait, since inherent methods always take
+/// priority -- so there's no recursion despite the shared names.
+#[async_trait::async_trait]
+impl crate::sources::market_source::MarketDataSource for HyperliquidDataSource {
+    fn venue(&self) -> &'static str {
+        "hyperliquid"
+    }
+
+    async fn get_all_markets(&self) -> Result<Vec<crate::sources::market_source::UnifiedMarket>, ApiError> {
+        let markets = self.get_all_markets().await.map_err(|e| ApiError::Upstream(e.to_string()))?;
+        Ok(markets.into_iter()
+            .map(|m| crate::sources::market_source::UnifiedMarket {
+                symbol: m.coin,
+                last_price: if m.mark_price > 0.0 { m.mark_price } else { m.index_price },
+                volume_24h: m.volume_24h,
+                price_change_percentage_24h: m.price_change_percentage_24h,
+                funding_rate: m.funding,
+            })
+            .collect())
+    }
+
+    async fn get_orderbook(&self, symbol: &str, depth: Option<u32>) -> Result<crate::sources::market_source::UnifiedOrderbook, ApiError> {
+        let book = self.get_orderbook(symbol, depth).await.map_err(|e| ApiError::Upstream(e.to_string()))?;
+        let to_levels = |levels: &[OrderbookLevel]| levels.iter()
+            .map(|l| crate::sources::market_source::UnifiedOrderbookLevel { price: l.price, size: l.size })
+            .collect();
+
+        Ok(crate::sources::market_source::UnifiedOrderbook {
+            symbol: book.coin,
+            bids: book.levels.first().map(|l| to_levels(l)).unwrap_or_default(),
+            asks: book.levels.get(1).map(|l| to_levels(l)).unwrap_or_default(),
+            time: book.time,
+        })
+    }
+
+    async fn get_recent_trades(&self, symbol: &str, limit: Option<u32>) -> Result<Vec<crate::sources::market_source::UnifiedTrade>, ApiError> {
+        let trades = self.get_recent_trades(symbol, limit).await.map_err(|e| ApiError::Upstream(e.to_string()))?;
+        Ok(trades.into_iter()
+            .map(|t| crate::sources::market_source::UnifiedTrade {
+                symbol: t.coin,
+                side: t.side,
+                price: t.px,
+                size: t.sz,
+                time: t.time,
+            })
+            .collect())
+    }
+
+    async fn get_candles(&self, symbol: &str, interval: &str, start_time: u64, end_time: u64) -> Result<Vec<crate::sources::market_source::UnifiedCandle>, ApiError> {
+        let candles = self.get_candles(symbol, interval, start_time, end_time).await.map_err(|e| ApiError::Upstream(e.to_string()))?;
+        Ok(candles.into_iter()
+            .map(|c| crate::sources::market_source::UnifiedCandle {
+                symbol: c.coin,
+                time: c.time,
+                open: c.open,
+                high: c.high,
+                low: c.low,
+                close: c.close,
+                volume: c.volume,
+            })
+            .collect())
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
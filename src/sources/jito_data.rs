@@ -1,4 +1,8 @@
 use std::collections::HashMap;
+use std::future::Future;
+use std::time::Duration;
+use rand::seq::SliceRandom;
+use rand::Rng;
 use serde::{Deserialize, Serialize};
 use utoipa::ToSchema;
 use jito_sdk_rust::{
@@ -8,11 +12,16 @@ use jito_sdk_rust::{
 };
 use solana_sdk::{
     signature::{Keypair, Signature},
-    transaction::Transaction,
+    transaction::{Transaction, VersionedTransaction},
     pubkey::Pubkey,
+    system_instruction,
 };
 use crate::errors::ApiError;
 
+const JUPITER_QUOTE_URL: &str = "https://quote-api.jup.ag/v6/quote";
+const JUPITER_SWAP_URL: &str = "https://quote-api.jup.ag/v6/swap";
+const RETRY_BASE_DELAY_MS: u64 = 200;
+
 #[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
 pub struct JitoConfig {
     pub block_engine_url: String,
@@ -21,6 +30,9 @@ pub struct JitoConfig {
     pub tip_amount: Option<u64>, // in lamports
     pub max_retries: u32,
     pub timeout_ms: u64,
+    /// When true (driven by `MOCK_JITO`), `JitoService` fabricates deterministic
+    /// responses instead of talking to a real block engine.
+    pub mock: bool,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
@@ -45,6 +57,17 @@ pub struct BundleResult {
     pub submitted_at: String,
 }
 
+/// Base64-encoded, unsigned swap + tip transactions built from a [`SwapIntent`]. The server
+/// holds no private key for an arbitrary caller-supplied `user_pubkey`, so it can only build
+/// these, not sign or submit them -- the caller must sign both (e.g. with their own wallet) and
+/// resubmit the result through `send_bundle`/`POST /jito/bundles`.
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct UnsignedSwapBundle {
+    pub swap_transaction: String,
+    pub tip_transaction: String,
+    pub tip_amount: u64,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
 pub struct TransactionResult {
     pub signature: String,
@@ -77,6 +100,39 @@ pub struct TipAccountsResult {
     pub recommended_tip_lamports: u64,
 }
 
+/// A swap intent expressed in terms of mints/amount rather than a pre-built transaction.
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct SwapIntent {
+    pub input_mint: String,
+    pub output_mint: String,
+    pub amount: u64,
+    pub slippage_bps: u16,
+    pub user_pubkey: String,
+    pub tip_amount: Option<u64>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+struct JupiterQuoteResponse {
+    #[serde(flatten)]
+    raw: HashMap<String, serde_json::Value>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+struct JupiterSwapRequest<'a> {
+    #[serde(rename = "quoteResponse")]
+    quote_response: &'a HashMap<String, serde_json::Value>,
+    #[serde(rename = "userPublicKey")]
+    user_public_key: String,
+    #[serde(rename = "wrapAndUnwrapSol")]
+    wrap_and_unwrap_sol: bool,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+struct JupiterSwapResponse {
+    #[serde(rename = "swapTransaction")]
+    swap_transaction: String,
+}
+
 impl Default for JitoConfig {
     fn default() -> Self {
         Self {
@@ -86,10 +142,45 @@ impl Default for JitoConfig {
             tip_amount: Some(1000), // 1000 lamports default tip
             max_retries: 3,
             timeout_ms: 30000,
+            mock: false,
         }
     }
 }
 
+/// A stable, deterministic (non-cryptographic) fingerprint used to fabricate
+/// mock bundle IDs/signatures from their inputs.
+fn mock_fingerprint(bytes: &[u8]) -> u64 {
+    use std::hash::{Hash, Hasher};
+    let mut hasher = std::collections::hash_map::DefaultHasher::new();
+    bytes.hash(&mut hasher);
+    hasher.finish()
+}
+
+/// Fabricate a `BundleStatusResult` that deterministically walks pending ->
+/// landed -> finalized as real wall-clock time passes, so repeated polling in
+/// mock mode still looks like a bundle progressing toward confirmation.
+fn mock_bundle_status(bundle_id: &str) -> BundleStatusResult {
+    let fp = mock_fingerprint(bundle_id.as_bytes());
+    let now_secs = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .map(|d| d.as_secs())
+        .unwrap_or(0);
+    let phase = now_secs.wrapping_add(fp) / 5 % 3;
+
+    let (status, landed_slot) = match phase {
+        0 => ("pending".to_string(), None),
+        1 => ("landed".to_string(), Some(200_000_000 + fp % 1_000_000)),
+        _ => ("finalized".to_string(), Some(200_000_000 + fp % 1_000_000)),
+    };
+
+    BundleStatusResult {
+        bundle_id: bundle_id.to_string(),
+        status,
+        landed_slot,
+        transactions: Vec::new(),
+    }
+}
+
 pub struct JitoService {
     config: JitoConfig,
     client: Option<JitoRpcClient>,
@@ -103,15 +194,69 @@ impl JitoService {
         }
     }
 
+    /// Retry a transient Jito RPC call with capped exponential backoff and full jitter.
+    ///
+    /// Each attempt is bounded by `JitoConfig.timeout_ms`; up to `JitoConfig.max_retries`
+    /// retries are made after the first attempt. `ApiError::InvalidInput` (malformed
+    /// base64/bincode) is never retried since retrying cannot change the outcome.
+    async fn with_retry<T, F, Fut>(&self, mut op: F) -> Result<T, ApiError>
+    where
+        F: FnMut() -> Fut,
+        Fut: Future<Output = Result<T, ApiError>>,
+    {
+        let per_attempt_timeout = Duration::from_millis(self.config.timeout_ms);
+        let cap_ms = self.config.timeout_ms.max(1);
+        let mut attempt = 0u32;
+
+        loop {
+            let outcome = match tokio::time::timeout(per_attempt_timeout, op()).await {
+                Ok(result) => result,
+                Err(_) => Err(ApiError::External("Jito RPC call timed out".to_string())),
+            };
+
+            let err = match outcome {
+                Ok(value) => return Ok(value),
+                Err(e @ ApiError::InvalidInput(_)) => return Err(e),
+                Err(e) => e,
+            };
+
+            if attempt >= self.config.max_retries {
+                return Err(err);
+            }
+
+            let delay_ms = RETRY_BASE_DELAY_MS
+                .saturating_mul(1u64 << attempt.min(16))
+                .min(cap_ms);
+            let jittered_ms = rand::thread_rng().gen_range(0..=delay_ms.max(1));
+            tokio::time::sleep(Duration::from_millis(jittered_ms)).await;
+            attempt += 1;
+        }
+    }
+
     pub async fn initialize(&mut self) -> Result<(), ApiError> {
+        // In mock mode we never reach a real block engine, so there is nothing to connect to.
+        if self.config.mock {
+            return Ok(());
+        }
+
         let client = JitoRpcClient::new(&self.config.block_engine_url)
             .map_err(|e| ApiError::External(format!("Failed to initialize Jito client: {}", e)))?;
-        
+
         self.client = Some(client);
         Ok(())
     }
 
     pub async fn get_tip_accounts(&self) -> Result<TipAccountsResult, ApiError> {
+        if self.config.mock {
+            return Ok(TipAccountsResult {
+                tip_accounts: (0..8)
+                    .map(|i| format!("MockTipAccount{i}1111111111111111111111111"))
+                    .collect(),
+                current_tip_lamports: 500,
+                recommended_tip_lamports: self.config.tip_amount.unwrap_or(1000),
+            });
+        }
+
         let client = self.client.as_ref()
             .ok_or_else(|| ApiError::Configuration("Jito client not initialized".to_string()))?;
 
@@ -128,28 +273,59 @@ impl JitoService {
     }
 
     pub async fn send_bundle(&self, request: BundleRequest) -> Result<BundleResult, ApiError> {
-        let client = self.client.as_ref()
-            .ok_or_else(|| ApiError::Configuration("Jito client not initialized".to_string()))?;
-
-        // Decode transactions from base64
-        let mut transactions = Vec::new();
+        // Validate the transactions are at least well-formed base64/bincode in mock
+        // mode too, so callers can still exercise their error handling offline.
+        let mut decoded_tx_bytes = Vec::with_capacity(request.transactions.len());
         for tx_data in &request.transactions {
             let tx_bytes = base64::decode(tx_data)
                 .map_err(|e| ApiError::InvalidInput(format!("Invalid transaction encoding: {}", e)))?;
-            
-            let transaction: Transaction = bincode::deserialize(&tx_bytes)
+            bincode::deserialize::<Transaction>(&tx_bytes)
                 .map_err(|e| ApiError::InvalidInput(format!("Invalid transaction format: {}", e)))?;
-            
-            transactions.push(transaction);
+            decoded_tx_bytes.push(tx_bytes);
         }
 
         let tip_amount = request.tip_amount.unwrap_or(self.config.tip_amount.unwrap_or(1000));
-        
+
+        if self.config.mock {
+            let mut seed = Vec::new();
+            for bytes in &decoded_tx_bytes {
+                seed.extend_from_slice(bytes);
+            }
+            let transaction_results: Vec<TransactionResult> = decoded_tx_bytes
+                .iter()
+                .map(|bytes| TransactionResult {
+                    signature: format!("mock-sig-{:016x}", mock_fingerprint(bytes)),
+                    status: "pending".to_string(),
+                    slot: None,
+                    confirmation_status: None,
+                    error: None,
+                })
+                .collect();
+
+            return Ok(BundleResult {
+                bundle_id: format!("mock-bundle-{:016x}", mock_fingerprint(&seed)),
+                status: "submitted".to_string(),
+                transactions: transaction_results,
+                tip_amount,
+                submitted_at: chrono::Utc::now().to_rfc3339(),
+            });
+        }
+
+        let client = self.client.as_ref()
+            .ok_or_else(|| ApiError::Configuration("Jito client not initialized".to_string()))?;
+
+        let transactions: Vec<Transaction> = decoded_tx_bytes
+            .iter()
+            .map(|bytes| bincode::deserialize(bytes).expect("validated above"))
+            .collect();
+
         let bundle = Bundle::new(transactions, tip_amount);
         
-        let bundle_id = client.send_bundle(&bundle)
-            .await
-            .map_err(|e| ApiError::External(format!("Failed to send bundle: {}", e)))?;
+        let bundle_id = self.with_retry(|| async {
+            client.send_bundle(&bundle)
+                .await
+                .map_err(|e| ApiError::External(format!("Failed to send bundle: {}", e)))
+        }).await?;
 
         // Create transaction results
         let transaction_results: Vec<TransactionResult> = request.transactions
@@ -173,7 +349,131 @@ impl JitoService {
         })
     }
 
+    /// Build (but not sign or submit) a Jupiter v6 swap plus a tipped transfer to a random
+    /// Jito tip account.
+    ///
+    /// Fetches the best route from the Jupiter quote API, requests the serialized swap
+    /// transaction, and builds a tip transfer payable by `intent.user_pubkey`. The server
+    /// holds no private key for an arbitrary caller-supplied pubkey, so both transactions
+    /// are returned unsigned for the caller to sign themselves and resubmit through
+    /// `send_bundle`/`POST /jito/bundles`.
+    pub async fn build_unsigned_swap_bundle(
+        &self,
+        intent: SwapIntent,
+    ) -> Result<UnsignedSwapBundle, ApiError> {
+        let client = self.client.as_ref()
+            .ok_or_else(|| ApiError::Configuration("Jito client not initialized".to_string()))?;
+
+        let http = reqwest::Client::new();
+
+        let quote: JupiterQuoteResponse = http
+            .get(JUPITER_QUOTE_URL)
+            .query(&[
+                ("inputMint", intent.input_mint.as_str()),
+                ("outputMint", intent.output_mint.as_str()),
+                ("amount", &intent.amount.to_string()),
+                ("slippageBps", &intent.slippage_bps.to_string()),
+            ])
+            .send()
+            .await
+            .map_err(|e| ApiError::External(format!("Jupiter quote request failed: {}", e)))?
+            .json()
+            .await
+            .map_err(|e| ApiError::External(format!("Invalid Jupiter quote response: {}", e)))?;
+
+        let swap_request = JupiterSwapRequest {
+            quote_response: &quote.raw,
+            user_public_key: intent.user_pubkey.clone(),
+            wrap_and_unwrap_sol: true,
+        };
+
+        let swap: JupiterSwapResponse = http
+            .post(JUPITER_SWAP_URL)
+            .json(&swap_request)
+            .send()
+            .await
+            .map_err(|e| ApiError::External(format!("Jupiter swap request failed: {}", e)))?
+            .json()
+            .await
+            .map_err(|e| ApiError::External(format!("Invalid Jupiter swap response: {}", e)))?;
+
+        let tx_bytes = base64::decode(&swap.swap_transaction)
+            .map_err(|e| ApiError::InvalidInput(format!("Invalid swap transaction encoding: {}", e)))?;
+
+        let swap_tx: VersionedTransaction = bincode::deserialize(&tx_bytes)
+            .map_err(|e| ApiError::InvalidInput(format!("Invalid swap transaction format: {}", e)))?;
+
+        let tip_amount = intent.tip_amount.unwrap_or(self.config.tip_amount.unwrap_or(1000));
+
+        let tip_accounts = client.get_tip_accounts()
+            .await
+            .map_err(|e| ApiError::External(format!("Failed to get tip accounts: {}", e)))?;
+        let tip_account = tip_accounts
+            .choose(&mut rand::thread_rng())
+            .ok_or_else(|| ApiError::External("No Jito tip accounts available".to_string()))?;
+
+        let user_pubkey: Pubkey = intent.user_pubkey.parse()
+            .map_err(|e| ApiError::InvalidInput(format!("Invalid user pubkey: {}", e)))?;
+        let tip_ix = system_instruction::transfer(&user_pubkey, tip_account, tip_amount);
+        let tip_tx = Transaction::new_with_payer(&[tip_ix], Some(&user_pubkey));
+
+        let swap_tx_bytes = bincode::serialize(&swap_tx)
+            .map_err(|e| ApiError::InternalError(format!("Failed to re-encode swap transaction: {}", e)))?;
+        let tip_tx_bytes = bincode::serialize(&tip_tx)
+            .map_err(|e| ApiError::InternalError(format!("Failed to encode tip transaction: {}", e)))?;
+
+        Ok(UnsignedSwapBundle {
+            swap_transaction: base64::encode(swap_tx_bytes),
+            tip_transaction: base64::encode(tip_tx_bytes),
+            tip_amount,
+        })
+    }
+
+    /// Subscribe to push-based status updates for a set of bundles.
+    ///
+    /// Internally polls `get_inflight_bundle_statuses` on an interval and only
+    /// emits an item when a bundle's status actually changes, so callers get the
+    /// same pending -> landed(slot) -> finalized/failed progression an `eth_subscribe`-style
+    /// RPC stream would give them without having to poll themselves. The stream
+    /// completes once every bundle has reached a terminal state.
+    pub fn subscribe_bundle_statuses(
+        self: std::sync::Arc<Self>,
+        bundle_ids: Vec<String>,
+    ) -> impl futures::Stream<Item = BundleStatusResult> {
+        async_stream::stream! {
+            let mut pending: std::collections::HashSet<String> = bundle_ids.into_iter().collect();
+            let mut last_status: HashMap<String, String> = HashMap::new();
+
+            while !pending.is_empty() {
+                tokio::time::sleep(Duration::from_secs(2)).await;
+
+                let ids: Vec<String> = pending.iter().cloned().collect();
+                let results = match self.get_inflight_bundle_statuses(ids).await {
+                    Ok(results) => results,
+                    Err(_) => continue, // transient fetch failure; keep polling until terminal
+                };
+
+                for result in results {
+                    let changed = last_status.get(&result.bundle_id) != Some(&result.status);
+                    if !changed {
+                        continue;
+                    }
+
+                    last_status.insert(result.bundle_id.clone(), result.status.clone());
+                    if result.status != "Pending" {
+                        pending.remove(&result.bundle_id);
+                    }
+                    yield result;
+                }
+            }
+        }
+    }
+
     pub async fn get_bundle_statuses(&self, bundle_ids: Vec<String>) -> Result<Vec<BundleStatusResult>, ApiError> {
+        if self.config.mock {
+            return Ok(bundle_ids.into_iter().map(|id| mock_bundle_status(&id)).collect());
+        }
+
         let client = self.client.as_ref()
             .ok_or_else(|| ApiError::Configuration("Jito client not initialized".to_string()))?;
 
@@ -183,7 +483,13 @@ impl JitoService {
             let bundle_uuid = bundle_id.parse()
                 .map_err(|e| ApiError::InvalidInput(format!("Invalid bundle ID format: {}", e)))?;
 
-            match client.get_bundle_status(&bundle_uuid).await {
+            let status_result = self.with_retry(|| async {
+                client.get_bundle_status(&bundle_uuid)
+                    .await
+                    .map_err(|e| ApiError::External(format!("Failed to get bundle status: {}", e)))
+            }).await;
+
+            match status_result {
                 Ok(status) => {
                     results.push(BundleStatusResult {
                         bundle_id: bundle_id.clone(),
@@ -212,6 +518,10 @@ impl JitoService {
     }
 
     pub async fn get_inflight_bundle_statuses(&self, bundle_ids: Vec<String>) -> Result<Vec<BundleStatusResult>, ApiError> {
+        if self.config.mock {
+            return Ok(bundle_ids.into_iter().map(|id| mock_bundle_status(&id)).collect());
+        }
+
         let client = self.client.as_ref()
             .ok_or_else(|| ApiError::Configuration("Jito client not initialized".to_string()))?;
 
@@ -223,9 +533,11 @@ impl JitoService {
         let bundle_uuids = bundle_uuids
             .map_err(|e| ApiError::InvalidInput(format!("Invalid bundle ID format: {}", e)))?;
 
-        let statuses = client.get_inflight_bundle_statuses(&bundle_uuids)
-            .await
-            .map_err(|e| ApiError::External(format!("Failed to get inflight bundle statuses: {}", e)))?;
+        let statuses = self.with_retry(|| async {
+            client.get_inflight_bundle_statuses(&bundle_uuids)
+                .await
+                .map_err(|e| ApiError::External(format!("Failed to get inflight bundle statuses: {}", e)))
+        }).await?;
 
         let results: Vec<BundleStatusResult> = bundle_ids
             .into_iter()
@@ -242,19 +554,31 @@ impl JitoService {
     }
 
     pub async fn send_transaction(&self, request: TransactionRequest) -> Result<TransactionResult, ApiError> {
-        let client = self.client.as_ref()
-            .ok_or_else(|| ApiError::Configuration("Jito client not initialized".to_string()))?;
-
         // Decode transaction from base64
         let tx_bytes = base64::decode(&request.transaction)
             .map_err(|e| ApiError::InvalidInput(format!("Invalid transaction encoding: {}", e)))?;
-        
+
         let transaction: Transaction = bincode::deserialize(&tx_bytes)
             .map_err(|e| ApiError::InvalidInput(format!("Invalid transaction format: {}", e)))?;
 
-        let signature = client.send_transaction(&transaction)
-            .await
-            .map_err(|e| ApiError::External(format!("Failed to send transaction: {}", e)))?;
+        if self.config.mock {
+            return Ok(TransactionResult {
+                signature: format!("mock-sig-{:016x}", mock_fingerprint(&tx_bytes)),
+                status: "submitted".to_string(),
+                slot: None,
+                confirmation_status: Some("processed".to_string()),
+                error: None,
+            });
+        }
+
+        let client = self.client.as_ref()
+            .ok_or_else(|| ApiError::Configuration("Jito client not initialized".to_string()))?;
+
+        let signature = self.with_retry(|| async {
+            client.send_transaction(&transaction)
+                .await
+                .map_err(|e| ApiError::External(format!("Failed to send transaction: {}", e)))
+        }).await?;
 
         Ok(TransactionResult {
             signature: signature.to_string(),
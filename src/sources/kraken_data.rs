@@ -3,6 +3,13 @@ use serde::{Deserialize, Serialize};
 use serde_json::Value;
 use reqwest;
 
+use crate::errors::ApiError;
+
+/// Per-call timeout for the Kraken REST calls instrumented via [`crate::metrics::observe`],
+/// matching [`crate::helpers::resilient_fetch::ResilientFetch`]'s 10s default per-fetcher
+/// timeout.
+const KRAKEN_CALL_TIMEOUT: std::time::Duration = std::time::Duration::from_secs(10);
+
 #[derive(Debug, Serialize, Deserialize, Clone, utoipa::ToSchema)]
 pub struct KrakenTicker {
     pub pair: String,
@@ -12,8 +19,28 @@ pub struct KrakenTicker {
     pub low_24h: f64,
     pub change_24h: f64,
     pub change_pct_24h: f64,
+    pub bid: f64,
+    pub ask: f64,
+    pub vwap: f64,
+    pub trade_count: u64,
+}
+
+/// A synthetic bid/ask derived from a Kraken ticker's last price by applying a configured
+/// markup, mirroring how a market maker quotes a margin over a reference price. `spread_pct`
+/// is echoed back so downstream consumers can audit the markup they were quoted.
+#[derive(Debug, Serialize, Deserialize, Clone, utoipa::ToSchema)]
+pub struct KrakenQuote {
+    pub pair: String,
+    pub bid: f64,
+    pub ask: f64,
+    pub mid: f64,
+    pub spread_pct: f64,
 }
 
+/// Default symmetric spread applied by [`KrakenDataSource::get_quote`] when none is configured
+/// at construction.
+const DEFAULT_SPREAD_PCT: f64 = 0.02;
+
 #[derive(Debug, Serialize, Deserialize, utoipa::ToSchema)]
 pub struct KrakenOrderBook {
     pub pair: String,
@@ -107,24 +134,58 @@ struct OHLCResult {
 
 pub struct KrakenDataSource {
     http_client: reqwest::Client,
+    spread_pct: f64,
 }
 
 impl KrakenDataSource {
     pub async fn new_async() -> Result<Self, Box<dyn std::error::Error + Send + Sync>> {
+        Self::with_spread_pct(DEFAULT_SPREAD_PCT).await
+    }
+
+    /// Like [`Self::new_async`], but applies `spread_pct` (e.g. `0.02` for a symmetric 2%
+    /// markup) to every [`Self::get_quote`] call instead of [`DEFAULT_SPREAD_PCT`], so all
+    /// quotes from this instance share one consistent configuration.
+    pub async fn with_spread_pct(spread_pct: f64) -> Result<Self, Box<dyn std::error::Error + Send + Sync>> {
         // Create the data source in a blocking task to avoid runtime issues
-        tokio::task::spawn_blocking(|| {
+        tokio::task::spawn_blocking(move || {
             let http_client = reqwest::Client::new();
-            
-            Ok::<Self, Box<dyn std::error::Error + Send + Sync>>(Self { http_client })
+
+            Ok::<Self, Box<dyn std::error::Error + Send + Sync>>(Self { http_client, spread_pct })
         })
         .await
         .map_err(|e| format!("Task join error: {e}"))?
     }
 
+    /// Derives a [`KrakenQuote`] from `pair`'s last price, applying this instance's configured
+    /// `spread_pct` symmetrically around the mid price.
+    pub async fn get_quote(&self, pair: &str) -> Result<KrakenQuote, Box<dyn std::error::Error + Send + Sync>> {
+        let tickers = self.get_tickers_async(vec![pair.to_string()]).await?;
+        let mid = tickers
+            .into_iter()
+            .next()
+            .ok_or_else(|| format!("no ticker data for pair {pair}"))?
+            .price;
+
+        let half_spread = mid * self.spread_pct / 2.0;
+        Ok(KrakenQuote {
+            pair: pair.to_string(),
+            bid: mid - half_spread,
+            ask: mid + half_spread,
+            mid,
+            spread_pct: self.spread_pct,
+        })
+    }
+
 
 
-    /// Get ticker information for specified pairs
+    /// Get ticker information for specified pairs. Latency/outcome (ok/timeout/error) is
+    /// recorded against the `"kraken"` source via [`crate::metrics::observe`], the same
+    /// instrumentation `helpers::news`'s `ResilientFetch` pipeline uses.
     pub async fn get_tickers_async(&self, pairs: Vec<String>) -> Result<Vec<KrakenTicker>, Box<dyn std::error::Error + Send + Sync>> {
+        crate::metrics::observe("kraken", KRAKEN_CALL_TIMEOUT, self.get_tickers_async_inner(pairs)).await
+    }
+
+    async fn get_tickers_async_inner(&self, pairs: Vec<String>) -> Result<Vec<KrakenTicker>, Box<dyn std::error::Error + Send + Sync>> {
         // If no pairs specified, use default popular pairs
         let pairs_to_fetch = if pairs.is_empty() {
             vec![
@@ -166,25 +227,42 @@ impl KrakenDataSource {
         let mut tickers = Vec::new();
 
         for (pair_name, ticker_data) in ticker_response {
-            // Extract data from the ticker response
+            // Extract data from the ticker response. Kraken's REST ticker arrays are
+            // [todayValue, last24HoursValue] for `v`/`h`/`l`, so the 24h figure is at index 1;
+            // `c`/`b`/`a` are [price, lotVolume] triples, so the price is at index 0; `o` is a
+            // single opening-price string, not an array.
             let current_price = ticker_data.c.first()
                 .and_then(|s| s.parse::<f64>().ok())
                 .unwrap_or(0.0);
-            
-            let volume_24h = ticker_data.a.get(1)
+
+            let bid = ticker_data.b.first()
+                .and_then(|s| s.parse::<f64>().ok())
+                .unwrap_or(0.0);
+
+            let ask = ticker_data.a.first()
+                .and_then(|s| s.parse::<f64>().ok())
+                .unwrap_or(0.0);
+
+            let vwap = ticker_data.p.get(1)
+                .and_then(|s| s.parse::<f64>().ok())
+                .unwrap_or(0.0);
+
+            let trade_count = ticker_data.t.get(1).copied().unwrap_or(0);
+
+            let volume_24h = ticker_data.v.get(1)
                 .and_then(|s| s.parse::<f64>().ok())
                 .unwrap_or(0.0);
-            
-            let high_24h = ticker_data.a.get(1)
+
+            let high_24h = ticker_data.h.get(1)
                 .and_then(|s| s.parse::<f64>().ok())
                 .unwrap_or(0.0);
-            
-            let low_24h = ticker_data.a.get(1)
+
+            let low_24h = ticker_data.l.get(1)
                 .and_then(|s| s.parse::<f64>().ok())
                 .unwrap_or(0.0);
-            
-            let open_price = ticker_data.a.first().unwrap_or(&"0".to_string()).parse::<f64>().unwrap_or(0.0);
-            
+
+            let open_price = ticker_data.o.parse::<f64>().unwrap_or(0.0);
+
             let change_24h = current_price - open_price;
             let change_pct_24h = if open_price > 0.0 {
                 (change_24h / open_price) * 100.0
@@ -200,6 +278,10 @@ impl KrakenDataSource {
                 low_24h,
                 change_24h,
                 change_pct_24h,
+                bid,
+                ask,
+                vwap,
+                trade_count,
             };
             tickers.push(ticker);
         }
@@ -207,8 +289,13 @@ impl KrakenDataSource {
         Ok(tickers)
     }
 
-    /// Get order book for a specific pair
+    /// Get order book for a specific pair. Instrumented the same way as
+    /// [`Self::get_tickers_async`]; see its doc comment.
     pub async fn get_order_book(&self, pair: &str, depth: u32) -> Result<KrakenOrderBook, Box<dyn std::error::Error + Send + Sync>> {
+        crate::metrics::observe("kraken", KRAKEN_CALL_TIMEOUT, self.get_order_book_inner(pair, depth)).await
+    }
+
+    async fn get_order_book_inner(&self, pair: &str, depth: u32) -> Result<KrakenOrderBook, Box<dyn std::error::Error + Send + Sync>> {
         // Convert pair format from "XBT/USD" to "XBTUSD" for Kraken API
         let kraken_pair = pair.replace("/", "");
         
@@ -340,8 +427,13 @@ impl KrakenDataSource {
         Ok(assets)
     }
 
-    /// Get recent trades for a pair
+    /// Get recent trades for a pair. Instrumented the same way as [`Self::get_tickers_async`];
+    /// see its doc comment.
     pub async fn get_recent_trades(&self, pair: &str, since: Option<u64>) -> Result<Value, Box<dyn std::error::Error + Send + Sync>> {
+        crate::metrics::observe("kraken", KRAKEN_CALL_TIMEOUT, self.get_recent_trades_inner(pair, since)).await
+    }
+
+    async fn get_recent_trades_inner(&self, pair: &str, since: Option<u64>) -> Result<Value, Box<dyn std::error::Error + Send + Sync>> {
         let kraken_pair = pair.replace("/", "");
         
         // Use async HTTP request directly
@@ -386,8 +478,13 @@ impl KrakenDataSource {
         Ok(trades_json)
     }
 
-    /// Get OHLC data for a pair
+    /// Get OHLC data for a pair. Instrumented the same way as [`Self::get_tickers_async`]; see
+    /// its doc comment.
     pub async fn get_ohlc(&self, pair: &str, interval: Option<u32>, since: Option<u64>) -> Result<Value, Box<dyn std::error::Error + Send + Sync>> {
+        crate::metrics::observe("kraken", KRAKEN_CALL_TIMEOUT, self.get_ohlc_inner(pair, interval, since)).await
+    }
+
+    async fn get_ohlc_inner(&self, pair: &str, interval: Option<u32>, since: Option<u64>) -> Result<Value, Box<dyn std::error::Error + Send + Sync>> {
         let kraken_pair = pair.replace("/", "");
         
         // Use async HTTP request directly
@@ -466,15 +563,23 @@ impl KrakenDataSource {
     }
 }
 
+use crate::services::rate_provider::RateProvider;
 use crate::types::TrendingItem;
 
-/// Async function to get trending crypto pairs from Kraken
-pub async fn get_trending_crypto_pairs(limit: usize) -> Result<Vec<TrendingItem>, Box<dyn std::error::Error + Send + Sync>> {
+/// Get trending crypto pairs, ranked by 24h volume, from `provider`. Pair discovery (which
+/// asset pairs count as "crypto") is still Kraken's own asset-pair listing -- no other
+/// provider in this crate exposes one yet -- but the actual ticker fetch goes through
+/// `provider` so a second exchange can be compared or swapped in without touching this
+/// function's ranking logic.
+pub async fn get_trending_crypto_pairs(
+    provider: &dyn RateProvider,
+    limit: usize,
+) -> Result<Vec<TrendingItem>, Box<dyn std::error::Error + Send + Sync>> {
     let data_source = KrakenDataSource::new_async().await?;
-    
+
     // Get all asset pairs using async version
     let asset_pairs = data_source.get_asset_pairs_async().await?;
-    
+
     // Filter for crypto pairs (common crypto quote currencies)
     let crypto_quote_currencies = ["USD", "USDT", "EUR", "BTC", "ETH"];
     let crypto_pairs: Vec<String> = asset_pairs
@@ -486,35 +591,34 @@ pub async fn get_trending_crypto_pairs(limit: usize) -> Result<Vec<TrendingItem>
         .collect();
 
     // Get ticker data for crypto pairs
-    let tickers = data_source.get_tickers_async(crypto_pairs).await?;
-        
+    let mut tickers = provider.tickers(crypto_pairs).await?;
+
     // Sort by volume and return top pairs
-    let mut sorted_tickers: Vec<KrakenTicker> = tickers.into_iter().collect();
-    sorted_tickers.sort_by(|a, b| b.volume.partial_cmp(&a.volume).unwrap_or(std::cmp::Ordering::Equal));
-    
-    let trending_items: Vec<TrendingItem> = sorted_tickers
+    tickers.sort_by(|a, b| b.volume.partial_cmp(&a.volume).unwrap_or(std::cmp::Ordering::Equal));
+
+    let trending_items: Vec<TrendingItem> = tickers
         .into_iter()
         .take(limit)
         .map(|ticker| {
             // Extract base symbol from pair (e.g., "XBTUSD" -> "XBT")
             let base_symbol = if ticker.pair.len() > 3 {
-                &ticker.pair[..ticker.pair.len() - 3]
+                ticker.pair[..ticker.pair.len() - 3].to_string()
             } else {
-                &ticker.pair
+                ticker.pair.clone()
             };
-            
+
             TrendingItem {
-                id: ticker.pair.clone(),
-                symbol: base_symbol.to_string(),
-                name: base_symbol.to_string(), // Kraken doesn't provide full names in ticker data
+                id: ticker.pair,
+                symbol: base_symbol.clone(),
+                name: base_symbol, // exchange tickers don't provide full names
                 price: Some(ticker.price),
-                price_change_24h: Some(ticker.change_24h),
-                price_change_percentage_24h: Some(ticker.change_pct_24h),
+                price_change_24h: ticker.change_24h,
+                price_change_percentage_24h: ticker.change_pct_24h,
                 volume: Some(ticker.volume),
-                market_cap: None, // Kraken ticker doesn't provide market cap
+                market_cap: None, // exchange tickers don't provide market cap
                 market_cap_rank: None,
                 score: Some(ticker.volume), // Use volume as score for ranking
-                source: "kraken".to_string(),
+                source: provider.provider_name().to_string(),
                 image_url: None,
                 last_updated: None,
             }
@@ -524,30 +628,599 @@ pub async fn get_trending_crypto_pairs(limit: usize) -> Result<Vec<TrendingItem>
     Ok(trending_items)
 }
 
-/// Get market data summary for a specific pair
-pub async fn get_market_summary(pair: &str) -> Result<Value, Box<dyn std::error::Error + Send + Sync>> {
-    let data_source = KrakenDataSource::new_async().await?;
-    
+/// Get market data summary for a specific pair. Ticker and order-book data come from
+/// `provider`; recent trades have no `RateProvider` equivalent yet, so that piece still goes
+/// directly to Kraken.
+pub async fn get_market_summary(provider: &dyn RateProvider, pair: &str) -> Result<Value, Box<dyn std::error::Error + Send + Sync>> {
     let mut summary = serde_json::Map::new();
-    
-    // Get ticker data
-    if let Ok(tickers) = data_source.get_tickers_async(vec![pair.to_string()]).await {
-            if let Some(ticker) = tickers.first() {
-                summary.insert("ticker".to_string(), serde_json::to_value(ticker)?);
+
+    if let Ok(tickers) = provider.tickers(vec![pair.to_string()]).await {
+        if let Some(ticker) = tickers.first() {
+            summary.insert("ticker".to_string(), serde_json::json!({
+                "pair": ticker.pair,
+                "price": ticker.price,
+                "bid": ticker.bid,
+                "ask": ticker.ask,
+                "volume": ticker.volume,
+                "high_24h": ticker.high_24h,
+                "low_24h": ticker.low_24h,
+                "change_24h": ticker.change_24h,
+                "change_pct_24h": ticker.change_pct_24h,
+            }));
+        }
+    }
+
+    if let Ok(order_book) = provider.order_book(pair, 10).await {
+        summary.insert("order_book".to_string(), serde_json::json!({
+            "pair": order_book.pair,
+            "bids": order_book.bids,
+            "asks": order_book.asks,
+        }));
+    }
+
+    let data_source = KrakenDataSource::new_async().await?;
+    if let Ok(trades) = data_source.get_recent_trades(pair, None).await {
+        summary.insert("recent_trades".to_string(), trades);
+    }
+
+    Ok(Value::Object(summary))
+}
+
+/// Normalizes [`KrakenDataSource`] behind [`crate::services::rate_provider::RateProvider`] so
+/// the trending/market-summary pipeline can accept `&dyn RateProvider` instead of being pinned
+/// to Kraken.
+#[async_trait::async_trait]
+impl crate::services::rate_provider::RateProvider for KrakenDataSource {
+    fn provider_name(&self) -> &'static str {
+        "kraken"
+    }
+
+    async fn tickers(&self, pairs: Vec<String>) -> Result<Vec<crate::services::rate_provider::NormalizedTicker>, ApiError> {
+        let tickers = self.get_tickers_async(pairs).await.map_err(|e| ApiError::Upstream(e.to_string()))?;
+        Ok(tickers
+            .into_iter()
+            .map(|t| crate::services::rate_provider::NormalizedTicker {
+                pair: t.pair,
+                price: t.price,
+                bid: Some(t.bid),
+                ask: Some(t.ask),
+                volume: t.volume,
+                high_24h: Some(t.high_24h),
+                low_24h: Some(t.low_24h),
+                change_24h: Some(t.change_24h),
+                change_pct_24h: Some(t.change_pct_24h),
+            })
+            .collect())
+    }
+
+    async fn order_book(&self, pair: &str, depth: u32) -> Result<crate::services::rate_provider::NormalizedOrderBook, ApiError> {
+        let order_book = self.get_order_book(pair, depth).await.map_err(|e| ApiError::Upstream(e.to_string()))?;
+        Ok(crate::services::rate_provider::NormalizedOrderBook {
+            pair: order_book.pair,
+            bids: order_book.bids,
+            asks: order_book.asks,
+        })
+    }
+}
+
+/// Kraken's legacy public WebSocket v1 endpoint (see <https://docs.kraken.com/websockets/>),
+/// used by [`KrakenDataSource::subscribe_rates`]'s push-based ticker feed.
+const KRAKEN_WS_V1_URL: &str = "wss://ws.kraken.com";
+
+/// An error pushed into a [`KrakenDataSource::subscribe_rates`] watch channel when the
+/// underlying WebSocket connection fails or drops; the stream reconnects with backoff
+/// regardless, so receivers just see an `Err` value until the next successful update.
+#[derive(Debug, Clone)]
+pub enum KrakenStreamError {
+    ConnectFailed(String),
+    Disconnected,
+}
+
+impl std::fmt::Display for KrakenStreamError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            KrakenStreamError::ConnectFailed(e) => write!(f, "kraken ws connect failed: {e}"),
+            KrakenStreamError::Disconnected => write!(f, "kraken ws disconnected"),
+        }
+    }
+}
+
+impl std::error::Error for KrakenStreamError {}
+
+/// Reads a numeric array field (e.g. `"c": ["1234.5", "0.001"]`) at `idx` and parses it as `f64`.
+fn v1_field_at(payload: &serde_json::Map<String, Value>, key: &str, idx: usize) -> Option<f64> {
+    payload.get(key)?.as_array()?.get(idx)?.as_str()?.parse().ok()
+}
+
+/// Parses one of Kraken's v1 `ticker` channel frames -- a JSON array
+/// `[channelID, {"a":[...],"b":[...],"c":[...],"v":[...],"h":[...],"l":[...]}, "ticker", pair]`
+/// -- into a [`KrakenTicker`]. Returns `None` for anything else on the socket (the initial
+/// `systemStatus`/`subscriptionStatus` objects, `heartbeat` events, or a shape we don't expect).
+fn parse_v1_ticker_frame(text: &str) -> Option<KrakenTicker> {
+    let value: Value = serde_json::from_str(text).ok()?;
+    let frame = value.as_array()?;
+    if frame.len() < 4 || frame[2].as_str() != Some("ticker") {
+        return None;
+    }
+    let payload = frame[1].as_object()?;
+    let pair = frame[3].as_str()?.to_string();
+
+    Some(KrakenTicker {
+        pair,
+        price: v1_field_at(payload, "c", 0)?,
+        volume: v1_field_at(payload, "v", 1).unwrap_or(0.0),
+        high_24h: v1_field_at(payload, "h", 1).unwrap_or(0.0),
+        low_24h: v1_field_at(payload, "l", 1).unwrap_or(0.0),
+        change_24h: 0.0,
+        change_pct_24h: 0.0,
+        bid: v1_field_at(payload, "b", 0).unwrap_or(0.0),
+        ask: v1_field_at(payload, "a", 0).unwrap_or(0.0),
+        vwap: v1_field_at(payload, "p", 1).unwrap_or(0.0),
+        trade_count: 0,
+    })
+}
+
+impl KrakenDataSource {
+    /// Streams live ticker updates for `pairs` over Kraken's v1 WebSocket `ticker` channel,
+    /// pushing each parsed [`KrakenTicker`] (or a [`KrakenStreamError`] on disconnect) into a
+    /// `watch` channel so consumers get updates pushed to them instead of polling
+    /// [`KrakenDataSource::get_tickers_async`]. Reconnects with exponential backoff for as long
+    /// as at least one receiver is still listening.
+    pub fn subscribe_rates(
+        &self,
+        pairs: Vec<String>,
+    ) -> tokio::sync::watch::Receiver<Result<KrakenTicker, KrakenStreamError>> {
+        let (tx, rx) = tokio::sync::watch::channel(Err(KrakenStreamError::Disconnected));
+        tokio::spawn(Self::run_rate_stream(pairs, tx));
+        rx
+    }
+
+    async fn run_rate_stream(
+        pairs: Vec<String>,
+        tx: tokio::sync::watch::Sender<Result<KrakenTicker, KrakenStreamError>>,
+    ) {
+        use futures::{SinkExt, StreamExt};
+
+        let mut attempt = 0u32;
+        while tx.receiver_count() > 0 {
+            match tokio_tungstenite::connect_async(KRAKEN_WS_V1_URL).await {
+                Ok((mut ws, _)) => {
+                    attempt = 0;
+                    let subscribe = serde_json::json!({
+                        "event": "subscribe",
+                        "pair": pairs,
+                        "subscription": { "name": "ticker" }
+                    });
+                    if let Err(e) = ws.send(tokio_tungstenite::tungstenite::Message::Text(subscribe.to_string().into())).await {
+                        tracing::warn!("kraken rate stream: subscribe failed: {e}");
+                        let _ = tx.send(Err(KrakenStreamError::ConnectFailed(e.to_string())));
+                    } else {
+                        while tx.receiver_count() > 0 {
+                            match ws.next().await {
+                                Some(Ok(tokio_tungstenite::tungstenite::Message::Text(text))) => {
+                                    if let Some(ticker) = parse_v1_ticker_frame(&text) {
+                                        let _ = tx.send(Ok(ticker));
+                                    }
+                                    // Ignore systemStatus/subscriptionStatus/heartbeat events.
+                                }
+                                Some(Ok(tokio_tungstenite::tungstenite::Message::Close(_))) | None => {
+                                    let _ = tx.send(Err(KrakenStreamError::Disconnected));
+                                    break;
+                                }
+                                Some(Ok(_)) => continue,
+                                Some(Err(e)) => {
+                                    tracing::warn!("kraken rate stream: connection error: {e}");
+                                    let _ = tx.send(Err(KrakenStreamError::ConnectFailed(e.to_string())));
+                                    break;
+                                }
+                            }
+                        }
+                    }
+                }
+                Err(e) => {
+                    tracing::warn!("kraken rate stream: connect failed: {e}");
+                    let _ = tx.send(Err(KrakenStreamError::ConnectFailed(e.to_string())));
+                }
+            }
+
+            if tx.receiver_count() == 0 {
+                break;
             }
+            let exp_ms = 1_000u64.saturating_mul(1u64 << attempt.min(16)).min(30_000);
+            tokio::time::sleep(std::time::Duration::from_millis(exp_ms)).await;
+            attempt += 1;
         }
-        
-        // Get order book
-        if let Ok(order_book) = data_source.get_order_book(pair, 10).await {
-            summary.insert("order_book".to_string(), serde_json::to_value(order_book)?);
+    }
+}
+
+/// Kraken's public WebSocket v2 endpoint (see <https://docs.kraken.com/api/docs/websocket-v2/ticker>).
+const KRAKEN_WS_URL: &str = "wss://ws.kraken.com/v2";
+
+/// A single ticker update from Kraken's WebSocket v2 `ticker` channel.
+#[derive(Debug, Clone, Deserialize, Serialize, utoipa::ToSchema)]
+pub struct KrakenWsTicker {
+    pub symbol: String,
+    pub bid: f64,
+    pub ask: f64,
+    pub last: f64,
+    pub volume: f64,
+    pub vwap: f64,
+    pub low: f64,
+    pub high: f64,
+    pub change: f64,
+    pub change_pct: f64,
+}
+
+#[derive(Debug, Deserialize)]
+pub(crate) struct KrakenWsTickerFrame {
+    pub(crate) channel: String,
+    pub(crate) data: Vec<KrakenWsTicker>,
+}
+
+/// Kraken's WS v2 socket multiplexes ticker updates with subscribe acks, heartbeats, and
+/// errors on the same connection. Frames this hub cares about (`ticker` channel updates)
+/// deserialize into `Ticker`; everything else passes through as raw JSON so callers still
+/// see it without the hub needing to model every message shape Kraken can send.
+#[derive(Debug, Deserialize)]
+#[serde(untagged)]
+pub(crate) enum KrakenWsMessage {
+    Ticker(KrakenWsTickerFrame),
+    Other(Value),
+}
+
+/// Shares one upstream WebSocket connection per subscribed pair across every SSE client
+/// watching it, fanning ticker updates out over a broadcast channel the same way
+/// [`crate::sources::finviz_data::ScreenerStreamHub`] shares a single poll loop. Unlike the
+/// poll-based hubs elsewhere in this module, this one holds a persistent push connection to
+/// Kraken and reconnects with backoff (per `retry`) instead of polling on an interval.
+pub struct KrakenWsHub {
+    subscriptions: tokio::sync::Mutex<std::collections::HashMap<String, tokio::sync::broadcast::Sender<Value>>>,
+    retry: crate::config::RetryConfig,
+}
+
+impl KrakenWsHub {
+    pub fn new(retry: crate::config::RetryConfig) -> Self {
+        Self { subscriptions: tokio::sync::Mutex::new(std::collections::HashMap::new()), retry }
+    }
+
+    /// Subscribe to live ticker updates for `pair` (e.g. `"BTC/USD"`). Opens the upstream
+    /// connection on the first subscriber for a given pair and reuses it after that.
+    pub fn subscribe(self: std::sync::Arc<Self>, pair: String) -> impl futures::Stream<Item = Value> {
+        async_stream::stream! {
+            let mut rx = {
+                let mut subs = self.subscriptions.lock().await;
+                match subs.get(&pair) {
+                    Some(tx) => tx.subscribe(),
+                    None => {
+                        let (tx, rx) = tokio::sync::broadcast::channel(64);
+                        subs.insert(pair.clone(), tx.clone());
+                        tokio::spawn(self.clone().connect_and_publish(pair.clone(), tx));
+                        rx
+                    }
+                }
+            };
+
+            loop {
+                match rx.recv().await {
+                    Ok(value) => yield value,
+                    Err(tokio::sync::broadcast::error::RecvError::Lagged(_)) => continue,
+                    Err(tokio::sync::broadcast::error::RecvError::Closed) => break,
+                }
+            }
         }
-        
-        // Get recent trades
-        if let Ok(trades) = data_source.get_recent_trades(pair, None).await {
-            summary.insert("recent_trades".to_string(), trades);
+    }
+
+    /// Holds a WebSocket connection to Kraken open for `pair`, forwarding each `ticker`
+    /// update to every subscriber. Reconnects with exponential backoff (capped by `retry`)
+    /// on disconnect or connect failure, and tears itself down once nobody is listening.
+    async fn connect_and_publish(
+        self: std::sync::Arc<Self>,
+        pair: String,
+        tx: tokio::sync::broadcast::Sender<Value>,
+    ) {
+        use futures::{SinkExt, StreamExt};
+
+        let mut attempt = 0u32;
+        while tx.receiver_count() > 0 {
+            match tokio_tungstenite::connect_async(KRAKEN_WS_URL).await {
+                Ok((mut ws, _)) => {
+                    attempt = 0;
+                    let subscribe = serde_json::json!({
+                        "method": "subscribe",
+                        "params": { "channel": "ticker", "symbol": [pair.clone()] }
+                    });
+                    let sent = ws.send(tokio_tungstenite::tungstenite::Message::Text(subscribe.to_string().into())).await;
+                    if let Err(e) = sent {
+                        tracing::warn!("kraken ws: subscribe failed for {pair}: {e}");
+                    } else {
+                        while tx.receiver_count() > 0 {
+                            match ws.next().await {
+                                Some(Ok(tokio_tungstenite::tungstenite::Message::Text(text))) => {
+                                    match serde_json::from_str::<KrakenWsMessage>(&text) {
+                                        Ok(KrakenWsMessage::Ticker(frame)) if frame.channel == "ticker" => {
+                                            if let Ok(value) = serde_json::to_value(&frame.data) {
+                                                let _ = tx.send(value);
+                                            }
+                                        }
+                                        Ok(KrakenWsMessage::Other(_)) | Err(_) => continue,
+                                        Ok(KrakenWsMessage::Ticker(_)) => continue,
+                                    }
+                                }
+                                Some(Ok(_)) => continue,
+                                Some(Err(e)) => {
+                                    tracing::warn!("kraken ws: connection error for {pair}: {e}");
+                                    break;
+                                }
+                                None => break,
+                            }
+                        }
+                    }
+                }
+                Err(e) => {
+                    tracing::warn!("kraken ws: connect failed for {pair}: {e}");
+                }
+            }
+
+            if tx.receiver_count() == 0 {
+                break;
+            }
+            tokio::time::sleep(self.backoff(attempt)).await;
+            attempt += 1;
         }
-        
-        Ok(Value::Object(summary))
+
+        self.subscriptions.lock().await.remove(&pair);
+    }
+
+    /// Exponential backoff derived from the shared `RetryConfig`, capped at `max_delay_ms`.
+    fn backoff(&self, attempt: u32) -> std::time::Duration {
+        let exp_ms = self.retry.base_delay_ms
+            .saturating_mul(1u64 << attempt.min(16))
+            .min(self.retry.max_delay_ms);
+        std::time::Duration::from_millis(exp_ms.max(self.retry.base_delay_ms))
+    }
+}
+
+/// One price level in a live-maintained [`KrakenOrderBookSnapshot`]. `price_str`/`volume_str`
+/// retain the exact decimal formatting Kraken sent them in, since the checksum in
+/// [`book_checksum`] is computed over that formatting (decimal point removed, leading zeros
+/// stripped), not over the parsed `f64`.
+#[derive(Debug, Clone)]
+struct BookLevel {
+    price: f64,
+    price_str: String,
+    volume_str: String,
+}
+
+/// A validated, continuously-updated order book for one pair, kept current by
+/// [`KrakenOrderBookHub`]. `bids` is sorted highest-first, `asks` lowest-first.
+#[derive(Debug, Clone, Serialize, utoipa::ToSchema)]
+pub struct KrakenOrderBookSnapshot {
+    pub pair: String,
+    pub bids: Vec<(f64, f64)>,
+    pub asks: Vec<(f64, f64)>,
+}
+
+/// An error pushed to [`KrakenOrderBookHub`] subscribers when the book can't be trusted: the
+/// connection dropped, or the server's CRC32 checksum no longer matches the locally-maintained
+/// book (in which case the hub discards its state and re-subscribes from a fresh snapshot).
+#[derive(Debug, Clone)]
+pub enum KrakenBookStreamError {
+    ConnectFailed(String),
+    Disconnected,
+    ChecksumMismatch,
+}
+
+impl std::fmt::Display for KrakenBookStreamError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            KrakenBookStreamError::ConnectFailed(e) => write!(f, "kraken book ws connect failed: {e}"),
+            KrakenBookStreamError::Disconnected => write!(f, "kraken book ws disconnected"),
+            KrakenBookStreamError::ChecksumMismatch => write!(f, "kraken book checksum mismatch"),
+        }
+    }
+}
+
+impl std::error::Error for KrakenBookStreamError {}
+
+/// Strips the decimal point and leading zeros from a level's price/volume string, per Kraken's
+/// checksum input format (e.g. `"12340.50000"` -> `"1234050000"`, `"0.00010000"` -> `"10000"`).
+fn checksum_token(s: &str) -> String {
+    let digits: String = s.chars().filter(|c| *c != '.').collect();
+    let trimmed = digits.trim_start_matches('0');
+    if trimmed.is_empty() { "0".to_string() } else { trimmed.to_string() }
+}
+
+/// Computes Kraken's book checksum: the top 10 asks (ascending), then the top 10 bids
+/// (descending), each level contributing its price then volume with [`checksum_token`]
+/// formatting, all concatenated into one ASCII string and CRC32'd.
+fn book_checksum(asks: &[BookLevel], bids: &[BookLevel]) -> u32 {
+    let mut input = String::new();
+    for level in asks.iter().take(10) {
+        input.push_str(&checksum_token(&level.price_str));
+        input.push_str(&checksum_token(&level.volume_str));
+    }
+    for level in bids.iter().take(10) {
+        input.push_str(&checksum_token(&level.price_str));
+        input.push_str(&checksum_token(&level.volume_str));
+    }
+    crc32fast::hash(input.as_bytes())
+}
+
+/// Parses one `[price, volume, time]` triple from a book snapshot/update payload.
+fn parse_book_level(raw: &Value) -> Option<BookLevel> {
+    let arr = raw.as_array()?;
+    let price_str = arr.first()?.as_str()?.to_string();
+    let volume_str = arr.get(1)?.as_str()?.to_string();
+    Some(BookLevel { price: price_str.parse().ok()?, price_str, volume_str })
+}
+
+/// Applies a delta to one side of the book: a level at a price already in `levels` is replaced
+/// (or removed, if its volume is `0`); a level at a new price is inserted. Re-sorts and
+/// truncates to depth 10 afterwards so the vector always reflects Kraken's tracked window.
+fn apply_book_delta(levels: &mut Vec<BookLevel>, deltas: &[Value], ascending: bool) {
+    for raw in deltas {
+        let Some(level) = parse_book_level(raw) else { continue };
+        levels.retain(|l| l.price != level.price);
+        if level.volume_str.parse::<f64>().unwrap_or(0.0) != 0.0 {
+            levels.push(level);
+        }
+    }
+
+    if ascending {
+        levels.sort_by(|a, b| a.price.partial_cmp(&b.price).unwrap_or(std::cmp::Ordering::Equal));
+    } else {
+        levels.sort_by(|a, b| b.price.partial_cmp(&a.price).unwrap_or(std::cmp::Ordering::Equal));
+    }
+    levels.truncate(10);
+}
+
+/// Maintains a local order book per pair over Kraken's legacy WS v1 `book` channel
+/// (`{"event":"subscribe","subscription":{"name":"book","depth":10}}`), validating every
+/// update against the server's CRC32 checksum before publishing it, so subscribers never see
+/// a corrupted ladder. On a checksum mismatch the local book is discarded and the connection
+/// is torn down to force a fresh snapshot on reconnect -- mirrors [`KrakenWsHub`]'s
+/// one-connection-per-pair sharing, but over `watch` (current-value) rather than `broadcast`
+/// (event-stream) semantics, since a consumer only ever wants the latest validated book.
+pub struct KrakenOrderBookHub {
+    subscriptions: tokio::sync::Mutex<std::collections::HashMap<String, tokio::sync::watch::Sender<Result<KrakenOrderBookSnapshot, KrakenBookStreamError>>>>,
+    retry: crate::config::RetryConfig,
+}
+
+impl KrakenOrderBookHub {
+    pub fn new(retry: crate::config::RetryConfig) -> Self {
+        Self { subscriptions: tokio::sync::Mutex::new(std::collections::HashMap::new()), retry }
+    }
+
+    /// Subscribe to the continuously-updated, checksum-validated book for `pair`. Opens the
+    /// upstream connection on the first subscriber for a given pair and reuses it after that.
+    pub async fn subscribe(
+        self: std::sync::Arc<Self>,
+        pair: String,
+    ) -> tokio::sync::watch::Receiver<Result<KrakenOrderBookSnapshot, KrakenBookStreamError>> {
+        let mut subs = self.subscriptions.lock().await;
+        match subs.get(&pair) {
+            Some(tx) => tx.subscribe(),
+            None => {
+                let (tx, rx) = tokio::sync::watch::channel(Err(KrakenBookStreamError::Disconnected));
+                subs.insert(pair.clone(), tx.clone());
+                tokio::spawn(self.clone().connect_and_publish(pair));
+                rx
+            }
+        }
+    }
+
+    async fn connect_and_publish(
+        self: std::sync::Arc<Self>,
+        pair: String,
+    ) {
+        use futures::{SinkExt, StreamExt};
+
+        let mut attempt = 0u32;
+        while self.has_subscribers(&pair).await {
+            let mut bids: Vec<BookLevel> = Vec::new();
+            let mut asks: Vec<BookLevel> = Vec::new();
+
+            match tokio_tungstenite::connect_async(KRAKEN_WS_V1_URL).await {
+                Ok((mut ws, _)) => {
+                    attempt = 0;
+                    let subscribe = serde_json::json!({
+                        "event": "subscribe",
+                        "pair": [pair.clone()],
+                        "subscription": { "name": "book", "depth": 10 }
+                    });
+                    if let Err(e) = ws.send(tokio_tungstenite::tungstenite::Message::Text(subscribe.to_string().into())).await {
+                        tracing::warn!("kraken book ws: subscribe failed for {pair}: {e}");
+                        self.publish(&pair, Err(KrakenBookStreamError::ConnectFailed(e.to_string()))).await;
+                    } else {
+                        'connection: while self.has_subscribers(&pair).await {
+                            match ws.next().await {
+                                Some(Ok(tokio_tungstenite::tungstenite::Message::Text(text))) => {
+                                    let Ok(value) = serde_json::from_str::<Value>(&text) else { continue };
+                                    let Some(frame) = value.as_array() else { continue };
+                                    if frame.len() < 4 { continue }
+
+                                    let mut checksum: Option<u32> = None;
+                                    for payload in &frame[1..frame.len() - 2] {
+                                        let Some(obj) = payload.as_object() else { continue };
+
+                                        if let Some(snapshot_asks) = obj.get("as").and_then(|v| v.as_array()) {
+                                            asks = snapshot_asks.iter().filter_map(parse_book_level).collect();
+                                            asks.sort_by(|a, b| a.price.partial_cmp(&b.price).unwrap_or(std::cmp::Ordering::Equal));
+                                            asks.truncate(10);
+                                        }
+                                        if let Some(snapshot_bids) = obj.get("bs").and_then(|v| v.as_array()) {
+                                            bids = snapshot_bids.iter().filter_map(parse_book_level).collect();
+                                            bids.sort_by(|a, b| b.price.partial_cmp(&a.price).unwrap_or(std::cmp::Ordering::Equal));
+                                            bids.truncate(10);
+                                        }
+                                        if let Some(ask_deltas) = obj.get("a").and_then(|v| v.as_array()) {
+                                            apply_book_delta(&mut asks, ask_deltas, true);
+                                        }
+                                        if let Some(bid_deltas) = obj.get("b").and_then(|v| v.as_array()) {
+                                            apply_book_delta(&mut bids, bid_deltas, false);
+                                        }
+                                        if let Some(c) = obj.get("c").and_then(|v| v.as_str()) {
+                                            checksum = c.parse().ok();
+                                        }
+                                    }
+
+                                    if let Some(expected) = checksum {
+                                        if book_checksum(&asks, &bids) != expected {
+                                            tracing::warn!("kraken book ws: checksum mismatch for {pair}, re-subscribing");
+                                            self.publish(&pair, Err(KrakenBookStreamError::ChecksumMismatch)).await;
+                                            break 'connection;
+                                        }
+                                    }
+
+                                    let snapshot = KrakenOrderBookSnapshot {
+                                        pair: pair.clone(),
+                                        bids: bids.iter().map(|l| (l.price, l.volume_str.parse().unwrap_or(0.0))).collect(),
+                                        asks: asks.iter().map(|l| (l.price, l.volume_str.parse().unwrap_or(0.0))).collect(),
+                                    };
+                                    self.publish(&pair, Ok(snapshot)).await;
+                                }
+                                Some(Ok(_)) => continue,
+                                Some(Err(e)) => {
+                                    tracing::warn!("kraken book ws: connection error for {pair}: {e}");
+                                    self.publish(&pair, Err(KrakenBookStreamError::ConnectFailed(e.to_string()))).await;
+                                    break;
+                                }
+                                None => {
+                                    self.publish(&pair, Err(KrakenBookStreamError::Disconnected)).await;
+                                    break;
+                                }
+                            }
+                        }
+                    }
+                }
+                Err(e) => {
+                    tracing::warn!("kraken book ws: connect failed for {pair}: {e}");
+                    self.publish(&pair, Err(KrakenBookStreamError::ConnectFailed(e.to_string()))).await;
+                }
+            }
+
+            if !self.has_subscribers(&pair).await {
+                break;
+            }
+            let exp_ms = self.retry.base_delay_ms
+                .saturating_mul(1u64 << attempt.min(16))
+                .min(self.retry.max_delay_ms);
+            tokio::time::sleep(std::time::Duration::from_millis(exp_ms.max(self.retry.base_delay_ms))).await;
+            attempt += 1;
+        }
+
+        self.subscriptions.lock().await.remove(&pair);
+    }
+
+    async fn has_subscribers(&self, pair: &str) -> bool {
+        self.subscriptions.lock().await.get(pair).map(|tx| tx.receiver_count() > 0).unwrap_or(false)
+    }
+
+    async fn publish(&self, pair: &str, value: Result<KrakenOrderBookSnapshot, KrakenBookStreamError>) {
+        if let Some(tx) = self.subscriptions.lock().await.get(pair) {
+            let _ = tx.send(value);
+        }
+    }
 }
 
 #[cfg(test)]
@@ -589,4 +1262,66 @@ mod tests {
         let result = data_source.get_server_time().await;
         assert!(result.is_ok());
     }
+
+    fn level(price_str: &str, volume_str: &str) -> BookLevel {
+        BookLevel {
+            price: price_str.parse().unwrap(),
+            price_str: price_str.to_string(),
+            volume_str: volume_str.to_string(),
+        }
+    }
+
+    #[test]
+    fn test_checksum_token_strips_dot_and_leading_zeros() {
+        assert_eq!(checksum_token("5541.30"), "554130");
+        assert_eq!(checksum_token("0.00010000"), "10000");
+        assert_eq!(checksum_token("0.00000000"), "0");
+    }
+
+    #[test]
+    fn test_book_checksum_matches_published_example() {
+        // Kraken's published book-checksum example: top-10 asks (ascending) then top-10 bids
+        // (descending) for a ten-level book snapshot.
+        let asks = vec![
+            level("5541.30", "2.50700000"),
+            level("5541.80", "0.33000000"),
+            level("5542.70", "0.64700000"),
+            level("5544.30", "0.34300000"),
+            level("5545.00", "1.40000000"),
+            level("5545.10", "0.76000000"),
+            level("5545.80", "1.00000000"),
+            level("5546.00", "0.49700000"),
+            level("5546.50", "0.40000000"),
+            level("5547.50", "0.34400000"),
+        ];
+        let bids = vec![
+            level("5541.20", "1.52900000"),
+            level("5539.90", "0.30000000"),
+            level("5539.50", "4.42000000"),
+            level("5539.10", "0.07500000"),
+            level("5538.10", "0.15000000"),
+            level("5537.60", "0.18200000"),
+            level("5537.20", "1.26000000"),
+            level("5536.60", "0.71000000"),
+            level("5536.20", "0.01000000"),
+            level("5535.90", "1.23000000"),
+        ];
+
+        assert_eq!(book_checksum(&asks, &bids), 1_196_020_538);
+    }
+
+    #[test]
+    fn test_book_checksum_truncates_to_top_ten_levels() {
+        // An 11th level beyond the top 10 on either side must not affect the checksum.
+        let mut asks = vec![level("5541.30", "2.50700000"); 10];
+        let bids = vec![level("5541.20", "1.52900000"); 10];
+        let with_extra_ask = {
+            let mut extended = asks.clone();
+            extended.push(level("9999.99", "9.99999999"));
+            extended
+        };
+        asks.truncate(10);
+
+        assert_eq!(book_checksum(&asks, &bids), book_checksum(&with_extra_ask, &bids));
+    }
 }
@@ -0,0 +1,155 @@
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+
+use futures::{SinkExt, StreamExt};
+use serde::Serialize;
+use tokio::sync::RwLock;
+use tokio_tungstenite::tungstenite::Message;
+
+use crate::config::RetryConfig;
+use crate::errors::ApiError;
+use crate::sources::kraken_data::KrakenWsMessage;
+use crate::utils::retry_with_backoff;
+
+const KRAKEN_WS_URL: &str = "wss://ws.kraken.com/v2";
+
+/// How long the read loop waits for any frame (ticker update or heartbeat) before treating the
+/// connection as stalled and forcing a reconnect.
+const HEARTBEAT_TIMEOUT: std::time::Duration = std::time::Duration::from_secs(30);
+
+/// Last known price/volume for one pair, kept current by [`KrakenSnapshotHub`] so hot-path reads
+/// (trending, the ticker endpoint) don't have to wait on a REST round-trip.
+#[derive(Debug, Clone, Serialize, utoipa::ToSchema)]
+pub struct KrakenTickerSnapshot {
+    pub pair: String,
+    pub last_price: f64,
+    pub volume_24h: f64,
+    pub updated_at: chrono::DateTime<chrono::Utc>,
+}
+
+/// Maintains a live snapshot of Kraken ticker data for a fixed pair set over a single persistent
+/// WebSocket connection, so `get_trending_cryptos_kraken` and `/kraken/ticker` can read
+/// sub-second-fresh data instead of polling REST on every request. Reconnects with backoff
+/// (via [`retry_with_backoff`]) on disconnect, and `is_healthy()` goes false on a dead socket or
+/// a stalled one (no frames within [`HEARTBEAT_TIMEOUT`]) so callers know to fall back to REST.
+pub struct KrakenSnapshotHub {
+    pairs: Vec<String>,
+    snapshots: RwLock<HashMap<String, KrakenTickerSnapshot>>,
+    healthy: AtomicBool,
+    retry: RetryConfig,
+}
+
+impl KrakenSnapshotHub {
+    pub fn new(pairs: Vec<String>, retry: RetryConfig) -> Self {
+        Self {
+            pairs,
+            snapshots: RwLock::new(HashMap::new()),
+            healthy: AtomicBool::new(false),
+            retry,
+        }
+    }
+
+    pub async fn snapshot(&self, pair: &str) -> Option<KrakenTickerSnapshot> {
+        self.snapshots.read().await.get(pair).cloned()
+    }
+
+    pub async fn all_snapshots(&self) -> Vec<KrakenTickerSnapshot> {
+        self.snapshots.read().await.values().cloned().collect()
+    }
+
+    pub fn is_healthy(&self) -> bool {
+        self.healthy.load(Ordering::Relaxed)
+    }
+
+    /// Runs forever: connect, subscribe to `self.pairs`, stream ticker updates into the
+    /// snapshot map, and reconnect with backoff whenever the socket dies or stalls.
+    pub async fn spawn(self: Arc<Self>) {
+        loop {
+            let pairs = self.pairs.clone();
+            let retry = self.retry.clone();
+            let connected = retry_with_backoff(
+                || async { Self::connect_and_subscribe(&pairs).await },
+                &retry,
+                "kraken_ws_connect",
+                None,
+            ).await;
+
+            let mut ws = match connected {
+                Ok(ws) => {
+                    self.healthy.store(true, Ordering::Relaxed);
+                    ws
+                }
+                Err(e) => {
+                    tracing::warn!("kraken snapshot hub: giving up connecting for this round: {e}");
+                    self.healthy.store(false, Ordering::Relaxed);
+                    continue;
+                }
+            };
+
+            loop {
+                match tokio::time::timeout(HEARTBEAT_TIMEOUT, ws.next()).await {
+                    Ok(Some(Ok(Message::Text(text)))) => {
+                        self.healthy.store(true, Ordering::Relaxed);
+                        self.ingest(&text).await;
+                    }
+                    Ok(Some(Ok(_))) => continue,
+                    Ok(Some(Err(e))) => {
+                        tracing::warn!("kraken snapshot hub: connection error: {e}");
+                        break;
+                    }
+                    Ok(None) => {
+                        tracing::warn!("kraken snapshot hub: connection closed");
+                        break;
+                    }
+                    Err(_) => {
+                        tracing::warn!("kraken snapshot hub: no frames within {:?}, reconnecting", HEARTBEAT_TIMEOUT);
+                        break;
+                    }
+                }
+            }
+
+            self.healthy.store(false, Ordering::Relaxed);
+        }
+    }
+
+    async fn connect_and_subscribe(
+        pairs: &[String],
+    ) -> Result<tokio_tungstenite::WebSocketStream<tokio_tungstenite::MaybeTlsStream<tokio::net::TcpStream>>, ApiError> {
+        let (mut ws, _) = tokio_tungstenite::connect_async(KRAKEN_WS_URL)
+            .await
+            .map_err(|e| ApiError::Upstream(format!("kraken ws connect failed: {e}")))?;
+
+        let subscribe = serde_json::json!({
+            "method": "subscribe",
+            "params": { "channel": "ticker", "symbol": pairs }
+        });
+        ws.send(Message::Text(subscribe.to_string().into()))
+            .await
+            .map_err(|e| ApiError::Upstream(format!("kraken ws subscribe failed: {e}")))?;
+
+        Ok(ws)
+    }
+
+    async fn ingest(&self, text: &str) {
+        let Ok(KrakenWsMessage::Ticker(frame)) = serde_json::from_str::<KrakenWsMessage>(text) else {
+            return;
+        };
+        if frame.channel != "ticker" {
+            return;
+        }
+
+        let mut snapshots = self.snapshots.write().await;
+        for ticker in frame.data {
+            snapshots.insert(
+                ticker.symbol.clone(),
+                KrakenTickerSnapshot {
+                    pair: ticker.symbol,
+                    last_price: ticker.last,
+                    volume_24h: ticker.volume,
+                    updated_at: chrono::Utc::now(),
+                },
+            );
+        }
+    }
+}
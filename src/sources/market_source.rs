@@ -0,0 +1,162 @@
+//! A small cross-venue market-data interface so handlers (e.g. a `/markets/compare/{coin}`
+//! endpoint) can treat [`crate::sources::hyperliquid_data::HyperliquidDataSource`] and
+//! [`crate::sources::coinbase_data::CoinbaseDataSource`] the same way instead of special-casing
+//! each venue. Mirrors the one-trait-per-concern shape of
+//! [`crate::helpers::price_aggregator::Exchange`], but covers the fuller
+//! markets/orderbook/trades/candles surface rather than just spot price.
+
+use async_trait::async_trait;
+use serde::Serialize;
+use utoipa::ToSchema;
+
+use crate::errors::ApiError;
+use crate::types::TrendingItem;
+
+/// One market's current state, unified across venues. `funding_rate` is `None` for spot venues
+/// (e.g. Coinbase) that have no perpetual funding.
+#[derive(Debug, Clone, Serialize, ToSchema)]
+pub struct UnifiedMarket {
+    pub symbol: String,
+    pub last_price: f64,
+    pub volume_24h: f64,
+    pub price_change_percentage_24h: f64,
+    pub funding_rate: Option<f64>,
+}
+
+#[derive(Debug, Clone, Serialize, ToSchema)]
+pub struct UnifiedOrderbookLevel {
+    pub price: f64,
+    pub size: f64,
+}
+
+#[derive(Debug, Clone, Serialize, ToSchema)]
+pub struct UnifiedOrderbook {
+    pub symbol: String,
+    pub bids: Vec<UnifiedOrderbookLevel>,
+    pub asks: Vec<UnifiedOrderbookLevel>,
+    pub time: u64,
+}
+
+#[derive(Debug, Clone, Serialize, ToSchema)]
+pub struct UnifiedTrade {
+    pub symbol: String,
+    pub side: String,
+    pub price: f64,
+    pub size: f64,
+    pub time: u64,
+}
+
+#[derive(Debug, Clone, Serialize, ToSchema)]
+pub struct UnifiedCandle {
+    pub symbol: String,
+    pub time: u64,
+    pub open: f64,
+    pub high: f64,
+    pub low: f64,
+    pub close: f64,
+    pub volume: f64,
+}
+
+/// Common read surface that both [`crate::sources::hyperliquid_data::HyperliquidDataSource`] and
+/// [`crate::sources::coinbase_data::CoinbaseDataSource`] implement, so cross-venue endpoints like
+/// `/markets/compare/{coin}` can query every venue the same way.
+#[async_trait]
+pub trait MarketDataSource: Send + Sync {
+    /// Short venue label used in comparison output, e.g. `"hyperliquid"` or `"coinbase"`.
+    fn venue(&self) -> &'static str;
+
+    async fn get_all_markets(&self) -> Result<Vec<UnifiedMarket>, ApiError>;
+    async fn get_orderbook(&self, symbol: &str, depth: Option<u32>) -> Result<UnifiedOrderbook, ApiError>;
+    async fn get_recent_trades(&self, symbol: &str, limit: Option<u32>) -> Result<Vec<UnifiedTrade>, ApiError>;
+    async fn get_candles(&self, symbol: &str, interval: &str, start_time: u64, end_time: u64) -> Result<Vec<UnifiedCandle>, ApiError>;
+
+    /// Top `limit` markets by 24h volume. Default impl sorts [`Self::get_all_markets`]'s result
+    /// descending by `volume_24h`; a venue with a cheaper native "top markets" call (e.g. an
+    /// already-sorted upstream endpoint) can override this.
+    async fn top_by_volume(&self, limit: usize) -> Result<Vec<UnifiedMarket>, ApiError> {
+        let mut markets = self.get_all_markets().await?;
+        markets.sort_by(|a, b| b.volume_24h.partial_cmp(&a.volume_24h).unwrap_or(std::cmp::Ordering::Equal));
+        markets.truncate(limit);
+        Ok(markets)
+    }
+
+    /// Splits [`Self::get_all_markets`]'s result into the `limit` biggest 24h gainers and the
+    /// `limit` biggest losers by `price_change_percentage_24h`.
+    async fn top_movers(&self, limit: usize) -> Result<(Vec<UnifiedMarket>, Vec<UnifiedMarket>), ApiError> {
+        let markets = self.get_all_markets().await?;
+
+        let mut gainers = markets.clone();
+        gainers.sort_by(|a, b| b.price_change_percentage_24h.partial_cmp(&a.price_change_percentage_24h).unwrap_or(std::cmp::Ordering::Equal));
+        gainers.truncate(limit);
+
+        let mut losers = markets;
+        losers.sort_by(|a, b| a.price_change_percentage_24h.partial_cmp(&b.price_change_percentage_24h).unwrap_or(std::cmp::Ordering::Equal));
+        losers.truncate(limit);
+
+        Ok((gainers, losers))
+    }
+
+    /// Normalizes [`Self::top_by_volume`] into [`TrendingItem`]s, scored by volume rank (1-based,
+    /// so the busiest market scores highest), the same shape
+    /// [`crate::sources::hyperliquid_data::HyperliquidDataSource::get_trending_defi_assets`] used
+    /// to build by hand before this became a shared default.
+    async fn trending(&self, limit: usize) -> Result<Vec<TrendingItem>, ApiError> {
+        let markets = self.top_by_volume(limit).await?;
+        let venue = self.venue();
+        let now = chrono::Utc::now().timestamp().to_string();
+
+        Ok(markets.into_iter()
+            .enumerate()
+            .map(|(index, market)| TrendingItem {
+                id: format!("{venue}_{}", market.symbol.to_lowercase()),
+                symbol: market.symbol.clone(),
+                name: market.symbol,
+                price: Some(market.last_price),
+                price_change_24h: None,
+                price_change_percentage_24h: Some(market.price_change_percentage_24h),
+                volume: Some(market.volume_24h),
+                market_cap: None,
+                market_cap_rank: None,
+                score: Some((limit - index) as f64),
+                source: venue.to_string(),
+                image_url: None,
+                last_updated: Some(now.clone()),
+            })
+            .collect())
+    }
+}
+
+/// Merges [`MarketDataSource::trending`] across every registered source into one leaderboard,
+/// deduplicating by symbol (case-insensitive) and keeping whichever venue reported the higher
+/// 24h volume for that symbol, then re-sorting and truncating to `limit`. Venues that error are
+/// logged and dropped rather than failing the whole aggregation, mirroring
+/// `crate::routes::markets::compare_market`'s per-venue error handling.
+pub async fn aggregate_trending(sources: &[std::sync::Arc<dyn MarketDataSource>], limit: usize) -> Vec<TrendingItem> {
+    use futures::future::join_all;
+
+    let per_source = join_all(sources.iter().map(|source| {
+        let source = source.clone();
+        async move {
+            source.trending(limit).await.map_err(|e| {
+                tracing::warn!("aggregate_trending: {} failed: {e}", source.venue());
+                e
+            })
+        }
+    })).await;
+
+    let mut by_symbol: std::collections::HashMap<String, TrendingItem> = std::collections::HashMap::new();
+    for item in per_source.into_iter().flatten().flatten() {
+        let key = item.symbol.to_uppercase();
+        match by_symbol.get(&key) {
+            Some(existing) if existing.volume.unwrap_or(0.0) >= item.volume.unwrap_or(0.0) => {}
+            _ => {
+                by_symbol.insert(key, item);
+            }
+        }
+    }
+
+    let mut merged: Vec<TrendingItem> = by_symbol.into_values().collect();
+    merged.sort_by(|a, b| b.volume.unwrap_or(0.0).partial_cmp(&a.volume.unwrap_or(0.0)).unwrap_or(std::cmp::Ordering::Equal));
+    merged.truncate(limit);
+    merged
+}
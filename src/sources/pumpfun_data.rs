@@ -1,6 +1,8 @@
+use std::collections::HashMap;
 use std::sync::Arc;
+use async_trait::async_trait;
 use serde::{Deserialize, Serialize};
-use utoipa::ToSchema;
+use utoipa::{IntoParams, ToSchema};
 use pumpfun::{
     common::types::{Cluster, PriorityFee},
     utils::CreateTokenMetadata,
@@ -10,10 +12,13 @@ use pumpfun::{
 use solana_sdk::{
     commitment_config::CommitmentConfig,
     native_token::LAMPORTS_PER_SOL,
-    signature::Keypair,
+    signature::{Keypair, Signature},
+    signer::Signer,
     pubkey::Pubkey,
 };
+use solana_client::rpc_client::RpcClient;
 use crate::errors::ApiError;
+use crate::services::candles::{Candle, CandleStore, Trade};
 
 #[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
 pub struct PumpFunConfig {
@@ -38,6 +43,10 @@ pub struct TokenCreateRequest {
     pub website: Option<String>,
     pub twitter: Option<String>,
     pub telegram: Option<String>,
+    /// If `true`, wait for the submitted transaction to reach [`PumpFunConfig::commitment`]
+    /// before responding, so `TransactionResult::success`/`slot` reflect what actually landed
+    /// on-chain. Defaults to `false` (the signature is returned as soon as the RPC accepts it).
+    pub confirm: Option<bool>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
@@ -45,6 +54,14 @@ pub struct TokenBuyRequest {
     pub mint_address: String,
     pub sol_amount: f64, // SOL amount
     pub slippage_bps: Option<u64>,
+    /// If `true`, wait for the submitted transaction to reach [`PumpFunConfig::commitment`]
+    /// before responding, so `TransactionResult::success`/`slot` reflect what actually landed
+    /// on-chain. Defaults to `false` (the signature is returned as soon as the RPC accepts it).
+    pub confirm: Option<bool>,
+    /// Reject the trade before it's submitted if the bonding curve's execution price deviates
+    /// from [`PumpFunService`]'s configured [`PriceSource`] by more than this many basis
+    /// points. A no-op unless a price source is configured.
+    pub price_tolerance_bps: Option<u64>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
@@ -52,6 +69,16 @@ pub struct TokenSellRequest {
     pub mint_address: String,
     pub token_amount: Option<u64>, // if None, sell all tokens
     pub slippage_bps: Option<u64>,
+    /// If `true`, wait for the submitted transaction to reach [`PumpFunConfig::commitment`]
+    /// before responding, so `TransactionResult::success`/`slot` reflect what actually landed
+    /// on-chain. Defaults to `false` (the signature is returned as soon as the RPC accepts it).
+    pub confirm: Option<bool>,
+    /// Reject the trade before it's submitted if the bonding curve's execution price deviates
+    /// from [`PumpFunService`]'s configured [`PriceSource`] by more than this many basis
+    /// points. A no-op unless a price source is configured. Applies even when `token_amount` is
+    /// `None`, since [`PumpFunService::sell_token`] resolves that case to the wallet's real
+    /// balance via [`PumpFunService::get_token_balance`] before quoting.
+    pub price_tolerance_bps: Option<u64>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
@@ -65,6 +92,10 @@ pub struct TokenCreateAndBuyRequest {
     pub telegram: Option<String>,
     pub sol_amount: f64, // SOL amount to buy
     pub slippage_bps: Option<u64>,
+    /// If `true`, wait for the submitted transaction to reach [`PumpFunConfig::commitment`]
+    /// before responding, so `TransactionResult::success`/`slot` reflect what actually landed
+    /// on-chain. Defaults to `false` (the signature is returned as soon as the RPC accepts it).
+    pub confirm: Option<bool>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
@@ -102,6 +133,186 @@ pub struct TradeInfo {
     pub trade_type: String, // "dev", "user", "bot"
 }
 
+/// Mirrors the `jsonParsed` token-amount shape Solana RPCs return for an SPL token account
+/// (e.g. `getTokenAccountBalance`), as returned by [`PumpFunService::get_token_balance`].
+/// `amount` is the raw integer balance as a string rather than a JSON number, since a `u64`
+/// close to its max would silently lose precision going through `f64`-backed JSON numbers.
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct TokenBalance {
+    pub amount: String,
+    pub decimals: u8,
+    pub ui_amount: f64,
+    pub ui_amount_string: String,
+}
+
+/// Namespaces a mint address into the `pair` key [`CandleStore`] persists under, so pump.fun
+/// candles can't collide with a Kraken pair of the same literal string.
+pub fn candle_store_pair(mint_address: &str) -> String {
+    format!("pumpfun:{mint_address}")
+}
+
+/// Folds `trades` (assumed already sorted ascending by time -- callers should sort/filter
+/// beforehand, same as [`crate::helpers::ledger::build_option_ledger`]) into OHLCV candles
+/// bucketed on `resolution_seconds` boundaries: the bucket's first trade sets `open`, its last
+/// sets `close`, `high`/`low` track the running extremes, and `volume` sums each trade's SOL
+/// amount. Trades with no parseable timestamp are skipped rather than failing the whole fold.
+pub fn fold_trades_into_candles(mint_address: &str, trades: &[TradeInfo], resolution_seconds: i64) -> Vec<Candle> {
+    let pair = candle_store_pair(mint_address);
+    let mut buckets: Vec<Candle> = Vec::new();
+
+    for trade in trades {
+        let Some(trade_time) = trade.timestamp.as_deref().and_then(|t| chrono::DateTime::parse_from_rfc3339(t).ok()) else {
+            continue;
+        };
+        let trade_time = trade_time.with_timezone(&chrono::Utc);
+        let bucket_start_secs = (trade_time.timestamp() as f64 / resolution_seconds as f64).floor() as i64 * resolution_seconds;
+        let Some(bucket_start) = chrono::DateTime::from_timestamp(bucket_start_secs, 0) else { continue };
+
+        match buckets.last_mut().filter(|c| c.bucket_start == bucket_start) {
+            Some(candle) => {
+                candle.high = candle.high.max(trade.price);
+                candle.low = candle.low.min(trade.price);
+                candle.close = trade.price;
+                candle.volume += trade.sol_amount;
+            }
+            None => buckets.push(Candle {
+                pair: pair.clone(),
+                bucket_start,
+                open: trade.price,
+                high: trade.price,
+                low: trade.price,
+                close: trade.price,
+                volume: trade.sol_amount,
+            }),
+        }
+    }
+
+    buckets
+}
+
+/// Batch-backfills historical `trades` for `mint_address`: persists each raw trade, then folds
+/// them into 1-minute candles (the resolution [`CandleStore::aggregated_candles`] rolls up
+/// from) and upserts those. A no-op when `candle_store` isn't configured.
+pub async fn backfill_trade_candles(candle_store: &CandleStore, mint_address: &str, trades: &[TradeInfo]) -> Result<(), ApiError> {
+    if !candle_store.is_enabled() {
+        return Ok(());
+    }
+
+    let pair = candle_store_pair(mint_address);
+    for trade in trades {
+        let Some(trade_time) = trade.timestamp.as_deref().and_then(|t| chrono::DateTime::parse_from_rfc3339(t).ok()) else {
+            continue;
+        };
+        candle_store
+            .upsert_trade(&Trade {
+                pair: pair.clone(),
+                trade_time: trade_time.with_timezone(&chrono::Utc),
+                price: trade.price,
+                volume: trade.sol_amount,
+                side: if trade.is_buy { "buy".to_string() } else { "sell".to_string() },
+            })
+            .await?;
+    }
+
+    for candle in fold_trades_into_candles(mint_address, trades, 60) {
+        candle_store.upsert_candle_1m(&candle).await?;
+    }
+
+    Ok(())
+}
+
+/// Incremental counterpart to [`backfill_trade_candles`] for a single freshly-observed trade
+/// (e.g. from [`PumpFunService::subscribe_events`]): persists the trade, then re-folds and
+/// upserts just its own 1-minute bucket rather than re-rolling the whole history.
+pub async fn record_live_trade(candle_store: &CandleStore, mint_address: &str, trade: &TradeInfo) -> Result<(), ApiError> {
+    backfill_trade_candles(candle_store, mint_address, std::slice::from_ref(trade)).await
+}
+
+/// Exact integer amount for bonding-curve reserve/quote arithmetic, avoiding the precision
+/// loss `f64` introduces once a product of two reserves exceeds 2^53. Backed by `u128` rather
+/// than a true 256-bit integer (the way cowprotocol's `number::U256` is) -- this crate has no
+/// big-integer dependency to reach for, and pump.fun's reserve magnitudes (virtual reserves on
+/// the order of 1e12, so `k = rv * tv` on the order of 1e24) fit comfortably within `u128`'s
+/// ~3.4e38 range. Serializes as a decimal string and deserializes from either a decimal or
+/// `0x`-prefixed hex string, so large values round-trip through JSON exactly rather than via a
+/// lossy JSON number.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Default)]
+pub struct Amount(pub u128);
+
+impl Amount {
+    pub const ZERO: Amount = Amount(0);
+
+    pub fn from_u64(value: u64) -> Self {
+        Amount(value as u128)
+    }
+
+    /// `amount * scale` truncated to an integer, for converting a caller-supplied fractional
+    /// SOL/token amount into its raw (lamport/smallest-unit) integer form. Saturates to `0` on
+    /// negative, NaN, or infinite input rather than panicking, since `amount` is caller input.
+    pub fn from_f64_lamports(amount: f64, scale: u64) -> Self {
+        let scaled = amount * scale as f64;
+        if !scaled.is_finite() || scaled <= 0.0 {
+            Amount::ZERO
+        } else {
+            Amount(scaled as u128)
+        }
+    }
+
+    pub fn checked_add(self, rhs: Amount) -> Option<Amount> {
+        self.0.checked_add(rhs.0).map(Amount)
+    }
+
+    pub fn checked_sub(self, rhs: Amount) -> Option<Amount> {
+        self.0.checked_sub(rhs.0).map(Amount)
+    }
+
+    pub fn checked_mul(self, rhs: Amount) -> Option<Amount> {
+        self.0.checked_mul(rhs.0).map(Amount)
+    }
+
+    /// `None` on division by zero, rather than producing an infinity the way `f64` division
+    /// would silently have.
+    pub fn checked_div(self, rhs: Amount) -> Option<Amount> {
+        if rhs.0 == 0 { None } else { Some(Amount(self.0 / rhs.0)) }
+    }
+
+    /// Lossy conversion for display-only fields; keep values in `Amount` as long as possible
+    /// and only call this once, at the edge of a computation.
+    pub fn to_f64(self) -> f64 {
+        self.0 as f64
+    }
+}
+
+impl std::fmt::Display for Amount {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+impl Serialize for Amount {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.0.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for Amount {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let raw = String::deserialize(deserializer)?;
+        let value = match raw.strip_prefix("0x").or_else(|| raw.strip_prefix("0X")) {
+            Some(hex) => u128::from_str_radix(hex, 16),
+            None => raw.parse::<u128>(),
+        }
+        .map_err(serde::de::Error::custom)?;
+        Ok(Amount(value))
+    }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
 pub struct BondingCurveInfo {
     pub mint_address: String,
@@ -113,6 +324,256 @@ pub struct BondingCurveInfo {
     pub complete: bool,
 }
 
+/// Pump.fun migrates a curve to Raydium once its real SOL reserves reach roughly this much.
+const MIGRATION_THRESHOLD_SOL: f64 = 85.0;
+
+/// Pump tokens use 6 decimals (vs. SOL's 9, i.e. `LAMPORTS_PER_SOL`), so converting a raw
+/// token amount to its human-readable quantity divides by this rather than `LAMPORTS_PER_SOL`.
+const TOKEN_SCALE: f64 = 1_000_000.0;
+
+/// Spot price per token in SOL from a curve's virtual reserves: `(virtual_sol_reserves / 1e9)
+/// / (virtual_token_reserves / 1e6)`, i.e. human SOL divided by human tokens. `0.0` for a
+/// curve with no token liquidity, which would otherwise divide by zero.
+pub(crate) fn spot_price_sol(virtual_sol_reserves: u64, virtual_token_reserves: u64) -> f64 {
+    if virtual_token_reserves == 0 {
+        return 0.0;
+    }
+    (virtual_sol_reserves as f64 / LAMPORTS_PER_SOL as f64) / (virtual_token_reserves as f64 / TOKEN_SCALE)
+}
+
+/// Which side of the constant-product curve a [`BondingCurveInfo::quote`] is for.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "lowercase")]
+pub enum BondingCurveSide {
+    Buy,
+    Sell,
+}
+
+/// A constant-product (xyk) quote against a bonding curve's virtual reserves, from
+/// [`BondingCurveInfo::quote`].
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct BondingCurveQuote {
+    pub side: BondingCurveSide,
+    /// SOL in for a buy, raw token units in for a sell.
+    pub input_amount: f64,
+    /// Raw token units out for a buy, SOL out for a sell.
+    pub output_amount: f64,
+    pub spot_price_sol: f64,
+    pub execution_price_sol: f64,
+    /// `(execution_price - spot_price) / spot_price`; positive means the trade moved the
+    /// price against the trader.
+    pub price_impact: f64,
+    /// How far `real_sol_reserves` is toward the ~85-SOL Raydium migration threshold, 0-100.
+    pub completion_percentage: f64,
+    pub implied_market_cap_sol: f64,
+    /// Worst-case `output_amount` the trader should accept given `slippage_bps`: `output_amount
+    /// * (1 - slippage_bps / 10_000)`. `None` when no `slippage_bps` was supplied.
+    pub min_amount_out: Option<f64>,
+}
+
+impl BondingCurveInfo {
+    /// Quote a buy or sell against this curve's virtual reserves under the constant-product
+    /// invariant `k = virtual_sol_reserves * virtual_token_reserves` pump.fun bonding curves
+    /// follow: a buy of `sol_in` yields `tokens_out = Tv - k / (Rv + sol_in)`, a sell of
+    /// `tokens_in` yields `sol_out = Rv - k / (Tv + tokens_in)`. Spot price is
+    /// `(Rv / 1e9) / (Tv / 1e6)` (SOL has 9 decimals, pump tokens 6; see [`spot_price_sol`]).
+    /// `slippage_bps`, if given, populates `min_amount_out` as the worst-case amount the
+    /// trader should accept.
+    ///
+    /// Rejects the quote once the curve has `complete == true` (migrated to Raydium, so these
+    /// virtual reserves no longer price anything) or either reserve is zero (unpriceable).
+    ///
+    /// The invariant itself is computed in [`Amount`] (`u128`) rather than `f64`: at pump.fun's
+    /// reserve magnitudes (virtual reserves ~1e12) the product `k` already exceeds `f64`'s
+    /// 2^53 exact-integer range, so doing this math in floating point silently rounds it.
+    /// `f64` is only used below to produce the human-facing price/impact/market-cap fields.
+    pub fn quote(&self, side: BondingCurveSide, amount: f64, slippage_bps: Option<u64>) -> Result<BondingCurveQuote, ApiError> {
+        if self.complete {
+            return Err(ApiError::InvalidInput(format!(
+                "bonding curve for {} has migrated to Raydium; virtual reserves no longer price it",
+                self.mint_address
+            )));
+        }
+        if self.virtual_sol_reserves == 0 || self.virtual_token_reserves == 0 {
+            return Err(ApiError::InvalidInput(format!(
+                "bonding curve for {} has zero reserves and can't be priced",
+                self.mint_address
+            )));
+        }
+
+        let rv = Amount::from_u64(self.virtual_sol_reserves);
+        let tv = Amount::from_u64(self.virtual_token_reserves);
+        // Reserves this close to u64::MAX aren't realistic for a pump.fun curve; treat an
+        // overflowing product as an unpriceable (zero-liquidity) curve rather than panicking.
+        let k = rv.checked_mul(tv).unwrap_or(Amount::ZERO);
+        let spot_price_sol = spot_price_sol(self.virtual_sol_reserves, self.virtual_token_reserves);
+
+        let (input_amount, output_amount, execution_price_sol) = match side {
+            BondingCurveSide::Buy => {
+                let sol_in = Amount::from_f64_lamports(amount, LAMPORTS_PER_SOL);
+                let rv_plus_in = rv.checked_add(sol_in).unwrap_or(rv);
+                let k_over = k.checked_div(rv_plus_in).unwrap_or(Amount::ZERO);
+                let tokens_out = tv.checked_sub(k_over).unwrap_or(Amount::ZERO);
+                let execution_price_sol = if tokens_out.0 > 0 {
+                    (sol_in.to_f64() / LAMPORTS_PER_SOL as f64) / (tokens_out.to_f64() / TOKEN_SCALE)
+                } else {
+                    spot_price_sol
+                };
+                (amount, tokens_out.to_f64(), execution_price_sol)
+            }
+            BondingCurveSide::Sell => {
+                let tokens_in = Amount::from_f64_lamports(amount, 1);
+                let tv_plus_in = tv.checked_add(tokens_in).unwrap_or(tv);
+                let k_over = k.checked_div(tv_plus_in).unwrap_or(Amount::ZERO);
+                let sol_out = rv.checked_sub(k_over).unwrap_or(Amount::ZERO);
+                let execution_price_sol = if tokens_in.0 > 0 {
+                    (sol_out.to_f64() / LAMPORTS_PER_SOL as f64) / (tokens_in.to_f64() / TOKEN_SCALE)
+                } else {
+                    spot_price_sol
+                };
+                (amount, sol_out.to_f64() / LAMPORTS_PER_SOL as f64, execution_price_sol)
+            }
+        };
+
+        let price_impact = if spot_price_sol > 0.0 {
+            (execution_price_sol - spot_price_sol) / spot_price_sol
+        } else {
+            0.0
+        };
+
+        let completion_percentage = ((self.real_sol_reserves as f64 / LAMPORTS_PER_SOL as f64)
+            / MIGRATION_THRESHOLD_SOL
+            * 100.0)
+            .min(100.0);
+        let implied_market_cap_sol = spot_price_sol * self.token_total_supply as f64;
+        let min_amount_out = slippage_bps.map(|bps| output_amount * (1.0 - bps as f64 / 10_000.0));
+
+        Ok(BondingCurveQuote {
+            side,
+            input_amount,
+            output_amount,
+            spot_price_sol,
+            execution_price_sol,
+            price_impact,
+            completion_percentage,
+            implied_market_cap_sol,
+            min_amount_out,
+        })
+    }
+
+    /// Thin wrapper over [`Self::quote`] for a buy of `sol_in` SOL.
+    pub fn quote_buy(&self, sol_in: f64, slippage_bps: Option<u64>) -> Result<BondingCurveQuote, ApiError> {
+        self.quote(BondingCurveSide::Buy, sol_in, slippage_bps)
+    }
+
+    /// Thin wrapper over [`Self::quote`] for a sell of `token_in` raw token units.
+    pub fn quote_sell(&self, token_in: f64, slippage_bps: Option<u64>) -> Result<BondingCurveQuote, ApiError> {
+        self.quote(BondingCurveSide::Sell, token_in, slippage_bps)
+    }
+}
+
+/// A source of live reference prices (SOL per token) for a pump.fun mint, so a bonding-curve
+/// trade can be sanity-checked against an independent read before it executes. Mirrors
+/// [`crate::services::rates::LatestRate`]'s one-method-per-source shape.
+#[async_trait]
+pub trait PriceSource: Send + Sync {
+    fn source_name(&self) -> &'static str;
+
+    async fn latest_price(&self, mint: &Pubkey) -> Result<f64, ApiError>;
+}
+
+/// Derives its reference price straight from the mint's own bonding curve -- the cheapest
+/// [`PriceSource`] to stand up, but it can't catch a manipulated or stale curve since it reads
+/// the same curve a trade would execute against. Prefer an independent feed (e.g.
+/// [`WatchedPriceSource`] wrapping a CEX quote stream) where one exists for the mint.
+pub struct BondingCurveSource {
+    pumpfun: PumpFun,
+}
+
+impl BondingCurveSource {
+    /// Builds its own read-only SDK client from `config` rather than sharing one with a
+    /// [`PumpFunService`], so it has no dependency on (and can't cyclically reference) the
+    /// service whose trades it's validating. The signing keypair is never used for anything
+    /// other than satisfying the SDK constructor -- bonding-curve lookups don't sign.
+    pub fn new(config: &PumpFunConfig) -> Self {
+        Self { pumpfun: PumpFun::new(Arc::new(Keypair::new()), config.to_cluster()) }
+    }
+}
+
+#[async_trait]
+impl PriceSource for BondingCurveSource {
+    fn source_name(&self) -> &'static str {
+        "bonding_curve"
+    }
+
+    async fn latest_price(&self, mint: &Pubkey) -> Result<f64, ApiError> {
+        match self.pumpfun.get_bonding_curve_account(mint).await {
+            Ok(curve) => Ok(spot_price_sol(curve.virtual_sol_reserves, curve.virtual_token_reserves)),
+            Err(e) => Err(ApiError::External(format!("Failed to get bonding curve: {e}"))),
+        }
+    }
+}
+
+/// A [`PriceSource`] kept warm by a background poll loop instead of fetching per request,
+/// mirroring [`crate::services::rates::RateFeed`]. Wraps any per-mint price fetcher (a
+/// streaming websocket quote client, a REST poll against an external market, ...) behind one
+/// `watch` channel per mint.
+pub struct WatchedPriceSource {
+    name: &'static str,
+    channels: HashMap<Pubkey, tokio::sync::watch::Receiver<Option<f64>>>,
+}
+
+type PriceFetchFuture = std::pin::Pin<Box<dyn std::future::Future<Output = Result<f64, ApiError>> + Send>>;
+type PriceFetchFn = Arc<dyn Fn(Pubkey) -> PriceFetchFuture + Send + Sync>;
+
+impl WatchedPriceSource {
+    /// Spawns the background poll loop and returns immediately; channels start out holding
+    /// `None` until `fetch` succeeds for each mint at least once.
+    pub fn spawn(name: &'static str, mints: Vec<Pubkey>, interval: std::time::Duration, fetch: PriceFetchFn) -> Self {
+        let mut channels = HashMap::new();
+        let mut senders = HashMap::new();
+        for mint in &mints {
+            let (tx, rx) = tokio::sync::watch::channel(None);
+            channels.insert(*mint, rx);
+            senders.insert(*mint, tx);
+        }
+
+        tokio::spawn(async move {
+            let mut ticker = tokio::time::interval(interval);
+            loop {
+                ticker.tick().await;
+                for mint in &mints {
+                    match fetch(*mint).await {
+                        Ok(price) => {
+                            if let Some(tx) = senders.get(mint) {
+                                let _ = tx.send(Some(price));
+                            }
+                        }
+                        Err(e) => {
+                            tracing::warn!("price feed poll failed for {name} {mint}: {e}");
+                        }
+                    }
+                }
+            }
+        });
+
+        Self { name, channels }
+    }
+}
+
+#[async_trait]
+impl PriceSource for WatchedPriceSource {
+    fn source_name(&self) -> &'static str {
+        self.name
+    }
+
+    async fn latest_price(&self, mint: &Pubkey) -> Result<f64, ApiError> {
+        self.channels.get(mint)
+            .and_then(|rx| *rx.borrow())
+            .ok_or_else(|| ApiError::NotFound(format!("no watched price yet for mint {mint}")))
+    }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
 pub struct PumpFunEvent {
     pub event_type: String,
@@ -122,6 +583,48 @@ pub struct PumpFunEvent {
     pub timestamp: String,
 }
 
+/// Server-side filter for [`PumpFunService::subscribe_stream`], so a caller watching one token
+/// isn't flooded with the full event firehose. Every provided field narrows the stream further
+/// (AND, not OR); `min_sol_amount` only constrains trade events -- create/other/error events
+/// pass through regardless, since they carry no SOL amount to compare.
+#[derive(Debug, Clone, Default, Deserialize, IntoParams, ToSchema)]
+pub struct PumpFunEventFilter {
+    /// Only events for this mint address.
+    pub mint_address: Option<String>,
+    /// Only events of this type: "create", "trade", "other", or "error".
+    pub event_type: Option<String>,
+    /// Only trades moving at least this much SOL.
+    pub min_sol_amount: Option<f64>,
+}
+
+impl PumpFunEventFilter {
+    fn matches(&self, event: &PumpFunEvent) -> bool {
+        if let Some(mint) = &self.mint_address {
+            let event_mint = event.token_info.as_ref().map(|t| t.mint_address.as_str())
+                .or_else(|| event.trade_info.as_ref().map(|t| t.mint_address.as_str()));
+            if event_mint != Some(mint.as_str()) {
+                return false;
+            }
+        }
+
+        if let Some(event_type) = &self.event_type {
+            if &event.event_type != event_type {
+                return false;
+            }
+        }
+
+        if let Some(min_sol_amount) = self.min_sol_amount {
+            if let Some(trade) = &event.trade_info {
+                if trade.sol_amount < min_sol_amount {
+                    return false;
+                }
+            }
+        }
+
+        true
+    }
+}
+
 impl Default for PumpFunConfig {
     fn default() -> Self {
         Self {
@@ -137,6 +640,25 @@ impl Default for PumpFunConfig {
 }
 
 impl PumpFunConfig {
+    /// Reads `PUMPFUN_RPC_URL`/`PUMPFUN_COMMITMENT`/`PUMPFUN_PRIVATE_KEY`/`PUMPFUN_PRIORITY_FEE_*`
+    /// from the environment, falling back to [`Self::default`] for anything unset.
+    pub fn from_env() -> Self {
+        let default = Self::default();
+        Self {
+            rpc_url: std::env::var("PUMPFUN_RPC_URL").unwrap_or(default.rpc_url),
+            commitment: std::env::var("PUMPFUN_COMMITMENT").unwrap_or(default.commitment),
+            private_key: std::env::var("PUMPFUN_PRIVATE_KEY").ok(),
+            priority_fee: PumpFunPriorityFee {
+                unit_limit: std::env::var("PUMPFUN_PRIORITY_FEE_UNIT_LIMIT").ok()
+                    .and_then(|v| v.parse().ok())
+                    .or(default.priority_fee.unit_limit),
+                unit_price: std::env::var("PUMPFUN_PRIORITY_FEE_UNIT_PRICE").ok()
+                    .and_then(|v| v.parse().ok())
+                    .or(default.priority_fee.unit_price),
+            },
+        }
+    }
+
     pub fn to_cluster(&self) -> Cluster {
         let commitment = match self.commitment.as_str() {
             "processed" => CommitmentConfig::processed(),
@@ -160,16 +682,137 @@ impl PumpFunConfig {
     }
 }
 
+/// How often [`PumpFunService::confirm_transaction`] polls `getSignatureStatuses` while
+/// waiting for a submitted transaction to reach [`PumpFunConfig::commitment`].
+const CONFIRMATION_POLL_INTERVAL: std::time::Duration = std::time::Duration::from_millis(1_000);
+/// Wall-clock cap on [`PumpFunService::confirm_transaction`]; a transaction still unconfirmed
+/// when this elapses resolves to `success: false` instead of hanging the caller.
+const CONFIRMATION_TIMEOUT: std::time::Duration = std::time::Duration::from_secs(60);
+
+/// Ranks a commitment level (`"processed"` < `"confirmed"` < `"finalized"`) so a landed
+/// transaction's confirmation status can be compared against [`PumpFunConfig::commitment`].
+/// Unrecognized values rank as `"processed"`, matching [`PumpFunConfig::to_cluster`]'s fallback.
+fn commitment_rank(level: &str) -> u8 {
+    match level.to_lowercase().as_str() {
+        "finalized" => 2,
+        "confirmed" => 1,
+        _ => 0,
+    }
+}
+
+/// SPL Token and Associated Token Account program ids, spelled out as raw constants the same
+/// way [`crate::sources::helius_data`] does rather than pulling in the `spl-token`/
+/// `spl-associated-token-account` crates just to derive one PDA.
+const SPL_TOKEN_PROGRAM_ID: &str = "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA";
+const ASSOCIATED_TOKEN_PROGRAM_ID: &str = "ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL";
+
 pub struct PumpFunService {
     config: PumpFunConfig,
     pumpfun: Option<PumpFun>,
+    /// Fan-out for converted [`PumpFunEvent`]s; every [`PumpFunService::subscribe_stream`]
+    /// caller gets its own `Receiver` off this one channel instead of its own SDK subscription.
+    events_tx: tokio::sync::broadcast::Sender<PumpFunEvent>,
+    /// Set to `true` once the underlying SDK subscription has been started, so concurrent
+    /// `subscribe_stream` callers don't each open their own upstream subscription.
+    events_subscribed: std::sync::atomic::AtomicBool,
+    /// Reference price feed consulted by [`Self::buy_token`]/[`Self::sell_token`] when a
+    /// caller supplies `price_tolerance_bps`. `None` (the default) skips the check entirely.
+    price_source: Option<Arc<dyn PriceSource>>,
 }
 
 impl PumpFunService {
     pub fn new(config: PumpFunConfig) -> Self {
+        let (events_tx, _rx) = tokio::sync::broadcast::channel(256);
         Self {
             config,
             pumpfun: None,
+            events_tx,
+            events_subscribed: std::sync::atomic::AtomicBool::new(false),
+            price_source: None,
+        }
+    }
+
+    /// Configures the [`PriceSource`] that `price_tolerance_bps` on a buy/sell request is
+    /// checked against. Leaving this unset makes `price_tolerance_bps` a no-op.
+    pub fn set_price_source(&mut self, source: Arc<dyn PriceSource>) {
+        self.price_source = Some(source);
+    }
+
+    /// When `tolerance_bps` is set and a [`PriceSource`] is configured, quotes `side`/`amount`
+    /// against the mint's bonding curve and rejects with [`ApiError::ValidationError`] if the
+    /// execution price deviates from the source's reference price by more than the tolerance --
+    /// a sanity check against a stale or manipulated curve before the caller spends SOL. A
+    /// no-op otherwise.
+    async fn check_price_tolerance(
+        &self,
+        mint: &Pubkey,
+        mint_address: &str,
+        side: BondingCurveSide,
+        amount: f64,
+        slippage_bps: Option<u64>,
+        tolerance_bps: Option<u64>,
+    ) -> Result<(), ApiError> {
+        let Some(tolerance_bps) = tolerance_bps else { return Ok(()) };
+        let Some(price_source) = self.price_source.as_ref() else { return Ok(()) };
+
+        let curve = self.get_bonding_curve(mint_address).await?;
+        let quote = curve.quote(side, amount, slippage_bps)?;
+        let reference_price = price_source.latest_price(mint).await?;
+        if reference_price <= 0.0 {
+            return Ok(());
+        }
+
+        let deviation = (quote.execution_price_sol - reference_price).abs() / reference_price;
+        if deviation > tolerance_bps as f64 / 10_000.0 {
+            return Err(ApiError::ValidationError(format!(
+                "execution price {:.9} SOL deviates {:.2}% from {}'s reference price {:.9} SOL, exceeding the {:.2}% tolerance",
+                quote.execution_price_sol,
+                deviation * 100.0,
+                price_source.source_name(),
+                reference_price,
+                tolerance_bps as f64 / 100.0,
+            )));
+        }
+
+        Ok(())
+    }
+
+    /// Polls `getSignatureStatuses` for `signature` until it reaches [`PumpFunConfig::commitment`]
+    /// or reports an on-chain error, bounded by [`CONFIRMATION_TIMEOUT`] so a dropped
+    /// transaction resolves to `success: false` rather than hanging. Returns the landed
+    /// `(slot, success, error)`.
+    async fn confirm_transaction(&self, signature: &str) -> (Option<u64>, bool, Option<String>) {
+        let signature: Signature = match signature.parse() {
+            Ok(signature) => signature,
+            Err(e) => return (None, false, Some(format!("invalid signature: {e}"))),
+        };
+        let rpc = RpcClient::new(self.config.rpc_url.clone());
+        let target_rank = commitment_rank(&self.config.commitment);
+        let deadline = tokio::time::Instant::now() + CONFIRMATION_TIMEOUT;
+
+        loop {
+            match rpc.get_signature_statuses(&[signature]) {
+                Ok(response) => {
+                    if let Some(Some(status)) = response.value.into_iter().next() {
+                        if let Some(err) = status.err {
+                            return (Some(status.slot), false, Some(err.to_string()));
+                        }
+                        let reached = status.confirmation_status
+                            .as_ref()
+                            .map(|c| commitment_rank(&format!("{c:?}")) >= target_rank)
+                            .unwrap_or(false);
+                        if reached {
+                            return (Some(status.slot), true, None);
+                        }
+                    }
+                }
+                Err(e) => return (None, false, Some(format!("failed to fetch signature status: {e}"))),
+            }
+
+            if tokio::time::Instant::now() >= deadline {
+                return (None, false, Some("timed out waiting for transaction confirmation".to_string()));
+            }
+            tokio::time::sleep(CONFIRMATION_POLL_INTERVAL).await;
         }
     }
 
@@ -211,12 +854,19 @@ impl PumpFunService {
         });
 
         match pumpfun.create(mint_keypair.insecure_clone(), metadata, fee).await {
-            Ok(signature) => Ok(TransactionResult {
-                signature: signature.to_string(),
-                success: true,
-                error: None,
-                slot: None,
-            }),
+            Ok(signature) => {
+                if request.confirm.unwrap_or(false) {
+                    let (slot, success, error) = self.confirm_transaction(&signature.to_string()).await;
+                    Ok(TransactionResult { signature: signature.to_string(), success, error, slot })
+                } else {
+                    Ok(TransactionResult {
+                        signature: signature.to_string(),
+                        success: true,
+                        error: None,
+                        slot: None,
+                    })
+                }
+            }
             Err(e) => Ok(TransactionResult {
                 signature: String::new(),
                 success: false,
@@ -254,12 +904,19 @@ impl PumpFunService {
         let lamports = (request.sol_amount * LAMPORTS_PER_SOL as f64) as u64;
 
         match pumpfun.create_and_buy(mint_keypair.insecure_clone(), metadata, lamports, request.slippage_bps, fee).await {
-            Ok(signature) => Ok(TransactionResult {
-                signature: signature.to_string(),
-                success: true,
-                error: None,
-                slot: None,
-            }),
+            Ok(signature) => {
+                if request.confirm.unwrap_or(false) {
+                    let (slot, success, error) = self.confirm_transaction(&signature.to_string()).await;
+                    Ok(TransactionResult { signature: signature.to_string(), success, error, slot })
+                } else {
+                    Ok(TransactionResult {
+                        signature: signature.to_string(),
+                        success: true,
+                        error: None,
+                        slot: None,
+                    })
+                }
+            }
             Err(e) => Ok(TransactionResult {
                 signature: String::new(),
                 success: false,
@@ -276,6 +933,11 @@ impl PumpFunService {
         let mint_pubkey = request.mint_address.parse::<Pubkey>()
             .map_err(|e| ApiError::InvalidInput(format!("Invalid mint address: {e}")))?;
 
+        self.check_price_tolerance(
+            &mint_pubkey, &request.mint_address, BondingCurveSide::Buy,
+            request.sol_amount, request.slippage_bps, request.price_tolerance_bps,
+        ).await?;
+
         // Create priority fee
         let fee = Some(PriorityFee {
             unit_limit: self.config.priority_fee.unit_limit,
@@ -286,12 +948,19 @@ impl PumpFunService {
         let lamports = (request.sol_amount * LAMPORTS_PER_SOL as f64) as u64;
 
         match pumpfun.buy(mint_pubkey, lamports, request.slippage_bps, fee).await {
-            Ok(signature) => Ok(TransactionResult {
-                signature: signature.to_string(),
-                success: true,
-                error: None,
-                slot: None,
-            }),
+            Ok(signature) => {
+                if request.confirm.unwrap_or(false) {
+                    let (slot, success, error) = self.confirm_transaction(&signature.to_string()).await;
+                    Ok(TransactionResult { signature: signature.to_string(), success, error, slot })
+                } else {
+                    Ok(TransactionResult {
+                        signature: signature.to_string(),
+                        success: true,
+                        error: None,
+                        slot: None,
+                    })
+                }
+            }
             Err(e) => Ok(TransactionResult {
                 signature: String::new(),
                 success: false,
@@ -308,19 +977,42 @@ impl PumpFunService {
         let mint_pubkey = request.mint_address.parse::<Pubkey>()
             .map_err(|e| ApiError::InvalidInput(format!("Invalid mint address: {e}")))?;
 
+        // A full-position sell (`token_amount: None`) sizes itself from the wallet's real
+        // on-chain balance rather than leaving that to the SDK/caller.
+        let token_amount = match request.token_amount {
+            Some(token_amount) => token_amount,
+            None => {
+                let balance = self.get_token_balance(&request.mint_address).await?;
+                balance.amount.parse::<u64>()
+                    .map_err(|e| ApiError::External(format!("Failed to parse token balance: {e}")))?
+            }
+        };
+
+        self.check_price_tolerance(
+            &mint_pubkey, &request.mint_address, BondingCurveSide::Sell,
+            token_amount as f64, request.slippage_bps, request.price_tolerance_bps,
+        ).await?;
+
         // Create priority fee
         let fee = Some(PriorityFee {
             unit_limit: self.config.priority_fee.unit_limit,
             unit_price: self.config.priority_fee.unit_price,
         });
 
-        match pumpfun.sell(mint_pubkey, request.token_amount, request.slippage_bps, fee).await {
-            Ok(signature) => Ok(TransactionResult {
-                signature: signature.to_string(),
-                success: true,
-                error: None,
-                slot: None,
-            }),
+        match pumpfun.sell(mint_pubkey, Some(token_amount), request.slippage_bps, fee).await {
+            Ok(signature) => {
+                if request.confirm.unwrap_or(false) {
+                    let (slot, success, error) = self.confirm_transaction(&signature.to_string()).await;
+                    Ok(TransactionResult { signature: signature.to_string(), success, error, slot })
+                } else {
+                    Ok(TransactionResult {
+                        signature: signature.to_string(),
+                        success: true,
+                        error: None,
+                        slot: None,
+                    })
+                }
+            }
             Err(e) => Ok(TransactionResult {
                 signature: String::new(),
                 success: false,
@@ -330,6 +1022,36 @@ impl PumpFunService {
         }
     }
 
+    /// Reads the configured wallet's associated token account for `mint_address` straight off
+    /// RPC and returns it in the same `amount`/`decimals`/`ui_amount`/`ui_amount_string` shape
+    /// `getTokenAccountBalance` does. Used by [`Self::sell_token`] to size a full-position sell
+    /// from the real on-chain balance rather than trusting a caller-supplied amount.
+    pub async fn get_token_balance(&self, mint_address: &str) -> Result<TokenBalance, ApiError> {
+        let private_key = self.config.private_key.as_ref()
+            .ok_or_else(|| ApiError::Configuration("PumpFun client not initialized".to_string()))?;
+        let owner = Keypair::from_base58_string(private_key).pubkey();
+
+        let mint = mint_address.parse::<Pubkey>()
+            .map_err(|e| ApiError::InvalidInput(format!("Invalid mint address: {e}")))?;
+        let token_program: Pubkey = SPL_TOKEN_PROGRAM_ID.parse().expect("valid constant pubkey");
+        let ata_program: Pubkey = ASSOCIATED_TOKEN_PROGRAM_ID.parse().expect("valid constant pubkey");
+        let (ata, _bump) = Pubkey::find_program_address(
+            &[owner.as_ref(), token_program.as_ref(), mint.as_ref()],
+            &ata_program,
+        );
+
+        let rpc = RpcClient::new(self.config.rpc_url.clone());
+        let balance = rpc.get_token_account_balance(&ata)
+            .map_err(|e| ApiError::External(format!("Failed to get token balance: {e}")))?;
+
+        Ok(TokenBalance {
+            amount: balance.amount,
+            decimals: balance.decimals,
+            ui_amount: balance.ui_amount.unwrap_or(0.0),
+            ui_amount_string: balance.ui_amount_string,
+        })
+    }
+
     pub async fn get_bonding_curve(&self, mint_address: &str) -> Result<BondingCurveInfo, ApiError> {
         let pumpfun = self.pumpfun.as_ref()
             .ok_or_else(|| ApiError::Configuration("PumpFun client not initialized".to_string()))?;
@@ -351,27 +1073,63 @@ impl PumpFunService {
         }
     }
 
-    pub async fn subscribe_events<F>(&self, callback: F) -> Result<(), ApiError>
-    where
-        F: Fn(PumpFunEvent) + Send + Sync + 'static,
-    {
-        let pumpfun = self.pumpfun.as_ref()
-            .ok_or_else(|| ApiError::Configuration("PumpFun client not initialized".to_string()))?;
+    /// Starts the single underlying SDK subscription that feeds every converted
+    /// [`PumpFunEvent`] into `self.events_tx` -- idempotent, so it's safe to call from every
+    /// [`Self::subscribe_stream`] invocation without opening a second upstream subscription.
+    async fn ensure_event_subscription(self: &Arc<Self>) -> Result<(), ApiError> {
+        if self.events_subscribed.swap(true, std::sync::atomic::Ordering::SeqCst) {
+            return Ok(());
+        }
 
+        let pumpfun = match self.pumpfun.as_ref() {
+            Some(pumpfun) => pumpfun,
+            None => {
+                self.events_subscribed.store(false, std::sync::atomic::Ordering::SeqCst);
+                return Err(ApiError::Configuration("PumpFun client not initialized".to_string()));
+            }
+        };
+
+        let events_tx = self.events_tx.clone();
         let subscription_callback = move |signature: String, event: Option<SdkPumpFunEvent>, error: Option<Box<dyn std::error::Error>>, _response| {
             let api_event = Self::convert_sdk_event_to_api_event(signature, event, error.map(|e| e.to_string()));
-            callback(api_event);
+            let _ = events_tx.send(api_event);
         };
 
         match pumpfun.subscribe(None, subscription_callback).await {
-            Ok(_subscription) => {
-                // The subscription is now active and will continue until dropped
-                Ok(())
-            },
-            Err(e) => Err(ApiError::External(format!("Failed to subscribe to events: {e}"))),
+            Ok(_subscription) => Ok(()),
+            Err(e) => {
+                self.events_subscribed.store(false, std::sync::atomic::Ordering::SeqCst);
+                Err(ApiError::External(format!("Failed to subscribe to events: {e}")))
+            }
         }
     }
 
+    /// Live [`PumpFunEvent`] stream shared across every caller and narrowed by `filter` so a
+    /// client watching one token isn't flooded with the full firehose. A subscriber whose
+    /// buffer falls behind the shared channel's capacity just skips the events it missed
+    /// (`RecvError::Lagged`) rather than blocking the producer or any other subscriber.
+    pub async fn subscribe_stream(
+        self: &Arc<Self>,
+        filter: PumpFunEventFilter,
+    ) -> Result<impl futures::Stream<Item = PumpFunEvent>, ApiError> {
+        self.ensure_event_subscription().await?;
+
+        let mut rx = self.events_tx.subscribe();
+        Ok(async_stream::stream! {
+            loop {
+                match rx.recv().await {
+                    Ok(event) => {
+                        if filter.matches(&event) {
+                            yield event;
+                        }
+                    }
+                    Err(tokio::sync::broadcast::error::RecvError::Lagged(_)) => continue,
+                    Err(tokio::sync::broadcast::error::RecvError::Closed) => break,
+                }
+            }
+        })
+    }
+
     // Helper method to convert SDK events to API events
     pub fn convert_sdk_event_to_api_event(
         signature: String,
@@ -407,7 +1165,7 @@ impl PumpFunService {
                     is_buy: trade_event.is_buy,
                     sol_amount: trade_event.sol_amount as f64 / LAMPORTS_PER_SOL as f64,
                     token_amount: trade_event.token_amount,
-                    price: 0.0, // Would need to be calculated
+                    price: spot_price_sol(trade_event.virtual_sol_reserves, trade_event.virtual_token_reserves),
                     timestamp: Some(chrono::Utc::now().to_rfc3339()),
                     trade_type: "user".to_string(),
                 }),
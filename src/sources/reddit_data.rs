@@ -1,12 +1,65 @@
 use roux::Subreddit;
 use serde_json::{json, Value};
-use std::collections::HashSet;
+use std::collections::{HashMap, HashSet};
 use regex::Regex;
 use std::env;
-pub async fn get_reddit_trending_stocks() -> Vec<String> {
+use lazy_static::lazy_static;
+
+use crate::config::RetryConfig;
+use crate::sources::symbol_universe;
+use crate::utils::with_retry;
+
+lazy_static! {
+    /// Process-wide retry policy for roux (Reddit) calls, read once from `RETRY_*` env vars (see
+    /// [`RetryConfig::from_env`]) so operators can tune it per environment without a redeploy.
+    static ref REDDIT_RETRY_POLICY: RetryConfig = RetryConfig::from_env();
+}
+
+/// roux's errors are all network/upstream in nature (there's no local parsing step to distinguish
+/// from a permanent failure), so every error is treated as transient and worth a retry.
+fn is_permanent_reddit_error<E>(_err: &E) -> bool {
+    false
+}
+
+lazy_static! {
+    /// Explicit `$TICKER` cashtags are a strong, low-noise signal -- whoever wrote it meant a
+    /// ticker -- so these are counted unconditionally, without checking the exchange allowlist.
+    static ref CASHTAG_RE: Regex = Regex::new(r"\$([A-Z]{1,5})\b").unwrap();
+    /// Bare uppercase candidates ("AAPL", but also "CEO", "USA", "YOLO") are only counted once
+    /// validated against the loaded exchange symbol allowlist (see `sources::symbol_universe`).
+    static ref BARE_TICKER_RE: Regex = Regex::new(r"\b[A-Z]{1,5}\b").unwrap();
+}
+
+/// Two-tier ticker extraction over a block of uppercased text: `$TICKER` cashtags are counted
+/// unconditionally, then every remaining bare uppercase word is counted only if it's a real
+/// symbol in `universe` -- this is what keeps "CEO", "USA", "YOLO" out without a hand-maintained
+/// ignore list that can never keep up with every capitalized word in English.
+fn extract_tickers(text: &str, universe: &HashSet<String>, mentions: &mut HashMap<String, u32>) {
+    let mut cashtagged: HashSet<String> = HashSet::new();
+    for cap in CASHTAG_RE.captures_iter(text) {
+        let ticker = cap[1].to_string();
+        cashtagged.insert(ticker.clone());
+        *mentions.entry(ticker).or_insert(0) += 1;
+    }
+
+    for m in BARE_TICKER_RE.find_iter(text) {
+        let ticker = m.as_str();
+        if cashtagged.contains(ticker) {
+            continue; // already counted via its cashtag above
+        }
+        if universe.contains(ticker) {
+            *mentions.entry(ticker.to_string()).or_insert(0) += 1;
+        }
+    }
+}
+
+/// Scrapes configured subreddits and counts each ticker *mention*, not just presence. Callers that
+/// only want membership (`get_reddit_trending_stocks`) collapse this to its keys, while
+/// `services::trends::TrendStore` needs the true per-scrape counts to compute mention velocity.
+pub async fn scrape_reddit_mentions() -> HashMap<String, u32> {
     println!("\n🔍 Scraping Reddit for trending stocks...");
 
-    let mut reddit_stocks: HashSet<String> = HashSet::new();
+    let mut mentions: HashMap<String, u32> = HashMap::new();
 
     // Load credentials from environment variables
     let client_id = env::var("REDDIT_CLIENT_ID").unwrap_or_default();
@@ -15,46 +68,36 @@ pub async fn get_reddit_trending_stocks() -> Vec<String> {
 
     if client_id.is_empty() || client_secret.is_empty() {
         println!("  Reddit credentials not found in environment variables. Skipping Reddit scraping.");
-        return vec![];
+        return HashMap::new();
     }
 
     // Define subreddits to scrape
     let subreddits = vec!["wallstreetbets", "stocks", "investing"];
 
-    // Regex for stock tickers
-    let ticker_re = Regex::new(r"\b[A-Z]{1,5}\b").unwrap();
-
-    // Common words to ignore
-    let ignore_words: HashSet<&'static str> = [
-        "THE", "AND", "FOR", "YOU", "ARE", "WAS", "HAS", "HAD", "NOT", "BUT", "ALL", "CAN", "HER",
-        "WERE", "SHE", "HIS", "ONE", "SAID", "THEY", "EACH", "WHICH", "DO", "HOW", "THEIR", "IF",
-        "WILL", "UP", "OTHER", "ABOUT", "OUT", "MANY", "THEN", "THEM", "THESE", "SO", "SOME",
-        "WOULD", "MAKE", "LIKE", "INTO", "HIM", "TIME", "TWO", "MORE", "GO", "NO", "WAY", "COULD",
-        "MY", "THAN", "FIRST", "BEEN", "CALL", "WHO", "ITS", "NOW", "FIND", "LONG", "DOWN", "DAY",
-        "DID", "GET", "COME", "MADE", "MAY", "PART"
-    ]
-    .iter()
-    .cloned()
-    .collect();
+    // Snapshot the exchange symbol allowlist once per scrape rather than re-locking it per
+    // candidate ticker (see `sources::symbol_universe`).
+    let universe = symbol_universe::snapshot().await;
 
     // Loop through subreddits
     for subreddit_name in subreddits {
         println!("  Scraping r/{subreddit_name}...");
 
         let subreddit = Subreddit::new(subreddit_name);
-        match subreddit.hot(20, None).await {
+        let listing = with_retry(
+            &REDDIT_RETRY_POLICY,
+            &format!("reddit hot listing for r/{subreddit_name}"),
+            is_permanent_reddit_error,
+            || subreddit.hot(20, None),
+        )
+        .await;
+        match listing {
             Ok(listing) => {
                 for post in listing.data.children {
                     let title = post.data.title.to_uppercase();
                     let text = post.data.selftext.to_uppercase();
                     let combined = format!("{title} {text}");
 
-                    for cap in ticker_re.find_iter(&combined) {
-                        let ticker = cap.as_str();
-                        if !ignore_words.contains(ticker) {
-                            reddit_stocks.insert(ticker.to_string());
-                        }
-                    }
+                    extract_tickers(&combined, &universe, &mut mentions);
                 }
             }
             Err(e) => {
@@ -64,7 +107,15 @@ pub async fn get_reddit_trending_stocks() -> Vec<String> {
         }
     }
 
-    reddit_stocks.into_iter().collect()
+    mentions
+}
+
+/// Tickers mentioned on the configured subreddits, ranked by mention count (highest first) so
+/// callers can weight by mention strength instead of treating membership as a flat, unordered set.
+pub async fn get_reddit_trending_stocks() -> Vec<(String, u32)> {
+    let mut ranked: Vec<(String, u32)> = scrape_reddit_mentions().await.into_iter().collect();
+    ranked.sort_by(|a, b| b.1.cmp(&a.1));
+    ranked
 }
 
 pub async fn get_reddit_news() -> Result<Value, String> {
@@ -94,10 +145,14 @@ pub async fn get_reddit_news() -> Result<Value, String> {
 /// Returns a JSON array of simplified posts with key fields.
 pub async fn get_subreddit_new_posts(subreddit_name: &str, limit: usize) -> Result<Value, String> {
     let subreddit = Subreddit::new(subreddit_name);
-    let listing = subreddit
-        .latest(limit as u32, None)
-        .await
-        .map_err(|e| format!("reddit new fetch error for r/{subreddit_name}: {e}"))?;
+    let listing = with_retry(
+        &REDDIT_RETRY_POLICY,
+        &format!("reddit new listing for r/{subreddit_name}"),
+        is_permanent_reddit_error,
+        || subreddit.latest(limit as u32, None),
+    )
+    .await
+    .map_err(|e| format!("reddit new fetch error for r/{subreddit_name}: {e}"))?;
 
     let mut posts: Vec<Value> = Vec::new();
     for child in listing.data.children {
@@ -0,0 +1,68 @@
+use std::collections::HashSet;
+
+use lazy_static::lazy_static;
+use reqwest::Client;
+use tokio::sync::RwLock;
+
+/// NASDAQ's own listing directory: pipe-delimited, one header row and one trailing
+/// "File Creation Time" footer row, `Symbol` is column 0.
+const NASDAQ_LISTED_URL: &str = "https://www.nasdaqtrader.com/dynamic/SymDir/nasdaqlisted.txt";
+/// Same directory's listing for everything NASDAQ itself doesn't list (NYSE, NYSE American,
+/// NYSE Arca, ...); `ACT Symbol` is column 0.
+const OTHER_LISTED_URL: &str = "https://www.nasdaqtrader.com/dynamic/SymDir/otherlisted.txt";
+
+lazy_static! {
+    /// Process-wide allowlist of real exchange ticker symbols, used to tell genuine tickers
+    /// apart from capitalized noise words ("CEO", "USA", "YOLO", ...) in free-text scraping.
+    /// Starts empty; [`refresh`] populates it at startup and the scheduler's
+    /// `symbol_universe_refresh` job (see `main.rs`) keeps it warm from there, so a cold or
+    /// failed fetch degrades to "nothing bare-word matches" rather than panicking.
+    static ref SYMBOLS: RwLock<HashSet<String>> = RwLock::new(HashSet::new());
+}
+
+/// Fetches both NASDAQ SymDir listing files and replaces the in-memory allowlist wholesale.
+/// Returns the resulting symbol count so the caller (startup, or the periodic refresh job) can
+/// log it.
+pub async fn refresh(http: &Client) -> Result<usize, String> {
+    let mut merged = fetch_and_parse(http, NASDAQ_LISTED_URL).await?;
+    merged.extend(fetch_and_parse(http, OTHER_LISTED_URL).await?);
+    let count = merged.len();
+
+    *SYMBOLS.write().await = merged;
+    Ok(count)
+}
+
+async fn fetch_and_parse(http: &Client, url: &str) -> Result<HashSet<String>, String> {
+    let body = http
+        .get(url)
+        .send()
+        .await
+        .map_err(|e| format!("failed to fetch {url}: {e}"))?
+        .text()
+        .await
+        .map_err(|e| format!("failed to read {url}: {e}"))?;
+    Ok(parse_listing(&body))
+}
+
+/// Parses a NASDAQ SymDir pipe-delimited file: skips the header row and the trailing
+/// "File Creation Time" footer row, takes the first (symbol) column from every remaining row.
+fn parse_listing(body: &str) -> HashSet<String> {
+    body.lines()
+        .skip(1)
+        .filter(|line| !line.starts_with("File Creation Time"))
+        .filter_map(|line| line.split('|').next())
+        .map(|s| s.trim().to_uppercase())
+        .filter(|s| !s.is_empty())
+        .collect()
+}
+
+/// Snapshot of the current allowlist for a single scrape pass, so callers hold the lock once
+/// instead of re-acquiring it per candidate ticker across a whole post's worth of regex matches.
+pub async fn snapshot() -> HashSet<String> {
+    SYMBOLS.read().await.clone()
+}
+
+/// Number of symbols currently loaded; `0` before the first successful [`refresh`].
+pub async fn len() -> usize {
+    SYMBOLS.read().await.len()
+}
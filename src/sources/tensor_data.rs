@@ -0,0 +1,40 @@
+use serde::Deserialize;
+
+const TENSOR_API_BASE: &str = "https://api.tensor.so";
+
+/// Floor/volume/listed-count for a single collection, as reported by Tensor's public
+/// marketplace API (the kind of listing/offer data a Solana NFT marketplace SDK exposes).
+#[derive(Debug, Clone, Default)]
+pub struct CollectionMarketStats {
+    pub floor_price: Option<f64>,
+    pub volume_24h: Option<f64>,
+    pub listed_count: Option<u32>,
+}
+
+#[derive(Debug, Deserialize)]
+struct TensorStatsResponse {
+    #[serde(rename = "floorPrice")]
+    floor_price: Option<f64>,
+    #[serde(rename = "volume24h")]
+    volume_24h: Option<f64>,
+    #[serde(rename = "numListed")]
+    num_listed: Option<u32>,
+}
+
+/// Fetch floor price / 24h volume / listed count for a collection from Tensor. Returns
+/// `None` (never an error) on any network or parse failure, so a marketplace outage just
+/// leaves that collection's stats blank rather than failing the whole aggregation.
+pub async fn fetch_collection_stats(client: &reqwest::Client, collection_id: &str) -> Option<CollectionMarketStats> {
+    let url = format!("{TENSOR_API_BASE}/api/v1/collections/{collection_id}/stats");
+    let response = client.get(&url).send().await.ok()?;
+    if !response.status().is_success() {
+        return None;
+    }
+    let stats: TensorStatsResponse = response.json().await.ok()?;
+
+    Some(CollectionMarketStats {
+        floor_price: stats.floor_price.map(|lamports| lamports / 1_000_000_000.0),
+        volume_24h: stats.volume_24h.map(|lamports| lamports / 1_000_000_000.0),
+        listed_count: stats.num_listed,
+    })
+}
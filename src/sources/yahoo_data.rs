@@ -5,7 +5,22 @@ use std::collections::HashSet;
 use serde_json::Value;
 use reqwest::Client;
 
+/// Per-call timeout for the Yahoo fetches instrumented via [`crate::metrics::observe`], matching
+/// [`crate::helpers::resilient_fetch::ResilientFetch`]'s 10s default per-fetcher timeout.
+const YAHOO_CALL_TIMEOUT: std::time::Duration = std::time::Duration::from_secs(10);
+
+/// Scrapes Yahoo's trending-tickers page for all-caps 1-5 letter symbols. Latency/outcome
+/// (ok/timeout/error) is recorded against the `"yahoo"` source via [`crate::metrics::observe`],
+/// the same instrumentation `helpers::news`'s `ResilientFetch` pipeline uses; a failed or timed
+/// out fetch still returns an empty list rather than propagating an error, matching this
+/// function's existing infallible signature.
 pub async fn get_trending_from_yahoo() -> Vec<String> {
+    crate::metrics::observe("yahoo", YAHOO_CALL_TIMEOUT, get_trending_from_yahoo_inner())
+        .await
+        .unwrap_or_default()
+}
+
+async fn get_trending_from_yahoo_inner() -> Result<Vec<String>, String> {
     let mut trending_stocks: HashSet<String> = HashSet::new();
 
     // Common headers
@@ -21,30 +36,31 @@ pub async fn get_trending_from_yahoo() -> Vec<String> {
         .build()
         .unwrap();
 
-    if let Ok(resp) = client
+    let resp = client
         .get("https://finance.yahoo.com/trending-tickers")
         .send()
         .await
-    {
-        if let Ok(body) = resp.text().await {
-            let document = Html::parse_document(&body);
-            let selector = Selector::parse("td, a, span, div").unwrap();
-            for element in document.select(&selector) {
-                let text = element.text().collect::<String>().trim().to_string();
-                if !text.is_empty()
-                    && text.len() <= 5
-                    && text.chars().all(|c| c.is_ascii_uppercase())
-                {
-                    trending_stocks.insert(text);
-                }
-            }
+        .map_err(|e| format!("yahoo trending-tickers req error: {e}"))?;
+    let body = resp.text().await.map_err(|e| format!("yahoo trending-tickers body error: {e}"))?;
+
+    let document = Html::parse_document(&body);
+    let selector = Selector::parse("td, a, span, div").unwrap();
+    for element in document.select(&selector) {
+        let text = element.text().collect::<String>().trim().to_string();
+        if !text.is_empty() && text.len() <= 5 && text.chars().all(|c| c.is_ascii_uppercase()) {
+            trending_stocks.insert(text);
         }
     }
 
-    trending_stocks.into_iter().collect()
+    Ok(trending_stocks.into_iter().collect())
 }
 
+/// Instrumented the same way as [`get_trending_from_yahoo`]; see its doc comment.
 pub async fn yahoo_predefined_list(scr_id: &str, count: usize) -> Result<Vec<String>, String> {
+    crate::metrics::observe("yahoo", YAHOO_CALL_TIMEOUT, yahoo_predefined_list_inner(scr_id, count)).await
+}
+
+async fn yahoo_predefined_list_inner(scr_id: &str, count: usize) -> Result<Vec<String>, String> {
     let url = format!(
         "https://query1.finance.yahoo.com/v1/finance/screener/predefined/saved?count={count}&scrIds={scr_id}"
     );
@@ -78,7 +94,12 @@ pub async fn yahoo_predefined_list(scr_id: &str, count: usize) -> Result<Vec<Str
     Ok(out)
 }
 
+/// Instrumented the same way as [`get_trending_from_yahoo`]; see its doc comment.
 pub async fn yahoo_trending(region: &str, count: usize) -> Result<Vec<String>, String> {
+    crate::metrics::observe("yahoo", YAHOO_CALL_TIMEOUT, yahoo_trending_inner(region, count)).await
+}
+
+async fn yahoo_trending_inner(region: &str, count: usize) -> Result<Vec<String>, String> {
     let url = format!(
         "https://query1.finance.yahoo.com/v1/finance/trending/{region}?count={count}"
     );
@@ -112,7 +133,12 @@ pub async fn yahoo_trending(region: &str, count: usize) -> Result<Vec<String>, S
     Ok(out)
 }
 
+/// Instrumented the same way as [`get_trending_from_yahoo`]; see its doc comment.
 pub async fn fetch_yahoo_options_chain(symbol: &str) -> Result<Value, String> {
+    crate::metrics::observe("yahoo", YAHOO_CALL_TIMEOUT, fetch_yahoo_options_chain_inner(symbol)).await
+}
+
+async fn fetch_yahoo_options_chain_inner(symbol: &str) -> Result<Value, String> {
     let url = format!("https://query2.finance.yahoo.com/v7/finance/options/{symbol}");
     let resp = Client::new()
         .get(url)
@@ -127,6 +153,5 @@ pub async fn fetch_yahoo_options_chain(symbol: &str) -> Result<Value, String> {
 }
 
 // pub async fn get_news() -> Result<Value, String> {
-    
-// }
 
+// }
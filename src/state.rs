@@ -5,7 +5,26 @@ use yahoo_finance_api::YahooConnector;
 use crate::config::Config;
 use crate::cache::MemoryCache;
 use crate::middleware::RateLimiter;
+use crate::middleware::redis_rate_limit::DeferredRateLimiter;
+use crate::monitoring::{PrometheusMetrics, SystemMonitor};
 use crate::optimized_client::OptimizedApiClient;
+use crate::services::backfill::BackfillTracker;
+use crate::services::candles::CandleStore;
+use crate::services::health::{HealthRegistry, IncidentLog};
+use crate::services::history::HistoryStore;
+use crate::services::live_feed::LiveFeedHub;
+use crate::services::market_store::MarketStore;
+use crate::services::position_manager::PositionManager;
+use crate::services::trends::TrendStore;
+use crate::sources::coinbase_data::CoinbaseDataSource;
+use crate::sources::finviz_cache::FinvizScrapeCache;
+use crate::sources::finviz_data::ScreenerStreamHub;
+use crate::sources::helius_data::{SolanaPubsubHub, SolanaWsHub, TransactionTracker};
+use crate::sources::hyperliquid_data::{HyperliquidDataSource, HyperliquidWsHub};
+use crate::sources::kraken_data::{KrakenOrderBookHub, KrakenWsHub};
+use crate::sources::kraken_ws::KrakenSnapshotHub;
+use crate::sources::alpaca_data::{AlpacaDataSource, AlpacaWsHub};
+use crate::sources::pumpfun_data::PumpFunService;
 
 #[derive(Clone)]
 pub struct AppState {
@@ -16,6 +35,32 @@ pub struct AppState {
     pub cache: Arc<MemoryCache>,
     pub rate_limiter: Arc<RateLimiter>,
     pub optimized_client: OptimizedApiClient,
+    pub data_rate_limiter: Arc<DeferredRateLimiter>,
+    pub solana_ws_hub: Arc<SolanaWsHub>,
+    pub solana_pubsub_hub: Arc<SolanaPubsubHub>,
+    pub transaction_tracker: Arc<TransactionTracker>,
+    pub finviz_cache: Arc<FinvizScrapeCache>,
+    pub screener_stream_hub: Arc<ScreenerStreamHub>,
+    pub history_store: Arc<HistoryStore>,
+    pub kraken_ws_hub: Arc<KrakenWsHub>,
+    pub alpaca_ws_hub: Arc<AlpacaWsHub>,
+    pub candle_store: Arc<CandleStore>,
+    pub backfill_tracker: Arc<BackfillTracker>,
+    pub prometheus_metrics: Arc<PrometheusMetrics>,
+    pub health_registry: Arc<HealthRegistry>,
+    pub incident_log: Arc<IncidentLog>,
+    pub system_monitor: Arc<SystemMonitor>,
+    pub kraken_snapshot_hub: Arc<KrakenSnapshotHub>,
+    pub kraken_book_hub: Arc<KrakenOrderBookHub>,
+    pub hyperliquid_ws_hub: Arc<HyperliquidWsHub>,
+    pub hyperliquid: Arc<HyperliquidDataSource>,
+    pub coinbase: Arc<CoinbaseDataSource>,
+    pub alpaca: Arc<AlpacaDataSource>,
+    pub trend_store: Arc<TrendStore>,
+    pub market_store: Arc<MarketStore>,
+    pub pumpfun_service: Arc<PumpFunService>,
+    pub position_manager: Arc<PositionManager>,
+    pub live_feed_hub: Arc<LiveFeedHub>,
 }
 
 
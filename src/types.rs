@@ -19,6 +19,22 @@ pub struct YahooQuery {
     pub rf_annual: Option<f64>,
     pub target_return_annual: Option<f64>,
     pub periods_per_year: Option<usize>,
+    /// Weight for Sharpe ratio in composite score (default: 0.4)
+    pub sharpe_w: Option<f64>,
+    /// Weight for Sortino ratio in composite score (default: 0.4)
+    pub sortino_w: Option<f64>,
+    /// Weight for Calmar ratio in composite score (default: 0.2)
+    pub calmar_w: Option<f64>,
+    /// Weight for CAGR's direct contribution to composite score (default: 0.0)
+    pub cagr_w: Option<f64>,
+    /// Penalty weight for volatility, subtracted from composite score (default: 0.0)
+    pub volatility_w: Option<f64>,
+    /// Penalty weight for max drawdown, subtracted from composite score (default: 0.0)
+    pub max_drawdown_w: Option<f64>,
+    /// Minimum composite score required to be included in `/recommendations/yahoo` (default: no minimum)
+    pub min_score: Option<f64>,
+    /// Maximum number of symbols `/recommendations/yahoo` returns after filtering/sorting (default: all qualifying)
+    pub top_n: Option<usize>,
 }
 
 #[derive(Deserialize, ToSchema, IntoParams)]
@@ -83,6 +99,12 @@ pub struct OptionContract {
     pub bid_price: Option<f64>,
     pub last_price: Option<f64>,
     pub implied_volatility: Option<f64>,
+    /// Analytic Black-Scholes Greeks, solved from `implied_volatility` once a mid premium and
+    /// spot price are available. Alpaca's contract payload has no such field, so this is always
+    /// `None` straight off the wire and only populated after [`crate::helpers::high_open_interest`]
+    /// merges in priced-and-solved data.
+    #[serde(default)]
+    pub greeks: Option<crate::helpers::options::Greeks>,
 }
 
 #[derive(Clone, Deserialize, Serialize, ToSchema)]
@@ -94,6 +116,11 @@ pub struct OptionPrices {
     pub open_interest: Option<u64>,
     pub open_interest_date: Option<String>,
     pub close_price_date: Option<String>,
+    /// Greeks from Alpaca's options snapshot quote, when available -- `None` when the snapshot
+    /// call fails or omits them, in which case [`crate::helpers::high_open_interest`] falls back
+    /// to solving them analytically against `implied_volatility`.
+    #[serde(default)]
+    pub greeks: Option<crate::helpers::options::Greeks>,
 }
 
 #[derive(Clone, Deserialize, Serialize, ToSchema)]
@@ -124,6 +151,14 @@ pub struct OptionsQuery {
     pub calmar_w: Option<f64>,
     pub min_delta: Option<f64>,
     pub max_delta: Option<f64>,
+    pub min_theta: Option<f64>,
+    pub max_theta: Option<f64>,
+    pub min_vega: Option<f64>,
+    pub max_vega: Option<f64>,
+    pub min_gamma: Option<f64>,
+    pub max_gamma: Option<f64>,
+    pub min_rho: Option<f64>,
+    pub max_rho: Option<f64>,
     pub min_premium: Option<f64>,
     pub max_premium: Option<f64>,
     pub min_volume: Option<u64>,
@@ -147,6 +182,8 @@ pub struct OptionsQuery {
     pub alpaca_limit: Option<u32>,
     pub underlying_top: Option<usize>,
     pub debug: Option<bool>,
+    pub pricing: Option<String>,
+    pub structure: Option<String>,
 }
 
 #[derive(Debug, Clone, Deserialize, Serialize, ToSchema)]
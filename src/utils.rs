@@ -1,17 +1,22 @@
 use std::time::Duration;
+use rand::Rng;
 use tokio::time::sleep;
 use tracing::{warn, error, info};
 use reqwest::Client;
 use serde_json::Value;
 
 use crate::errors::ApiError;
-use crate::config::RetryConfig;
+use crate::config::{BackoffMode, RetryConfig};
+use crate::monitoring::PrometheusMetrics;
 
-/// Retry a function with exponential backoff
+/// Retry a function with exponential backoff. `metrics`, when set, is given one
+/// [`PrometheusMetrics::record_retry_attempt`] call per retry (not per call), so operators can
+/// alert on upstream degradation driven by `RetryConfig`.
 pub async fn retry_with_backoff<F, Fut, T>(
     mut f: F,
     config: &RetryConfig,
     operation_name: &str,
+    metrics: Option<&PrometheusMetrics>,
 ) -> Result<T, ApiError>
 where
     F: FnMut() -> Fut,
@@ -22,7 +27,8 @@ where
     }
 
     let mut last_error = None;
-    
+    let mut prev_delay_ms = config.base_delay_ms;
+
     for attempt in 0..=config.max_retries {
         match f().await {
             Ok(result) => {
@@ -33,9 +39,9 @@ where
             }
             Err(e) => {
                 last_error = Some(e);
-                
+
                 if attempt < config.max_retries {
-                    let delay = calculate_backoff_delay(attempt, config);
+                    let delay = calculate_backoff_delay(attempt, config, &mut prev_delay_ms);
                     warn!(
                         "{} failed (attempt {}/{}), retrying in {:?}: {:?}",
                         operation_name,
@@ -44,21 +50,43 @@ where
                         delay,
                         last_error
                     );
+                    if let Some(metrics) = metrics {
+                        metrics.record_retry_attempt();
+                    }
                     sleep(delay).await;
                 }
             }
         }
     }
-    
+
     Err(last_error.unwrap())
 }
 
-fn calculate_backoff_delay(attempt: u32, config: &RetryConfig) -> Duration {
-    let base_delay = Duration::from_millis(config.base_delay_ms);
-    let max_delay = Duration::from_millis(config.max_delay_ms);
-    
-    let delay = base_delay * 2_u32.pow(attempt);
-    delay.min(max_delay)
+/// Compute the delay before the next retry per `config.backoff_mode`. `prev_delay_ms` is only
+/// read and updated by [`BackoffMode::DecorrelatedJitter`]; it starts at `base_delay_ms` and
+/// carries the previously-chosen delay forward so each attempt's range is anchored to the last,
+/// rather than to the attempt number, which is what keeps concurrent retriers from re-converging.
+fn calculate_backoff_delay(attempt: u32, config: &RetryConfig, prev_delay_ms: &mut u64) -> Duration {
+    let base_delay_ms = config.base_delay_ms;
+    let max_delay_ms = config.max_delay_ms;
+
+    let delay_ms = match config.backoff_mode {
+        BackoffMode::Exponential => {
+            base_delay_ms.saturating_mul(1u64 << attempt.min(16)).min(max_delay_ms)
+        }
+        BackoffMode::FullJitter => {
+            let exp_ms = base_delay_ms.saturating_mul(1u64 << attempt.min(16)).min(max_delay_ms);
+            rand::thread_rng().gen_range(0..=exp_ms.max(1))
+        }
+        BackoffMode::DecorrelatedJitter => {
+            let upper = prev_delay_ms.saturating_mul(3).max(base_delay_ms);
+            let delay = rand::thread_rng().gen_range(base_delay_ms..=upper).min(max_delay_ms);
+            *prev_delay_ms = delay;
+            delay
+        }
+    };
+
+    Duration::from_millis(delay_ms)
 }
 
 /// Validate a ticker symbol
@@ -96,30 +124,143 @@ pub fn is_retryable_error(status: reqwest::StatusCode) -> bool {
     status == reqwest::StatusCode::TOO_MANY_REQUESTS
 }
 
-/// Make an HTTP request with retry logic
+/// Make an HTTP request with retry logic. On a 429/503, honors the upstream's own `Retry-After`
+/// header (clamped to `max_delay_ms`) instead of the computed backoff delay when one is present —
+/// CoinGecko's free tier in particular returns explicit cool-down windows, and sleeping for
+/// exactly that long avoids getting IP-throttled by retrying too early.
 pub async fn make_request_with_retry(
     _client: &Client,
     request_builder: reqwest::RequestBuilder,
     config: &RetryConfig,
     operation_name: &str,
+    metrics: Option<&PrometheusMetrics>,
 ) -> Result<reqwest::Response, ApiError> {
-    retry_with_backoff(
-        || async {
-            let response = request_builder.try_clone()
-                .ok_or_else(|| ApiError::InternalError("Cannot clone request".to_string()))?
-                .send()
-                .await
-                .map_err(|e| ApiError::Upstream(e.to_string()))?;
-            
-            if is_retryable_error(response.status()) {
-                return Err(ApiError::Upstream(format!("HTTP {}: {}", response.status(), response.status().canonical_reason().unwrap_or("Unknown"))));
+    if !config.enabled {
+        let request = request_builder.try_clone()
+            .ok_or_else(|| ApiError::InternalError("Cannot clone request".to_string()))?;
+        return request.send().await.map_err(|e| ApiError::Upstream(e.to_string()));
+    }
+
+    let max_delay = Duration::from_millis(config.max_delay_ms);
+    let mut last_error = None;
+    let mut prev_delay_ms = config.base_delay_ms;
+
+    for attempt in 0..=config.max_retries {
+        let request = request_builder.try_clone()
+            .ok_or_else(|| ApiError::InternalError("Cannot clone request".to_string()))?;
+        match request.send().await {
+            Ok(response) if !is_retryable_error(response.status()) => {
+                if attempt > 0 {
+                    info!("{} succeeded after {} retries", operation_name, attempt);
+                }
+                return Ok(response);
             }
-            
-            Ok(response)
-        },
-        config,
-        operation_name,
-    ).await
+            Ok(response) => {
+                let status = response.status();
+                let retry_after = if status == reqwest::StatusCode::TOO_MANY_REQUESTS || status.as_u16() == 503 {
+                    crate::http_client::parse_retry_after(response.headers())
+                } else {
+                    None
+                };
+                last_error = Some(ApiError::Upstream(format!("HTTP {status}: {}", status.canonical_reason().unwrap_or("Unknown"))));
+
+                if attempt < config.max_retries {
+                    let delay = retry_after
+                        .map(|d| d.min(max_delay))
+                        .unwrap_or_else(|| calculate_backoff_delay(attempt, config, &mut prev_delay_ms));
+                    warn!(
+                        "{} failed (attempt {}/{}), retrying in {:?}: {:?}",
+                        operation_name,
+                        attempt + 1,
+                        config.max_retries + 1,
+                        delay,
+                        last_error
+                    );
+                    if let Some(metrics) = metrics {
+                        metrics.record_retry_attempt();
+                    }
+                    sleep(delay).await;
+                }
+            }
+            Err(e) => {
+                last_error = Some(ApiError::Upstream(e.to_string()));
+
+                if attempt < config.max_retries {
+                    let delay = calculate_backoff_delay(attempt, config, &mut prev_delay_ms);
+                    warn!(
+                        "{} failed (attempt {}/{}), retrying in {:?}: {:?}",
+                        operation_name,
+                        attempt + 1,
+                        config.max_retries + 1,
+                        delay,
+                        last_error
+                    );
+                    if let Some(metrics) = metrics {
+                        metrics.record_retry_attempt();
+                    }
+                    sleep(delay).await;
+                }
+            }
+        }
+    }
+
+    Err(last_error.unwrap())
+}
+
+/// Retry a fallible operation whose error type isn't `ApiError` (Yahoo's `Result<_, String>`,
+/// roux's reddit client, etc). Unlike [`retry_with_backoff`], the caller classifies its own
+/// errors via `is_permanent`, so a deterministic failure (a parse error, "not enough quotes")
+/// returns immediately on the first attempt instead of being retried against a policy that can't
+/// fix it -- only errors judged transient consume the retry budget.
+pub async fn with_retry<F, Fut, T, E>(
+    policy: &RetryConfig,
+    operation_name: &str,
+    is_permanent: impl Fn(&E) -> bool,
+    mut op: F,
+) -> Result<T, E>
+where
+    F: FnMut() -> Fut,
+    Fut: std::future::Future<Output = Result<T, E>>,
+    E: std::fmt::Debug,
+{
+    if !policy.enabled {
+        return op().await;
+    }
+
+    let mut last_error = None;
+    let mut prev_delay_ms = policy.base_delay_ms;
+
+    for attempt in 0..=policy.max_retries {
+        match op().await {
+            Ok(result) => {
+                if attempt > 0 {
+                    info!("{} succeeded after {} retries", operation_name, attempt);
+                }
+                return Ok(result);
+            }
+            Err(e) => {
+                if is_permanent(&e) {
+                    return Err(e);
+                }
+
+                last_error = Some(e);
+                if attempt < policy.max_retries {
+                    let delay = calculate_backoff_delay(attempt, policy, &mut prev_delay_ms);
+                    warn!(
+                        "{} failed (attempt {}/{}), retrying in {:?}: {:?}",
+                        operation_name,
+                        attempt + 1,
+                        policy.max_retries + 1,
+                        delay,
+                        last_error
+                    );
+                    sleep(delay).await;
+                }
+            }
+        }
+    }
+
+    Err(last_error.unwrap())
 }
 
 /// Parse JSON response with error handling
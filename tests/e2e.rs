@@ -6,6 +6,9 @@ async fn spawn_app() -> (String, JoinHandle<()>) {
     let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
     let addr = listener.local_addr().unwrap();
     let cache = std::sync::Arc::new(trading_api::cache::MemoryCache::new());
+    let pumpfun_service = std::sync::Arc::new(trading_api::sources::pumpfun_data::PumpFunService::new(
+        trading_api::sources::pumpfun_data::PumpFunConfig::from_env(),
+    ));
     let state = AppState {
         http: reqwest::Client::new(),
         yahoo: std::sync::Arc::new(YahooConnector::new().unwrap()),
@@ -14,8 +17,34 @@ async fn spawn_app() -> (String, JoinHandle<()>) {
         cache: cache.clone(),
         rate_limiter: std::sync::Arc::new(trading_api::middleware::RateLimiter::new(trading_api::middleware::RateLimitConfig::default())),
         optimized_client: trading_api::optimized_client::OptimizedApiClient::new(cache).unwrap(),
+        data_rate_limiter: std::sync::Arc::new(trading_api::middleware::redis_rate_limit::DeferredRateLimiter::new(trading_api::middleware::redis_rate_limit::DeferredRateLimitConfig::default())),
+        solana_ws_hub: std::sync::Arc::new(trading_api::sources::helius_data::SolanaWsHub::new(None)),
+        solana_pubsub_hub: std::sync::Arc::new(trading_api::sources::helius_data::SolanaPubsubHub::new(None, trading_api::config::Config::default().retry)),
+        transaction_tracker: std::sync::Arc::new(trading_api::sources::helius_data::TransactionTracker::new(None)),
+        finviz_cache: std::sync::Arc::new(trading_api::sources::finviz_cache::FinvizScrapeCache::new()),
+        screener_stream_hub: std::sync::Arc::new(trading_api::sources::finviz_data::ScreenerStreamHub::new()),
+        history_store: std::sync::Arc::new(trading_api::services::history::HistoryStore::disabled()),
+        kraken_ws_hub: std::sync::Arc::new(trading_api::sources::kraken_data::KrakenWsHub::new(trading_api::config::Config::default().retry)),
+        alpaca_ws_hub: trading_api::sources::alpaca_data::AlpacaWsHub::new(String::new(), String::new(), trading_api::config::Config::default().retry),
+        candle_store: std::sync::Arc::new(trading_api::services::candles::CandleStore::disabled()),
+        backfill_tracker: std::sync::Arc::new(trading_api::services::backfill::BackfillTracker::new()),
+        prometheus_metrics: std::sync::Arc::new(trading_api::monitoring::PrometheusMetrics::new()),
+        health_registry: std::sync::Arc::new(trading_api::services::health::HealthRegistry::new()),
+        incident_log: std::sync::Arc::new(trading_api::services::health::IncidentLog::new(200)),
+        system_monitor: std::sync::Arc::new(trading_api::monitoring::SystemMonitor::new()),
+        kraken_snapshot_hub: std::sync::Arc::new(trading_api::sources::kraken_ws::KrakenSnapshotHub::new(vec![], trading_api::config::Config::default().retry)),
+        kraken_book_hub: std::sync::Arc::new(trading_api::sources::kraken_data::KrakenOrderBookHub::new(trading_api::config::Config::default().retry)),
+        hyperliquid_ws_hub: std::sync::Arc::new(trading_api::sources::hyperliquid_data::HyperliquidWsHub::new(trading_api::config::Config::default().retry)),
+        hyperliquid: std::sync::Arc::new(trading_api::sources::hyperliquid_data::HyperliquidDataSource::new().await.unwrap()),
+        coinbase: std::sync::Arc::new(trading_api::sources::coinbase_data::CoinbaseDataSource::new()),
+        alpaca: std::sync::Arc::new(trading_api::sources::alpaca_data::AlpacaDataSource::new(String::new(), String::new())),
+        trend_store: std::sync::Arc::new(trading_api::services::trends::TrendStore::new()),
+        market_store: std::sync::Arc::new(trading_api::services::market_store::MarketStore::disabled()),
+        pumpfun_service: pumpfun_service.clone(),
+        position_manager: std::sync::Arc::new(trading_api::services::position_manager::PositionManager::disabled(pumpfun_service)),
+        live_feed_hub: std::sync::Arc::new(trading_api::services::live_feed::LiveFeedHub::new()),
     };
-    let app = build_app(state).into_make_service();
+    let app = build_app(state).into_make_service_with_connect_info::<std::net::SocketAddr>();
     let h = tokio::spawn(async move { axum::serve(listener, app).await.unwrap() });
     (format!("http://{addr}"), h)
 }
@@ -28,6 +57,18 @@ async fn health_ok() {
     assert!(res.status().is_success());
 }
 
+#[tokio::test]
+async fn pumpfun_endpoints() {
+    if std::env::var("RUN_E2E").is_err() { return; }
+    let (base, _h) = spawn_app().await;
+    // Regression test for the router never being mounted in `build_app`: these must be
+    // reachable, not 404, even if the underlying pump.fun API call itself fails.
+    let res = reqwest::get(format!("{base}/pumpfun/positions/rules")).await.unwrap();
+    assert!(res.status().is_success(), "failed: /pumpfun/positions/rules with status: {}", res.status());
+    let res = reqwest::get(format!("{base}/pumpfun/trending?limit=2")).await.unwrap();
+    assert_ne!(res.status(), reqwest::StatusCode::NOT_FOUND, "failed: /pumpfun/trending with status: {}", res.status());
+}
+
 #[tokio::test]
 async fn data_endpoints() {
     if std::env::var("RUN_E2E").is_err() { return; }
@@ -16,6 +16,34 @@ async fn spawn_app() -> (String, JoinHandle<()>) {
         cache: cache.clone(),
         rate_limiter: std::sync::Arc::new(trading_api::middleware::RateLimiter::new(trading_api::middleware::RateLimitConfig::default())),
         optimized_client: trading_api::optimized_client::OptimizedApiClient::new(cache).unwrap(),
+        data_rate_limiter: std::sync::Arc::new(trading_api::middleware::redis_rate_limit::DeferredRateLimiter::new(trading_api::middleware::redis_rate_limit::DeferredRateLimitConfig::default())),
+        solana_ws_hub: std::sync::Arc::new(trading_api::sources::helius_data::SolanaWsHub::new(None)),
+        solana_pubsub_hub: std::sync::Arc::new(trading_api::sources::helius_data::SolanaPubsubHub::new(None, trading_api::config::Config::default().retry)),
+        transaction_tracker: std::sync::Arc::new(trading_api::sources::helius_data::TransactionTracker::new(None)),
+        finviz_cache: std::sync::Arc::new(trading_api::sources::finviz_cache::FinvizScrapeCache::new()),
+        screener_stream_hub: std::sync::Arc::new(trading_api::sources::finviz_data::ScreenerStreamHub::new()),
+        history_store: std::sync::Arc::new(trading_api::services::history::HistoryStore::disabled()),
+        kraken_ws_hub: std::sync::Arc::new(trading_api::sources::kraken_data::KrakenWsHub::new(trading_api::config::Config::default().retry)),
+        alpaca_ws_hub: trading_api::sources::alpaca_data::AlpacaWsHub::new(String::new(), String::new(), trading_api::config::Config::default().retry),
+        candle_store: std::sync::Arc::new(trading_api::services::candles::CandleStore::disabled()),
+        backfill_tracker: std::sync::Arc::new(trading_api::services::backfill::BackfillTracker::new()),
+        prometheus_metrics: std::sync::Arc::new(trading_api::monitoring::PrometheusMetrics::new()),
+        health_registry: std::sync::Arc::new(trading_api::services::health::HealthRegistry::new()),
+        incident_log: std::sync::Arc::new(trading_api::services::health::IncidentLog::new(200)),
+        system_monitor: std::sync::Arc::new(trading_api::monitoring::SystemMonitor::new()),
+        kraken_snapshot_hub: std::sync::Arc::new(trading_api::sources::kraken_ws::KrakenSnapshotHub::new(vec![], trading_api::config::Config::default().retry)),
+        kraken_book_hub: std::sync::Arc::new(trading_api::sources::kraken_data::KrakenOrderBookHub::new(trading_api::config::Config::default().retry)),
+        hyperliquid_ws_hub: std::sync::Arc::new(trading_api::sources::hyperliquid_data::HyperliquidWsHub::new(trading_api::config::Config::default().retry)),
+        hyperliquid: std::sync::Arc::new(trading_api::sources::hyperliquid_data::HyperliquidDataSource::new().await.unwrap()),
+        coinbase: std::sync::Arc::new(trading_api::sources::coinbase_data::CoinbaseDataSource::new()),
+        alpaca: std::sync::Arc::new(trading_api::sources::alpaca_data::AlpacaDataSource::new(String::new(), String::new())),
+        trend_store: std::sync::Arc::new(trading_api::services::trends::TrendStore::new()),
+        market_store: std::sync::Arc::new(trading_api::services::market_store::MarketStore::disabled()),
+        pumpfun_service: std::sync::Arc::new(trading_api::sources::pumpfun_data::PumpFunService::new(trading_api::sources::pumpfun_data::PumpFunConfig::from_env())),
+        position_manager: std::sync::Arc::new(trading_api::services::position_manager::PositionManager::disabled(
+            std::sync::Arc::new(trading_api::sources::pumpfun_data::PumpFunService::new(trading_api::sources::pumpfun_data::PumpFunConfig::from_env())),
+        )),
+        live_feed_hub: std::sync::Arc::new(trading_api::services::live_feed::LiveFeedHub::new()),
     };
     let app = build_app(state).into_make_service();
     let h = tokio::spawn(async move { axum::serve(listener, app).await.unwrap() });